@@ -0,0 +1,132 @@
+use std::hint::black_box;
+use std::net::{IpAddr, Ipv4Addr};
+use std::sync::LazyLock as Lazy;
+
+use bytes::Bytes;
+use const_addrs::ip;
+use criterion::{BatchSize, Criterion, criterion_group, criterion_main};
+use holo_bgp::af::{AddressFamily, Ipv4Unicast};
+use holo_bgp::neighbor::{Neighbor, NeighborUpdateQueue, Neighbors, PeerType};
+use holo_bgp::packet::attribute::{Attrs, BaseAttrs};
+use holo_bgp::packet::message::{EncodeCxt, Message, NegotiatedCapability};
+use holo_bgp::update_group::{UpdateGroups, encode_messages};
+use ipnetwork::Ipv4Network;
+
+const NUM_PREFIXES: u32 = 10000;
+const NUM_NEIGHBORS: usize = 16;
+const NUM_PEERS: u32 = 1000;
+
+static MESSAGES: Lazy<Vec<Message>> = Lazy::new(|| {
+    let attrs = Attrs {
+        base: BaseAttrs {
+            nexthop: Some(ip!("1.1.1.1")),
+            local_pref: Some(100),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let prefixes = (0..NUM_PREFIXES)
+        .map(|i| {
+            let addr = Ipv4Addr::from(0x0a000000 + (i << 8));
+            Ipv4Network::new(addr, 24).unwrap()
+        })
+        .collect();
+    let mut queue = NeighborUpdateQueue::<Ipv4Unicast> {
+        reach: [(attrs, prefixes)].into(),
+        unreach: Default::default(),
+    };
+    Ipv4Unicast::build_updates(&mut queue, Message::MAX_LEN).0
+});
+
+static CXT: Lazy<EncodeCxt> = Lazy::new(|| EncodeCxt {
+    capabilities: [NegotiatedCapability::FourOctetAsNumber].into(),
+});
+
+// Encodes the same UPDATE messages separately for each neighbor.
+fn per_neighbor_encode(n: usize) -> Vec<Vec<Bytes>> {
+    (0..n)
+        .map(|_| MESSAGES.iter().map(|msg| msg.encode(&CXT)).collect())
+        .collect()
+}
+
+// Encodes the UPDATE messages once and shares the buffer among all
+// neighbors.
+fn update_group_encode(n: usize) -> Vec<Bytes> {
+    let buf = encode_messages(&MESSAGES, &CXT);
+    (0..n).map(|_| buf.clone()).collect()
+}
+
+// Creates a set of iBGP neighbors sharing the same export policy.
+fn neighbors(n: u32) -> Neighbors {
+    (0..n)
+        .map(|i| {
+            let addr = IpAddr::from(Ipv4Addr::from(0x0a000001 + i));
+            let mut nbr = Neighbor::new(addr, PeerType::Internal);
+            nbr.config.peer_as = 65000;
+            nbr.config.apply_policy.export_policy =
+                ["export-all".to_owned()].into();
+            nbr.capabilities_nego =
+                [NegotiatedCapability::FourOctetAsNumber].into();
+            (addr, nbr)
+        })
+        .collect()
+}
+
+// Places all neighbors in their update groups.
+fn update_groups_build(neighbors: &mut Neighbors) -> UpdateGroups {
+    let mut update_groups = UpdateGroups::default();
+    for nbr in neighbors.values_mut() {
+        update_groups.join(nbr);
+    }
+    update_groups
+}
+
+// Changes the shared export policy of all neighbors, moving every neighbor
+// to a new update group.
+fn update_groups_regroup(
+    update_groups: &mut UpdateGroups,
+    neighbors: &mut Neighbors,
+) {
+    for nbr in neighbors.values_mut() {
+        nbr.config.apply_policy.export_policy =
+            ["export-none".to_owned()].into();
+        update_groups.update(nbr);
+    }
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    Lazy::force(&MESSAGES);
+
+    c.bench_function("Per-neighbor encode", |b| {
+        b.iter(|| per_neighbor_encode(black_box(NUM_NEIGHBORS)))
+    });
+    c.bench_function("Update group encode", |b| {
+        b.iter(|| update_group_encode(black_box(NUM_NEIGHBORS)))
+    });
+    c.bench_function("Update group build", |b| {
+        b.iter_batched(
+            || neighbors(NUM_PEERS),
+            |mut neighbors| update_groups_build(black_box(&mut neighbors)),
+            BatchSize::LargeInput,
+        )
+    });
+    c.bench_function("Update group regroup", |b| {
+        b.iter_batched(
+            || {
+                let mut neighbors = neighbors(NUM_PEERS);
+                let update_groups = update_groups_build(&mut neighbors);
+                (update_groups, neighbors)
+            },
+            |(mut update_groups, mut neighbors)| {
+                update_groups_regroup(
+                    black_box(&mut update_groups),
+                    &mut neighbors,
+                )
+            },
+            BatchSize::LargeInput,
+        )
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -4,6 +4,7 @@
 // SPDX-License-Identifier: MIT
 //
 
+use std::collections::BTreeSet;
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
 use holo_utils::bgp::AfiSafi;
@@ -51,8 +52,16 @@ pub trait AddressFamily: Sized {
     // Modify the next hop(s) for transmission.
     fn nexthop_tx_change(nbr: &Neighbor, local: bool, attrs: &mut BaseAttrs);
 
-    // Build BGP UPDATE messages based on the provided update queue.
-    fn build_updates(queue: &mut NeighborUpdateQueue<Self>) -> Vec<Message>;
+    // Build BGP UPDATE messages based on the provided update queue, packing
+    // as many prefixes as possible in each message without exceeding the
+    // given maximum message length.
+    //
+    // Also returns the prefixes that were withdrawn because their path
+    // attributes don't fit in a message of the given maximum length.
+    fn build_updates(
+        queue: &mut NeighborUpdateQueue<Self>,
+        max_len: u16,
+    ) -> (Vec<Message>, BTreeSet<Self::IpNetwork>);
 }
 
 #[derive(Debug)]
@@ -122,19 +131,35 @@ impl AddressFamily for Ipv4Unicast {
         }
     }
 
-    fn build_updates(queue: &mut NeighborUpdateQueue<Self>) -> Vec<Message> {
+    fn build_updates(
+        queue: &mut NeighborUpdateQueue<Self>,
+        max_len: u16,
+    ) -> (Vec<Message>, BTreeSet<Self::IpNetwork>) {
         let mut msgs = vec![];
+        let mut oversized = BTreeSet::new();
         let reach = std::mem::take(&mut queue.reach);
-        let unreach = std::mem::take(&mut queue.unreach);
+        let mut unreach = std::mem::take(&mut queue.unreach);
 
         // Reachable prefixes.
         for (attrs, prefixes) in reach.into_iter() {
             let nexthop = Ipv4Addr::get(attrs.base.nexthop.unwrap()).unwrap();
-            let max = (Message::MAX_LEN
-                - UpdateMsg::MIN_LEN
-                - attrs.length()
-                - attribute::nexthop::length())
-                / (1 + Ipv4Addr::LENGTH as u16);
+            let max = max_len
+                .checked_sub(UpdateMsg::MIN_LEN)
+                .and_then(|len| len.checked_sub(attrs.length()))
+                .and_then(|len| len.checked_sub(attribute::nexthop::length()))
+                .map(|len| len / (1 + Ipv4Addr::LENGTH as u16))
+                .unwrap_or(0);
+
+            // The path attributes don't fit in a message that the neighbor
+            // can receive (e.g. they were learned from a neighbor that
+            // supports extended messages). As per RFC 8654 Section 4, the
+            // UPDATE must not be sent and the prefixes are treated as
+            // withdrawn.
+            if max == 0 {
+                unreach.extend(prefixes.iter().copied());
+                oversized.extend(prefixes);
+                continue;
+            }
 
             msgs.extend(
                 prefixes.into_iter().chunks(max as usize).into_iter().map(
@@ -157,8 +182,8 @@ impl AddressFamily for Ipv4Unicast {
 
         // Unreachable prefixes.
         if !unreach.is_empty() {
-            let max = (Message::MAX_LEN - UpdateMsg::MIN_LEN)
-                / (1 + Ipv4Addr::LENGTH as u16);
+            let max =
+                (max_len - UpdateMsg::MIN_LEN) / (1 + Ipv4Addr::LENGTH as u16);
 
             msgs.extend(
                 unreach.into_iter().chunks(max as usize).into_iter().map(
@@ -178,7 +203,7 @@ impl AddressFamily for Ipv4Unicast {
             );
         }
 
-        msgs
+        (msgs, oversized)
     }
 }
 
@@ -255,23 +280,37 @@ impl AddressFamily for Ipv6Unicast {
         }
     }
 
-    fn build_updates(queue: &mut NeighborUpdateQueue<Self>) -> Vec<Message> {
+    fn build_updates(
+        queue: &mut NeighborUpdateQueue<Self>,
+        max_len: u16,
+    ) -> (Vec<Message>, BTreeSet<Self::IpNetwork>) {
         let mut msgs = vec![];
+        let mut oversized = BTreeSet::new();
         let reach = std::mem::take(&mut queue.reach);
-        let unreach = std::mem::take(&mut queue.unreach);
+        let mut unreach = std::mem::take(&mut queue.unreach);
 
         // Reachable prefixes.
         for (attrs, prefixes) in reach.into_iter() {
             let nexthop = Ipv6Addr::get(attrs.base.nexthop.unwrap()).unwrap();
             let ll_nexthop = attrs.base.ll_nexthop;
             let nexthop_len = if ll_nexthop.is_some() { 32 } else { 16 };
-            let max = (Message::MAX_LEN
-                - UpdateMsg::MIN_LEN
-                - attrs.length()
-                - ATTR_MIN_LEN_EXT
-                - MpReachNlri::MIN_LEN
-                - nexthop_len)
-                / (1 + Ipv6Addr::LENGTH as u16);
+            let max = max_len
+                .checked_sub(UpdateMsg::MIN_LEN)
+                .and_then(|len| len.checked_sub(attrs.length()))
+                .and_then(|len| len.checked_sub(ATTR_MIN_LEN_EXT))
+                .and_then(|len| len.checked_sub(MpReachNlri::MIN_LEN))
+                .and_then(|len| len.checked_sub(nexthop_len))
+                .map(|len| len / (1 + Ipv6Addr::LENGTH as u16))
+                .unwrap_or(0);
+
+            // The path attributes don't fit in a message that the neighbor
+            // can receive. As per RFC 8654 Section 4, the UPDATE must not be
+            // sent and the prefixes are treated as withdrawn.
+            if max == 0 {
+                unreach.extend(prefixes.iter().copied());
+                oversized.extend(prefixes);
+                continue;
+            }
 
             msgs.extend(
                 prefixes.into_iter().chunks(max as usize).into_iter().map(
@@ -295,7 +334,7 @@ impl AddressFamily for Ipv6Unicast {
 
         // Unreachable prefixes.
         if !unreach.is_empty() {
-            let max = (Message::MAX_LEN
+            let max = (max_len
                 - UpdateMsg::MIN_LEN
                 - ATTR_MIN_LEN_EXT
                 - MpUnreachNlri::MIN_LEN)
@@ -319,6 +358,93 @@ impl AddressFamily for Ipv6Unicast {
             );
         }
 
-        msgs
+        (msgs, oversized)
+    }
+}
+
+// ===== unit tests =====
+
+#[cfg(test)]
+mod tests {
+    use const_addrs::{ip, net4, net6};
+    use holo_utils::bgp::Comm;
+
+    use super::*;
+    use crate::packet::attribute::{Attrs, CommList};
+
+    // Attributes that only fit in an extended message.
+    fn oversized_attrs(nexthop: IpAddr) -> Attrs {
+        Attrs {
+            base: BaseAttrs {
+                nexthop: Some(nexthop),
+                ..Default::default()
+            },
+            comm: Some(CommList((0..2000).map(Comm).collect())),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn oversized_attrs_ipv4() {
+        let attrs = oversized_attrs(ip!("1.1.1.1"));
+        let mut queue = NeighborUpdateQueue::<Ipv4Unicast> {
+            reach: [(attrs.clone(), [net4!("10.0.1.0/24")].into())].into(),
+            unreach: [net4!("10.0.2.0/24")].into(),
+        };
+
+        // The prefixes are withdrawn toward a peer that doesn't support
+        // extended messages.
+        let (msgs, oversized) =
+            Ipv4Unicast::build_updates(&mut queue, Message::MAX_LEN);
+        assert_eq!(oversized, [net4!("10.0.1.0/24")].into());
+        assert_eq!(msgs.len(), 1);
+        let Message::Update(msg) = &msgs[0] else {
+            panic!("unexpected message type");
+        };
+        assert!(msg.reach.is_none());
+        assert!(msg.attrs.is_none());
+        assert_eq!(
+            msg.unreach.as_ref().unwrap().prefixes,
+            [net4!("10.0.1.0/24"), net4!("10.0.2.0/24")]
+        );
+
+        // The prefixes are advertised to a peer that supports extended
+        // messages.
+        let mut queue = NeighborUpdateQueue::<Ipv4Unicast> {
+            reach: [(attrs, [net4!("10.0.1.0/24")].into())].into(),
+            unreach: Default::default(),
+        };
+        let (msgs, oversized) =
+            Ipv4Unicast::build_updates(&mut queue, Message::MAX_LEN_EXT);
+        assert!(oversized.is_empty());
+        assert_eq!(msgs.len(), 1);
+        let Message::Update(msg) = &msgs[0] else {
+            panic!("unexpected message type");
+        };
+        assert!(msg.reach.is_some());
+        assert!(msg.unreach.is_none());
+    }
+
+    #[test]
+    fn oversized_attrs_ipv6() {
+        let attrs = oversized_attrs(ip!("2001:db8::1"));
+        let mut queue = NeighborUpdateQueue::<Ipv6Unicast> {
+            reach: [(attrs, [net6!("2001:db8:1::/64")].into())].into(),
+            unreach: Default::default(),
+        };
+
+        let (msgs, oversized) =
+            Ipv6Unicast::build_updates(&mut queue, Message::MAX_LEN);
+        assert_eq!(oversized, [net6!("2001:db8:1::/64")].into());
+        assert_eq!(msgs.len(), 1);
+        let Message::Update(msg) = &msgs[0] else {
+            panic!("unexpected message type");
+        };
+        assert!(msg.mp_reach.is_none());
+        assert!(matches!(
+            &msg.mp_unreach,
+            Some(MpUnreachNlri::Ipv6Unicast { prefixes })
+                if *prefixes == [net6!("2001:db8:1::/64")]
+        ));
     }
 }
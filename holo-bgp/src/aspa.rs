@@ -0,0 +1,337 @@
+//
+// Copyright (c) The Holo Core Contributors
+//
+// SPDX-License-Identifier: MIT
+//
+
+use std::collections::{BTreeSet, HashMap};
+use std::net::SocketAddr;
+use std::path::Path;
+
+use holo_utils::bgp::AspaValidationState;
+use holo_utils::task::Task;
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc::Sender;
+
+use crate::northbound::configuration::AspaCfg;
+use crate::packet::attribute::{AsPath, AsPathSegmentType};
+use crate::tasks;
+use crate::tasks::messages::input::AspaDbUpdateMsg;
+
+// ASPA object store.
+//
+// Maps each customer AS to its set of provider ASes, as attested by the
+// validated ASPA payloads.
+#[derive(Clone, Debug, Default)]
+#[derive(Deserialize, Serialize)]
+pub struct AspaDb {
+    pub records: HashMap<u32, BTreeSet<u32>>,
+}
+
+// Relationship of a neighbor to the local AS.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AspaRole {
+    Customer,
+    Peer,
+    Provider,
+    Rs,
+    RsClient,
+}
+
+// Result of the authorization check of a single AS_PATH hop.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Hop {
+    ProviderPlus,
+    NotProviderPlus,
+    NoAttestation,
+}
+
+// JSON file containing validated ASPA payloads.
+//
+// Both the rpki-client and Routinator output formats are supported.
+#[derive(Debug, Deserialize)]
+struct AspaFile {
+    #[serde(default)]
+    aspas: Vec<AspaFileRecord>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AspaFileRecord {
+    #[serde(alias = "customer_asid")]
+    customer: AspaFileAsn,
+    providers: Vec<AspaFileAsn>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum AspaFileAsn {
+    Number(u32),
+    String(String),
+}
+
+// ===== impl AspaDb =====
+
+impl AspaDb {
+    // Reads the ASPA records from the given JSON file.
+    pub(crate) fn read(path: &Path) -> Result<AspaDb, std::io::Error> {
+        let data = std::fs::read_to_string(path)?;
+        let file: AspaFile = serde_json::from_str(&data)?;
+
+        let mut records: HashMap<u32, BTreeSet<u32>> = HashMap::new();
+        for record in file.aspas {
+            let customer = record.customer.get()?;
+            let providers = records.entry(customer).or_default();
+            for provider in record.providers {
+                providers.insert(provider.get()?);
+            }
+        }
+
+        Ok(AspaDb { records })
+    }
+
+    // Verifies the given AS_PATH, received from a neighbor with the provided
+    // AS number and relationship to the local AS.
+    pub(crate) fn verify(
+        &self,
+        as_path: &AsPath,
+        role: AspaRole,
+        peer_as: u32,
+    ) -> AspaValidationState {
+        // AS_PATHs containing AS_SETs are always considered invalid.
+        if as_path
+            .segments
+            .iter()
+            .any(|segment| segment.seg_type != AsPathSegmentType::Sequence)
+        {
+            return AspaValidationState::Invalid;
+        }
+
+        // Collapse prepends, ordering the ASes from the origin AS to the most
+        // recently added one.
+        let mut path = as_path.iter().collect::<Vec<_>>();
+        path.dedup();
+        path.reverse();
+
+        // Except for transparent route servers, the most recently added AS
+        // must be the neighbor's AS.
+        let Some(last) = path.last() else {
+            return AspaValidationState::Invalid;
+        };
+        if role != AspaRole::Rs && *last != peer_as {
+            return AspaValidationState::Invalid;
+        }
+
+        let n = path.len();
+        let max_up_ramp =
+            self.up_ramp(&path, |hop| hop != Hop::NotProviderPlus);
+        let min_up_ramp = self.up_ramp(&path, |hop| hop == Hop::ProviderPlus);
+        match role {
+            AspaRole::Customer
+            | AspaRole::Peer
+            | AspaRole::Rs
+            | AspaRole::RsClient => {
+                // Upstream verification procedure.
+                if max_up_ramp < n {
+                    return AspaValidationState::Invalid;
+                }
+                if min_up_ramp < n {
+                    return AspaValidationState::Unknown;
+                }
+            }
+            AspaRole::Provider => {
+                // Downstream verification procedure.
+                if n <= 2 {
+                    return AspaValidationState::Valid;
+                }
+                let max_down_ramp =
+                    self.down_ramp(&path, |hop| hop != Hop::NotProviderPlus);
+                let min_down_ramp =
+                    self.down_ramp(&path, |hop| hop == Hop::ProviderPlus);
+                if max_up_ramp + max_down_ramp < n {
+                    return AspaValidationState::Invalid;
+                }
+                if min_up_ramp + min_down_ramp < n {
+                    return AspaValidationState::Unknown;
+                }
+            }
+        }
+
+        AspaValidationState::Valid
+    }
+
+    // Checks whether `provider` is authorized as a provider of `customer`.
+    fn hop(&self, customer: u32, provider: u32) -> Hop {
+        match self.records.get(&customer) {
+            Some(providers) if providers.contains(&provider) => {
+                Hop::ProviderPlus
+            }
+            Some(_) => Hop::NotProviderPlus,
+            None => Hop::NoAttestation,
+        }
+    }
+
+    // Returns the length of the longest sequence of ASes, starting from the
+    // origin AS, where each hop satisfies the given predicate.
+    fn up_ramp(&self, path: &[u32], pred: impl Fn(Hop) -> bool) -> usize {
+        let mut k = 1;
+        while k < path.len() && pred(self.hop(path[k - 1], path[k])) {
+            k += 1;
+        }
+        k
+    }
+
+    // Returns the length of the longest sequence of ASes, starting from the
+    // most recently added AS, where each hop satisfies the given predicate.
+    fn down_ramp(&self, path: &[u32], pred: impl Fn(Hop) -> bool) -> usize {
+        let n = path.len();
+        let mut l = 1;
+        while l < n && pred(self.hop(path[n - l], path[n - l - 1])) {
+            l += 1;
+        }
+        l
+    }
+}
+
+// ===== impl AspaFileAsn =====
+
+impl AspaFileAsn {
+    fn get(&self) -> Result<u32, std::io::Error> {
+        match self {
+            AspaFileAsn::Number(asn) => Ok(*asn),
+            AspaFileAsn::String(asn) => {
+                asn.strip_prefix("AS").unwrap_or(asn).parse().map_err(|_| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        format!("invalid AS number: {asn}"),
+                    )
+                })
+            }
+        }
+    }
+}
+
+// ===== global functions =====
+
+// Starts the task that loads the ASPA records from the configured source,
+// either a local JSON file or an RTR cache.
+//
+// The records are sent to the instance once loaded, and again whenever the
+// RTR cache provides an update.
+//
+// Returns `None` when ASPA verification is disabled.
+pub(crate) fn source_task(
+    config: &AspaCfg,
+    aspa_db_updatep: &Sender<AspaDbUpdateMsg>,
+) -> Option<Task<()>> {
+    if let Some(addr) = config.rtr_cache_addr {
+        let addr = SocketAddr::new(addr, config.rtr_cache_port);
+        return Some(tasks::aspa_rtr_client(addr, aspa_db_updatep));
+    }
+
+    let path = config.file.as_ref()?;
+    Some(tasks::aspa_file_read(path.clone(), aspa_db_updatep))
+}
+
+// ===== tests =====
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::packet::attribute::AsPathSegment;
+
+    fn as_path(members: &[u32]) -> AsPath {
+        AsPath {
+            segments: [AsPathSegment {
+                seg_type: AsPathSegmentType::Sequence,
+                members: members.iter().copied().collect(),
+            }]
+            .into(),
+        }
+    }
+
+    fn aspa_db(records: &[(u32, &[u32])]) -> AspaDb {
+        AspaDb {
+            records: records
+                .iter()
+                .map(|(customer, providers)| {
+                    (*customer, providers.iter().copied().collect())
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn verify_upstream() {
+        // AS 10 is a customer of AS 20, which is a customer of AS 30.
+        let db = aspa_db(&[(10, &[20]), (20, &[30])]);
+
+        // Fully attested path, with prepends.
+        let path = as_path(&[30, 20, 20, 10]);
+        assert_eq!(
+            db.verify(&path, AspaRole::Customer, 30),
+            AspaValidationState::Valid
+        );
+
+        // AS 30 has no ASPA record.
+        let path = as_path(&[40, 30, 20, 10]);
+        assert_eq!(
+            db.verify(&path, AspaRole::Customer, 40),
+            AspaValidationState::Unknown
+        );
+
+        // Route leak: AS 30 isn't a provider of AS 10.
+        let path = as_path(&[20, 30, 10]);
+        assert_eq!(
+            db.verify(&path, AspaRole::Customer, 20),
+            AspaValidationState::Invalid
+        );
+
+        // The neighbor's AS must be the most recently added one.
+        let path = as_path(&[20, 10]);
+        assert_eq!(
+            db.verify(&path, AspaRole::Peer, 30),
+            AspaValidationState::Invalid
+        );
+        assert_eq!(
+            db.verify(&path, AspaRole::Rs, 30),
+            AspaValidationState::Valid
+        );
+    }
+
+    #[test]
+    fn verify_downstream() {
+        // AS 10 and AS 50 are customers of AS 20 and AS 40 respectively,
+        // which are both customers of AS 30.
+        let db = aspa_db(&[(10, &[20]), (20, &[30]), (40, &[30]), (50, &[40])]);
+
+        // Valid up-ramp followed by a valid down-ramp.
+        let path = as_path(&[40, 30, 20, 10]);
+        assert_eq!(
+            db.verify(&path, AspaRole::Provider, 40),
+            AspaValidationState::Valid
+        );
+
+        // Lateral peering at the apex (AS 20 and AS 60) is allowed.
+        let db2 =
+            aspa_db(&[(10, &[20]), (20, &[99]), (40, &[60]), (60, &[99])]);
+        let path = as_path(&[40, 60, 20, 10]);
+        assert_eq!(
+            db2.verify(&path, AspaRole::Provider, 40),
+            AspaValidationState::Valid
+        );
+
+        // Valley: the route goes down to AS 50 and back up again.
+        let path = as_path(&[40, 50, 40, 30, 20, 10]);
+        assert_eq!(
+            db.verify(&path, AspaRole::Provider, 40),
+            AspaValidationState::Invalid
+        );
+
+        // Paths with up to two ASes are always valid.
+        let path = as_path(&[40, 50]);
+        assert_eq!(
+            db.verify(&path, AspaRole::Provider, 40),
+            AspaValidationState::Valid
+        );
+    }
+}
@@ -15,6 +15,7 @@ use crate::packet::error::AttrError;
 use crate::packet::iana::AttrType;
 use crate::packet::message::Message;
 use crate::rib::Route;
+use crate::update_group::UpdateGroupId;
 
 // BGP debug messages.
 #[derive(Debug)]
@@ -28,6 +29,9 @@ pub enum Debug<'a> {
     NbrMsgRx(&'a IpAddr, &'a Message),
     NbrMsgTx(&'a IpAddr, &'a Message),
     NbrAttrError(AttrType, AttrError),
+    UpdateGroupJoin(&'a IpAddr, UpdateGroupId),
+    UpdateGroupLeave(&'a IpAddr, UpdateGroupId),
+    UpdateGroupSplit(&'a IpAddr, UpdateGroupId),
     BestPathFound(IpNetwork, &'a Route),
     BestPathNotFound(IpNetwork),
     NhtUpdate(IpAddr, Option<u32>),
@@ -95,6 +99,14 @@ impl Debug<'_> {
                 // Parent span(s): bgp-instance
                 debug!(?attr_type, ?action, "{}", self);
             }
+            Debug::UpdateGroupJoin(addr, group_id)
+            | Debug::UpdateGroupLeave(addr, group_id)
+            | Debug::UpdateGroupSplit(addr, group_id) => {
+                // Parent span(s): bgp-instance
+                debug_span!("neighbor", %addr).in_scope(|| {
+                    debug!(%group_id, "{}", self);
+                });
+            }
             Debug::BestPathFound(prefix, route) => {
                 // Parent span(s): bgp-instance
                 debug!(%prefix, origin = ?route.origin, "{}", self);
@@ -151,6 +163,15 @@ impl std::fmt::Display for Debug<'_> {
             Debug::NbrAttrError(..) => {
                 write!(f, "malformed attribute")
             }
+            Debug::UpdateGroupJoin(..) => {
+                write!(f, "joined update group")
+            }
+            Debug::UpdateGroupLeave(..) => {
+                write!(f, "left update group")
+            }
+            Debug::UpdateGroupSplit(..) => {
+                write!(f, "split off from update group")
+            }
             Debug::BestPathFound(..) => {
                 write!(f, "best path found")
             }
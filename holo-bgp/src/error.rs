@@ -35,6 +35,10 @@ pub enum IoError {
     TcpAuthError(std::io::Error),
     TcpRecvError(std::io::Error),
     TcpSendError(std::io::Error),
+    MrtWriteError(std::io::Error),
+    AspaReadError(std::io::Error),
+    RtrConnectError(std::io::Error),
+    RtrSessionError(std::io::Error),
 }
 
 // Neighbor Rx errors.
@@ -121,7 +125,11 @@ impl IoError {
             | IoError::TcpAuthError(error)
             | IoError::TcpInfoError(error)
             | IoError::TcpRecvError(error)
-            | IoError::TcpSendError(error) => {
+            | IoError::TcpSendError(error)
+            | IoError::MrtWriteError(error)
+            | IoError::AspaReadError(error)
+            | IoError::RtrConnectError(error)
+            | IoError::RtrSessionError(error) => {
                 warn!(error = %with_source(error), "{}", self);
             }
         }
@@ -155,6 +163,18 @@ impl std::fmt::Display for IoError {
             IoError::TcpSendError(..) => {
                 write!(f, "failed to send TCP data")
             }
+            IoError::MrtWriteError(..) => {
+                write!(f, "failed to write MRT file")
+            }
+            IoError::AspaReadError(..) => {
+                write!(f, "failed to read ASPA records")
+            }
+            IoError::RtrConnectError(..) => {
+                write!(f, "failed to connect to RTR cache")
+            }
+            IoError::RtrSessionError(..) => {
+                write!(f, "RTR session with the cache failed")
+            }
         }
     }
 }
@@ -168,7 +188,11 @@ impl std::error::Error for IoError {
             | IoError::TcpAuthError(error)
             | IoError::TcpInfoError(error)
             | IoError::TcpRecvError(error)
-            | IoError::TcpSendError(error) => Some(error),
+            | IoError::TcpSendError(error)
+            | IoError::MrtWriteError(error)
+            | IoError::AspaReadError(error)
+            | IoError::RtrConnectError(error)
+            | IoError::RtrSessionError(error) => Some(error),
         }
     }
 }
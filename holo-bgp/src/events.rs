@@ -4,11 +4,13 @@
 // SPDX-License-Identifier: MIT
 //
 
+use std::collections::{BTreeMap, BTreeSet};
 use std::net::IpAddr;
 
+use bytes::Bytes;
 use chrono::Utc;
 use holo_protocol::InstanceShared;
-use holo_utils::bgp::RouteType;
+use holo_utils::bgp::{AfiSafi, Comm, RouteType, WellKnownCommunities};
 use holo_utils::ibus::IbusChannelsTx;
 use holo_utils::ip::{IpAddrKind, IpNetworkKind};
 use holo_utils::policy::{PolicyResult, PolicyType};
@@ -17,6 +19,7 @@ use ipnetwork::IpNetwork;
 use num_traits::FromPrimitive;
 
 use crate::af::{AddressFamily, Ipv4Unicast, Ipv6Unicast};
+use crate::aspa::AspaDb;
 use crate::debug::Debug;
 use crate::error::{Error, IoError, NbrRxError};
 use crate::instance::{InstanceUpView, PolicyApplyTasks};
@@ -24,12 +27,14 @@ use crate::neighbor::{Neighbor, Neighbors, PeerType, fsm};
 use crate::packet::attribute::Attrs;
 use crate::packet::iana::{Afi, Safi};
 use crate::packet::message::{
-    Capability, Message, MpReachNlri, MpUnreachNlri, RouteRefreshMsg, UpdateMsg,
+    Capability, Message, MpReachNlri, MpUnreachNlri, OrfWhenToRefresh,
+    RouteRefreshMsg, UpdateMsg,
 };
 use crate::policy::RoutePolicyInfo;
 use crate::rib::{AttrSetsCxt, Rib, Route, RouteOrigin, RoutingTable};
 use crate::tasks::messages::output::PolicyApplyMsg;
-use crate::{network, rib};
+use crate::update_group::{UpdateGroup, UpdateGroupId};
+use crate::{mrt, network, rib, update_group};
 
 // ===== TCP connection request =====
 
@@ -99,6 +104,7 @@ pub(crate) fn process_nbr_msg(
     neighbors: &mut Neighbors,
     nbr_addr: IpAddr,
     msg: Result<Message, NbrRxError>,
+    raw: Option<Bytes>,
 ) -> Result<(), Error> {
     // Lookup neighbor.
     let Some(nbr) = neighbors.get_mut(&nbr_addr) else {
@@ -115,6 +121,11 @@ pub(crate) fn process_nbr_msg(
             // Update statistics.
             nbr.statistics.msgs_rcvd.update(&msg);
 
+            // Log message in the MRT format.
+            if let Some(raw) = &raw {
+                mrt::log_message(instance, nbr, &msg, raw);
+            }
+
             match msg {
                 Message::Open(msg) => {
                     nbr.fsm_event(instance, fsm::Event::RcvdOpen(msg));
@@ -152,11 +163,30 @@ pub(crate) fn process_nbr_msg(
 fn process_nbr_update(
     instance: &mut InstanceUpView<'_>,
     nbr: &mut Neighbor,
-    msg: UpdateMsg,
+    mut msg: UpdateMsg,
 ) -> Result<(), Error> {
+    // Handle End-of-RIB markers by flushing the routes that remained
+    // long-lived stale (RFC 9494).
+    if let Some(afi) = msg.is_end_of_rib() {
+        let afi_safi = match afi {
+            Afi::Ipv4 => AfiSafi::Ipv4Unicast,
+            Afi::Ipv6 => AfiSafi::Ipv6Unicast,
+        };
+        nbr.llgr_stale_end(instance, afi_safi);
+        return Ok(());
+    }
+
     let rib = &mut instance.state.rib;
     let ibus_tx = &instance.tx.ibus;
 
+    // Ignore the AIGP attribute received over sessions where it's disabled
+    // (RFC 7311).
+    if !nbr.aigp_enabled()
+        && let Some(attrs) = &mut msg.attrs
+    {
+        attrs.base.aigp = None;
+    }
+
     // Process IPv4 reachable NLRIs.
     //
     // Use nexthop from the NEXTHOP attribute.
@@ -170,6 +200,7 @@ fn process_nbr_update(
                 reach.prefixes,
                 attrs,
                 instance.config.asn,
+                instance.state.aspa.as_ref(),
                 instance.shared,
                 &instance.state.policy_apply_tasks,
             );
@@ -198,6 +229,7 @@ fn process_nbr_update(
                         prefixes,
                         attrs,
                         instance.config.asn,
+                        instance.state.aspa.as_ref(),
                         instance.shared,
                         &instance.state.policy_apply_tasks,
                     );
@@ -215,6 +247,7 @@ fn process_nbr_update(
                         prefixes,
                         attrs,
                         instance.config.asn,
+                        instance.state.aspa.as_ref(),
                         instance.shared,
                         &instance.state.policy_apply_tasks,
                     );
@@ -275,6 +308,7 @@ fn process_nbr_reach_prefixes<A>(
     nlri_prefixes: Vec<A::IpNetwork>,
     mut attrs: Attrs,
     local_asn: u32,
+    aspa: Option<&AspaDb>,
     shared: &InstanceShared,
     policy_apply_tasks: &PolicyApplyTasks,
 ) where
@@ -301,34 +335,49 @@ fn process_nbr_reach_prefixes<A>(
         attrs.base.as_path.replace(nbr.config.peer_as, local_asn);
     }
 
+    // Verify the AS_PATH against the ASPA records.
+    let aspa_state = nbr.aspa_verify(aspa, &attrs.base.as_path);
+
     // Update pre-policy Adj-RIB-In routes.
     let table = A::table(&mut rib.tables);
     let route_attrs = rib.attr_sets.get_route_attr_sets(&attrs);
     for prefix in &nlri_prefixes {
         let dest = table.prefixes.entry(*prefix).or_default();
         let adj_rib = dest.adj_rib.entry(nbr.remote_addr).or_default();
-        let route = Route::new(origin, route_attrs.clone(), route_type);
+        let mut route = Route::new(origin, route_attrs.clone(), route_type);
+        route.aspa_state = aspa_state;
         adj_rib.update_in_pre(Box::new(route), &mut rib.attr_sets);
     }
 
+    // Enqueue import policy application.
+    let mut rpinfo =
+        RoutePolicyInfo::new(origin, route_type, None, None, attrs);
+    rpinfo.aspa_state = aspa_state;
+    let routes = nlri_prefixes
+        .into_iter()
+        .map(|prefix| (prefix.into(), rpinfo.clone()))
+        .collect();
+    nbr_policy_import_enqueue::<A>(nbr, routes, shared, policy_apply_tasks);
+}
+
+// Enqueues the application of the neighbor's import policies to the provided
+// list of routes.
+pub(crate) fn nbr_policy_import_enqueue<A>(
+    nbr: &Neighbor,
+    routes: Vec<(IpNetwork, RoutePolicyInfo)>,
+    shared: &InstanceShared,
+    policy_apply_tasks: &PolicyApplyTasks,
+) where
+    A: AddressFamily,
+{
     // Get policy configuration for the address family.
-    let apply_policy_cfg = &nbr
-        .config
-        .afi_safi
-        .get(&A::AFI_SAFI)
-        .map(|afi_safi| &afi_safi.apply_policy)
-        .unwrap_or(&nbr.config.apply_policy);
+    let apply_policy_cfg = nbr.apply_policy_cfg(A::AFI_SAFI);
 
-    // Enqueue import policy application.
-    let rpinfo = RoutePolicyInfo::new(origin, route_type, None, None, attrs);
     let msg = PolicyApplyMsg::Neighbor {
         policy_type: PolicyType::Import,
         nbr_addr: nbr.remote_addr,
         afi_safi: A::AFI_SAFI,
-        routes: nlri_prefixes
-            .into_iter()
-            .map(|prefix| (prefix.into(), rpinfo.clone()))
-            .collect(),
+        routes,
         policies: apply_policy_cfg
             .import_policy
             .iter()
@@ -397,21 +446,55 @@ fn process_nbr_route_refresh(
         return Ok(());
     }
 
-    match (afi, safi) {
-        (Afi::Ipv4, Safi::Unicast) => {
-            nbr.resend_adj_rib_out::<Ipv4Unicast>(instance);
-        }
-        (Afi::Ipv6, Safi::Unicast) => {
-            nbr.resend_adj_rib_out::<Ipv6Unicast>(instance);
-        }
+    let afi_safi = match (afi, safi) {
+        (Afi::Ipv4, Safi::Unicast) => AfiSafi::Ipv4Unicast,
+        (Afi::Ipv6, Safi::Unicast) => AfiSafi::Ipv6Unicast,
         _ => {
             // Ignore unsupported AFI/SAFI combination.
             return Ok(());
         }
+    };
+
+    // Update the neighbor's Address Prefix ORF.
+    if let Some(orf) = msg.orf {
+        let when_to_refresh = orf.when_to_refresh;
+        if nbr.orf_process(afi_safi, orf) {
+            // The received ORF is part of the outbound parameters of update
+            // groups.
+            instance.state.update_groups.update(nbr);
+
+            // When asked to defer, the re-advertisement of routes is delayed
+            // until a subsequent ROUTE-REFRESH message is received
+            // (RFC 5291 - Section 5).
+            if when_to_refresh == OrfWhenToRefresh::Defer {
+                return Ok(());
+            }
+        }
+    }
+
+    // Re-advertise the Adj-RIB-Out. When the neighbor has an ORF in place,
+    // routes filtered by it are withdrawn as well.
+    match afi_safi {
+        AfiSafi::Ipv4Unicast if nbr.orf_rcvd.contains_key(&afi_safi) => {
+            nbr.readvertise_adj_rib_out::<Ipv4Unicast>(instance, false);
+        }
+        AfiSafi::Ipv6Unicast if nbr.orf_rcvd.contains_key(&afi_safi) => {
+            nbr.readvertise_adj_rib_out::<Ipv6Unicast>(instance, false);
+        }
+        AfiSafi::Ipv4Unicast => {
+            nbr.resend_adj_rib_out::<Ipv4Unicast>(instance);
+        }
+        AfiSafi::Ipv6Unicast => {
+            nbr.resend_adj_rib_out::<Ipv6Unicast>(instance);
+        }
     }
 
     // Send UPDATE message(s) to the neighbor.
-    let msg_list = nbr.update_queues.build_updates();
+    let msg_list = nbr.update_queues.build_updates(
+        nbr.remote_addr,
+        &mut instance.state.rib,
+        nbr.max_msg_len(),
+    );
     if !msg_list.is_empty() {
         nbr.message_list_send(msg_list);
     }
@@ -432,12 +515,37 @@ pub(crate) fn process_nbr_timer(
         return Ok(());
     };
 
+    // Long-lived stale timers aren't part of the FSM.
+    if let fsm::Timer::LlgrStale(afi_safi) = timer {
+        nbr.llgr_stale_end(instance, afi_safi);
+        return Ok(());
+    }
+
     // Invoke FSM event.
     nbr.fsm_event(instance, fsm::Event::Timer(timer));
 
     Ok(())
 }
 
+// ===== ASPA records update =====
+
+pub(crate) fn process_aspa_db_update(
+    instance: &mut InstanceUpView<'_>,
+    neighbors: &mut Neighbors,
+    db: Option<AspaDb>,
+) {
+    instance.state.aspa = db;
+
+    // Re-run the import policies with the updated ASPA state.
+    for nbr in neighbors
+        .values()
+        .filter(|nbr| nbr.state == fsm::State::Established)
+    {
+        nbr.aspa_revalidate::<Ipv4Unicast>(instance);
+        nbr.aspa_revalidate::<Ipv6Unicast>(instance);
+    }
+}
+
 // ===== neighbor policy import result =====
 
 pub(crate) fn process_nbr_policy_import<A>(
@@ -467,12 +575,23 @@ where
 
         // Update post-policy Adj-RIB-In routes.
         match result {
-            PolicyResult::Accept(rpinfo) => {
-                let route = Route::new(
+            PolicyResult::Accept(mut rpinfo) => {
+                // Lower the preference of routes carrying the
+                // GRACEFUL_SHUTDOWN community (RFC 8326, Section 4).
+                if rpinfo.attrs.comm.as_ref().is_some_and(|comm| {
+                    comm.0.contains(&Comm(
+                        WellKnownCommunities::GracefulShutdown as u32,
+                    ))
+                }) {
+                    rpinfo.attrs.base.local_pref = Some(0);
+                }
+
+                let mut route = Route::new(
                     rpinfo.origin,
                     rib.attr_sets.get_route_attr_sets(&rpinfo.attrs),
                     rpinfo.route_type,
                 );
+                route.aspa_state = rpinfo.aspa_state;
 
                 // Update nexthop tracking.
                 if let Some(old_route) = adj_rib.in_post() {
@@ -562,6 +681,18 @@ where
                     adj_rib
                         .update_out_post(Box::new(route), &mut rib.attr_sets);
 
+                    // Skip routes suppressed by conditional advertisement or
+                    // filtered by the neighbor's ORF.
+                    if !nbr.cond_adv_permits(
+                        instance.shared,
+                        A::AFI_SAFI,
+                        prefix.into(),
+                        rpinfo.clone(),
+                    ) || !nbr.orf_permits(A::AFI_SAFI, &prefix.into())
+                    {
+                        continue;
+                    }
+
                     // Update route's attributes before transmission.
                     let mut attrs = rpinfo.attrs;
                     rib::attrs_tx_update::<A>(
@@ -569,6 +700,7 @@ where
                         nbr,
                         instance.config.asn,
                         rpinfo.origin.is_local(),
+                        &table.nht,
                     );
 
                     // Update neighbor's Tx queue.
@@ -587,11 +719,173 @@ where
     }
 
     // Send UPDATE message(s) to the neighbor.
-    let msg_list = nbr.update_queues.build_updates();
+    let msg_list = nbr.update_queues.build_updates(
+        nbr.remote_addr,
+        rib,
+        nbr.max_msg_len(),
+    );
     if !msg_list.is_empty() {
         nbr.message_list_send(msg_list);
     }
 
+    // Send the End-of-RIB marker if this completes the initial routing update.
+    if nbr.eor_pending.remove(&A::AFI_SAFI) {
+        nbr.eor_send(A::AFI_SAFI);
+    }
+
+    Ok(())
+}
+
+// ===== update group policy export result =====
+
+pub(crate) fn process_update_group_policy_export<A>(
+    instance: &mut InstanceUpView<'_>,
+    neighbors: &mut Neighbors,
+    group_id: UpdateGroupId,
+    prefixes: Vec<(IpNetwork, PolicyResult<RoutePolicyInfo>)>,
+) -> Result<(), Error>
+where
+    A: AddressFamily,
+{
+    // Lookup update group.
+    let Some(group) = instance.state.update_groups.groups.get_mut(&group_id)
+    else {
+        return Ok(());
+    };
+
+    // The export policy results for these prefixes are no longer in flight.
+    group.policy_completed(
+        A::AFI_SAFI,
+        prefixes.iter().map(|(prefix, _)| *prefix),
+    );
+
+    // Any established member can be used as a reference when updating route
+    // attributes before transmission, since all members share the same
+    // outbound parameters.
+    let Some(ref_addr) = group.members.iter().copied().find(|addr| {
+        neighbors
+            .get(addr)
+            .is_some_and(|nbr| nbr.state == fsm::State::Established)
+    }) else {
+        return Ok(());
+    };
+
+    let rib = &mut instance.state.rib;
+    let table = A::table(&mut rib.tables);
+    let mut excluded: BTreeMap<IpAddr, BTreeSet<A::IpNetwork>> =
+        Default::default();
+    for (prefix, result) in prefixes {
+        // Get RIB destination.
+        let prefix = A::IpNetwork::get(prefix).unwrap();
+        let dest = table.prefixes.entry(prefix).or_default();
+
+        // Update post-policy Adj-RIB-Out routes.
+        match result {
+            PolicyResult::Accept(rpinfo) => {
+                let route = Route::new(
+                    rpinfo.origin,
+                    rib.attr_sets.get_route_attr_sets(&rpinfo.attrs),
+                    rpinfo.route_type,
+                );
+
+                let mut update = false;
+                let mut unchanged = vec![];
+                for nbr_addr in &group.members {
+                    // Skip members to which this route isn't distributed
+                    // anymore.
+                    let Some(adj_rib) = dest
+                        .adj_rib
+                        .get_mut(nbr_addr)
+                        .filter(|adj_rib| adj_rib.out_pre().is_some())
+                    else {
+                        excluded.entry(*nbr_addr).or_default().insert(prefix);
+                        continue;
+                    };
+
+                    // Check if the Adj-RIB-Out was updated.
+                    if adj_rib.out_post().is_none_or(|adj_rib_route| {
+                        adj_rib_route.attrs != route.attrs
+                    }) {
+                        adj_rib.update_out_post(
+                            Box::new(route.clone()),
+                            &mut rib.attr_sets,
+                        );
+                        update = true;
+                    } else {
+                        unchanged.push(*nbr_addr);
+                    }
+                }
+
+                // Skip members whose Adj-RIB-Out was already up to date.
+                if update {
+                    for nbr_addr in unchanged {
+                        excluded.entry(nbr_addr).or_default().insert(prefix);
+                    }
+                }
+
+                // Skip routes suppressed by conditional advertisement or
+                // filtered by the neighbors' ORF.
+                if update
+                    && neighbors[&ref_addr].cond_adv_permits(
+                        instance.shared,
+                        A::AFI_SAFI,
+                        prefix.into(),
+                        rpinfo.clone(),
+                    )
+                    && neighbors[&ref_addr]
+                        .orf_permits(A::AFI_SAFI, &prefix.into())
+                {
+                    // Update route's attributes before transmission.
+                    let mut attrs = rpinfo.attrs;
+                    rib::attrs_tx_update::<A>(
+                        &mut attrs,
+                        &neighbors[&ref_addr],
+                        instance.config.asn,
+                        rpinfo.origin.is_local(),
+                        &table.nht,
+                    );
+
+                    // Update group's Tx queue.
+                    let update_queue =
+                        A::update_queue(&mut group.update_queues);
+                    update_queue.reach.entry(attrs).or_default().insert(prefix);
+                }
+            }
+            PolicyResult::Reject => {
+                let mut withdraw = false;
+                let mut unchanged = vec![];
+                for nbr_addr in &group.members {
+                    if let Some(adj_rib) = dest.adj_rib.get_mut(nbr_addr)
+                        && adj_rib.remove_out_post(&mut rib.attr_sets).is_some()
+                    {
+                        withdraw = true;
+                    } else {
+                        unchanged.push(*nbr_addr);
+                    }
+                }
+
+                if withdraw {
+                    // Skip members that weren't advertised the route.
+                    for nbr_addr in unchanged {
+                        excluded.entry(nbr_addr).or_default().insert(prefix);
+                    }
+
+                    // Update group's Tx queue.
+                    let update_queue =
+                        A::update_queue(&mut group.update_queues);
+                    update_queue.unreach.insert(prefix);
+                }
+            }
+        }
+    }
+
+    // Send UPDATE message(s) to the group members.
+    let slow_peers =
+        group.flush::<A>(neighbors, table, &mut rib.attr_sets, &excluded);
+
+    // Split off members that can't keep up.
+    update_group::split_slow_peers(instance, neighbors, slow_peers);
+
     Ok(())
 }
 
@@ -704,6 +998,11 @@ where
     }
 
     // Phase 3: Route Dissemination.
+    //
+    // Merge split-off neighbors that have caught up back into their update
+    // groups first.
+    let update_groups = &mut instance.state.update_groups;
+    update_groups.merge_recovered(neighbors);
     for nbr in neighbors
         .values_mut()
         .filter(|nbr| nbr.state == fsm::State::Established)
@@ -713,6 +1012,15 @@ where
             continue;
         }
 
+        // Skip members of shared update groups (handled below).
+        if nbr
+            .update_group
+            .and_then(|group_id| update_groups.groups.get(&group_id))
+            .is_some_and(|group| group.is_shared())
+        {
+            continue;
+        }
+
         // Evaluate routes eligible for distribution to this neighbor.
         //
         // Any routes that fail to meet the distribution criteria are marked
@@ -747,9 +1055,31 @@ where
             );
         }
     }
+    let mut slow_peers = vec![];
+    for group in update_groups
+        .groups
+        .values_mut()
+        .filter(|group| group.is_shared())
+    {
+        // Skip update groups that haven't this address-family enabled.
+        if group.export_policy(A::AFI_SAFI).is_none() {
+            continue;
+        }
+
+        slow_peers.extend(update_group_routes::<A>(
+            group,
+            neighbors,
+            table,
+            &reach,
+            &unreach,
+            instance.shared,
+            &mut instance.state.rib.attr_sets,
+            &instance.state.policy_apply_tasks,
+        ));
+    }
 
     // Remove routing table entries that no longer hold any data.
-    for prefix in queued_prefixes {
+    for prefix in queued_prefixes.iter().copied() {
         if let prefix_trie::map::Entry::Occupied(entry) =
             table.prefixes.entry(prefix)
         {
@@ -767,6 +1097,17 @@ where
         }
     }
 
+    // Re-evaluate conditional advertisements watching the updated prefixes.
+    for nbr in neighbors
+        .values_mut()
+        .filter(|nbr| nbr.cond_adv.contains_key(&A::AFI_SAFI))
+    {
+        nbr.cond_adv_update::<A>(instance, &queued_prefixes);
+    }
+
+    // Split off neighbors that can't keep up with their update groups.
+    update_group::split_slow_peers(instance, neighbors, slow_peers);
+
     Ok(())
 }
 
@@ -793,7 +1134,13 @@ fn withdraw_routes<A>(
     }
 
     // Send UPDATE message(s) to the neighbor.
-    let msg_list = nbr.update_queues.build_updates();
+    let max_len = nbr.max_msg_len();
+    let msg_list = A::update_queue(&mut nbr.update_queues).build_updates(
+        [nbr.remote_addr],
+        table,
+        attr_sets,
+        max_len,
+    );
     if !msg_list.is_empty() {
         nbr.message_list_send(msg_list);
     }
@@ -817,12 +1164,7 @@ pub(crate) fn advertise_routes<A>(
     }
 
     // Get policy configuration for the address family.
-    let apply_policy_cfg = &nbr
-        .config
-        .afi_safi
-        .get(&A::AFI_SAFI)
-        .map(|afi_safi| &afi_safi.apply_policy)
-        .unwrap_or(&nbr.config.apply_policy);
+    let apply_policy_cfg = nbr.apply_policy_cfg(A::AFI_SAFI);
 
     // Enqueue export policy application.
     let routes = routes
@@ -846,3 +1188,99 @@ pub(crate) fn advertise_routes<A>(
         policy_apply_tasks.enqueue(msg);
     }
 }
+
+// Disseminates routes to the members of a shared update group.
+//
+// Distribution filters are evaluated for each member individually, while
+// export policies are evaluated only once on behalf of the whole group.
+//
+// Returns the list of members that are lagging behind.
+#[expect(clippy::too_many_arguments)]
+fn update_group_routes<A>(
+    group: &mut UpdateGroup,
+    neighbors: &mut Neighbors,
+    table: &mut RoutingTable<A>,
+    reach: &[(A::IpNetwork, Box<Route>)],
+    unreach: &[A::IpNetwork],
+    shared: &InstanceShared,
+    attr_sets: &mut AttrSetsCxt,
+    policy_apply_tasks: &PolicyApplyTasks,
+) -> Vec<IpAddr>
+where
+    A: AddressFamily,
+{
+    let mut group_reach = BTreeMap::new();
+    for nbr_addr in &group.members {
+        let Some(nbr) = neighbors.get_mut(nbr_addr) else {
+            continue;
+        };
+        if nbr.state != fsm::State::Established {
+            continue;
+        }
+
+        // Update pre-policy Adj-RIB-Out routes, withdrawing routes that fail
+        // to meet the distribution criteria for this member.
+        let mut nbr_unreach = vec![];
+        for (prefix, route) in reach {
+            if !nbr.distribute_filter(route) {
+                nbr_unreach.push(*prefix);
+                continue;
+            }
+
+            let dest = table.prefixes.get_mut(prefix).unwrap();
+            let adj_rib = dest.adj_rib.entry(nbr.remote_addr).or_default();
+            adj_rib.update_out_pre(route.clone(), attr_sets);
+            group_reach.entry(*prefix).or_insert_with(|| route.clone());
+        }
+        if !nbr_unreach.is_empty() {
+            withdraw_routes::<A>(nbr, table, &nbr_unreach, attr_sets);
+        }
+
+        // Update Adj-RIB-Out for unfeasible routes.
+        for prefix in unreach {
+            let dest = table.prefixes.get_mut(prefix).unwrap();
+            let Some(adj_rib) = dest.adj_rib.get_mut(&nbr.remote_addr) else {
+                continue;
+            };
+
+            adj_rib.remove_out_pre(attr_sets);
+            if adj_rib.remove_out_post(attr_sets).is_some() {
+                let update_queue = A::update_queue(&mut group.update_queues);
+                update_queue.unreach.insert(*prefix);
+            }
+        }
+    }
+
+    // Withdraw unfeasible routes immediately.
+    let slow_peers =
+        group.flush::<A>(neighbors, table, attr_sets, &Default::default());
+
+    // Enqueue export policy application.
+    let routes = group_reach
+        .into_iter()
+        .map(|(prefix, route)| (prefix.into(), route.policy_info()))
+        .collect::<Vec<_>>();
+    if !routes.is_empty() {
+        group.policy_dispatched(
+            A::AFI_SAFI,
+            routes.iter().map(|(prefix, _)| *prefix),
+        );
+        let policy = group.export_policy(A::AFI_SAFI).unwrap();
+        let msg = PolicyApplyMsg::UpdateGroup {
+            group_id: group.id,
+            afi_safi: A::AFI_SAFI,
+            routes,
+            policies: policy
+                .policies
+                .iter()
+                .map(|policy| shared.policies.get(policy).unwrap().clone())
+                .collect(),
+            match_sets: shared.policy_match_sets.clone(),
+            default_policy: policy.default_policy,
+        };
+        policy_apply_tasks.enqueue(msg);
+        group.statistics.policy_runs += 1;
+    }
+
+    slow_peers
+}
@@ -15,6 +15,7 @@ use ipnetwork::IpNetwork;
 use crate::af::{AddressFamily, Ipv4Unicast, Ipv6Unicast};
 use crate::debug::Debug;
 use crate::instance::{Instance, InstanceUpView};
+use crate::packet::attribute::Attrs;
 use crate::policy::RoutePolicyInfo;
 use crate::rib::RouteOrigin;
 use crate::tasks::messages::output::PolicyApplyMsg;
@@ -108,14 +109,19 @@ where
 {
     // Check if redistribution is enabled for this address family and route
     // protocol.
-    if instance
+    let Some(redistribution_cfg) = instance
         .config
         .afi_safi
         .get(&A::AFI_SAFI)
         .and_then(|afi_safi| afi_safi.redistribution.get(&msg.protocol))
-        .is_none()
-    {
+    else {
         return;
+    };
+
+    // Originate the AIGP attribute using the RIB metric, if configured.
+    let mut attrs = Attrs::default();
+    if redistribution_cfg.aigp {
+        attrs.base.aigp = Some(msg.metric.into());
     }
 
     // Get policy configuration for the address family.
@@ -135,7 +141,7 @@ where
             RouteType::Internal,
             msg.tag,
             Some(msg.opaque_attrs),
-            Default::default(),
+            attrs,
         ),
         policies: apply_policy_cfg
             .import_policy
@@ -21,19 +21,23 @@ use tokio::sync::mpsc;
 use tokio::sync::mpsc::{Receiver, Sender, UnboundedReceiver, UnboundedSender};
 
 use crate::af::{Ipv4Unicast, Ipv6Unicast};
+use crate::aspa::AspaDb;
 use crate::debug::{Debug, InstanceInactiveReason};
 use crate::error::{Error, IoError};
+use crate::mrt::MrtState;
 use crate::neighbor::{Neighbors, fsm};
 use crate::northbound::configuration::InstanceCfg;
 use crate::packet::iana::{CeaseSubcode, ErrorCode};
 use crate::packet::message::NotificationMsg;
 use crate::rib::Rib;
 use crate::tasks::messages::input::{
-    NbrRxMsg, NbrTimerMsg, PolicyResultMsg, TcpAcceptMsg, TcpConnectMsg,
+    AspaDbUpdateMsg, NbrRxMsg, NbrTimerMsg, PolicyResultMsg, TcpAcceptMsg,
+    TcpConnectMsg,
 };
 use crate::tasks::messages::output::PolicyApplyMsg;
 use crate::tasks::messages::{ProtocolInputMsg, ProtocolOutputMsg};
-use crate::{events, ibus, network, tasks};
+use crate::update_group::UpdateGroups;
+use crate::{aspa, events, ibus, mrt, network, tasks};
 
 #[derive(Debug)]
 pub struct Instance {
@@ -71,6 +75,16 @@ pub struct InstanceState {
     pub decision_process_task: Option<TimeoutTask>,
     // BGP RIB.
     pub rib: Rib,
+    // Dynamic update groups.
+    pub update_groups: UpdateGroups,
+    // Graceful shutdown enabled through RPC.
+    pub graceful_shutdown: bool,
+    // ASPA object store.
+    pub aspa: Option<AspaDb>,
+    // Task loading the ASPA records.
+    pub aspa_task: Option<Task<()>>,
+    // MRT dump state.
+    pub mrt: MrtState,
 }
 
 #[derive(Debug)]
@@ -100,6 +114,10 @@ pub struct ProtocolInputChannelsTx {
     pub policy_result: UnboundedSender<PolicyResultMsg>,
     // Decision Process triggering message.
     pub decision_process: Sender<()>,
+    // MRT table dump triggering message.
+    pub mrt_table_dump: Sender<()>,
+    // ASPA records update.
+    pub aspa_db_update: Sender<AspaDbUpdateMsg>,
 }
 
 #[derive(Debug)]
@@ -116,6 +134,10 @@ pub struct ProtocolInputChannelsRx {
     pub policy_result: UnboundedReceiver<PolicyResultMsg>,
     // Decision Process triggering message.
     pub decision_process: Receiver<()>,
+    // MRT table dump triggering message.
+    pub mrt_table_dump: Receiver<()>,
+    // ASPA records update.
+    pub aspa_db_update: Receiver<AspaDbUpdateMsg>,
 }
 
 pub struct InstanceUpView<'a> {
@@ -153,7 +175,16 @@ impl Instance {
         Debug::InstanceStart.log();
 
         match InstanceState::new(router_id, &self.tx) {
-            Ok(state) => {
+            Ok(mut state) => {
+                // Start loading ASPA records.
+                state.aspa_task = aspa::source_task(
+                    &self.config.aspa,
+                    &self.tx.protocol_input.aspa_db_update,
+                );
+
+                // Start MRT dumps.
+                state.mrt.update(&self.config.mrt, &self.tx);
+
                 // Store instance initial state.
                 self.state = Some(state);
             }
@@ -288,6 +319,8 @@ impl ProtocolInstance for Instance {
         let (nbr_timerp, nbr_timerc) = mpsc::channel(4);
         let (policy_resultp, policy_resultc) = mpsc::unbounded_channel();
         let (decision_processp, decision_processc) = mpsc::channel(1);
+        let (mrt_table_dumpp, mrt_table_dumpc) = mpsc::channel(1);
+        let (aspa_db_updatep, aspa_db_updatec) = mpsc::channel(4);
 
         let tx = ProtocolInputChannelsTx {
             tcp_accept: tcp_acceptp,
@@ -296,6 +329,8 @@ impl ProtocolInstance for Instance {
             nbr_timer: nbr_timerp,
             policy_result: policy_resultp,
             decision_process: decision_processp,
+            mrt_table_dump: mrt_table_dumpp,
+            aspa_db_update: aspa_db_updatep,
         };
         let rx = ProtocolInputChannelsRx {
             tcp_accept: tcp_acceptc,
@@ -304,6 +339,8 @@ impl ProtocolInstance for Instance {
             nbr_timer: nbr_timerc,
             policy_result: policy_resultc,
             decision_process: decision_processc,
+            mrt_table_dump: mrt_table_dumpc,
+            aspa_db_update: aspa_db_updatec,
         };
 
         (tx, rx)
@@ -375,6 +412,11 @@ impl InstanceState {
             policy_apply_tasks,
             decision_process_task: None,
             rib: Default::default(),
+            update_groups: Default::default(),
+            graceful_shutdown: false,
+            aspa: None,
+            aspa_task: None,
+            mrt: Default::default(),
         })
     }
 
@@ -424,6 +466,12 @@ impl MessageReceiver<ProtocolInputMsg> for ProtocolInputChannelsRx {
             msg = self.decision_process.recv() => {
                 msg.map(ProtocolInputMsg::TriggerDecisionProcess)
             }
+            msg = self.mrt_table_dump.recv() => {
+                msg.map(ProtocolInputMsg::MrtTableDump)
+            }
+            msg = self.aspa_db_update.recv() => {
+                msg.map(ProtocolInputMsg::AspaDbUpdate)
+            }
         }
     }
 }
@@ -458,6 +506,19 @@ fn process_ibus_msg(
         IbusMsg::PolicyMatchSetsUpd(match_sets) => {
             // Update the local copy of the policy match sets.
             instance.shared.policy_match_sets = match_sets;
+
+            if let Some((mut instance, neighbors)) = instance.as_up() {
+                for nbr in neighbors.values_mut() {
+                    // Send updated ORF entries, if necessary.
+                    if nbr.state == fsm::State::Established {
+                        nbr.orf_send(instance.shared, AfiSafi::Ipv4Unicast);
+                        nbr.orf_send(instance.shared, AfiSafi::Ipv6Unicast);
+                    }
+
+                    // Re-evaluate conditional advertisements.
+                    nbr.cond_adv_policy_update(&mut instance, None);
+                }
+            }
         }
         IbusMsg::PolicyUpd(policy) => {
             // Update the local copy of the policy definition.
@@ -465,10 +526,44 @@ fn process_ibus_msg(
                 .shared
                 .policies
                 .insert(policy.name.clone(), policy.clone());
+
+            if let Some((mut instance, neighbors)) = instance.as_up() {
+                for nbr in neighbors.values_mut() {
+                    // Send updated ORF entries, if necessary.
+                    if nbr.state == fsm::State::Established {
+                        nbr.orf_send(instance.shared, AfiSafi::Ipv4Unicast);
+                        nbr.orf_send(instance.shared, AfiSafi::Ipv6Unicast);
+                    }
+
+                    // Re-evaluate conditional advertisements using this
+                    // policy.
+                    nbr.cond_adv_policy_update(
+                        &mut instance,
+                        Some(&policy.name),
+                    );
+                }
+            }
         }
         IbusMsg::PolicyDel(policy_name) => {
             // Remove the local copy of the policy definition.
             instance.shared.policies.remove(&policy_name);
+
+            if let Some((mut instance, neighbors)) = instance.as_up() {
+                for nbr in neighbors.values_mut() {
+                    // Send updated ORF entries, if necessary.
+                    if nbr.state == fsm::State::Established {
+                        nbr.orf_send(instance.shared, AfiSafi::Ipv4Unicast);
+                        nbr.orf_send(instance.shared, AfiSafi::Ipv6Unicast);
+                    }
+
+                    // Re-evaluate conditional advertisements using this
+                    // policy.
+                    nbr.cond_adv_policy_update(
+                        &mut instance,
+                        Some(&policy_name),
+                    );
+                }
+            }
         }
         IbusMsg::RouteRedistributeAdd(msg) => {
             // Route redistribute update notification.
@@ -516,6 +611,7 @@ fn process_protocol_msg(
                 neighbors,
                 msg.nbr_addr,
                 msg.msg,
+                msg.raw,
             )?;
         }
         // Neighbor's timeout has expired.
@@ -572,12 +668,36 @@ fn process_protocol_msg(
                     )?
                 }
             },
+            PolicyResultMsg::UpdateGroup {
+                group_id,
+                afi_safi,
+                routes,
+            } => match afi_safi {
+                AfiSafi::Ipv4Unicast => {
+                    events::process_update_group_policy_export::<Ipv4Unicast>(
+                        instance, neighbors, group_id, routes,
+                    )?
+                }
+                AfiSafi::Ipv6Unicast => {
+                    events::process_update_group_policy_export::<Ipv6Unicast>(
+                        instance, neighbors, group_id, routes,
+                    )?
+                }
+            },
         },
         // Decision process.
         ProtocolInputMsg::TriggerDecisionProcess(_) => {
             events::decision_process::<Ipv4Unicast>(instance, neighbors)?;
             events::decision_process::<Ipv6Unicast>(instance, neighbors)?;
         }
+        // MRT table dump.
+        ProtocolInputMsg::MrtTableDump(_) => {
+            mrt::table_dump(instance, neighbors);
+        }
+        // ASPA records update.
+        ProtocolInputMsg::AspaDbUpdate(msg) => {
+            events::process_aspa_db_update(instance, neighbors, msg.db);
+        }
     }
 
     Ok(())
@@ -10,15 +10,19 @@
 )]
 
 pub mod af;
+pub mod aspa;
 pub mod debug;
 pub mod error;
 pub mod events;
 pub mod ibus;
 pub mod instance;
+pub mod mrt;
 pub mod neighbor;
 pub mod network;
 pub mod northbound;
 pub mod packet;
 pub mod policy;
 pub mod rib;
+pub mod rtr;
 pub mod tasks;
+pub mod update_group;
@@ -0,0 +1,821 @@
+//
+// Copyright (c) The Holo Core Contributors
+//
+// SPDX-License-Identifier: MIT
+//
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{BufWriter, ErrorKind, Write};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use bytes::{BufMut, Bytes, BytesMut};
+use chrono::Utc;
+use holo_protocol::InstanceChannelsTx;
+use holo_utils::bytes::BytesMutExt;
+use holo_utils::ip::Ipv6AddrExt;
+use holo_utils::task::{IntervalTask, Task};
+use ipnetwork::IpNetwork;
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+
+use crate::af::{AddressFamily, Ipv4Unicast, Ipv6Unicast};
+use crate::error::IoError;
+use crate::instance::{Instance, InstanceUpView};
+use crate::neighbor::{Neighbor, Neighbors, fsm};
+use crate::northbound::configuration::MrtCfg;
+use crate::packet::attribute::{AttrFlags, Attrs};
+use crate::packet::iana::{Afi, AttrType};
+use crate::packet::message::{
+    EncodeCxt, Message, NegotiatedCapability, ReachNlri, encode_ipv4_prefix,
+    encode_ipv6_prefix,
+};
+use crate::rib::{RouteOrigin, RoutingTable};
+use crate::tasks;
+
+// MRT record types.
+//
+// IANA registry:
+// https://www.iana.org/assignments/mrt/mrt.xhtml#type-codes
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MrtType {
+    // RFC 6396
+    TableDumpV2 = 13,
+    Bgp4mp = 16,
+}
+
+// TABLE_DUMP_V2 subtypes.
+//
+// IANA registry:
+// https://www.iana.org/assignments/mrt/mrt.xhtml#table-dump-v2-subtype-codes
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TableDumpV2Subtype {
+    // RFC 6396
+    PeerIndexTable = 1,
+    RibIpv4Unicast = 2,
+    RibIpv6Unicast = 4,
+}
+
+// BGP4MP subtypes.
+//
+// IANA registry:
+// https://www.iana.org/assignments/mrt/mrt.xhtml#BGP4MP-codes
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Bgp4mpSubtype {
+    // RFC 6396
+    MessageAs4 = 4,
+    StateChangeAs4 = 5,
+}
+
+// RIB dumped in a TABLE_DUMP_V2 snapshot.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TableDumpRib {
+    // Best routes selected by the decision process, attributed to the
+    // neighbors they were learned from.
+    LocRib,
+    // Routes received from the neighbors, before the import policies are
+    // applied.
+    AdjRibIn,
+}
+
+// MRT runtime state.
+#[derive(Debug, Default)]
+pub struct MrtState {
+    // Periodic table dump task.
+    pub table_dump_task: Option<IntervalTask>,
+    // Task writing the MRT files.
+    pub writer: Option<MrtWriter>,
+}
+
+// MRT writer task.
+//
+// All file I/O is performed by a dedicated blocking task so that the main
+// event loop never waits on the filesystem.
+#[derive(Debug)]
+pub struct MrtWriter {
+    pub tx: UnboundedSender<MrtWriterMsg>,
+    pub task: Task<()>,
+}
+
+// Messages sent to the MRT writer task.
+#[derive(Debug)]
+pub enum MrtWriterMsg {
+    // Records to append to the update log.
+    UpdateLog(Bytes),
+    // TABLE_DUMP_V2 snapshots of the Loc-RIB and Adj-RIBs-In to write to new
+    // files.
+    TableDump { loc_rib: Bytes, adj_rib_in: Bytes },
+}
+
+#[derive(Debug)]
+pub struct UpdateLog {
+    pub file: BufWriter<File>,
+    pub opened: Instant,
+}
+
+// Peer entry of the PEER_INDEX_TABLE record.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MrtPeer {
+    pub bgp_id: Ipv4Addr,
+    pub addr: IpAddr,
+    pub asn: u32,
+}
+
+// Common fields of the BGP4MP records.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Bgp4mpHeader {
+    pub peer_as: u32,
+    pub local_as: u32,
+    pub peer_addr: IpAddr,
+    pub local_addr: IpAddr,
+}
+
+// ===== impl MrtState =====
+
+impl MrtState {
+    // Updates the MRT state after a configuration change.
+    pub(crate) fn update(
+        &mut self,
+        config: &MrtCfg,
+        instance_tx: &InstanceChannelsTx<Instance>,
+    ) {
+        // (Re)start the periodic table dump task.
+        self.table_dump_task =
+            match (&config.directory, config.table_dump_interval) {
+                (Some(_), Some(interval)) => {
+                    Some(tasks::mrt_table_dump_interval(
+                        interval,
+                        &instance_tx.protocol_input.mrt_table_dump,
+                    ))
+                }
+                _ => None,
+            };
+
+        // Restart the writer task. The old task is detached so it can finish
+        // writing the records already sent to it, exiting once its channel is
+        // closed.
+        if let Some(mut writer) = self.writer.take() {
+            writer.task.detach();
+        }
+        if let Some(directory) = &config.directory {
+            let (tx, rx) = mpsc::unbounded_channel();
+            let rotation_interval =
+                Duration::from_secs(config.update_log_rotation_interval.into());
+            let task =
+                tasks::mrt_writer(directory.clone(), rotation_interval, rx);
+            self.writer = Some(MrtWriter { tx, task });
+        }
+    }
+
+    // Sends the given message to the writer task.
+    fn write(&self, msg: MrtWriterMsg) {
+        if let Some(writer) = &self.writer {
+            let _ = writer.tx.send(msg);
+        }
+    }
+}
+
+// ===== global functions =====
+
+// Writes TABLE_DUMP_V2 snapshots of the Loc-RIB and Adj-RIBs-In, each one to
+// a separate file.
+pub(crate) fn table_dump(instance: &InstanceUpView<'_>, neighbors: &Neighbors) {
+    if instance.config.mrt.directory.is_none() {
+        return;
+    }
+
+    let timestamp = timestamp();
+    let loc_rib =
+        encode_table_dump(instance, neighbors, timestamp, TableDumpRib::LocRib);
+    let adj_rib_in = encode_table_dump(
+        instance,
+        neighbors,
+        timestamp,
+        TableDumpRib::AdjRibIn,
+    );
+    instance.state.mrt.write(MrtWriterMsg::TableDump {
+        loc_rib: loc_rib.freeze(),
+        adj_rib_in: adj_rib_in.freeze(),
+    });
+}
+
+// Logs a received UPDATE message in the BGP4MP format.
+//
+// The message is logged exactly as received from the network.
+pub(crate) fn log_message(
+    instance: &mut InstanceUpView<'_>,
+    nbr: &Neighbor,
+    msg: &Message,
+    raw: &[u8],
+) {
+    if !matches!(msg, Message::Update(_)) {
+        return;
+    }
+    let config = &instance.config.mrt;
+    if config.directory.is_none() || !config.update_log_enabled {
+        return;
+    }
+
+    let header = bgp4mp_header(instance, nbr);
+    let mut buf = BytesMut::new();
+    encode_bgp4mp_message(&mut buf, timestamp(), &header, raw);
+    instance
+        .state
+        .mrt
+        .write(MrtWriterMsg::UpdateLog(buf.freeze()));
+}
+
+// Logs a neighbor state change in the BGP4MP format.
+pub(crate) fn log_state_change(
+    instance: &mut InstanceUpView<'_>,
+    nbr: &Neighbor,
+    old_state: fsm::State,
+    new_state: fsm::State,
+) {
+    let config = &instance.config.mrt;
+    if config.directory.is_none() || !config.update_log_enabled {
+        return;
+    }
+
+    let header = bgp4mp_header(instance, nbr);
+    let mut buf = BytesMut::new();
+    encode_bgp4mp_state_change(
+        &mut buf,
+        timestamp(),
+        &header,
+        old_state,
+        new_state,
+    );
+    instance
+        .state
+        .mrt
+        .write(MrtWriterMsg::UpdateLog(buf.freeze()));
+}
+
+// Writes the records received from the instance to the MRT files until the
+// channel is closed.
+pub(crate) fn writer_loop(
+    directory: String,
+    rotation_interval: Duration,
+    mut rx: UnboundedReceiver<MrtWriterMsg>,
+) {
+    let mut update_log = None;
+    while let Some(msg) = rx.blocking_recv() {
+        let result = match msg {
+            MrtWriterMsg::UpdateLog(buf) => update_log_write(
+                &mut update_log,
+                &directory,
+                rotation_interval,
+                &buf,
+            ),
+            MrtWriterMsg::TableDump {
+                loc_rib,
+                adj_rib_in,
+            } => file_create(&directory, "rib")
+                .and_then(|mut file| file.write_all(&loc_rib))
+                .and_then(|_| file_create(&directory, "adj-rib-in"))
+                .and_then(|mut file| file.write_all(&adj_rib_in)),
+        };
+        if let Err(error) = result {
+            IoError::MrtWriteError(error).log();
+        }
+
+        // Flush the update log once there's nothing else pending.
+        if rx.is_empty()
+            && let Some(update_log) = &mut update_log
+            && let Err(error) = update_log.file.flush()
+        {
+            IoError::MrtWriteError(error).log();
+        }
+    }
+}
+
+// Encodes a full TABLE_DUMP_V2 snapshot of the given RIB.
+//
+// The first entry of the PEER_INDEX_TABLE represents the local router and is
+// used for locally originated routes, while the remaining entries represent
+// the neighbors the other routes were learned from.
+pub fn encode_table_dump(
+    instance: &InstanceUpView<'_>,
+    neighbors: &Neighbors,
+    timestamp: u32,
+    rib: TableDumpRib,
+) -> BytesMut {
+    let mut buf = BytesMut::new();
+
+    // Build the peer index table.
+    let mut peers = vec![MrtPeer {
+        bgp_id: instance.state.router_id,
+        addr: IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+        asn: instance.config.asn,
+    }];
+    let mut peer_idx = BTreeMap::new();
+    for nbr in neighbors.values() {
+        peer_idx.insert(nbr.remote_addr, peers.len() as u16);
+        peers.push(MrtPeer {
+            bgp_id: nbr.identifier.unwrap_or(Ipv4Addr::UNSPECIFIED),
+            addr: nbr.remote_addr,
+            asn: nbr.config.peer_as,
+        });
+    }
+    encode_peer_index_table(
+        &mut buf,
+        timestamp,
+        instance.state.router_id,
+        instance.name,
+        &peers,
+    );
+
+    // Dump the RIB entries of each address family.
+    let mut seqno = 0;
+    let tables = &instance.state.rib.tables;
+    encode_rib_entries::<Ipv4Unicast>(
+        &mut buf,
+        timestamp,
+        &mut seqno,
+        &tables.ipv4_unicast,
+        &peer_idx,
+        rib,
+    );
+    encode_rib_entries::<Ipv6Unicast>(
+        &mut buf,
+        timestamp,
+        &mut seqno,
+        &tables.ipv6_unicast,
+        &peer_idx,
+        rib,
+    );
+
+    buf
+}
+
+// Encodes a PEER_INDEX_TABLE record.
+pub fn encode_peer_index_table(
+    buf: &mut BytesMut,
+    timestamp: u32,
+    collector_id: Ipv4Addr,
+    view_name: &str,
+    peers: &[MrtPeer],
+) {
+    encode_record(
+        buf,
+        timestamp,
+        MrtType::TableDumpV2,
+        TableDumpV2Subtype::PeerIndexTable as u16,
+        |buf| {
+            buf.put_ipv4(&collector_id);
+            buf.put_u16(view_name.len() as u16);
+            buf.put_slice(view_name.as_bytes());
+            buf.put_u16(peers.len() as u16);
+            for peer in peers {
+                // Peer addresses are always encoded along with 4-octet ASNs.
+                let mut peer_type = 0x02;
+                if peer.addr.is_ipv6() {
+                    peer_type |= 0x01;
+                }
+                buf.put_u8(peer_type);
+                buf.put_ipv4(&peer.bgp_id);
+                buf.put_ip(&peer.addr);
+                buf.put_u32(peer.asn);
+            }
+        },
+    );
+}
+
+// Encodes a BGP4MP_MESSAGE_AS4 record containing the given raw BGP message.
+pub fn encode_bgp4mp_message(
+    buf: &mut BytesMut,
+    timestamp: u32,
+    header: &Bgp4mpHeader,
+    msg: &[u8],
+) {
+    encode_record(
+        buf,
+        timestamp,
+        MrtType::Bgp4mp,
+        Bgp4mpSubtype::MessageAs4 as u16,
+        |buf| {
+            header.encode(buf);
+            buf.put_slice(msg);
+        },
+    );
+}
+
+// Encodes a BGP4MP_STATE_CHANGE_AS4 record.
+pub fn encode_bgp4mp_state_change(
+    buf: &mut BytesMut,
+    timestamp: u32,
+    header: &Bgp4mpHeader,
+    old_state: fsm::State,
+    new_state: fsm::State,
+) {
+    encode_record(
+        buf,
+        timestamp,
+        MrtType::Bgp4mp,
+        Bgp4mpSubtype::StateChangeAs4 as u16,
+        |buf| {
+            header.encode(buf);
+            buf.put_u16(state_code(old_state));
+            buf.put_u16(state_code(new_state));
+        },
+    );
+}
+
+// ===== impl Bgp4mpHeader =====
+
+impl Bgp4mpHeader {
+    fn encode(&self, buf: &mut BytesMut) {
+        let afi = match self.peer_addr {
+            IpAddr::V4(_) => Afi::Ipv4,
+            IpAddr::V6(_) => Afi::Ipv6,
+        };
+
+        buf.put_u32(self.peer_as);
+        buf.put_u32(self.local_as);
+        // Interface index (unused).
+        buf.put_u16(0);
+        buf.put_u16(afi as u16);
+        buf.put_ip(&self.peer_addr);
+        buf.put_ip(&self.local_addr);
+    }
+}
+
+// ===== helper functions =====
+
+fn encode_record(
+    buf: &mut BytesMut,
+    timestamp: u32,
+    mrt_type: MrtType,
+    subtype: u16,
+    encode_body: impl FnOnce(&mut BytesMut),
+) {
+    buf.put_u32(timestamp);
+    buf.put_u16(mrt_type as u16);
+    buf.put_u16(subtype);
+
+    // The length field will be initialized later.
+    let len_pos = buf.len();
+    buf.put_u32(0);
+
+    // Message body.
+    encode_body(buf);
+
+    // Rewrite message length.
+    let len = (buf.len() - len_pos - 4) as u32;
+    buf[len_pos..len_pos + 4].copy_from_slice(&len.to_be_bytes());
+}
+
+fn encode_rib_entries<A>(
+    buf: &mut BytesMut,
+    timestamp: u32,
+    seqno: &mut u32,
+    table: &RoutingTable<A>,
+    peer_idx: &BTreeMap<IpAddr, u16>,
+    rib: TableDumpRib,
+) where
+    A: AddressFamily,
+{
+    let subtype = match A::AFI {
+        Afi::Ipv4 => TableDumpV2Subtype::RibIpv4Unicast,
+        Afi::Ipv6 => TableDumpV2Subtype::RibIpv6Unicast,
+    };
+
+    for (prefix, dest) in &table.prefixes {
+        // Collect the routes of the requested RIB.
+        let mut entries = vec![];
+        match rib {
+            TableDumpRib::LocRib => {
+                if let Some(route) = &dest.local {
+                    let idx = match route.origin {
+                        RouteOrigin::Neighbor { remote_addr, .. } => {
+                            peer_idx.get(&remote_addr).copied()
+                        }
+                        RouteOrigin::Protocol(_) => Some(0),
+                    };
+                    if let Some(idx) = idx {
+                        entries.push((
+                            idx,
+                            route.last_modified,
+                            route.attrs.get(),
+                        ));
+                    }
+                }
+            }
+            TableDumpRib::AdjRibIn => {
+                for (nbr_addr, adj_rib) in &dest.adj_rib {
+                    let (Some(idx), Some(route)) =
+                        (peer_idx.get(nbr_addr), adj_rib.in_pre())
+                    else {
+                        continue;
+                    };
+                    entries.push((
+                        *idx,
+                        route.last_modified,
+                        route.attrs.get(),
+                    ));
+                }
+            }
+        }
+        if entries.is_empty() {
+            continue;
+        }
+
+        encode_record(
+            buf,
+            timestamp,
+            MrtType::TableDumpV2,
+            subtype as u16,
+            |buf| {
+                buf.put_u32(*seqno);
+                match prefix.into() {
+                    IpNetwork::V4(prefix) => encode_ipv4_prefix(buf, &prefix),
+                    IpNetwork::V6(prefix) => encode_ipv6_prefix(buf, &prefix),
+                }
+                buf.put_u16(entries.len() as u16);
+                for (idx, last_modified, attrs) in entries {
+                    let originated = timestamp.saturating_sub(
+                        last_modified.elapsed().as_secs() as u32,
+                    );
+                    buf.put_u16(idx);
+                    buf.put_u32(originated);
+                    encode_rib_entry_attrs(buf, &attrs);
+                }
+            },
+        );
+        *seqno = seqno.wrapping_add(1);
+    }
+}
+
+// Encodes the path attributes of a RIB entry.
+//
+// As per RFC 6396, AS_PATH attributes are always encoded using 4-octet ASNs,
+// and the MP_REACH_NLRI attribute only contains the next hop address(es).
+fn encode_rib_entry_attrs(buf: &mut BytesMut, attrs: &Attrs) {
+    let cxt = EncodeCxt {
+        capabilities: [NegotiatedCapability::FourOctetAsNumber].into(),
+    };
+
+    let len_pos = buf.len();
+    buf.put_u16(0);
+
+    let mut reach = None;
+    match attrs.base.nexthop {
+        Some(IpAddr::V4(nexthop)) => {
+            reach = Some(ReachNlri {
+                prefixes: vec![],
+                nexthop,
+            });
+        }
+        Some(IpAddr::V6(nexthop)) => {
+            let ll_nexthop = attrs.base.ll_nexthop;
+            let nexthop_len = if ll_nexthop.is_some() {
+                Ipv6Addr::LENGTH * 2
+            } else {
+                Ipv6Addr::LENGTH
+            };
+            buf.put_u8(AttrFlags::OPTIONAL.bits());
+            buf.put_u8(AttrType::MpReachNlri as u8);
+            buf.put_u8(1 + nexthop_len as u8);
+            buf.put_u8(nexthop_len as u8);
+            buf.put_ipv6(&nexthop);
+            if let Some(ll_nexthop) = ll_nexthop {
+                buf.put_ipv6(&ll_nexthop);
+            }
+        }
+        None => (),
+    }
+    attrs.encode(buf, &reach, &None, &None, &cxt);
+
+    // Rewrite attribute length.
+    let len = (buf.len() - len_pos - 2) as u16;
+    buf[len_pos..len_pos + 2].copy_from_slice(&len.to_be_bytes());
+}
+
+fn bgp4mp_header(
+    instance: &InstanceUpView<'_>,
+    nbr: &Neighbor,
+) -> Bgp4mpHeader {
+    let local_addr = nbr
+        .conn_info
+        .as_ref()
+        .map(|conn_info| conn_info.local_addr)
+        .unwrap_or_else(|| match nbr.remote_addr {
+            IpAddr::V4(_) => IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+            IpAddr::V6(_) => IpAddr::V6(Ipv6Addr::UNSPECIFIED),
+        });
+
+    Bgp4mpHeader {
+        peer_as: nbr.config.peer_as,
+        local_as: nbr.config.local_as.unwrap_or(instance.config.asn),
+        peer_addr: nbr.remote_addr,
+        local_addr,
+    }
+}
+
+// Returns the BGP FSM state code used in BGP4MP_STATE_CHANGE records.
+fn state_code(state: fsm::State) -> u16 {
+    match state {
+        fsm::State::Idle => 1,
+        fsm::State::Connect => 2,
+        fsm::State::Active => 3,
+        fsm::State::OpenSent => 4,
+        fsm::State::OpenConfirm => 5,
+        fsm::State::Established => 6,
+    }
+}
+
+// Appends the given records to the update log, rotating the log file when
+// necessary.
+fn update_log_write(
+    update_log: &mut Option<UpdateLog>,
+    directory: &str,
+    rotation_interval: Duration,
+    buf: &[u8],
+) -> Result<(), std::io::Error> {
+    if update_log
+        .as_ref()
+        .is_some_and(|log| log.opened.elapsed() >= rotation_interval)
+    {
+        *update_log = None;
+    }
+
+    let update_log = match update_log {
+        Some(update_log) => update_log,
+        None => {
+            let file = file_create(directory, "updates")?;
+            update_log.insert(UpdateLog {
+                file: BufWriter::new(file),
+                opened: Instant::now(),
+            })
+        }
+    };
+    update_log.file.write_all(buf)
+}
+
+// Creates a new MRT file named after the current time.
+//
+// A sequence number is appended to the file name when a file with the same
+// name already exists, which happens when more than one file is created
+// within the same second.
+fn file_create(directory: &str, prefix: &str) -> Result<File, std::io::Error> {
+    let suffix = Utc::now().format("%Y%m%d.%H%M%S");
+    let name = format!("{prefix}.{suffix}");
+    let mut seqno = 0;
+    loop {
+        let path = match seqno {
+            0 => Path::new(directory).join(&name),
+            _ => Path::new(directory).join(format!("{name}.{seqno}")),
+        };
+        match File::options().write(true).create_new(true).open(path) {
+            Err(error) if error.kind() == ErrorKind::AlreadyExists => {
+                seqno += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+fn timestamp() -> u32 {
+    Utc::now().timestamp() as u32
+}
+
+// ===== unit tests =====
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::packet::message::KeepaliveMsg;
+
+    #[test]
+    fn peer_index_table() {
+        let mut buf = BytesMut::new();
+        let peers = [MrtPeer {
+            bgp_id: Ipv4Addr::new(2, 2, 2, 2),
+            addr: IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2)),
+            asn: 65002,
+        }];
+        encode_peer_index_table(
+            &mut buf,
+            1,
+            Ipv4Addr::new(1, 1, 1, 1),
+            "main",
+            &peers,
+        );
+        assert_eq!(
+            buf.as_ref(),
+            [
+                0x00, 0x00, 0x00, 0x01, 0x00, 0x0d, 0x00, 0x01, 0x00, 0x00,
+                0x00, 0x19, 0x01, 0x01, 0x01, 0x01, 0x00, 0x04, b'm', b'a',
+                b'i', b'n', 0x00, 0x01, 0x02, 0x02, 0x02, 0x02, 0x02, 0x0a,
+                0x00, 0x00, 0x02, 0x00, 0x00, 0xfd, 0xea,
+            ]
+        );
+    }
+
+    #[test]
+    fn bgp4mp_state_change() {
+        let mut buf = BytesMut::new();
+        let header = Bgp4mpHeader {
+            peer_as: 65002,
+            local_as: 65001,
+            peer_addr: IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2)),
+            local_addr: IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)),
+        };
+        encode_bgp4mp_state_change(
+            &mut buf,
+            1,
+            &header,
+            fsm::State::OpenConfirm,
+            fsm::State::Established,
+        );
+        assert_eq!(
+            buf.as_ref(),
+            [
+                0x00, 0x00, 0x00, 0x01, 0x00, 0x10, 0x00, 0x05, 0x00, 0x00,
+                0x00, 0x18, 0x00, 0x00, 0xfd, 0xea, 0x00, 0x00, 0xfd, 0xe9,
+                0x00, 0x00, 0x00, 0x01, 0x0a, 0x00, 0x00, 0x02, 0x0a, 0x00,
+                0x00, 0x01, 0x00, 0x05, 0x00, 0x06,
+            ]
+        );
+    }
+
+    #[test]
+    fn bgp4mp_message() {
+        let mut buf = BytesMut::new();
+        let header = Bgp4mpHeader {
+            peer_as: 65002,
+            local_as: 65001,
+            peer_addr: IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2)),
+            local_addr: IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)),
+        };
+        let cxt = EncodeCxt {
+            capabilities: Default::default(),
+        };
+        let msg = Message::Keepalive(KeepaliveMsg {}).encode(&cxt);
+        encode_bgp4mp_message(&mut buf, 1, &header, &msg);
+
+        // MRT header + BGP4MP header + KEEPALIVE message.
+        assert_eq!(buf.len(), 12 + 20 + 19);
+        assert_eq!(&buf[8..12], &(20u32 + 19).to_be_bytes());
+        assert_eq!(&buf[32..], msg.as_ref());
+    }
+
+    #[test]
+    fn rib_entries() {
+        use holo_utils::bgp::RouteType;
+
+        use crate::packet::attribute::BaseAttrs;
+        use crate::rib::{AttrSetsCxt, LocalRoute, Route};
+
+        let nbr_addr = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2));
+        let attrs = Attrs {
+            base: BaseAttrs {
+                nexthop: Some(nbr_addr),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let origin = RouteOrigin::Neighbor {
+            identifier: Ipv4Addr::new(2, 2, 2, 2),
+            remote_addr: nbr_addr,
+        };
+        let mut attr_sets = AttrSetsCxt::default();
+        let route_attrs = attr_sets.get_route_attr_sets(&attrs);
+
+        // Add a route learned from the neighbor to both the Adj-RIB-In and
+        // the Loc-RIB.
+        let mut table = RoutingTable::<Ipv4Unicast>::default();
+        let dest = table
+            .prefixes
+            .entry("10.0.1.0/24".parse().unwrap())
+            .or_default();
+        let route =
+            Route::new(origin, route_attrs.clone(), RouteType::External);
+        dest.adj_rib
+            .entry(nbr_addr)
+            .or_default()
+            .update_in_pre(Box::new(route), &mut attr_sets);
+        dest.local = Some(Box::new(LocalRoute {
+            origin,
+            attrs: route_attrs,
+            route_type: RouteType::External,
+            last_modified: Instant::now(),
+            nexthops: None,
+            aspa_state: None,
+        }));
+        let peer_idx = [(nbr_addr, 1)].into();
+
+        // Each RIB has a single entry attributed to the neighbor. Offsets:
+        // MRT header (12), sequence number (4), prefix (4), entry count (2).
+        for rib in [TableDumpRib::LocRib, TableDumpRib::AdjRibIn] {
+            let mut buf = BytesMut::new();
+            let mut seqno = 0;
+            encode_rib_entries(&mut buf, 1, &mut seqno, &table, &peer_idx, rib);
+            assert_eq!(seqno, 1);
+            assert_eq!(&buf[20..22], &1u16.to_be_bytes());
+            assert_eq!(&buf[22..24], &1u16.to_be_bytes());
+        }
+    }
+}
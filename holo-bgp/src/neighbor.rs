@@ -11,37 +11,52 @@ use std::sync::atomic::{self, AtomicU32};
 use std::time::Duration;
 
 use arbitrary::Arbitrary;
+use bytes::Bytes;
 use chrono::{DateTime, Utc};
-use holo_protocol::InstanceChannelsTx;
-use holo_utils::bgp::{AfiSafi, RouteType, WellKnownCommunities};
+use holo_protocol::{InstanceChannelsTx, InstanceShared};
+use holo_utils::bgp::{
+    AfiSafi, AspaValidationState, Comm, RouteType, WellKnownCommunities,
+};
 use holo_utils::ibus::IbusChannelsTx;
+use holo_utils::ip::IpNetworkKind;
+use holo_utils::policy::{ApplyPolicyCfg, IpPrefixRange};
 use holo_utils::socket::{TTL_MAX, TcpConnInfo, TcpStream};
 use holo_utils::task::{IntervalTask, Task, TimeoutTask};
+use ipnetwork::IpNetwork;
 use num_traits::{FromPrimitive, ToPrimitive};
 use tokio::sync::mpsc;
 use tokio::sync::mpsc::{Sender, UnboundedSender};
 
 use crate::af::{AddressFamily, Ipv4Unicast, Ipv6Unicast};
+use crate::aspa::AspaDb;
 use crate::debug::Debug;
 use crate::error::Error;
 use crate::instance::{Instance, InstanceUpView};
-use crate::northbound::configuration::{InstanceCfg, NeighborCfg};
+use crate::northbound::configuration::{
+    CondAdvCfg, CondAdvCondition, InstanceCfg, NeighborCfg,
+};
 use crate::northbound::notification;
 use crate::northbound::rpc::ClearType;
-use crate::packet::attribute::{AS_TRANS, Attrs};
+use crate::packet::attribute::{AS_TRANS, AsPath, Attrs, CommList};
 use crate::packet::iana::{
-    Afi, CeaseSubcode, ErrorCode, FsmErrorSubcode, Safi,
+    Afi, CeaseSubcode, ErrorCode, FsmErrorSubcode, OrfType, Safi,
 };
 use crate::packet::message::{
-    Capability, DecodeCxt, EncodeCxt, KeepaliveMsg, Message,
-    NegotiatedCapability, NotificationMsg, OpenMsg, RouteRefreshMsg,
+    Capability, DecodeCxt, EncodeCxt, KeepaliveMsg, LlgrTuple, Message,
+    NegotiatedCapability, NotificationMsg, OpenMsg, OrfEntry, OrfMode,
+    OrfTuple, OrfWhenToRefresh, PrefixOrfEntry, RouteRefreshMsg,
+    RouteRefreshOrf, UpdateMsg,
+};
+use crate::policy::RoutePolicyInfo;
+use crate::rib::{
+    AttrSetsCxt, LocalRoute, Rib, Route, RouteOrigin, RoutingTable,
 };
-use crate::rib::{Rib, Route, RouteOrigin};
 #[cfg(feature = "testing")]
 use crate::tasks::messages::ProtocolOutputMsg;
 use crate::tasks::messages::input::{NbrTimerMsg, TcpConnectMsg};
 use crate::tasks::messages::output::NbrTxMsg;
-use crate::{events, rib, tasks};
+use crate::update_group::UpdateGroupId;
+use crate::{events, mrt, policy, rib, tasks};
 
 // Large hold-time used during session initialization.
 const LARGE_HOLDTIME: u16 = 240;
@@ -66,11 +81,27 @@ pub struct Neighbor {
     pub statistics: NeighborStatistics,
     pub tasks: NeighborTasks,
     pub update_queues: NeighborUpdateQueues,
+    pub update_group: Option<UpdateGroupId>,
+    // Graceful shutdown enabled through RPC.
+    pub graceful_shutdown_rpc: bool,
+    // Whether graceful shutdown is in effect.
+    pub graceful_shutdown: bool,
+    pub cond_adv: BTreeMap<AfiSafi, CondAdvState>,
+    // Address Prefix ORF entries received from the neighbor.
+    pub orf_rcvd: BTreeMap<AfiSafi, BTreeMap<u32, PrefixOrfEntry>>,
+    // Address Prefix ORF entries last sent to the neighbor.
+    pub orf_sent: BTreeMap<AfiSafi, Vec<PrefixOrfEntry>>,
+    // Long-lived stale timers, one per address family with retained stale
+    // routes.
+    pub llgr_stale: BTreeMap<AfiSafi, TimeoutTask>,
+    // Address families pending the transmission of an End-of-RIB marker.
+    pub eor_pending: BTreeSet<AfiSafi>,
     pub msg_txp: Option<UnboundedSender<NbrTxMsg>>,
+    pub msg_tx_backlog: Arc<AtomicU32>,
 }
 
 // BGP peer type.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
 #[derive(Arbitrary)]
 pub enum PeerType {
     Internal,
@@ -108,6 +139,15 @@ pub struct NeighborTasks {
     pub holdtime: Option<TimeoutTask>,
 }
 
+// Conditional advertisement state.
+#[derive(Debug)]
+pub struct CondAdvState {
+    // Loc-RIB prefixes accepted by the condition policy.
+    pub matched: BTreeSet<IpNetwork>,
+    // Whether the routes accepted by the advertise policy are advertised.
+    pub advertise: bool,
+}
+
 // Neighbor Tx update queues.
 #[derive(Debug, Default)]
 pub struct NeighborUpdateQueues {
@@ -127,6 +167,7 @@ pub type Neighbors = BTreeMap<IpAddr, Neighbor>;
 
 // Finite State Machine.
 pub mod fsm {
+    use holo_utils::bgp::AfiSafi;
     use holo_utils::socket::{TcpConnInfo, TcpStream};
     use serde::{Deserialize, Serialize};
 
@@ -187,6 +228,8 @@ pub mod fsm {
         ConnectRetry,
         Hold,
         AutoStart,
+        // Long-lived stale timer (RFC 9494). Handled outside the FSM.
+        LlgrStale(AfiSafi),
     }
 }
 
@@ -194,7 +237,7 @@ pub mod fsm {
 
 impl Neighbor {
     // Creates a new neighbor in the Idle state with default configuration.
-    pub(crate) fn new(remote_addr: IpAddr, peer_type: PeerType) -> Neighbor {
+    pub fn new(remote_addr: IpAddr, peer_type: PeerType) -> Neighbor {
         Neighbor {
             remote_addr,
             config: Default::default(),
@@ -213,7 +256,16 @@ impl Neighbor {
             statistics: Default::default(),
             tasks: Default::default(),
             update_queues: Default::default(),
+            update_group: None,
+            graceful_shutdown_rpc: false,
+            graceful_shutdown: false,
+            cond_adv: Default::default(),
+            orf_rcvd: Default::default(),
+            orf_sent: Default::default(),
+            llgr_stale: Default::default(),
+            eor_pending: Default::default(),
             msg_txp: None,
+            msg_tx_backlog: Default::default(),
         }
     }
 
@@ -417,7 +469,7 @@ impl Neighbor {
                     Some(fsm::State::Idle)
                 }
                 fsm::Event::ConnFail => {
-                    self.session_close(rib, instance.tx, None);
+                    self.session_fail(rib, instance.tx, None);
                     Some(fsm::State::Idle)
                 }
                 fsm::Event::RcvdError(error) => {
@@ -437,7 +489,7 @@ impl Neighbor {
                     let error_code = ErrorCode::HoldTimerExpired;
                     let error_subcode = 0;
                     let msg = NotificationMsg::new(error_code, error_subcode);
-                    self.session_close(rib, instance.tx, Some(msg));
+                    self.session_fail(rib, instance.tx, Some(msg));
                     Some(fsm::State::Idle)
                 }
                 _ => {
@@ -484,6 +536,9 @@ impl Neighbor {
             .log();
         }
 
+        // Log state change in the MRT format.
+        mrt::log_state_change(instance, self, self.state, next_state);
+
         // Send YANG-modeled notification.
         if next_state == fsm::State::Established {
             notification::established(instance, self);
@@ -499,6 +554,11 @@ impl Neighbor {
             self.last_established = Some(Utc::now());
         }
 
+        // Leave the update group.
+        if self.state == fsm::State::Established {
+            instance.state.update_groups.leave(self);
+        }
+
         if next_state == fsm::State::Established {
             // Update statistics.
             self.statistics.established_transitions += 1;
@@ -526,6 +586,7 @@ impl Neighbor {
 
         // Spawn neighbor TCP Tx task.
         let (msg_txp, msg_txc) = mpsc::unbounded_channel();
+        self.msg_tx_backlog = Default::default();
         let cxt = EncodeCxt {
             capabilities: Default::default(),
         };
@@ -540,15 +601,26 @@ impl Neighbor {
         self.msg_txp = Some(msg_txp);
 
         // Spawn neighbor TCP Rx task.
+        //
+        // The decoding context starts without any capabilities, which limits
+        // the message size to 4096 octets as per RFC 4271. Once the peer's
+        // OPEN message is received, the capabilities supported by both sides
+        // are added to the context.
         let cxt = DecodeCxt {
             peer_type: self.peer_type,
             peer_as: self.config.peer_as,
             reject_as_sets: instance.config.reject_as_sets,
             capabilities: Default::default(),
         };
+        let capabilities_local = self
+            .capabilities_local(instance.config)
+            .iter()
+            .map(|cap| cap.as_negotiated())
+            .collect();
         let tcp_rx_task = tasks::nbr_rx(
             self,
             cxt,
+            capabilities_local,
             read_half,
             &instance.tx.protocol_input.nbr_msg_rx,
         );
@@ -582,6 +654,24 @@ impl Neighbor {
         let msg = NbrTxMsg::UpdateCapabilities(self.capabilities_nego.clone());
         let _ = self.msg_txp.as_ref().unwrap().send(msg);
 
+        // Update the graceful shutdown state.
+        self.graceful_shutdown = self.is_graceful_shutdown(instance);
+
+        // Evaluate the conditional advertisement state.
+        self.cond_adv_reset::<Ipv4Unicast>(instance);
+        self.cond_adv_reset::<Ipv6Unicast>(instance);
+
+        // Join the update group matching the outbound parameters.
+        instance.state.update_groups.join(self);
+
+        // Send our Address Prefix ORF entries, if any.
+        self.orf_send(instance.shared, AfiSafi::Ipv4Unicast);
+        self.orf_send(instance.shared, AfiSafi::Ipv6Unicast);
+
+        // Flush the long-lived stale routes of the address families for which
+        // LLGR is no longer in effect.
+        self.llgr_stale_update(instance);
+
         // Send initial routing updates.
         self.initial_routing_update::<Ipv4Unicast>(instance);
         self.initial_routing_update::<Ipv6Unicast>(instance);
@@ -593,6 +683,38 @@ impl Neighbor {
         rib: &mut Rib,
         instance_tx: &InstanceChannelsTx<Instance>,
         send_notif: Option<NotificationMsg>,
+    ) {
+        // Flush any long-lived stale routes.
+        self.llgr_stale.clear();
+
+        self.session_release(rib, instance_tx, send_notif);
+    }
+
+    // Closes the BGP session after a connection failure or hold timer
+    // expiration.
+    //
+    // Unlike `session_close`, the routes received from the neighbor are
+    // retained as long-lived stale routes for the address families where
+    // LLGR is in effect (RFC 9494).
+    fn session_fail(
+        &mut self,
+        rib: &mut Rib,
+        instance_tx: &InstanceChannelsTx<Instance>,
+        send_notif: Option<NotificationMsg>,
+    ) {
+        self.llgr_stale_start::<Ipv4Unicast>(rib, instance_tx);
+        self.llgr_stale_start::<Ipv6Unicast>(rib, instance_tx);
+
+        self.session_release(rib, instance_tx, send_notif);
+    }
+
+    // Releases all session resources, clearing the routes exchanged with the
+    // neighbor.
+    fn session_release(
+        &mut self,
+        rib: &mut Rib,
+        instance_tx: &InstanceChannelsTx<Instance>,
+        send_notif: Option<NotificationMsg>,
     ) {
         // Send a notification message.
         if self.state >= fsm::State::OpenSent
@@ -611,6 +733,9 @@ impl Neighbor {
         self.capabilities_adv.clear();
         self.capabilities_rcvd.clear();
         self.capabilities_nego.clear();
+        self.orf_rcvd.clear();
+        self.orf_sent.clear();
+        self.eor_pending.clear();
         self.clear_routes::<Ipv4Unicast>(rib, &instance_tx.ibus);
         self.clear_routes::<Ipv6Unicast>(rib, &instance_tx.ibus);
         self.tasks = Default::default();
@@ -622,23 +747,13 @@ impl Neighbor {
 
     // Enqueues a single BGP message for transmission.
     pub(crate) fn message_send(&mut self, msg: Message) {
-        if self.config.trace_opts.packets_resolved.load().tx(&msg) {
-            Debug::NbrMsgTx(&self.remote_addr, &msg).log();
-        }
-
-        // Update statistics.
-        self.statistics.msgs_sent.update(&msg);
-
-        // Keep track of the last sent notification.
-        if let Message::Notification(msg) = &msg {
-            self.notification_sent = Some((Utc::now(), msg.clone()));
-        }
+        self.message_tx_account(&msg);
 
         // Ignore any possible error as the connection might have gone down
         // already.
         let nbr_addr = self.remote_addr;
         let msg = NbrTxMsg::SendMessage { nbr_addr, msg };
-        let _ = self.msg_txp.as_ref().unwrap().send(msg);
+        self.message_tx_enqueue(msg);
     }
 
     // Enqueues a list of BGP messages for transmission.
@@ -647,28 +762,90 @@ impl Neighbor {
     // as they are sent all at once.
     pub(crate) fn message_list_send(&mut self, msg_list: Vec<Message>) {
         for msg in &msg_list {
-            if self.config.trace_opts.packets_resolved.load().tx(msg) {
-                Debug::NbrMsgTx(&self.remote_addr, msg).log();
-            }
+            self.message_tx_account(msg);
+        }
 
-            // Update statistics.
-            self.statistics.msgs_sent.update(msg);
+        // Ignore any possible error as the connection might have gone down
+        // already.
+        let nbr_addr = self.remote_addr;
+        let msg = NbrTxMsg::SendMessageList { nbr_addr, msg_list };
+        self.message_tx_enqueue(msg);
+    }
 
-            // Keep track of the last sent notification.
-            if let Message::Notification(msg) = &msg {
-                self.notification_sent = Some((Utc::now(), msg.clone()));
-            }
+    // Enqueues a list of pre-encoded BGP messages for transmission.
+    //
+    // Used by update groups to share the same encoded UPDATE messages among
+    // all group members.
+    pub(crate) fn message_list_send_encoded(
+        &mut self,
+        msg_list: Arc<Vec<Message>>,
+        buf: Bytes,
+    ) {
+        for msg in msg_list.iter() {
+            self.message_tx_account(msg);
         }
 
         // Ignore any possible error as the connection might have gone down
         // already.
         let nbr_addr = self.remote_addr;
-        let msg = NbrTxMsg::SendMessageList { nbr_addr, msg_list };
+        let msg = NbrTxMsg::SendEncodedList {
+            nbr_addr,
+            msg_list,
+            buf,
+        };
+        self.message_tx_enqueue(msg);
+    }
+
+    // Returns the number of messages pending transmission.
+    pub(crate) fn msg_tx_backlog(&self) -> u32 {
+        self.msg_tx_backlog.load(atomic::Ordering::Relaxed)
+    }
+
+    // Logs and updates statistics for an outgoing BGP message.
+    fn message_tx_account(&mut self, msg: &Message) {
+        if self.config.trace_opts.packets_resolved.load().tx(msg) {
+            Debug::NbrMsgTx(&self.remote_addr, msg).log();
+        }
+
+        // Update statistics.
+        self.statistics.msgs_sent.update(msg);
+
+        // Keep track of the last sent notification.
+        if let Message::Notification(msg) = &msg {
+            self.notification_sent = Some((Utc::now(), msg.clone()));
+        }
+    }
+
+    // Hands a message over to the Tx task, keeping track of the backlog.
+    fn message_tx_enqueue(&mut self, msg: NbrTxMsg) {
+        self.msg_tx_backlog
+            .fetch_add(msg.msg_count(), atomic::Ordering::Relaxed);
         let _ = self.msg_txp.as_ref().unwrap().send(msg);
     }
 
     // Sends a BGP OPEN message based on the local configuration.
     fn open_send(&mut self, instance_cfg: &InstanceCfg, identifier: Ipv4Addr) {
+        let capabilities = self.capabilities_local(instance_cfg);
+
+        // Keep track of the advertised capabilities.
+        self.capabilities_adv.clone_from(&capabilities);
+
+        // Fill-in and send message.
+        let msg = Message::Open(OpenMsg {
+            version: OpenMsg::VERSION,
+            my_as: instance_cfg.asn.try_into().unwrap_or(AS_TRANS),
+            holdtime: self.config.timers.holdtime,
+            identifier,
+            capabilities,
+        });
+        self.message_send(msg);
+    }
+
+    // Returns the capabilities to advertise based on the local configuration.
+    pub(crate) fn capabilities_local(
+        &self,
+        instance_cfg: &InstanceCfg,
+    ) -> BTreeSet<Capability> {
         // Base capabilities.
         let mut capabilities: BTreeSet<_> = [
             Capability::RouteRefresh,
@@ -696,18 +873,63 @@ impl Neighbor {
             });
         }
 
-        // Keep track of the advertised capabilities.
-        self.capabilities_adv.clone_from(&capabilities);
+        // Extended Message capability.
+        if self.config.extended_message {
+            capabilities.insert(Capability::ExtendedMessage);
+        }
 
-        // Fill-in and send message.
-        let msg = Message::Open(OpenMsg {
-            version: OpenMsg::VERSION,
-            my_as: instance_cfg.asn.try_into().unwrap_or(AS_TRANS),
-            holdtime: self.config.timers.holdtime,
-            identifier,
-            capabilities,
-        });
-        self.message_send(msg);
+        // Outbound Route Filtering capability.
+        let orf_tuples: BTreeSet<_> = self
+            .config
+            .afi_safi
+            .iter()
+            .filter(|(_, afi_safi)| afi_safi.enabled)
+            .filter_map(|(afi_safi, afi_safi_cfg)| {
+                let mode =
+                    match (afi_safi_cfg.orf.send, afi_safi_cfg.orf.receive) {
+                        (true, true) => OrfMode::Both,
+                        (true, false) => OrfMode::Send,
+                        (false, true) => OrfMode::Receive,
+                        (false, false) => return None,
+                    };
+                let (afi, safi) = afi_safi_split(*afi_safi);
+                Some(OrfTuple {
+                    afi,
+                    safi,
+                    orf_type: OrfType::AddressPrefix,
+                    mode,
+                })
+            })
+            .collect();
+        if !orf_tuples.is_empty() {
+            capabilities.insert(Capability::Orf(orf_tuples));
+        }
+
+        // Long-Lived Graceful Restart capability.
+        //
+        // Forwarding state isn't preserved across restarts, hence the "F"
+        // flag is never set.
+        let llgr_tuples: BTreeSet<_> = self
+            .config
+            .afi_safi
+            .iter()
+            .filter(|(_, afi_safi)| afi_safi.enabled && afi_safi.llgr.enabled)
+            .map(|(afi_safi, afi_safi_cfg)| {
+                let (afi, safi) = afi_safi_split(*afi_safi);
+                LlgrTuple {
+                    afi,
+                    safi,
+                    forwarding: false,
+                    stale_time: afi_safi_cfg.llgr.stale_time,
+                }
+            })
+            .collect();
+        if !llgr_tuples.is_empty() {
+            capabilities
+                .insert(Capability::LongLivedGracefulRestart(llgr_tuples));
+        }
+
+        capabilities
     }
 
     // Processes the received OPEN message while in the OpenSent state.
@@ -861,8 +1083,12 @@ impl Neighbor {
         seconds: u16,
         nbr_timerp: &Sender<NbrTimerMsg>,
     ) {
-        let task =
-            tasks::nbr_timer(self, fsm::Timer::Hold, seconds, nbr_timerp);
+        let task = tasks::nbr_timer(
+            self,
+            fsm::Timer::Hold,
+            seconds.into(),
+            nbr_timerp,
+        );
         self.tasks.holdtime = Some(task);
     }
 
@@ -884,7 +1110,7 @@ impl Neighbor {
         let task = tasks::nbr_timer(
             self,
             fsm::Timer::ConnectRetry,
-            self.config.timers.connect_retry_interval,
+            self.config.timers.connect_retry_interval.into(),
             nbr_timerp,
         );
         self.tasks.connect_retry = Some(task);
@@ -897,8 +1123,10 @@ impl Neighbor {
 
     // Sends an initial routing update for the specified address-family after
     // the session is established.
-    fn initial_routing_update<A>(&mut self, instance: &mut InstanceUpView<'_>)
-    where
+    pub(crate) fn initial_routing_update<A>(
+        &mut self,
+        instance: &mut InstanceUpView<'_>,
+    ) where
         A: AddressFamily,
     {
         // Check if the address-family is enabled for this session.
@@ -912,22 +1140,23 @@ impl Neighbor {
             .prefixes
             .iter()
             .filter_map(|(prefix, dest)| {
-                dest.local.as_ref().map(|route| {
-                    let route = Route {
-                        origin: route.origin,
-                        attrs: route.attrs.clone(),
-                        route_type: route.route_type,
-                        igp_cost: None,
-                        last_modified: route.last_modified,
-                        ineligible_reason: None,
-                        reject_reason: None,
-                    };
-                    (prefix, Box::new(route))
-                })
+                dest.local
+                    .as_ref()
+                    .map(|route| (prefix, loc_rib_route(route)))
             })
             .filter(|(_, route)| self.distribute_filter(route))
             .collect::<Vec<_>>();
 
+        // Send an End-of-RIB marker to LLGR-capable neighbors once the
+        // initial routing update is complete.
+        if self.llgr_capable() {
+            if routes.is_empty() {
+                self.eor_send(A::AFI_SAFI);
+            } else {
+                self.eor_pending.insert(A::AFI_SAFI);
+            }
+        }
+
         // Advertise the best routes.
         events::advertise_routes::<A>(
             self,
@@ -939,109 +1168,921 @@ impl Neighbor {
         );
     }
 
-    // Re-send the current Adj-RIB-Out.
-    pub(crate) fn resend_adj_rib_out<A>(
+    // Readvertises the best routes of the given prefixes to the neighbor.
+    pub(crate) fn routes_resync<A>(
         &mut self,
         instance: &mut InstanceUpView<'_>,
+        prefixes: BTreeSet<A::IpNetwork>,
     ) where
         A: AddressFamily,
     {
+        // Check if the address-family is enabled for this session.
+        if !self.is_af_enabled(A::AFI, A::SAFI) {
+            return;
+        }
+
+        // Get list of best routes for the given prefixes.
         let table = A::table(&mut instance.state.rib.tables);
-        for (prefix, dest) in &table.prefixes {
-            let Some(adj_rib) = dest.adj_rib.get(&self.remote_addr) else {
-                continue;
-            };
-            let Some(route) = adj_rib.out_post() else {
-                continue;
-            };
+        let routes = prefixes
+            .into_iter()
+            .filter_map(|prefix| {
+                let route = table.prefixes.get(&prefix)?.local.as_ref()?;
+                Some((prefix, loc_rib_route(route)))
+            })
+            .filter(|(_, route)| self.distribute_filter(route))
+            .collect::<Vec<_>>();
 
-            // Update route's attributes before transmission.
-            let mut attrs = route.attrs.get();
-            rib::attrs_tx_update::<A>(
-                &mut attrs,
-                self,
-                instance.config.asn,
-                route.origin.is_local(),
-            );
+        // Advertise the best routes.
+        events::advertise_routes::<A>(
+            self,
+            table,
+            routes,
+            instance.shared,
+            &mut instance.state.rib.attr_sets,
+            &instance.state.policy_apply_tasks,
+        );
+    }
 
-            // Update neighbor's Tx queue.
-            let update_queue = A::update_queue(&mut self.update_queues);
-            update_queue.reach.entry(attrs).or_default().insert(prefix);
+    // Sends an End-of-RIB marker for the given address family.
+    pub(crate) fn eor_send(&mut self, afi_safi: AfiSafi) {
+        let (afi, _) = afi_safi_split(afi_safi);
+        let msg = UpdateMsg::end_of_rib(afi);
+        self.message_send(Message::Update(msg));
+    }
+
+    // Returns the routing policy configuration that applies to the given
+    // address family.
+    pub(crate) fn apply_policy_cfg(
+        &self,
+        afi_safi: AfiSafi,
+    ) -> &ApplyPolicyCfg {
+        self.config
+            .afi_safi
+            .get(&afi_safi)
+            .map(|afi_safi_cfg| &afi_safi_cfg.apply_policy)
+            .unwrap_or(&self.config.apply_policy)
+    }
+
+    // Returns whether the neighbor advertised the LLGR capability.
+    pub(crate) fn llgr_capable(&self) -> bool {
+        self.capabilities_rcvd
+            .iter()
+            .any(|cap| cap.as_long_lived_graceful_restart().is_some())
+    }
+
+    // Returns the LLGR capability tuple advertised by the neighbor for the
+    // given address family, if any.
+    pub(crate) fn llgr_tuple_rcvd(
+        &self,
+        afi_safi: AfiSafi,
+    ) -> Option<&LlgrTuple> {
+        let (afi, safi) = afi_safi_split(afi_safi);
+        self.capabilities_rcvd
+            .iter()
+            .filter_map(Capability::as_long_lived_graceful_restart)
+            .flatten()
+            .find(|tuple| tuple.afi == afi && tuple.safi == safi)
+    }
+
+    // Returns whether LLGR is locally enabled for the given address family.
+    fn llgr_enabled(&self, afi_safi: AfiSafi) -> bool {
+        self.config
+            .afi_safi
+            .get(&afi_safi)
+            .is_some_and(|afi_safi_cfg| {
+                afi_safi_cfg.enabled && afi_safi_cfg.llgr.enabled
+            })
+    }
+
+    // Returns the time long-lived stale routes of the given address family
+    // are retained, or `None` if LLGR isn't in effect for it.
+    //
+    // The stale time advertised by the neighbor is capped by the locally
+    // configured one.
+    fn llgr_stale_time(&self, afi_safi: AfiSafi) -> Option<u32> {
+        if !self.llgr_enabled(afi_safi) {
+            return None;
         }
+        let afi_safi_cfg = self.config.afi_safi.get(&afi_safi)?;
+        let tuple = self.llgr_tuple_rcvd(afi_safi)?;
+        let stale_time =
+            std::cmp::min(tuple.stale_time, afi_safi_cfg.llgr.stale_time);
+        (stale_time != 0).then_some(stale_time)
     }
 
-    // Clears the Adj-RIB-In and Adj-RIB-Out for the given address family.
-    fn clear_routes<A>(&mut self, rib: &mut Rib, ibus_tx: &IbusChannelsTx)
-    where
+    // Marks the routes received from the neighbor as long-lived stale routes
+    // and starts the stale timer, if LLGR is in effect for the given address
+    // family.
+    fn llgr_stale_start<A>(
+        &mut self,
+        rib: &mut Rib,
+        instance_tx: &InstanceChannelsTx<Instance>,
+    ) where
         A: AddressFamily,
     {
+        let Some(stale_time) = self.llgr_stale_time(A::AFI_SAFI) else {
+            self.llgr_stale.remove(&A::AFI_SAFI);
+            return;
+        };
+
         let table = A::table(&mut rib.tables);
         for (prefix, dest) in table.prefixes.iter_mut() {
-            // Clear the Adj-RIB-In and Adj-RIB-Out.
-            if let Some(mut adj_rib) = dest.adj_rib.remove(&self.remote_addr) {
-                // Update nexthop tracking.
-                if let Some(adj_in_route) = adj_rib.in_post() {
+            let Some(adj_rib) = dest.adj_rib.get_mut(&self.remote_addr) else {
+                continue;
+            };
+            let Some(route) = adj_rib.in_pre() else {
+                continue;
+            };
+
+            // RFC 9494 - Section 4.3:
+            // "A BGP speaker MUST NOT retain routes carrying the NO_LLGR
+            // community as long-lived stale routes".
+            if route.attrs.has_comm(WellKnownCommunities::NoLlgr)
+                || adj_rib.in_post().is_some_and(|route| {
+                    route.attrs.has_comm(WellKnownCommunities::NoLlgr)
+                })
+            {
+                if let Some(route) = adj_rib.remove_in_post(&mut rib.attr_sets)
+                {
                     rib::nexthop_untrack(
                         &mut table.nht,
                         &prefix,
-                        adj_in_route,
-                        ibus_tx,
+                        &route,
+                        &instance_tx.ibus,
                     );
                 }
-
                 adj_rib.remove_in_pre(&mut rib.attr_sets);
-                adj_rib.remove_in_post(&mut rib.attr_sets);
-                adj_rib.remove_out_pre(&mut rib.attr_sets);
-                adj_rib.remove_out_post(&mut rib.attr_sets);
+            } else {
+                // Attach the LLGR_STALE community.
+                let route = llgr_stale_route(route, &mut rib.attr_sets);
+                adj_rib.update_in_pre(route, &mut rib.attr_sets);
+                if let Some(route) = adj_rib.in_post() {
+                    let route = llgr_stale_route(route, &mut rib.attr_sets);
+                    adj_rib.update_in_post(route, &mut rib.attr_sets);
+                }
             }
 
             // Enqueue prefix for the BGP Decision Process.
             table.queued_prefixes.insert(prefix);
         }
+
+        // Start the stale timer.
+        let task = tasks::nbr_timer(
+            self,
+            fsm::Timer::LlgrStale(A::AFI_SAFI),
+            stale_time,
+            &instance_tx.protocol_input.nbr_timer,
+        );
+        self.llgr_stale.insert(A::AFI_SAFI, task);
     }
 
-    // Clears the neighbor session.
-    pub(crate) fn clear_session(
+    // Flushes the long-lived stale routes of the given address family, either
+    // because the stale timer expired or the End-of-RIB marker was received.
+    pub(crate) fn llgr_stale_end(
         &mut self,
         instance: &mut InstanceUpView<'_>,
-        clear_type: ClearType,
+        afi_safi: AfiSafi,
     ) {
-        match clear_type {
-            ClearType::Admin => {
-                // Close the session with the "Administrative Reset" subcode.
-                let msg = NotificationMsg::new(
-                    ErrorCode::Cease,
-                    CeaseSubcode::AdministrativeReset,
-                );
-                self.fsm_event(instance, fsm::Event::Stop(Some(msg)));
-            }
-            ClearType::Hard => {
-                // Close the session with the "Hard Reset" subcode.
-                let msg = NotificationMsg::new(
-                    ErrorCode::Cease,
-                    CeaseSubcode::HardReset,
-                );
-                self.fsm_event(instance, fsm::Event::Stop(Some(msg)));
+        if self.llgr_stale.remove(&afi_safi).is_none() {
+            return;
+        }
+
+        let rib = &mut instance.state.rib;
+        match afi_safi {
+            AfiSafi::Ipv4Unicast => {
+                self.llgr_stale_flush::<Ipv4Unicast>(rib, &instance.tx.ibus)
             }
-            ClearType::Soft => {
-                // Re-send the current Adj-RIB-Out to this neighbor.
-                self.resend_adj_rib_out::<Ipv4Unicast>(instance);
-                self.resend_adj_rib_out::<Ipv6Unicast>(instance);
-                let msg_list = self.update_queues.build_updates();
-                if !msg_list.is_empty() {
-                    self.message_list_send(msg_list);
-                }
+            AfiSafi::Ipv6Unicast => {
+                self.llgr_stale_flush::<Ipv6Unicast>(rib, &instance.tx.ibus)
             }
-            ClearType::SoftInbound => {
-                // Request the Adj-RIB-In for this neighbor to be re-sent.
-                for (afi, safi) in self
-                    .capabilities_nego
-                    .iter()
-                    .filter_map(|cap| {
-                        if let NegotiatedCapability::MultiProtocol {
-                            afi,
-                            safi,
-                        } = cap
+        }
+
+        // Schedule the BGP Decision Process.
+        instance.state.schedule_decision_process(instance.tx);
+    }
+
+    // Flushes the long-lived stale routes of the address families for which
+    // LLGR is no longer in effect, either because it was disabled locally or
+    // the neighbor stopped advertising it.
+    //
+    // This applies regardless of the session state, as the stale routes are
+    // retained while the session is down. The capabilities advertised by the
+    // neighbor are only taken into account once its OPEN message is received.
+    pub(crate) fn llgr_stale_update(
+        &mut self,
+        instance: &mut InstanceUpView<'_>,
+    ) {
+        let open_rcvd = self.state >= fsm::State::OpenConfirm;
+        let afi_safis = self
+            .llgr_stale
+            .keys()
+            .copied()
+            .filter(|afi_safi| {
+                !self.llgr_enabled(*afi_safi)
+                    || (open_rcvd && self.llgr_stale_time(*afi_safi).is_none())
+            })
+            .collect::<Vec<_>>();
+        for afi_safi in afi_safis {
+            self.llgr_stale_end(instance, afi_safi);
+        }
+    }
+
+    // Removes the long-lived stale routes that weren't refreshed by the
+    // neighbor.
+    fn llgr_stale_flush<A>(&mut self, rib: &mut Rib, ibus_tx: &IbusChannelsTx)
+    where
+        A: AddressFamily,
+    {
+        let table = A::table(&mut rib.tables);
+        for (prefix, dest) in table.prefixes.iter_mut() {
+            let Some(adj_rib) = dest.adj_rib.get_mut(&self.remote_addr) else {
+                continue;
+            };
+            if !adj_rib.in_pre().is_some_and(|route| route.llgr_stale) {
+                continue;
+            }
+
+            if let Some(route) = adj_rib.remove_in_post(&mut rib.attr_sets) {
+                rib::nexthop_untrack(&mut table.nht, &prefix, &route, ibus_tx);
+            }
+            adj_rib.remove_in_pre(&mut rib.attr_sets);
+
+            // Enqueue prefix for the BGP Decision Process.
+            table.queued_prefixes.insert(prefix);
+        }
+    }
+
+    // Updates the graceful shutdown state, re-advertising all routes if it
+    // has changed.
+    pub(crate) fn graceful_shutdown_update(
+        &mut self,
+        instance: &mut InstanceUpView<'_>,
+    ) {
+        let graceful_shutdown = self.is_graceful_shutdown(instance);
+        if graceful_shutdown == self.graceful_shutdown {
+            return;
+        }
+        self.graceful_shutdown = graceful_shutdown;
+
+        if self.state != fsm::State::Established {
+            return;
+        }
+
+        // The GRACEFUL_SHUTDOWN community is attached to outgoing routes, so
+        // the neighbor might need to move to a different update group.
+        instance.state.update_groups.update(self);
+
+        // Re-advertise all routes.
+        self.resend_adj_rib_out::<Ipv4Unicast>(instance);
+        self.resend_adj_rib_out::<Ipv6Unicast>(instance);
+        let msg_list = self.update_queues.build_updates(
+            self.remote_addr,
+            &mut instance.state.rib,
+            self.max_msg_len(),
+        );
+        if !msg_list.is_empty() {
+            self.message_list_send(msg_list);
+        }
+    }
+
+    // Returns the maximum length of the messages sent to this neighbor.
+    pub(crate) fn max_msg_len(&self) -> u16 {
+        Message::max_len(&self.capabilities_nego)
+    }
+
+    // Returns whether the AIGP attribute is enabled for this session. Unless
+    // explicitly configured, AIGP is enabled only for internal sessions.
+    pub(crate) fn aigp_enabled(&self) -> bool {
+        self.config
+            .aigp
+            .unwrap_or(self.peer_type == PeerType::Internal)
+    }
+
+    // Verifies the given AS_PATH against the ASPA records.
+    //
+    // Returns `None` when no ASPA records are available or the neighbor's
+    // role isn't configured.
+    pub(crate) fn aspa_verify(
+        &self,
+        aspa: Option<&AspaDb>,
+        as_path: &AsPath,
+    ) -> Option<AspaValidationState> {
+        let aspa = aspa?;
+        let role = self.config.aspa_role?;
+        Some(aspa.verify(as_path, role, self.config.peer_as))
+    }
+
+    // Re-verifies all routes received from this neighbor against the current
+    // ASPA records, re-applying the import policies to them.
+    pub(crate) fn aspa_revalidate<A>(&self, instance: &mut InstanceUpView<'_>)
+    where
+        A: AddressFamily,
+    {
+        if !self.is_af_enabled(A::AFI, A::SAFI) {
+            return;
+        }
+
+        let aspa = instance.state.aspa.as_ref();
+        let table = A::table(&mut instance.state.rib.tables);
+        let routes = table
+            .prefixes
+            .iter()
+            .filter_map(|(prefix, dest)| {
+                let route = dest.adj_rib.get(&self.remote_addr)?.in_pre()?;
+                let mut rpinfo = route.policy_info();
+                rpinfo.aspa_state =
+                    self.aspa_verify(aspa, &rpinfo.attrs.base.as_path);
+                Some((prefix.into(), rpinfo))
+            })
+            .collect::<Vec<_>>();
+        if routes.is_empty() {
+            return;
+        }
+
+        events::nbr_policy_import_enqueue::<A>(
+            self,
+            routes,
+            instance.shared,
+            &instance.state.policy_apply_tasks,
+        );
+    }
+
+    // Returns whether graceful shutdown should be in effect for this neighbor,
+    // either at the instance or neighbor level.
+    fn is_graceful_shutdown(&self, instance: &InstanceUpView<'_>) -> bool {
+        instance.config.graceful_shutdown
+            || instance.state.graceful_shutdown
+            || self.config.graceful_shutdown
+            || self.graceful_shutdown_rpc
+    }
+
+    // Re-send the current Adj-RIB-Out.
+    pub(crate) fn resend_adj_rib_out<A>(
+        &mut self,
+        instance: &mut InstanceUpView<'_>,
+    ) where
+        A: AddressFamily,
+    {
+        let table = A::table(&mut instance.state.rib.tables);
+        for (prefix, dest) in &table.prefixes {
+            let Some(adj_rib) = dest.adj_rib.get(&self.remote_addr) else {
+                continue;
+            };
+            let Some(route) = adj_rib.out_post() else {
+                continue;
+            };
+
+            // Skip routes suppressed by conditional advertisement or filtered
+            // by the neighbor's ORF.
+            if !self.cond_adv_permits(
+                instance.shared,
+                A::AFI_SAFI,
+                prefix.into(),
+                route.policy_info(),
+            ) || !self.orf_permits(A::AFI_SAFI, &prefix.into())
+            {
+                continue;
+            }
+
+            // Update route's attributes before transmission.
+            let mut attrs = route.attrs.get();
+            rib::attrs_tx_update::<A>(
+                &mut attrs,
+                self,
+                instance.config.asn,
+                route.origin.is_local(),
+                &table.nht,
+            );
+
+            // Update neighbor's Tx queue.
+            let update_queue = A::update_queue(&mut self.update_queues);
+            update_queue.reach.entry(attrs).or_default().insert(prefix);
+        }
+    }
+
+    // Re-evaluates the conditional advertisement state from scratch, updating
+    // the advertised routes accordingly.
+    pub(crate) fn cond_adv_reset<A>(
+        &mut self,
+        instance: &mut InstanceUpView<'_>,
+    ) where
+        A: AddressFamily,
+    {
+        let old_state = self.cond_adv.remove(&A::AFI_SAFI);
+
+        if let Some(cfg) = self.cond_adv_cfg(A::AFI_SAFI) {
+            let matched = Self::cond_adv_matched::<A>(instance, &cfg);
+            let state = CondAdvState::new(matched, cfg.condition);
+            self.cond_adv.insert(A::AFI_SAFI, state);
+        } else if old_state.is_none() {
+            return;
+        }
+
+        // Re-advertise the Adj-RIB-Out.
+        if self.state == fsm::State::Established {
+            self.readvertise_adj_rib_out::<A>(instance, false);
+        }
+    }
+
+    // Updates the conditional advertisement state after changes to the given
+    // Loc-RIB prefixes.
+    pub(crate) fn cond_adv_update<A>(
+        &mut self,
+        instance: &mut InstanceUpView<'_>,
+        prefixes: &BTreeSet<A::IpNetwork>,
+    ) where
+        A: AddressFamily,
+    {
+        let Some(cfg) = self.cond_adv_cfg(A::AFI_SAFI) else {
+            return;
+        };
+        let Some(state) = self.cond_adv.get_mut(&A::AFI_SAFI) else {
+            return;
+        };
+
+        // Update the set of Loc-RIB routes accepted by the condition policy.
+        let condition_policy = cfg.condition_policy.as_ref().unwrap();
+        let table = A::table(&mut instance.state.rib.tables);
+        for prefix in prefixes {
+            let route = table
+                .prefixes
+                .get(prefix)
+                .and_then(|dest| dest.local.as_ref());
+            if let Some(route) = route
+                && policy::policy_match(
+                    instance.shared,
+                    A::AFI_SAFI,
+                    (*prefix).into(),
+                    route.policy_info(),
+                    condition_policy,
+                )
+            {
+                state.matched.insert((*prefix).into());
+            } else {
+                state.matched.remove(&(*prefix).into());
+            }
+        }
+
+        // Check whether the condition has changed.
+        let advertise = CondAdvState::evaluate(&state.matched, cfg.condition);
+        if advertise == state.advertise {
+            return;
+        }
+        state.advertise = advertise;
+
+        // Advertise or withdraw the routes subject to the condition.
+        if self.state == fsm::State::Established {
+            self.readvertise_adj_rib_out::<A>(instance, true);
+        }
+    }
+
+    // Re-evaluates the conditional advertisements referencing the given
+    // routing policy, or all of them when the policy match sets have
+    // changed.
+    pub(crate) fn cond_adv_policy_update(
+        &mut self,
+        instance: &mut InstanceUpView<'_>,
+        policy: Option<&str>,
+    ) {
+        for afi_safi in [AfiSafi::Ipv4Unicast, AfiSafi::Ipv6Unicast] {
+            let Some(cfg) = self.cond_adv_cfg(afi_safi) else {
+                continue;
+            };
+            if let Some(policy) = policy
+                && cfg.advertise_policy.as_deref() != Some(policy)
+                && cfg.condition_policy.as_deref() != Some(policy)
+            {
+                continue;
+            }
+
+            let advertise_policy_changed = policy.is_none_or(|policy| {
+                cfg.advertise_policy.as_deref() == Some(policy)
+            });
+            match afi_safi {
+                AfiSafi::Ipv4Unicast => {
+                    self.cond_adv_reevaluate::<Ipv4Unicast>(
+                        instance,
+                        &cfg,
+                        advertise_policy_changed,
+                    );
+                }
+                AfiSafi::Ipv6Unicast => {
+                    self.cond_adv_reevaluate::<Ipv6Unicast>(
+                        instance,
+                        &cfg,
+                        advertise_policy_changed,
+                    );
+                }
+            }
+        }
+    }
+
+    // Re-evaluates the advertisement condition after a routing policy change,
+    // updating the advertised routes only when necessary.
+    fn cond_adv_reevaluate<A>(
+        &mut self,
+        instance: &mut InstanceUpView<'_>,
+        cfg: &CondAdvCfg,
+        advertise_policy_changed: bool,
+    ) where
+        A: AddressFamily,
+    {
+        let matched = Self::cond_adv_matched::<A>(instance, cfg);
+        let Some(state) = self.cond_adv.get_mut(&A::AFI_SAFI) else {
+            return;
+        };
+        let advertise = CondAdvState::evaluate(&matched, cfg.condition);
+        let old_advertise = std::mem::replace(&mut state.advertise, advertise);
+        state.matched = matched;
+
+        if self.state != fsm::State::Established {
+            return;
+        }
+        if !old_advertise && advertise_policy_changed {
+            // The set of suppressed routes might have changed, so all routes
+            // need to be re-evaluated.
+            self.readvertise_adj_rib_out::<A>(instance, false);
+        } else if old_advertise != advertise {
+            // Advertise or withdraw the routes subject to the condition.
+            self.readvertise_adj_rib_out::<A>(instance, true);
+        }
+    }
+
+    // Returns the Loc-RIB prefixes accepted by the condition policy.
+    fn cond_adv_matched<A>(
+        instance: &mut InstanceUpView<'_>,
+        cfg: &CondAdvCfg,
+    ) -> BTreeSet<IpNetwork>
+    where
+        A: AddressFamily,
+    {
+        let condition_policy = cfg.condition_policy.as_ref().unwrap();
+        let table = A::table(&mut instance.state.rib.tables);
+        table
+            .prefixes
+            .iter()
+            .filter_map(|(prefix, dest)| {
+                dest.local.as_ref().map(|route| (prefix, route))
+            })
+            .filter(|(prefix, route)| {
+                policy::policy_match(
+                    instance.shared,
+                    A::AFI_SAFI,
+                    (*prefix).into(),
+                    route.policy_info(),
+                    condition_policy,
+                )
+            })
+            .map(|(prefix, _)| prefix.into())
+            .collect()
+    }
+
+    // Returns whether the given route can be advertised according to the
+    // conditional advertisement state.
+    pub(crate) fn cond_adv_permits(
+        &self,
+        shared: &InstanceShared,
+        afi_safi: AfiSafi,
+        prefix: IpNetwork,
+        rpinfo: RoutePolicyInfo,
+    ) -> bool {
+        let Some(state) = self.cond_adv.get(&afi_safi) else {
+            return true;
+        };
+        if state.advertise {
+            return true;
+        }
+
+        !self.cond_adv_subject(shared, afi_safi, prefix, rpinfo)
+    }
+
+    // Returns whether the given route is subject to conditional
+    // advertisement, that is, accepted by the advertise policy.
+    fn cond_adv_subject(
+        &self,
+        shared: &InstanceShared,
+        afi_safi: AfiSafi,
+        prefix: IpNetwork,
+        rpinfo: RoutePolicyInfo,
+    ) -> bool {
+        let Some(cfg) = self.cond_adv_cfg(afi_safi) else {
+            return false;
+        };
+        let advertise_policy = cfg.advertise_policy.as_ref().unwrap();
+        policy::policy_match(shared, afi_safi, prefix, rpinfo, advertise_policy)
+    }
+
+    // Returns the conditional advertisement configuration for the given
+    // address-family, provided it's complete.
+    fn cond_adv_cfg(&self, afi_safi: AfiSafi) -> Option<CondAdvCfg> {
+        let cfg = &self.config.afi_safi.get(&afi_safi)?.cond_adv;
+        if cfg.advertise_policy.is_none() || cfg.condition_policy.is_none() {
+            return None;
+        }
+        Some(cfg.clone())
+    }
+
+    // Re-advertises the Adj-RIB-Out according to the conditional
+    // advertisement state and the neighbor's ORF, withdrawing suppressed
+    // routes.
+    //
+    // When `subject_only` is set, only the routes accepted by the advertise
+    // policy are considered.
+    pub(crate) fn readvertise_adj_rib_out<A>(
+        &mut self,
+        instance: &mut InstanceUpView<'_>,
+        subject_only: bool,
+    ) where
+        A: AddressFamily,
+    {
+        let advertise = self
+            .cond_adv
+            .get(&A::AFI_SAFI)
+            .is_none_or(|state| state.advertise);
+        let table = A::table(&mut instance.state.rib.tables);
+        for (prefix, dest) in &table.prefixes {
+            let Some(adj_rib) = dest.adj_rib.get(&self.remote_addr) else {
+                continue;
+            };
+            let Some(route) = adj_rib.out_post() else {
+                continue;
+            };
+
+            let subject = self.cond_adv_subject(
+                instance.shared,
+                A::AFI_SAFI,
+                prefix.into(),
+                route.policy_info(),
+            );
+            if subject_only && !subject {
+                continue;
+            }
+
+            if (subject && !advertise)
+                || !self.orf_permits(A::AFI_SAFI, &prefix.into())
+            {
+                let update_queue = A::update_queue(&mut self.update_queues);
+                update_queue.unreach.insert(prefix);
+            } else {
+                // Update route's attributes before transmission.
+                let mut attrs = route.attrs.get();
+                rib::attrs_tx_update::<A>(
+                    &mut attrs,
+                    self,
+                    instance.config.asn,
+                    route.origin.is_local(),
+                    &table.nht,
+                );
+                let update_queue = A::update_queue(&mut self.update_queues);
+                update_queue.reach.entry(attrs).or_default().insert(prefix);
+            }
+        }
+
+        // Send UPDATE message(s) to the neighbor.
+        let msg_list = self.update_queues.build_updates(
+            self.remote_addr,
+            &mut instance.state.rib,
+            self.max_msg_len(),
+        );
+        if !msg_list.is_empty() {
+            self.message_list_send(msg_list);
+        }
+    }
+
+    // Returns whether the given prefix can be advertised according to the
+    // Address Prefix ORF received from the neighbor.
+    //
+    // Entries are evaluated in ascending sequence order, and the first match
+    // determines the result. Prefixes not matching any entry are filtered.
+    pub(crate) fn orf_permits(
+        &self,
+        afi_safi: AfiSafi,
+        prefix: &IpNetwork,
+    ) -> bool {
+        let Some(entries) = self.orf_rcvd.get(&afi_safi) else {
+            return true;
+        };
+        if entries.is_empty() {
+            return true;
+        }
+
+        entries
+            .values()
+            .find(|entry| entry.matches(prefix))
+            .is_some_and(|entry| entry.permit)
+    }
+
+    // Updates the received Address Prefix ORF, returning whether it was
+    // accepted.
+    pub(crate) fn orf_process(
+        &mut self,
+        afi_safi: AfiSafi,
+        orf: RouteRefreshOrf,
+    ) -> bool {
+        // RFC 5291 - Section 5:
+        // ORF entries can only be received if the "Receive" side was
+        // advertised by the local speaker and the "Send" side by the
+        // neighbor.
+        let (afi, safi) = afi_safi_split(afi_safi);
+        if orf.orf_type != OrfType::AddressPrefix
+            || !orf_mode_advertised(
+                &self.capabilities_adv,
+                afi,
+                safi,
+                OrfMode::Receive,
+            )
+            || !orf_mode_advertised(
+                &self.capabilities_rcvd,
+                afi,
+                safi,
+                OrfMode::Send,
+            )
+        {
+            return false;
+        }
+
+        let entries = self.orf_rcvd.entry(afi_safi).or_default();
+        for entry in orf.entries {
+            match entry {
+                OrfEntry::Add(entry) => {
+                    entries.insert(entry.seqno, entry);
+                }
+                OrfEntry::Remove(entry) => {
+                    entries.remove(&entry.seqno);
+                }
+                OrfEntry::RemoveAll => {
+                    entries.clear();
+                }
+            }
+        }
+
+        true
+    }
+
+    // Sends the prefix sets accepted by the import policy to the neighbor as
+    // Address Prefix ORF entries, provided they have changed since they were
+    // last sent.
+    pub(crate) fn orf_send(
+        &mut self,
+        shared: &InstanceShared,
+        afi_safi: AfiSafi,
+    ) {
+        let (afi, safi) = afi_safi_split(afi_safi);
+        if !orf_mode_advertised(
+            &self.capabilities_adv,
+            afi,
+            safi,
+            OrfMode::Send,
+        ) || !orf_mode_advertised(
+            &self.capabilities_rcvd,
+            afi,
+            safi,
+            OrfMode::Receive,
+        ) {
+            return;
+        }
+
+        // Build the ORF entries from the prefix sets matched by the import
+        // policy.
+        let apply_policy_cfg = self.apply_policy_cfg(afi_safi);
+        let policies = apply_policy_cfg
+            .import_policy
+            .iter()
+            .filter_map(|policy| shared.policies.get(policy).cloned())
+            .collect::<Vec<_>>();
+        let ranges = policy::import_prefix_ranges(
+            afi,
+            &policies,
+            &shared.policy_match_sets,
+            apply_policy_cfg.default_import_policy,
+        );
+        let entries = orf_entries(afi, ranges);
+        if self.orf_sent.get(&afi_safi) == Some(&entries) {
+            return;
+        }
+
+        // Replace all ORF entries previously sent, splitting them across
+        // multiple messages if necessary. The neighbor is asked to
+        // re-advertise its routes only after receiving the last message.
+        let max_entries = (Message::MAX_LEN
+            - RouteRefreshMsg::LEN
+            - RouteRefreshOrf::HDR_LEN) as usize
+            / PrefixOrfEntry::MAX_LEN;
+        let mut orf_entries = std::iter::once(OrfEntry::RemoveAll)
+            .chain(entries.iter().cloned().map(OrfEntry::Add))
+            .collect::<Vec<_>>();
+        let mut msg_list = vec![];
+        while !orf_entries.is_empty() {
+            let chunk: Vec<_> = orf_entries
+                .drain(..max_entries.min(orf_entries.len()))
+                .collect();
+            let when_to_refresh = if orf_entries.is_empty() {
+                OrfWhenToRefresh::Immediate
+            } else {
+                OrfWhenToRefresh::Defer
+            };
+            msg_list.push(Message::RouteRefresh(RouteRefreshMsg {
+                afi: afi as u16,
+                safi: safi as u8,
+                orf: Some(RouteRefreshOrf {
+                    when_to_refresh,
+                    orf_type: OrfType::AddressPrefix,
+                    entries: chunk,
+                }),
+            }));
+        }
+        self.message_list_send(msg_list);
+        self.orf_sent.insert(afi_safi, entries);
+    }
+
+    // Clears the Adj-RIB-In and Adj-RIB-Out for the given address family.
+    fn clear_routes<A>(&mut self, rib: &mut Rib, ibus_tx: &IbusChannelsTx)
+    where
+        A: AddressFamily,
+    {
+        // Long-lived stale routes are retained in the Adj-RIB-In.
+        let retain_stale = self.llgr_stale.contains_key(&A::AFI_SAFI);
+
+        let table = A::table(&mut rib.tables);
+        for (prefix, dest) in table.prefixes.iter_mut() {
+            if retain_stale {
+                // Clear the Adj-RIB-Out only.
+                if let Some(adj_rib) = dest.adj_rib.get_mut(&self.remote_addr) {
+                    adj_rib.remove_out_pre(&mut rib.attr_sets);
+                    adj_rib.remove_out_post(&mut rib.attr_sets);
+                }
+                continue;
+            }
+
+            // Clear the Adj-RIB-In and Adj-RIB-Out.
+            if let Some(mut adj_rib) = dest.adj_rib.remove(&self.remote_addr) {
+                // Update nexthop tracking.
+                if let Some(adj_in_route) = adj_rib.in_post() {
+                    rib::nexthop_untrack(
+                        &mut table.nht,
+                        &prefix,
+                        adj_in_route,
+                        ibus_tx,
+                    );
+                }
+
+                adj_rib.remove_in_pre(&mut rib.attr_sets);
+                adj_rib.remove_in_post(&mut rib.attr_sets);
+                adj_rib.remove_out_pre(&mut rib.attr_sets);
+                adj_rib.remove_out_post(&mut rib.attr_sets);
+            }
+
+            // Enqueue prefix for the BGP Decision Process.
+            table.queued_prefixes.insert(prefix);
+        }
+    }
+
+    // Clears the neighbor session.
+    pub(crate) fn clear_session(
+        &mut self,
+        instance: &mut InstanceUpView<'_>,
+        clear_type: ClearType,
+    ) {
+        match clear_type {
+            ClearType::Admin => {
+                // Close the session with the "Administrative Reset" subcode.
+                let msg = NotificationMsg::new(
+                    ErrorCode::Cease,
+                    CeaseSubcode::AdministrativeReset,
+                );
+                self.fsm_event(instance, fsm::Event::Stop(Some(msg)));
+            }
+            ClearType::Hard => {
+                // Close the session with the "Hard Reset" subcode.
+                let msg = NotificationMsg::new(
+                    ErrorCode::Cease,
+                    CeaseSubcode::HardReset,
+                );
+                self.fsm_event(instance, fsm::Event::Stop(Some(msg)));
+            }
+            ClearType::Soft => {
+                // Re-send the current Adj-RIB-Out to this neighbor.
+                self.resend_adj_rib_out::<Ipv4Unicast>(instance);
+                self.resend_adj_rib_out::<Ipv6Unicast>(instance);
+                let msg_list = self.update_queues.build_updates(
+                    self.remote_addr,
+                    &mut instance.state.rib,
+                    self.max_msg_len(),
+                );
+                if !msg_list.is_empty() {
+                    self.message_list_send(msg_list);
+                }
+            }
+            ClearType::SoftInbound => {
+                // Request the Adj-RIB-In for this neighbor to be re-sent.
+                for (afi, safi) in self
+                    .capabilities_nego
+                    .iter()
+                    .filter_map(|cap| {
+                        if let NegotiatedCapability::MultiProtocol {
+                            afi,
+                            safi,
+                        } = cap
                         {
                             Some((afi.to_u16().unwrap(), safi.to_u8().unwrap()))
                         } else {
@@ -1050,7 +2091,11 @@ impl Neighbor {
                     })
                     .collect::<Vec<_>>()
                 {
-                    let msg = RouteRefreshMsg { afi, safi };
+                    let msg = RouteRefreshMsg {
+                        afi,
+                        safi,
+                        orf: None,
+                    };
                     self.message_send(Message::RouteRefresh(msg));
                 }
             }
@@ -1079,6 +2124,13 @@ impl Neighbor {
             return false;
         }
 
+        // RFC 9494 - Section 4.6:
+        // "A BGP speaker MUST NOT advertise LLGR_STALE routes to a neighbor
+        // that has not advertised the LLGR Capability".
+        if route.is_llgr_stale() && !self.llgr_capable() {
+            return false;
+        }
+
         // Handle well-known communities.
         if let Some(comm) = &route.attrs.comm {
             for comm in comm
@@ -1146,13 +2198,50 @@ impl MessageStatistics {
     }
 }
 
+// ===== impl CondAdvState =====
+
+impl CondAdvState {
+    fn new(
+        matched: BTreeSet<IpNetwork>,
+        condition: CondAdvCondition,
+    ) -> CondAdvState {
+        let advertise = CondAdvState::evaluate(&matched, condition);
+        CondAdvState { matched, advertise }
+    }
+
+    fn evaluate(
+        matched: &BTreeSet<IpNetwork>,
+        condition: CondAdvCondition,
+    ) -> bool {
+        match condition {
+            CondAdvCondition::Exist => !matched.is_empty(),
+            CondAdvCondition::NonExist => matched.is_empty(),
+        }
+    }
+}
+
 // ===== impl NeighborUpdateQueues =====
 
 impl NeighborUpdateQueues {
-    pub(crate) fn build_updates(&mut self) -> Vec<Message> {
+    pub(crate) fn build_updates(
+        &mut self,
+        nbr_addr: IpAddr,
+        rib: &mut Rib,
+        max_len: u16,
+    ) -> Vec<Message> {
         [
-            self.ipv4_unicast.build_updates(),
-            self.ipv6_unicast.build_updates(),
+            self.ipv4_unicast.build_updates(
+                [nbr_addr],
+                &mut rib.tables.ipv4_unicast,
+                &mut rib.attr_sets,
+                max_len,
+            ),
+            self.ipv6_unicast.build_updates(
+                [nbr_addr],
+                &mut rib.tables.ipv6_unicast,
+                &mut rib.attr_sets,
+                max_len,
+            ),
         ]
         .concat()
     }
@@ -1164,8 +2253,30 @@ impl<A> NeighborUpdateQueue<A>
 where
     A: AddressFamily,
 {
-    fn build_updates(&mut self) -> Vec<Message> {
-        A::build_updates(self)
+    // Builds the UPDATE messages pending in the queue.
+    //
+    // Prefixes withdrawn because their path attributes don't fit in a message
+    // are removed from the post-policy Adj-RIB-Out of the given neighbors,
+    // so they aren't advertised again later (e.g. on a route refresh).
+    pub(crate) fn build_updates(
+        &mut self,
+        nbr_addrs: impl IntoIterator<Item = IpAddr> + Clone,
+        table: &mut RoutingTable<A>,
+        attr_sets: &mut AttrSetsCxt,
+        max_len: u16,
+    ) -> Vec<Message> {
+        let (msgs, oversized) = A::build_updates(self, max_len);
+        for prefix in oversized {
+            let Some(dest) = table.prefixes.get_mut(&prefix) else {
+                continue;
+            };
+            for nbr_addr in nbr_addrs.clone() {
+                if let Some(adj_rib) = dest.adj_rib.get_mut(&nbr_addr) {
+                    adj_rib.remove_out_post(attr_sets);
+                }
+            }
+        }
+        msgs
     }
 }
 
@@ -1180,3 +2291,266 @@ where
         }
     }
 }
+
+// ===== helper functions =====
+
+// Returns the AFI and SAFI corresponding to the given address-family.
+fn afi_safi_split(afi_safi: AfiSafi) -> (Afi, Safi) {
+    match afi_safi {
+        AfiSafi::Ipv4Unicast => (Ipv4Unicast::AFI, Ipv4Unicast::SAFI),
+        AfiSafi::Ipv6Unicast => (Ipv6Unicast::AFI, Ipv6Unicast::SAFI),
+    }
+}
+
+// Builds the Address Prefix ORF entries corresponding to the prefix ranges
+// accepted by the import policy.
+fn orf_entries(
+    afi: Afi,
+    ranges: Option<BTreeSet<IpPrefixRange>>,
+) -> Vec<PrefixOrfEntry> {
+    match ranges {
+        // The import policy might accept any prefix, so the neighbor
+        // is asked to send all its routes.
+        None => vec![],
+        // The import policy doesn't accept any prefix, so the neighbor
+        // is asked not to send any route.
+        Some(ranges) if ranges.is_empty() => vec![PrefixOrfEntry {
+            seqno: 5,
+            permit: false,
+            min_len: 0,
+            max_len: afi.max_prefixlen(),
+            prefix: IpNetwork::default(afi),
+        }],
+        Some(ranges) => ranges
+            .into_iter()
+            .zip((1..).map(|idx| idx * 5))
+            .map(|(range, seqno)| {
+                let plen = range.prefix.prefix();
+                let min_len = if range.masklen_lower == plen {
+                    0
+                } else {
+                    range.masklen_lower
+                };
+                let max_len = if range.masklen_lower == plen
+                    && range.masklen_upper == plen
+                {
+                    0
+                } else {
+                    range.masklen_upper
+                };
+                PrefixOrfEntry {
+                    seqno,
+                    permit: true,
+                    min_len,
+                    max_len,
+                    prefix: range.prefix,
+                }
+            })
+            .collect(),
+    }
+}
+
+// Returns the route to be advertised for the given Loc-RIB best route.
+fn loc_rib_route(route: &LocalRoute) -> Box<Route> {
+    Box::new(Route {
+        origin: route.origin,
+        attrs: route.attrs.clone(),
+        route_type: route.route_type,
+        igp_cost: None,
+        last_modified: route.last_modified,
+        ineligible_reason: None,
+        reject_reason: None,
+        aspa_state: route.aspa_state,
+        llgr_stale: false,
+    })
+}
+
+// Returns a copy of the given route marked as a long-lived stale route, with
+// the LLGR_STALE community attached.
+fn llgr_stale_route(route: &Route, attr_sets: &mut AttrSetsCxt) -> Box<Route> {
+    let mut attrs = route.attrs.get();
+    attrs
+        .comm
+        .get_or_insert_with(|| CommList(Default::default()))
+        .0
+        .insert(Comm(WellKnownCommunities::LlgrStale as u32));
+    let mut route = route.clone();
+    route.attrs = attr_sets.get_route_attr_sets(&attrs);
+    route.llgr_stale = true;
+    Box::new(route)
+}
+
+// Checks whether the given side of the Address Prefix ORF capability is
+// present in the provided set of capabilities.
+fn orf_mode_advertised(
+    capabilities: &BTreeSet<Capability>,
+    afi: Afi,
+    safi: Safi,
+    mode: OrfMode,
+) -> bool {
+    capabilities
+        .iter()
+        .filter_map(Capability::as_orf)
+        .flatten()
+        .any(|tuple| {
+            tuple.afi == afi
+                && tuple.safi == safi
+                && tuple.orf_type == OrfType::AddressPrefix
+                && (tuple.mode == mode || tuple.mode == OrfMode::Both)
+        })
+}
+
+// ===== unit tests =====
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cond_adv_evaluate() {
+        let empty = BTreeSet::new();
+        let matched = BTreeSet::from(["10.0.0.0/8".parse().unwrap()]);
+
+        assert!(!CondAdvState::evaluate(&empty, CondAdvCondition::Exist));
+        assert!(CondAdvState::evaluate(&matched, CondAdvCondition::Exist));
+        assert!(CondAdvState::evaluate(&empty, CondAdvCondition::NonExist));
+        assert!(!CondAdvState::evaluate(
+            &matched,
+            CondAdvCondition::NonExist
+        ));
+    }
+
+    #[test]
+    fn orf_permits() {
+        let entry =
+            |seqno, permit, min_len, max_len, prefix: &str| PrefixOrfEntry {
+                seqno,
+                permit,
+                min_len,
+                max_len,
+                prefix: prefix.parse().unwrap(),
+            };
+        let mut nbr = Neighbor::new(
+            IpAddr::V4(Ipv4Addr::new(1, 1, 1, 1)),
+            PeerType::External,
+        );
+        let afi_safi = AfiSafi::Ipv4Unicast;
+        let permits = |nbr: &Neighbor, prefix: &str| {
+            nbr.orf_permits(afi_safi, &prefix.parse().unwrap())
+        };
+
+        // No ORF received.
+        assert!(permits(&nbr, "192.168.0.0/24"));
+
+        nbr.orf_rcvd.insert(
+            afi_safi,
+            [
+                (5, entry(5, false, 0, 0, "10.1.0.0/16")),
+                (10, entry(10, true, 16, 24, "10.0.0.0/8")),
+                (15, entry(15, true, 0, 0, "172.16.0.0/12")),
+            ]
+            .into(),
+        );
+        assert!(!permits(&nbr, "10.1.0.0/16"));
+        assert!(permits(&nbr, "10.1.1.0/24"));
+        assert!(permits(&nbr, "10.2.0.0/16"));
+        assert!(!permits(&nbr, "10.2.0.0/25"));
+        assert!(!permits(&nbr, "10.0.0.0/8"));
+        assert!(permits(&nbr, "172.16.0.0/12"));
+        assert!(!permits(&nbr, "172.16.0.0/16"));
+        assert!(!permits(&nbr, "192.168.0.0/24"));
+    }
+
+    #[test]
+    fn orf_entries() {
+        let range =
+            |prefix: &str, masklen_lower, masklen_upper| IpPrefixRange {
+                prefix: prefix.parse().unwrap(),
+                masklen_lower,
+                masklen_upper,
+            };
+
+        // Import policy accepting any prefix.
+        assert!(super::orf_entries(Afi::Ipv4, None).is_empty());
+
+        // Import policy not accepting any prefix.
+        let entries = super::orf_entries(Afi::Ipv6, Some(BTreeSet::new()));
+        assert_eq!(
+            entries,
+            vec![PrefixOrfEntry {
+                seqno: 5,
+                permit: false,
+                min_len: 0,
+                max_len: 128,
+                prefix: "::/0".parse().unwrap(),
+            }]
+        );
+        assert!(entries[0].matches(&"2001:db8::/32".parse().unwrap()));
+        assert!(entries[0].matches(&"::/0".parse().unwrap()));
+
+        // Import policy accepting a set of prefix ranges.
+        let entries = super::orf_entries(
+            Afi::Ipv4,
+            Some(
+                [range("10.0.0.0/8", 16, 24), range("172.16.0.0/12", 12, 12)]
+                    .into(),
+            ),
+        );
+        assert_eq!(
+            entries,
+            vec![
+                PrefixOrfEntry {
+                    seqno: 5,
+                    permit: true,
+                    min_len: 16,
+                    max_len: 24,
+                    prefix: "10.0.0.0/8".parse().unwrap(),
+                },
+                PrefixOrfEntry {
+                    seqno: 10,
+                    permit: true,
+                    min_len: 0,
+                    max_len: 0,
+                    prefix: "172.16.0.0/12".parse().unwrap(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn build_updates_extended_message() {
+        use crate::packet::attribute::BaseAttrs;
+
+        let attrs = Attrs {
+            base: BaseAttrs {
+                nexthop: Some(IpAddr::V4(Ipv4Addr::new(1, 1, 1, 1))),
+                local_pref: Some(100),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let prefixes: BTreeSet<_> = (0..4000u32)
+            .map(|i| {
+                let addr = Ipv4Addr::from(0x0a000000 + (i << 8));
+                ipnetwork::Ipv4Network::new(addr, 24).unwrap()
+            })
+            .collect();
+        let cxt = EncodeCxt {
+            capabilities: Default::default(),
+        };
+
+        for (max_len, num_msgs) in
+            [(Message::MAX_LEN, 5), (Message::MAX_LEN_EXT, 1)]
+        {
+            let mut queue = NeighborUpdateQueue::<Ipv4Unicast> {
+                reach: [(attrs.clone(), prefixes.clone())].into(),
+                unreach: Default::default(),
+            };
+            let (msgs, _) = Ipv4Unicast::build_updates(&mut queue, max_len);
+            assert_eq!(msgs.len(), num_msgs);
+            for msg in &msgs {
+                assert!(msg.encode(&cxt).len() <= max_len as usize);
+            }
+        }
+    }
+}
@@ -7,7 +7,9 @@
 use std::collections::BTreeSet;
 use std::net::{IpAddr, SocketAddr};
 use std::sync::Arc;
+use std::sync::atomic::{self, AtomicU32};
 
+use bytes::Bytes;
 use holo_utils::capabilities;
 use holo_utils::ip::{AddressFamily, IpAddrExt, IpAddrKind};
 use holo_utils::socket::{
@@ -19,7 +21,9 @@ use tokio::sync::mpsc::error::SendError;
 use tokio::sync::mpsc::{Sender, UnboundedReceiver};
 
 use crate::error::{Error, IoError, NbrRxError};
-use crate::packet::message::{DecodeCxt, EncodeCxt, Message};
+use crate::packet::message::{
+    DecodeCxt, EncodeCxt, Message, NegotiatedCapability,
+};
 use crate::tasks::messages::input::{NbrRxMsg, TcpAcceptMsg};
 use crate::tasks::messages::output::NbrTxMsg;
 
@@ -206,8 +210,10 @@ pub(crate) async fn nbr_write_loop(
     mut stream: OwnedWriteHalf,
     mut cxt: EncodeCxt,
     mut nbr_msg_txc: UnboundedReceiver<NbrTxMsg>,
+    msg_tx_backlog: Arc<AtomicU32>,
 ) {
     while let Some(msg) = nbr_msg_txc.recv().await {
+        let msg_count = msg.msg_count();
         match msg {
             // Send message to the peer.
             NbrTxMsg::SendMessage { msg, .. } => {
@@ -225,9 +231,16 @@ pub(crate) async fn nbr_write_loop(
                     }
                 }
             }
+            // Send list of pre-encoded messages to the peer.
+            NbrTxMsg::SendEncodedList { buf, .. } => {
+                if let Err(error) = stream.write_all(&buf).await {
+                    IoError::TcpSendError(error).log();
+                }
+            }
             // Update negotiated capabilities.
             NbrTxMsg::UpdateCapabilities(caps) => cxt.capabilities = caps,
         }
+        msg_tx_backlog.fetch_sub(msg_count, atomic::Ordering::Relaxed);
     }
 }
 
@@ -236,6 +249,7 @@ pub(crate) async fn nbr_read_loop(
     mut stream: OwnedReadHalf,
     nbr_addr: IpAddr,
     mut cxt: DecodeCxt,
+    capabilities_local: BTreeSet<NegotiatedCapability>,
     nbr_msg_rxp: Sender<NbrRxMsg>,
 ) -> Result<(), SendError<NbrRxMsg>> {
     const BUF_SIZE: usize = 65535;
@@ -250,6 +264,7 @@ pub(crate) async fn nbr_read_loop(
                 let msg = NbrRxMsg {
                     nbr_addr,
                     msg: Err(NbrRxError::TcpConnClosed),
+                    raw: None,
                 };
                 nbr_msg_rxp.send(msg).await?;
                 return Ok(());
@@ -263,23 +278,31 @@ pub(crate) async fn nbr_read_loop(
 
         // Decode message(s).
         while let Some(msg_size) = Message::get_message_len(&data) {
-            let msg = Message::decode(&data[0..msg_size], &cxt)
-                .map_err(NbrRxError::MsgDecodeError);
+            let raw = Bytes::copy_from_slice(&data[0..msg_size]);
+            let msg =
+                Message::decode(&raw, &cxt).map_err(NbrRxError::MsgDecodeError);
             data.drain(..msg_size);
 
-            // Keep track of received capabilities as they influence how some
-            // messages should be decoded.
+            // Keep track of negotiated capabilities as they influence how
+            // some messages should be decoded (e.g. the maximum message
+            // length is raised only when both sides support the Extended
+            // Message capability).
             if let Ok(Message::Open(msg)) = &msg {
                 let capabilities = msg
                     .capabilities
                     .iter()
                     .map(|cap| cap.as_negotiated())
+                    .filter(|cap| capabilities_local.contains(cap))
                     .collect::<BTreeSet<_>>();
                 cxt.capabilities = capabilities;
             }
 
             // Notify that the BGP message was received.
-            let msg = NbrRxMsg { nbr_addr, msg };
+            let msg = NbrRxMsg {
+                nbr_addr,
+                msg,
+                raw: Some(raw),
+            };
             nbr_msg_rxp.send(msg).await?;
         }
     }
@@ -21,9 +21,10 @@ use holo_utils::yang::DataNodeRefExt;
 use holo_yang::TryFromYang;
 
 use crate::af::{Ipv4Unicast, Ipv6Unicast};
+use crate::aspa::{self, AspaRole};
 use crate::instance::{Instance, InstanceUpView};
 use crate::neighbor::{Neighbor, PeerType, fsm};
-use crate::network;
+use crate::{events, network};
 use crate::northbound::yang_gen::bgp;
 use crate::packet::iana::{CeaseSubcode, ErrorCode};
 use crate::packet::message::{Message, NotificationMsg};
@@ -51,8 +52,16 @@ pub enum Event {
     NeighborDelete(IpAddr),
     NeighborReset(IpAddr, NotificationMsg),
     NeighborUpdateAuth(IpAddr),
+    NeighborUpdateGroup(IpAddr),
+    NeighborCondAdvUpdate(IpAddr, AfiSafi),
+    NeighborOrfUpdate(IpAddr),
+    NeighborLlgrUpdate(IpAddr),
+    NeighborAspaUpdate(IpAddr),
     RedistributeIbusSub(Protocol, AddressFamily),
     RedistributeDelete(Protocol, AddressFamily, AfiSafi),
+    GracefulShutdownUpdate,
+    AspaUpdate,
+    MrtUpdate,
     UpdateTraceOptions,
 }
 
@@ -71,6 +80,9 @@ pub struct InstanceCfg {
     pub apply_policy: ApplyPolicyCfg,
     pub afi_safi: BTreeMap<AfiSafi, InstanceAfiSafiCfg>,
     pub reject_as_sets: bool,
+    pub graceful_shutdown: bool,
+    pub aspa: AspaCfg,
+    pub mrt: MrtCfg,
     pub trace_opts: InstanceTraceOptions,
 }
 
@@ -99,6 +111,21 @@ pub struct InstanceAfiSafiCfg {
     pub redistribution: HashMap<Protocol, RedistributionCfg>,
 }
 
+#[derive(Debug)]
+pub struct AspaCfg {
+    pub file: Option<String>,
+    pub rtr_cache_addr: Option<IpAddr>,
+    pub rtr_cache_port: u16,
+}
+
+#[derive(Debug)]
+pub struct MrtCfg {
+    pub directory: Option<String>,
+    pub table_dump_interval: Option<u32>,
+    pub update_log_enabled: bool,
+    pub update_log_rotation_interval: u32,
+}
+
 #[derive(Clone, Copy, Debug)]
 pub enum InstanceTraceOption {
     Events,
@@ -135,6 +162,10 @@ pub struct NeighborCfg {
     pub apply_policy: ApplyPolicyCfg,
     pub prefix_limit: PrefixLimitCfg,
     pub afi_safi: BTreeMap<AfiSafi, NeighborAfiSafiCfg>,
+    pub extended_message: bool,
+    pub aigp: Option<bool>,
+    pub aspa_role: Option<AspaRole>,
+    pub graceful_shutdown: bool,
     pub trace_opts: NeighborTraceOptions,
 }
 
@@ -166,6 +197,35 @@ pub struct NeighborAfiSafiCfg {
     pub prefix_limit: PrefixLimitCfg,
     pub send_default_route: bool,
     pub apply_policy: ApplyPolicyCfg,
+    pub cond_adv: CondAdvCfg,
+    pub orf: OrfCfg,
+    pub llgr: LlgrCfg,
+}
+
+#[derive(Clone, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct CondAdvCfg {
+    pub advertise_policy: Option<String>,
+    pub condition_policy: Option<String>,
+    pub condition: CondAdvCondition,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub enum CondAdvCondition {
+    #[default]
+    Exist,
+    NonExist,
+}
+
+#[derive(Debug, Default)]
+pub struct OrfCfg {
+    pub send: bool,
+    pub receive: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct LlgrCfg {
+    pub enabled: bool,
+    pub stale_time: u32,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -205,7 +265,9 @@ pub struct PrefixLimitCfg {
 }
 
 #[derive(Debug, Default)]
-pub struct RedistributionCfg {}
+pub struct RedistributionCfg {
+    pub aigp: bool,
+}
 
 #[derive(Debug)]
 pub struct AsPathOptions {
@@ -559,6 +621,18 @@ fn load_callbacks() -> Callbacks<Instance> {
             let protocol = Protocol::try_from_yang(&protocol).unwrap();
             ListEntry::Redistribution(afi_safi, protocol)
         })
+        .path(bgp::global::afi_safis::afi_safi::ipv4_unicast::redistribution::aigp::PATH)
+        .modify_apply(|instance, args| {
+            let (afi_safi, protocol) = args.list_entry.into_redistribution().unwrap();
+            let afi_safi = instance.config.afi_safi.get_mut(&afi_safi).unwrap();
+            let redistribution = afi_safi.redistribution.get_mut(&protocol).unwrap();
+
+            let aigp = args.dnode.get_bool();
+            redistribution.aigp = aigp;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::RedistributeIbusSub(protocol, AddressFamily::Ipv4));
+        })
         .path(bgp::global::afi_safis::afi_safi::ipv6_unicast::prefix_limit::max_prefixes::PATH)
         .modify_apply(|instance, args| {
             let afi_safi = args.list_entry.into_afi_safi().unwrap();
@@ -645,6 +719,18 @@ fn load_callbacks() -> Callbacks<Instance> {
             let protocol = Protocol::try_from_yang(&protocol).unwrap();
             ListEntry::Redistribution(afi_safi, protocol)
         })
+        .path(bgp::global::afi_safis::afi_safi::ipv6_unicast::redistribution::aigp::PATH)
+        .modify_apply(|instance, args| {
+            let (afi_safi, protocol) = args.list_entry.into_redistribution().unwrap();
+            let afi_safi = instance.config.afi_safi.get_mut(&afi_safi).unwrap();
+            let redistribution = afi_safi.redistribution.get_mut(&protocol).unwrap();
+
+            let aigp = args.dnode.get_bool();
+            redistribution.aigp = aigp;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::RedistributeIbusSub(protocol, AddressFamily::Ipv6));
+        })
         .path(bgp::global::apply_policy::import_policy::PATH)
         .create_apply(|instance, args| {
             let policy = args.dnode.get_string();
@@ -680,6 +766,91 @@ fn load_callbacks() -> Callbacks<Instance> {
             let reject = args.dnode.get_bool();
             instance.config.reject_as_sets = reject;
         })
+        .path(bgp::global::graceful_shutdown::enabled::PATH)
+        .modify_apply(|instance, args| {
+            let enabled = args.dnode.get_bool();
+            instance.config.graceful_shutdown = enabled;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::GracefulShutdownUpdate);
+        })
+        .path(bgp::global::aspa::file::PATH)
+        .modify_apply(|instance, args| {
+            let file = args.dnode.get_string();
+            instance.config.aspa.file = Some(file);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::AspaUpdate);
+        })
+        .delete_apply(|instance, args| {
+            instance.config.aspa.file = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::AspaUpdate);
+        })
+        .path(bgp::global::aspa::rtr_cache::address::PATH)
+        .modify_apply(|instance, args| {
+            let addr = args.dnode.get_ip();
+            instance.config.aspa.rtr_cache_addr = Some(addr);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::AspaUpdate);
+        })
+        .delete_apply(|instance, args| {
+            instance.config.aspa.rtr_cache_addr = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::AspaUpdate);
+        })
+        .path(bgp::global::aspa::rtr_cache::port::PATH)
+        .modify_apply(|instance, args| {
+            let port = args.dnode.get_u16();
+            instance.config.aspa.rtr_cache_port = port;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::AspaUpdate);
+        })
+        .path(bgp::global::mrt::directory::PATH)
+        .modify_apply(|instance, args| {
+            let directory = args.dnode.get_string();
+            instance.config.mrt.directory = Some(directory);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::MrtUpdate);
+        })
+        .delete_apply(|instance, args| {
+            instance.config.mrt.directory = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::MrtUpdate);
+        })
+        .path(bgp::global::mrt::table_dump_interval::PATH)
+        .modify_apply(|instance, args| {
+            let interval = args.dnode.get_u32();
+            instance.config.mrt.table_dump_interval = Some(interval);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::MrtUpdate);
+        })
+        .delete_apply(|instance, args| {
+            instance.config.mrt.table_dump_interval = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::MrtUpdate);
+        })
+        .path(bgp::global::mrt::update_log::enabled::PATH)
+        .modify_apply(|instance, args| {
+            let enabled = args.dnode.get_bool();
+            instance.config.mrt.update_log_enabled = enabled;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::MrtUpdate);
+        })
+        .path(bgp::global::mrt::update_log::rotation_interval::PATH)
+        .modify_apply(|instance, args| {
+            let interval = args.dnode.get_u32();
+            instance.config.mrt.update_log_rotation_interval = interval;
+        })
         .path(bgp::global::trace_options::flag::PATH)
         .create_apply(|instance, args| {
             let trace_opt = args.dnode.get_string_relative("name").unwrap();
@@ -1074,6 +1245,9 @@ fn load_callbacks() -> Callbacks<Instance> {
 
             let disable = args.dnode.get_bool();
             nbr.config.as_path_options.disable_peer_as_filter = disable;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::NeighborUpdateGroup(nbr_addr));
         })
         .path(bgp::neighbors::neighbor::apply_policy::import_policy::PATH)
         .create_apply(|instance, args| {
@@ -1082,6 +1256,9 @@ fn load_callbacks() -> Callbacks<Instance> {
 
             let policy = args.dnode.get_string();
             nbr.config.apply_policy.import_policy.insert(policy);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::NeighborOrfUpdate(nbr_addr));
         })
         .delete_apply(|instance, args| {
             let nbr_addr = args.list_entry.into_neighbor().unwrap();
@@ -1089,6 +1266,9 @@ fn load_callbacks() -> Callbacks<Instance> {
 
             let policy = args.dnode.get_string();
             nbr.config.apply_policy.import_policy.remove(&policy);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::NeighborOrfUpdate(nbr_addr));
         })
         .path(bgp::neighbors::neighbor::apply_policy::default_import_policy::PATH)
         .modify_apply(|instance, args| {
@@ -1098,6 +1278,9 @@ fn load_callbacks() -> Callbacks<Instance> {
             let default = args.dnode.get_string();
             let default = DefaultPolicyType::try_from_yang(&default).unwrap();
             nbr.config.apply_policy.default_import_policy = default;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::NeighborOrfUpdate(nbr_addr));
         })
         .path(bgp::neighbors::neighbor::apply_policy::export_policy::PATH)
         .create_apply(|instance, args| {
@@ -1106,6 +1289,9 @@ fn load_callbacks() -> Callbacks<Instance> {
 
             let policy = args.dnode.get_string();
             nbr.config.apply_policy.export_policy.insert(policy);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::NeighborUpdateGroup(nbr_addr));
         })
         .delete_apply(|instance, args| {
             let nbr_addr = args.list_entry.into_neighbor().unwrap();
@@ -1113,6 +1299,9 @@ fn load_callbacks() -> Callbacks<Instance> {
 
             let policy = args.dnode.get_string();
             nbr.config.apply_policy.export_policy.remove(&policy);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::NeighborUpdateGroup(nbr_addr));
         })
         .path(bgp::neighbors::neighbor::apply_policy::default_export_policy::PATH)
         .modify_apply(|instance, args| {
@@ -1122,6 +1311,9 @@ fn load_callbacks() -> Callbacks<Instance> {
             let default = args.dnode.get_string();
             let default = DefaultPolicyType::try_from_yang(&default).unwrap();
             nbr.config.apply_policy.default_export_policy = default;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::NeighborUpdateGroup(nbr_addr));
         })
         .path(bgp::neighbors::neighbor::prefix_limit::max_prefixes::PATH)
         .modify_apply(|instance, args| {
@@ -1187,6 +1379,9 @@ fn load_callbacks() -> Callbacks<Instance> {
             let (nbr_addr, afi_safi) = args.list_entry.into_neighbor_afi_safi().unwrap();
             let nbr = instance.neighbors.get_mut(&nbr_addr).unwrap();
             nbr.config.afi_safi.remove(&afi_safi);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::NeighborCondAdvUpdate(nbr_addr, afi_safi));
         })
         .lookup(|_instance, list_entry, dnode| {
             let nbr_addr = list_entry.into_neighbor().unwrap();
@@ -1211,6 +1406,9 @@ fn load_callbacks() -> Callbacks<Instance> {
 
             let policy = args.dnode.get_string();
             afi_safi.apply_policy.import_policy.insert(policy);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::NeighborOrfUpdate(nbr_addr));
         })
         .delete_apply(|instance, args| {
             let (nbr_addr, afi_safi) = args.list_entry.into_neighbor_afi_safi().unwrap();
@@ -1219,6 +1417,9 @@ fn load_callbacks() -> Callbacks<Instance> {
 
             let policy = args.dnode.get_string();
             afi_safi.apply_policy.import_policy.remove(&policy);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::NeighborOrfUpdate(nbr_addr));
         })
         .path(bgp::neighbors::neighbor::afi_safis::afi_safi::apply_policy::default_import_policy::PATH)
         .modify_apply(|instance, args| {
@@ -1229,6 +1430,9 @@ fn load_callbacks() -> Callbacks<Instance> {
             let default = args.dnode.get_string();
             let default = DefaultPolicyType::try_from_yang(&default).unwrap();
             afi_safi.apply_policy.default_import_policy = default;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::NeighborOrfUpdate(nbr_addr));
         })
         .path(bgp::neighbors::neighbor::afi_safis::afi_safi::apply_policy::export_policy::PATH)
         .create_apply(|instance, args| {
@@ -1238,6 +1442,9 @@ fn load_callbacks() -> Callbacks<Instance> {
 
             let policy = args.dnode.get_string();
             afi_safi.apply_policy.export_policy.insert(policy);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::NeighborUpdateGroup(nbr_addr));
         })
         .delete_apply(|instance, args| {
             let (nbr_addr, afi_safi) = args.list_entry.into_neighbor_afi_safi().unwrap();
@@ -1246,6 +1453,9 @@ fn load_callbacks() -> Callbacks<Instance> {
 
             let policy = args.dnode.get_string();
             afi_safi.apply_policy.export_policy.remove(&policy);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::NeighborUpdateGroup(nbr_addr));
         })
         .path(bgp::neighbors::neighbor::afi_safis::afi_safi::apply_policy::default_export_policy::PATH)
         .modify_apply(|instance, args| {
@@ -1256,6 +1466,9 @@ fn load_callbacks() -> Callbacks<Instance> {
             let default = args.dnode.get_string();
             let default = DefaultPolicyType::try_from_yang(&default).unwrap();
             afi_safi.apply_policy.default_export_policy = default;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::NeighborUpdateGroup(nbr_addr));
         })
         .path(bgp::neighbors::neighbor::afi_safis::afi_safi::ipv4_unicast::prefix_limit::max_prefixes::PATH)
         .modify_apply(|instance, args| {
@@ -1391,6 +1604,177 @@ fn load_callbacks() -> Callbacks<Instance> {
             let send = args.dnode.get_bool();
             afi_safi.send_default_route = send;
         })
+        .path(bgp::neighbors::neighbor::extended_message::PATH)
+        .modify_apply(|instance, args| {
+            let nbr_addr = args.list_entry.into_neighbor().unwrap();
+            let nbr = instance.neighbors.get_mut(&nbr_addr).unwrap();
+
+            let enabled = args.dnode.get_bool();
+            nbr.config.extended_message = enabled;
+
+            let event_queue = args.event_queue;
+            let msg = NotificationMsg::new(ErrorCode::Cease, CeaseSubcode::OtherConfigurationChange);
+            event_queue.insert(Event::NeighborReset(nbr.remote_addr, msg));
+        })
+        .path(bgp::neighbors::neighbor::aigp::PATH)
+        .modify_apply(|instance, args| {
+            let nbr_addr = args.list_entry.into_neighbor().unwrap();
+            let nbr = instance.neighbors.get_mut(&nbr_addr).unwrap();
+
+            let enabled = args.dnode.get_bool();
+            nbr.config.aigp = Some(enabled);
+
+            let event_queue = args.event_queue;
+            let msg = NotificationMsg::new(ErrorCode::Cease, CeaseSubcode::OtherConfigurationChange);
+            event_queue.insert(Event::NeighborReset(nbr.remote_addr, msg));
+        })
+        .delete_apply(|instance, args| {
+            let nbr_addr = args.list_entry.into_neighbor().unwrap();
+            let nbr = instance.neighbors.get_mut(&nbr_addr).unwrap();
+
+            nbr.config.aigp = None;
+
+            let event_queue = args.event_queue;
+            let msg = NotificationMsg::new(ErrorCode::Cease, CeaseSubcode::OtherConfigurationChange);
+            event_queue.insert(Event::NeighborReset(nbr.remote_addr, msg));
+        })
+        .path(bgp::neighbors::neighbor::aspa_role::PATH)
+        .modify_apply(|instance, args| {
+            let nbr_addr = args.list_entry.into_neighbor().unwrap();
+            let nbr = instance.neighbors.get_mut(&nbr_addr).unwrap();
+
+            let role = args.dnode.get_string();
+            let role = AspaRole::try_from_yang(&role).unwrap();
+            nbr.config.aspa_role = Some(role);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::NeighborAspaUpdate(nbr.remote_addr));
+        })
+        .delete_apply(|instance, args| {
+            let nbr_addr = args.list_entry.into_neighbor().unwrap();
+            let nbr = instance.neighbors.get_mut(&nbr_addr).unwrap();
+
+            nbr.config.aspa_role = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::NeighborAspaUpdate(nbr.remote_addr));
+        })
+        .path(bgp::neighbors::neighbor::graceful_shutdown::enabled::PATH)
+        .modify_apply(|instance, args| {
+            let nbr_addr = args.list_entry.into_neighbor().unwrap();
+            let nbr = instance.neighbors.get_mut(&nbr_addr).unwrap();
+
+            let enabled = args.dnode.get_bool();
+            nbr.config.graceful_shutdown = enabled;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::GracefulShutdownUpdate);
+        })
+        .path(bgp::neighbors::neighbor::afi_safis::afi_safi::conditional_advertisement::advertise_policy::PATH)
+        .modify_apply(|instance, args| {
+            let (nbr_addr, afi_safi) = args.list_entry.into_neighbor_afi_safi().unwrap();
+            let nbr = instance.neighbors.get_mut(&nbr_addr).unwrap();
+            let afi_safi_cfg = nbr.config.afi_safi.get_mut(&afi_safi).unwrap();
+
+            let policy = args.dnode.get_string();
+            afi_safi_cfg.cond_adv.advertise_policy = Some(policy);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::NeighborCondAdvUpdate(nbr_addr, afi_safi));
+        })
+        .delete_apply(|instance, args| {
+            let (nbr_addr, afi_safi) = args.list_entry.into_neighbor_afi_safi().unwrap();
+            let nbr = instance.neighbors.get_mut(&nbr_addr).unwrap();
+            let afi_safi_cfg = nbr.config.afi_safi.get_mut(&afi_safi).unwrap();
+
+            afi_safi_cfg.cond_adv.advertise_policy = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::NeighborCondAdvUpdate(nbr_addr, afi_safi));
+        })
+        .path(bgp::neighbors::neighbor::afi_safis::afi_safi::conditional_advertisement::condition_policy::PATH)
+        .modify_apply(|instance, args| {
+            let (nbr_addr, afi_safi) = args.list_entry.into_neighbor_afi_safi().unwrap();
+            let nbr = instance.neighbors.get_mut(&nbr_addr).unwrap();
+            let afi_safi_cfg = nbr.config.afi_safi.get_mut(&afi_safi).unwrap();
+
+            let policy = args.dnode.get_string();
+            afi_safi_cfg.cond_adv.condition_policy = Some(policy);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::NeighborCondAdvUpdate(nbr_addr, afi_safi));
+        })
+        .delete_apply(|instance, args| {
+            let (nbr_addr, afi_safi) = args.list_entry.into_neighbor_afi_safi().unwrap();
+            let nbr = instance.neighbors.get_mut(&nbr_addr).unwrap();
+            let afi_safi_cfg = nbr.config.afi_safi.get_mut(&afi_safi).unwrap();
+
+            afi_safi_cfg.cond_adv.condition_policy = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::NeighborCondAdvUpdate(nbr_addr, afi_safi));
+        })
+        .path(bgp::neighbors::neighbor::afi_safis::afi_safi::conditional_advertisement::condition::PATH)
+        .modify_apply(|instance, args| {
+            let (nbr_addr, afi_safi) = args.list_entry.into_neighbor_afi_safi().unwrap();
+            let nbr = instance.neighbors.get_mut(&nbr_addr).unwrap();
+            let afi_safi_cfg = nbr.config.afi_safi.get_mut(&afi_safi).unwrap();
+
+            let condition = args.dnode.get_string();
+            let condition = CondAdvCondition::try_from_yang(&condition).unwrap();
+            afi_safi_cfg.cond_adv.condition = condition;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::NeighborCondAdvUpdate(nbr_addr, afi_safi));
+        })
+        .path(bgp::neighbors::neighbor::afi_safis::afi_safi::orf::send::PATH)
+        .modify_apply(|instance, args| {
+            let (nbr_addr, afi_safi) = args.list_entry.into_neighbor_afi_safi().unwrap();
+            let nbr = instance.neighbors.get_mut(&nbr_addr).unwrap();
+            let afi_safi_cfg = nbr.config.afi_safi.get_mut(&afi_safi).unwrap();
+
+            let send = args.dnode.get_bool();
+            afi_safi_cfg.orf.send = send;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::NeighborOrfUpdate(nbr_addr));
+        })
+        .path(bgp::neighbors::neighbor::afi_safis::afi_safi::orf::receive::PATH)
+        .modify_apply(|instance, args| {
+            let (nbr_addr, afi_safi) = args.list_entry.into_neighbor_afi_safi().unwrap();
+            let nbr = instance.neighbors.get_mut(&nbr_addr).unwrap();
+            let afi_safi_cfg = nbr.config.afi_safi.get_mut(&afi_safi).unwrap();
+
+            let receive = args.dnode.get_bool();
+            afi_safi_cfg.orf.receive = receive;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::NeighborOrfUpdate(nbr_addr));
+        })
+        .path(bgp::neighbors::neighbor::afi_safis::afi_safi::long_lived_graceful_restart::enabled::PATH)
+        .modify_apply(|instance, args| {
+            let (nbr_addr, afi_safi) = args.list_entry.into_neighbor_afi_safi().unwrap();
+            let nbr = instance.neighbors.get_mut(&nbr_addr).unwrap();
+            let afi_safi_cfg = nbr.config.afi_safi.get_mut(&afi_safi).unwrap();
+
+            let enabled = args.dnode.get_bool();
+            afi_safi_cfg.llgr.enabled = enabled;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::NeighborLlgrUpdate(nbr_addr));
+        })
+        .path(bgp::neighbors::neighbor::afi_safis::afi_safi::long_lived_graceful_restart::stale_time::PATH)
+        .modify_apply(|instance, args| {
+            let (nbr_addr, afi_safi) = args.list_entry.into_neighbor_afi_safi().unwrap();
+            let nbr = instance.neighbors.get_mut(&nbr_addr).unwrap();
+            let afi_safi_cfg = nbr.config.afi_safi.get_mut(&afi_safi).unwrap();
+
+            let stale_time = args.dnode.get_u32();
+            afi_safi_cfg.llgr.stale_time = stale_time;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::NeighborLlgrUpdate(nbr_addr));
+        })
         .path(bgp::neighbors::neighbor::trace_options::flag::PATH)
         .create_apply(|instance, args| {
             let nbr_addr = args.list_entry.into_neighbor().unwrap();
@@ -1576,6 +1960,16 @@ impl Provider for Instance {
                     network::listen_socket_md5sig_update(&listener.socket, &nbr_addr, key.as_deref());
                 }
             }
+            Event::NeighborUpdateGroup(nbr_addr) => {
+                let Some((instance, neighbors)) = self.as_up() else {
+                    return;
+                };
+                let nbr = neighbors.get_mut(&nbr_addr).unwrap();
+
+                // Move the neighbor to a different update group if its
+                // outbound parameters have changed.
+                instance.state.update_groups.update(nbr);
+            }
             Event::RedistributeIbusSub(protocol, af) => {
                 self.tx.ibus.route_redistribute_sub(protocol, Some(af));
             }
@@ -1593,6 +1987,127 @@ impl Provider for Instance {
                     }
                 }
             }
+            Event::NeighborCondAdvUpdate(nbr_addr, afi_safi) => {
+                let Some((mut instance, neighbors)) = self.as_up() else {
+                    return;
+                };
+                let Some(nbr) = neighbors.get_mut(&nbr_addr) else {
+                    return;
+                };
+
+                // Re-evaluate the advertisement condition from scratch.
+                match afi_safi {
+                    AfiSafi::Ipv4Unicast => {
+                        nbr.cond_adv_reset::<Ipv4Unicast>(&mut instance);
+                    }
+                    AfiSafi::Ipv6Unicast => {
+                        nbr.cond_adv_reset::<Ipv6Unicast>(&mut instance);
+                    }
+                }
+
+                // Conditional advertisement settings are part of the outbound
+                // parameters of update groups.
+                instance.state.update_groups.update(nbr);
+            }
+            Event::NeighborOrfUpdate(nbr_addr) => {
+                let Some((mut instance, neighbors)) = self.as_up() else {
+                    return;
+                };
+                let Some(nbr) = neighbors.get_mut(&nbr_addr) else {
+                    return;
+                };
+                if nbr.state < fsm::State::OpenSent {
+                    return;
+                }
+
+                // Changes to the ORF capability require the session to be
+                // reset. Otherwise, just send the updated ORF entries.
+                if nbr.capabilities_local(instance.config) != nbr.capabilities_adv {
+                    let msg = NotificationMsg::new(ErrorCode::Cease, CeaseSubcode::OtherConfigurationChange);
+                    nbr.fsm_event(&mut instance, fsm::Event::Stop(Some(msg)));
+                    return;
+                }
+                if nbr.state != fsm::State::Established {
+                    return;
+                }
+                nbr.orf_send(instance.shared, AfiSafi::Ipv4Unicast);
+                nbr.orf_send(instance.shared, AfiSafi::Ipv6Unicast);
+            }
+            Event::NeighborLlgrUpdate(nbr_addr) => {
+                let Some((mut instance, neighbors)) = self.as_up() else {
+                    return;
+                };
+                let Some(nbr) = neighbors.get_mut(&nbr_addr) else {
+                    return;
+                };
+
+                // Flush the long-lived stale routes that are no longer
+                // retained, which are kept even while the session is down.
+                nbr.llgr_stale_update(&mut instance);
+
+                if nbr.state < fsm::State::OpenSent {
+                    return;
+                }
+
+                // Changes to the LLGR capability require the session to be
+                // reset.
+                if nbr.capabilities_local(instance.config) != nbr.capabilities_adv {
+                    let msg = NotificationMsg::new(ErrorCode::Cease, CeaseSubcode::OtherConfigurationChange);
+                    nbr.fsm_event(&mut instance, fsm::Event::Stop(Some(msg)));
+                }
+            }
+            Event::GracefulShutdownUpdate => {
+                let Some((mut instance, neighbors)) = self.as_up() else {
+                    return;
+                };
+
+                for nbr in neighbors.values_mut() {
+                    nbr.graceful_shutdown_update(&mut instance);
+                }
+            }
+            Event::NeighborAspaUpdate(nbr_addr) => {
+                let Some((mut instance, neighbors)) = self.as_up() else {
+                    return;
+                };
+                let Some(nbr) = neighbors.get_mut(&nbr_addr) else {
+                    return;
+                };
+                if nbr.state != fsm::State::Established {
+                    return;
+                }
+
+                // Re-run the import policies with the updated ASPA state.
+                nbr.aspa_revalidate::<Ipv4Unicast>(&mut instance);
+                nbr.aspa_revalidate::<Ipv6Unicast>(&mut instance);
+            }
+            Event::AspaUpdate => {
+                let Some((mut instance, neighbors)) = self.as_up() else {
+                    return;
+                };
+
+                // Restart loading the ASPA records. The current records are
+                // kept until the new ones are received.
+                instance.state.aspa_task = aspa::source_task(
+                    &instance.config.aspa,
+                    &instance.tx.protocol_input.aspa_db_update,
+                );
+
+                // ASPA verification was disabled.
+                if instance.state.aspa_task.is_none() {
+                    events::process_aspa_db_update(
+                        &mut instance,
+                        neighbors,
+                        None,
+                    );
+                }
+            }
+            Event::MrtUpdate => {
+                let Some((instance, _)) = self.as_up() else {
+                    return;
+                };
+
+                instance.state.mrt.update(&instance.config.mrt, instance.tx);
+            }
             Event::UpdateTraceOptions => {
                 for nbr in self.neighbors.values_mut() {
                     let nbr_trace_opts = &nbr.config.trace_opts;
@@ -1707,6 +2222,7 @@ where
 impl Default for InstanceCfg {
     fn default() -> InstanceCfg {
         let reject_as_sets = bgp::global::reject_as_sets::DFLT;
+        let graceful_shutdown = bgp::global::graceful_shutdown::enabled::DFLT;
 
         InstanceCfg {
             asn: 0,
@@ -1717,11 +2233,40 @@ impl Default for InstanceCfg {
             apply_policy: Default::default(),
             afi_safi: Default::default(),
             reject_as_sets,
+            graceful_shutdown,
+            aspa: Default::default(),
+            mrt: Default::default(),
             trace_opts: Default::default(),
         }
     }
 }
 
+impl Default for AspaCfg {
+    fn default() -> AspaCfg {
+        let rtr_cache_port = bgp::global::aspa::rtr_cache::port::DFLT;
+
+        AspaCfg {
+            file: None,
+            rtr_cache_addr: None,
+            rtr_cache_port,
+        }
+    }
+}
+
+impl Default for MrtCfg {
+    fn default() -> MrtCfg {
+        let update_log_enabled = bgp::global::mrt::update_log::enabled::DFLT;
+        let update_log_rotation_interval = bgp::global::mrt::update_log::rotation_interval::DFLT;
+
+        MrtCfg {
+            directory: None,
+            table_dump_interval: None,
+            update_log_enabled,
+            update_log_rotation_interval,
+        }
+    }
+}
+
 impl Default for DistanceCfg {
     fn default() -> DistanceCfg {
         let external = bgp::global::distance::external::DFLT;
@@ -1769,6 +2314,8 @@ impl Default for NeighborCfg {
     fn default() -> NeighborCfg {
         let enabled = bgp::neighbors::neighbor::enabled::DFLT;
         let log_neighbor_state_changes = bgp::neighbors::neighbor::logging_options::log_neighbor_state_changes::DFLT;
+        let extended_message = bgp::neighbors::neighbor::extended_message::DFLT;
+        let graceful_shutdown = bgp::neighbors::neighbor::graceful_shutdown::enabled::DFLT;
 
         NeighborCfg {
             enabled,
@@ -1782,6 +2329,10 @@ impl Default for NeighborCfg {
             apply_policy: Default::default(),
             prefix_limit: Default::default(),
             afi_safi: Default::default(),
+            extended_message,
+            aigp: None,
+            aspa_role: None,
+            graceful_shutdown,
             trace_opts: Default::default(),
         }
     }
@@ -1830,10 +2381,22 @@ impl Default for NeighborAfiSafiCfg {
             prefix_limit: Default::default(),
             send_default_route: false,
             apply_policy: Default::default(),
+            cond_adv: Default::default(),
+            orf: Default::default(),
+            llgr: Default::default(),
         }
     }
 }
 
+impl Default for LlgrCfg {
+    fn default() -> LlgrCfg {
+        let enabled = bgp::neighbors::neighbor::afi_safis::afi_safi::long_lived_graceful_restart::enabled::DFLT;
+        let stale_time = bgp::neighbors::neighbor::afi_safis::afi_safi::long_lived_graceful_restart::stale_time::DFLT;
+
+        LlgrCfg { enabled, stale_time }
+    }
+}
+
 impl Default for RouteSelectionCfg {
     fn default() -> RouteSelectionCfg {
         // TODO: fetch defaults from YANG module
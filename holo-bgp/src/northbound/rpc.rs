@@ -7,6 +7,7 @@
 use holo_northbound::rpc::{Provider, RpcResult, YangOps, YangRpc};
 
 use crate::instance::Instance;
+use crate::mrt;
 use crate::northbound::yang_gen::{self, bgp};
 
 impl Provider for Instance {
@@ -56,3 +57,41 @@ impl YangRpc<Instance> for bgp::neighbors::clear::Clear {
         Ok(())
     }
 }
+
+impl YangRpc<Instance> for bgp::global::mrt::dump::Dump {
+    fn invoke(&mut self, instance: &mut Instance) -> RpcResult {
+        let Some((instance, neighbors)) = instance.as_up() else {
+            return Ok(());
+        };
+
+        mrt::table_dump(&instance, neighbors);
+
+        Ok(())
+    }
+}
+
+impl YangRpc<Instance> for bgp::neighbors::graceful_shutdown::GracefulShutdown {
+    fn invoke(&mut self, instance: &mut Instance) -> RpcResult {
+        let Some((mut instance, neighbors)) = instance.as_up() else {
+            return Ok(());
+        };
+
+        let enable = self.input.enable.unwrap_or(false);
+        match &self.input.remote_addr {
+            Some(remote_addr) => {
+                if let Some(nbr) = neighbors.get_mut(remote_addr) {
+                    nbr.graceful_shutdown_rpc = enable;
+                    nbr.graceful_shutdown_update(&mut instance);
+                }
+            }
+            None => {
+                instance.state.graceful_shutdown = enable;
+                for nbr in neighbors.values_mut() {
+                    nbr.graceful_shutdown_update(&mut instance);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
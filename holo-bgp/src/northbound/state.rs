@@ -21,7 +21,7 @@ use crate::neighbor::{Neighbor, fsm};
 use crate::northbound::yang_gen::{self, bgp};
 use crate::packet::attribute::{AsPathSegment, AttrFlags, BaseAttrs, Comms, ExtComms, Extv6Comms, LargeComms, UnknownAttr};
 use crate::packet::iana::{Afi, Safi};
-use crate::packet::message::{AddPathTuple, Capability};
+use crate::packet::message::{AddPathTuple, Capability, PrefixOrfEntry};
 use crate::rib::{AttrSet, Destination, LocalRoute, Route};
 
 pub static AFI_SAFIS: [AfiSafi; 2] = [AfiSafi::Ipv4Unicast, AfiSafi::Ipv6Unicast];
@@ -85,6 +85,30 @@ impl<'a> YangContainer<'a, Instance> for bgp::global::statistics::Statistics {
     }
 }
 
+impl<'a> YangContainer<'a, Instance> for bgp::global::graceful_shutdown::GracefulShutdown {
+    type ParentListEntry = ();
+
+    fn new(instance: &'a Instance, _: &Self::ParentListEntry) -> Option<Self> {
+        let state = instance.state.as_ref()?;
+        let active = instance.config.graceful_shutdown || state.graceful_shutdown;
+        if !active {
+            return None;
+        }
+        Some(Self { active: Some(active) })
+    }
+}
+
+impl<'a> YangContainer<'a, Instance> for bgp::global::aspa::Aspa {
+    type ParentListEntry = ();
+
+    fn new(instance: &'a Instance, _: &Self::ParentListEntry) -> Option<Self> {
+        let aspa = instance.state.as_ref()?.aspa.as_ref()?;
+        Some(Self {
+            record_count: Some(aspa.records.len() as u32),
+        })
+    }
+}
+
 impl<'a> YangList<'a, Instance> for bgp::neighbors::neighbor::Neighbor<'a> {
     type ParentListEntry = ();
     type ListEntry = &'a Neighbor;
@@ -117,6 +141,19 @@ impl<'a> YangList<'a, Instance> for bgp::neighbors::neighbor::Neighbor<'a> {
     }
 }
 
+impl<'a> YangContainer<'a, Instance> for bgp::neighbors::neighbor::graceful_shutdown::GracefulShutdown {
+    type ParentListEntry = &'a Neighbor;
+
+    fn new(_instance: &'a Instance, nbr: &Self::ParentListEntry) -> Option<Self> {
+        if !nbr.graceful_shutdown {
+            return None;
+        }
+        Some(Self {
+            active: Some(nbr.graceful_shutdown),
+        })
+    }
+}
+
 impl<'a> YangContainer<'a, Instance> for bgp::neighbors::neighbor::timers::Timers {
     type ParentListEntry = &'a Neighbor;
 
@@ -155,6 +192,53 @@ impl<'a> YangList<'a, Instance> for bgp::neighbors::neighbor::afi_safis::afi_saf
     }
 }
 
+impl<'a> YangContainer<'a, Instance> for bgp::neighbors::neighbor::afi_safis::afi_safi::conditional_advertisement::ConditionalAdvertisement {
+    type ParentListEntry = (&'a Neighbor, AfiSafi);
+
+    fn new(_instance: &'a Instance, (nbr, afi_safi): &Self::ParentListEntry) -> Option<Self> {
+        let state = nbr.cond_adv.get(afi_safi)?;
+        Some(Self {
+            advertise: Some(state.advertise),
+        })
+    }
+}
+
+impl<'a> YangContainer<'a, Instance> for bgp::neighbors::neighbor::afi_safis::afi_safi::long_lived_graceful_restart::LongLivedGracefulRestart {
+    type ParentListEntry = (&'a Neighbor, AfiSafi);
+
+    fn new(_instance: &'a Instance, (nbr, afi_safi): &Self::ParentListEntry) -> Option<Self> {
+        let received_stale_time = nbr.llgr_tuple_rcvd(*afi_safi).map(|tuple| tuple.stale_time);
+        let stale_routes = nbr.llgr_stale.contains_key(afi_safi);
+        if received_stale_time.is_none() && !stale_routes {
+            return None;
+        }
+        Some(Self {
+            received_stale_time,
+            stale_routes: Some(stale_routes),
+        })
+    }
+}
+
+impl<'a> YangList<'a, Instance> for bgp::neighbors::neighbor::afi_safis::afi_safi::orf::received_entry::ReceivedEntry<'a> {
+    type ParentListEntry = (&'a Neighbor, AfiSafi);
+    type ListEntry = &'a PrefixOrfEntry;
+
+    fn iter(_instance: &'a Instance, (nbr, afi_safi): &Self::ParentListEntry) -> Option<impl ListIterator<'a, Self::ListEntry>> {
+        let entries = nbr.orf_rcvd.get(afi_safi)?;
+        Some(entries.values())
+    }
+
+    fn new(_instance: &'a Instance, entry: &Self::ListEntry) -> Self {
+        Self {
+            sequence: entry.seqno,
+            action: Some(if entry.permit { "permit" } else { "deny" }.into()),
+            prefix: Some(entry.prefix),
+            min_length: Some(entry.min_len),
+            max_length: Some(entry.max_len),
+        }
+    }
+}
+
 impl<'a> YangContainer<'a, Instance> for bgp::neighbors::neighbor::afi_safis::afi_safi::prefixes::Prefixes {
     type ParentListEntry = (&'a Neighbor, AfiSafi);
 
@@ -591,6 +675,7 @@ impl<'a> YangList<'a, Instance> for bgp::rib::afi_safis::afi_safi::ipv4_unicast:
             eligible_route: None,
             ineligible_reason: None,
             reject_reason: None,
+            aspa_state: route.aspa_state.as_ref().map(|s| s.to_yang()),
         }
     }
 }
@@ -711,6 +796,7 @@ impl<'a> YangList<'a, Instance> for bgp::rib::afi_safis::afi_safi::ipv4_unicast:
             ineligible_reason: route.ineligible_reason.as_ref().map(|r| r.to_yang()),
             best_path: None, // TODO
             reject_reason: route.reject_reason.as_ref().map(|r| r.to_yang()),
+            aspa_state: route.aspa_state.as_ref().map(|s| s.to_yang()),
         }
     }
 }
@@ -865,6 +951,7 @@ impl<'a> YangList<'a, Instance> for bgp::rib::afi_safis::afi_safi::ipv6_unicast:
             eligible_route: None,
             ineligible_reason: None,
             reject_reason: None,
+            aspa_state: route.aspa_state.as_ref().map(|s| s.to_yang()),
         }
     }
 }
@@ -985,6 +1072,7 @@ impl<'a> YangList<'a, Instance> for bgp::rib::afi_safis::afi_safi::ipv6_unicast:
             ineligible_reason: route.ineligible_reason.as_ref().map(|r| r.to_yang()),
             best_path: None, // TODO
             reject_reason: route.reject_reason.as_ref().map(|r| r.to_yang()),
+            aspa_state: route.aspa_state.as_ref().map(|s| s.to_yang()),
         }
     }
 }
@@ -9,8 +9,9 @@ use std::borrow::Cow;
 use holo_yang::{ToYang, TryFromYang};
 use num_traits::FromPrimitive;
 
+use crate::aspa::AspaRole;
 use crate::neighbor::{PeerType, fsm};
-use crate::northbound::configuration::{InstanceTraceOption, NeighborTraceOption, PrivateAsRemove};
+use crate::northbound::configuration::{CondAdvCondition, InstanceTraceOption, NeighborTraceOption, PrivateAsRemove};
 use crate::packet::attribute::AsPathSegmentType;
 use crate::packet::iana::{CapabilityCode, CeaseSubcode, ErrorCode, FsmErrorSubcode, MessageHeaderErrorSubcode, OpenMessageErrorSubcode, RouteRefreshErrorSubcode, Safi, UpdateMessageErrorSubcode};
 use crate::packet::message::{AddPathMode, NotificationMsg};
@@ -67,6 +68,9 @@ impl ToYang for CapabilityCode {
             CapabilityCode::AddPath => "holo-bgp:add-paths".into(),
             CapabilityCode::RouteRefresh => "iana-bgp-types:route-refresh".into(),
             CapabilityCode::EnhancedRouteRefresh => "holo-bgp:enhanced-route-refresh".into(),
+            CapabilityCode::ExtendedMessage => "holo-bgp:extended-message".into(),
+            CapabilityCode::OutboundRouteFiltering => "holo-bgp:orf".into(),
+            CapabilityCode::LongLivedGracefulRestart => "holo-bgp:llgr".into(),
         }
     }
 }
@@ -215,7 +219,9 @@ impl ToYang for RouteIneligibleReason {
 impl ToYang for RouteRejectReason {
     fn to_yang(&self) -> Cow<'static, str> {
         match self {
+            RouteRejectReason::LlgrStale => "holo-bgp:llgr-stale".into(),
             RouteRejectReason::LocalPrefLower => "iana-bgp-rib-types:local-pref-lower".into(),
+            RouteRejectReason::AigpHigher => "holo-bgp:aigp-higher".into(),
             RouteRejectReason::AsPathLonger => "iana-bgp-rib-types:as-path-longer".into(),
             RouteRejectReason::OriginTypeHigher => "iana-bgp-rib-types:origin-type-higher".into(),
             RouteRejectReason::MedHigher => "iana-bgp-rib-types:med-higher".into(),
@@ -240,6 +246,29 @@ impl TryFromYang for PrivateAsRemove {
     }
 }
 
+impl TryFromYang for AspaRole {
+    fn try_from_yang(value: &str) -> Option<AspaRole> {
+        match value {
+            "customer" => Some(AspaRole::Customer),
+            "peer" => Some(AspaRole::Peer),
+            "provider" => Some(AspaRole::Provider),
+            "rs" => Some(AspaRole::Rs),
+            "rs-client" => Some(AspaRole::RsClient),
+            _ => None,
+        }
+    }
+}
+
+impl TryFromYang for CondAdvCondition {
+    fn try_from_yang(value: &str) -> Option<CondAdvCondition> {
+        match value {
+            "exist" => Some(CondAdvCondition::Exist),
+            "non-exist" => Some(CondAdvCondition::NonExist),
+            _ => None,
+        }
+    }
+}
+
 impl TryFromYang for InstanceTraceOption {
     fn try_from_yang(value: &str) -> Option<InstanceTraceOption> {
         match value {
@@ -59,6 +59,7 @@ pub struct BaseAttrs {
     pub atomic_aggregate: Option<()>,
     pub originator_id: Option<Ipv4Addr>,
     pub cluster_list: Option<ClusterList>,
+    pub aigp: Option<u64>,
 }
 
 // BGP Path Attribute Flags.
@@ -260,6 +261,11 @@ impl Attrs {
             extv6_comm.encode(buf);
         }
 
+        // AIGP attribute.
+        if let Some(aigp) = self.base.aigp {
+            aigp::encode(aigp, buf);
+        }
+
         // LARGE_COMMUNITY attribute.
         if let Some(large_comm) = &self.large_comm {
             large_comm.encode(buf);
@@ -298,6 +304,7 @@ impl Attrs {
         let mut atomic_aggregate = None;
         let mut originator_id = None;
         let mut cluster_list = None;
+        let mut aigp = None;
         let mut comm = None;
         let mut ext_comm = None;
         let mut extv6_comm = None;
@@ -473,6 +480,7 @@ impl Attrs {
                 AttrType::Extv6Community => {
                     Extv6Comms::decode(&mut buf, &mut extv6_comm)
                 }
+                AttrType::Aigp => aigp::decode(&mut buf, &mut aigp),
                 AttrType::LargeCommunity => {
                     LargeComms::decode(&mut buf, &mut large_comm)
                 }
@@ -517,6 +525,7 @@ impl Attrs {
                     atomic_aggregate,
                     originator_id,
                     cluster_list,
+                    aigp,
                 },
                 comm,
                 ext_comm,
@@ -570,6 +579,9 @@ impl Attrs {
         if let Some(extv6_comm) = &self.extv6_comm {
             length += extv6_comm.length();
         }
+        if self.base.aigp.is_some() {
+            length += aigp::length();
+        }
         if let Some(large_comm) = &self.large_comm {
             length += large_comm.length();
         }
@@ -1051,6 +1063,65 @@ mod originator_id {
     }
 }
 
+// ===== AIGP attribute =====
+
+mod aigp {
+    use super::*;
+    const LEN: u8 = 11;
+    const TLV_HDR_LEN: u16 = 3;
+    const TLV_TYPE_AIGP: u8 = 1;
+    const TLV_LEN_AIGP: u16 = 11;
+
+    pub(super) fn encode(aigp: u64, buf: &mut BytesMut) {
+        buf.put_u8(AttrFlags::OPTIONAL.bits());
+        buf.put_u8(AttrType::Aigp as u8);
+        buf.put_u8(LEN);
+        buf.put_u8(TLV_TYPE_AIGP);
+        buf.put_u16(TLV_LEN_AIGP);
+        buf.put_u64(aigp);
+    }
+
+    pub(super) fn decode(
+        buf: &mut Bytes,
+        aigp: &mut Option<u64>,
+    ) -> Result<(), AttrError> {
+        let mut value = None;
+        while buf.remaining() > 0 {
+            if buf.remaining() < TLV_HDR_LEN as usize {
+                return Err(AttrError::Discard);
+            }
+            let tlv_type = buf.try_get_u8()?;
+            let tlv_len = buf.try_get_u16()?;
+            if tlv_len < TLV_HDR_LEN
+                || (tlv_len - TLV_HDR_LEN) as usize > buf.remaining()
+            {
+                return Err(AttrError::Discard);
+            }
+            let mut buf_tlv =
+                buf.copy_to_bytes((tlv_len - TLV_HDR_LEN) as usize);
+
+            // RFC 7311 - Section 3:
+            // Only the first AIGP TLV is considered, and unknown TLVs are
+            // ignored.
+            if tlv_type == TLV_TYPE_AIGP {
+                if tlv_len != TLV_LEN_AIGP {
+                    return Err(AttrError::Discard);
+                }
+                if value.is_none() {
+                    value = Some(buf_tlv.try_get_u64()?);
+                }
+            }
+        }
+
+        *aigp = value;
+        Ok(())
+    }
+
+    pub(super) fn length() -> u16 {
+        ATTR_MIN_LEN + LEN as u16
+    }
+}
+
 // ===== impl ClusterList =====
 
 impl ClusterList {
@@ -1244,7 +1315,7 @@ impl MpReachNlri {
 impl MpUnreachNlri {
     pub const MIN_LEN: u16 = 3;
 
-    fn encode(&self, buf: &mut BytesMut) {
+    pub(crate) fn encode(&self, buf: &mut BytesMut) {
         buf.put_u8((AttrFlags::OPTIONAL | AttrFlags::EXTENDED).bits());
         buf.put_u8(AttrType::MpUnreachNlri as u8);
 
@@ -1465,7 +1536,8 @@ fn attribute_flags(attr_type: AttrType) -> AttrFlags {
         | AttrType::OriginatorId
         | AttrType::ClusterList
         | AttrType::MpReachNlri
-        | AttrType::MpUnreachNlri => AttrFlags::OPTIONAL,
+        | AttrType::MpUnreachNlri
+        | AttrType::Aigp => AttrFlags::OPTIONAL,
 
         // Optional transitive.
         AttrType::Aggregator
@@ -15,6 +15,7 @@ pub enum DecodeError {
     MessageHeader(MessageHeaderError),
     OpenMessage(OpenMessageError),
     UpdateMessage(UpdateMessageError),
+    RouteRefreshMessage(RouteRefreshMessageError),
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -53,6 +54,14 @@ pub enum UpdateMessageError {
     InvalidNetworkField,
 }
 
+// ROUTE-REFRESH message errors.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub enum RouteRefreshMessageError {
+    ReadOutOfBounds,
+    InvalidMessageLength,
+}
+
 // Attribute errors.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[derive(Deserialize, Serialize)]
@@ -73,6 +82,7 @@ impl std::fmt::Display for DecodeError {
             DecodeError::MessageHeader(error) => error.fmt(f),
             DecodeError::OpenMessage(error) => error.fmt(f),
             DecodeError::UpdateMessage(error) => error.fmt(f),
+            DecodeError::RouteRefreshMessage(error) => error.fmt(f),
         }
     }
 }
@@ -103,6 +113,12 @@ impl From<UpdateMessageError> for DecodeError {
     }
 }
 
+impl From<RouteRefreshMessageError> for DecodeError {
+    fn from(error: RouteRefreshMessageError) -> DecodeError {
+        DecodeError::RouteRefreshMessage(error)
+    }
+}
+
 // ===== impl MessageHeaderError =====
 
 impl std::fmt::Display for MessageHeaderError {
@@ -203,6 +219,29 @@ impl From<TryGetError> for UpdateMessageError {
     }
 }
 
+// ===== impl RouteRefreshMessageError =====
+
+impl std::fmt::Display for RouteRefreshMessageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ROUTE-REFRESH message error: ")?;
+
+        match self {
+            RouteRefreshMessageError::ReadOutOfBounds => {
+                write!(f, "attempt to read out of bounds")
+            }
+            RouteRefreshMessageError::InvalidMessageLength => {
+                write!(f, "invalid message length")
+            }
+        }
+    }
+}
+
+impl From<TryGetError> for RouteRefreshMessageError {
+    fn from(_error: TryGetError) -> RouteRefreshMessageError {
+        RouteRefreshMessageError::ReadOutOfBounds
+    }
+}
+
 // ===== impl AttrError =====
 
 impl From<TryGetError> for AttrError {
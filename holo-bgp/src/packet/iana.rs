@@ -50,11 +50,11 @@ pub enum CapabilityCode {
     // RFC 2918
     RouteRefresh = 2,
     // RFC 5291
-    //OutboundRouteFiltering = 3,
+    OutboundRouteFiltering = 3,
     // RFC 8950
     //ExtendedNextHop = 5,
     // RFC 8654
-    //ExtendedMessage = 6,
+    ExtendedMessage = 6,
     // RFC 8205
     //BgpSec = 7,
     // RFC 8277
@@ -69,6 +69,8 @@ pub enum CapabilityCode {
     AddPath = 69,
     // RFC7313
     EnhancedRouteRefresh = 70,
+    // RFC 9494
+    LongLivedGracefulRestart = 71,
 }
 
 // BGP Error (Notification) Codes.
@@ -190,6 +192,18 @@ pub enum RouteRefreshErrorSubcode {
     InvalidMessageLength = 1,
 }
 
+// Outbound Route Filtering (ORF) types.
+//
+// IANA registry:
+// https://www.iana.org/assignments/bgp-parameters/bgp-parameters.xhtml#bgp-parameters-9
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(FromPrimitive, ToPrimitive)]
+#[derive(Deserialize, Serialize)]
+pub enum OrfType {
+    // RFC 5292
+    AddressPrefix = 64,
+}
+
 // Address Family identifiers (AFI).
 pub type Afi = AddressFamily;
 
@@ -266,7 +280,7 @@ pub enum AttrType {
     // RFC 5701
     Extv6Community = 25,
     // RFC 7311
-    //Aigp = 26,
+    Aigp = 26,
     // RFC 6514
     //PeDistinguisherLabels = 27,
     // RFC-ietf-idr-rfc7752bis-16
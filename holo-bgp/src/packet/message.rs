@@ -14,7 +14,7 @@ use holo_utils::bytes::{BytesExt, BytesMutExt, TLS_BUF};
 use holo_utils::ip::{
     Ipv4AddrExt, Ipv4NetworkExt, Ipv6AddrExt, Ipv6NetworkExt,
 };
-use ipnetwork::{Ipv4Network, Ipv6Network};
+use ipnetwork::{IpNetwork, Ipv4Network, Ipv6Network};
 use num_derive::{FromPrimitive, ToPrimitive};
 use num_traits::{FromPrimitive, ToPrimitive};
 use serde::{Deserialize, Serialize};
@@ -23,11 +23,13 @@ use serde_with::skip_serializing_none;
 use crate::neighbor::PeerType;
 use crate::packet::attribute::Attrs;
 use crate::packet::error::{
-    DecodeError, MessageHeaderError, OpenMessageError, UpdateMessageError,
+    DecodeError, MessageHeaderError, OpenMessageError,
+    RouteRefreshMessageError, UpdateMessageError,
 };
 use crate::packet::iana::{
     Afi, CapabilityCode, ErrorCode, MessageHeaderErrorSubcode, MessageType,
-    OpenMessageErrorSubcode, OpenParamType, Safi, UpdateMessageErrorSubcode,
+    OpenMessageErrorSubcode, OpenParamType, OrfType, RouteRefreshErrorSubcode,
+    Safi, UpdateMessageErrorSubcode,
 };
 
 //
@@ -123,6 +125,9 @@ pub enum Capability {
     AddPath(BTreeSet<AddPathTuple>),
     RouteRefresh,
     EnhancedRouteRefresh,
+    ExtendedMessage,
+    Orf(BTreeSet<OrfTuple>),
+    LongLivedGracefulRestart(BTreeSet<LlgrTuple>),
 }
 
 // This is a stripped down version of `Capability`, containing only data that
@@ -137,6 +142,9 @@ pub enum NegotiatedCapability {
     AddPath,
     RouteRefresh,
     EnhancedRouteRefresh,
+    ExtendedMessage,
+    Orf,
+    LongLivedGracefulRestart,
 }
 
 #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
@@ -157,6 +165,36 @@ pub enum AddPathMode {
     ReceiveSend = 3,
 }
 
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(Deserialize, Serialize)]
+pub struct OrfTuple {
+    pub afi: Afi,
+    pub safi: Safi,
+    pub orf_type: OrfType,
+    pub mode: OrfMode,
+}
+
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(Deserialize, Serialize)]
+pub struct LlgrTuple {
+    pub afi: Afi,
+    pub safi: Safi,
+    // Whether forwarding state has been preserved ("F" flag).
+    pub forwarding: bool,
+    // Long-lived stale time in seconds (24 bits).
+    pub stale_time: u32,
+}
+
+// Send/Receive value for a per-AFI/SAFI instance of the ORF Capability.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(FromPrimitive, ToPrimitive)]
+#[derive(Deserialize, Serialize)]
+pub enum OrfMode {
+    Receive = 1,
+    Send = 2,
+    Both = 3,
+}
+
 //
 // UPDATE Message.
 //
@@ -258,11 +296,87 @@ pub struct KeepaliveMsg {}
 // |      AFI      | Res.  | SAFI  |
 // +-------+-------+-------+-------+
 //
+// When ORFs are carried in the message (RFC 5291), the fields above are
+// followed by:
+//
+// +--------------------------------------------------+
+// | When-to-refresh (1 octet)                        |
+// +--------------------------------------------------+
+// | ORF Type (1 octet)                               |
+// +--------------------------------------------------+
+// | Length of ORFs (2 octets)                        |
+// +--------------------------------------------------+
+// | First ORF entry (variable)                       |
+// +--------------------------------------------------+
+// | Second ORF entry (variable)                      |
+// ...
+//
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[skip_serializing_none]
 #[derive(Deserialize, Serialize)]
 pub struct RouteRefreshMsg {
     pub afi: u16,
     pub safi: u8,
+    pub orf: Option<RouteRefreshOrf>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub struct RouteRefreshOrf {
+    pub when_to_refresh: OrfWhenToRefresh,
+    pub orf_type: OrfType,
+    pub entries: Vec<OrfEntry>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(FromPrimitive, ToPrimitive)]
+#[derive(Deserialize, Serialize)]
+pub enum OrfWhenToRefresh {
+    Immediate = 1,
+    Defer = 2,
+}
+
+//
+// ORF entry.
+//
+// Encoding format (Address Prefix ORF, RFC 5292):
+//
+// +--------------------------------+
+// | Action (2 bit)                 |
+// +--------------------------------+
+// | Match (1 bit)                  |
+// +--------------------------------+
+// | Reserved (5 bit)               |
+// +--------------------------------+
+// | Sequence (4 octets)            |
+// +--------------------------------+
+// | Minlen (1 octet)               |
+// +--------------------------------+
+// | Maxlen (1 octet)               |
+// +--------------------------------+
+// | Length (1 octet)               |
+// +--------------------------------+
+// | Prefix (variable length)       |
+// +--------------------------------+
+//
+// A REMOVE-ALL entry consists of the first octet only.
+//
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub enum OrfEntry {
+    Add(PrefixOrfEntry),
+    Remove(PrefixOrfEntry),
+    RemoveAll,
+}
+
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(Deserialize, Serialize)]
+pub struct PrefixOrfEntry {
+    pub seqno: u32,
+    pub permit: bool,
+    pub min_len: u8,
+    pub max_len: u8,
+    pub prefix: IpNetwork,
 }
 
 // BGP message decoding context.
@@ -285,6 +399,7 @@ pub struct DecodeCxt {
 impl Message {
     pub const MIN_LEN: u16 = 19;
     pub const MAX_LEN: u16 = 4096;
+    pub const MAX_LEN_EXT: u16 = 65535;
     const MSG_LEN_POS: std::ops::Range<usize> = 16..18;
 
     // Encodes BGP message into a bytes buffer.
@@ -319,7 +434,8 @@ impl Message {
     pub fn decode(data: &[u8], cxt: &DecodeCxt) -> Result<Self, DecodeError> {
         let mut buf = Bytes::copy_from_slice(data);
 
-        let msg_etype = Self::decode_header(&mut buf)?;
+        let max_len = Self::max_len(&cxt.capabilities);
+        let msg_etype = Self::decode_header(&mut buf, max_len)?;
         match msg_etype {
             MessageType::Open => {
                 let msg = OpenMsg::decode(&mut buf)?;
@@ -344,9 +460,19 @@ impl Message {
         }
     }
 
+    // Returns the maximum message length given the negotiated capabilities.
+    pub fn max_len(capabilities: &BTreeSet<NegotiatedCapability>) -> u16 {
+        if capabilities.contains(&NegotiatedCapability::ExtendedMessage) {
+            Self::MAX_LEN_EXT
+        } else {
+            Self::MAX_LEN
+        }
+    }
+
     // Decodes the BGP message header.
     fn decode_header(
         buf: &mut Bytes,
+        max_len: u16,
     ) -> Result<MessageType, MessageHeaderError> {
         // Parse and validate marker.
         let marker = buf.try_get_u128()?;
@@ -356,7 +482,7 @@ impl Message {
 
         // Parse and validate message length.
         let msg_len = buf.try_get_u16()?;
-        if msg_len < Self::MIN_LEN || msg_len > Self::MAX_LEN {
+        if msg_len < Self::MIN_LEN || msg_len > max_len {
             return Err(MessageHeaderError::BadMessageLength(msg_len));
         }
 
@@ -378,6 +504,15 @@ impl Message {
             return Err(MessageHeaderError::BadMessageLength(msg_len));
         }
 
+        // OPEN and KEEPALIVE messages can't exceed the standard maximum
+        // length, even when the Extended Message capability is in use
+        // (RFC 8654 - Section 4).
+        if matches!(msg_etype, MessageType::Open | MessageType::Keepalive)
+            && msg_len > Self::MAX_LEN
+        {
+            return Err(MessageHeaderError::BadMessageLength(msg_len));
+        }
+
         Ok(msg_etype)
     }
 
@@ -563,6 +698,50 @@ impl Capability {
                 buf.put_u8(CapabilityCode::EnhancedRouteRefresh as u8);
                 buf.put_u8(0);
             }
+            Capability::ExtendedMessage => {
+                buf.put_u8(CapabilityCode::ExtendedMessage as u8);
+                buf.put_u8(0);
+            }
+            Capability::Orf(tuples) => {
+                buf.put_u8(CapabilityCode::OutboundRouteFiltering as u8);
+                buf.put_u8(0);
+                let mut tuples = tuples.iter().peekable();
+                while let Some(tuple) = tuples.next() {
+                    let (afi, safi) = (tuple.afi, tuple.safi);
+                    buf.put_u16(afi as u16);
+                    buf.put_u8(0);
+                    buf.put_u8(safi as u8);
+                    // The "Number of ORFs" field will be initialized later.
+                    let count_pos = buf.len();
+                    buf.put_u8(0);
+                    let mut count = 1;
+                    buf.put_u8(tuple.orf_type as u8);
+                    buf.put_u8(tuple.mode as u8);
+                    while let Some(tuple) = tuples
+                        .next_if(|tuple| tuple.afi == afi && tuple.safi == safi)
+                    {
+                        buf.put_u8(tuple.orf_type as u8);
+                        buf.put_u8(tuple.mode as u8);
+                        count += 1;
+                    }
+                    buf[count_pos] = count;
+                }
+            }
+            Capability::LongLivedGracefulRestart(tuples) => {
+                buf.put_u8(CapabilityCode::LongLivedGracefulRestart as u8);
+                buf.put_u8(0);
+                for tuple in tuples {
+                    buf.put_u16(tuple.afi as u16);
+                    buf.put_u8(tuple.safi as u8);
+                    let flags = if tuple.forwarding {
+                        LlgrTuple::FLAG_FORWARDING
+                    } else {
+                        0
+                    };
+                    buf.put_u8(flags);
+                    buf.put_u24(tuple.stale_time);
+                }
+            }
         }
 
         // Rewrite the "Capability Length" field.
@@ -652,6 +831,70 @@ impl Capability {
 
                 Capability::EnhancedRouteRefresh
             }
+            Some(CapabilityCode::ExtendedMessage) => {
+                if cap_len != 0 {
+                    return Err(OpenMessageError::MalformedOptParam);
+                }
+
+                Capability::ExtendedMessage
+            }
+            Some(CapabilityCode::OutboundRouteFiltering) => {
+                let mut tuples = BTreeSet::new();
+                while buf_cap.remaining() > 0 {
+                    let afi = buf_cap.try_get_u16()?;
+                    let _reserved = buf_cap.try_get_u8()?;
+                    let safi = buf_cap.try_get_u8()?;
+                    let count = buf_cap.try_get_u8()?;
+                    for _ in 0..count {
+                        let orf_type = buf_cap.try_get_u8()?;
+                        let mode = buf_cap.try_get_u8()?;
+
+                        // Ignore unknown values.
+                        let (Some(afi), Some(safi), Some(orf_type), Some(mode)) = (
+                            Afi::from_u16(afi),
+                            Safi::from_u8(safi),
+                            OrfType::from_u8(orf_type),
+                            OrfMode::from_u8(mode),
+                        ) else {
+                            continue;
+                        };
+                        tuples.insert(OrfTuple {
+                            afi,
+                            safi,
+                            orf_type,
+                            mode,
+                        });
+                    }
+                }
+                Capability::Orf(tuples)
+            }
+            Some(CapabilityCode::LongLivedGracefulRestart) => {
+                if cap_len % 7 != 0 {
+                    return Err(OpenMessageError::MalformedOptParam);
+                }
+
+                let mut tuples = BTreeSet::new();
+                while buf_cap.remaining() > 0 {
+                    let afi = buf_cap.try_get_u16()?;
+                    let safi = buf_cap.try_get_u8()?;
+                    let flags = buf_cap.try_get_u8()?;
+                    let stale_time = buf_cap.try_get_u24()?;
+
+                    // Ignore unknown values.
+                    let (Some(afi), Some(safi)) =
+                        (Afi::from_u16(afi), Safi::from_u8(safi))
+                    else {
+                        continue;
+                    };
+                    tuples.insert(LlgrTuple {
+                        afi,
+                        safi,
+                        forwarding: flags & LlgrTuple::FLAG_FORWARDING != 0,
+                        stale_time,
+                    });
+                }
+                Capability::LongLivedGracefulRestart(tuples)
+            }
             _ => {
                 // Ignore unknown capability.
                 return Ok(None);
@@ -672,6 +915,11 @@ impl Capability {
             Capability::EnhancedRouteRefresh => {
                 CapabilityCode::EnhancedRouteRefresh
             }
+            Capability::ExtendedMessage => CapabilityCode::ExtendedMessage,
+            Capability::Orf(..) => CapabilityCode::OutboundRouteFiltering,
+            Capability::LongLivedGracefulRestart(..) => {
+                CapabilityCode::LongLivedGracefulRestart
+            }
         }
     }
 
@@ -688,6 +936,13 @@ impl Capability {
             Capability::EnhancedRouteRefresh => {
                 NegotiatedCapability::EnhancedRouteRefresh
             }
+            Capability::ExtendedMessage => {
+                NegotiatedCapability::ExtendedMessage
+            }
+            Capability::Orf(..) => NegotiatedCapability::Orf,
+            Capability::LongLivedGracefulRestart(..) => {
+                NegotiatedCapability::LongLivedGracefulRestart
+            }
         }
     }
 }
@@ -708,15 +963,76 @@ impl NegotiatedCapability {
             NegotiatedCapability::EnhancedRouteRefresh => {
                 CapabilityCode::EnhancedRouteRefresh
             }
+            NegotiatedCapability::ExtendedMessage => {
+                CapabilityCode::ExtendedMessage
+            }
+            NegotiatedCapability::Orf => CapabilityCode::OutboundRouteFiltering,
+            NegotiatedCapability::LongLivedGracefulRestart => {
+                CapabilityCode::LongLivedGracefulRestart
+            }
         }
     }
 }
 
+// ===== impl LlgrTuple =====
+
+impl LlgrTuple {
+    pub const FLAG_FORWARDING: u8 = 0x80;
+}
+
 // ===== impl UpdateMsg =====
 
 impl UpdateMsg {
     pub const MIN_LEN: u16 = 23;
 
+    // Returns an End-of-RIB marker for the given address family.
+    //
+    // RFC 4724 - Section 2:
+    // "An UPDATE message with no reachable Network Layer Reachability
+    // Information (NLRI) and empty withdrawn NLRI is specified as the
+    // End-of-RIB marker that can be used by a BGP speaker to indicate to
+    // its peer the completion of the initial routing update after the
+    // session is established".
+    pub fn end_of_rib(afi: Afi) -> Self {
+        let mp_unreach = match afi {
+            Afi::Ipv4 => None,
+            Afi::Ipv6 => Some(MpUnreachNlri::Ipv6Unicast { prefixes: vec![] }),
+        };
+        UpdateMsg {
+            reach: None,
+            unreach: None,
+            mp_reach: None,
+            mp_unreach,
+            attrs: None,
+        }
+    }
+
+    // Checks whether this message is an End-of-RIB marker, returning the
+    // corresponding address family.
+    pub fn is_end_of_rib(&self) -> Option<Afi> {
+        if self.reach.is_some()
+            || self.unreach.is_some()
+            || self.mp_reach.is_some()
+            || self.attrs.is_some()
+        {
+            return None;
+        }
+        match &self.mp_unreach {
+            None => Some(Afi::Ipv4),
+            Some(MpUnreachNlri::Ipv4Unicast { prefixes })
+                if prefixes.is_empty() =>
+            {
+                Some(Afi::Ipv4)
+            }
+            Some(MpUnreachNlri::Ipv6Unicast { prefixes })
+                if prefixes.is_empty() =>
+            {
+                Some(Afi::Ipv6)
+            }
+            Some(_) => None,
+        }
+    }
+
     fn encode(&self, buf: &mut BytesMut, cxt: &EncodeCxt) {
         buf.put_u8(MessageType::Update as u8);
 
@@ -750,12 +1066,15 @@ impl UpdateMsg {
                 &self.mp_unreach,
                 cxt,
             );
-
-            // Rewrite the "Total Path Attribute Length" field.
-            let len = (buf.len() - start_pos - 2) as u16;
-            buf[start_pos..start_pos + 2].copy_from_slice(&len.to_be_bytes());
+        } else if let Some(mp_unreach) = &self.mp_unreach {
+            // Withdrawal-only messages carry no other attributes.
+            mp_unreach.encode(buf);
         }
 
+        // Rewrite the "Total Path Attribute Length" field.
+        let len = (buf.len() - start_pos - 2) as u16;
+        buf[start_pos..start_pos + 2].copy_from_slice(&len.to_be_bytes());
+
         // Network Layer Reachability Information.
         if let Some(reach) = &self.reach {
             // Encode prefixes.
@@ -954,6 +1273,15 @@ impl From<DecodeError> for NotificationMsg {
                     }
                 } as u8;
             }
+            DecodeError::RouteRefreshMessage(error) => {
+                error_code = ErrorCode::RouteRefreshMessageError as u8;
+                error_subcode = match error {
+                    RouteRefreshMessageError::ReadOutOfBounds
+                    | RouteRefreshMessageError::InvalidMessageLength => {
+                        RouteRefreshErrorSubcode::InvalidMessageLength
+                    }
+                } as u8;
+            }
         }
 
         // TODO: set notification data.
@@ -984,20 +1312,209 @@ impl KeepaliveMsg {
 // ===== impl RouteRefreshMsg =====
 
 impl RouteRefreshMsg {
-    const LEN: u16 = 23;
+    pub const LEN: u16 = 23;
 
     fn encode(&self, buf: &mut BytesMut) {
         buf.put_u8(MessageType::RouteRefresh as u8);
         buf.put_u16(self.afi);
         buf.put_u8(0);
         buf.put_u8(self.safi);
+        if let Some(orf) = &self.orf {
+            orf.encode(buf);
+        }
     }
 
     pub fn decode(buf: &mut Bytes) -> Result<Self, DecodeError> {
         let afi = buf.try_get_u16()?;
         let _reserved = buf.try_get_u8()?;
         let safi = buf.try_get_u8()?;
-        Ok(RouteRefreshMsg { afi, safi })
+        let orf = match Afi::from_u16(afi) {
+            Some(afi) if buf.remaining() > 0 => {
+                RouteRefreshOrf::decode(buf, afi)?
+            }
+            _ => None,
+        };
+        Ok(RouteRefreshMsg { afi, safi, orf })
+    }
+}
+
+// ===== impl RouteRefreshOrf =====
+
+impl RouteRefreshOrf {
+    pub const HDR_LEN: u16 = 4;
+
+    fn encode(&self, buf: &mut BytesMut) {
+        buf.put_u8(self.when_to_refresh as u8);
+        buf.put_u8(self.orf_type as u8);
+
+        // The "Length of ORFs" field will be initialized later.
+        let start_pos = buf.len();
+        buf.put_u16(0);
+
+        for entry in &self.entries {
+            entry.encode(buf);
+        }
+
+        // Rewrite the "Length of ORFs" field.
+        let orfs_len = (buf.len() - start_pos - 2) as u16;
+        buf[start_pos..start_pos + 2].copy_from_slice(&orfs_len.to_be_bytes());
+    }
+
+    fn decode(
+        buf: &mut Bytes,
+        afi: Afi,
+    ) -> Result<Option<Self>, RouteRefreshMessageError> {
+        let when_to_refresh = buf.try_get_u8()?;
+        let when_to_refresh = OrfWhenToRefresh::from_u8(when_to_refresh)
+            .unwrap_or(OrfWhenToRefresh::Immediate);
+
+        // NOTE: only a single ORF type (Address Prefix) is supported, so any
+        // other ORF type present in the message is ignored.
+        let mut orf = None;
+        while buf.remaining() > 0 {
+            let orf_type = buf.try_get_u8()?;
+            let orfs_len = buf.try_get_u16()?;
+            if orfs_len as usize > buf.remaining() {
+                return Err(RouteRefreshMessageError::InvalidMessageLength);
+            }
+            let mut buf_orfs = buf.copy_to_bytes(orfs_len as usize);
+            let Some(orf_type) = OrfType::from_u8(orf_type) else {
+                continue;
+            };
+
+            let mut entries = vec![];
+            while buf_orfs.remaining() > 0 {
+                if let Some(entry) = OrfEntry::decode(&mut buf_orfs, afi)? {
+                    entries.push(entry);
+                }
+            }
+            orf = Some(RouteRefreshOrf {
+                when_to_refresh,
+                orf_type,
+                entries,
+            });
+        }
+
+        Ok(orf)
+    }
+}
+
+// ===== impl OrfEntry =====
+
+impl OrfEntry {
+    const ACTION_ADD: u8 = 0;
+    const ACTION_REMOVE: u8 = 1;
+    const ACTION_REMOVE_ALL: u8 = 2;
+    const MATCH_DENY: u8 = 0x20;
+
+    fn encode(&self, buf: &mut BytesMut) {
+        let (action, entry) = match self {
+            OrfEntry::Add(entry) => (Self::ACTION_ADD, entry),
+            OrfEntry::Remove(entry) => (Self::ACTION_REMOVE, entry),
+            OrfEntry::RemoveAll => {
+                buf.put_u8(Self::ACTION_REMOVE_ALL << 6);
+                return;
+            }
+        };
+
+        let mut flags = action << 6;
+        if !entry.permit {
+            flags |= Self::MATCH_DENY;
+        }
+        buf.put_u8(flags);
+        buf.put_u32(entry.seqno);
+        buf.put_u8(entry.min_len);
+        buf.put_u8(entry.max_len);
+        match entry.prefix {
+            IpNetwork::V4(prefix) => encode_ipv4_prefix(buf, &prefix),
+            IpNetwork::V6(prefix) => encode_ipv6_prefix(buf, &prefix),
+        }
+    }
+
+    fn decode(
+        buf: &mut Bytes,
+        afi: Afi,
+    ) -> Result<Option<Self>, RouteRefreshMessageError> {
+        let flags = buf.try_get_u8()?;
+        let action = flags >> 6;
+        if action == Self::ACTION_REMOVE_ALL {
+            return Ok(Some(OrfEntry::RemoveAll));
+        }
+        let permit = flags & Self::MATCH_DENY == 0;
+        let seqno = buf.try_get_u32()?;
+        let min_len = buf.try_get_u8()?;
+        let max_len = buf.try_get_u8()?;
+
+        // Parse prefix.
+        let plen = buf.try_get_u8()?;
+        let plen_wire = prefix_wire_len(plen);
+        if plen_wire > buf.remaining() {
+            return Err(RouteRefreshMessageError::InvalidMessageLength);
+        }
+        let prefix = match afi {
+            Afi::Ipv4 => {
+                let mut prefix_bytes = [0; Ipv4Addr::LENGTH];
+                if plen_wire > prefix_bytes.len() {
+                    return Err(RouteRefreshMessageError::InvalidMessageLength);
+                }
+                buf.try_copy_to_slice(&mut prefix_bytes[..plen_wire])?;
+                Ipv4Network::new(Ipv4Addr::from(prefix_bytes), plen)
+                    .map(|prefix| IpNetwork::V4(prefix.apply_mask()))
+            }
+            Afi::Ipv6 => {
+                let mut prefix_bytes = [0; Ipv6Addr::LENGTH];
+                if plen_wire > prefix_bytes.len() {
+                    return Err(RouteRefreshMessageError::InvalidMessageLength);
+                }
+                buf.try_copy_to_slice(&mut prefix_bytes[..plen_wire])?;
+                Ipv6Network::new(Ipv6Addr::from(prefix_bytes), plen)
+                    .map(|prefix| IpNetwork::V6(prefix.apply_mask()))
+            }
+        };
+        let Ok(prefix) = prefix else {
+            return Err(RouteRefreshMessageError::InvalidMessageLength);
+        };
+
+        let entry = PrefixOrfEntry {
+            seqno,
+            permit,
+            min_len,
+            max_len,
+            prefix,
+        };
+        match action {
+            Self::ACTION_ADD => Ok(Some(OrfEntry::Add(entry))),
+            Self::ACTION_REMOVE => Ok(Some(OrfEntry::Remove(entry))),
+            // Ignore unknown action.
+            _ => Ok(None),
+        }
+    }
+}
+
+// ===== impl PrefixOrfEntry =====
+
+impl PrefixOrfEntry {
+    pub const MAX_LEN: usize = 24;
+
+    // Returns whether the given prefix matches this ORF entry, as specified
+    // in RFC 5292 - Section 3.
+    pub fn matches(&self, prefix: &IpNetwork) -> bool {
+        let plen = self.prefix.prefix();
+        let max_plen = match self.prefix {
+            IpNetwork::V4(_) => Ipv4Network::MAX_PREFIXLEN,
+            IpNetwork::V6(_) => Ipv6Network::MAX_PREFIXLEN,
+        };
+        let (min_len, max_len) = match (self.min_len, self.max_len) {
+            (0, 0) => (plen, plen),
+            (0, max_len) => (plen, max_len),
+            (min_len, 0) => (min_len, max_plen),
+            (min_len, max_len) => (min_len, max_len),
+        };
+
+        prefix.is_ipv4() == self.prefix.is_ipv4()
+            && self.prefix.contains(prefix.ip())
+            && prefix.prefix() >= min_len
+            && prefix.prefix() <= max_len
     }
 }
 
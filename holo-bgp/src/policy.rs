@@ -9,12 +9,14 @@ use std::net::IpAddr;
 use std::sync::Arc;
 
 use derive_new::new;
-use holo_utils::bgp::{AfiSafi, RouteType};
-use holo_utils::ip::IpNetworkKind;
+use holo_protocol::InstanceShared;
+use holo_utils::bgp::{AfiSafi, AspaValidationState, RouteType};
+use holo_utils::ip::{AddressFamily, IpNetworkKind};
 use holo_utils::policy::{
     BgpNexthop, BgpPolicyAction, BgpPolicyCondition, BgpSetCommMethod,
-    BgpSetCommOptions, BgpSetMed, DefaultPolicyType, MatchSets,
-    MetricModification, Policy, PolicyAction, PolicyCondition, PolicyResult,
+    BgpSetCommOptions, BgpSetMed, DefaultPolicyType, IpPrefixRange,
+    MatchSetRestrictedType, MatchSets, MetricModification, Policy,
+    PolicyAction, PolicyCondition, PolicyConditionType, PolicyResult,
     PolicyType,
 };
 use holo_utils::southbound::RouteOpaqueAttrs;
@@ -26,6 +28,7 @@ use tokio::sync::mpsc::UnboundedSender;
 use crate::packet::attribute::{Attrs, CommList, CommType};
 use crate::rib::RouteOrigin;
 use crate::tasks::messages::input::PolicyResultMsg;
+use crate::update_group::UpdateGroupId;
 
 // Represents a simplified version of `Route`, containing only information
 // relevant for the application of routing policies.
@@ -39,6 +42,8 @@ pub struct RoutePolicyInfo {
     pub tag: Option<u32>,
     pub opaque_attrs: Option<RouteOpaqueAttrs>,
     pub attrs: Attrs,
+    #[new(default)]
+    pub aspa_state: Option<AspaValidationState>,
 }
 
 // ===== global functions =====
@@ -56,21 +61,13 @@ pub(crate) fn neighbor_apply(
     policy_resultp: &UnboundedSender<PolicyResultMsg>,
 ) {
     // Process policies for each route and collect the results.
-    let routes = routes
-        .into_iter()
-        .map(|(prefix, rpinfo)| {
-            let result = process_policies(
-                afi_safi,
-                prefix,
-                rpinfo,
-                policies,
-                match_sets,
-                default_policy,
-            );
-
-            (prefix, result)
-        })
-        .collect();
+    let routes = process_route_list(
+        afi_safi,
+        routes,
+        policies,
+        match_sets,
+        default_policy,
+    );
 
     // Send the resulting policy decisions to the specified channel.
     let _ = policy_resultp.send(PolicyResultMsg::Neighbor {
@@ -110,8 +107,133 @@ pub(crate) fn redistribute_apply(
     });
 }
 
+// Applies update group export routing policies to a provided list of routes
+// and sends the resulting policy decisions to the specified channel.
+pub(crate) fn update_group_apply(
+    group_id: UpdateGroupId,
+    afi_safi: AfiSafi,
+    routes: Vec<(IpNetwork, RoutePolicyInfo)>,
+    policies: &[Arc<Policy>],
+    match_sets: &MatchSets,
+    default_policy: DefaultPolicyType,
+    policy_resultp: &UnboundedSender<PolicyResultMsg>,
+) {
+    // Process policies for each route and collect the results.
+    let routes = process_route_list(
+        afi_safi,
+        routes,
+        policies,
+        match_sets,
+        default_policy,
+    );
+
+    // Send the resulting policy decisions to the specified channel.
+    let _ = policy_resultp.send(PolicyResultMsg::UpdateGroup {
+        group_id,
+        afi_safi,
+        routes,
+    });
+}
+
+// Evaluates a single routing policy synchronously, returning whether the
+// route is accepted by it.
+//
+// Routes not matching any policy statement are rejected.
+pub(crate) fn policy_match(
+    shared: &InstanceShared,
+    afi_safi: AfiSafi,
+    prefix: IpNetwork,
+    rpinfo: RoutePolicyInfo,
+    policy: &str,
+) -> bool {
+    let Some(policy) = shared.policies.get(policy) else {
+        return false;
+    };
+
+    let result = process_policies(
+        afi_safi,
+        prefix,
+        rpinfo,
+        std::slice::from_ref(policy),
+        &shared.policy_match_sets,
+        DefaultPolicyType::RejectRoute,
+    );
+    matches!(result, PolicyResult::Accept(_))
+}
+
+// Returns the prefix ranges that can be accepted by the given import policies,
+// for use as Address Prefix ORF entries.
+//
+// Returns `None` when the policies might accept prefixes outside the matched
+// prefix sets, in which case the received routes can't be filtered by prefix.
+pub(crate) fn import_prefix_ranges(
+    af: AddressFamily,
+    policies: &[Arc<Policy>],
+    match_sets: &MatchSets,
+    default_policy: DefaultPolicyType,
+) -> Option<BTreeSet<IpPrefixRange>> {
+    if default_policy == DefaultPolicyType::AcceptRoute {
+        return None;
+    }
+
+    let mut ranges = BTreeSet::new();
+    for stmt in policies.iter().flat_map(|policy| policy.stmts.values()) {
+        // Statements rejecting the matched routes don't accept any prefix.
+        if stmt
+            .actions
+            .values()
+            .any(|action| matches!(action, PolicyAction::Accept(false)))
+        {
+            continue;
+        }
+
+        // Any other statement must match on a prefix set, otherwise it might
+        // accept arbitrary prefixes.
+        let Some(PolicyCondition::MatchPrefixSet(name)) =
+            stmt.conditions.get(&PolicyConditionType::MatchPrefixSet)
+        else {
+            return None;
+        };
+        if matches!(stmt.prefix_set_match_type, MatchSetRestrictedType::Invert)
+        {
+            return None;
+        }
+        if let Some(set) = match_sets.prefixes.get(&(name.clone(), af)) {
+            ranges.extend(set.prefixes.iter().cloned());
+        }
+    }
+
+    Some(ranges)
+}
+
 // ===== helper functions =====
 
+// Processes routing policies for a list of routes and returns the policy
+// results.
+fn process_route_list(
+    afi_safi: AfiSafi,
+    routes: Vec<(IpNetwork, RoutePolicyInfo)>,
+    policies: &[Arc<Policy>],
+    match_sets: &MatchSets,
+    default_policy: DefaultPolicyType,
+) -> Vec<(IpNetwork, PolicyResult<RoutePolicyInfo>)> {
+    routes
+        .into_iter()
+        .map(|(prefix, rpinfo)| {
+            let result = process_policies(
+                afi_safi,
+                prefix,
+                rpinfo,
+                policies,
+                match_sets,
+                default_policy,
+            );
+
+            (prefix, result)
+        })
+        .collect()
+}
+
 // Processes routing policies for a specific route and returns the policy
 // result.
 fn process_policies(
@@ -321,6 +443,10 @@ fn process_stmt_condition(
                     let set = match_sets.bgp.nexthops.get(value).unwrap();
                     match_type.compare(set, &nexthop)
                 }
+                // "aspa-validation"
+                BgpPolicyCondition::AspaValidation(state) => {
+                    rpinfo.aspa_state == Some(*state)
+                }
             }
         }
         // Ignore unsupported conditions.
@@ -488,3 +614,120 @@ fn action_set_comm<T>(
         *comm_list = None;
     }
 }
+
+// ===== unit tests =====
+
+#[cfg(test)]
+mod tests {
+    use holo_utils::policy::{
+        MatchSetType, PolicyActionType, PolicyStmt, PrefixSet,
+    };
+
+    use super::*;
+
+    fn stmt(
+        name: &str,
+        prefix_set: Option<&str>,
+        accept: Option<bool>,
+    ) -> PolicyStmt {
+        PolicyStmt {
+            name: name.to_owned(),
+            prefix_set_match_type: MatchSetRestrictedType::Any,
+            tag_set_match_type: MatchSetType::Any,
+            conditions: prefix_set
+                .map(|name| {
+                    (
+                        PolicyConditionType::MatchPrefixSet,
+                        PolicyCondition::MatchPrefixSet(name.to_owned()),
+                    )
+                })
+                .into_iter()
+                .collect(),
+            actions: accept
+                .map(|accept| {
+                    (PolicyActionType::Accept, PolicyAction::Accept(accept))
+                })
+                .into_iter()
+                .collect(),
+        }
+    }
+
+    fn policy(stmts: Vec<PolicyStmt>) -> Arc<Policy> {
+        Arc::new(Policy {
+            name: "import".to_owned(),
+            stmts: stmts
+                .into_iter()
+                .map(|stmt| (stmt.name.clone(), stmt))
+                .collect(),
+        })
+    }
+
+    fn range(prefix: &str, lower: u8, upper: u8) -> IpPrefixRange {
+        IpPrefixRange {
+            prefix: prefix.parse().unwrap(),
+            masklen_lower: lower,
+            masklen_upper: upper,
+        }
+    }
+
+    #[test]
+    fn import_prefix_ranges() {
+        let mut match_sets = MatchSets::default();
+        for (name, ranges) in [
+            ("set1", vec![range("10.0.0.0/8", 16, 24)]),
+            ("set2", vec![range("172.16.0.0/12", 12, 12)]),
+        ] {
+            match_sets.prefixes.insert(
+                (name.to_owned(), AddressFamily::Ipv4),
+                PrefixSet {
+                    name: name.to_owned(),
+                    mode: AddressFamily::Ipv4,
+                    prefixes: ranges.into_iter().collect(),
+                },
+            );
+        }
+        let ranges = |policies: &[Arc<Policy>], default_policy| {
+            super::import_prefix_ranges(
+                AddressFamily::Ipv4,
+                policies,
+                &match_sets,
+                default_policy,
+            )
+        };
+        let reject = DefaultPolicyType::RejectRoute;
+
+        // Prefix sets of accepting statements.
+        let policies = [policy(vec![
+            stmt("10", Some("set1"), Some(true)),
+            stmt("20", Some("set2"), None),
+        ])];
+        assert_eq!(
+            ranges(&policies, reject),
+            Some(
+                [range("10.0.0.0/8", 16, 24), range("172.16.0.0/12", 12, 12)]
+                    .into()
+            )
+        );
+
+        // Prefix sets of rejecting statements are ignored.
+        let policies = [policy(vec![
+            stmt("10", Some("set1"), Some(false)),
+            stmt("20", Some("set2"), Some(true)),
+        ])];
+        assert_eq!(
+            ranges(&policies, reject),
+            Some([range("172.16.0.0/12", 12, 12)].into())
+        );
+
+        // Accepting statement not matching on a prefix set.
+        let policies = [policy(vec![
+            stmt("10", Some("set1"), Some(true)),
+            stmt("20", None, Some(true)),
+        ])];
+        assert_eq!(ranges(&policies, reject), None);
+
+        // Default policy accepting unmatched routes.
+        let policies = [policy(vec![stmt("10", Some("set1"), Some(true))])];
+        assert_eq!(ranges(&policies, DefaultPolicyType::AcceptRoute), None);
+    }
+}
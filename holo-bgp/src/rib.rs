@@ -10,7 +10,9 @@ use std::net::{IpAddr, Ipv4Addr};
 use std::sync::Arc;
 use std::time::Instant;
 
-use holo_utils::bgp::RouteType;
+use holo_utils::bgp::{
+    AspaValidationState, Comm, RouteType, WellKnownCommunities,
+};
 use holo_utils::ibus::IbusChannelsTx;
 use holo_utils::protocol::Protocol;
 use prefix_trie::map::PrefixMap;
@@ -24,7 +26,8 @@ use crate::northbound::configuration::{
     DistanceCfg, InstanceTraceOptions, MultipathCfg, RouteSelectionCfg,
 };
 use crate::packet::attribute::{
-    Attrs, BaseAttrs, Comms, ExtComms, Extv6Comms, LargeComms, UnknownAttr,
+    Attrs, BaseAttrs, CommList, Comms, ExtComms, Extv6Comms, LargeComms,
+    UnknownAttr,
 };
 use crate::policy::RoutePolicyInfo;
 
@@ -75,6 +78,7 @@ pub struct LocalRoute {
     pub route_type: RouteType,
     pub last_modified: Instant,
     pub nexthops: Option<BTreeSet<IpAddr>>,
+    pub aspa_state: Option<AspaValidationState>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -86,6 +90,10 @@ pub struct Route {
     pub last_modified: Instant,
     pub ineligible_reason: Option<RouteIneligibleReason>,
     pub reject_reason: Option<RouteRejectReason>,
+    pub aspa_state: Option<AspaValidationState>,
+    // Whether the route was retained as a long-lived stale route after the
+    // session went down (RFC 9494).
+    pub llgr_stale: bool,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -149,7 +157,9 @@ pub enum RouteIneligibleReason {
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum RouteRejectReason {
+    LlgrStale,
     LocalPrefLower,
+    AigpHigher,
     AsPathLonger,
     OriginTypeHigher,
     MedHigher,
@@ -292,6 +302,21 @@ impl AdjRib {
     }
 }
 
+// ===== impl LocalRoute =====
+
+impl LocalRoute {
+    pub(crate) fn policy_info(&self) -> RoutePolicyInfo {
+        RoutePolicyInfo {
+            origin: self.origin,
+            route_type: self.route_type,
+            tag: None,
+            opaque_attrs: None,
+            attrs: self.attrs.get(),
+            aspa_state: self.aspa_state,
+        }
+    }
+}
+
 // ===== impl Route =====
 
 impl Route {
@@ -308,6 +333,8 @@ impl Route {
             last_modified: Instant::now(),
             ineligible_reason: None,
             reject_reason: None,
+            aspa_state: None,
+            llgr_stale: false,
         }
     }
 
@@ -318,6 +345,7 @@ impl Route {
             tag: None,
             opaque_attrs: None,
             attrs: self.attrs.get(),
+            aspa_state: self.aspa_state,
         }
     }
 
@@ -325,12 +353,32 @@ impl Route {
         self.ineligible_reason.is_none()
     }
 
+    // Returns whether the route carries the LLGR_STALE community.
+    pub(crate) fn is_llgr_stale(&self) -> bool {
+        self.attrs.has_comm(WellKnownCommunities::LlgrStale)
+    }
+
     fn compare(
         &self,
         other: &Route,
         selection_cfg: &RouteSelectionCfg,
         mpath_cfg: Option<&MultipathCfg>,
     ) -> RouteCompare {
+        // Routes carrying the LLGR_STALE community are less preferred than
+        // any other route (RFC 9494, Section 4.3).
+        let reason = RouteRejectReason::LlgrStale;
+        match (self.is_llgr_stale(), other.is_llgr_stale()) {
+            (false, true) => {
+                return RouteCompare::Preferred(reason);
+            }
+            (true, false) => {
+                return RouteCompare::LessPreferred(reason);
+            }
+            _ => {
+                // Move to next tie-breaker.
+            }
+        }
+
         // Compare LOCAL_PREFERENCE attributes.
         let a = self.attrs.base.value.local_pref.unwrap_or(DFLT_LOCAL_PREF);
         let b = other.attrs.base.value.local_pref.unwrap_or(DFLT_LOCAL_PREF);
@@ -347,6 +395,39 @@ impl Route {
             }
         }
 
+        // Compare AIGP attributes (RFC 7311).
+        //
+        // Routes carrying AIGP are preferred over routes that don't. When
+        // both carry it, the value is increased by the IGP cost to the
+        // next-hop before the comparison.
+        let reason = RouteRejectReason::AigpHigher;
+        match (self.attrs.base.value.aigp, other.attrs.base.value.aigp) {
+            (None, None) => {
+                // Move to next tie-breaker.
+            }
+            (Some(_), None) => {
+                return RouteCompare::Preferred(reason);
+            }
+            (None, Some(_)) => {
+                return RouteCompare::LessPreferred(reason);
+            }
+            (Some(a), Some(b)) => {
+                let a = a.saturating_add(self.igp_cost.unwrap_or(0).into());
+                let b = b.saturating_add(other.igp_cost.unwrap_or(0).into());
+                match a.cmp(&b) {
+                    Ordering::Less => {
+                        return RouteCompare::Preferred(reason);
+                    }
+                    Ordering::Greater => {
+                        return RouteCompare::LessPreferred(reason);
+                    }
+                    Ordering::Equal => {
+                        // Move to next tie-breaker.
+                    }
+                }
+            }
+        }
+
         // Compare AS_PATH lengths.
         if !selection_cfg.ignore_as_path_length {
             let a = self.attrs.base.value.as_path.path_length();
@@ -553,6 +634,13 @@ impl RouteAttrs {
             unknown: self.unknown.clone(),
         }
     }
+
+    // Returns whether the given well-known community is attached.
+    pub(crate) fn has_comm(&self, comm: WellKnownCommunities) -> bool {
+        self.comm
+            .as_ref()
+            .is_some_and(|set| set.value.0.contains(&Comm(comm as u32)))
+    }
 }
 
 // ===== impl AttrSetsCxt =====
@@ -801,6 +889,7 @@ pub(crate) fn loc_rib_update<A>(
             && local_route.attrs == best_route.attrs
             && local_route.route_type == best_route.route_type
             && local_route.nexthops == nexthops
+            && local_route.aspa_state == best_route.aspa_state
         {
             return;
         }
@@ -812,6 +901,7 @@ pub(crate) fn loc_rib_update<A>(
             route_type: best_route.route_type,
             last_modified: best_route.last_modified,
             nexthops,
+            aspa_state: best_route.aspa_state,
         };
 
         // Install local route in the global RIB.
@@ -852,6 +942,7 @@ pub(crate) fn attrs_tx_update<A>(
     nbr: &Neighbor,
     local_asn: u32,
     local: bool,
+    nht: &HashMap<IpAddr, NhtEntry<A>>,
 ) where
     A: AddressFamily,
 {
@@ -875,7 +966,35 @@ pub(crate) fn attrs_tx_update<A>(
     }
 
     // Update the next-hop attribute based on the address family if necessary.
+    let prev_nexthop = (!local).then(|| A::nexthop_rx_extract(&attrs.base));
     A::nexthop_tx_change(nbr, local, &mut attrs.base);
+
+    // Update the AIGP attribute (RFC 7311).
+    if !nbr.aigp_enabled() {
+        // Never send AIGP over sessions where it's disabled.
+        attrs.base.aigp = None;
+    } else if let Some(prev_nexthop) = prev_nexthop
+        && prev_nexthop != A::nexthop_rx_extract(&attrs.base)
+        && let Some(aigp) = &mut attrs.base.aigp
+    {
+        // When the next-hop is changed, add the IGP cost to the previous
+        // next-hop to the accumulated value.
+        let metric = nht
+            .get(&prev_nexthop)
+            .and_then(|nht| nht.metric)
+            .unwrap_or(0);
+        *aigp = aigp.saturating_add(metric.into());
+    }
+
+    // Attach the GRACEFUL_SHUTDOWN community (RFC 8326).
+    if nbr.graceful_shutdown {
+        let comm = Comm(WellKnownCommunities::GracefulShutdown as u32);
+        attrs
+            .comm
+            .get_or_insert_with(|| CommList(Default::default()))
+            .0
+            .insert(comm);
+    }
 }
 
 pub(crate) fn nexthop_track<A>(
@@ -928,6 +1047,8 @@ pub(crate) fn nexthop_untrack<A>(
 
 #[cfg(test)]
 mod tests {
+    use holo_utils::socket::TcpConnInfo;
+
     use super::*;
     use crate::packet::attribute::BaseAttrs;
 
@@ -956,6 +1077,8 @@ mod tests {
             last_modified: Instant::now(),
             ineligible_reason: None,
             reject_reason: None,
+            aspa_state: None,
+            llgr_stale: false,
         }
     }
 
@@ -1065,4 +1188,92 @@ mod tests {
             other => panic!("expected PreferExternal, got {:?}", other),
         }
     }
+
+    #[test]
+    fn compare_aigp() {
+        let cfg = RouteSelectionCfg::default();
+        let with_aigp = |aigp, igp_cost| {
+            let mut route =
+                make_route(ibgp_origin(), RouteType::Internal, igp_cost);
+            route.attrs.base = Arc::new(AttrSet {
+                index: 0,
+                value: BaseAttrs {
+                    aigp,
+                    ..Default::default()
+                },
+            });
+            route
+        };
+
+        // Routes carrying AIGP are preferred over routes that don't.
+        let a = with_aigp(Some(100), Some(10));
+        let b = with_aigp(None, Some(1));
+        assert_eq!(
+            a.compare(&b, &cfg, None),
+            RouteCompare::Preferred(RouteRejectReason::AigpHigher)
+        );
+        assert_eq!(
+            b.compare(&a, &cfg, None),
+            RouteCompare::LessPreferred(RouteRejectReason::AigpHigher)
+        );
+
+        // The IGP cost to the next-hop is added to the AIGP value.
+        let a = with_aigp(Some(100), Some(10));
+        let b = with_aigp(Some(105), Some(1));
+        assert_eq!(
+            a.compare(&b, &cfg, None),
+            RouteCompare::LessPreferred(RouteRejectReason::AigpHigher)
+        );
+
+        // Equal accumulated metrics fall through to the next tie-breakers.
+        let a = with_aigp(Some(100), Some(5));
+        let b = with_aigp(Some(95), Some(10));
+        assert_eq!(
+            a.compare(&b, &cfg, None),
+            RouteCompare::Preferred(RouteRejectReason::NexthopCostHigher)
+        );
+    }
+
+    #[test]
+    fn attrs_tx_update_graceful_shutdown() {
+        let mut nbr = Neighbor::new(
+            IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2)),
+            PeerType::Internal,
+        );
+        nbr.conn_info = Some(TcpConnInfo {
+            local_addr: IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)),
+            local_port: 179,
+            remote_addr: IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2)),
+            remote_port: 50000,
+        });
+        let gshut = Comm(WellKnownCommunities::GracefulShutdown as u32);
+
+        // No community is attached when graceful shutdown is disabled.
+        let mut attrs = Attrs::default();
+        attrs_tx_update::<Ipv4Unicast>(
+            &mut attrs,
+            &nbr,
+            65000,
+            true,
+            &Default::default(),
+        );
+        assert!(attrs.comm.is_none());
+
+        // The GRACEFUL_SHUTDOWN community is appended to existing ones.
+        nbr.graceful_shutdown = true;
+        let mut attrs = Attrs {
+            comm: Some(CommList([Comm(0x00010001)].into())),
+            ..Default::default()
+        };
+        attrs_tx_update::<Ipv4Unicast>(
+            &mut attrs,
+            &nbr,
+            65000,
+            true,
+            &Default::default(),
+        );
+        let comm = attrs.comm.unwrap();
+        assert!(comm.0.contains(&gshut));
+        assert!(comm.0.contains(&Comm(0x00010001)));
+    }
 }
@@ -0,0 +1,665 @@
+//
+// Copyright (c) The Holo Core Contributors
+//
+// SPDX-License-Identifier: MIT
+//
+
+use std::collections::BTreeSet;
+use std::net::SocketAddr;
+use std::ops::RangeInclusive;
+use std::time::Duration;
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio::net::tcp::OwnedWriteHalf;
+use tokio::sync::mpsc::Sender;
+use tokio::time::{Instant, sleep, sleep_until};
+
+use crate::aspa::AspaDb;
+use crate::error::IoError;
+use crate::tasks::messages::input::AspaDbUpdateMsg;
+
+// RPKI-Router protocol version implemented by the client.
+pub const VERSION: u8 = 2;
+
+// PDU header length.
+pub const HDR_LEN: usize = 8;
+
+// Maximum accepted PDU length.
+pub const MAX_PDU_LEN: usize = 65535;
+
+// Timing parameters used until the cache provides its own.
+const DFLT_REFRESH_INTERVAL: Duration = Duration::from_secs(3600);
+const DFLT_RETRY_INTERVAL: Duration = Duration::from_secs(600);
+const DFLT_EXPIRE_INTERVAL: Duration = Duration::from_secs(7200);
+
+// Valid ranges of the End of Data timing parameters (RFC 8210 - Section 6).
+const REFRESH_INTERVAL_RANGE: RangeInclusive<u32> = 1..=86400;
+const RETRY_INTERVAL_RANGE: RangeInclusive<u32> = 1..=7200;
+const EXPIRE_INTERVAL_RANGE: RangeInclusive<u32> = 600..=172800;
+
+// Flag of the ASPA PDU indicating an announcement, as opposed to a withdrawal.
+const ASPA_FLAG_ANNOUNCE: u8 = 0x01;
+
+//
+// RTR PDU.
+//
+// Encoding format (PDU header):
+//
+// 0          8          16         24        31
+// .-------------------------------------------.
+// | Protocol |   PDU    |                     |
+// | Version  |   Type   |  Session ID / Flags |
+// +-------------------------------------------+
+// |                                           |
+// |                 Length                    |
+// |                                           |
+// `-------------------------------------------'
+//
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Pdu {
+    SerialNotify {
+        session_id: u16,
+        serial: u32,
+    },
+    SerialQuery {
+        session_id: u16,
+        serial: u32,
+    },
+    ResetQuery,
+    CacheResponse {
+        session_id: u16,
+    },
+    EndOfData {
+        session_id: u16,
+        serial: u32,
+        refresh_interval: u32,
+        retry_interval: u32,
+        expire_interval: u32,
+    },
+    CacheReset,
+    ErrorReport {
+        code: u16,
+        text: String,
+    },
+    Aspa {
+        announce: bool,
+        customer: u32,
+        providers: BTreeSet<u32>,
+    },
+    // PDUs not relevant to ASPA (e.g. prefixes and router keys), which are
+    // ignored.
+    Other(u8),
+}
+
+// RTR PDU types.
+//
+// IANA registry:
+// https://www.iana.org/assignments/rpki/rpki.xhtml#pdus
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PduType {
+    SerialNotify = 0,
+    SerialQuery = 1,
+    ResetQuery = 2,
+    CacheResponse = 3,
+    EndOfData = 7,
+    CacheReset = 8,
+    ErrorReport = 10,
+    Aspa = 11,
+}
+
+// RTR session with a cache.
+#[derive(Debug)]
+struct Session {
+    // Session ID and serial number of the data currently held.
+    serial: Option<(u16, u32)>,
+    // ASPA records received from the cache.
+    db: AspaDb,
+    // Timing parameters provided by the cache.
+    refresh_interval: Duration,
+    retry_interval: Duration,
+    expire_interval: Duration,
+    // Last time the data was synchronized with the cache.
+    last_sync: Option<Instant>,
+}
+
+// ===== impl Pdu =====
+
+impl Pdu {
+    // Encodes the PDU into a bytes buffer.
+    //
+    // PDUs of unsupported types can't be encoded, since their contents aren't
+    // known.
+    pub fn encode(&self) -> Result<BytesMut, std::io::Error> {
+        let mut buf = BytesMut::new();
+        let (pdu_type, session_id) = match self {
+            Pdu::SerialNotify { session_id, .. } => {
+                (PduType::SerialNotify, *session_id)
+            }
+            Pdu::SerialQuery { session_id, .. } => {
+                (PduType::SerialQuery, *session_id)
+            }
+            Pdu::ResetQuery => (PduType::ResetQuery, 0),
+            Pdu::CacheResponse { session_id } => {
+                (PduType::CacheResponse, *session_id)
+            }
+            Pdu::EndOfData { session_id, .. } => {
+                (PduType::EndOfData, *session_id)
+            }
+            Pdu::CacheReset => (PduType::CacheReset, 0),
+            Pdu::ErrorReport { code, .. } => (PduType::ErrorReport, *code),
+            Pdu::Aspa { announce, .. } => {
+                let flags = if *announce { ASPA_FLAG_ANNOUNCE } else { 0 };
+                (PduType::Aspa, u16::from(flags) << 8)
+            }
+            Pdu::Other(_) => {
+                return Err(invalid_data("unsupported PDU type"));
+            }
+        };
+
+        // Encode PDU header.
+        buf.put_u8(VERSION);
+        buf.put_u8(pdu_type as u8);
+        buf.put_u16(session_id);
+        // The length field will be rewritten later.
+        buf.put_u32(0);
+
+        // Encode PDU body.
+        match self {
+            Pdu::SerialNotify { serial, .. }
+            | Pdu::SerialQuery { serial, .. } => {
+                buf.put_u32(*serial);
+            }
+            Pdu::EndOfData {
+                serial,
+                refresh_interval,
+                retry_interval,
+                expire_interval,
+                ..
+            } => {
+                buf.put_u32(*serial);
+                buf.put_u32(*refresh_interval);
+                buf.put_u32(*retry_interval);
+                buf.put_u32(*expire_interval);
+            }
+            Pdu::ErrorReport { text, .. } => {
+                // No erroneous PDU is encapsulated.
+                buf.put_u32(0);
+                buf.put_u32(text.len() as u32);
+                buf.put_slice(text.as_bytes());
+            }
+            Pdu::Aspa {
+                customer,
+                providers,
+                ..
+            } => {
+                buf.put_u32(*customer);
+                for provider in providers {
+                    buf.put_u32(*provider);
+                }
+            }
+            _ => (),
+        }
+
+        // Rewrite PDU length.
+        let len = buf.len() as u32;
+        buf[4..8].copy_from_slice(&len.to_be_bytes());
+
+        Ok(buf)
+    }
+
+    // Decodes a full PDU from a bytes buffer.
+    pub fn decode(data: &[u8]) -> Result<Self, std::io::Error> {
+        let mut buf = Bytes::copy_from_slice(data);
+        if buf.len() < HDR_LEN {
+            return Err(invalid_data("truncated PDU"));
+        }
+
+        // Parse PDU header.
+        let version = buf.get_u8();
+        let pdu_type = buf.get_u8();
+        let session_id = buf.get_u16();
+        let _len = buf.get_u32();
+
+        // Error reports are accepted regardless of the protocol version, so
+        // that version negotiation failures can be reported.
+        if version != VERSION && pdu_type != PduType::ErrorReport as u8 {
+            return Err(invalid_data("unsupported protocol version"));
+        }
+
+        // Parse PDU body.
+        let pdu = match pdu_type {
+            t if t == PduType::SerialNotify as u8 => Pdu::SerialNotify {
+                session_id,
+                serial: try_get_u32(&mut buf)?,
+            },
+            t if t == PduType::SerialQuery as u8 => Pdu::SerialQuery {
+                session_id,
+                serial: try_get_u32(&mut buf)?,
+            },
+            t if t == PduType::ResetQuery as u8 => Pdu::ResetQuery,
+            t if t == PduType::CacheResponse as u8 => {
+                Pdu::CacheResponse { session_id }
+            }
+            t if t == PduType::EndOfData as u8 => {
+                let serial = try_get_u32(&mut buf)?;
+                let refresh_interval = try_get_u32(&mut buf)?;
+                let retry_interval = try_get_u32(&mut buf)?;
+                let expire_interval = try_get_u32(&mut buf)?;
+
+                // RFC 8210 - Section 6:
+                // The timing parameters must be within the ranges specified
+                // for each one of them.
+                if !REFRESH_INTERVAL_RANGE.contains(&refresh_interval)
+                    || !RETRY_INTERVAL_RANGE.contains(&retry_interval)
+                    || !EXPIRE_INTERVAL_RANGE.contains(&expire_interval)
+                {
+                    return Err(invalid_data("invalid timing parameters"));
+                }
+
+                Pdu::EndOfData {
+                    session_id,
+                    serial,
+                    refresh_interval,
+                    retry_interval,
+                    expire_interval,
+                }
+            }
+            t if t == PduType::CacheReset as u8 => Pdu::CacheReset,
+            t if t == PduType::ErrorReport as u8 => {
+                let pdu_len = try_get_u32(&mut buf)? as usize;
+                if buf.remaining() < pdu_len {
+                    return Err(invalid_data("truncated error report"));
+                }
+                buf.advance(pdu_len);
+                let text_len = try_get_u32(&mut buf)? as usize;
+                if buf.remaining() < text_len {
+                    return Err(invalid_data("truncated error report"));
+                }
+                let text = buf.split_to(text_len);
+                Pdu::ErrorReport {
+                    code: session_id,
+                    text: String::from_utf8_lossy(&text).into_owned(),
+                }
+            }
+            t if t == PduType::Aspa as u8 => {
+                let flags = (session_id >> 8) as u8;
+                let customer = try_get_u32(&mut buf)?;
+                if !buf.remaining().is_multiple_of(4) {
+                    return Err(invalid_data("invalid ASPA PDU length"));
+                }
+                let mut providers = BTreeSet::new();
+                while buf.remaining() > 0 {
+                    providers.insert(buf.get_u32());
+                }
+                Pdu::Aspa {
+                    announce: flags & ASPA_FLAG_ANNOUNCE != 0,
+                    customer,
+                    providers,
+                }
+            }
+            _ => Pdu::Other(pdu_type),
+        };
+
+        Ok(pdu)
+    }
+
+    // Returns the length of the PDU at the start of the buffer, or `None` if
+    // the PDU header wasn't fully received yet.
+    pub fn get_pdu_len(data: &[u8]) -> Result<Option<usize>, std::io::Error> {
+        if data.len() < HDR_LEN {
+            return Ok(None);
+        }
+
+        let len = u32::from_be_bytes(data[4..8].try_into().unwrap()) as usize;
+        if !(HDR_LEN..=MAX_PDU_LEN).contains(&len) {
+            return Err(invalid_data("invalid PDU length"));
+        }
+
+        Ok(Some(len))
+    }
+}
+
+// ===== impl Session =====
+
+impl Session {
+    // Returns the query used to synchronize with the cache: a Serial Query
+    // when data from a previous session is held, or a Reset Query otherwise.
+    fn query(&self) -> Pdu {
+        match self.serial {
+            Some((session_id, serial)) => {
+                Pdu::SerialQuery { session_id, serial }
+            }
+            None => Pdu::ResetQuery,
+        }
+    }
+
+    // Returns whether the data held has expired.
+    fn expired(&self) -> bool {
+        self.last_sync.is_some_and(|last_sync| {
+            last_sync.elapsed() >= self.expire_interval
+        })
+    }
+
+    // Runs the RTR protocol over the given connection until an error occurs
+    // or the connection is closed.
+    async fn run(
+        &mut self,
+        stream: TcpStream,
+        aspa_db_updatep: &Sender<AspaDbUpdateMsg>,
+    ) -> Result<(), std::io::Error> {
+        let (mut read_half, mut write_half) = stream.into_split();
+        let mut buf = [0; MAX_PDU_LEN];
+        let mut data = Vec::with_capacity(MAX_PDU_LEN);
+
+        // Records being received in response to the last query.
+        let mut pending: Option<AspaDb> = None;
+
+        send(&mut write_half, &self.query()).await?;
+        let mut refresh = Instant::now() + self.refresh_interval;
+
+        loop {
+            tokio::select! {
+                result = read_half.read(&mut buf) => {
+                    let num_bytes = result?;
+                    if num_bytes == 0 {
+                        return Err(std::io::Error::new(
+                            std::io::ErrorKind::UnexpectedEof,
+                            "connection closed by the cache",
+                        ));
+                    }
+                    data.extend_from_slice(&buf[..num_bytes]);
+                }
+                _ = sleep_until(refresh) => {
+                    // Poll the cache for new data.
+                    if pending.is_none() {
+                        send(&mut write_half, &self.query()).await?;
+                    }
+                    refresh = Instant::now() + self.refresh_interval;
+                    continue;
+                }
+            }
+
+            // Decode and process PDU(s).
+            while let Some(pdu_len) = Pdu::get_pdu_len(&data)? {
+                if data.len() < pdu_len {
+                    break;
+                }
+                let pdu = Pdu::decode(&data[..pdu_len])?;
+                data.drain(..pdu_len);
+
+                match pdu {
+                    Pdu::SerialNotify { .. } => {
+                        // New data is available.
+                        if pending.is_none() {
+                            send(&mut write_half, &self.query()).await?;
+                        }
+                    }
+                    Pdu::CacheResponse { session_id } => {
+                        // Incremental updates are applied on top of the data
+                        // already held, while a full data set replaces it.
+                        pending = match self.serial {
+                            Some((held_session_id, _)) => {
+                                if session_id != held_session_id {
+                                    return Err(invalid_data(
+                                        "session ID mismatch",
+                                    ));
+                                }
+                                Some(self.db.clone())
+                            }
+                            None => Some(AspaDb::default()),
+                        };
+                    }
+                    Pdu::Aspa {
+                        announce,
+                        customer,
+                        providers,
+                    } => {
+                        let Some(db) = &mut pending else {
+                            continue;
+                        };
+                        if announce {
+                            db.records.insert(customer, providers);
+                        } else {
+                            db.records.remove(&customer);
+                        }
+                    }
+                    Pdu::EndOfData {
+                        session_id,
+                        serial,
+                        refresh_interval,
+                        retry_interval,
+                        expire_interval,
+                    } => {
+                        let Some(db) = pending.take() else {
+                            continue;
+                        };
+                        self.db = db;
+                        self.serial = Some((session_id, serial));
+                        self.refresh_interval =
+                            Duration::from_secs(refresh_interval.into());
+                        self.retry_interval =
+                            Duration::from_secs(retry_interval.into());
+                        self.expire_interval =
+                            Duration::from_secs(expire_interval.into());
+                        self.last_sync = Some(Instant::now());
+                        refresh = Instant::now() + self.refresh_interval;
+
+                        // Send the updated records to the BGP instance.
+                        let msg = AspaDbUpdateMsg {
+                            db: Some(self.db.clone()),
+                        };
+                        let _ = aspa_db_updatep.send(msg).await;
+                    }
+                    Pdu::CacheReset => {
+                        // The cache can't provide an incremental update.
+                        pending = None;
+                        self.serial = None;
+                        send(&mut write_half, &Pdu::ResetQuery).await?;
+                    }
+                    Pdu::ErrorReport { code, text } => {
+                        return Err(std::io::Error::other(format!(
+                            "error report received (code {code}): {text}"
+                        )));
+                    }
+                    Pdu::SerialQuery { .. } | Pdu::ResetQuery => {
+                        return Err(invalid_data("unexpected query PDU"));
+                    }
+                    Pdu::Other(_) => (),
+                }
+            }
+        }
+    }
+}
+
+impl Default for Session {
+    fn default() -> Session {
+        Session {
+            serial: None,
+            db: Default::default(),
+            refresh_interval: DFLT_REFRESH_INTERVAL,
+            retry_interval: DFLT_RETRY_INTERVAL,
+            expire_interval: DFLT_EXPIRE_INTERVAL,
+            last_sync: None,
+        }
+    }
+}
+
+// ===== global functions =====
+
+// Keeps the ASPA records synchronized with the given RTR cache, reconnecting
+// whenever the session fails.
+//
+// The records are discarded once they're older than the expire interval
+// provided by the cache.
+pub(crate) async fn client_loop(
+    addr: SocketAddr,
+    aspa_db_updatep: Sender<AspaDbUpdateMsg>,
+) {
+    let mut session = Session::default();
+
+    loop {
+        match TcpStream::connect(addr).await {
+            Ok(stream) => {
+                if let Err(error) = session.run(stream, &aspa_db_updatep).await
+                {
+                    IoError::RtrSessionError(error).log();
+                }
+            }
+            Err(error) => {
+                IoError::RtrConnectError(error).log();
+            }
+        }
+
+        if session.expired() {
+            session = Session::default();
+            let msg = AspaDbUpdateMsg { db: None };
+            let _ = aspa_db_updatep.send(msg).await;
+        }
+
+        sleep(session.retry_interval).await;
+    }
+}
+
+// ===== helper functions =====
+
+async fn send(
+    write_half: &mut OwnedWriteHalf,
+    pdu: &Pdu,
+) -> Result<(), std::io::Error> {
+    write_half.write_all(&pdu.encode()?).await
+}
+
+fn try_get_u32(buf: &mut Bytes) -> Result<u32, std::io::Error> {
+    buf.try_get_u32().map_err(|_| invalid_data("truncated PDU"))
+}
+
+fn invalid_data(error: &str) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, error)
+}
+
+// ===== unit tests =====
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reset_query() {
+        let pdu = Pdu::ResetQuery;
+        let bytes = [0x02, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08];
+        assert_eq!(pdu.encode().unwrap().as_ref(), bytes);
+        assert_eq!(Pdu::decode(&bytes).unwrap(), pdu);
+    }
+
+    #[test]
+    fn serial_query() {
+        let pdu = Pdu::SerialQuery {
+            session_id: 0x1234,
+            serial: 10,
+        };
+        let bytes = [
+            0x02, 0x01, 0x12, 0x34, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00,
+            0x0a,
+        ];
+        assert_eq!(pdu.encode().unwrap().as_ref(), bytes);
+        assert_eq!(Pdu::decode(&bytes).unwrap(), pdu);
+    }
+
+    #[test]
+    fn end_of_data() {
+        let pdu = Pdu::EndOfData {
+            session_id: 1,
+            serial: 2,
+            refresh_interval: 3600,
+            retry_interval: 600,
+            expire_interval: 7200,
+        };
+        let bytes = [
+            0x02, 0x07, 0x00, 0x01, 0x00, 0x00, 0x00, 0x18, 0x00, 0x00, 0x00,
+            0x02, 0x00, 0x00, 0x0e, 0x10, 0x00, 0x00, 0x02, 0x58, 0x00, 0x00,
+            0x1c, 0x20,
+        ];
+        assert_eq!(pdu.encode().unwrap().as_ref(), bytes);
+        assert_eq!(Pdu::decode(&bytes).unwrap(), pdu);
+    }
+
+    #[test]
+    fn end_of_data_invalid_intervals() {
+        for (refresh_interval, retry_interval, expire_interval) in [
+            (0, 600, 7200),
+            (86401, 600, 7200),
+            (3600, 0, 7200),
+            (3600, 7201, 7200),
+            (3600, 600, 599),
+            (3600, 600, 172801),
+        ] {
+            let pdu = Pdu::EndOfData {
+                session_id: 1,
+                serial: 2,
+                refresh_interval,
+                retry_interval,
+                expire_interval,
+            };
+            let bytes = pdu.encode().unwrap();
+            assert!(Pdu::decode(&bytes).is_err());
+        }
+    }
+
+    #[test]
+    fn aspa() {
+        let pdu = Pdu::Aspa {
+            announce: true,
+            customer: 65001,
+            providers: [65002, 65003].into(),
+        };
+        let bytes = [
+            0x02, 0x0b, 0x01, 0x00, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0xfd,
+            0xe9, 0x00, 0x00, 0xfd, 0xea, 0x00, 0x00, 0xfd, 0xeb,
+        ];
+        assert_eq!(pdu.encode().unwrap().as_ref(), bytes);
+        assert_eq!(Pdu::decode(&bytes).unwrap(), pdu);
+    }
+
+    #[test]
+    fn aspa_withdraw() {
+        let pdu = Pdu::Aspa {
+            announce: false,
+            customer: 65001,
+            providers: Default::default(),
+        };
+        let bytes = [
+            0x02, 0x0b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0xfd,
+            0xe9,
+        ];
+        assert_eq!(pdu.encode().unwrap().as_ref(), bytes);
+        assert_eq!(Pdu::decode(&bytes).unwrap(), pdu);
+    }
+
+    #[test]
+    fn error_report() {
+        let bytes = [
+            0x01, 0x0a, 0x00, 0x04, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x02, b'n', b'o',
+        ];
+        assert_eq!(
+            Pdu::decode(&bytes).unwrap(),
+            Pdu::ErrorReport {
+                code: 4,
+                text: "no".to_owned(),
+            }
+        );
+    }
+
+    #[test]
+    fn ipv4_prefix_ignored() {
+        let bytes = [
+            0x02, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x14, 0x01, 0x18, 0x18,
+            0x00, 0x0a, 0x00, 0x00, 0x00, 0x00, 0x00, 0xfd, 0xe9,
+        ];
+        assert_eq!(Pdu::get_pdu_len(&bytes).unwrap(), Some(20));
+        assert_eq!(Pdu::decode(&bytes).unwrap(), Pdu::Other(4));
+        assert!(Pdu::Other(4).encode().is_err());
+    }
+}
@@ -4,6 +4,9 @@
 // SPDX-License-Identifier: MIT
 //
 
+use std::collections::BTreeSet;
+use std::net::SocketAddr;
+use std::path::Path;
 use std::sync::{Arc, atomic};
 use std::time::Duration;
 
@@ -13,11 +16,15 @@ use tokio::sync::mpsc::{Sender, UnboundedReceiver, UnboundedSender};
 use tokio::time::sleep;
 use tracing::{Instrument, debug_span, error};
 
+use crate::aspa::AspaDb;
 use crate::debug::Debug;
-use crate::error::NbrRxError;
+use crate::error::{IoError, NbrRxError};
+use crate::mrt::MrtWriterMsg;
 use crate::neighbor::{Neighbor, fsm};
-use crate::packet::message::{DecodeCxt, EncodeCxt, KeepaliveMsg, Message};
-use crate::{network, policy};
+use crate::packet::message::{
+    DecodeCxt, EncodeCxt, KeepaliveMsg, Message, NegotiatedCapability,
+};
+use crate::{mrt, network, policy, rtr};
 
 //
 // BGP tasks diagram:
@@ -36,7 +43,8 @@ use crate::{network, policy};
 //                                     |              |
 //                policy_apply (Nx) -> |              | -> (Nx) policy_apply
 // schedule_decision_process (0/1x) -> |              |
-//                                     |              |
+//   mrt_table_dump_interval (0/1x) -> |              | -> (0/1x) mrt_writer
+//               aspa_source (0/1x) -> |              |
 //                                     +--------------+
 //                              ibus_tx (1x) | ^ (1x) ibus_rx
 //                                           | |
@@ -52,6 +60,7 @@ pub mod messages {
     use std::net::IpAddr;
     use std::sync::Arc;
 
+    use bytes::Bytes;
     use holo_utils::bgp::AfiSafi;
     use holo_utils::policy::{
         DefaultPolicyType, MatchSets, Policy, PolicyResult, PolicyType,
@@ -60,10 +69,12 @@ pub mod messages {
     use ipnetwork::IpNetwork;
     use serde::{Deserialize, Serialize};
 
+    use crate::aspa::AspaDb;
     use crate::error::NbrRxError;
     use crate::neighbor::fsm;
     use crate::packet::message::{Message, NegotiatedCapability};
     use crate::policy::RoutePolicyInfo;
+    use crate::update_group::UpdateGroupId;
 
     // Type aliases.
     pub type ProtocolInputMsg = input::ProtocolMsg;
@@ -81,6 +92,8 @@ pub mod messages {
             NbrTimer(NbrTimerMsg),
             PolicyResult(PolicyResultMsg),
             TriggerDecisionProcess(()),
+            MrtTableDump(()),
+            AspaDbUpdate(AspaDbUpdateMsg),
         }
 
         #[derive(Debug, Deserialize, Serialize)]
@@ -101,6 +114,14 @@ pub mod messages {
         pub struct NbrRxMsg {
             pub nbr_addr: IpAddr,
             pub msg: Result<Message, NbrRxError>,
+            // Raw message as received from the network.
+            #[serde(skip)]
+            pub raw: Option<Bytes>,
+        }
+
+        #[derive(Debug, Deserialize, Serialize)]
+        pub struct AspaDbUpdateMsg {
+            pub db: Option<AspaDb>,
         }
 
         #[derive(Debug, Deserialize, Serialize)]
@@ -122,6 +143,11 @@ pub mod messages {
                 prefix: IpNetwork,
                 result: PolicyResult<RoutePolicyInfo>,
             },
+            UpdateGroup {
+                group_id: UpdateGroupId,
+                afi_safi: AfiSafi,
+                routes: Vec<(IpNetwork, PolicyResult<RoutePolicyInfo>)>,
+            },
         }
 
         impl TcpAcceptMsg {
@@ -171,6 +197,12 @@ pub mod messages {
                 nbr_addr: IpAddr,
                 msg_list: Vec<Message>,
             },
+            SendEncodedList {
+                nbr_addr: IpAddr,
+                msg_list: Arc<Vec<Message>>,
+                #[serde(skip)]
+                buf: Bytes,
+            },
             UpdateCapabilities(BTreeSet<NegotiatedCapability>),
         }
 
@@ -199,6 +231,33 @@ pub mod messages {
                 #[serde(skip)]
                 default_policy: DefaultPolicyType,
             },
+            UpdateGroup {
+                group_id: UpdateGroupId,
+                afi_safi: AfiSafi,
+                routes: Vec<(IpNetwork, RoutePolicyInfo)>,
+                #[serde(skip)]
+                policies: Vec<Arc<Policy>>,
+                #[serde(skip)]
+                match_sets: Arc<MatchSets>,
+                #[serde(skip)]
+                default_policy: DefaultPolicyType,
+            },
+        }
+
+        impl NbrTxMsg {
+            // Returns the number of BGP messages carried by this message.
+            pub(crate) fn msg_count(&self) -> u32 {
+                match self {
+                    NbrTxMsg::SendMessage { .. } => 1,
+                    NbrTxMsg::SendMessageList { msg_list, .. } => {
+                        msg_list.len() as u32
+                    }
+                    NbrTxMsg::SendEncodedList { msg_list, .. } => {
+                        msg_list.len() as u32
+                    }
+                    NbrTxMsg::UpdateCapabilities(..) => 0,
+                }
+            }
         }
     }
 }
@@ -293,6 +352,7 @@ pub(crate) fn tcp_connect(
 pub(crate) fn nbr_rx(
     nbr: &Neighbor,
     cxt: DecodeCxt,
+    capabilities_local: BTreeSet<NegotiatedCapability>,
     read_half: OwnedReadHalf,
     nbr_msg_rxp: &Sender<messages::input::NbrRxMsg>,
 ) -> Task<()> {
@@ -321,6 +381,7 @@ pub(crate) fn nbr_rx(
                             read_half,
                             nbr_addr,
                             cxt,
+                            capabilities_local,
                             nbr_msg_rxp,
                         )
                         .await;
@@ -333,6 +394,7 @@ pub(crate) fn nbr_rx(
                     let msg = messages::input::NbrRxMsg {
                         nbr_addr,
                         msg: Err(NbrRxError::TcpConnClosed),
+                        raw: None,
                     };
                     let _ = nbr_msg_rxp.send(msg).await;
                 }
@@ -364,9 +426,17 @@ pub(crate) fn nbr_tx(
         let span2 = debug_span!("output");
         let _span2_guard = span2.enter();
 
+        let msg_tx_backlog = nbr.msg_tx_backlog.clone();
+
         Task::spawn(
             async move {
-                network::nbr_write_loop(write_half, cxt, msg_txc).await;
+                network::nbr_write_loop(
+                    write_half,
+                    cxt,
+                    msg_txc,
+                    msg_tx_backlog,
+                )
+                .await;
             }
             .in_current_span(),
         )
@@ -374,9 +444,12 @@ pub(crate) fn nbr_tx(
     #[cfg(feature = "testing")]
     {
         let proto_output_tx = proto_output_tx.clone();
+        let msg_tx_backlog = nbr.msg_tx_backlog.clone();
         Task::spawn(async move {
             // Relay message to the test framework.
             while let Some(msg) = msg_txc.recv().await {
+                msg_tx_backlog
+                    .fetch_sub(msg.msg_count(), atomic::Ordering::Relaxed);
                 let msg = messages::ProtocolOutputMsg::NbrTx(msg);
                 let _ = proto_output_tx.send(msg).await;
             }
@@ -388,7 +461,7 @@ pub(crate) fn nbr_tx(
 pub(crate) fn nbr_timer(
     nbr: &Neighbor,
     timer: fsm::Timer,
-    seconds: u16,
+    seconds: u32,
     nbr_timerp: &Sender<messages::input::NbrTimerMsg>,
 ) -> TimeoutTask {
     #[cfg(not(feature = "testing"))]
@@ -420,6 +493,7 @@ pub(crate) fn nbr_kalive_interval(
         let msg_txp = nbr.msg_txp.as_ref().unwrap().clone();
         let nbr_addr = nbr.remote_addr;
         let msg_counter = nbr.statistics.msgs_sent.total.clone();
+        let msg_tx_backlog = nbr.msg_tx_backlog.clone();
         let trace_opts = nbr.config.trace_opts.packets_resolved.clone();
 
         IntervalTask::new(
@@ -428,6 +502,7 @@ pub(crate) fn nbr_kalive_interval(
             move || {
                 let msg_txp = msg_txp.clone();
                 let msg_counter = msg_counter.clone();
+                let msg_tx_backlog = msg_tx_backlog.clone();
                 let trace_opts = trace_opts.clone();
 
                 async move {
@@ -440,6 +515,7 @@ pub(crate) fn nbr_kalive_interval(
                         nbr_addr,
                         msg,
                     };
+                    msg_tx_backlog.fetch_add(1, atomic::Ordering::Relaxed);
                     let _ = msg_txp.send(msg);
                     msg_counter.fetch_add(1, atomic::Ordering::Relaxed);
                 }
@@ -507,6 +583,24 @@ pub(crate) fn policy_apply(
                             &policy_resultp,
                         );
                     }
+                    messages::output::PolicyApplyMsg::UpdateGroup {
+                        group_id,
+                        afi_safi,
+                        routes,
+                        policies,
+                        match_sets,
+                        default_policy,
+                    } => {
+                        policy::update_group_apply(
+                            group_id,
+                            afi_safi,
+                            routes,
+                            &policies,
+                            &match_sets,
+                            default_policy,
+                            &policy_resultp,
+                        );
+                    }
                 }
             }
         })
@@ -517,6 +611,85 @@ pub(crate) fn policy_apply(
     }
 }
 
+// Periodic MRT table dumps.
+pub(crate) fn mrt_table_dump_interval(
+    interval: u32,
+    mrt_table_dumpp: &Sender<()>,
+) -> IntervalTask {
+    #[cfg(not(feature = "testing"))]
+    {
+        let mrt_table_dumpp = mrt_table_dumpp.clone();
+        IntervalTask::new(
+            Duration::from_secs(interval.into()),
+            false,
+            move || {
+                let mrt_table_dumpp = mrt_table_dumpp.clone();
+                async move {
+                    let _ = mrt_table_dumpp.send(()).await;
+                }
+            },
+        )
+    }
+    #[cfg(feature = "testing")]
+    {
+        IntervalTask {}
+    }
+}
+
+// ASPA records loading from a JSON file.
+pub(crate) fn aspa_file_read(
+    path: String,
+    aspa_db_updatep: &Sender<messages::input::AspaDbUpdateMsg>,
+) -> Task<()> {
+    let aspa_db_updatep = aspa_db_updatep.clone();
+    Task::spawn_blocking(move || {
+        let db = match AspaDb::read(Path::new(&path)) {
+            Ok(db) => Some(db),
+            Err(error) => {
+                IoError::AspaReadError(error).log();
+                None
+            }
+        };
+        let msg = messages::input::AspaDbUpdateMsg { db };
+        let _ = aspa_db_updatep.blocking_send(msg);
+    })
+}
+
+// ASPA records synchronization with an RTR cache.
+pub(crate) fn aspa_rtr_client(
+    addr: SocketAddr,
+    aspa_db_updatep: &Sender<messages::input::AspaDbUpdateMsg>,
+) -> Task<()> {
+    #[cfg(not(feature = "testing"))]
+    {
+        let span = debug_span!("rtr-cache", %addr);
+        let _span_guard = span.enter();
+
+        let aspa_db_updatep = aspa_db_updatep.clone();
+        Task::spawn(
+            async move {
+                rtr::client_loop(addr, aspa_db_updatep).await;
+            }
+            .in_current_span(),
+        )
+    }
+    #[cfg(feature = "testing")]
+    {
+        Task::spawn(async move { std::future::pending().await })
+    }
+}
+
+// MRT file writer.
+pub(crate) fn mrt_writer(
+    directory: String,
+    rotation_interval: Duration,
+    rx: UnboundedReceiver<MrtWriterMsg>,
+) -> Task<()> {
+    Task::spawn_blocking(move || {
+        mrt::writer_loop(directory, rotation_interval, rx)
+    })
+}
+
 // Timeout to trigger the decision process.
 pub(crate) fn schedule_decision_process(
     decision_processp: &Sender<()>,
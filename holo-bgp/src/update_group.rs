@@ -0,0 +1,637 @@
+//
+// Copyright (c) The Holo Core Contributors
+//
+// SPDX-License-Identifier: MIT
+//
+
+use std::collections::{BTreeMap, BTreeSet, btree_map};
+use std::net::IpAddr;
+use std::sync::Arc;
+
+use bytes::{Bytes, BytesMut};
+use holo_utils::bgp::AfiSafi;
+use holo_utils::ip::IpNetworkKind;
+use holo_utils::policy::DefaultPolicyType;
+use ipnetwork::IpNetwork;
+
+use crate::af::{AddressFamily, Ipv4Unicast, Ipv6Unicast};
+use crate::debug::Debug;
+use crate::instance::InstanceUpView;
+use crate::neighbor::{
+    Neighbor, NeighborUpdateQueue, NeighborUpdateQueues, Neighbors, PeerType,
+    fsm,
+};
+use crate::northbound::configuration::CondAdvCfg;
+use crate::packet::message::{
+    EncodeCxt, Message, NegotiatedCapability, PrefixOrfEntry,
+};
+use crate::rib::{AttrSetsCxt, RoutingTable};
+
+// Number of messages pending transmission above which a neighbor is
+// considered too slow to keep up with its update group.
+pub const SLOW_PEER_THRESHOLD: u32 = 4096;
+
+// Update group identifier.
+pub type UpdateGroupId = u32;
+
+// Dynamic update groups.
+//
+// Neighbors sharing the same outbound parameters are placed in the same
+// update group. Export policies are evaluated once per group, and the
+// resulting UPDATE messages are encoded once and fanned out to all members.
+//
+// Groups with a single member are handled like standalone neighbors.
+#[derive(Debug, Default)]
+pub struct UpdateGroups {
+    pub groups: BTreeMap<UpdateGroupId, UpdateGroup>,
+    pub keys: BTreeMap<UpdateGroupKey, UpdateGroupId>,
+    next_id: UpdateGroupId,
+}
+
+#[derive(Debug)]
+pub struct UpdateGroup {
+    pub id: UpdateGroupId,
+    pub key: UpdateGroupKey,
+    pub members: BTreeSet<IpAddr>,
+    // Whether this group holds a slow peer that was split off from its
+    // original group.
+    pub split: bool,
+    pub update_queues: NeighborUpdateQueues,
+    // Prefixes whose export policy results are in flight, along with the
+    // number of pending policy runs for each of them.
+    pub policy_in_flight: BTreeMap<AfiSafi, BTreeMap<IpNetwork, usize>>,
+    pub statistics: UpdateGroupStatistics,
+}
+
+// Outbound parameters that must match for neighbors to share an update group.
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct UpdateGroupKey {
+    pub peer_type: PeerType,
+    pub peer_as: u32,
+    pub local_addr: Option<IpAddr>,
+    pub shared_subnet: bool,
+    pub disable_peer_as_filter: bool,
+    pub graceful_shutdown: bool,
+    pub aigp: bool,
+    pub capabilities: BTreeSet<NegotiatedCapability>,
+    pub export_policy: BTreeMap<AfiSafi, UpdateGroupExportPolicy>,
+}
+
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct UpdateGroupExportPolicy {
+    pub policies: BTreeSet<String>,
+    pub default_policy: DefaultPolicyType,
+    pub cond_adv: CondAdvCfg,
+    pub orf: BTreeMap<u32, PrefixOrfEntry>,
+}
+
+#[derive(Debug, Default)]
+pub struct UpdateGroupStatistics {
+    pub policy_runs: u64,
+    pub msgs_encoded: u64,
+    pub msgs_fanned_out: u64,
+    pub splits: u32,
+}
+
+// ===== impl UpdateGroups =====
+
+impl UpdateGroups {
+    // Adds the neighbor to the update group matching its outbound parameters,
+    // creating a new group if necessary.
+    pub fn join(&mut self, nbr: &mut Neighbor) {
+        let key = UpdateGroupKey::new(nbr);
+        let group_id = match self.keys.get(&key) {
+            Some(group_id) => *group_id,
+            None => {
+                let group_id = self.group_create(key.clone(), false).id;
+                self.keys.insert(key, group_id);
+                group_id
+            }
+        };
+
+        let group = self.groups.get_mut(&group_id).unwrap();
+        group.members.insert(nbr.remote_addr);
+        nbr.update_group = Some(group_id);
+        Debug::UpdateGroupJoin(&nbr.remote_addr, group_id).log();
+    }
+
+    // Removes the neighbor from its update group, deleting the group if it
+    // becomes empty.
+    pub fn leave(&mut self, nbr: &mut Neighbor) {
+        let Some(group_id) = nbr.update_group.take() else {
+            return;
+        };
+        let Some(group) = self.groups.get_mut(&group_id) else {
+            return;
+        };
+
+        group.members.remove(&nbr.remote_addr);
+        Debug::UpdateGroupLeave(&nbr.remote_addr, group_id).log();
+        if group.members.is_empty() {
+            let group = self.groups.remove(&group_id).unwrap();
+            if !group.split {
+                self.keys.remove(&group.key);
+            }
+        }
+    }
+
+    // Moves the neighbor to a different update group if its outbound
+    // parameters have changed.
+    pub fn update(&mut self, nbr: &mut Neighbor) {
+        let Some(group_id) = nbr.update_group else {
+            return;
+        };
+        if let Some(group) = self.groups.get(&group_id)
+            && group.key == UpdateGroupKey::new(nbr)
+        {
+            return;
+        }
+
+        self.leave(nbr);
+        self.join(nbr);
+    }
+
+    // Splits a slow neighbor off from its update group, placing it in a
+    // dedicated group until it catches up.
+    pub(crate) fn split(&mut self, nbr: &mut Neighbor) {
+        let Some(group_id) = nbr.update_group else {
+            return;
+        };
+        let Some(group) = self.groups.get_mut(&group_id) else {
+            return;
+        };
+        if group.members.len() == 1 {
+            return;
+        }
+        group.statistics.splits += 1;
+        let key = group.key.clone();
+
+        self.leave(nbr);
+        let group = self.group_create(key, true);
+        group.members.insert(nbr.remote_addr);
+        nbr.update_group = Some(group.id);
+        Debug::UpdateGroupSplit(&nbr.remote_addr, group.id).log();
+    }
+
+    // Merges split-off neighbors that have drained their Tx backlog back into
+    // their original update groups.
+    pub(crate) fn merge_recovered(&mut self, neighbors: &mut Neighbors) {
+        let recovered = self
+            .groups
+            .values()
+            .filter(|group| group.split)
+            .flat_map(|group| group.members.iter())
+            .filter(|addr| {
+                neighbors
+                    .get(addr)
+                    .is_some_and(|nbr| nbr.msg_tx_backlog() == 0)
+            })
+            .copied()
+            .collect::<Vec<_>>();
+
+        for addr in recovered {
+            let nbr = neighbors.get_mut(&addr).unwrap();
+            self.leave(nbr);
+            self.join(nbr);
+        }
+    }
+
+    fn group_create(
+        &mut self,
+        key: UpdateGroupKey,
+        split: bool,
+    ) -> &mut UpdateGroup {
+        self.next_id += 1;
+        let id = self.next_id;
+        let group = UpdateGroup {
+            id,
+            key,
+            members: Default::default(),
+            split,
+            update_queues: Default::default(),
+            policy_in_flight: Default::default(),
+            statistics: Default::default(),
+        };
+        self.groups.entry(id).or_insert(group)
+    }
+}
+
+// ===== impl UpdateGroup =====
+
+impl UpdateGroup {
+    // Returns whether the group has more than one member, in which case
+    // outbound processing is performed on behalf of all members at once.
+    pub(crate) fn is_shared(&self) -> bool {
+        self.members.len() > 1
+    }
+
+    // Returns the export policy configured for the given address-family.
+    pub(crate) fn export_policy(
+        &self,
+        afi_safi: AfiSafi,
+    ) -> Option<&UpdateGroupExportPolicy> {
+        self.key.export_policy.get(&afi_safi)
+    }
+
+    // Records that export policy results for the given prefixes are in
+    // flight.
+    pub(crate) fn policy_dispatched(
+        &mut self,
+        afi_safi: AfiSafi,
+        prefixes: impl IntoIterator<Item = IpNetwork>,
+    ) {
+        let in_flight = self.policy_in_flight.entry(afi_safi).or_default();
+        for prefix in prefixes {
+            *in_flight.entry(prefix).or_default() += 1;
+        }
+    }
+
+    // Records that export policy results for the given prefixes were
+    // received.
+    pub(crate) fn policy_completed(
+        &mut self,
+        afi_safi: AfiSafi,
+        prefixes: impl IntoIterator<Item = IpNetwork>,
+    ) {
+        let Some(in_flight) = self.policy_in_flight.get_mut(&afi_safi) else {
+            return;
+        };
+        for prefix in prefixes {
+            if let btree_map::Entry::Occupied(mut entry) =
+                in_flight.entry(prefix)
+            {
+                *entry.get_mut() -= 1;
+                if *entry.get() == 0 {
+                    entry.remove();
+                }
+            }
+        }
+        if in_flight.is_empty() {
+            self.policy_in_flight.remove(&afi_safi);
+        }
+    }
+
+    // Returns the prefixes of the given address-family whose export policy
+    // results are in flight.
+    fn policy_in_flight<A>(&self) -> BTreeSet<A::IpNetwork>
+    where
+        A: AddressFamily,
+    {
+        self.policy_in_flight
+            .get(&A::AFI_SAFI)
+            .into_iter()
+            .flat_map(|in_flight| in_flight.keys())
+            .filter_map(|prefix| A::IpNetwork::get(*prefix))
+            .collect()
+    }
+
+    // Builds the UPDATE messages pending in the group's Tx queue, encodes
+    // them once and sends them to all group members.
+    //
+    // Members that must not receive some of the queued prefixes get UPDATE
+    // messages built specifically for them.
+    //
+    // Returns the list of members that are lagging behind.
+    pub(crate) fn flush<A>(
+        &mut self,
+        neighbors: &mut Neighbors,
+        table: &mut RoutingTable<A>,
+        attr_sets: &mut AttrSetsCxt,
+        excluded: &BTreeMap<IpAddr, BTreeSet<A::IpNetwork>>,
+    ) -> Vec<IpAddr>
+    where
+        A: AddressFamily,
+    {
+        let queue = A::update_queue(&mut self.update_queues);
+        if queue.reach.is_empty() && queue.unreach.is_empty() {
+            return vec![];
+        }
+
+        // Build member-specific messages first.
+        let max_len = Message::max_len(&self.key.capabilities);
+        let mut member_msgs = excluded
+            .iter()
+            .filter(|(addr, _)| self.members.contains(addr))
+            .map(|(addr, prefixes)| {
+                let mut queue = queue.filtered(prefixes);
+                let msg_list =
+                    queue.build_updates([*addr], table, attr_sets, max_len);
+                (*addr, msg_list)
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        // Build and encode the messages shared by the remaining members.
+        let members = self
+            .members
+            .iter()
+            .filter(|addr| !member_msgs.contains_key(addr))
+            .copied()
+            .collect::<Vec<_>>();
+        let msg_list = queue.build_updates(members, table, attr_sets, max_len);
+        let cxt = EncodeCxt {
+            capabilities: self.key.capabilities.clone(),
+        };
+        let buf = encode_messages(&msg_list, &cxt);
+        let msg_list = Arc::new(msg_list);
+        self.statistics.msgs_encoded += msg_list.len() as u64;
+
+        // Fan out messages.
+        let mut slow_peers = vec![];
+        for addr in &self.members {
+            let Some(nbr) = neighbors.get_mut(addr) else {
+                continue;
+            };
+            if nbr.state != fsm::State::Established {
+                continue;
+            }
+
+            match member_msgs.remove(addr) {
+                Some(msg_list) => {
+                    if !msg_list.is_empty() {
+                        nbr.message_list_send(msg_list);
+                    }
+                }
+                None => {
+                    self.statistics.msgs_fanned_out += msg_list.len() as u64;
+                    nbr.message_list_send_encoded(
+                        msg_list.clone(),
+                        buf.clone(),
+                    );
+                }
+            }
+
+            if nbr.msg_tx_backlog() > SLOW_PEER_THRESHOLD {
+                slow_peers.push(*addr);
+            }
+        }
+
+        slow_peers
+    }
+}
+
+// ===== impl UpdateGroupKey =====
+
+impl UpdateGroupKey {
+    pub(crate) fn new(nbr: &Neighbor) -> UpdateGroupKey {
+        let export_policy = [
+            (AfiSafi::Ipv4Unicast, Ipv4Unicast::AFI, Ipv4Unicast::SAFI),
+            (AfiSafi::Ipv6Unicast, Ipv6Unicast::AFI, Ipv6Unicast::SAFI),
+        ]
+        .into_iter()
+        .filter(|(_, afi, safi)| nbr.is_af_enabled(*afi, *safi))
+        .map(|(afi_safi, _, _)| {
+            let apply_policy_cfg = nbr
+                .config
+                .afi_safi
+                .get(&afi_safi)
+                .map(|afi_safi| &afi_safi.apply_policy)
+                .unwrap_or(&nbr.config.apply_policy);
+            let policy = UpdateGroupExportPolicy {
+                policies: apply_policy_cfg.export_policy.clone(),
+                default_policy: apply_policy_cfg.default_export_policy,
+                cond_adv: nbr
+                    .config
+                    .afi_safi
+                    .get(&afi_safi)
+                    .map(|afi_safi| afi_safi.cond_adv.clone())
+                    .unwrap_or_default(),
+                orf: nbr.orf_rcvd.get(&afi_safi).cloned().unwrap_or_default(),
+            };
+            (afi_safi, policy)
+        })
+        .collect();
+
+        UpdateGroupKey {
+            peer_type: nbr.peer_type,
+            peer_as: nbr.config.peer_as,
+            local_addr: nbr.conn_info.as_ref().map(|conn| conn.local_addr),
+            shared_subnet: nbr.shared_subnet,
+            disable_peer_as_filter: nbr
+                .config
+                .as_path_options
+                .disable_peer_as_filter,
+            graceful_shutdown: nbr.graceful_shutdown,
+            aigp: nbr.aigp_enabled(),
+            capabilities: nbr.capabilities_nego.clone(),
+            export_policy,
+        }
+    }
+}
+
+// ===== impl NeighborUpdateQueue =====
+
+impl<A> NeighborUpdateQueue<A>
+where
+    A: AddressFamily,
+{
+    // Returns a copy of the queue without the given prefixes.
+    fn filtered(
+        &self,
+        excluded: &BTreeSet<A::IpNetwork>,
+    ) -> NeighborUpdateQueue<A> {
+        NeighborUpdateQueue {
+            reach: self
+                .reach
+                .iter()
+                .map(|(attrs, prefixes)| {
+                    (
+                        attrs.clone(),
+                        prefixes.difference(excluded).copied().collect(),
+                    )
+                })
+                .filter(|(_, prefixes): &(_, BTreeSet<_>)| !prefixes.is_empty())
+                .collect(),
+            unreach: self.unreach.difference(excluded).copied().collect(),
+        }
+    }
+}
+
+// ===== global functions =====
+
+// Encodes a list of BGP messages into a single contiguous buffer.
+pub fn encode_messages(msg_list: &[Message], cxt: &EncodeCxt) -> Bytes {
+    let mut buf = BytesMut::new();
+    for msg in msg_list {
+        buf.extend_from_slice(&msg.encode(cxt));
+    }
+    buf.freeze()
+}
+
+// Splits off the given slow peers from their update groups.
+//
+// Since export policy results that are still in flight won't reach the split
+// peers anymore, the corresponding routes are readvertised to them from the
+// Loc-RIB.
+pub(crate) fn split_slow_peers(
+    instance: &mut InstanceUpView<'_>,
+    neighbors: &mut Neighbors,
+    slow_peers: Vec<IpAddr>,
+) {
+    for addr in slow_peers {
+        let nbr = neighbors.get_mut(&addr).unwrap();
+        let Some(group) = nbr.update_group.and_then(|group_id| {
+            instance.state.update_groups.groups.get(&group_id)
+        }) else {
+            continue;
+        };
+        let ipv4_in_flight = group.policy_in_flight::<Ipv4Unicast>();
+        let ipv6_in_flight = group.policy_in_flight::<Ipv6Unicast>();
+
+        instance.state.update_groups.split(nbr);
+        nbr.routes_resync::<Ipv4Unicast>(instance, ipv4_in_flight);
+        nbr.routes_resync::<Ipv6Unicast>(instance, ipv6_in_flight);
+    }
+}
+
+// ===== unit tests =====
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic;
+
+    use const_addrs::{ip, net4};
+
+    use super::*;
+    use crate::packet::attribute::{Attrs, BaseAttrs};
+
+    fn neighbor(addr: IpAddr, peer_as: u32) -> Neighbor {
+        let mut nbr = Neighbor::new(addr, PeerType::External);
+        nbr.config.peer_as = peer_as;
+        nbr
+    }
+
+    #[test]
+    fn join_same_key() {
+        let mut groups = UpdateGroups::default();
+        let mut nbr1 = neighbor(ip!("10.0.0.1"), 65001);
+        let mut nbr2 = neighbor(ip!("10.0.0.2"), 65001);
+        let mut nbr3 = neighbor(ip!("10.0.0.3"), 65002);
+
+        groups.join(&mut nbr1);
+        groups.join(&mut nbr2);
+        groups.join(&mut nbr3);
+        assert_eq!(nbr1.update_group, nbr2.update_group);
+        assert_ne!(nbr1.update_group, nbr3.update_group);
+        assert_eq!(groups.groups.len(), 2);
+
+        let group = &groups.groups[&nbr1.update_group.unwrap()];
+        assert!(group.is_shared());
+        assert_eq!(group.members.len(), 2);
+    }
+
+    #[test]
+    fn leave_and_update() {
+        let mut groups = UpdateGroups::default();
+        let mut nbr1 = neighbor(ip!("10.0.0.1"), 65001);
+        let mut nbr2 = neighbor(ip!("10.0.0.2"), 65001);
+        groups.join(&mut nbr1);
+        groups.join(&mut nbr2);
+
+        // Changing the outbound parameters moves the neighbor to a
+        // different group.
+        nbr2.config.as_path_options.disable_peer_as_filter = true;
+        groups.update(&mut nbr2);
+        assert_ne!(nbr1.update_group, nbr2.update_group);
+        assert_eq!(groups.groups.len(), 2);
+
+        // Empty groups are deleted.
+        groups.leave(&mut nbr1);
+        groups.leave(&mut nbr2);
+        assert!(nbr1.update_group.is_none());
+        assert!(groups.groups.is_empty());
+        assert!(groups.keys.is_empty());
+    }
+
+    #[test]
+    fn split_and_merge() {
+        let mut groups = UpdateGroups::default();
+        let mut neighbors = Neighbors::new();
+        for addr in [ip!("10.0.0.1"), ip!("10.0.0.2")] {
+            let mut nbr = neighbor(addr, 65001);
+            groups.join(&mut nbr);
+            neighbors.insert(addr, nbr);
+        }
+        let group_id = neighbors[&ip!("10.0.0.1")].update_group;
+
+        // Split off a slow neighbor.
+        let nbr = neighbors.get_mut(&ip!("10.0.0.2")).unwrap();
+        nbr.msg_tx_backlog
+            .store(SLOW_PEER_THRESHOLD + 1, atomic::Ordering::Relaxed);
+        groups.split(nbr);
+        assert_ne!(nbr.update_group, group_id);
+        assert!(groups.groups[&nbr.update_group.unwrap()].split);
+        assert!(!groups.groups[&group_id.unwrap()].is_shared());
+
+        // The neighbor stays split while its backlog isn't drained.
+        groups.merge_recovered(&mut neighbors);
+        assert_ne!(neighbors[&ip!("10.0.0.2")].update_group, group_id);
+
+        // Merge the neighbor back once it caught up.
+        let nbr = neighbors.get_mut(&ip!("10.0.0.2")).unwrap();
+        nbr.msg_tx_backlog.store(0, atomic::Ordering::Relaxed);
+        groups.merge_recovered(&mut neighbors);
+        assert_eq!(neighbors[&ip!("10.0.0.2")].update_group, group_id);
+        assert_eq!(groups.groups.len(), 1);
+    }
+
+    #[test]
+    fn policy_in_flight() {
+        let mut groups = UpdateGroups::default();
+        let mut nbr = neighbor(ip!("10.0.0.1"), 65001);
+        groups.join(&mut nbr);
+        let group = groups.groups.get_mut(&nbr.update_group.unwrap()).unwrap();
+
+        // The same prefix might be part of multiple policy runs.
+        let prefix1 = IpNetwork::V4(net4!("10.0.1.0/24"));
+        let prefix2 = IpNetwork::V4(net4!("10.0.2.0/24"));
+        group.policy_dispatched(AfiSafi::Ipv4Unicast, [prefix1, prefix2]);
+        group.policy_dispatched(AfiSafi::Ipv4Unicast, [prefix1]);
+        assert_eq!(
+            group.policy_in_flight::<Ipv4Unicast>(),
+            [net4!("10.0.1.0/24"), net4!("10.0.2.0/24")].into()
+        );
+        assert!(group.policy_in_flight::<Ipv6Unicast>().is_empty());
+
+        // Prefixes stay in flight until all policy runs are completed.
+        group.policy_completed(AfiSafi::Ipv4Unicast, [prefix1, prefix2]);
+        assert_eq!(
+            group.policy_in_flight::<Ipv4Unicast>(),
+            [net4!("10.0.1.0/24")].into()
+        );
+        group.policy_completed(AfiSafi::Ipv4Unicast, [prefix1]);
+        assert!(group.policy_in_flight.is_empty());
+    }
+
+    #[test]
+    fn filtered_queue() {
+        let attrs = Attrs {
+            base: BaseAttrs {
+                nexthop: Some(ip!("1.1.1.1")),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let queue = NeighborUpdateQueue::<Ipv4Unicast> {
+            reach: [(
+                attrs,
+                [net4!("10.0.1.0/24"), net4!("10.0.2.0/24")].into(),
+            )]
+            .into(),
+            unreach: [net4!("10.0.3.0/24")].into(),
+        };
+
+        let excluded = [net4!("10.0.1.0/24"), net4!("10.0.3.0/24")].into();
+        let filtered = queue.filtered(&excluded);
+        assert_eq!(filtered.reach.len(), 1);
+        assert_eq!(
+            filtered.reach.values().next().unwrap(),
+            &[net4!("10.0.2.0/24")].into()
+        );
+        assert!(filtered.unreach.is_empty());
+
+        let excluded = [net4!("10.0.1.0/24"), net4!("10.0.2.0/24")].into();
+        let filtered = queue.filtered(&excluded);
+        assert!(filtered.reach.is_empty());
+        assert_eq!(filtered.unreach.len(), 1);
+    }
+}
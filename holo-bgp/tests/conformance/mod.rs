@@ -5,3 +5,43 @@
 //
 
 mod topologies;
+
+use holo_bgp::instance::Instance;
+use holo_protocol::test::stub::run_test;
+
+// Test description:
+//
+// Input:
+//  * Ibus: policy match sets and the ADV and COND policies. COND matches a
+//    prefix that isn't present in the Loc-RIB
+// Output: no changes
+//
+// Input:
+//  * Northbound: advertise the routes accepted by ADV to rt2 only if a route
+//    accepted by COND exists
+// Output:
+//  * Protocol: withdraw 1.1.1.1/32 from rt2
+//  * Northbound: conditional advertisement state for rt2 is "false"
+//
+// Input:
+//  * Ibus: update the COND-SET prefix set to match 3.3.3.3/32, which is present
+//    in the Loc-RIB
+// Output:
+//  * Protocol: re-advertise 1.1.1.1/32 to rt2
+//  * Northbound: conditional advertisement state for rt2 is "true"
+//
+// Input:
+//  * Ibus: add the OTHER-SET prefix set and the OTHER policy, neither of which
+//    is used by the conditional advertisement
+// Output: no changes
+//
+// Input:
+//  * Ibus: update the COND policy to match OTHER-SET, whose prefix isn't
+//    present in the Loc-RIB
+// Output:
+//  * Protocol: withdraw 1.1.1.1/32 from rt2 (and nothing else)
+//  * Northbound: conditional advertisement state for rt2 is "false"
+#[tokio::test]
+async fn nb_config_cond_adv1() {
+    run_test::<Instance>("nb-config-cond-adv1", "topo1-1", "rt1").await;
+}
@@ -7,8 +7,10 @@
 use std::sync::LazyLock as Lazy;
 
 use const_addrs::ip4;
-use holo_bgp::packet::iana::{Afi, Safi};
-use holo_bgp::packet::message::{Capability, Message, OpenMsg};
+use holo_bgp::packet::iana::{Afi, OrfType, Safi};
+use holo_bgp::packet::message::{
+    Capability, LlgrTuple, Message, OpenMsg, OrfMode, OrfTuple,
+};
 
 use super::{test_decode_msg, test_encode_msg};
 
@@ -84,6 +86,77 @@ static OPEN3: Lazy<(Vec<u8>, Message)> = Lazy::new(|| {
     )
 });
 
+static OPEN4: Lazy<(Vec<u8>, Message)> = Lazy::new(|| {
+    (
+        vec![
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x21, 0x01, 0x04, 0x00, 0x01,
+            0x00, 0xb4, 0x01, 0x01, 0x01, 0x01, 0x04, 0x02, 0x02, 0x06, 0x00,
+        ],
+        Message::Open(OpenMsg {
+            version: OpenMsg::VERSION,
+            my_as: 1,
+            holdtime: 180,
+            identifier: ip4!("1.1.1.1"),
+            capabilities: [Capability::ExtendedMessage].into(),
+        }),
+    )
+});
+
+static OPEN5: Lazy<(Vec<u8>, Message)> = Lazy::new(|| {
+    (
+        vec![
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x28, 0x01, 0x04, 0x00, 0x01,
+            0x00, 0xb4, 0x01, 0x01, 0x01, 0x01, 0x0b, 0x02, 0x09, 0x03, 0x07,
+            0x00, 0x01, 0x00, 0x01, 0x01, 0x40, 0x03,
+        ],
+        Message::Open(OpenMsg {
+            version: OpenMsg::VERSION,
+            my_as: 1,
+            holdtime: 180,
+            identifier: ip4!("1.1.1.1"),
+            capabilities: [Capability::Orf(
+                [OrfTuple {
+                    afi: Afi::Ipv4,
+                    safi: Safi::Unicast,
+                    orf_type: OrfType::AddressPrefix,
+                    mode: OrfMode::Both,
+                }]
+                .into(),
+            )]
+            .into(),
+        }),
+    )
+});
+
+static OPEN6: Lazy<(Vec<u8>, Message)> = Lazy::new(|| {
+    (
+        vec![
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x28, 0x01, 0x04, 0x00, 0x01,
+            0x00, 0xb4, 0x01, 0x01, 0x01, 0x01, 0x0b, 0x02, 0x09, 0x47, 0x07,
+            0x00, 0x01, 0x01, 0x80, 0x01, 0x51, 0x80,
+        ],
+        Message::Open(OpenMsg {
+            version: OpenMsg::VERSION,
+            my_as: 1,
+            holdtime: 180,
+            identifier: ip4!("1.1.1.1"),
+            capabilities: [Capability::LongLivedGracefulRestart(
+                [LlgrTuple {
+                    afi: Afi::Ipv4,
+                    safi: Safi::Unicast,
+                    forwarding: true,
+                    stale_time: 86400,
+                }]
+                .into(),
+            )]
+            .into(),
+        }),
+    )
+});
+
 #[test]
 fn test_encode_open1() {
     let (ref bytes, ref msg) = *OPEN1;
@@ -119,3 +192,39 @@ fn test_decode_open3() {
     let (ref bytes, ref msg) = *OPEN3;
     test_decode_msg(bytes, msg);
 }
+
+#[test]
+fn test_encode_open4() {
+    let (ref bytes, ref msg) = *OPEN4;
+    test_encode_msg(bytes, msg);
+}
+
+#[test]
+fn test_decode_open4() {
+    let (ref bytes, ref msg) = *OPEN4;
+    test_decode_msg(bytes, msg);
+}
+
+#[test]
+fn test_encode_open5() {
+    let (ref bytes, ref msg) = *OPEN5;
+    test_encode_msg(bytes, msg);
+}
+
+#[test]
+fn test_decode_open5() {
+    let (ref bytes, ref msg) = *OPEN5;
+    test_decode_msg(bytes, msg);
+}
+
+#[test]
+fn test_encode_open6() {
+    let (ref bytes, ref msg) = *OPEN6;
+    test_encode_msg(bytes, msg);
+}
+
+#[test]
+fn test_decode_open6() {
+    let (ref bytes, ref msg) = *OPEN6;
+    test_decode_msg(bytes, msg);
+}
@@ -6,8 +6,12 @@
 
 use std::sync::LazyLock as Lazy;
 
-use holo_bgp::packet::iana::{Afi, Safi};
-use holo_bgp::packet::message::{Message, RouteRefreshMsg};
+use const_addrs::net;
+use holo_bgp::packet::iana::{Afi, OrfType, Safi};
+use holo_bgp::packet::message::{
+    Message, OrfEntry, OrfWhenToRefresh, PrefixOrfEntry, RouteRefreshMsg,
+    RouteRefreshOrf,
+};
 
 use super::{test_decode_msg, test_encode_msg};
 
@@ -21,6 +25,44 @@ static ROUTE_REFRESH1: Lazy<(Vec<u8>, Message)> = Lazy::new(|| {
         Message::RouteRefresh(RouteRefreshMsg {
             afi: Afi::Ipv4 as u16,
             safi: Safi::Unicast as u8,
+            orf: None,
+        }),
+    )
+});
+
+static ROUTE_REFRESH2: Lazy<(Vec<u8>, Message)> = Lazy::new(|| {
+    (
+        vec![
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x2f, 0x05, 0x00, 0x01, 0x00,
+            0x01, 0x01, 0x40, 0x00, 0x14, 0x80, 0x00, 0x00, 0x00, 0x00, 0x05,
+            0x00, 0x00, 0x08, 0x0a, 0x20, 0x00, 0x00, 0x00, 0x0a, 0x18, 0x20,
+            0x10, 0xac, 0x10,
+        ],
+        Message::RouteRefresh(RouteRefreshMsg {
+            afi: Afi::Ipv4 as u16,
+            safi: Safi::Unicast as u8,
+            orf: Some(RouteRefreshOrf {
+                when_to_refresh: OrfWhenToRefresh::Immediate,
+                orf_type: OrfType::AddressPrefix,
+                entries: vec![
+                    OrfEntry::RemoveAll,
+                    OrfEntry::Add(PrefixOrfEntry {
+                        seqno: 5,
+                        permit: true,
+                        min_len: 0,
+                        max_len: 0,
+                        prefix: net!("10.0.0.0/8"),
+                    }),
+                    OrfEntry::Add(PrefixOrfEntry {
+                        seqno: 10,
+                        permit: false,
+                        min_len: 24,
+                        max_len: 32,
+                        prefix: net!("172.16.0.0/16"),
+                    }),
+                ],
+            }),
         }),
     )
 });
@@ -36,3 +78,15 @@ fn test_decode_route_refresh1() {
     let (ref bytes, ref msg) = *ROUTE_REFRESH1;
     test_decode_msg(bytes, msg);
 }
+
+#[test]
+fn test_encode_route_refresh2() {
+    let (ref bytes, ref msg) = *ROUTE_REFRESH2;
+    test_encode_msg(bytes, msg);
+}
+
+#[test]
+fn test_decode_route_refresh2() {
+    let (ref bytes, ref msg) = *ROUTE_REFRESH2;
+    test_decode_msg(bytes, msg);
+}
@@ -12,7 +12,7 @@ use holo_bgp::packet::attribute::{
     Aggregator, AsPath, AsPathSegment, AsPathSegmentType, Attrs, BaseAttrs,
     ClusterList, CommList,
 };
-use holo_bgp::packet::iana::Origin;
+use holo_bgp::packet::iana::{Afi, Origin};
 use holo_bgp::packet::message::{
     DecodeCxt, Message, MpReachNlri, MpUnreachNlri, NegotiatedCapability,
     ReachNlri, UnreachNlri, UpdateMsg,
@@ -114,6 +114,7 @@ static UPDATE2: Lazy<(Vec<u8>, Message)> = Lazy::new(|| {
                     atomic_aggregate: Some(()),
                     originator_id: Some(ip4!("1.1.1.1")),
                     cluster_list: Some(ClusterList(vec![ip4!("3.3.3.3")])),
+                    aigp: None,
                 },
                 comm: Some(CommList([Comm(1), Comm(2), Comm(3)].into())),
                 ext_comm: Some(CommList(
@@ -131,6 +132,91 @@ static UPDATE2: Lazy<(Vec<u8>, Message)> = Lazy::new(|| {
     )
 });
 
+static UPDATE3: Lazy<(Vec<u8>, Message)> = Lazy::new(|| {
+    (
+        vec![
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x45, 0x02, 0x00, 0x00, 0x00,
+            0x2a, 0x40, 0x01, 0x01, 0x00, 0x50, 0x02, 0x00, 0x06, 0x02, 0x01,
+            0x00, 0x00, 0x00, 0x01, 0x40, 0x03, 0x04, 0x01, 0x01, 0x01, 0x01,
+            0x40, 0x05, 0x04, 0x00, 0x00, 0x00, 0x64, 0x80, 0x1a, 0x0b, 0x01,
+            0x00, 0x0b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x64, 0x18,
+            0x0a, 0x00, 0x01,
+        ],
+        Message::Update(UpdateMsg {
+            reach: Some(ReachNlri {
+                prefixes: vec![net4!("10.0.1.0/24")],
+                nexthop: ip4!("1.1.1.1"),
+            }),
+            unreach: None,
+            mp_reach: None,
+            mp_unreach: None,
+            attrs: Some(Attrs {
+                base: BaseAttrs {
+                    origin: Origin::Igp,
+                    as_path: AsPath {
+                        segments: [AsPathSegment {
+                            seg_type: AsPathSegmentType::Sequence,
+                            members: [1].into(),
+                        }]
+                        .into(),
+                    },
+                    local_pref: Some(100),
+                    aigp: Some(100),
+                    ..Default::default()
+                },
+                comm: None,
+                ext_comm: None,
+                extv6_comm: None,
+                large_comm: None,
+                unknown: None,
+            }),
+        }),
+    )
+});
+
+static UPDATE4: Lazy<(Vec<u8>, Message)> = Lazy::new(|| {
+    (
+        vec![
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x1e, 0x02, 0x00, 0x00, 0x00,
+            0x07, 0x90, 0x0f, 0x00, 0x03, 0x00, 0x02, 0x01,
+        ],
+        Message::Update(UpdateMsg {
+            reach: None,
+            unreach: None,
+            mp_reach: None,
+            mp_unreach: Some(MpUnreachNlri::Ipv6Unicast { prefixes: vec![] }),
+            attrs: None,
+        }),
+    )
+});
+
+static UPDATE5: Lazy<(Vec<u8>, Message)> = Lazy::new(|| {
+    (
+        vec![
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x40, 0x02, 0x00, 0x00, 0x00,
+            0x29, 0x90, 0x0f, 0x00, 0x25, 0x00, 0x02, 0x01, 0x80, 0x20, 0x01,
+            0x0d, 0xb8, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x01, 0x80, 0x20, 0x01, 0x0d, 0xb8, 0x00, 0x02, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02,
+        ],
+        Message::Update(UpdateMsg {
+            reach: None,
+            unreach: None,
+            mp_reach: None,
+            mp_unreach: Some(MpUnreachNlri::Ipv6Unicast {
+                prefixes: vec![
+                    net6!("2001:db8:2::1/128"),
+                    net6!("2001:db8:2::2/128"),
+                ],
+            }),
+            attrs: None,
+        }),
+    )
+});
+
 #[test]
 fn test_encode_update1() {
     let (ref bytes, ref msg) = *UPDATE1;
@@ -155,6 +241,62 @@ fn test_decode_update2() {
     test_decode_msg(bytes, msg);
 }
 
+#[test]
+fn test_encode_update3() {
+    let (ref bytes, ref msg) = *UPDATE3;
+    test_encode_msg(bytes, msg);
+}
+
+#[test]
+fn test_decode_update3() {
+    let (ref bytes, ref msg) = *UPDATE3;
+    test_decode_msg(bytes, msg);
+}
+
+#[test]
+fn test_encode_update4() {
+    let (ref bytes, ref msg) = *UPDATE4;
+    test_encode_msg(bytes, msg);
+}
+
+#[test]
+fn test_decode_update4() {
+    let (ref bytes, ref msg) = *UPDATE4;
+    test_decode_msg(bytes, msg);
+}
+
+#[test]
+fn test_encode_update5() {
+    let (ref bytes, ref msg) = *UPDATE5;
+    test_encode_msg(bytes, msg);
+}
+
+#[test]
+fn test_decode_update5() {
+    let (ref bytes, ref msg) = *UPDATE5;
+    test_decode_msg(bytes, msg);
+}
+
+#[test]
+fn test_end_of_rib() {
+    for (msg, afi) in
+        [(&*UPDATE1, Some(Afi::Ipv4)), (&*UPDATE4, Some(Afi::Ipv6))]
+    {
+        let Message::Update(msg) = &msg.1 else {
+            unreachable!()
+        };
+        assert_eq!(msg.is_end_of_rib(), afi);
+        assert_eq!(*msg, UpdateMsg::end_of_rib(afi.unwrap()));
+    }
+
+    for msg in [&*UPDATE2, &*UPDATE5] {
+        let Message::Update(msg) = &msg.1 else {
+            unreachable!()
+        };
+        assert_eq!(msg.is_end_of_rib(), None);
+    }
+}
+
 #[test]
 fn test_decode_malformed_updates() {
     let cxt: DecodeCxt = DecodeCxt {
@@ -31,6 +31,13 @@ static TYPEDEFS: &[(&str, TypeSpec)] = &[
             copy_semantics: true,
         },
     ),
+    (
+        "ldp-igp-sync-state",
+        TypeSpec {
+            rust_type: "LdpSyncState",
+            copy_semantics: true,
+        },
+    ),
     (
         "level",
         TypeSpec {
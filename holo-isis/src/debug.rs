@@ -13,6 +13,7 @@ use std::time::Duration;
 use holo_utils::ibus::IbusMsg;
 use holo_utils::ip::AddressFamily;
 use holo_utils::mac_addr::MacAddr;
+use holo_utils::mpls::LdpSyncState;
 use holo_yang::ToYang;
 use serde::{Deserialize, Serialize};
 use tracing::{debug, debug_span};
@@ -39,6 +40,7 @@ pub enum Debug<'a> {
     InterfaceStart(&'a str),
     InterfaceStop(&'a str, InterfaceInactiveReason),
     InterfaceDisChange(&'a str, LevelNumber, &'a Option<DisCandidate>),
+    InterfaceLdpSyncChange(&'a str, LdpSyncState, LdpSyncState),
     // Adjacencies
     AdjacencyCreate(&'a Adjacency),
     AdjacencyDelete(&'a Adjacency),
@@ -142,6 +144,12 @@ impl Debug<'_> {
                     }
                 })
             }
+            Debug::InterfaceLdpSyncChange(name, old_state, new_state) => {
+                // Parent span(s): isis-instance
+                debug_span!("interface", %name).in_scope(|| {
+                    debug!(?old_state, ?new_state, "{}", self);
+                })
+            }
             Debug::AdjacencyCreate(adj) | Debug::AdjacencyDelete(adj) => {
                 // Parent span(s): isis-instance
                 debug_span!("adjacency", system_id = %adj.system_id.to_yang())
@@ -282,6 +290,9 @@ impl std::fmt::Display for Debug<'_> {
             Debug::InterfaceDisChange(..) => {
                 write!(f, "interface DIS change")
             }
+            Debug::InterfaceLdpSyncChange(..) => {
+                write!(f, "LDP-IGP synchronization state change")
+            }
             Debug::AdjacencyCreate(..) => {
                 write!(f, "adjacency created")
             }
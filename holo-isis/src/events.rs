@@ -13,6 +13,7 @@ use std::collections::BTreeMap;
 use bytes::Bytes;
 use chrono::Utc;
 use holo_utils::mac_addr::MacAddr;
+use holo_utils::mpls::LdpSyncState;
 
 use crate::adjacency::{Adjacency, AdjacencyEvent, AdjacencyState};
 use crate::collections::{
@@ -1588,3 +1589,32 @@ pub(crate) fn process_spf_delay_event(
     // Trigger SPF Delay FSM event.
     spf::fsm(level, event, instance, arenas)
 }
+
+// ===== LDP-IGP synchronization holddown timeout =====
+
+pub(crate) fn process_ldp_sync_holddown_timeout(
+    instance: &mut InstanceUpView<'_>,
+    arenas: &mut InstanceArenas,
+    iface_key: InterfaceKey,
+) -> Result<(), Error> {
+    // Lookup interface.
+    let iface = arenas.interfaces.get_mut_by_key(&iface_key)?;
+    iface.state.tasks.ldp_sync_holddown = None;
+
+    if iface.state.ldp_sync == LdpSyncState::NotAchieved {
+        // Stop advertising the maximum link metric.
+        let new_state = LdpSyncState::HolddownExpired;
+        Debug::InterfaceLdpSyncChange(
+            &iface.name,
+            iface.state.ldp_sync,
+            new_state,
+        )
+        .log();
+        iface.state.ldp_sync = new_state;
+
+        // Schedule LSP reorigination.
+        instance.schedule_lsp_origination(iface.config.level_type.resolved);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,674 @@
+//
+// Copyright (c) The Holo Core Contributors
+//
+// SPDX-License-Identifier: MIT
+//
+
+use std::collections::BTreeSet;
+
+use holo_utils::sr::IgpAlgoType;
+use num_traits::FromPrimitive;
+
+use crate::collections::Arena;
+use crate::instance::InstanceUpView;
+use crate::lsdb::LspEntry;
+use crate::northbound::configuration::FlexAlgoCfg;
+use crate::packet::iana::{self, AslaSabmFlags, FadFlags, IgpMetricType, MtId};
+use crate::packet::subtlvs::capability::{ExcludeSrlgsStlv, FadStlv, FadStlvs};
+use crate::packet::subtlvs::neighbor::{
+    AdminGroupStlv, ExtAdminGroupStlv, MinMaxUniLinkDelayStlv,
+    TeDefaultMetricStlv,
+};
+use crate::packet::tlv::{AppSpecificSrlgTlv, IsReachStlvs};
+use crate::packet::{LevelNumber, SystemId};
+
+// Flexible Algorithm Definition in effect within a level.
+#[derive(Debug)]
+pub struct FlexAlgo {
+    pub algo: u8,
+    pub metric_type: IgpMetricType,
+    pub exclude_any: Option<ExtAdminGroupStlv>,
+    pub include_any: Option<ExtAdminGroupStlv>,
+    pub include_all: Option<ExtAdminGroupStlv>,
+    pub exclude_srlgs: BTreeSet<u32>,
+    // Routers that advertise support for the algorithm.
+    pub participants: BTreeSet<SystemId>,
+}
+
+// Link attributes used by the Flexible Algorithm computation.
+#[derive(Debug, Default)]
+struct LinkAttrs<'a> {
+    admin_group: Option<&'a AdminGroupStlv>,
+    ext_admin_group: Option<&'a ExtAdminGroupStlv>,
+    te_default_metric: Option<&'a TeDefaultMetricStlv>,
+    min_max_uni_link_delay: Option<&'a MinMaxUniLinkDelayStlv>,
+}
+
+// ===== impl FlexAlgo =====
+
+impl FlexAlgo {
+    // Selects the Flexible Algorithm Definition in effect for the given level.
+    //
+    // Returns `None` if there's no usable definition, in which case the router
+    // MUST NOT participate in the algorithm (RFC 9350 - Section 5.3).
+    pub(crate) fn compute(
+        algo: u8,
+        level: LevelNumber,
+        instance: &InstanceUpView<'_>,
+        lsp_entries: &Arena<LspEntry>,
+    ) -> Option<FlexAlgo> {
+        let lsdb = instance.state.lsdb.get(level);
+        let spt = instance.state.spt.get(MtId::Standard).get(level);
+        let mut participants = BTreeSet::new();
+        let mut fads = vec![];
+
+        // Only the routers reachable within the level are considered.
+        for vertex in spt
+            .iter()
+            .filter(|vertex| !vertex.id.lan_id.is_pseudonode())
+        {
+            let system_id = vertex.id.lan_id.system_id;
+            for lsp in lsdb
+                .iter_for_lan_id(lsp_entries, vertex.id.lan_id)
+                .map(|lse| &lse.data)
+                .filter(|lsp| lsp.rem_lifetime != 0)
+                .filter(|lsp| lsp.seqno != 0)
+            {
+                if lsp.tlvs.sr_algos().is_some_and(|sr_algos| {
+                    sr_algos.get().contains(&IgpAlgoType::FlexAlgo(algo))
+                }) {
+                    participants.insert(system_id);
+                }
+                fads.extend(
+                    lsp.tlvs
+                        .fads()
+                        .filter(|fad| fad.flex_algo == algo)
+                        .map(|fad| (system_id, fad)),
+                );
+            }
+        }
+        let fad = fad_select(fads)?;
+
+        // The computation is only meaningful if the local router participates
+        // in the algorithm.
+        if !participants.contains(&instance.config.system_id.unwrap()) {
+            return None;
+        }
+
+        FlexAlgo::from_fad(algo, fad, participants)
+    }
+
+    // Builds the Flexible Algorithm from the winning FAD.
+    //
+    // Returns `None` if the FAD has components that aren't supported.
+    fn from_fad(
+        algo: u8,
+        fad: &FadStlv,
+        participants: BTreeSet<SystemId>,
+    ) -> Option<FlexAlgo> {
+        // If the node does not support or understand any of the components
+        // of the winning FAD, it MUST NOT participate in the algorithm.
+        if iana::IgpAlgoType::from_u8(fad.calc_type).is_none()
+            || !fad.sub_tlvs.unknown.is_empty()
+        {
+            return None;
+        }
+        let metric_type = IgpMetricType::from_u8(fad.metric_type)?;
+
+        // The Flexible Algorithm Prefix Metric isn't supported.
+        if fad
+            .sub_tlvs
+            .flags
+            .as_ref()
+            .is_some_and(|flags| flags.get().contains(FadFlags::M))
+        {
+            return None;
+        }
+
+        Some(FlexAlgo {
+            algo,
+            metric_type,
+            exclude_any: fad.sub_tlvs.exclude_admin_group.clone(),
+            include_any: fad.sub_tlvs.include_any_admin_group.clone(),
+            include_all: fad.sub_tlvs.include_all_admin_group.clone(),
+            exclude_srlgs: fad
+                .sub_tlvs
+                .exclude_srlgs
+                .iter()
+                .flat_map(|srlgs| srlgs.get().iter().copied())
+                .collect(),
+            participants,
+        })
+    }
+
+    // Returns the metric of the given link for this algorithm, or `None` if
+    // the link must be pruned from the topology.
+    //
+    // `app_srlgs` contains the Application-Specific SRLG TLVs advertised by
+    // the router for the link's neighbor.
+    pub(crate) fn link_metric<'a>(
+        &self,
+        igp_metric: u32,
+        sub_tlvs: Option<&IsReachStlvs>,
+        app_srlgs: impl IntoIterator<Item = &'a AppSpecificSrlgTlv>,
+    ) -> Option<u32> {
+        let attrs = sub_tlvs.map(link_attrs).unwrap_or_default();
+
+        // Apply the SRLG constraints.
+        if !self.exclude_srlgs.is_empty()
+            && link_srlgs(sub_tlvs, app_srlgs)
+                .any(|srlg| self.exclude_srlgs.contains(&srlg))
+        {
+            return None;
+        }
+
+        // Apply the administrative group constraints.
+        //
+        // When only the legacy Administrative Group is advertised, it's
+        // treated as the first 32 bits of the Extended Administrative Group.
+        let admin_group = attrs
+            .ext_admin_group
+            .cloned()
+            .or_else(|| attrs.admin_group.map(ExtAdminGroupStlv::from))
+            .unwrap_or_else(|| ExtAdminGroupStlv::new(vec![]));
+        if let Some(exclude_any) = &self.exclude_any
+            && admin_group.intersects(exclude_any)
+        {
+            return None;
+        }
+        if let Some(include_any) = &self.include_any
+            && !admin_group.intersects(include_any)
+        {
+            return None;
+        }
+        if let Some(include_all) = &self.include_all
+            && !admin_group.contains(include_all)
+        {
+            return None;
+        }
+
+        // Links that don't advertise the metric used by the algorithm are
+        // pruned from the topology.
+        match self.metric_type {
+            IgpMetricType::IgpMetric => Some(igp_metric),
+            IgpMetricType::MinUniLinkDelay => {
+                attrs.min_max_uni_link_delay.map(|delay| delay.min_delay)
+            }
+            IgpMetricType::TeDefaultMetric => {
+                attrs.te_default_metric.map(|metric| metric.get())
+            }
+        }
+    }
+}
+
+// ===== global functions =====
+
+// Builds the FAD Sub-TLV corresponding to the given configuration.
+pub(crate) fn fad_stlv(algo: u8, fa_cfg: &FlexAlgoCfg) -> FadStlv {
+    // Merge all configured extended administrative groups into a single
+    // bitmask.
+    let admin_group = |groups: &BTreeSet<Vec<u8>>| {
+        (!groups.is_empty()).then(|| {
+            let len = groups.iter().map(Vec::len).max().unwrap_or(0);
+            let mut bitmask = vec![0; len];
+            for group in groups {
+                for (byte, value) in bitmask.iter_mut().zip(group) {
+                    *byte |= value;
+                }
+            }
+            ExtAdminGroupStlv::new(bitmask)
+        })
+    };
+
+    FadStlv {
+        flex_algo: algo,
+        metric_type: fa_cfg.metric_type as u8,
+        calc_type: fa_cfg.calc_type as u8,
+        priority: fa_cfg.priority,
+        sub_tlvs: FadStlvs {
+            exclude_admin_group: admin_group(&fa_cfg.exclude_any),
+            include_any_admin_group: admin_group(&fa_cfg.include_any),
+            include_all_admin_group: admin_group(&fa_cfg.include_all),
+            flags: None,
+            exclude_srlgs: (!fa_cfg.exclude_srlgs.is_empty()).then(|| {
+                ExcludeSrlgsStlv::new(
+                    fa_cfg.exclude_srlgs.iter().copied().collect(),
+                )
+            }),
+            unknown: vec![],
+        },
+    }
+}
+
+// ===== helper functions =====
+
+// Selects the winning FAD among the ones advertised within the level.
+//
+// From the advertisements of the FAD within the level, a router MUST select
+// the one with the highest priority value. If there are multiple
+// advertisements of the FAD with the same highest priority, the router MUST
+// select the one that is originated from the router with the highest
+// System-ID.
+fn fad_select<'a>(
+    fads: impl IntoIterator<Item = (SystemId, &'a FadStlv)>,
+) -> Option<&'a FadStlv> {
+    fads.into_iter()
+        .max_by_key(|(system_id, fad)| (fad.priority, *system_id))
+        .map(|(_, fad)| fad)
+}
+
+// Returns the link attributes that apply to the Flexible Algorithm
+// application.
+//
+// As per RFC 9350 - Section 12, only the attributes advertised in the ASLA
+// Sub-TLV with the X-bit set (or with zero-length SABM and UDABM) are used.
+// When the L-flag is set, the legacy advertisements are used instead.
+fn link_attrs(sub_tlvs: &IsReachStlvs) -> LinkAttrs<'_> {
+    let asla = sub_tlvs
+        .asla
+        .iter()
+        .find(|asla| asla.sabm.contains(AslaSabmFlags::X))
+        .or_else(|| {
+            sub_tlvs
+                .asla
+                .iter()
+                .find(|asla| asla.sabm_length == 0 && asla.udabm_length == 0)
+        });
+
+    match asla {
+        Some(asla) if asla.l_flag => LinkAttrs {
+            admin_group: sub_tlvs.admin_group.as_ref(),
+            ext_admin_group: sub_tlvs.ext_admin_group.as_ref(),
+            te_default_metric: sub_tlvs.te_default_metric.as_ref(),
+            min_max_uni_link_delay: sub_tlvs.min_max_uni_link_delay.as_ref(),
+        },
+        Some(asla) => LinkAttrs {
+            admin_group: asla.sub_tlvs.admin_group.as_ref(),
+            ext_admin_group: asla.sub_tlvs.ext_admin_group.as_ref(),
+            te_default_metric: asla.sub_tlvs.te_default_metric.as_ref(),
+            min_max_uni_link_delay: asla
+                .sub_tlvs
+                .min_max_uni_link_delay
+                .as_ref(),
+        },
+        None => LinkAttrs::default(),
+    }
+}
+
+// Returns the SRLGs of the given link that apply to the Flexible Algorithm
+// application.
+//
+// Only the Application-Specific SRLG TLVs whose link identifiers match the
+// link are considered. As with the ASLA Sub-TLV, the TLVs with the X-bit set
+// take precedence over the ones with zero-length SABM and UDABM. The legacy
+// SRLG TLV isn't supported, so TLVs with the L-flag set don't contribute any
+// SRLG.
+fn link_srlgs<'a>(
+    sub_tlvs: Option<&IsReachStlvs>,
+    app_srlgs: impl IntoIterator<Item = &'a AppSpecificSrlgTlv>,
+) -> impl Iterator<Item = u32> + 'a {
+    let link_match = |tlv: &AppSpecificSrlgTlv| {
+        let Some(sub_tlvs) = sub_tlvs else {
+            return true;
+        };
+        tlv.sub_tlvs
+            .ipv4_interface_addr
+            .as_ref()
+            .is_none_or(|addr| {
+                sub_tlvs.ipv4_interface_addr.is_empty()
+                    || sub_tlvs.ipv4_interface_addr.contains(addr)
+            })
+            && tlv.sub_tlvs.ipv4_neighbor_addr.as_ref().is_none_or(|addr| {
+                sub_tlvs.ipv4_neighbor_addr.is_empty()
+                    || sub_tlvs.ipv4_neighbor_addr.contains(addr)
+            })
+    };
+    let tlvs = app_srlgs
+        .into_iter()
+        .filter(|tlv| link_match(tlv))
+        .collect::<Vec<_>>();
+    let flex_algo = tlvs.iter().any(|tlv| tlv.sabm.contains(AslaSabmFlags::X));
+
+    tlvs.into_iter()
+        .filter(move |tlv| {
+            if flex_algo {
+                tlv.sabm.contains(AslaSabmFlags::X)
+            } else {
+                tlv.sabm_length == 0 && tlv.udabm_length == 0
+            }
+        })
+        .filter(|tlv| !tlv.l_flag)
+        .flat_map(|tlv| tlv.srlgs.iter().copied())
+}
+
+// ===== unit tests =====
+
+#[cfg(test)]
+mod tests {
+    use std::net::Ipv4Addr;
+
+    use super::*;
+    use crate::packet::LanId;
+    use crate::packet::subtlvs::capability::FadFlagsStlv;
+    use crate::packet::subtlvs::neighbor::{
+        AslaStlv, AslaStlvs, Ipv4InterfaceAddrStlv, MinMaxUniLinkDelayFlags,
+    };
+
+    fn fad(priority: u8, sub_tlvs: FadStlvs) -> FadStlv {
+        FadStlv {
+            flex_algo: 128,
+            metric_type: IgpMetricType::IgpMetric as u8,
+            calc_type: iana::IgpAlgoType::Spf as u8,
+            priority,
+            sub_tlvs,
+        }
+    }
+
+    fn flex_algo(
+        metric_type: IgpMetricType,
+        exclude_any: Option<u8>,
+        include_any: Option<u8>,
+        include_all: Option<u8>,
+    ) -> FlexAlgo {
+        let admin_group = |group: u8| ExtAdminGroupStlv::new(vec![group]);
+        FlexAlgo {
+            algo: 128,
+            metric_type,
+            exclude_any: exclude_any.map(admin_group),
+            include_any: include_any.map(admin_group),
+            include_all: include_all.map(admin_group),
+            exclude_srlgs: Default::default(),
+            participants: Default::default(),
+        }
+    }
+
+    // Link attributes advertised in an ASLA sub-TLV with the X-bit set.
+    fn asla_link(sub_tlvs: AslaStlvs) -> IsReachStlvs {
+        IsReachStlvs {
+            asla: vec![AslaStlv {
+                l_flag: false,
+                sabm_length: 8,
+                sabm: AslaSabmFlags::X,
+                udabm_length: 0,
+                udabm: 0,
+                sub_tlvs,
+            }],
+            ..Default::default()
+        }
+    }
+
+    // Application-Specific SRLG TLV for the given applications.
+    fn app_srlg(sabm: AslaSabmFlags, srlgs: Vec<u32>) -> AppSpecificSrlgTlv {
+        AppSpecificSrlgTlv {
+            neighbor: LanId::from([0, 0, 0, 0, 0, 2, 0]),
+            l_flag: false,
+            sabm_length: if sabm.is_empty() { 0 } else { 8 },
+            sabm,
+            udabm_length: 0,
+            udabm: 0,
+            sub_tlvs: Default::default(),
+            srlgs,
+        }
+    }
+
+    fn asla_admin_group(group: u8) -> IsReachStlvs {
+        asla_link(AslaStlvs {
+            ext_admin_group: Some(ExtAdminGroupStlv::new(vec![group])),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn fad_selection() {
+        let rt1 = SystemId::from([0, 0, 0, 0, 0, 1]);
+        let rt2 = SystemId::from([0, 0, 0, 0, 0, 2]);
+        let rt3 = SystemId::from([0, 0, 0, 0, 0, 3]);
+        let fad1 = fad(100, Default::default());
+        let fad2 = fad(200, Default::default());
+        let fad3 = fad(200, Default::default());
+
+        // The FAD with the highest priority wins.
+        let winner = fad_select([(rt3, &fad1), (rt1, &fad2)]).unwrap();
+        assert!(std::ptr::eq(winner, &fad2));
+
+        // Ties are broken by the highest System-ID.
+        let winner =
+            fad_select([(rt3, &fad1), (rt2, &fad2), (rt1, &fad3)]).unwrap();
+        assert!(std::ptr::eq(winner, &fad2));
+
+        // No FAD, no winner.
+        assert!(fad_select([]).is_none());
+    }
+
+    #[test]
+    fn fad_unsupported() {
+        // Supported FAD.
+        let flex_algo = FlexAlgo::from_fad(
+            128,
+            &fad(
+                128,
+                FadStlvs {
+                    exclude_admin_group: Some(ExtAdminGroupStlv::new(vec![1])),
+                    ..Default::default()
+                },
+            ),
+            Default::default(),
+        )
+        .unwrap();
+        assert_eq!(flex_algo.metric_type, IgpMetricType::IgpMetric);
+        assert_eq!(
+            flex_algo.exclude_any,
+            Some(ExtAdminGroupStlv::new(vec![1]))
+        );
+
+        // Unknown calculation type.
+        let mut unknown_calc_type = fad(128, Default::default());
+        unknown_calc_type.calc_type = 100;
+        assert!(
+            FlexAlgo::from_fad(128, &unknown_calc_type, Default::default())
+                .is_none()
+        );
+
+        // Unknown metric type.
+        let mut unknown_metric_type = fad(128, Default::default());
+        unknown_metric_type.metric_type = 100;
+        assert!(
+            FlexAlgo::from_fad(128, &unknown_metric_type, Default::default())
+                .is_none()
+        );
+
+        // Flexible Algorithm Prefix Metric.
+        let prefix_metric = fad(
+            128,
+            FadStlvs {
+                flags: Some(FadFlagsStlv::new(FadFlags::M)),
+                ..Default::default()
+            },
+        );
+        assert!(
+            FlexAlgo::from_fad(128, &prefix_metric, Default::default())
+                .is_none()
+        );
+
+        // SRLG-based exclusion.
+        let exclude_srlg = fad(
+            128,
+            FadStlvs {
+                exclude_srlgs: Some(ExcludeSrlgsStlv::new(vec![10, 20])),
+                ..Default::default()
+            },
+        );
+        let flex_algo =
+            FlexAlgo::from_fad(128, &exclude_srlg, Default::default()).unwrap();
+        assert_eq!(flex_algo.exclude_srlgs, [10, 20].into());
+    }
+
+    #[test]
+    fn link_affinity() {
+        // Exclude-any.
+        let fa = flex_algo(IgpMetricType::IgpMetric, Some(0x01), None, None);
+        assert_eq!(fa.link_metric(10, None, []), Some(10));
+        assert_eq!(
+            fa.link_metric(10, Some(&asla_admin_group(0x02)), []),
+            Some(10)
+        );
+        assert_eq!(fa.link_metric(10, Some(&asla_admin_group(0x03)), []), None);
+
+        // Include-any.
+        let fa = flex_algo(IgpMetricType::IgpMetric, None, Some(0x03), None);
+        assert_eq!(fa.link_metric(10, None, []), None);
+        assert_eq!(
+            fa.link_metric(10, Some(&asla_admin_group(0x02)), []),
+            Some(10)
+        );
+        assert_eq!(fa.link_metric(10, Some(&asla_admin_group(0x04)), []), None);
+
+        // Include-all.
+        let fa = flex_algo(IgpMetricType::IgpMetric, None, None, Some(0x03));
+        assert_eq!(
+            fa.link_metric(10, Some(&asla_admin_group(0x07)), []),
+            Some(10)
+        );
+        assert_eq!(fa.link_metric(10, Some(&asla_admin_group(0x01)), []), None);
+
+        // The legacy Administrative Group is mapped to the first 32 bits of
+        // the Extended Administrative Group.
+        let fa = flex_algo(IgpMetricType::IgpMetric, Some(0x01), None, None);
+        let link = asla_link(AslaStlvs {
+            admin_group: Some(AdminGroupStlv::new(0x0100_0000)),
+            ..Default::default()
+        });
+        assert_eq!(fa.link_metric(10, Some(&link), []), None);
+    }
+
+    #[test]
+    fn link_srlg() {
+        let mut fa = flex_algo(IgpMetricType::IgpMetric, None, None, None);
+        fa.exclude_srlgs = [10, 20].into();
+
+        // Links without SRLGs aren't pruned.
+        assert_eq!(fa.link_metric(10, None, []), Some(10));
+
+        // Links that share at least one SRLG with the exclusion set are
+        // pruned.
+        let srlg = app_srlg(AslaSabmFlags::X, vec![5, 20]);
+        assert_eq!(fa.link_metric(10, None, [&srlg]), None);
+        let srlg = app_srlg(AslaSabmFlags::X, vec![5, 15]);
+        assert_eq!(fa.link_metric(10, None, [&srlg]), Some(10));
+
+        // TLVs for other applications are ignored.
+        let srlg = app_srlg(AslaSabmFlags::R, vec![10]);
+        assert_eq!(fa.link_metric(10, None, [&srlg]), Some(10));
+
+        // TLVs with zero-length SABM and UDABM apply to all applications,
+        // unless a TLV with the X-bit set is also present.
+        let all_apps = app_srlg(AslaSabmFlags::empty(), vec![10]);
+        assert_eq!(fa.link_metric(10, None, [&all_apps]), None);
+        let flex_algo = app_srlg(AslaSabmFlags::X, vec![5]);
+        assert_eq!(fa.link_metric(10, None, [&all_apps, &flex_algo]), Some(10));
+
+        // TLVs with the L-flag set refer to the legacy SRLG advertisements.
+        let mut legacy = app_srlg(AslaSabmFlags::X, vec![10]);
+        legacy.l_flag = true;
+        assert_eq!(fa.link_metric(10, None, [&legacy]), Some(10));
+
+        // TLVs whose link identifiers don't match the link are ignored.
+        let mut srlg = app_srlg(AslaSabmFlags::X, vec![10]);
+        srlg.sub_tlvs.ipv4_interface_addr =
+            Some(Ipv4InterfaceAddrStlv::new(Ipv4Addr::new(10, 0, 1, 1)));
+        let link = IsReachStlvs {
+            ipv4_interface_addr: vec![Ipv4InterfaceAddrStlv::new(
+                Ipv4Addr::new(10, 0, 2, 1),
+            )],
+            ..Default::default()
+        };
+        assert_eq!(fa.link_metric(10, Some(&link), [&srlg]), Some(10));
+        assert_eq!(fa.link_metric(10, None, [&srlg]), None);
+    }
+
+    #[test]
+    fn link_asla() {
+        let fa = flex_algo(IgpMetricType::IgpMetric, Some(0x01), None, None);
+
+        // Legacy attributes are ignored without an ASLA sub-TLV.
+        let legacy = IsReachStlvs {
+            ext_admin_group: Some(ExtAdminGroupStlv::new(vec![0x01])),
+            ..Default::default()
+        };
+        assert_eq!(fa.link_metric(10, Some(&legacy), []), Some(10));
+
+        // Legacy attributes are used when the ASLA sub-TLV has the L-flag
+        // set.
+        let mut link = legacy.clone();
+        link.asla.push(AslaStlv {
+            l_flag: true,
+            sabm_length: 8,
+            sabm: AslaSabmFlags::X,
+            udabm_length: 0,
+            udabm: 0,
+            sub_tlvs: Default::default(),
+        });
+        assert_eq!(fa.link_metric(10, Some(&link), []), None);
+
+        // ASLA sub-TLVs for other applications are ignored.
+        let mut link = asla_admin_group(0x01);
+        link.asla[0].sabm = AslaSabmFlags::R;
+        assert_eq!(fa.link_metric(10, Some(&link), []), Some(10));
+
+        // ASLA sub-TLVs with zero-length SABM and UDABM apply to all
+        // applications.
+        link.asla[0].sabm_length = 0;
+        link.asla[0].sabm = AslaSabmFlags::empty();
+        assert_eq!(fa.link_metric(10, Some(&link), []), None);
+    }
+
+    #[test]
+    fn link_metric_type() {
+        let link = asla_link(AslaStlvs {
+            te_default_metric: Some(TeDefaultMetricStlv::new(100)),
+            min_max_uni_link_delay: Some(MinMaxUniLinkDelayStlv::new(
+                MinMaxUniLinkDelayFlags::empty(),
+                500,
+                1000,
+            )),
+            ..Default::default()
+        });
+
+        let fa = flex_algo(IgpMetricType::IgpMetric, None, None, None);
+        assert_eq!(fa.link_metric(10, Some(&link), []), Some(10));
+        let fa = flex_algo(IgpMetricType::TeDefaultMetric, None, None, None);
+        assert_eq!(fa.link_metric(10, Some(&link), []), Some(100));
+        assert_eq!(fa.link_metric(10, None, []), None);
+        let fa = flex_algo(IgpMetricType::MinUniLinkDelay, None, None, None);
+        assert_eq!(fa.link_metric(10, Some(&link), []), Some(500));
+        assert_eq!(fa.link_metric(10, None, []), None);
+    }
+
+    #[test]
+    fn fad_config() {
+        let fa_cfg = FlexAlgoCfg {
+            exclude_any: [vec![0x01], vec![0x00, 0x02]].into(),
+            ..Default::default()
+        };
+        let fad = fad_stlv(128, &fa_cfg);
+        assert_eq!(fad.flex_algo, 128);
+        assert_eq!(fad.priority, 128);
+        assert_eq!(
+            fad.sub_tlvs.exclude_admin_group,
+            Some(ExtAdminGroupStlv::new(vec![0x01, 0x02]))
+        );
+        assert!(fad.sub_tlvs.include_any_admin_group.is_none());
+        assert!(fad.sub_tlvs.include_all_admin_group.is_none());
+        assert!(fad.sub_tlvs.exclude_srlgs.is_none());
+
+        let fa_cfg = FlexAlgoCfg {
+            exclude_srlgs: [20, 10].into(),
+            ..Default::default()
+        };
+        let fad = fad_stlv(128, &fa_cfg);
+        assert_eq!(
+            fad.sub_tlvs.exclude_srlgs,
+            Some(ExcludeSrlgsStlv::new(vec![10, 20]))
+        );
+    }
+}
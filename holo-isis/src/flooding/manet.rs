@@ -62,6 +62,7 @@ pub(crate) fn init_cache(
             false,
             None,
             MetricMode::HopCount,
+            None,
             instance,
             interfaces,
             adjacencies,
@@ -22,6 +22,7 @@ use holo_utils::sr::{MsdType, SrCfg};
 use crate::adjacency::{AdjacencyEvent, AdjacencyState};
 use crate::error::Error;
 use crate::instance::Instance;
+use crate::interface;
 use crate::packet::LevelType;
 use crate::route::RouteSys;
 
@@ -39,6 +40,27 @@ pub(crate) fn process_router_id_update(
     }
 }
 
+pub(crate) fn process_ldp_sync_update(
+    instance: &mut Instance,
+    ifname: String,
+    synced: Option<bool>,
+) {
+    // Update the local copy of the LDP-IGP synchronization status.
+    match synced {
+        Some(synced) => {
+            instance.system.ldp_sync.insert(ifname, synced);
+        }
+        None => {
+            instance.system.ldp_sync.remove(&ifname);
+        }
+    }
+
+    // Update the synchronization state of all interfaces.
+    if let Some((mut instance, arenas)) = instance.as_up() {
+        interface::ldp_sync_update_all(&mut instance, arenas);
+    }
+}
+
 pub(crate) fn process_iface_update(
     instance: &mut Instance,
     msg: InterfaceUpdateMsg,
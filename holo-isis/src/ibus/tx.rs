@@ -33,6 +33,10 @@ pub(crate) fn hostname_sub(ibus_tx: &IbusChannelsTx) {
     ibus_tx.hostname_sub();
 }
 
+pub(crate) fn ldp_sync_sub(ibus_tx: &IbusChannelsTx) {
+    ibus_tx.ldp_sync_sub();
+}
+
 pub(crate) fn route_install(
     ibus_tx: &IbusChannelsTx,
     destination: &IpNetwork,
@@ -42,21 +46,7 @@ pub(crate) fn route_install(
     interfaces: &Interfaces,
 ) {
     // Fill-in nexthops.
-    let nexthops = route
-        .nexthops
-        .values()
-        .map(|nexthop| {
-            let iface = &interfaces[nexthop.iface_idx];
-            Nexthop::Address {
-                ifindex: iface.system.ifindex.unwrap(),
-                addr: nexthop.addr,
-                labels: nexthop
-                    .sr_label
-                    .map(|label| vec![label])
-                    .unwrap_or_default(),
-            }
-        })
-        .collect::<BTreeSet<_>>();
+    let nexthops = route_nexthops(route, interfaces);
 
     // Install route.
     let msg = RouteMsg {
@@ -74,6 +64,7 @@ pub(crate) fn route_install(
             route_type: route.route_type,
         },
         nexthops: nexthops.clone(),
+        backup_nexthops: Default::default(),
     };
     ibus_tx.route_ip_add(msg);
 
@@ -127,6 +118,45 @@ pub(crate) fn route_uninstall(
     }
 }
 
+pub(crate) fn flex_algo_label_install(
+    ibus_tx: &IbusChannelsTx,
+    route: &Route,
+    old_sr_label: Option<Label>,
+    interfaces: &Interfaces,
+) {
+    // Uninstall previous SR Prefix-SID input label if it has changed.
+    if old_sr_label != route.sr_label
+        && let Some(old_sr_label) = old_sr_label
+    {
+        flex_algo_label_uninstall(ibus_tx, old_sr_label);
+    }
+
+    // Install SR Prefix-SID input label.
+    if let Some(sr_label) = route.sr_label {
+        let msg = LabelInstallMsg {
+            protocol: Protocol::ISIS,
+            label: sr_label,
+            nexthops: route_nexthops(route, interfaces),
+            route: None,
+            replace: true,
+        };
+        ibus_tx.route_mpls_add(msg);
+    }
+}
+
+pub(crate) fn flex_algo_label_uninstall(
+    ibus_tx: &IbusChannelsTx,
+    label: Label,
+) {
+    let msg = LabelUninstallMsg {
+        protocol: Protocol::ISIS,
+        label,
+        nexthops: BTreeSet::new(),
+        route: None,
+    };
+    ibus_tx.route_mpls_del(msg);
+}
+
 pub(crate) fn adj_sid_install(
     ibus_tx: &IbusChannelsTx,
     iface: &Interface,
@@ -157,3 +187,23 @@ pub(crate) fn adj_sid_uninstall(ibus_tx: &IbusChannelsTx, label: Label) {
     };
     ibus_tx.route_mpls_del(msg);
 }
+
+// ===== helper functions =====
+
+fn route_nexthops(route: &Route, interfaces: &Interfaces) -> BTreeSet<Nexthop> {
+    route
+        .nexthops
+        .values()
+        .map(|nexthop| {
+            let iface = &interfaces[nexthop.iface_idx];
+            Nexthop::Address {
+                ifindex: iface.system.ifindex.unwrap(),
+                addr: nexthop.addr,
+                labels: nexthop
+                    .sr_label
+                    .map(|label| vec![label])
+                    .unwrap_or_default(),
+            }
+        })
+        .collect()
+}
@@ -38,9 +38,9 @@ use crate::packet::{LevelNumber, LevelType, Levels, SystemId};
 use crate::route::{Route, RouteFlags, RouteSys, SummaryRoute};
 use crate::spf::{SpfLogEntry, SpfScheduler, Spt, Topologies};
 use crate::tasks::messages::input::{
-    AdjHoldTimerMsg, AdjInitLsdbSyncMsg, DisElectionMsg, LspDeleteMsg,
-    LspOriginateMsg, LspPurgeMsg, LspRefreshMsg, NetRxPduMsg, SendCsnpMsg,
-    SendPsnpMsg, SpfDelayEventMsg,
+    AdjHoldTimerMsg, AdjInitLsdbSyncMsg, DisElectionMsg, LdpSyncHolddownMsg,
+    LspDeleteMsg, LspOriginateMsg, LspPurgeMsg, LspRefreshMsg, NetRxPduMsg,
+    SendCsnpMsg, SendPsnpMsg, SpfDelayEventMsg,
 };
 use crate::tasks::messages::{ProtocolInputMsg, ProtocolOutputMsg};
 use crate::{events, ibus, lsdb, spf, tasks};
@@ -71,6 +71,8 @@ pub struct InstanceSys {
     pub node_msd: BTreeMap<MsdType, u8>,
     // Redistributed routes.
     pub routes: Levels<JointPrefixMap<IpNetwork, RouteSys>>,
+    // LDP-IGP synchronization status of each LDP-enabled interface.
+    pub ldp_sync: BTreeMap<String, bool>,
 }
 
 #[derive(Debug)]
@@ -96,6 +98,9 @@ pub struct InstanceState {
     // Routing table (per-level and L1/L2).
     pub rib_single: Levels<BTreeMap<IpNetwork, Route>>,
     pub rib_multi: BTreeMap<IpNetwork, Route>,
+    // Flexible Algorithm routing tables (per-level and combined).
+    pub flex_algo_rib_single: Levels<BTreeMap<u8, BTreeMap<IpNetwork, Route>>>,
+    pub flex_algo_rib: BTreeMap<u8, BTreeMap<IpNetwork, Route>>,
     // Summary routes (L1 to L2).
     pub summaries: BTreeMap<IpNetwork, SummaryRoute>,
     // Event counters.
@@ -156,6 +161,8 @@ pub struct ProtocolInputChannelsTx {
     pub lsp_refresh: UnboundedSender<LspRefreshMsg>,
     // SPF Delay FSM event.
     pub spf_delay_event: UnboundedSender<SpfDelayEventMsg>,
+    // LDP-IGP synchronization holddown timer event.
+    pub ldp_sync_holddown: UnboundedSender<LdpSyncHolddownMsg>,
 }
 
 #[derive(Debug)]
@@ -182,6 +189,8 @@ pub struct ProtocolInputChannelsRx {
     pub lsp_refresh: UnboundedReceiver<LspRefreshMsg>,
     // SPF Delay FSM event.
     pub spf_delay_event: UnboundedReceiver<SpfDelayEventMsg>,
+    // LDP-IGP synchronization holddown timer event.
+    pub ldp_sync_holddown: UnboundedReceiver<LdpSyncHolddownMsg>,
 }
 
 pub struct InstanceUpView<'a> {
@@ -248,6 +257,16 @@ impl Instance {
         {
             ibus::tx::route_uninstall(&instance.tx.ibus, prefix, route);
         }
+        for label in instance
+            .state
+            .flex_algo_rib
+            .values()
+            .flat_map(|rib| rib.values())
+            .filter(|route| route.flags.contains(RouteFlags::INSTALLED))
+            .filter_map(|route| route.sr_label)
+        {
+            ibus::tx::flex_algo_label_uninstall(&instance.tx.ibus, label);
+        }
 
         // Stop interfaces.
         let reason = InterfaceInactiveReason::InstanceDown;
@@ -353,6 +372,9 @@ impl ProtocolInstance for Instance {
 
         // Request information about the system hostname.
         ibus::tx::hostname_sub(&self.tx.ibus);
+
+        // Request information about the LDP-IGP synchronization status.
+        ibus::tx::ldp_sync_sub(&self.tx.ibus);
     }
 
     fn shutdown(mut self) {
@@ -392,6 +414,8 @@ impl ProtocolInstance for Instance {
         let (lsp_deletep, lsp_deletec) = mpsc::unbounded_channel();
         let (lsp_refreshp, lsp_refreshc) = mpsc::unbounded_channel();
         let (spf_delay_eventp, spf_delay_eventc) = mpsc::unbounded_channel();
+        let (ldp_sync_holddownp, ldp_sync_holddownc) =
+            mpsc::unbounded_channel();
 
         let tx = ProtocolInputChannelsTx {
             net_pdu_rx: net_pdu_rxp,
@@ -405,6 +429,7 @@ impl ProtocolInstance for Instance {
             lsp_delete: lsp_deletep,
             lsp_refresh: lsp_refreshp,
             spf_delay_event: spf_delay_eventp,
+            ldp_sync_holddown: ldp_sync_holddownp,
         };
         let rx = ProtocolInputChannelsRx {
             net_pdu_rx: net_pdu_rxc,
@@ -418,6 +443,7 @@ impl ProtocolInstance for Instance {
             lsp_delete: lsp_deletec,
             lsp_refresh: lsp_refreshc,
             spf_delay_event: spf_delay_eventc,
+            ldp_sync_holddown: ldp_sync_holddownc,
         };
 
         (tx, rx)
@@ -446,6 +472,8 @@ impl InstanceState {
             flooding_reduction: Default::default(),
             rib_single: Default::default(),
             rib_multi: Default::default(),
+            flex_algo_rib_single: Default::default(),
+            flex_algo_rib: Default::default(),
             summaries: Default::default(),
             counters: Default::default(),
             discontinuity_time: Utc::now(),
@@ -566,6 +594,9 @@ impl MessageReceiver<ProtocolInputMsg> for ProtocolInputChannelsRx {
             msg = self.spf_delay_event.recv() => {
                 msg.map(ProtocolInputMsg::SpfDelayEvent)
             }
+            msg = self.ldp_sync_holddown.recv() => {
+                msg.map(ProtocolInputMsg::LdpSyncHolddown)
+            }
         }
     }
 }
@@ -706,6 +737,14 @@ fn process_ibus_msg(
         IbusMsg::BierCfgUpd(bier_config) => {
             ibus::rx::process_bier_cfg_update(instance, bier_config);
         }
+        // LDP-IGP synchronization status update.
+        IbusMsg::LdpSyncUpd { ifname, synced } => {
+            ibus::rx::process_ldp_sync_update(instance, ifname, Some(synced));
+        }
+        // LDP-IGP synchronization status removal.
+        IbusMsg::LdpSyncDel { ifname } => {
+            ibus::rx::process_ldp_sync_update(instance, ifname, None);
+        }
         // Ignore other events.
         _ => {}
     }
@@ -820,6 +859,14 @@ fn process_protocol_msg(
                 instance, arenas, msg.level, msg.event,
             )?
         }
+        // LDP-IGP synchronization holddown timer event.
+        ProtocolInputMsg::LdpSyncHolddown(msg) => {
+            events::process_ldp_sync_holddown_timeout(
+                instance,
+                arenas,
+                msg.iface_key,
+            )?;
+        }
     }
 
     Ok(())
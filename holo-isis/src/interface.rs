@@ -14,6 +14,7 @@ use std::sync::atomic::{self, AtomicU32};
 use chrono::{DateTime, Utc};
 use holo_utils::ip::{AddrList, AddressFamily};
 use holo_utils::mac_addr::MacAddr;
+use holo_utils::mpls::LdpSyncState;
 use holo_utils::socket::{AsyncFd, Socket, SocketExt};
 use holo_utils::southbound::InterfaceFlags;
 use holo_utils::sr::MsdType;
@@ -25,15 +26,15 @@ use crate::adjacency::{Adjacency, AdjacencyEvent, AdjacencyState};
 use crate::collections::{Adjacencies, Arena, InterfaceId, InterfaceIndex};
 use crate::debug::{Debug, InterfaceInactiveReason};
 use crate::error::{Error, IoError};
-use crate::instance::InstanceUpView;
+use crate::instance::{InstanceArenas, InstanceUpView};
 use crate::network::{LLC_HDR, MulticastAddr};
 use crate::northbound::configuration::InterfaceCfg;
 use crate::northbound::notification;
 use crate::packet::iana::{MtId, Nlpid, PduType};
 use crate::packet::pdu::{Hello, HelloTlvs, HelloVariant, Lsp, Pdu};
 use crate::packet::tlv::{
-    ExtendedSeqNum, LspEntry, MtFlags, MultiTopologyEntry, ThreeWayAdjState,
-    ThreeWayAdjTlv,
+    ExtendedSeqNum, LspEntry, MAX_WIDE_LINK_METRIC, MtFlags,
+    MultiTopologyEntry, ThreeWayAdjState, ThreeWayAdjTlv,
 };
 use crate::packet::{LanId, LevelNumber, LevelType, Levels, LspId, SystemId};
 use crate::tasks::messages::output::NetTxPduMsg;
@@ -70,6 +71,7 @@ pub struct InterfaceState {
     pub srm_list: Levels<BTreeMap<LspId, IntervalTask>>,
     pub ssn_list: Levels<BTreeMap<LspId, LspEntry>>,
     pub ext_seqnum: (u64, Arc<AtomicU32>),
+    pub ldp_sync: LdpSyncState,
     pub event_counters: InterfaceEventCounters,
     pub packet_counters: Levels<InterfacePacketCounters>,
     pub discontinuity_time: DateTime<Utc>,
@@ -91,6 +93,7 @@ pub struct InterfaceTasks {
     pub dis_initial_election: Levels<Option<TimeoutTask>>,
     pub psnp_interval: Levels<Option<IntervalTask>>,
     pub csnp_interval: Levels<Option<IntervalTask>>,
+    pub ldp_sync_holddown: Option<TimeoutTask>,
 }
 
 #[derive(Debug, Default)]
@@ -236,6 +239,9 @@ impl Interface {
         // Mark interface as active.
         self.state.active = true;
 
+        // Update the LDP-IGP synchronization state.
+        self.ldp_sync_update(instance);
+
         // Schedule LSP reorigination.
         instance.schedule_lsp_origination(self.config.level_type.resolved);
 
@@ -293,6 +299,7 @@ impl Interface {
         self.dis_initial_election_stop();
         self.psnp_interval_stop();
         self.csnp_interval_stop();
+        self.ldp_sync_update(instance);
 
         // Schedule LSP reorigination.
         instance.schedule_lsp_origination(self.config.level_type.resolved);
@@ -514,6 +521,48 @@ impl Interface {
         ))
     }
 
+    // Updates the LDP-IGP synchronization state of the interface (RFC 5443),
+    // starting or stopping the holddown timer as necessary.
+    //
+    // Returns whether the metric advertised for the interface has changed.
+    pub(crate) fn ldp_sync_update(
+        &mut self,
+        instance: &InstanceUpView<'_>,
+    ) -> bool {
+        let enabled =
+            self.config.ldp_igp_sync && self.state.active && !self.is_passive();
+        let ldp_synced = instance.system.ldp_sync.get(&self.name).copied();
+        let old_state = self.state.ldp_sync;
+        let new_state = old_state.next(enabled, ldp_synced);
+        if new_state == old_state {
+            return false;
+        }
+
+        Debug::InterfaceLdpSyncChange(&self.name, old_state, new_state).log();
+        self.state.ldp_sync = new_state;
+
+        // Start or stop the holddown timer.
+        let holddown = instance.config.ldp_igp_sync_holddown;
+        if new_state == LdpSyncState::NotAchieved && holddown != 0 {
+            let task = tasks::ldp_sync_holddown_timer(self, holddown, instance);
+            self.state.tasks.ldp_sync_holddown = Some(task);
+        } else {
+            self.state.tasks.ldp_sync_holddown = None;
+        }
+
+        old_state.max_metric() != new_state.max_metric()
+    }
+
+    // Returns the link metric to advertise for the interface, which is the
+    // maximum link metric while waiting for LDP to synchronize.
+    pub(crate) fn ldp_sync_metric(&self, metric: u32) -> u32 {
+        if self.state.ldp_sync.max_metric() {
+            MAX_WIDE_LINK_METRIC
+        } else {
+            metric
+        }
+    }
+
     fn generate_hello(
         &self,
         level: impl Into<LevelType>,
@@ -978,3 +1027,18 @@ impl Default for CircuitIdAllocator {
         }
     }
 }
+
+// ===== global functions =====
+
+// Updates the LDP-IGP synchronization state of all interfaces, reoriginating
+// the LSPs of the levels where an advertised metric has changed.
+pub(crate) fn ldp_sync_update_all(
+    instance: &mut InstanceUpView<'_>,
+    arenas: &mut InstanceArenas,
+) {
+    for iface in arenas.interfaces.iter_mut() {
+        if iface.ldp_sync_update(instance) {
+            instance.schedule_lsp_origination(iface.config.level_type.resolved);
+        }
+    }
+}
@@ -52,15 +52,16 @@ use crate::packet::subtlvs::prefix::{
 };
 use crate::packet::subtlvs::spb::{IsidEntry, IsidFlags, SpbmSiStlv};
 use crate::packet::tlv::{
-    IpReachTlvEntry, Ipv4Reach, Ipv4ReachStlvs, Ipv6Reach, Ipv6ReachStlvs,
-    IsReach, IsReachStlvs, LegacyIpv4Reach, LegacyIsReach, MAX_NARROW_METRIC,
-    MtCapStlvs, MtCapabilityTlv, MtFlags, MultiTopologyEntry, RouterCapFlags,
-    RouterCapTlv,
+    AppSpecificSrlgTlv, IpReachTlvEntry, Ipv4Reach, Ipv4ReachStlvs, Ipv6Reach,
+    Ipv6ReachStlvs, IsReach, IsReachStlvs, LegacyIpv4Reach, LegacyIsReach,
+    MAX_NARROW_METRIC, MtCapStlvs, MtCapabilityTlv, MtFlags,
+    MultiTopologyEntry, RouterCapFlags, RouterCapTlv, SidLabelBindingFlags,
+    SidLabelBindingStlvs, SidLabelBindingTlv,
 };
 use crate::packet::{LanId, LevelNumber, LevelType, LspId, SystemId};
 use crate::spf::{SpfType, VertexId};
 use crate::tasks::messages::input::LspPurgeMsg;
-use crate::{spf, tasks};
+use crate::{flex_algo, spf, tasks};
 
 // LSP ZeroAge lifetime.
 pub const LSP_ZERO_AGE_LIFETIME: u64 = 60;
@@ -274,6 +275,8 @@ fn lsp_build_tlvs(
     let mut ipv6_addrs = BTreeSet::new();
     let mut ipv6_reach = BTreeMap::new();
     let mut mt_ipv6_reach = BTreeMap::new();
+    let mut sid_label_binding = vec![];
+    let mut app_srlg = vec![];
 
     // Add supported protocols.
     if instance.config.is_af_enabled(AddressFamily::Ipv4) {
@@ -306,6 +309,7 @@ fn lsp_build_tlvs(
     // Iterate over all active interfaces.
     for iface in arenas.interfaces.iter().filter(|iface| iface.state.active) {
         // Add IS reachability information.
+        let ext_is_reach_start = ext_is_reach.len();
         lsp_build_tlvs_is_reach(
             instance,
             iface,
@@ -317,6 +321,14 @@ fn lsp_build_tlvs(
             &arenas.adjacencies,
         );
 
+        // Add application-specific SRLGs of the interface links.
+        lsp_build_tlvs_app_srlg(
+            instance,
+            iface,
+            &ext_is_reach[ext_is_reach_start..],
+            &mut app_srlg,
+        );
+
         // Add IP addresses and IP reachability information.
         lsp_build_tlvs_ip_local(
             instance,
@@ -339,6 +351,9 @@ fn lsp_build_tlvs(
         &mut ipv6_reach,
     );
 
+    // Add SR Mapping Server entries.
+    lsp_build_tlvs_sid_label_binding(instance, &mut sid_label_binding);
+
     // In an L1/L2 router, propagate L1 IP reachability to L2 for inter-area
     // routing. Skip propagation when the overload bit is set, since
     // advertising L1 prefixes into L2 would attract transit traffic that
@@ -385,6 +400,8 @@ fn lsp_build_tlvs(
         ipv6_reach.into_values(),
         mt_ipv6_reach.into_values(),
         instance.config.ipv6_router_id,
+        sid_label_binding,
+        app_srlg,
     )
 }
 
@@ -450,6 +467,8 @@ fn lsp_build_tlvs_pseudo(
         [],
         [],
         None,
+        vec![],
+        vec![],
     )
 }
 
@@ -481,7 +500,8 @@ fn lsp_build_tlvs_router_cap(
         cap.sub_tlvs.sr_cap = Some(SrCapabilitiesStlv::new(sr_cap_flags, srgb));
 
         // Add SR-Algorithm Sub-TLV.
-        cap.sub_tlvs.sr_algo = Some(SrAlgoStlv::new([IgpAlgoType::Spf].into()));
+        let sr_algos = lsp_sr_algos(instance).collect();
+        cap.sub_tlvs.sr_algo = Some(SrAlgoStlv::new(sr_algos));
 
         // Add SR Local Block Sub-TLV.
         let mut srlb = vec![];
@@ -518,10 +538,20 @@ fn lsp_build_tlvs_router_cap(
         ));
     }
 
+    // Add Flexible Algorithm Definition Sub-TLVs.
+    cap.sub_tlvs.fad = instance
+        .config
+        .flex_algo
+        .iter()
+        .filter(|(_, fa_cfg)| fa_cfg.advertise_definition)
+        .map(|(algo, fa_cfg)| flex_algo::fad_stlv(*algo, fa_cfg))
+        .collect();
+
     if cap.sub_tlvs.sr_cap.is_some()
         || cap.sub_tlvs.node_msd.is_some()
         || !cap.sub_tlvs.node_tags.is_empty()
         || cap.sub_tlvs.flooding_algo.is_some()
+        || !cap.sub_tlvs.fad.is_empty()
     {
         router_cap.push(cap);
     }
@@ -588,7 +618,7 @@ fn lsp_build_tlvs_is_reach(
     mt_is_reach: &mut Vec<IsReach>,
     adjacencies: &Arena<Adjacency>,
 ) {
-    let metric = iface.config.metric.get(level);
+    let metric = iface.ldp_sync_metric(iface.config.metric.get(level));
 
     match iface.config.interface_type {
         InterfaceType::Broadcast => {
@@ -648,7 +678,9 @@ fn lsp_build_tlvs_is_reach(
                     );
                     mt_is_reach.push(IsReach {
                         neighbor: dis.lan_id,
-                        metric: iface.config.topology_metric(mt_id, level),
+                        metric: iface.ldp_sync_metric(
+                            iface.config.topology_metric(mt_id, level),
+                        ),
                         sub_tlvs,
                     });
                 }
@@ -715,7 +747,9 @@ fn lsp_build_tlvs_is_reach(
                     );
                     mt_is_reach.push(IsReach {
                         neighbor,
-                        metric: iface.config.topology_metric(mt_id, level),
+                        metric: iface.ldp_sync_metric(
+                            iface.config.topology_metric(mt_id, level),
+                        ),
                         sub_tlvs,
                     });
                 }
@@ -1010,6 +1044,46 @@ fn lsp_build_is_reach_asla_stlvs(
     }
 }
 
+fn lsp_build_tlvs_app_srlg(
+    instance: &InstanceUpView<'_>,
+    iface: &Interface,
+    links: &[IsReach],
+    app_srlg: &mut Vec<AppSpecificSrlgTlv>,
+) {
+    if !matches!(
+        instance.config.link_attr_mode,
+        LinkAttrMode::AppSpecific | LinkAttrMode::Transition
+    ) {
+        return;
+    }
+
+    // Applications that share the same set of SRLGs are grouped into a single
+    // Application-Specific SRLG TLV.
+    let mut groups = BTreeMap::new();
+    for (app, asla_cfg) in iface
+        .config
+        .asla
+        .iter()
+        .filter(|(_, asla_cfg)| !asla_cfg.srlgs.is_empty())
+    {
+        *groups.entry(&asla_cfg.srlgs).or_default() |= app.sabm();
+    }
+    for link in links {
+        for (srlgs, sabm) in &groups {
+            app_srlg.push(AppSpecificSrlgTlv {
+                neighbor: link.neighbor,
+                l_flag: false,
+                sabm_length: 1,
+                sabm: *sabm,
+                udabm_length: 0,
+                udabm: 0,
+                sub_tlvs: Default::default(),
+                srlgs: srlgs.iter().copied().collect(),
+            });
+        }
+    }
+}
+
 fn lsp_build_ipv4_reach_stlvs(
     instance: &InstanceUpView<'_>,
     prefix: Ipv4Network,
@@ -1034,15 +1108,17 @@ fn lsp_build_ipv4_reach_stlvs(
 
     // Add Prefix-SID Sub-TLV(s).
     if add_prefix_sid && instance.config.sr.enabled {
-        let algo = IgpAlgoType::Spf;
-        if let Some(prefix_sid_cfg) = instance
-            .shared
-            .sr_config
-            .prefix_sids
-            .get(&(prefix.into(), algo))
-        {
-            let prefix_sid = lsp_build_prefix_sid_stlv(prefix_sid_cfg);
-            sub_tlvs.prefix_sids.insert(algo, prefix_sid);
+        for algo in lsp_sr_algos(instance) {
+            if let Some(prefix_sid_cfg) = instance
+                .shared
+                .sr_config
+                .prefix_sids
+                .get(&(prefix.into(), algo))
+            {
+                let prefix_sid =
+                    lsp_build_prefix_sid_stlv(prefix_sid_cfg, algo);
+                sub_tlvs.prefix_sids.insert(algo, prefix_sid);
+            }
         }
     }
 
@@ -1074,15 +1150,17 @@ fn lsp_build_ipv6_reach_stlvs(
 
     // Add Prefix-SID Sub-TLV(s).
     if add_prefix_sid && instance.config.sr.enabled {
-        let algo = IgpAlgoType::Spf;
-        if let Some(prefix_sid_cfg) = instance
-            .shared
-            .sr_config
-            .prefix_sids
-            .get(&(prefix.into(), algo))
-        {
-            let prefix_sid = lsp_build_prefix_sid_stlv(prefix_sid_cfg);
-            sub_tlvs.prefix_sids.insert(algo, prefix_sid);
+        for algo in lsp_sr_algos(instance) {
+            if let Some(prefix_sid_cfg) = instance
+                .shared
+                .sr_config
+                .prefix_sids
+                .get(&(prefix.into(), algo))
+            {
+                let prefix_sid =
+                    lsp_build_prefix_sid_stlv(prefix_sid_cfg, algo);
+                sub_tlvs.prefix_sids.insert(algo, prefix_sid);
+            }
         }
     }
 
@@ -1137,7 +1215,58 @@ fn lsp_build_ipv6_reach_stlvs(
     sub_tlvs
 }
 
-fn lsp_build_prefix_sid_stlv(prefix_sid_cfg: &SrCfgPrefixSid) -> PrefixSidStlv {
+fn lsp_build_tlvs_sid_label_binding(
+    instance: &InstanceUpView<'_>,
+    sid_label_binding: &mut Vec<SidLabelBindingTlv>,
+) {
+    if !instance.config.sr.enabled {
+        return;
+    }
+
+    // Collect the entries of all advertised mapping server policies. If the
+    // same entry is present in multiple policies, the first one is used.
+    let sr_config = &instance.shared.sr_config;
+    let mut entries = BTreeMap::new();
+    for ((prefix, algo), entry) in instance
+        .config
+        .sr
+        .ms_advertise_policies
+        .iter()
+        .filter_map(|name| sr_config.ms_policies.get(name))
+        .flat_map(|policy| policy.entries.iter())
+        .filter(|((prefix, _), _)| {
+            let af = match prefix {
+                IpNetwork::V4(_) => AddressFamily::Ipv4,
+                IpNetwork::V6(_) => AddressFamily::Ipv6,
+            };
+            instance.config.is_af_enabled(af)
+        })
+    {
+        entries.entry((*prefix, *algo)).or_insert(entry);
+    }
+
+    for ((prefix, algo), entry) in entries {
+        let mut flags = SidLabelBindingFlags::empty();
+        if prefix.is_ipv6() {
+            flags.insert(SidLabelBindingFlags::F);
+        }
+        let mut sub_tlvs = SidLabelBindingStlvs::default();
+        let sid = Sid::Index(entry.index);
+        let prefix_sid = PrefixSidStlv::new(PrefixSidFlags::empty(), algo, sid);
+        sub_tlvs.prefix_sids.insert(algo, prefix_sid);
+        sid_label_binding.push(SidLabelBindingTlv {
+            flags,
+            range: entry.range as u16,
+            prefix,
+            sub_tlvs,
+        });
+    }
+}
+
+fn lsp_build_prefix_sid_stlv(
+    prefix_sid_cfg: &SrCfgPrefixSid,
+    algo: IgpAlgoType,
+) -> PrefixSidStlv {
     let mut flags = PrefixSidFlags::empty();
     match prefix_sid_cfg.last_hop {
         SidLastHopBehavior::ExpNull => {
@@ -1149,11 +1278,23 @@ fn lsp_build_prefix_sid_stlv(prefix_sid_cfg: &SrCfgPrefixSid) -> PrefixSidStlv {
         }
         SidLastHopBehavior::Php => (),
     }
-    let algo = IgpAlgoType::Spf;
     let sid = Sid::Index(prefix_sid_cfg.index);
     PrefixSidStlv::new(flags, algo, sid)
 }
 
+// Returns the SR algorithms supported by the local router.
+fn lsp_sr_algos<'a>(
+    instance: &'a InstanceUpView<'_>,
+) -> impl Iterator<Item = IgpAlgoType> + 'a {
+    [IgpAlgoType::Spf].into_iter().chain(
+        instance
+            .config
+            .flex_algo
+            .keys()
+            .map(|algo| IgpAlgoType::FlexAlgo(*algo)),
+    )
+}
+
 fn lsp_build_fragments(
     instance: &mut InstanceUpView<'_>,
     arenas: &InstanceArenas,
@@ -33,7 +33,7 @@ use crate::interface::InterfaceType;
 use crate::northbound::notification;
 use crate::northbound::yang_gen::isis;
 use crate::packet::auth::AuthMethod;
-use crate::packet::iana::{AslaSabmFlags, FloodingAlgo, MtId, PduType};
+use crate::packet::iana::{AslaSabmFlags, FloodingAlgo, IgpAlgoType, IgpMetricType, MtId, PduType};
 use crate::packet::{AreaAddr, LevelNumber, LevelType, LevelTypeIterator, SystemId};
 use crate::route::RouteFlags;
 use crate::{ibus, spf, sr};
@@ -48,6 +48,7 @@ pub enum ListEntry {
     Redistribution(AddressFamily, LevelNumber, Protocol),
     Topology(MtId),
     NodeTag(u32),
+    FlexAlgo(u8),
     TraceOption(InstanceTraceOption),
     Interface(InterfaceIndex),
     InterfaceAddressFamily(InterfaceIndex, AddressFamily),
@@ -77,12 +78,14 @@ pub enum Event {
     InterfaceUpdateHelloInterval(InterfaceIndex, LevelNumber),
     InterfaceUpdateCsnpInterval(InterfaceIndex),
     InterfaceBfdChange(InterfaceIndex),
+    InterfaceLdpSyncChange(InterfaceIndex),
     InterfaceUpdateTraceOptions(InterfaceIndex),
     InterfaceIbusSub(InterfaceIndex),
     ReoriginateLsps(LevelNumber),
     RefreshLsps,
     RerunSpf,
     ReinstallRoutes,
+    FlexAlgoChange,
     OverloadChange(bool),
     SrEnabledChange(bool),
     RedistributeAdd(AddressFamily, Protocol),
@@ -112,6 +115,7 @@ pub struct InstanceCfg {
     pub auth_resolved: Arc<ArcSwap<Option<AuthMethod>>>,
     pub ipv4_router_id: Option<Ipv4Addr>,
     pub ipv6_router_id: Option<Ipv6Addr>,
+    pub ldp_igp_sync_holddown: u16,
     pub max_paths: u16,
     pub afs: BTreeMap<AddressFamily, AddressFamilyCfg>,
     pub spf_initial_delay: u32,
@@ -128,6 +132,7 @@ pub struct InstanceCfg {
     pub att_suppress: bool,
     pub att_ignore: bool,
     pub sr: InstanceSrCfg,
+    pub flex_algo: BTreeMap<u8, FlexAlgoCfg>,
     pub bier: InstanceBierCfg,
     pub spb: InstanceSpbCfg,
     pub trace_opts: InstanceTraceOptions,
@@ -157,13 +162,15 @@ pub enum StandardApp {
     RsvpTe,
     SrPolicy,
     Lfa,
+    FlexAlgo,
 }
 
 // Per-application application-specific link attributes configuration.
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Debug, Default)]
 pub struct InterfaceAslaCfg {
     pub te_metric: Option<u32>,
     pub admin_group: Option<u32>,
+    pub srlgs: BTreeSet<u32>,
 }
 
 #[derive(Debug)]
@@ -174,6 +181,21 @@ pub struct InstanceFloodingReductionCfg {
 #[derive(Debug)]
 pub struct InstanceSrCfg {
     pub enabled: bool,
+    pub ms_advertise_policies: BTreeSet<String>,
+    pub ms_receive: bool,
+}
+
+// Flexible Algorithm Definition configuration (RFC 9350).
+#[derive(Debug)]
+pub struct FlexAlgoCfg {
+    pub advertise_definition: bool,
+    pub metric_type: IgpMetricType,
+    pub calc_type: IgpAlgoType,
+    pub priority: u8,
+    pub exclude_any: BTreeSet<Vec<u8>>,
+    pub include_any: BTreeSet<Vec<u8>>,
+    pub include_all: BTreeSet<Vec<u8>>,
+    pub exclude_srlgs: BTreeSet<u32>,
 }
 
 #[derive(Debug)]
@@ -279,6 +301,7 @@ pub struct InterfaceCfg {
     pub metric: LevelsCfgWithDefault<u32>,
     pub bfd_enabled: bool,
     pub bfd_params: bfd::ClientCfg,
+    pub ldp_igp_sync: bool,
     pub afs: BTreeSet<AddressFamily>,
     pub mt: HashMap<MtId, InterfaceMtCfg>,
     pub asla: BTreeMap<StandardApp, InterfaceAslaCfg>,
@@ -467,6 +490,179 @@ fn load_callbacks() -> Callbacks<Instance> {
             let node_tag = dnode.get_u32_relative("tag").unwrap();
             ListEntry::NodeTag(node_tag)
         })
+        .path(isis::isis_flex_algo::flex_algo::PATH)
+        .create_apply(|instance, args| {
+            let algo = args.dnode.get_u8_relative("algo-number").unwrap();
+            instance.config.flex_algo.insert(algo, Default::default());
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
+        .delete_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            instance.config.flex_algo.remove(&algo);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
+        .lookup(|_instance, _list_entry, dnode| {
+            let algo = dnode.get_u8_relative("algo-number").unwrap();
+            ListEntry::FlexAlgo(algo)
+        })
+        .path(isis::isis_flex_algo::flex_algo::advertise_definition::PATH)
+        .modify_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let fa_cfg = instance.config.flex_algo.get_mut(&algo).unwrap();
+
+            let advertise = args.dnode.get_bool();
+            fa_cfg.advertise_definition = advertise;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
+        .path(isis::isis_flex_algo::flex_algo::admin_groups::exclude_admin_groups::PATH)
+        .create_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let fa_cfg = instance.config.flex_algo.get_mut(&algo).unwrap();
+
+            let admin_group = args.dnode.get_string();
+            let admin_group = HexString::try_from_yang(&admin_group).unwrap();
+            fa_cfg.exclude_any.insert(admin_group.0);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
+        .delete_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let fa_cfg = instance.config.flex_algo.get_mut(&algo).unwrap();
+
+            let admin_group = args.dnode.get_string();
+            let admin_group = HexString::try_from_yang(&admin_group).unwrap();
+            fa_cfg.exclude_any.remove(&admin_group.0);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
+        .path(isis::isis_flex_algo::flex_algo::admin_groups::include_any_admin_groups::PATH)
+        .create_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let fa_cfg = instance.config.flex_algo.get_mut(&algo).unwrap();
+
+            let admin_group = args.dnode.get_string();
+            let admin_group = HexString::try_from_yang(&admin_group).unwrap();
+            fa_cfg.include_any.insert(admin_group.0);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
+        .delete_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let fa_cfg = instance.config.flex_algo.get_mut(&algo).unwrap();
+
+            let admin_group = args.dnode.get_string();
+            let admin_group = HexString::try_from_yang(&admin_group).unwrap();
+            fa_cfg.include_any.remove(&admin_group.0);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
+        .path(isis::isis_flex_algo::flex_algo::admin_groups::include_all_admin_groups::PATH)
+        .create_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let fa_cfg = instance.config.flex_algo.get_mut(&algo).unwrap();
+
+            let admin_group = args.dnode.get_string();
+            let admin_group = HexString::try_from_yang(&admin_group).unwrap();
+            fa_cfg.include_all.insert(admin_group.0);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
+        .delete_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let fa_cfg = instance.config.flex_algo.get_mut(&algo).unwrap();
+
+            let admin_group = args.dnode.get_string();
+            let admin_group = HexString::try_from_yang(&admin_group).unwrap();
+            fa_cfg.include_all.remove(&admin_group.0);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
+        .path(isis::isis_flex_algo::flex_algo::exclude_srlgs::PATH)
+        .create_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let fa_cfg = instance.config.flex_algo.get_mut(&algo).unwrap();
+
+            let srlg = args.dnode.get_u32();
+            fa_cfg.exclude_srlgs.insert(srlg);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
+        .delete_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let fa_cfg = instance.config.flex_algo.get_mut(&algo).unwrap();
+
+            let srlg = args.dnode.get_u32();
+            fa_cfg.exclude_srlgs.remove(&srlg);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
+        .path(isis::isis_flex_algo::flex_algo::metric_type::PATH)
+        .modify_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let fa_cfg = instance.config.flex_algo.get_mut(&algo).unwrap();
+
+            let metric_type = args.dnode.get_string();
+            let metric_type = IgpMetricType::try_from_yang(&metric_type).unwrap();
+            fa_cfg.metric_type = metric_type;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
+        .delete_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let fa_cfg = instance.config.flex_algo.get_mut(&algo).unwrap();
+
+            fa_cfg.metric_type = IgpMetricType::IgpMetric;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
+        .path(isis::isis_flex_algo::flex_algo::calc_type::PATH)
+        .modify_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let fa_cfg = instance.config.flex_algo.get_mut(&algo).unwrap();
+
+            let calc_type = args.dnode.get_string();
+            let calc_type = IgpAlgoType::try_from_yang(&calc_type).unwrap();
+            fa_cfg.calc_type = calc_type;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
+        .path(isis::isis_flex_algo::flex_algo::priority::PATH)
+        .modify_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let fa_cfg = instance.config.flex_algo.get_mut(&algo).unwrap();
+
+            let priority = args.dnode.get_u8();
+            fa_cfg.priority = priority;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
+        .delete_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let fa_cfg = instance.config.flex_algo.get_mut(&algo).unwrap();
+
+            fa_cfg.priority = 128;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
         .path(isis::metric_type::value::PATH)
         .modify_apply(|instance, args| {
             let metric_type = args.dnode.get_string();
@@ -829,6 +1025,11 @@ fn load_callbacks() -> Callbacks<Instance> {
             event_queue.insert(Event::ReoriginateLsps(LevelNumber::L1));
             event_queue.insert(Event::ReoriginateLsps(LevelNumber::L2));
         })
+        .path(isis::mpls::ldp::igp_sync::holddown::PATH)
+        .modify_apply(|instance, args| {
+            let holddown = args.dnode.get_u16();
+            instance.config.ldp_igp_sync_holddown = holddown;
+        })
         .path(isis::spf_control::paths::PATH)
         .modify_apply(|instance, args| {
             let max_paths = args.dnode.get_u16();
@@ -1813,6 +2014,17 @@ fn load_callbacks() -> Callbacks<Instance> {
         .delete_apply(|_instance, _args| {
             // Nothing to do.
         })
+        .path(isis::interfaces::interface::mpls::ldp::igp_sync::PATH)
+        .modify_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let enabled = args.dnode.get_bool();
+            iface.config.ldp_igp_sync = enabled;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceLdpSyncChange(iface_idx));
+        })
         .path(isis::interfaces::interface::address_families::address_family_list::PATH)
         .create_apply(|instance, args| {
             let iface_idx = args.list_entry.into_interface().unwrap();
@@ -2030,6 +2242,31 @@ fn load_callbacks() -> Callbacks<Instance> {
             event_queue.insert(Event::ReoriginateLsps(LevelNumber::L1));
             event_queue.insert(Event::ReoriginateLsps(LevelNumber::L2));
         })
+        .path(isis::interfaces::interface::isis_asla::interface_asla::srlgs::srlg::PATH)
+        .create_apply(|instance, args| {
+            let (iface_idx, app) = args.list_entry.into_interface_asla().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+            let asla_cfg = iface.config.asla.get_mut(&app).unwrap();
+
+            let srlg = args.dnode.get_u32();
+            asla_cfg.srlgs.insert(srlg);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L1));
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L2));
+        })
+        .delete_apply(|instance, args| {
+            let (iface_idx, app) = args.list_entry.into_interface_asla().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+            let asla_cfg = iface.config.asla.get_mut(&app).unwrap();
+
+            let srlg = args.dnode.get_u32();
+            asla_cfg.srlgs.remove(&srlg);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L1));
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L2));
+        })
         .path(isis::interfaces::interface::extended_sequence_number::mode::PATH)
         .modify_apply(|instance, args| {
             let iface_idx = args.list_entry.into_interface().unwrap();
@@ -2305,6 +2542,31 @@ fn load_callbacks() -> Callbacks<Instance> {
             event_queue.insert(Event::ReoriginateLsps(LevelNumber::L1));
             event_queue.insert(Event::ReoriginateLsps(LevelNumber::L2));
         })
+        .path(isis::segment_routing::bindings::advertise::policies::PATH)
+        .create_apply(|instance, args| {
+            let policy = args.dnode.get_string();
+            instance.config.sr.ms_advertise_policies.insert(policy);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L1));
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L2));
+        })
+        .delete_apply(|instance, args| {
+            let policy = args.dnode.get_string();
+            instance.config.sr.ms_advertise_policies.remove(&policy);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L1));
+            event_queue.insert(Event::ReoriginateLsps(LevelNumber::L2));
+        })
+        .path(isis::segment_routing::bindings::receive::PATH)
+        .modify_apply(|instance, args| {
+            let receive = args.dnode.get_bool();
+            instance.config.sr.ms_receive = receive;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::RerunSpf);
+        })
         .build()
 }
 
@@ -2448,6 +2710,15 @@ impl Provider for Instance {
                     }
                 });
             }
+            Event::InterfaceLdpSyncChange(iface_idx) => {
+                let Some((mut instance, arenas)) = self.as_up() else {
+                    return;
+                };
+                let iface = &mut arenas.interfaces[iface_idx];
+                if iface.ldp_sync_update(&instance) {
+                    instance.schedule_lsp_origination(iface.config.level_type.resolved);
+                }
+            }
             Event::InterfaceUpdateTraceOptions(iface_idx) => {
                 let iface = &mut self.arenas.interfaces[iface_idx];
                 iface.config.update_trace_options(&self.config);
@@ -2484,6 +2755,21 @@ impl Provider for Instance {
                         let distance = route.distance(instance.config);
                         ibus::tx::route_install(&instance.tx.ibus, prefix, route, None, distance, &arenas.interfaces);
                     }
+                    for route in instance.state.flex_algo_rib.values().flat_map(|rib| rib.values()).filter(|route| route.flags.contains(RouteFlags::INSTALLED)) {
+                        ibus::tx::flex_algo_label_install(&instance.tx.ibus, route, None, &arenas.interfaces);
+                    }
+                }
+            }
+            Event::FlexAlgoChange => {
+                if let Some((mut instance, _)) = self.as_up() {
+                    for level in instance.config.levels() {
+                        // (Re)originate LSPs to update the Router Capability
+                        // TLV.
+                        instance.schedule_lsp_origination(level);
+
+                        // Recompute the Flexible Algorithm topologies.
+                        instance.tx.protocol_input.spf_delay_event(level, spf::fsm::Event::ConfigChange);
+                    }
                 }
             }
             Event::OverloadChange(overload_status) => {
@@ -2739,6 +3025,7 @@ impl StandardApp {
             StandardApp::RsvpTe => AslaSabmFlags::R,
             StandardApp::SrPolicy => AslaSabmFlags::S,
             StandardApp::Lfa => AslaSabmFlags::F,
+            StandardApp::FlexAlgo => AslaSabmFlags::X,
         }
     }
 }
@@ -2795,6 +3082,7 @@ impl Default for InstanceCfg {
         let overload_status = isis::overload::status::DFLT;
         let att_suppress = isis::attached_bit::suppress_advertisement::DFLT;
         let att_ignore = isis::attached_bit::ignore_reception::DFLT;
+        let ldp_igp_sync_holddown = isis::mpls::ldp::igp_sync::holddown::DFLT;
 
         InstanceCfg {
             enabled,
@@ -2813,6 +3101,7 @@ impl Default for InstanceCfg {
             max_paths,
             ipv4_router_id: None,
             ipv6_router_id: None,
+            ldp_igp_sync_holddown,
             afs: Default::default(),
             spf_initial_delay,
             spf_short_delay,
@@ -2828,6 +3117,7 @@ impl Default for InstanceCfg {
             att_suppress,
             att_ignore,
             sr: Default::default(),
+            flex_algo: Default::default(),
             bier: Default::default(),
             spb: Default::default(),
             trace_opts: Default::default(),
@@ -2865,8 +3155,29 @@ impl Default for InstanceFloodingReductionCfg {
 impl Default for InstanceSrCfg {
     fn default() -> Self {
         let enabled = isis::segment_routing::enabled::DFLT;
+        let ms_receive = isis::segment_routing::bindings::receive::DFLT;
         Self {
             enabled,
+            ms_advertise_policies: Default::default(),
+            ms_receive,
+        }
+    }
+}
+
+impl Default for FlexAlgoCfg {
+    fn default() -> Self {
+        let advertise_definition = isis::isis_flex_algo::flex_algo::advertise_definition::DFLT;
+        let calc_type = isis::isis_flex_algo::flex_algo::calc_type::DFLT;
+        let calc_type = IgpAlgoType::try_from_yang(calc_type).unwrap();
+        Self {
+            advertise_definition,
+            metric_type: IgpMetricType::IgpMetric,
+            calc_type,
+            priority: 128,
+            exclude_any: Default::default(),
+            include_any: Default::default(),
+            include_all: Default::default(),
+            exclude_srlgs: Default::default(),
         }
     }
 }
@@ -2962,6 +3273,7 @@ impl Default for InterfaceCfg {
             l2: None,
         };
         let bfd_enabled = isis::interfaces::interface::bfd::enabled::DFLT;
+        let ldp_igp_sync = isis::interfaces::interface::mpls::ldp::igp_sync::DFLT;
         InterfaceCfg {
             enabled,
             level_type,
@@ -2981,6 +3293,7 @@ impl Default for InterfaceCfg {
             metric,
             bfd_enabled,
             bfd_params: Default::default(),
+            ldp_igp_sync,
             afs: Default::default(),
             mt: Default::default(),
             asla: Default::default(),
@@ -17,6 +17,7 @@ pub mod yang;
 #[allow(unused_imports, unused_variables)]
 #[allow(clippy::module_inception)]
 pub mod yang_gen {
+    use holo_utils::mpls::LdpSyncState;
     pub use routing::control_plane_protocols::control_plane_protocol::isis;
 
     use crate::adjacency::AdjacencyState;
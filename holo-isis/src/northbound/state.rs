@@ -16,6 +16,7 @@ use std::time::Instant;
 use holo_northbound::state::{ListIterator, Provider, YangContainer, YangList, YangOps};
 use holo_utils::crypto::CryptoAlgo;
 use holo_utils::mac_addr::MacAddr;
+use holo_utils::num::SaturatingInto;
 use holo_utils::option::OptionExt;
 use holo_utils::protocol::Protocol;
 use holo_yang::types::{HexStr, HexString, TimerValueMillis, TimerValueSecs16, Timeticks};
@@ -2260,6 +2261,20 @@ impl<'a> YangContainer<'a, Instance> for isis::interfaces::interface::event_coun
     }
 }
 
+impl<'a> YangContainer<'a, Instance> for isis::interfaces::interface::ldp_igp_sync::LdpIgpSync {
+    type ParentListEntry = &'a Interface;
+
+    fn new(_instance: &'a Instance, iface: &Self::ParentListEntry) -> Option<Self> {
+        if !iface.config.ldp_igp_sync {
+            return None;
+        }
+        Some(Self {
+            state: Some(iface.state.ldp_sync),
+            holddown_timer: iface.state.tasks.ldp_sync_holddown.as_ref().map(|task| task.remaining().as_secs().saturating_into()).ignore_in_testing(),
+        })
+    }
+}
+
 impl<'a> YangList<'a, Instance> for isis::interfaces::interface::packet_counters::level::Level {
     type ParentListEntry = &'a Interface;
     type ListEntry = (&'a Interface, LevelNumber);
@@ -353,6 +353,9 @@ impl ToYangFlags for AslaSabmFlags {
         if self.contains(AslaSabmFlags::F) {
             bits.push("iana-igp-link-attr-apps:lfa-app");
         }
+        if self.contains(AslaSabmFlags::X) {
+            bits.push("iana-igp-link-attr-apps:flex-algo-app");
+        }
         bits
     }
 }
@@ -469,12 +472,34 @@ impl TryFromYang for ExtendedSeqNumMode {
     }
 }
 
+impl TryFromYang for IgpMetricType {
+    fn try_from_yang(value: &str) -> Option<IgpMetricType> {
+        match value {
+            "iana-igp-metric-types:igp-metric" => Some(IgpMetricType::IgpMetric),
+            "iana-igp-metric-types:min-unidirectional-link-delay" => Some(IgpMetricType::MinUniLinkDelay),
+            "iana-igp-metric-types:te-default-metric" => Some(IgpMetricType::TeDefaultMetric),
+            _ => None,
+        }
+    }
+}
+
+impl TryFromYang for IgpAlgoType {
+    fn try_from_yang(value: &str) -> Option<IgpAlgoType> {
+        match value {
+            "iana-igp-algo-types:algo-spf" => Some(IgpAlgoType::Spf),
+            "iana-igp-algo-types:algo-strict-spf" => Some(IgpAlgoType::StrictSpf),
+            _ => None,
+        }
+    }
+}
+
 impl TryFromYang for StandardApp {
     fn try_from_yang(value: &str) -> Option<StandardApp> {
         match value {
             "iana-igp-link-attr-apps:rsvp-te-app" => Some(StandardApp::RsvpTe),
             "iana-igp-link-attr-apps:sr-policy-app" => Some(StandardApp::SrPolicy),
             "iana-igp-link-attr-apps:lfa-app" => Some(StandardApp::Lfa),
+            "iana-igp-link-attr-apps:flex-algo-app" => Some(StandardApp::FlexAlgo),
             _ => None,
         }
     }
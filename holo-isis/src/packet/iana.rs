@@ -57,12 +57,14 @@ pub enum TlvType {
     DynamicHostname = 137,
     Ipv6RouterId = 140,
     MtCapability = 144,
+    SidLabelBinding = 149,
     MtIsReach = 222,
     MultiTopology = 229,
     Ipv6Addresses = 232,
     MtIpv4Reach = 235,
     Ipv6Reach = 236,
     MtIpv6Reach = 237,
+    AppSpecificSrlg = 238,
     ThreeWayAdj = 240,
     RouterCapability = 242,
 }
@@ -147,6 +149,8 @@ bitflags! {
         const S = 0x4000_0000_0000_0000;
         // F-bit: Loop-Free Alternate (LFA).
         const F = 0x2000_0000_0000_0000;
+        // X-bit: Flexible Algorithm.
+        const X = 0x1000_0000_0000_0000;
     }
 }
 
@@ -166,6 +170,18 @@ pub enum PrefixStlvType {
     BierInfo = 32,
 }
 
+// IS-IS Sub-TLVs for the SID/Label Binding TLV.
+//
+// IANA registry:
+// https://www.iana.org/assignments/isis-tlv-codepoints/isis-tlv-codepoints.xhtml#isis-tlv-codepoints-149-150
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[derive(FromPrimitive, ToPrimitive)]
+#[derive(Deserialize, Serialize)]
+pub enum SidLabelBindingStlvType {
+    SidLabel = 1,
+    PrefixSid = 3,
+}
+
 // IS-IS Sub-TLVs for the Router Capability TLV.
 //
 // IANA registry:
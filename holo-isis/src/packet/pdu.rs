@@ -27,18 +27,19 @@ use crate::packet::auth::AuthMethod;
 use crate::packet::error::{DecodeError, DecodeResult};
 use crate::packet::iana::{MtId, PduType, TlvType};
 use crate::packet::subtlvs::capability::{
-    FloodingAlgoStlv, SrAlgoStlv, SrCapabilitiesStlv,
+    FadStlv, FloodingAlgoStlv, SrAlgoStlv, SrCapabilitiesStlv,
 };
 use crate::packet::tlv::{
-    AreaAddressesTlv, AuthenticationTlv, DynamicHostnameTlv, ExtendedSeqNum,
-    ExtendedSeqNumTlv, Ipv4AddressesTlv, Ipv4Reach, Ipv4ReachTlv,
-    Ipv4RouterIdTlv, Ipv6AddressesTlv, Ipv6Reach, Ipv6ReachTlv,
-    Ipv6RouterIdTlv, IsReach, IsReachTlv, LegacyIpv4Reach, LegacyIpv4ReachTlv,
-    LegacyIsReach, LegacyIsReachTlv, LspBufferSizeTlv, LspEntriesTlv, LspEntry,
-    MtCapabilityTlv, MtFlags, MultiTopologyEntry, MultiTopologyTlv,
-    NeighborsTlv, PaddingTlv, ProtocolsSupportedTlv, PurgeOriginatorIdTlv,
-    RouterCapTlv, TLV_HDR_SIZE, TLV_MAX_LEN, ThreeWayAdjTlv, Tlv, UnknownTlv,
-    tlv_entries_split, tlv_take_max,
+    AppSpecificSrlgTlv, AreaAddressesTlv, AuthenticationTlv,
+    DynamicHostnameTlv, ExtendedSeqNum, ExtendedSeqNumTlv, Ipv4AddressesTlv,
+    Ipv4Reach, Ipv4ReachTlv, Ipv4RouterIdTlv, Ipv6AddressesTlv, Ipv6Reach,
+    Ipv6ReachTlv, Ipv6RouterIdTlv, IsReach, IsReachTlv, LegacyIpv4Reach,
+    LegacyIpv4ReachTlv, LegacyIsReach, LegacyIsReachTlv, LspBufferSizeTlv,
+    LspEntriesTlv, LspEntry, MtCapabilityTlv, MtFlags, MultiTopologyEntry,
+    MultiTopologyTlv, NeighborsTlv, PaddingTlv, ProtocolsSupportedTlv,
+    PurgeOriginatorIdTlv, RouterCapTlv, SidLabelBindingTlv, TLV_HDR_SIZE,
+    TLV_MAX_LEN, ThreeWayAdjTlv, Tlv, UnknownTlv, tlv_entries_split,
+    tlv_take_max,
 };
 use crate::packet::{
     AreaAddr, LanId, LevelNumber, LevelType, LspId, SystemId, auth,
@@ -169,6 +170,8 @@ pub struct LspTlvs {
     pub ipv6_reach: Vec<Ipv6ReachTlv>,
     pub mt_ipv6_reach: Vec<Ipv6ReachTlv>,
     pub ipv6_router_id: Option<Ipv6RouterIdTlv>,
+    pub sid_label_binding: Vec<SidLabelBindingTlv>,
+    pub app_srlg: Vec<AppSpecificSrlgTlv>,
     pub unknown: Vec<UnknownTlv>,
 }
 
@@ -1209,6 +1212,19 @@ impl Lsp {
                         Err(error) => error.log(),
                     }
                 }
+                Some(TlvType::SidLabelBinding) => {
+                    match SidLabelBindingTlv::decode(tlv_len, &mut buf_tlv) {
+                        Ok(tlv) => tlvs.sid_label_binding.push(tlv),
+                        Err(error) => error.log(),
+                    }
+                }
+                Some(TlvType::AppSpecificSrlg) => {
+                    match AppSpecificSrlgTlv::decode(tlv_len, &mut buf_tlv) {
+                        Ok(Some(tlv)) => tlvs.app_srlg.push(tlv),
+                        Ok(None) => {}
+                        Err(error) => error.log(),
+                    }
+                }
                 _ => {
                     // Save unknown top-level TLV.
                     tlvs.unknown
@@ -1326,6 +1342,12 @@ impl Lsp {
             if let Some(tlv) = &self.tlvs.ipv6_router_id {
                 tlv.encode(&mut buf);
             }
+            for tlv in &self.tlvs.sid_label_binding {
+                tlv.encode(&mut buf);
+            }
+            for tlv in &self.tlvs.app_srlg {
+                tlv.encode(&mut buf);
+            }
 
             // Store LSP raw data.
             let bytes = pdu_encode_end(buf, len_pos, auth, Some(self));
@@ -1499,6 +1521,8 @@ impl LspTlvs {
         ipv6_reach: impl IntoIterator<Item = Ipv6Reach>,
         mt_ipv6_reach: impl IntoIterator<Item = Ipv6Reach>,
         ipv6_router_id: Option<Ipv6Addr>,
+        sid_label_binding: Vec<SidLabelBindingTlv>,
+        app_srlg: Vec<AppSpecificSrlgTlv>,
     ) -> Self {
         LspTlvs {
             auth: None,
@@ -1537,6 +1561,8 @@ impl LspTlvs {
                 })
                 .collect(),
             ipv6_router_id: ipv6_router_id.map(Ipv6RouterIdTlv::new),
+            sid_label_binding,
+            app_srlg,
             unknown: Default::default(),
         }
     }
@@ -1582,6 +1608,9 @@ impl LspTlvs {
         let ipv6_addrs = tlv_take_max(&mut self.ipv6_addrs, &mut rem_len);
         let ipv6_reach = tlv_take_max(&mut self.ipv6_reach, &mut rem_len);
         let mt_ipv6_reach = tlv_take_max(&mut self.mt_ipv6_reach, &mut rem_len);
+        let sid_label_binding =
+            tlv_take_max(&mut self.sid_label_binding, &mut rem_len);
+        let app_srlg = tlv_take_max(&mut self.app_srlg, &mut rem_len);
         if rem_len == max_len {
             return None;
         }
@@ -1609,6 +1638,8 @@ impl LspTlvs {
             ipv6_reach,
             mt_ipv6_reach,
             ipv6_router_id,
+            sid_label_binding,
+            app_srlg,
             unknown: Default::default(),
         })
     }
@@ -1666,6 +1697,8 @@ impl LspTlvs {
             && self.ipv6_reach.is_empty()
             && self.mt_ipv6_reach.is_empty()
             && self.ipv6_router_id.is_none()
+            && self.sid_label_binding.is_empty()
+            && self.app_srlg.is_empty()
     }
 
     // Returns an iterator over all supported protocols from the TLV of type 129.
@@ -1812,6 +1845,18 @@ impl LspTlvs {
             .flat_map(|tlv| tlv.list.iter())
     }
 
+    // Returns an iterator over all SID/Label Binding TLVs of type 149.
+    pub(crate) fn sid_label_binding(
+        &self,
+    ) -> impl Iterator<Item = &SidLabelBindingTlv> {
+        self.sid_label_binding.iter()
+    }
+
+    // Returns an iterator over all Application-Specific SRLG TLVs of type 238.
+    pub(crate) fn app_srlg(&self) -> impl Iterator<Item = &AppSpecificSrlgTlv> {
+        self.app_srlg.iter()
+    }
+
     // Returns the first SR-Capabilities Sub-TLV found within any Router
     // Capabilities TLV.
     pub(crate) fn sr_cap(&self) -> Option<&SrCapabilitiesStlv> {
@@ -1835,6 +1880,14 @@ impl LspTlvs {
             .iter()
             .find_map(|router_cap| router_cap.sub_tlvs.flooding_algo.as_ref())
     }
+
+    // Returns an iterator over all Flexible Algorithm Definition Sub-TLVs
+    // found within the Router Capabilities TLVs.
+    pub(crate) fn fads(&self) -> impl Iterator<Item = &FadStlv> {
+        self.router_cap
+            .iter()
+            .flat_map(|router_cap| router_cap.sub_tlvs.fad.iter())
+    }
 }
 
 // In conformance tests, we only care whether the LSP Remaining Lifetime is
@@ -15,7 +15,7 @@ use derive_new::new;
 use holo_utils::bytes::{BytesExt, BytesMutExt};
 use holo_utils::mpls::Label;
 use holo_utils::sr::{IgpAlgoType, Sid};
-use num_traits::FromPrimitive;
+use num_traits::{FromPrimitive, ToPrimitive};
 use serde::{Deserialize, Serialize};
 use tracing::debug_span;
 
@@ -195,7 +195,7 @@ impl SrAlgoStlv {
     pub(crate) fn encode(&self, buf: &mut BytesMut) {
         let start_pos = tlv_encode_start(buf, RouterCapStlvType::SrAlgorithm);
         for algo in &self.0 {
-            buf.put_u8(*algo as u8);
+            buf.put_u8(algo.to_u8().unwrap());
         }
         tlv_encode_end(buf, start_pos);
     }
@@ -266,6 +266,30 @@ impl ExtAdminGroupStlv {
     pub(crate) fn get(&self) -> &[u8] {
         &self.0
     }
+
+    // Returns whether any of the groups set in `other` is also set in this
+    // bitmask.
+    pub(crate) fn intersects(&self, other: &ExtAdminGroupStlv) -> bool {
+        self.0.iter().zip(other.0.iter()).any(|(a, b)| a & b != 0)
+    }
+
+    // Returns whether all of the groups set in `other` are also set in this
+    // bitmask.
+    pub(crate) fn contains(&self, other: &ExtAdminGroupStlv) -> bool {
+        other.0.iter().enumerate().all(|(i, b)| {
+            let a = self.0.get(i).copied().unwrap_or(0);
+            a & b == *b
+        })
+    }
+}
+
+impl From<&AdminGroupStlv> for ExtAdminGroupStlv {
+    // RFC 7308 - Section 2.3.1:
+    // "The bits of the AG SHOULD be the same as the first 32 bits of the
+    // EAG".
+    fn from(admin_group: &AdminGroupStlv) -> ExtAdminGroupStlv {
+        ExtAdminGroupStlv(admin_group.0.to_be_bytes().to_vec())
+    }
 }
 
 // ===== impl Ipv4InterfaceAddrStlv =====
@@ -16,7 +16,7 @@ use holo_utils::bier::{BierEncapId, BiftId};
 use holo_utils::bytes::{BytesExt, BytesMutExt};
 use holo_utils::mpls::Label;
 use holo_utils::sr::{IgpAlgoType, Sid};
-use num_traits::FromPrimitive;
+use num_traits::{FromPrimitive, ToPrimitive};
 use serde::{Deserialize, Serialize};
 
 use crate::packet::error::{TlvDecodeError, TlvDecodeResult};
@@ -242,7 +242,7 @@ impl PrefixSidStlv {
     pub(crate) fn encode(&self, buf: &mut BytesMut) {
         let start_pos = tlv_encode_start(buf, PrefixStlvType::PrefixSid);
         buf.put_u8(self.flags.bits());
-        buf.put_u8(self.algo as u8);
+        buf.put_u8(self.algo.to_u8().unwrap());
         match self.sid {
             Sid::Index(index) => buf.put_u32(index),
             Sid::Label(label) => buf.put_u24(label.get()),
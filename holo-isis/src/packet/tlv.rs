@@ -30,8 +30,8 @@ use tracing::debug_span;
 
 use crate::packet::error::{TlvDecodeError, TlvDecodeResult};
 use crate::packet::iana::{
-    AuthenticationType, MtCapStlvType, NeighborStlvType, Nlpid, PrefixStlvType,
-    RouterCapStlvType, TlvType,
+    AslaSabmFlags, AuthenticationType, MtCapStlvType, NeighborStlvType, Nlpid,
+    PrefixStlvType, RouterCapStlvType, SidLabelBindingStlvType, TlvType,
 };
 #[cfg(feature = "testing")]
 use crate::packet::pdu::serde_lsp_rem_lifetime_filter;
@@ -53,6 +53,8 @@ pub const TLV_HDR_SIZE: usize = 2;
 pub const TLV_MAX_LEN: usize = 255;
 // Maximum narrow metric.
 pub const MAX_NARROW_METRIC: u32 = 63;
+// Maximum wide link metric that doesn't exclude the link from SPF (RFC 5305).
+pub const MAX_WIDE_LINK_METRIC: u32 = 0xFFFFFE;
 // Multi-Topology flags mask.
 pub const MT_FLAGS_MASK: u16 = 0xf000;
 // Multi-Topology ID mask.
@@ -480,6 +482,65 @@ pub struct MtCapStlvs {
     pub unknown: Vec<UnknownTlv>,
 }
 
+#[derive(Clone, Debug, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub struct SidLabelBindingTlv {
+    pub flags: SidLabelBindingFlags,
+    pub range: u16,
+    pub prefix: IpNetwork,
+    pub sub_tlvs: SidLabelBindingStlvs,
+}
+
+bitflags! {
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[derive(Deserialize, Serialize)]
+    #[serde(transparent)]
+    pub struct SidLabelBindingFlags: u8 {
+        const F = 0x80;
+        const M = 0x40;
+        const S = 0x20;
+        const D = 0x10;
+        const A = 0x08;
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+#[serde_with::apply(
+    BTreeMap => #[serde(default, skip_serializing_if = "BTreeMap::is_empty")],
+    Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")],
+)]
+#[derive(Deserialize, Serialize)]
+pub struct SidLabelBindingStlvs {
+    pub prefix_sids: BTreeMap<IgpAlgoType, PrefixSidStlv>,
+    pub unknown: Vec<UnknownTlv>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub struct AppSpecificSrlgTlv {
+    pub neighbor: LanId,
+    pub l_flag: bool,
+    pub sabm_length: u8,
+    pub sabm: AslaSabmFlags,
+    pub udabm_length: u8,
+    pub udabm: u64,
+    pub sub_tlvs: AppSpecificSrlgStlvs,
+    pub srlgs: Vec<u32>,
+}
+
+// Link Identifier Sub-TLVs of the Application-Specific SRLG TLV.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[serde_with::apply(
+    Option => #[serde(default, skip_serializing_if = "Option::is_none")],
+    Vec => #[serde(default, skip_serializing_if = "Vec::is_empty")],
+)]
+#[derive(Deserialize, Serialize)]
+pub struct AppSpecificSrlgStlvs {
+    pub ipv4_interface_addr: Option<subtlvs::neighbor::Ipv4InterfaceAddrStlv>,
+    pub ipv4_neighbor_addr: Option<subtlvs::neighbor::Ipv4NeighborAddrStlv>,
+    pub unknown: Vec<UnknownTlv>,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 #[derive(new)]
 #[derive(Deserialize, Serialize)]
@@ -2817,6 +2878,299 @@ impl Tlv for MtCapabilityTlv {
     }
 }
 
+// ===== impl SidLabelBindingTlv =====
+
+impl SidLabelBindingTlv {
+    const MIN_SIZE: usize = 4;
+
+    pub(crate) fn decode(
+        tlv_len: u8,
+        buf: &mut Bytes,
+    ) -> TlvDecodeResult<Self> {
+        // Validate the TLV length.
+        if (tlv_len as usize) < Self::MIN_SIZE {
+            return Err(TlvDecodeError::InvalidLength(tlv_len));
+        }
+
+        // Parse fixed fields.
+        let flags = buf.try_get_u8()?;
+        let flags = SidLabelBindingFlags::from_bits_truncate(flags);
+        let range = buf.try_get_u16()?;
+        let plen = buf.try_get_u8()?;
+
+        // Parse prefix (variable length).
+        let plen_wire = prefix_wire_len(plen);
+        let prefix = if flags.contains(SidLabelBindingFlags::F) {
+            if plen > Ipv6Network::MAX_PREFIXLEN {
+                return Err(TlvDecodeError::InvalidPrefixLength(plen));
+            }
+            let mut prefix_bytes = [0; Ipv6Addr::LENGTH];
+            buf.try_copy_to_slice(&mut prefix_bytes[..plen_wire])?;
+            Ipv6Network::new(Ipv6Addr::from(prefix_bytes), plen)
+                .map(IpNetwork::V6)
+        } else {
+            if plen > Ipv4Network::MAX_PREFIXLEN {
+                return Err(TlvDecodeError::InvalidPrefixLength(plen));
+            }
+            let mut prefix_bytes = [0; Ipv4Addr::LENGTH];
+            buf.try_copy_to_slice(&mut prefix_bytes[..plen_wire])?;
+            Ipv4Network::new(Ipv4Addr::from(prefix_bytes), plen)
+                .map(IpNetwork::V4)
+        }
+        .map_err(|_| TlvDecodeError::InvalidPrefixLength(plen))?;
+
+        // Parse Sub-TLVs.
+        let mut sub_tlvs = SidLabelBindingStlvs::default();
+        while buf.remaining() >= TLV_HDR_SIZE {
+            // Parse TLV type.
+            let stlv_type = buf.try_get_u8()?;
+            let stlv_etype = SidLabelBindingStlvType::from_u8(stlv_type);
+
+            // Parse and validate TLV length.
+            let stlv_len = buf.try_get_u8()?;
+            if stlv_len as usize > buf.remaining() {
+                return Err(TlvDecodeError::InvalidLength(stlv_len));
+            }
+
+            // Parse Sub-TLV value.
+            let span =
+                debug_span!("sub-TLV", r#type = stlv_type, length = stlv_len);
+            let _span_guard = span.enter();
+            let mut buf_stlv = buf.copy_to_bytes(stlv_len as usize);
+            match stlv_etype {
+                Some(SidLabelBindingStlvType::PrefixSid) => {
+                    match PrefixSidStlv::decode(stlv_len, &mut buf_stlv) {
+                        Ok(stlv) => {
+                            if let Some(stlv) = stlv {
+                                sub_tlvs.prefix_sids.insert(stlv.algo, stlv);
+                            }
+                        }
+                        Err(error) => error.log(),
+                    }
+                }
+                _ => {
+                    // Save unknown Sub-TLV.
+                    sub_tlvs
+                        .unknown
+                        .push(UnknownTlv::new(stlv_type, stlv_len, buf_stlv));
+                }
+            }
+        }
+
+        Ok(SidLabelBindingTlv {
+            flags,
+            range,
+            prefix,
+            sub_tlvs,
+        })
+    }
+
+    pub(crate) fn encode(&self, buf: &mut BytesMut) {
+        let start_pos = tlv_encode_start(buf, TlvType::SidLabelBinding);
+        buf.put_u8(self.flags.bits());
+        buf.put_u16(self.range);
+
+        // Encode prefix (variable length).
+        let plen = self.prefix.prefix();
+        let plen_wire = prefix_wire_len(plen);
+        buf.put_u8(plen);
+        match self.prefix {
+            IpNetwork::V4(prefix) => {
+                buf.put(&prefix.ip().octets()[0..plen_wire]);
+            }
+            IpNetwork::V6(prefix) => {
+                buf.put(&prefix.ip().octets()[0..plen_wire]);
+            }
+        }
+
+        // Encode Sub-TLVs.
+        for stlv in self.sub_tlvs.prefix_sids.values() {
+            stlv.encode(buf);
+        }
+        tlv_encode_end(buf, start_pos);
+    }
+}
+
+impl Tlv for SidLabelBindingTlv {
+    fn len(&self) -> usize {
+        TLV_HDR_SIZE
+            + Self::MIN_SIZE
+            + prefix_wire_len(self.prefix.prefix())
+            + self
+                .sub_tlvs
+                .prefix_sids
+                .values()
+                .map(|stlv| stlv.len())
+                .sum::<usize>()
+    }
+}
+
+// ===== impl AppSpecificSrlgTlv =====
+
+impl AppSpecificSrlgTlv {
+    const MIN_SIZE: usize = 10;
+    const MAX_MASK_LEN: u8 = 8;
+    const FLAG_MASK: u8 = 0x80;
+    const LENGTH_MASK: u8 = 0x7F;
+    const LINK_ID_STLV_SIZE: usize = 6;
+    const SRLG_SIZE: usize = 4;
+
+    pub(crate) fn decode(
+        tlv_len: u8,
+        buf: &mut Bytes,
+    ) -> TlvDecodeResult<Option<Self>> {
+        // Validate the TLV length.
+        if (tlv_len as usize) < Self::MIN_SIZE {
+            return Err(TlvDecodeError::InvalidLength(tlv_len));
+        }
+
+        // Parse neighbor System-ID and pseudonode number.
+        let neighbor = LanId::decode(buf)?;
+
+        // Parse Application Identifier Bit Mask.
+        let byte0 = buf.try_get_u8()?;
+        let l_flag = (byte0 & Self::FLAG_MASK) != 0;
+        let sabm_length = byte0 & Self::LENGTH_MASK;
+        let byte1 = buf.try_get_u8()?;
+        let udabm_length = byte1 & Self::LENGTH_MASK;
+
+        // Per RFC 9479, ignore the entire TLV if either mask length > 8.
+        if sabm_length > Self::MAX_MASK_LEN || udabm_length > Self::MAX_MASK_LEN
+        {
+            return Ok(None);
+        }
+        let mut sabm = 0u64;
+        for i in 0..sabm_length as usize {
+            sabm |= (buf.try_get_u8()? as u64) << (56 - i * 8);
+        }
+        let sabm = AslaSabmFlags::from_bits_truncate(sabm);
+        let mut udabm = 0u64;
+        for i in 0..udabm_length as usize {
+            udabm |= (buf.try_get_u8()? as u64) << (56 - i * 8);
+        }
+
+        // Parse Link Identifier Sub-TLVs.
+        let stlvs_len = buf.try_get_u8()?;
+        if stlvs_len as usize > buf.remaining() {
+            return Err(TlvDecodeError::InvalidLength(stlvs_len));
+        }
+        let mut buf_stlvs = buf.copy_to_bytes(stlvs_len as usize);
+        let mut sub_tlvs = AppSpecificSrlgStlvs::default();
+        while buf_stlvs.remaining() >= TLV_HDR_SIZE {
+            // Parse TLV type.
+            let stlv_type = buf_stlvs.try_get_u8()?;
+            let stlv_etype = NeighborStlvType::from_u8(stlv_type);
+
+            // Parse and validate TLV length.
+            let stlv_len = buf_stlvs.try_get_u8()?;
+            if stlv_len as usize > buf_stlvs.remaining() {
+                return Err(TlvDecodeError::InvalidLength(stlv_len));
+            }
+
+            // Parse Sub-TLV value.
+            let span =
+                debug_span!("sub-TLV", r#type = stlv_type, length = stlv_len);
+            let _span_guard = span.enter();
+            let mut buf_stlv = buf_stlvs.copy_to_bytes(stlv_len as usize);
+            match stlv_etype {
+                Some(NeighborStlvType::Ipv4InterfaceAddress) => {
+                    match subtlvs::neighbor::Ipv4InterfaceAddrStlv::decode(
+                        stlv_len,
+                        &mut buf_stlv,
+                    ) {
+                        Ok(stlv) => sub_tlvs.ipv4_interface_addr = Some(stlv),
+                        Err(error) => error.log(),
+                    }
+                }
+                Some(NeighborStlvType::Ipv4NeighborAddress) => {
+                    match subtlvs::neighbor::Ipv4NeighborAddrStlv::decode(
+                        stlv_len,
+                        &mut buf_stlv,
+                    ) {
+                        Ok(stlv) => sub_tlvs.ipv4_neighbor_addr = Some(stlv),
+                        Err(error) => error.log(),
+                    }
+                }
+                _ => {
+                    // Save unknown Sub-TLV.
+                    sub_tlvs
+                        .unknown
+                        .push(UnknownTlv::new(stlv_type, stlv_len, buf_stlv));
+                }
+            }
+        }
+
+        // Parse SRLG values.
+        if !buf.remaining().is_multiple_of(Self::SRLG_SIZE) {
+            return Err(TlvDecodeError::InvalidLength(tlv_len));
+        }
+        let mut srlgs = vec![];
+        while buf.remaining() >= Self::SRLG_SIZE {
+            srlgs.push(buf.try_get_u32()?);
+        }
+
+        Ok(Some(AppSpecificSrlgTlv {
+            neighbor,
+            l_flag,
+            sabm_length,
+            sabm,
+            udabm_length,
+            udabm,
+            sub_tlvs,
+            srlgs,
+        }))
+    }
+
+    pub(crate) fn encode(&self, buf: &mut BytesMut) {
+        let start_pos = tlv_encode_start(buf, TlvType::AppSpecificSrlg);
+        self.neighbor.encode(buf);
+
+        // Encode Application Identifier Bit Mask.
+        let byte0 = if self.l_flag { Self::FLAG_MASK } else { 0 }
+            | (self.sabm_length & Self::LENGTH_MASK);
+        buf.put_u8(byte0);
+        buf.put_u8(self.udabm_length & Self::LENGTH_MASK);
+        for i in 0..self.sabm_length as usize {
+            buf.put_u8((self.sabm.bits() >> (56 - i * 8)) as u8);
+        }
+        for i in 0..self.udabm_length as usize {
+            buf.put_u8((self.udabm >> (56 - i * 8)) as u8);
+        }
+
+        // Encode Link Identifier Sub-TLVs.
+        let subtlvs_len_pos = buf.len();
+        buf.put_u8(0);
+        if let Some(stlv) = &self.sub_tlvs.ipv4_interface_addr {
+            stlv.encode(buf);
+        }
+        if let Some(stlv) = &self.sub_tlvs.ipv4_neighbor_addr {
+            stlv.encode(buf);
+        }
+        // Rewrite Sub-TLVs length field.
+        buf[subtlvs_len_pos] = (buf.len() - 1 - subtlvs_len_pos) as u8;
+
+        // Encode SRLG values.
+        for srlg in &self.srlgs {
+            buf.put_u32(*srlg);
+        }
+        tlv_encode_end(buf, start_pos);
+    }
+}
+
+impl Tlv for AppSpecificSrlgTlv {
+    fn len(&self) -> usize {
+        TLV_HDR_SIZE
+            + Self::MIN_SIZE
+            + self.sabm_length as usize
+            + self.udabm_length as usize
+            + self.sub_tlvs.ipv4_interface_addr.iter().count()
+                * Self::LINK_ID_STLV_SIZE
+            + self.sub_tlvs.ipv4_neighbor_addr.iter().count()
+                * Self::LINK_ID_STLV_SIZE
+            + self.srlgs.len() * Self::SRLG_SIZE
+    }
+}
+
 // ===== blanket implementations =====
 
 impl<T: EntryBasedTlv> Tlv for T {
@@ -42,6 +42,7 @@ bitflags! {
         const CONNECTED = 0x01;
         const INSTALLED = 0x02;
         const SUMMARY = 0x04;
+        const SR_MAPPING = 0x08;
     }
 }
 
@@ -248,6 +249,54 @@ pub(crate) fn update_rib(
     *instance.state.rib_mut(instance.config.level_type) = new_rib;
 }
 
+// Updates the Flexible Algorithm routing tables for the specified level, and
+// their Prefix-SID label entries.
+//
+// For L1/L2 routers, the L1 and L2 routes are merged, preferring L1 routes.
+pub(crate) fn update_flex_algo_rib(
+    level: LevelNumber,
+    new_ribs: BTreeMap<u8, BTreeMap<IpNetwork, Route>>,
+    instance: &mut InstanceUpView<'_>,
+    interfaces: &Interfaces,
+) {
+    // Store the new local RIBs for the current level.
+    *instance.state.flex_algo_rib_single.get_mut(level) = new_ribs;
+
+    // Merge L1 and L2 local RIBs.
+    let ribs_l1 = instance.state.flex_algo_rib_single.get(LevelNumber::L1);
+    let ribs_l2 = instance.state.flex_algo_rib_single.get(LevelNumber::L2);
+    let mut flex_algo_rib: BTreeMap<u8, BTreeMap<IpNetwork, Route>> =
+        BTreeMap::new();
+    for (algo, rib) in ribs_l2.iter().chain(ribs_l1.iter()) {
+        flex_algo_rib
+            .entry(*algo)
+            .or_default()
+            .extend(rib.iter().map(|(prefix, route)| (*prefix, route.clone())));
+    }
+
+    // Update Prefix-SID label entries.
+    let mut old_flex_algo_rib =
+        std::mem::take(&mut instance.state.flex_algo_rib);
+    for (algo, rib) in &mut flex_algo_rib {
+        let old_rib = old_flex_algo_rib.remove(algo).unwrap_or_default();
+        update_flex_algo_labels(rib, old_rib, instance, interfaces);
+    }
+
+    // Uninstall the label entries of algorithms that are no longer
+    // available.
+    for old_rib in old_flex_algo_rib.into_values() {
+        update_flex_algo_labels(
+            &mut BTreeMap::new(),
+            old_rib,
+            instance,
+            interfaces,
+        );
+    }
+
+    // Store the new combined RIBs.
+    instance.state.flex_algo_rib = flex_algo_rib;
+}
+
 // ===== helper functions =====
 
 // Updates IS-IS routes in the global RIB.
@@ -310,3 +359,58 @@ fn update_global_rib(
         ibus::tx::route_uninstall(&instance.tx.ibus, &dest, &route);
     }
 }
+
+// Updates the Prefix-SID label entries of a Flexible Algorithm.
+fn update_flex_algo_labels(
+    rib: &mut BTreeMap<IpNetwork, Route>,
+    mut old_rib: BTreeMap<IpNetwork, Route>,
+    instance: &InstanceUpView<'_>,
+    interfaces: &Interfaces,
+) {
+    // Install new label entries or label entries that have changed.
+    for (prefix, route) in rib {
+        let mut old_sr_label = None;
+
+        // Remove route from the old RIB if it's present.
+        if let Some(old_route) = old_rib.remove(prefix)
+            && old_route.flags.contains(RouteFlags::INSTALLED)
+        {
+            old_sr_label = old_route.sr_label;
+
+            // Skip reinstalling the label entry if it hasn't changed.
+            if old_route.sr_label == route.sr_label
+                && old_route.nexthops == route.nexthops
+            {
+                route.flags.insert(RouteFlags::INSTALLED);
+                continue;
+            }
+        }
+
+        if !route.flags.contains(RouteFlags::CONNECTED)
+            && !route.nexthops.is_empty()
+            && route.sr_label.is_some()
+        {
+            ibus::tx::flex_algo_label_install(
+                &instance.tx.ibus,
+                route,
+                old_sr_label,
+                interfaces,
+            );
+            route.flags.insert(RouteFlags::INSTALLED);
+        } else if let Some(old_sr_label) = old_sr_label {
+            ibus::tx::flex_algo_label_uninstall(
+                &instance.tx.ibus,
+                old_sr_label,
+            );
+        }
+    }
+
+    // Uninstall label entries that are no longer available.
+    for label in old_rib
+        .into_values()
+        .filter(|route| route.flags.contains(RouteFlags::INSTALLED))
+        .filter_map(|route| route.sr_label)
+    {
+        ibus::tx::flex_algo_label_uninstall(&instance.tx.ibus, label);
+    }
+}
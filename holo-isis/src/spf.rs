@@ -25,6 +25,7 @@ use crate::adjacency::{Adjacency, AdjacencyState};
 use crate::collections::{Arena, InterfaceIndex, Interfaces, Lsdb};
 use crate::debug::Debug;
 use crate::error::Error;
+use crate::flex_algo::FlexAlgo;
 use crate::instance::{InstanceArenas, InstanceUpView};
 use crate::interface::InterfaceType;
 use crate::lsdb::{LspEntry, LspLogId};
@@ -32,9 +33,9 @@ use crate::northbound::configuration::MetricType;
 use crate::packet::iana::{FloodingAlgo, MtId, Nlpid};
 use crate::packet::pdu::Lsp;
 use crate::packet::subtlvs::prefix::{PrefixAttrFlags, PrefixSidStlv};
-use crate::packet::tlv::IpReachTlvEntry;
+use crate::packet::tlv::{IpReachTlvEntry, IsReachStlvs};
 use crate::packet::{LanId, LevelNumber, LevelType, LspId, SystemId};
-use crate::route::Route;
+use crate::route::{Route, RouteFlags};
 use crate::{flooding, route, sr, tasks};
 
 // Maximum size of the SPF log record.
@@ -119,6 +120,8 @@ pub struct VertexNexthop {
 pub struct VertexEdge {
     pub id: VertexId,
     pub cost: u32,
+    // Advertised link metric.
+    pub metric: u32,
 }
 
 // Represents an IP reachability entry attached to a vertex.
@@ -530,6 +533,7 @@ pub(crate) fn compute_spt(
     local: bool,
     mt_id: Option<MtId>,
     metric_mode: MetricMode,
+    flex_algo: Option<&FlexAlgo>,
     instance: &InstanceUpView<'_>,
     interfaces: &Interfaces,
     adjacencies: &Arena<Adjacency>,
@@ -609,6 +613,7 @@ pub(crate) fn compute_spt(
             mt_id,
             metric_mode,
             metric_type,
+            flex_algo,
             lsdb,
             lsp_entries,
         ) {
@@ -618,6 +623,7 @@ pub(crate) fn compute_spt(
                 mt_id,
                 metric_mode,
                 metric_type,
+                None,
                 lsdb,
                 lsp_entries,
             )
@@ -626,6 +632,14 @@ pub(crate) fn compute_spt(
                 continue;
             }
 
+            // Prune routers that don't participate in the Flexible Algorithm.
+            if let Some(flex_algo) = flex_algo
+                && !link.id.lan_id.is_pseudonode()
+                && !flex_algo.participants.contains(&link.id.lan_id.system_id)
+            {
+                continue;
+            }
+
             // Check if the link's vertex is already on the shortest-path tree.
             if spt.contains(&link.id) {
                 continue;
@@ -692,6 +706,7 @@ pub(crate) fn compute_spt(
                             mt_id,
                             vertex,
                             &link,
+                            flex_algo.is_some(),
                             &mut used_adjs,
                             interfaces,
                             adjacencies,
@@ -751,6 +766,7 @@ fn compute_spf(
                     true,
                     Some(mt_id),
                     MetricMode::Normal,
+                    None,
                     instance,
                     interfaces,
                     adjacencies,
@@ -778,6 +794,14 @@ fn compute_spf(
         }
     }
 
+    // Collect the SID mappings advertised by SR Mapping Servers.
+    let sr_mappings =
+        if instance.config.sr.enabled && instance.config.sr.ms_receive {
+            sr::mapping_server_entries(instance, level, lsp_entries)
+        } else {
+            vec![]
+        };
+
     // Compute the new RIB for the current level.
     //
     // Since multiple topologies per address family aren't currently supported,
@@ -788,10 +812,13 @@ fn compute_spf(
             compute_routes(
                 level,
                 mt_id,
+                IgpAlgoType::Spf,
+                instance.state.spt.get(mt_id).get(level),
                 instance,
                 interfaces,
                 adjacencies,
                 lsp_entries,
+                &sr_mappings,
                 &mut new_rib,
             );
         }
@@ -800,6 +827,26 @@ fn compute_spf(
     // Update the local RIB and global RIB.
     route::update_rib(level, new_rib, instance, interfaces);
 
+    // Compute the Flexible Algorithm routes for the current level and update
+    // their Prefix-SID label entries.
+    let new_flex_algo_ribs = if instance.config.sr.enabled {
+        compute_flex_algo_routes(
+            level,
+            instance,
+            interfaces,
+            adjacencies,
+            lsp_entries,
+        )
+    } else {
+        BTreeMap::new()
+    };
+    route::update_flex_algo_rib(
+        level,
+        new_flex_algo_ribs,
+        instance,
+        interfaces,
+    );
+
     // If this is an L1 LSP in an L1/L2 router, schedule LSP reorigination at L2
     // to propagate updates. This happens only after SPF, as the SPT tree is
     // needed to compute distances to L1 routers.
@@ -840,10 +887,13 @@ fn compute_spf(
 fn compute_routes(
     level: LevelNumber,
     mt_id: MtId,
+    algo: IgpAlgoType,
+    spt: &Spt,
     instance: &InstanceUpView<'_>,
     interfaces: &Interfaces,
     adjacencies: &Arena<Adjacency>,
     lsp_entries: &Arena<LspEntry>,
+    sr_mappings: &[sr::SidMapping],
     rib: &mut BTreeMap<IpNetwork, Route>,
 ) {
     let lsdb = instance.state.lsdb.get(level);
@@ -861,7 +911,7 @@ fn compute_routes(
             }
             MtId::Ipv6Unicast => true,
         };
-    for vertex in instance.state.spt.get(mt_id).get(level).iter() {
+    for vertex in spt.iter() {
         // Skip if the zeroth LSP is missing.
         let Some(zeroth_lsp) = zeroth_lsp(vertex.id.lan_id, lsdb, lsp_entries)
         else {
@@ -879,6 +929,7 @@ fn compute_routes(
             instance.config.level_type,
             level,
             mt_id,
+            algo,
             vertex,
             att_bit,
             is_l2_attached_to_backbone,
@@ -888,6 +939,12 @@ fn compute_routes(
             lsdb,
             lsp_entries,
         ) {
+            // Flexible Algorithm routes are computed only for prefixes that
+            // have a Prefix-SID advertised for the algorithm.
+            if algo != IgpAlgoType::Spf && network.prefix_sid.is_none() {
+                continue;
+            }
+
             let route = match rib.entry(network.prefix) {
                 btree_map::Entry::Vacant(v) => {
                     // If the route does not exist, create a new entry.
@@ -928,6 +985,17 @@ fn compute_routes(
                     .collect();
             }
 
+            // Fall back to the SID mappings advertised by SR Mapping Servers
+            // when the prefix originator didn't advertise a Prefix-SID.
+            if instance.config.sr.enabled
+                && route.prefix_sid.is_none()
+                && let Some(prefix_sid) =
+                    sr::mapping_server_lookup(sr_mappings, &network.prefix)
+            {
+                route.prefix_sid = Some(prefix_sid);
+                route.flags.insert(RouteFlags::SR_MAPPING);
+            }
+
             // Update route's Prefix-SID (if any).
             if instance.config.sr.enabled && route.prefix_sid.is_some() {
                 let af = network.prefix.address_family();
@@ -948,6 +1016,69 @@ fn compute_routes(
     }
 }
 
+// Computes the routing tables of the Flexible Algorithms this router
+// participates in.
+//
+// Since IP forwarding always follows the default algorithm, Flexible Algorithm
+// routes are installed in the form of Prefix-SID label entries only.
+fn compute_flex_algo_routes(
+    level: LevelNumber,
+    instance: &InstanceUpView<'_>,
+    interfaces: &Interfaces,
+    adjacencies: &Arena<Adjacency>,
+    lsp_entries: &Arena<LspEntry>,
+) -> BTreeMap<u8, BTreeMap<IpNetwork, Route>> {
+    let root_system_id = instance.config.system_id.unwrap();
+    let mut ribs = BTreeMap::new();
+
+    for algo in instance.config.flex_algo.keys() {
+        let mut rib = BTreeMap::new();
+
+        // Select the Flexible Algorithm Definition in effect for this level.
+        if let Some(flex_algo) =
+            FlexAlgo::compute(*algo, level, instance, lsp_entries)
+        {
+            for mt_id in [MtId::Standard, MtId::Ipv6Unicast] {
+                if !instance.config.is_topology_enabled(mt_id) {
+                    continue;
+                }
+
+                // Compute the Flexible Algorithm shortest-path tree.
+                let spt = compute_spt(
+                    level,
+                    root_system_id,
+                    true,
+                    Some(mt_id),
+                    MetricMode::Normal,
+                    Some(&flex_algo),
+                    instance,
+                    interfaces,
+                    adjacencies,
+                    lsp_entries,
+                );
+
+                // Compute the Flexible Algorithm routes.
+                compute_routes(
+                    level,
+                    mt_id,
+                    IgpAlgoType::FlexAlgo(*algo),
+                    &spt,
+                    instance,
+                    interfaces,
+                    adjacencies,
+                    lsp_entries,
+                    &[],
+                    &mut rib,
+                );
+            }
+        }
+
+        ribs.insert(*algo, rib);
+    }
+
+    ribs
+}
+
 // Resolves the outgoing interface and IP addresses of the next-hop used to
 // reach a vertex via the specified edge.
 //
@@ -959,6 +1090,7 @@ fn resolve_nexthop(
     mt_id: MtId,
     vertex: &Vertex,
     link: &VertexEdge,
+    flex_algo: bool,
     used_adjs: &mut BTreeSet<MacAddr>,
     interfaces: &Interfaces,
     adjacencies: &Arena<Adjacency>,
@@ -985,7 +1117,12 @@ fn resolve_nexthop(
                     .filter(|adj| adj.topologies.contains(&mt_id))
                     .filter(|adj| adj.state == AdjacencyState::Up),
                 InterfaceType::PointToPoint => {
-                    if iface.config.metric.get(level) != link.cost {
+                    // The cost of Flexible Algorithm links is derived from
+                    // the algorithm's metric type, so the advertised metric
+                    // is checked instead.
+                    let metric =
+                        if flex_algo { link.metric } else { link.cost };
+                    if iface.config.metric.get(level) != metric {
                         return None;
                     }
                     iface
@@ -1015,6 +1152,7 @@ fn vertex_edges<'a>(
     mt_id: Option<MtId>,
     metric_mode: MetricMode,
     metric_type: MetricType,
+    flex_algo: Option<&'a FlexAlgo>,
     lsdb: &'a Lsdb,
     lsp_entries: &'a Arena<LspEntry>,
 ) -> impl Iterator<Item = VertexEdge> + 'a {
@@ -1032,16 +1170,22 @@ fn vertex_edges<'a>(
             if mt_id.is_none_or(|mt_id| mt_id == MtId::Standard)
                 && metric_type.is_standard_enabled()
             {
-                let iter = lsp.tlvs.is_reach().map(move |reach| {
+                let iter = lsp.tlvs.is_reach().filter_map(move |reach| {
                     let cost = vertex_edge_cost(
+                        lsp,
                         &reach.neighbor,
                         reach.metric,
+                        None,
                         metric_mode,
-                    );
-                    VertexEdge {
+                        flex_algo,
+                        lsdb,
+                        lsp_entries,
+                    )?;
+                    Some(VertexEdge {
                         id: VertexId::from(reach.neighbor),
                         cost,
-                    }
+                        metric: reach.metric.into(),
+                    })
                 });
                 standard_iter = Some(iter);
             }
@@ -1057,16 +1201,22 @@ fn vertex_edges<'a>(
                     // this link MUST NOT be considered during the normal SPF
                     // computation".
                     .filter(|reach| reach.metric < MAX_LINK_METRIC_WIDE)
-                    .map(move |reach| {
+                    .filter_map(move |reach| {
                         let cost = vertex_edge_cost(
+                            lsp,
                             &reach.neighbor,
                             reach.metric,
+                            Some(&reach.sub_tlvs),
                             metric_mode,
-                        );
-                        VertexEdge {
+                            flex_algo,
+                            lsdb,
+                            lsp_entries,
+                        )?;
+                        Some(VertexEdge {
                             id: VertexId::from(reach.neighbor),
                             cost,
-                        }
+                            metric: reach.metric,
+                        })
                     });
                 wide_iter = Some(iter);
             }
@@ -1081,16 +1231,22 @@ fn vertex_edges<'a>(
                     // this link MUST NOT be considered during the normal SPF
                     // computation".
                     .filter(|reach| reach.metric < MAX_LINK_METRIC_WIDE)
-                    .map(move |reach| {
+                    .filter_map(move |reach| {
                         let cost = vertex_edge_cost(
+                            lsp,
                             &reach.neighbor,
                             reach.metric,
+                            Some(&reach.sub_tlvs),
                             metric_mode,
-                        );
-                        VertexEdge {
+                            flex_algo,
+                            lsdb,
+                            lsp_entries,
+                        )?;
+                        Some(VertexEdge {
                             id: VertexId::from(reach.neighbor),
                             cost,
-                        }
+                            metric: reach.metric,
+                        })
                     });
                 mt_iter = Some(iter);
             }
@@ -1104,16 +1260,22 @@ fn vertex_edges<'a>(
                     // this link MUST NOT be considered during the normal SPF
                     // computation".
                     .filter(|reach| reach.metric < MAX_LINK_METRIC_WIDE)
-                    .map(move |reach| {
+                    .filter_map(move |reach| {
                         let cost = vertex_edge_cost(
+                            lsp,
                             &reach.neighbor,
                             reach.metric,
+                            Some(&reach.sub_tlvs),
                             metric_mode,
-                        );
-                        VertexEdge {
+                            flex_algo,
+                            lsdb,
+                            lsp_entries,
+                        )?;
+                        Some(VertexEdge {
                             id: VertexId::from(reach.neighbor),
                             cost,
-                        }
+                            metric: reach.metric,
+                        })
                     });
                 mt_all_iter = Some(iter);
             }
@@ -1128,18 +1290,45 @@ fn vertex_edges<'a>(
 }
 
 // Compute cost to the IS reachability entry.
+//
+// Returns `None` if the link must be pruned from the Flexible Algorithm
+// topology.
 fn vertex_edge_cost(
+    lsp: &Lsp,
     neighbor: &LanId,
     metric: impl Into<u32>,
+    sub_tlvs: Option<&IsReachStlvs>,
     metric_mode: MetricMode,
-) -> u32 {
+    flex_algo: Option<&FlexAlgo>,
+    lsdb: &Lsdb,
+    lsp_entries: &Arena<LspEntry>,
+) -> Option<u32> {
     match metric_mode {
-        MetricMode::Normal => metric.into(),
+        MetricMode::Normal => {
+            // Links from pseudonodes don't carry any link attributes, so their
+            // advertised metric (zero) is used regardless of the algorithm.
+            match flex_algo {
+                Some(flex_algo) if !lsp.lsp_id.is_pseudonode() => {
+                    // The Application-Specific SRLG TLVs might be advertised
+                    // in any of the router's LSP fragments.
+                    let lan_id = LanId::from((lsp.lsp_id.system_id, 0));
+                    let app_srlgs = lsdb
+                        .iter_for_lan_id(lsp_entries, lan_id)
+                        .map(|lse| &lse.data)
+                        .filter(|lsp| lsp.seqno != 0)
+                        .filter(|lsp| lsp.rem_lifetime != 0)
+                        .flat_map(|lsp| lsp.tlvs.app_srlg())
+                        .filter(|tlv| tlv.neighbor == *neighbor);
+                    flex_algo.link_metric(metric.into(), sub_tlvs, app_srlgs)
+                }
+                _ => Some(metric.into()),
+            }
+        }
         MetricMode::HopCount => {
             if neighbor.is_pseudonode() {
-                0
+                Some(0)
             } else {
-                1
+                Some(1)
             }
         }
     }
@@ -1150,6 +1339,7 @@ fn vertex_networks<'a>(
     level_type: LevelType,
     level: LevelNumber,
     mt_id: MtId,
+    algo: IgpAlgoType,
     vertex: &Vertex,
     att_bit: bool,
     is_l2_attached_to_backbone: bool,
@@ -1233,7 +1423,7 @@ fn vertex_networks<'a>(
                         // MAX_PATH_METRIC this prefix MUST NOT be considered
                         // during the normal SPF computation".
                         .filter(|reach| reach.metric <= MAX_PATH_METRIC_WIDE)
-                        .map(|reach| {
+                        .map(move |reach| {
                             VertexNetwork {
                                 prefix: reach.prefix.into(),
                                 metric: reach.metric,
@@ -1249,7 +1439,7 @@ fn vertex_networks<'a>(
                                 prefix_sid: reach
                                     .sub_tlvs
                                     .prefix_sids
-                                    .get(&IgpAlgoType::Spf)
+                                    .get(&algo)
                                     .cloned(),
                             }
                         });
@@ -1273,14 +1463,14 @@ fn vertex_networks<'a>(
                     // considered during the normal Shortest Path First (SPF)
                     // computation".
                     .filter(|reach| reach.metric <= MAX_PATH_METRIC_WIDE)
-                    .map(|reach| VertexNetwork {
+                    .map(move |reach| VertexNetwork {
                         prefix: reach.prefix.into(),
                         metric: reach.metric,
                         external: reach.external,
                         prefix_sid: reach
                             .sub_tlvs
                             .prefix_sids
-                            .get(&IgpAlgoType::Spf)
+                            .get(&algo)
                             .cloned(),
                     });
                 ipv6_iter = Some(iter);
@@ -11,7 +11,8 @@ use std::net::IpAddr;
 
 use holo_utils::ip::AddressFamily;
 use holo_utils::mpls::Label;
-use holo_utils::sr::{IgpAlgoType, Sid};
+use holo_utils::sr::{IgpAlgoType, Sid, prefix_range_offset};
+use ipnetwork::IpNetwork;
 
 use crate::adjacency::{Adjacency, AdjacencySid};
 use crate::collections::Arena;
@@ -25,8 +26,18 @@ use crate::packet::subtlvs::capability::{
     LabelBlockEntry, SrCapabilitiesFlags,
 };
 use crate::packet::subtlvs::prefix::{PrefixSidFlags, PrefixSidStlv};
+use crate::packet::tlv::SidLabelBindingFlags;
 use crate::packet::{LanId, LevelNumber, SystemId};
-use crate::route::Route;
+use crate::route::{Route, RouteFlags};
+
+// SID mapping advertised by an SR Mapping Server.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct SidMapping {
+    pub adv_rtr: SystemId,
+    pub prefix: IpNetwork,
+    pub range: u32,
+    pub prefix_sid: PrefixSidStlv,
+}
 
 // ===== global functions =====
 
@@ -48,14 +59,18 @@ pub(crate) fn prefix_sid_update(
     // A router receiving a Prefix-SID from a remote node and with an algorithm
     // value that the remote node has not advertised in the SR-Algorithm TLV
     // MUST ignore the Prefix-SID Sub-TLV.
+    //
+    // This check doesn't apply to SIDs learned from SR Mapping Servers, since
+    // the prefix originator might not support SR at all.
     let lsdb = instance.state.lsdb.get(level);
-    if !lsdb
-        .iter_for_lan_id(lsp_entries, adv_rtr)
-        .map(|lse| &lse.data)
-        .filter(|lsp| lsp.rem_lifetime != 0)
-        .filter(|lsp| lsp.seqno != 0)
-        .filter_map(|lsp| lsp.tlvs.sr_algos())
-        .any(|sr_algos| sr_algos.get().contains(&IgpAlgoType::Spf))
+    if !route.flags.contains(RouteFlags::SR_MAPPING)
+        && !lsdb
+            .iter_for_lan_id(lsp_entries, adv_rtr)
+            .map(|lse| &lse.data)
+            .filter(|lsp| lsp.rem_lifetime != 0)
+            .filter(|lsp| lsp.seqno != 0)
+            .filter_map(|lsp| lsp.tlvs.sr_algos())
+            .any(|sr_algos| sr_algos.get().contains(&prefix_sid.algo))
     {
         return;
     }
@@ -93,6 +108,80 @@ pub(crate) fn prefix_sid_update(
     }
 }
 
+// Returns the SID mappings advertised by SR Mapping Servers in the given level,
+// sorted by order of preference.
+pub(crate) fn mapping_server_entries(
+    instance: &InstanceUpView<'_>,
+    level: LevelNumber,
+    lsp_entries: &Arena<LspEntry>,
+) -> Vec<SidMapping> {
+    let lsdb = instance.state.lsdb.get(level);
+    let mut mappings = lsdb
+        .iter(lsp_entries)
+        .map(|lse| &lse.data)
+        .filter(|lsp| lsp.rem_lifetime != 0)
+        .filter(|lsp| lsp.seqno != 0)
+        .filter(|lsp| lsp.lsp_id.pseudonode == 0)
+        .flat_map(|lsp| {
+            lsp.tlvs.sid_label_binding().filter_map(|tlv| {
+                // Mirror Context mappings aren't supported.
+                if tlv.flags.contains(SidLabelBindingFlags::M) {
+                    return None;
+                }
+                let prefix_sid =
+                    tlv.sub_tlvs.prefix_sids.get(&IgpAlgoType::Spf)?;
+                Some(SidMapping {
+                    adv_rtr: lsp.lsp_id.system_id,
+                    prefix: tlv.prefix,
+                    range: tlv.range.into(),
+                    prefix_sid: *prefix_sid,
+                })
+            })
+        })
+        .collect::<Vec<_>>();
+
+    // Overlapping mappings are resolved by preferring the smallest range, then
+    // the longest prefix, then the smallest starting prefix, then the smallest
+    // starting SID, and finally the lowest advertising System ID.
+    let sid_value = |sid: &Sid| match sid {
+        Sid::Index(index) => *index,
+        Sid::Label(label) => label.get(),
+    };
+    mappings.sort_by(|a, b| {
+        a.range
+            .cmp(&b.range)
+            .then_with(|| b.prefix.prefix().cmp(&a.prefix.prefix()))
+            .then_with(|| a.prefix.cmp(&b.prefix))
+            .then_with(|| {
+                sid_value(&a.prefix_sid.sid).cmp(&sid_value(&b.prefix_sid.sid))
+            })
+            .then_with(|| a.adv_rtr.cmp(&b.adv_rtr))
+    });
+
+    mappings
+}
+
+// Looks up the Prefix-SID mapped to the given prefix by SR Mapping Servers.
+pub(crate) fn mapping_server_lookup(
+    mappings: &[SidMapping],
+    prefix: &IpNetwork,
+) -> Option<PrefixSidStlv> {
+    mappings.iter().find_map(|mapping| {
+        let offset =
+            prefix_range_offset(&mapping.prefix, mapping.range, prefix)?;
+        let sid = match mapping.prefix_sid.sid {
+            Sid::Index(index) => Sid::Index(index.checked_add(offset)?),
+            Sid::Label(label) if offset == 0 => Sid::Label(label),
+            Sid::Label(_) => return None,
+        };
+        Some(PrefixSidStlv::new(
+            mapping.prefix_sid.flags,
+            mapping.prefix_sid.algo,
+            sid,
+        ))
+    })
+}
+
 // Adds SR Adjacency SIDs to the given adjacency.
 pub(crate) fn adj_sids_add(
     instance: &InstanceUpView<'_>,
@@ -48,6 +48,7 @@ use crate::{lsdb, network, spf};
 //            lsp_delete_timer (Nx) -> |              |
 //           lsp_refresh_timer (Nx) -> |              |
 //             spf_delay_timer (Nx) -> |              |
+//      ldp_sync_holddown_timer (Nx) -> |              |
 //                                     |              |
 //                                     +--------------+
 //                              ibus_tx (1x) | ^ (1x) ibus_rx
@@ -94,6 +95,7 @@ pub mod messages {
             LspDelete(LspDeleteMsg),
             LspRefresh(LspRefreshMsg),
             SpfDelayEvent(SpfDelayEventMsg),
+            LdpSyncHolddown(LdpSyncHolddownMsg),
         }
 
         #[derive(Debug)]
@@ -178,6 +180,12 @@ pub mod messages {
             pub level: LevelNumber,
             pub event: spf::fsm::Event,
         }
+
+        #[derive(Debug)]
+        #[derive(Deserialize, Serialize)]
+        pub struct LdpSyncHolddownMsg {
+            pub iface_key: InterfaceKey,
+        }
     }
 
     // Output messages (main task -> child task).
@@ -633,3 +641,29 @@ pub(crate) fn spf_delay_timer(
         TimeoutTask {}
     }
 }
+
+// LDP-IGP synchronization holddown timer task.
+pub(crate) fn ldp_sync_holddown_timer(
+    iface: &Interface,
+    holddown: u16,
+    instance: &InstanceUpView<'_>,
+) -> TimeoutTask {
+    #[cfg(not(feature = "testing"))]
+    {
+        let timeout = Duration::from_secs(holddown.into());
+        let iface_id = iface.id;
+        let ldp_sync_holddownp =
+            instance.tx.protocol_input.ldp_sync_holddown.clone();
+
+        TimeoutTask::new(timeout, move || async move {
+            let msg = messages::input::LdpSyncHolddownMsg {
+                iface_key: iface_id.into(),
+            };
+            let _ = ldp_sync_holddownp.send(msg);
+        })
+    }
+    #[cfg(feature = "testing")]
+    {
+        TimeoutTask {}
+    }
+}
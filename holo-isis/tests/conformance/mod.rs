@@ -678,6 +678,43 @@ async fn nb_config_enabled2() {
     run_test::<Instance>("nb-config-enabled2", "topo2-1", "rt6").await;
 }
 
+// Input:
+//  * Ibus: SR configuration update (SRGB, SRLB and Prefix-SIDs, including an
+//    algorithm 128 Prefix-SID for 3.3.3.3/32)
+// Output: no changes
+//
+// Input:
+//  * Northbound: enable SR and configure Flexible Algorithm 128, excluding
+//    links with admin group 1
+// Output:
+//  * Protocol: send an updated local LSP to all adjacencies
+//  * Northbound:
+//    - add a Router Capability TLV including the SR-Algorithm and FAD sub-TLVs
+//      to the local LSP
+//    - add algorithm 0 and 128 Prefix-SID sub-TLVs to 3.3.3.3/32
+//  * Ibus: install Adj-SIDs for all adjacencies
+//
+// Input:
+//  * Protocol: received updated LSPs from rt4, rt5 and rt6 advertising
+//    algorithm 128 and their algorithm 0 and 128 Prefix-SIDs. rt4 advertises
+//    admin group 1 on its link to rt5
+// Output:
+//  * Protocol: flood the received LSPs to the other adjacencies
+//  * Northbound: the received LSPs are present in the LSDB
+//
+// Input:
+//  * Protocol: SPF delay timer expiration
+// Output:
+//  * Ibus:
+//    - install the algorithm 0 Prefix-SIDs of rt4, rt5 and rt6
+//    - install the algorithm 128 Prefix-SIDs of rt4 and rt6. The rt5 one isn't
+//      installed since the rt4-rt5 link is excluded from the algorithm's
+//      topology
+#[tokio::test]
+async fn nb_config_flex_algo1() {
+    run_test::<Instance>("nb-config-flex-algo1", "topo1-1", "rt3").await;
+}
+
 // Input:
 //  * Northbound: disable the IPv4 address family for the "lo" interface
 // Output:
@@ -33,13 +33,14 @@ use holo_isis::packet::subtlvs::prefix::{
 };
 use holo_isis::packet::subtlvs::spb::{IsidEntry, IsidFlags, SpbmSiStlv};
 use holo_isis::packet::tlv::{
-    AreaAddressesTlv, DynamicHostnameTlv, Ipv4AddressesTlv, Ipv4Reach,
-    Ipv4ReachStlvs, Ipv4ReachTlv, Ipv4RouterIdTlv, Ipv6AddressesTlv, Ipv6Reach,
-    Ipv6ReachStlvs, Ipv6ReachTlv, Ipv6RouterIdTlv, IsReach, IsReachStlvs,
-    IsReachTlv, LegacyIpv4Reach, LegacyIpv4ReachTlv, LegacyIsReach,
-    LegacyIsReachTlv, LspBufferSizeTlv, MtCapStlvs, MtCapabilityTlv, MtFlags,
-    MultiTopologyEntry, MultiTopologyTlv, ProtocolsSupportedTlv,
-    PurgeOriginatorIdTlv, RouterCapFlags, RouterCapStlvs, RouterCapTlv,
+    AppSpecificSrlgStlvs, AppSpecificSrlgTlv, AreaAddressesTlv,
+    DynamicHostnameTlv, Ipv4AddressesTlv, Ipv4Reach, Ipv4ReachStlvs,
+    Ipv4ReachTlv, Ipv4RouterIdTlv, Ipv6AddressesTlv, Ipv6Reach, Ipv6ReachStlvs,
+    Ipv6ReachTlv, Ipv6RouterIdTlv, IsReach, IsReachStlvs, IsReachTlv,
+    LegacyIpv4Reach, LegacyIpv4ReachTlv, LegacyIsReach, LegacyIsReachTlv,
+    LspBufferSizeTlv, MtCapStlvs, MtCapabilityTlv, MtFlags, MultiTopologyEntry,
+    MultiTopologyTlv, ProtocolsSupportedTlv, PurgeOriginatorIdTlv,
+    RouterCapFlags, RouterCapStlvs, RouterCapTlv,
 };
 use holo_isis::packet::{AreaAddr, LanId, LevelNumber, LspId, SystemId};
 use holo_utils::keychain::Key;
@@ -355,6 +356,8 @@ static LSP1: Lazy<(Vec<u8>, Option<&Key>, Pdu)> = Lazy::new(|| {
                 }],
                 mt_ipv6_reach: vec![],
                 ipv6_router_id: Some(Ipv6RouterIdTlv::new(ip6!("2001:db8::1"))),
+                sid_label_binding: vec![],
+                app_srlg: vec![],
                 unknown: vec![],
             },
             None,
@@ -489,6 +492,8 @@ static LSP2: Lazy<(Vec<u8>, Option<&Key>, Pdu)> = Lazy::new(|| {
                 ipv6_reach: vec![],
                 mt_ipv6_reach: vec![],
                 ipv6_router_id: None,
+                sid_label_binding: vec![],
+                app_srlg: vec![],
                 unknown: vec![],
             },
             None,
@@ -570,6 +575,8 @@ static LSP3_HMAC_MD5: Lazy<(Vec<u8>, Option<&Key>, Pdu)> = Lazy::new(|| {
                 ipv6_reach: vec![],
                 mt_ipv6_reach: vec![],
                 ipv6_router_id: None,
+                sid_label_binding: vec![],
+                app_srlg: vec![],
                 unknown: vec![],
             },
             Some(&KEY_HMAC_MD5),
@@ -653,6 +660,8 @@ static LSP3_HMAC_SHA256: Lazy<(Vec<u8>, Option<&Key>, Pdu)> = Lazy::new(|| {
                 ipv6_reach: vec![],
                 mt_ipv6_reach: vec![],
                 ipv6_router_id: None,
+                sid_label_binding: vec![],
+                app_srlg: vec![],
                 unknown: vec![],
             },
             Some(&KEY_HMAC_SHA256),
@@ -779,6 +788,8 @@ static LSP4: Lazy<(Vec<u8>, Option<&Key>, Pdu)> = Lazy::new(|| {
                     ],
                 }],
                 ipv6_router_id: None,
+                sid_label_binding: vec![],
+                app_srlg: vec![],
                 unknown: vec![],
             },
             None,
@@ -834,6 +845,8 @@ static LSP5: Lazy<(Vec<u8>, Option<&Key>, Pdu)> = Lazy::new(|| {
                 ipv6_reach: vec![],
                 mt_ipv6_reach: vec![],
                 ipv6_router_id: None,
+                sid_label_binding: vec![],
+                app_srlg: vec![],
                 unknown: vec![],
             },
             None,
@@ -929,6 +942,76 @@ static LSP6_MT_CAP: Lazy<(Vec<u8>, Option<&Key>, Pdu)> = Lazy::new(|| {
                 ipv6_reach: vec![],
                 mt_ipv6_reach: vec![],
                 ipv6_router_id: None,
+                sid_label_binding: vec![],
+                app_srlg: vec![],
+                unknown: vec![],
+            },
+            None,
+        )),
+    )
+});
+
+static LSP7_APP_SRLG: Lazy<(Vec<u8>, Option<&Key>, Pdu)> = Lazy::new(|| {
+    (
+        vec![
+            0x83, 0x1b, 0x01, 0x00, 0x12, 0x01, 0x00, 0x00, 0x00, 0x3f, 0x04,
+            0x92, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x06, 0x9d, 0x64, 0x01, 0x81, 0x01, 0xcc, 0x01, 0x04, 0x03,
+            0x49, 0x00, 0x00, 0xee, 0x19, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02,
+            0x00, 0x01, 0x00, 0x10, 0x06, 0x06, 0x04, 0x0a, 0x00, 0x01, 0x01,
+            0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x14,
+        ],
+        None,
+        Pdu::Lsp(Lsp::new(
+            LevelNumber::L1,
+            1170,
+            LspId::from([0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00]),
+            0x00000006,
+            LspFlags::IS_TYPE1,
+            LspTlvs {
+                auth: None,
+                protocols_supported: Some(ProtocolsSupportedTlv {
+                    list: vec![0xcc],
+                }),
+                router_cap: vec![],
+                mt_cap: vec![],
+                area_addrs: vec![AreaAddressesTlv {
+                    list: vec![AreaAddr::from([0x49, 0, 0].as_slice())],
+                }],
+                multi_topology: vec![],
+                purge_originator_id: None,
+                hostname: None,
+                lsp_buf_size: None,
+                is_reach: vec![],
+                ext_is_reach: vec![],
+                mt_is_reach: vec![],
+                ipv4_addrs: vec![],
+                ipv4_internal_reach: vec![],
+                ipv4_external_reach: vec![],
+                ext_ipv4_reach: vec![],
+                mt_ipv4_reach: vec![],
+                ipv4_router_id: None,
+                ipv6_addrs: vec![],
+                ipv6_reach: vec![],
+                mt_ipv6_reach: vec![],
+                ipv6_router_id: None,
+                sid_label_binding: vec![],
+                app_srlg: vec![AppSpecificSrlgTlv {
+                    neighbor: LanId::from([0, 0, 0, 0, 0, 2, 0]),
+                    l_flag: false,
+                    sabm_length: 1,
+                    sabm: AslaSabmFlags::X,
+                    udabm_length: 0,
+                    udabm: 0,
+                    sub_tlvs: AppSpecificSrlgStlvs {
+                        ipv4_interface_addr: Some(Ipv4InterfaceAddrStlv::new(
+                            ip4!("10.0.1.1"),
+                        )),
+                        ipv4_neighbor_addr: None,
+                        unknown: vec![],
+                    },
+                    srlgs: vec![10, 20],
+                }],
                 unknown: vec![],
             },
             None,
@@ -1024,6 +1107,18 @@ fn test_decode_lsp6_mt_cap() {
     test_decode_pdu(bytes, lsp, auth);
 }
 
+#[test]
+fn test_encode_lsp7_app_srlg() {
+    let (ref bytes, ref auth, ref lsp) = *LSP7_APP_SRLG;
+    test_encode_pdu(bytes, lsp, auth);
+}
+
+#[test]
+fn test_decode_lsp7_app_srlg() {
+    let (ref bytes, ref auth, ref lsp) = *LSP7_APP_SRLG;
+    test_decode_pdu(bytes, lsp, auth);
+}
+
 #[test]
 fn test_decode_lsp_crypto_auth_short_digest() {
     use bytes::Bytes;
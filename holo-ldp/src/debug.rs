@@ -26,6 +26,7 @@ pub enum Debug<'a> {
     InterfaceDelete(&'a str),
     InterfaceStart(&'a str),
     InterfaceStop(&'a str, InterfaceInactiveReason),
+    InterfaceLdpSyncChange(&'a str, bool),
     TargetedNbrCreate(&'a IpAddr),
     TargetedNbrDelete(&'a IpAddr),
     TargetedNbrStart(&'a IpAddr),
@@ -102,6 +103,12 @@ impl Debug<'_> {
                     debug!(%reason, "{}", self);
                 });
             }
+            Debug::InterfaceLdpSyncChange(name, synced) => {
+                // Parent span(s): ldp-instance
+                debug_span!("interface", %name).in_scope(|| {
+                    debug!(%synced, "{}", self);
+                });
+            }
             Debug::TargetedNbrCreate(addr)
             | Debug::TargetedNbrDelete(addr)
             | Debug::TargetedNbrStart(addr)
@@ -254,6 +261,9 @@ impl std::fmt::Display for Debug<'_> {
             Debug::InterfaceStop(..) => {
                 write!(f, "stopping interface")
             }
+            Debug::InterfaceLdpSyncChange(..) => {
+                write!(f, "LDP-IGP synchronization state change")
+            }
             Debug::TargetedNbrCreate(..) => {
                 write!(f, "targeted neighbor created")
             }
@@ -30,7 +30,7 @@ use crate::packet::messages::hello::{
 };
 use crate::packet::messages::notification::StatusCode;
 use crate::tasks::messages::input::AdjTimeoutMsg;
-use crate::{network, tasks};
+use crate::{interface, network, tasks};
 
 #[derive(Debug)]
 pub struct Adjacency {
@@ -355,4 +355,7 @@ pub(crate) fn adjacency_delete(
         false,
     );
     Neighbors::delete_check(instance, &lsr_id, status_code);
+    if let Some(ifname) = ifname {
+        interface::ldp_sync_update(instance, &ifname);
+    }
 }
@@ -8,7 +8,7 @@ use std::collections::{VecDeque, btree_map};
 use std::net::{IpAddr, Ipv4Addr};
 
 use chrono::Utc;
-use holo_utils::ip::IpNetworkKind;
+use holo_utils::ip::{AddressFamily, IpNetworkKind};
 use holo_utils::mpls::Label;
 use holo_utils::socket::{SocketExt, TTL_MAX, TcpConnInfo, TcpStream};
 use tracing::{Span, debug_span};
@@ -36,7 +36,7 @@ use crate::packet::messages::{
     NotifMsg,
 };
 use crate::packet::{AddressMessageType, LabelMessageType, Message, Pdu};
-use crate::{ibus, network};
+use crate::{ibus, interface, network};
 
 // ===== UDP packet receipt =====
 
@@ -256,7 +256,11 @@ fn process_hello(
             true,
         );
 
+        let ifname = adj.source.ifname.clone();
         instance.state.ipv4.adjacencies.insert(adj);
+        if let Some(ifname) = ifname {
+            interface::ldp_sync_update(instance, &ifname);
+        }
     }
 
     // Find associated neighbor or create a new one.
@@ -572,6 +576,29 @@ fn process_nbr_msg_notification(
         return Err(Error::NbrRcvdError(nbr.lsr_id, status_code));
     }
 
+    //
+    // RFC 5919 - Section 4:
+    // "An LDP speaker that conforms to this specification SHOULD signal
+    // completion of its label advertisements to a peer by means of a
+    // Notification message, if its peer has advertised the Unrecognized
+    // Notification capability during session establishment".
+    //
+    if StatusCode::decode(msg.status.status_code) == Some(StatusCode::EndOfLib)
+        && msg.fec.is_some_and(|fec| {
+            fec.0.contains(&FecElem::Wildcard(FecElemWildcard::Typed(
+                TypedWildcardFecElem::Prefix(AddressFamily::Ipv4),
+            )))
+        })
+        && !nbr.flags.contains(NeighborFlags::END_OF_LIB_RCVD)
+    {
+        nbr.flags.insert(NeighborFlags::END_OF_LIB_RCVD);
+
+        // Update the LDP-IGP synchronization state of the interfaces this
+        // neighbor was discovered on.
+        let lsr_id = nbr.lsr_id;
+        interface::ldp_sync_update_nbr(instance, lsr_id);
+    }
+
     Ok(())
 }
 
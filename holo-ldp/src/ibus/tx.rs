@@ -25,6 +25,18 @@ pub(crate) fn route_redistribute_sub(ibus_tx: &IbusChannelsTx) {
     }
 }
 
+pub(crate) fn ldp_sync_upd(
+    ibus_tx: &IbusChannelsTx,
+    ifname: &str,
+    synced: bool,
+) {
+    ibus_tx.ldp_sync_upd(ifname.to_owned(), synced);
+}
+
+pub(crate) fn ldp_sync_del(ibus_tx: &IbusChannelsTx, ifname: &str) {
+    ibus_tx.ldp_sync_del(ifname.to_owned());
+}
+
 pub(crate) fn label_install(
     ibus_tx: &IbusChannelsTx,
     fec: &FecInner,
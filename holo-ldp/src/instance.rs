@@ -74,6 +74,8 @@ pub struct InstanceState {
     pub neighbors: Neighbors,
     // Known FECs and their associated label mappings.
     pub fecs: BTreeMap<IpNetwork, Fec>,
+    // LDP-IGP synchronization state of the active interfaces.
+    pub ldp_sync: BTreeMap<String, bool>,
     // IPv4 instance state.
     pub ipv4: InstanceIpv4State,
 }
@@ -412,6 +414,7 @@ impl InstanceState {
             router_id,
             neighbors: Default::default(),
             fecs: Default::default(),
+            ldp_sync: Default::default(),
             ipv4: InstanceIpv4State::new(
                 disc_socket,
                 edisc_socket,
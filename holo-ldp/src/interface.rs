@@ -26,7 +26,7 @@ use crate::packet::messages::hello::{
     TlvIpv4TransAddr,
 };
 use crate::packet::messages::notification::StatusCode;
-use crate::{discovery, network, tasks};
+use crate::{discovery, ibus, network, tasks};
 
 #[derive(Debug)]
 pub struct Interface {
@@ -101,6 +101,13 @@ impl Interface {
             .leave_multicast_ipv4(&instance.state.ipv4.disc_socket);
         self.state = None;
 
+        // Withdraw the LDP-IGP synchronization state of the interface. This
+        // is done first so that deleting the adjacencies below doesn't
+        // trigger a redundant synchronization update.
+        if instance.state.ldp_sync.remove(&self.name).is_some() {
+            ibus::tx::ldp_sync_del(&instance.tx.ibus, &self.name);
+        }
+
         // Delete adjacencies.
         let adjacencies = &mut instance.state.ipv4.adjacencies;
         for adj_idx in adjacencies
@@ -122,12 +129,26 @@ impl Interface {
         match self.is_ready() {
             Ok(()) if !self.is_active() => {
                 // Attempt to activate interface.
-                if let Err(error) = self.start(instance.state) {
-                    Error::InterfaceStartError(
-                        self.name.clone(),
-                        Box::new(error),
-                    )
-                    .log();
+                match self.start(instance.state) {
+                    Ok(()) => {
+                        // Advertise the interface as not yet synchronized.
+                        instance
+                            .state
+                            .ldp_sync
+                            .insert(self.name.clone(), false);
+                        ibus::tx::ldp_sync_upd(
+                            &instance.tx.ibus,
+                            &self.name,
+                            false,
+                        );
+                    }
+                    Err(error) => {
+                        Error::InterfaceStartError(
+                            self.name.clone(),
+                            Box::new(error),
+                        )
+                        .log();
+                    }
                 }
             }
             Err(reason) if self.is_active() => {
@@ -295,3 +316,61 @@ impl InterfaceSys {
         IpAddr::from(addr.ip())
     }
 }
+
+// ===== global functions =====
+
+// Updates the LDP-IGP synchronization state of the given interface, notifying
+// the IGPs when it changes.
+//
+// An interface is considered synchronized once the initial label exchange has
+// completed with at least one of the neighbors discovered over it.
+pub(crate) fn ldp_sync_update(instance: &mut InstanceUpView<'_>, ifname: &str) {
+    let Some(synced) = instance.state.ldp_sync.get(ifname).copied() else {
+        return;
+    };
+
+    let new_synced = instance
+        .state
+        .ipv4
+        .adjacencies
+        .iter_by_iface(ifname)
+        .into_iter()
+        .flatten()
+        .any(|adj| {
+            instance
+                .state
+                .neighbors
+                .get_by_lsr_id(&adj.lsr_id)
+                .is_some_and(|(_, nbr)| nbr.is_label_exchange_complete())
+        });
+    if new_synced == synced {
+        return;
+    }
+
+    Debug::InterfaceLdpSyncChange(ifname, new_synced).log();
+    instance
+        .state
+        .ldp_sync
+        .insert(ifname.to_owned(), new_synced);
+    ibus::tx::ldp_sync_upd(&instance.tx.ibus, ifname, new_synced);
+}
+
+// Updates the LDP-IGP synchronization state of the interfaces the given
+// neighbor was discovered on.
+pub(crate) fn ldp_sync_update_nbr(
+    instance: &mut InstanceUpView<'_>,
+    lsr_id: Ipv4Addr,
+) {
+    let ifnames = instance
+        .state
+        .ipv4
+        .adjacencies
+        .iter_by_lsr_id(&lsr_id)
+        .into_iter()
+        .flatten()
+        .filter_map(|adj| adj.source.ifname.clone())
+        .collect::<Vec<_>>();
+    for ifname in ifnames {
+        ldp_sync_update(instance, &ifname);
+    }
+}
@@ -49,7 +49,7 @@ use crate::tasks::messages::input::{
     NbrBackoffTimeoutMsg, NbrKaTimeoutMsg, NbrRxPduMsg, TcpConnectMsg,
 };
 use crate::tasks::messages::output::NbrTxPduMsg;
-use crate::{ibus, tasks};
+use crate::{ibus, interface, tasks};
 
 #[derive(Debug)]
 pub struct Neighbor {
@@ -94,6 +94,7 @@ bitflags! {
         const CAP_DYNAMIC = 0x02;
         const CAP_TYPED_WCARD = 0x04;
         const CAP_UNREC_NOTIF = 0x08;
+        const END_OF_LIB_RCVD = 0x10;
     }
 }
 
@@ -233,6 +234,7 @@ impl Neighbor {
                 .log();
 
                 let old_state = nbr.state;
+                let lsr_id = nbr.lsr_id;
                 nbr.state = new_state;
                 if new_state == fsm::State::Operational
                     || old_state == fsm::State::Operational
@@ -247,6 +249,14 @@ impl Neighbor {
                 if let Some(action) = action {
                     Neighbor::fsm_action(instance, nbr_idx, action);
                 }
+
+                // Update the LDP-IGP synchronization state of the interfaces
+                // this neighbor was discovered on.
+                if (new_state == fsm::State::Operational)
+                    != (old_state == fsm::State::Operational)
+                {
+                    interface::ldp_sync_update_nbr(instance, lsr_id);
+                }
             }
             Err(error) => {
                 error.log();
@@ -520,12 +530,27 @@ impl Neighbor {
         self.uptime = None;
         self.tasks = Default::default();
         self.pdu_txp = None;
+        self.flags.remove(NeighborFlags::END_OF_LIB_RCVD);
     }
 
     pub(crate) fn is_operational(&self) -> bool {
         self.state == fsm::State::Operational
     }
 
+    // Returns whether the initial label exchange with the neighbor has
+    // completed.
+    //
+    // The local label mappings are all sent as soon as the session becomes
+    // operational. When the neighbor supports the Unrecognized Notification
+    // capability, its End-of-LIB notification signals that it has finished
+    // advertising its own label mappings (RFC 5919). Otherwise, there's no
+    // way to tell, so the session becoming operational is all that counts.
+    pub(crate) fn is_label_exchange_complete(&self) -> bool {
+        self.is_operational()
+            && (!self.flags.contains(NeighborFlags::CAP_UNREC_NOTIF)
+                || self.flags.contains(NeighborFlags::END_OF_LIB_RCVD))
+    }
+
     pub(crate) fn kalive_timeout_remaining(&self) -> Option<Duration> {
         self.tasks
             .kalive_timeout
@@ -31,6 +31,13 @@ static TYPEDEFS: &[(&str, TypeSpec)] = &[
             copy_semantics: true,
         },
     ),
+    (
+        "ldp-igp-sync-state",
+        TypeSpec {
+            rust_type: "LdpSyncState",
+            copy_semantics: true,
+        },
+    ),
     (
         "nbr-state-type",
         TypeSpec {
@@ -38,6 +45,13 @@ static TYPEDEFS: &[(&str, TypeSpec)] = &[
             copy_semantics: true,
         },
     ),
+    (
+        "nssa-translator-state-type",
+        TypeSpec {
+            rust_type: "NssaTranslatorState",
+            copy_semantics: true,
+        },
+    ),
     (
         "packet-type",
         TypeSpec {
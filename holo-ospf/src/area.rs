@@ -4,29 +4,36 @@
 // SPDX-License-Identifier: MIT
 //
 
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap, btree_map};
 use std::net::Ipv4Addr;
 
 use chrono::{DateTime, Utc};
 use derive_new::new;
 use holo_utils::ip::IpNetworkKind;
+use holo_utils::task::TimeoutTask;
 
 use crate::collections::{
     AreaId, AreaIndex, Areas, Arena, InterfaceIndex, Interfaces, Lsdb, LsdbId,
 };
 use crate::debug::{Debug, InterfaceInactiveReason, LsaFlushReason};
 use crate::error::Error;
+use crate::flood::FloodingReduction;
 use crate::instance::InstanceUpView;
 use crate::interface::{Interface, VirtualLinkState, ism};
-use crate::lsdb::{LSA_INFINITY, LsaEntry, LsaEntryFlags, LsaOriginateEvent};
+use crate::lfa::LfaTopology;
+use crate::lsdb::{
+    self, LSA_INFINITY, LsaEntry, LsaEntryFlags, LsaOriginateEvent,
+};
 use crate::northbound::configuration::{AreaCfg, RangeCfg};
+use crate::northbound::notification;
 use crate::packet::iana::PacketType;
 use crate::packet::lsa::{LsaKey, LsaRouterFlagsVersion};
 use crate::route::{
-    Nexthops, PathType, RouteNetFlags, RouteRtr, SummaryNet, SummaryNetFlags,
-    SummaryRtr,
+    self, ExternalNet, Nexthops, PathType, RouteNetFlags, RouteRtr, SummaryNet,
+    SummaryNetFlags, SummaryRtr,
 };
 use crate::spf::Vertex;
+use crate::tasks;
 use crate::version::Version;
 
 // Backbone area ID.
@@ -59,13 +66,26 @@ pub struct AreaState<V: Version> {
     pub transit_capability: bool,
     // Shortest-path tree.
     pub spt: BTreeMap<V::VertexId, Vertex<V>>,
+    // Per-algorithm shortest-path trees (Flexible Algorithm).
+    pub flex_algo_spt: BTreeMap<u8, BTreeMap<V::VertexId, Vertex<V>>>,
+    // Topology information used by IP Fast Reroute.
+    pub lfa: Option<LfaTopology<V>>,
+    // Flooding reduction state.
+    pub flooding_reduction: FloodingReduction<V>,
     // Table of all routers in the area.
     pub routers: BTreeMap<Ipv4Addr, RouteRtr<V>>,
     // Table of summaries originated into this area.
     pub net_summaries: BTreeMap<V::IpNetwork, (u32, SummaryNet<V>)>,
     pub rtr_summaries: BTreeMap<Ipv4Addr, (u32, SummaryRtr<V>)>,
+    // Table of NSSA external routes originated into this area.
+    pub nssa_externals: BTreeMap<V::IpNetwork, (u32, ExternalNet<V>)>,
+    // NSSA translator state.
+    pub nssa_translator_state: NssaTranslatorState,
+    // NSSA translator stability interval (RFC 3101 - Section 3.1).
+    pub nssa_translator_stability_timer: Option<TimeoutTask>,
     // Statistics.
     pub spf_run_count: u32,
+    pub nssa_translator_event_count: u32,
     pub discontinuity_time: DateTime<Utc>,
     // OSPF version-specific data.
     pub version: V::State,
@@ -79,6 +99,22 @@ pub enum AreaType {
     Nssa,
 }
 
+// NSSA translator role (RFC 3101 - Section 3.1).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NssaTranslatorRole {
+    Always,
+    Candidate,
+}
+
+// NSSA translator state (RFC 3101 - Section 3.1).
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum NssaTranslatorState {
+    #[default]
+    Disabled,
+    Enabled,
+    Elected,
+}
+
 // OSPF area range.
 #[derive(Debug, Default)]
 pub struct Range {
@@ -192,10 +228,17 @@ where
             lsdb: Default::default(),
             transit_capability: false,
             spt: Default::default(),
+            flex_algo_spt: Default::default(),
+            lfa: None,
+            flooding_reduction: Default::default(),
             routers: Default::default(),
             net_summaries: Default::default(),
             rtr_summaries: Default::default(),
+            nssa_externals: Default::default(),
+            nssa_translator_state: Default::default(),
+            nssa_translator_stability_timer: None,
             spf_run_count: 0,
+            nssa_translator_event_count: 0,
             discontinuity_time: Utc::now(),
             version: Default::default(),
         }
@@ -283,6 +326,125 @@ pub(crate) fn update_summary_lsas<V>(
     }
 }
 
+pub(crate) fn update_nssa<V>(
+    instance: &mut InstanceUpView<'_, V>,
+    areas: &mut Areas<V>,
+    interfaces: &Arena<Interface<V>>,
+    lsa_entries: &Arena<LsaEntry<V>>,
+) where
+    V: Version,
+{
+    // Check ABR status.
+    let is_abr = areas.is_abr(interfaces);
+
+    // Update the NSSA translator state of all areas.
+    let mut translator_change = false;
+    for area in areas.iter_mut() {
+        let mut state = nssa_translator_state(area, is_abr, instance);
+
+        // RFC 3101 - Section 3.1:
+        // "If an elected translator determines its services are no longer
+        // required, it continues to perform its translation duties for the
+        // additional time interval defined by TranslatorStabilityInterval".
+        if state == NssaTranslatorState::Disabled
+            && area.state.nssa_translator_state == NssaTranslatorState::Elected
+            && area.config.area_type == AreaType::Nssa
+            && is_abr
+        {
+            if area.state.nssa_translator_stability_timer.is_none() {
+                let interval = area.config.nssa_translator_stability_interval;
+                let task = tasks::nssa_translator_stability_timer(
+                    area, instance, interval,
+                );
+                area.state.nssa_translator_stability_timer = Some(task);
+            }
+            state = NssaTranslatorState::Elected;
+        } else {
+            area.state.nssa_translator_stability_timer = None;
+        }
+
+        if state != area.state.nssa_translator_state {
+            nssa_translator_state_change(area, state, instance);
+            translator_change = true;
+        }
+    }
+
+    // Compute the Type-7 LSAs that should be translated into Type-5 LSAs.
+    update_translated_routes(instance, areas, lsa_entries);
+
+    // Proceed to originate and/or flush Type-5 and Type-7 LSAs as required.
+    update_externals(instance, areas, interfaces, lsa_entries);
+
+    // Reoriginate Router-LSAs if the Nt-bit has changed.
+    if translator_change {
+        instance
+            .tx
+            .protocol_input
+            .lsa_orig_event(LsaOriginateEvent::NssaTranslatorChange);
+    }
+}
+
+// Originates, reoriginates or flushes the AS-external and NSSA LSAs of this
+// router, covering both redistributed and translated routes.
+pub(crate) fn update_externals<V>(
+    instance: &mut InstanceUpView<'_, V>,
+    areas: &mut Areas<V>,
+    interfaces: &Arena<Interface<V>>,
+    lsa_entries: &Arena<LsaEntry<V>>,
+) where
+    V: Version,
+{
+    // Check ABR status.
+    let is_abr = areas.is_abr(interfaces);
+
+    // Keep track of the Router-LSA E-bit of all areas.
+    let old_e_bits = areas
+        .iter()
+        .map(|area| lsdb::router_lsa_e_bit(area, instance))
+        .collect::<Vec<_>>();
+
+    // Compute redistributed routes.
+    let redistributed = route::redistributed_externals(instance, interfaces);
+
+    // Proceed to originate and/or flush Type-7 LSAs as required.
+    for area_idx in areas.indexes().collect::<Vec<_>>() {
+        update_nssa_lsas(
+            area_idx,
+            is_abr,
+            &redistributed,
+            instance,
+            areas,
+            lsa_entries,
+        );
+    }
+
+    // Proceed to originate and/or flush Type-5 LSAs as required.
+    //
+    // Routers not attached to any regular area (e.g. internal NSSA routers)
+    // advertise redistributed routes through Type-7 LSAs only, as Type-5 LSAs
+    // aren't flooded into stub areas or NSSAs (RFC 3101 - Section 2.3).
+    let as_external = areas.iter().any(|area| {
+        area.config.area_type == AreaType::Normal && area.is_active(interfaces)
+    });
+    let redistributed = if as_external {
+        redistributed
+    } else {
+        Default::default()
+    };
+    update_as_external_lsas(instance, redistributed, lsa_entries);
+
+    // Reoriginate Router-LSAs if the E-bit has changed.
+    let e_bit_change = areas.iter().zip(old_e_bits).any(|(area, old_e_bit)| {
+        lsdb::router_lsa_e_bit(area, instance) != old_e_bit
+    });
+    if e_bit_change {
+        instance
+            .tx
+            .protocol_input
+            .lsa_orig_event(LsaOriginateEvent::AsbrStatusChange);
+    }
+}
+
 // ===== helper functions =====
 
 fn update_virtual_link<V>(
@@ -457,26 +619,32 @@ fn update_net_summary_lsas<V>(
 
     // Save the old table of summary routes.
     let area = &mut areas[area_idx];
-    let mut old_summaries = std::mem::take(&mut area.state.net_summaries);
+    let old_summaries = std::mem::take(&mut area.state.net_summaries);
+
+    // Assign LSA-IDs.
+    let lsa_ids =
+        V::prefix_lsa_ids(new_summaries.iter().map(|(prefix, _)| *prefix));
 
     // (Re)originate the required Summary-LSAs.
     area.state.net_summaries = new_summaries
         .into_iter()
-        .map(|(prefix, new_summary)| {
-            let lsa_id = match old_summaries.remove(&prefix) {
+        .filter_map(|(prefix, new_summary)| {
+            let new_lsa_id = prefix_lsa_id(&lsa_ids, &prefix)?;
+            let lsa_id = match old_summaries.get(&prefix) {
                 Some((old_lsa_id, old_summary)) => {
-                    // Reoriginate summary LSA if the route has changed, reusing
-                    // the previous LSA-ID.
-                    if new_summary != old_summary {
+                    // Reoriginate summary LSA if the route or its LSA-ID has
+                    // changed.
+                    let lsa_id = new_lsa_id.unwrap_or(*old_lsa_id);
+                    if new_summary != *old_summary || lsa_id != *old_lsa_id {
                         V::lsa_orig_inter_area_network(
                             area,
                             instance,
                             prefix,
-                            Some(old_lsa_id),
+                            Some(lsa_id),
                             &new_summary,
                         );
                     }
-                    old_lsa_id
+                    lsa_id
                 }
                 None => {
                     // Originate new summary LSA.
@@ -484,20 +652,22 @@ fn update_net_summary_lsas<V>(
                         area,
                         instance,
                         prefix,
-                        None,
+                        new_lsa_id,
                         &new_summary,
                     )
                 }
             };
 
-            (prefix, (lsa_id, new_summary))
+            Some((prefix, (lsa_id, new_summary)))
         })
         .collect();
 
     // Flush old summaries that are no longer valid.
     let lsa_type = V::type3_summary(instance.config.extended_lsa);
-    let lsa_ids = old_summaries.into_values().map(|(lsa_id, _)| lsa_id);
-    flush_summary_lsas(lsa_type, lsa_ids, area, instance, lsa_entries);
+    let lsa_ids = stale_lsa_ids(old_summaries, &area.state.net_summaries);
+    let lsdb_id = LsdbId::Area(area.id);
+    let lsdb = &area.state.lsdb;
+    flush_lsas(lsa_type, lsa_ids, lsdb_id, lsdb, instance, lsa_entries);
 }
 
 fn update_rtr_summary_lsas<V>(
@@ -555,7 +725,9 @@ fn update_rtr_summary_lsas<V>(
     // Flush old summaries that are no longer valid.
     let lsa_type = V::type4_summary(instance.config.extended_lsa);
     let lsa_ids = old_summaries.into_values().map(|(lsa_id, _)| lsa_id);
-    flush_summary_lsas(lsa_type, lsa_ids, area, instance, lsa_entries);
+    let lsdb_id = LsdbId::Area(area.id);
+    let lsdb = &area.state.lsdb;
+    flush_lsas(lsa_type, lsa_ids, lsdb_id, lsdb, instance, lsa_entries);
 }
 
 fn compute_net_summaries<V>(
@@ -583,8 +755,11 @@ where
         summaries.extend(rsummaries);
     }
 
-    // Add default route for stub/NSSA areas.
-    if area.config.area_type != AreaType::Normal {
+    // Add default route for stub areas and totally NSSA areas (RFC 3101 -
+    // Section 2.3).
+    if area.config.area_type == AreaType::Stub
+        || (area.config.area_type == AreaType::Nssa && !area.config.summary)
+    {
         let prefix = V::IpNetwork::default(instance.state.af);
         let default_summary = SummaryNet {
             prefix_options: Default::default(),
@@ -613,7 +788,10 @@ where
         .filter(|(_, route)| {
             !matches!(
                 route.path_type,
-                PathType::Type1External | PathType::Type2External
+                PathType::Type1External
+                    | PathType::Type2External
+                    | PathType::Nssa1
+                    | PathType::Nssa2
             )
         })
         // Skip unreachable destinations.
@@ -713,6 +891,9 @@ where
         .iter()
         // Check the routing table from all other areas.
         .filter(|area_src| area_src.id != area.id)
+        // ASBRs within NSSAs aren't advertised to other areas, as their
+        // routes are advertised through translated Type-5 LSAs.
+        .filter(|area_src| area_src.config.area_type != AreaType::Nssa)
         .flat_map(|area_src| area_src.state.routers.iter())
         // Only ASBR routes are advertised in summary-LSAs.
         .filter(|(_, route)| route.flags.is_asbr())
@@ -739,6 +920,278 @@ where
     // TODO: filter-out non-preferred paths.
 }
 
+pub(crate) fn nssa_translator_state_change<V>(
+    area: &mut Area<V>,
+    state: NssaTranslatorState,
+    instance: &InstanceUpView<'_, V>,
+) where
+    V: Version,
+{
+    Debug::<V>::NssaTranslatorStateChange(
+        area.area_id,
+        area.state.nssa_translator_state,
+        state,
+    )
+    .log();
+    area.state.nssa_translator_state = state;
+    area.state.nssa_translator_event_count += 1;
+    area.state.discontinuity_time = Utc::now();
+    notification::nssa_translator_status_change(instance, area);
+}
+
+fn nssa_translator_state<V>(
+    area: &Area<V>,
+    is_abr: bool,
+    instance: &InstanceUpView<'_, V>,
+) -> NssaTranslatorState
+where
+    V: Version,
+{
+    // Only NSSA border routers can perform translation.
+    if area.config.area_type != AreaType::Nssa || !is_abr {
+        return NssaTranslatorState::Disabled;
+    }
+
+    match area.config.nssa_translator_role {
+        NssaTranslatorRole::Always => NssaTranslatorState::Enabled,
+        NssaTranslatorRole::Candidate => {
+            // The router is elected as the translator if no other reachable
+            // NSSA border router has the Nt-bit set and its Router ID is the
+            // highest among all reachable NSSA border routers.
+            let router_id = instance.state.router_id;
+            let elected = !area
+                .state
+                .routers
+                .iter()
+                .filter(|(rtr_id, _)| **rtr_id != router_id)
+                .filter(|(_, route)| route.path_type == PathType::IntraArea)
+                .filter(|(_, route)| route.flags.is_abr())
+                .any(|(rtr_id, route)| {
+                    route.flags.is_nssa_translator() || *rtr_id > router_id
+                });
+            if elected {
+                NssaTranslatorState::Elected
+            } else {
+                NssaTranslatorState::Disabled
+            }
+        }
+    }
+}
+
+fn update_nssa_lsas<V>(
+    area_idx: AreaIndex,
+    is_abr: bool,
+    redistributed: &BTreeMap<V::IpNetwork, ExternalNet<V>>,
+    instance: &InstanceUpView<'_, V>,
+    areas: &mut Areas<V>,
+    lsa_entries: &Arena<LsaEntry<V>>,
+) where
+    V: Version,
+{
+    // Compute NSSA routes.
+    let area = &mut areas[area_idx];
+    let mut new_externals = BTreeMap::new();
+    if area.config.area_type == AreaType::Nssa {
+        // Redistributed routes (RFC 3101 - Section 2.3). The P-bit is set
+        // unless this router is also an ABR, in which case the corresponding
+        // Type-5 LSAs are originated directly.
+        new_externals.extend(redistributed.iter().map(|(prefix, external)| {
+            let mut external = external.clone();
+            external.propagate = !is_abr;
+            (*prefix, external)
+        }));
+
+        // Type-7 default route (RFC 3101 - Section 2.7).
+        if is_abr && area.config.nssa_default_originate {
+            let prefix = V::IpNetwork::default(instance.state.af);
+            let default_external = ExternalNet {
+                prefix_options: Default::default(),
+                e_bit: true,
+                metric: area.config.default_cost,
+                fwd_addr: None,
+                tag: None,
+                propagate: false,
+            };
+            new_externals.insert(prefix, default_external);
+        }
+    }
+
+    // Save the old table of NSSA routes.
+    let old_externals = std::mem::take(&mut area.state.nssa_externals);
+
+    // Assign LSA-IDs.
+    let lsa_ids = V::prefix_lsa_ids(new_externals.keys().copied());
+
+    // (Re)originate the required Type-7 LSAs.
+    area.state.nssa_externals = new_externals
+        .into_iter()
+        .filter_map(|(prefix, new_external)| {
+            let new_lsa_id = prefix_lsa_id(&lsa_ids, &prefix)?;
+            let lsa_id = match old_externals.get(&prefix) {
+                Some((old_lsa_id, old_external)) => {
+                    // Reoriginate Type-7 LSA if the route or its LSA-ID has
+                    // changed.
+                    let lsa_id = new_lsa_id.unwrap_or(*old_lsa_id);
+                    if new_external != *old_external || lsa_id != *old_lsa_id {
+                        V::lsa_orig_nssa(
+                            area,
+                            instance,
+                            prefix,
+                            Some(lsa_id),
+                            &new_external,
+                        );
+                    }
+                    lsa_id
+                }
+                None => {
+                    // Originate new Type-7 LSA.
+                    V::lsa_orig_nssa(
+                        area,
+                        instance,
+                        prefix,
+                        new_lsa_id,
+                        &new_external,
+                    )
+                }
+            };
+
+            Some((prefix, (lsa_id, new_external)))
+        })
+        .collect();
+
+    // Flush old Type-7 LSAs that are no longer valid.
+    let lsa_type = V::type7_nssa(instance.config.extended_lsa);
+    let lsa_ids = stale_lsa_ids(old_externals, &area.state.nssa_externals);
+    let lsdb_id = LsdbId::Area(area.id);
+    let lsdb = &area.state.lsdb;
+    flush_lsas(lsa_type, lsa_ids, lsdb_id, lsdb, instance, lsa_entries);
+}
+
+fn update_translated_routes<V>(
+    instance: &mut InstanceUpView<'_, V>,
+    areas: &Areas<V>,
+    lsa_entries: &Arena<LsaEntry<V>>,
+) where
+    V: Version,
+{
+    // Compute the Type-7 LSAs that should be translated (RFC 3101 - Section
+    // 3.2).
+    let extended_lsa = instance.config.extended_lsa;
+    let router_id = instance.state.router_id;
+    let mut new_externals: BTreeMap<V::IpNetwork, ExternalNet<V>> =
+        BTreeMap::new();
+    for area in areas.iter().filter(|area| {
+        area.state.nssa_translator_state != NssaTranslatorState::Disabled
+    }) {
+        for lsa in V::nssa_networks(&area.state.lsdb, extended_lsa, lsa_entries)
+            // Only Type-7 LSAs with the P-bit set are translated.
+            .filter(|lsa| lsa.propagate)
+            // Filter out unreachable LSAs.
+            .filter(|lsa| lsa.metric < LSA_INFINITY)
+            // Filter out LSAs originated by the calculating router itself.
+            .filter(|lsa| lsa.adv_rtr != router_id)
+            // The forwarding address must be non-zero.
+            .filter(|lsa| lsa.fwd_addr.is_some())
+            // The LSA must be the one selected for routing.
+            .filter(|lsa| {
+                instance.state.rib.get(&lsa.prefix).is_some_and(|route| {
+                    matches!(route.path_type, PathType::Nssa1 | PathType::Nssa2)
+                        && route.area_id == Some(area.area_id)
+                })
+            })
+        {
+            let new_external = ExternalNet {
+                prefix_options: lsa.prefix_options,
+                e_bit: lsa.e_bit,
+                metric: lsa.metric,
+                fwd_addr: lsa.fwd_addr,
+                tag: lsa.tag,
+                propagate: false,
+            };
+
+            // When multiple Type-7 LSAs describe the same destination, prefer
+            // Type-1 metrics over Type-2 metrics, then the lowest metric.
+            match new_externals.entry(lsa.prefix) {
+                btree_map::Entry::Occupied(mut o) => {
+                    let curr_external = o.get();
+                    if (new_external.e_bit, new_external.metric)
+                        < (curr_external.e_bit, curr_external.metric)
+                    {
+                        o.insert(new_external);
+                    }
+                }
+                btree_map::Entry::Vacant(v) => {
+                    v.insert(new_external);
+                }
+            }
+        }
+    }
+
+    instance.state.nssa_translated = new_externals;
+}
+
+fn update_as_external_lsas<V>(
+    instance: &mut InstanceUpView<'_, V>,
+    redistributed: BTreeMap<V::IpNetwork, ExternalNet<V>>,
+    lsa_entries: &Arena<LsaEntry<V>>,
+) where
+    V: Version,
+{
+    let extended_lsa = instance.config.extended_lsa;
+
+    // Compute AS-external routes. Redistributed routes take precedence over
+    // translated ones.
+    let mut new_externals = instance.state.nssa_translated.clone();
+    new_externals.extend(redistributed);
+
+    // Save the old table of AS-external routes.
+    let old_externals = std::mem::take(&mut instance.state.externals);
+
+    // Assign LSA-IDs.
+    let lsa_ids = V::prefix_lsa_ids(new_externals.keys().copied());
+
+    // (Re)originate the required Type-5 LSAs.
+    let mut externals = BTreeMap::new();
+    for (prefix, new_external) in new_externals {
+        let Some(new_lsa_id) = prefix_lsa_id(&lsa_ids, &prefix) else {
+            continue;
+        };
+        let lsa_id = match old_externals.get(&prefix) {
+            Some((old_lsa_id, old_external)) => {
+                // Reoriginate Type-5 LSA if the route or its LSA-ID has
+                // changed.
+                let lsa_id = new_lsa_id.unwrap_or(*old_lsa_id);
+                if new_external != *old_external || lsa_id != *old_lsa_id {
+                    V::lsa_orig_as_external(
+                        instance,
+                        prefix,
+                        Some(lsa_id),
+                        &new_external,
+                    );
+                }
+                lsa_id
+            }
+            None => {
+                // Originate new Type-5 LSA.
+                V::lsa_orig_as_external(
+                    instance,
+                    prefix,
+                    new_lsa_id,
+                    &new_external,
+                )
+            }
+        };
+        externals.insert(prefix, (lsa_id, new_external));
+    }
+    instance.state.externals = externals;
+
+    // Flush old Type-5 LSAs that are no longer valid.
+    let lsa_type = V::type5_external(extended_lsa);
+    let lsa_ids = stale_lsa_ids(old_externals, &instance.state.externals);
+    let lsdb = &instance.state.lsdb;
+    flush_lsas(lsa_type, lsa_ids, LsdbId::As, lsdb, instance, lsa_entries);
+}
+
 fn nexthops_area_check<V>(
     nexthops: &Nexthops<V::IpAddr>,
     area: &Area<V>,
@@ -753,22 +1206,51 @@ where
     })
 }
 
-fn flush_summary_lsas<V>(
+// Returns the LSA-ID assigned to the given prefix, or `None` if the prefix
+// can't be advertised.
+//
+// The inner `None` means the LSA-ID is allocated on demand.
+fn prefix_lsa_id<P: Ord>(
+    lsa_ids: &Option<BTreeMap<P, u32>>,
+    prefix: &P,
+) -> Option<Option<u32>> {
+    match lsa_ids {
+        Some(lsa_ids) => lsa_ids.get(prefix).copied().map(Some),
+        None => Some(None),
+    }
+}
+
+// Returns the LSA-IDs of the old table that aren't used by any entry of the
+// new table.
+fn stale_lsa_ids<P, T>(
+    old: BTreeMap<P, (u32, T)>,
+    new: &BTreeMap<P, (u32, T)>,
+) -> impl Iterator<Item = u32> + use<P, T> {
+    let new_lsa_ids = new
+        .values()
+        .map(|(lsa_id, _)| *lsa_id)
+        .collect::<BTreeSet<_>>();
+    old.into_values()
+        .map(|(lsa_id, _)| lsa_id)
+        .filter(move |lsa_id| !new_lsa_ids.contains(lsa_id))
+}
+
+fn flush_lsas<V>(
     lsa_type: V::LsaType,
     lsa_ids: impl Iterator<Item = u32>,
-    area: &Area<V>,
+    lsdb_id: LsdbId,
+    lsdb: &Lsdb<V>,
     instance: &InstanceUpView<'_, V>,
     lsa_entries: &Arena<LsaEntry<V>>,
 ) where
     V: Version,
 {
-    let lsdb_id = LsdbId::Area(area.id);
     let adv_rtr = instance.state.router_id;
 
-    // Flush previously originated summaries that are no longer valid.
+    // Flush previously originated LSAs that are no longer valid.
     for lsa_id in lsa_ids {
         let lsa_key = LsaKey::new(lsa_type, adv_rtr, lsa_id.into());
-        if let Some((_, lse)) = area.state.lsdb.get(lsa_entries, &lsa_key) {
+        if let Some((_, lse)) = lsdb.get(lsa_entries, &lsa_key) {
             instance.tx.protocol_input.lsa_flush(
                 lsdb_id,
                 lse.id,
@@ -777,10 +1259,8 @@ fn flush_summary_lsas<V>(
         }
     }
 
-    // Flush received self-originated summaries that are no longer valid.
-    for (_, lse) in area
-        .state
-        .lsdb
+    // Flush received self-originated LSAs that are no longer valid.
+    for (_, lse) in lsdb
         .iter_by_type_advrtr(lsa_entries, lsa_type, adv_rtr)
         .filter(|(_, lse)| lse.flags.contains(LsaEntryFlags::RECEIVED))
     {
@@ -149,6 +149,14 @@ impl<T> Arena<T> {
     pub(crate) fn iter_mut(&mut self) -> impl Iterator<Item = (Index, &mut T)> {
         self.0.iter_mut()
     }
+
+    pub(crate) fn get2_mut(
+        &mut self,
+        i1: Index,
+        i2: Index,
+    ) -> (Option<&mut T>, Option<&mut T>) {
+        self.0.get2_mut(i1, i2)
+    }
 }
 
 impl<T> Default for Arena<T> {
@@ -8,11 +8,13 @@ use std::net::Ipv4Addr;
 use std::time::Duration;
 
 use holo_utils::ibus::IbusMsg;
+use holo_utils::mpls::LdpSyncState;
 use holo_yang::ToYang;
 use ipnetwork::{Ipv4Network, Ipv6Network};
 use serde::{Deserialize, Serialize};
 use tracing::{debug, debug_span};
 
+use crate::area::NssaTranslatorState;
 use crate::gr::GrExitReason;
 use crate::interface::{Interface, VirtualLinkKey, ism};
 use crate::neighbor::{NeighborNetId, nsm};
@@ -20,7 +22,7 @@ use crate::packet::Packet;
 use crate::packet::error::LsaValidationError;
 use crate::packet::tlv::GrReason;
 use crate::spf;
-use crate::spf::SpfLogType;
+use crate::spf::{SpfAlgorithm, SpfLogType};
 use crate::version::Version;
 
 // OSPF debug messages.
@@ -40,6 +42,7 @@ pub enum Debug<'a, V: Version> {
     InterfacePrimaryAddrDelete(&'a str),
     InterfaceLinkLocalSelect(&'a str, &'a Ipv6Network),
     InterfaceLinkLocalDelete(&'a str),
+    InterfaceLdpSyncChange(&'a str, LdpSyncState, LdpSyncState),
     IsmEvent(&'a str, &'a ism::State, &'a ism::Event),
     IsmTransition(&'a str, &'a ism::State, &'a ism::State),
     IsmDrElection(
@@ -53,11 +56,19 @@ pub enum Debug<'a, V: Version> {
     VirtualLinkSrcAddrChange(&'a VirtualLinkKey, V::NetIpAddr, V::NetIpAddr),
     VirtualLinkNbrAddrChange(&'a VirtualLinkKey, V::NetIpAddr, V::NetIpAddr),
     VirtualLinkCostChange(&'a VirtualLinkKey, u32, u32),
+    // NSSA
+    NssaTranslatorStateChange(
+        Ipv4Addr,
+        NssaTranslatorState,
+        NssaTranslatorState,
+    ),
     // Neighbors
     NeighborCreate(Ipv4Addr),
     NeighborDelete(Ipv4Addr),
     NeighborBfdReg(Ipv4Addr),
     NeighborBfdUnreg(Ipv4Addr),
+    NeighborOobResyncStart(Ipv4Addr),
+    NeighborOobResyncEnd(Ipv4Addr),
     NsmEvent(Ipv4Addr, &'a nsm::State, &'a nsm::Event),
     NsmTransition(Ipv4Addr, &'a nsm::State, &'a nsm::State),
     // Network
@@ -74,6 +85,7 @@ pub enum Debug<'a, V: Version> {
     LsaDiscard(Ipv4Addr, &'a V::LsaHdr, &'a LsaValidationError),
     LsaMinArrivalDiscard(Ipv4Addr, &'a V::LsaHdr),
     LsaSelfOriginated(Ipv4Addr, &'a V::LsaHdr),
+    LsaFloodReduced(Ipv4Addr, &'a V::LsaHdr),
     // LSDB maintenance
     LsaInstall(&'a V::LsaHdr),
     LsaOriginate(&'a V::LsaHdr),
@@ -83,7 +95,7 @@ pub enum Debug<'a, V: Version> {
     // SPF
     SpfDelayFsmEvent(&'a spf::fsm::State, &'a spf::fsm::Event),
     SpfDelayFsmTransition(&'a spf::fsm::State, &'a spf::fsm::State),
-    SpfStart(SpfLogType),
+    SpfStart(SpfLogType, SpfAlgorithm),
     SpfFinish(Duration),
     SpfNetworkUnreachableAbr(&'a V::IpNetwork, Ipv4Addr),
     SpfRouterUnreachableAbr(&'a Ipv4Addr, Ipv4Addr),
@@ -92,6 +104,8 @@ pub enum Debug<'a, V: Version> {
     GrHelperReject(Ipv4Addr, GrRejectReason),
     GrHelperEnter(Ipv4Addr, GrReason, u32),
     GrHelperExit(Ipv4Addr, GrExitReason),
+    GrRestartEnter(u16),
+    GrRestartExit(GrExitReason),
     // Internal bus
     IbusRx(&'a IbusMsg),
 }
@@ -102,6 +116,7 @@ pub enum InstanceInactiveReason {
     AdminDown,
     MissingRouterId,
     Resetting,
+    GracefulRestart,
 }
 
 // Reason why OSPF is inactive on an interface.
@@ -115,6 +130,9 @@ pub enum InterfaceInactiveReason {
     MissingMtu,
     MissingIpv4Address,
     MissingLinkLocalAddress,
+    UnnumberedNotPointToPoint,
+    MissingNeighborAddress,
+    PrimaryInterfaceDown,
     LoopedBack,
     Resetting,
 }
@@ -128,6 +146,7 @@ pub enum SeqNoMismatchReason {
     InconsistentSeqNo,
     UnexpectedDbDesc,
     InvalidLsaType,
+    ResyncTimeout,
 }
 
 // Reason why an LSA is being flushed.
@@ -221,6 +240,12 @@ where
                     })
                 })
             }
+            Debug::InterfaceLdpSyncChange(name, old_state, new_state) => {
+                // Parent span(s): ospf-instance
+                debug_span!("interface", %name).in_scope(|| {
+                    debug!(?old_state, ?new_state, "{}", self);
+                })
+            }
             Debug::IsmDrElection(name, old_dr, new_dr, old_bdr, new_bdr) => {
                 // Parent span(s): ospf-instance
                 debug_span!("interface", %name).in_scope(|| {
@@ -240,10 +265,18 @@ where
                     debug!(%old, %new, "{}", self);
                 })
             }
+            Debug::NssaTranslatorStateChange(area_id, old_state, new_state) => {
+                // Parent span(s): ospf-instance
+                debug_span!("area", %area_id).in_scope(|| {
+                    debug!(?old_state, ?new_state, "{}", self);
+                })
+            }
             Debug::NeighborCreate(router_id)
             | Debug::NeighborDelete(router_id)
             | Debug::NeighborBfdReg(router_id)
-            | Debug::NeighborBfdUnreg(router_id) => {
+            | Debug::NeighborBfdUnreg(router_id)
+            | Debug::NeighborOobResyncStart(router_id)
+            | Debug::NeighborOobResyncEnd(router_id) => {
                 // Parent span(s): ospf-instance
                 debug_span!("neighbor", %router_id).in_scope(|| {
                     debug!("{}", self);
@@ -288,7 +321,8 @@ where
             }
             Debug::QuestionableAck(router_id, lsa_hdr)
             | Debug::LsaMinArrivalDiscard(router_id, lsa_hdr)
-            | Debug::LsaSelfOriginated(router_id, lsa_hdr) => {
+            | Debug::LsaSelfOriginated(router_id, lsa_hdr)
+            | Debug::LsaFloodReduced(router_id, lsa_hdr) => {
                 // Parent span(s): ospf-instance
                 debug_span!("neighbor", %router_id).in_scope(|| {
                     debug!(?lsa_hdr, "{}", self);
@@ -320,9 +354,11 @@ where
                 // Parent span(s): ospf-instance
                 debug!(?old_state, ?new_state, "{}", self);
             }
-            Debug::SpfStart(spf_type) => {
+            Debug::SpfStart(spf_type, algorithm) => {
                 // Parent span(s): ospf-instance:spf
-                debug!(spf_type = %spf_type.to_yang(), "{}", self);
+                let spf_type = spf_type.to_yang();
+                let algorithm = algorithm.to_yang();
+                debug!(%spf_type, %algorithm, "{}", self);
             }
             Debug::SpfFinish(run_duration) => {
                 // Parent span(s): ospf-instance:spf
@@ -358,6 +394,14 @@ where
                     debug!(%reason, "{}", self);
                 })
             }
+            Debug::GrRestartEnter(restart_interval) => {
+                // Parent span(s): ospf-instance
+                debug!(%restart_interval, "{}", self);
+            }
+            Debug::GrRestartExit(reason) => {
+                // Parent span(s): ospf-instance
+                debug!(%reason, "{}", self);
+            }
             Debug::IbusRx(msg) => {
                 // Parent span(s): ospf-instance
                 debug_span!("internal-bus").in_scope(|| {
@@ -419,6 +463,9 @@ where
             Debug::IsmTransition(..) => {
                 write!(f, "state transition")
             }
+            Debug::InterfaceLdpSyncChange(..) => {
+                write!(f, "LDP-IGP synchronization state change")
+            }
             Debug::IsmDrElection(..) => {
                 write!(f, "DR election")
             }
@@ -431,6 +478,9 @@ where
             Debug::VirtualLinkCostChange(..) => {
                 write!(f, "virtual link cost changed")
             }
+            Debug::NssaTranslatorStateChange(..) => {
+                write!(f, "NSSA translator state changed")
+            }
             Debug::NeighborCreate(..) => {
                 write!(f, "neighbor created")
             }
@@ -443,6 +493,12 @@ where
             Debug::NeighborBfdUnreg(..) => {
                 write!(f, "BFD peer unregistered")
             }
+            Debug::NeighborOobResyncStart(..) => {
+                write!(f, "starting out-of-band LSDB resynchronization")
+            }
+            Debug::NeighborOobResyncEnd(..) => {
+                write!(f, "out-of-band LSDB resynchronization completed")
+            }
             Debug::NsmEvent(..) => {
                 write!(f, "event")
             }
@@ -470,6 +526,9 @@ where
             Debug::LsaSelfOriginated(..) => {
                 write!(f, "received self-originated LSA")
             }
+            Debug::LsaFloodReduced(..) => {
+                write!(f, "LSA flooding suppressed by flooding reduction")
+            }
             Debug::LsaInstall(..) => {
                 write!(f, "installing LSA")
             }
@@ -516,6 +575,12 @@ where
             Debug::GrHelperExit(..) => {
                 write!(f, "exiting from helper mode")
             }
+            Debug::GrRestartEnter(..) => {
+                write!(f, "entering graceful restart mode")
+            }
+            Debug::GrRestartExit(..) => {
+                write!(f, "exiting from graceful restart mode")
+            }
             Debug::IbusRx(..) => {
                 write!(f, "message")
             }
@@ -537,6 +602,9 @@ impl std::fmt::Display for InstanceInactiveReason {
             InstanceInactiveReason::Resetting => {
                 write!(f, "resetting")
             }
+            InstanceInactiveReason::GracefulRestart => {
+                write!(f, "graceful restart")
+            }
         }
     }
 }
@@ -567,6 +635,15 @@ impl std::fmt::Display for InterfaceInactiveReason {
             InterfaceInactiveReason::MissingLinkLocalAddress => {
                 write!(f, "missing link local IPv6 address")
             }
+            InterfaceInactiveReason::UnnumberedNotPointToPoint => {
+                write!(f, "unnumbered address on non point-to-point interface")
+            }
+            InterfaceInactiveReason::MissingNeighborAddress => {
+                write!(f, "missing multi-area adjacency neighbor address")
+            }
+            InterfaceInactiveReason::PrimaryInterfaceDown => {
+                write!(f, "multi-area adjacency primary interface down")
+            }
             InterfaceInactiveReason::LoopedBack => {
                 write!(f, "missing IP address")
             }
@@ -597,6 +674,9 @@ impl std::fmt::Display for SeqNoMismatchReason {
             SeqNoMismatchReason::InvalidLsaType => {
                 write!(f, "invalid LSA type")
             }
+            SeqNoMismatchReason::ResyncTimeout => {
+                write!(f, "out-of-band resynchronization timeout")
+            }
         }
     }
 }
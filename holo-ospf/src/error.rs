@@ -40,6 +40,8 @@ pub enum Error<V: Version> {
     DbDescReject(Ipv4Addr, nsm::State),
     Ospfv2LsaUnknownType(V::LsaType),
     Ospfv3LsaReservedScope(V::LsaType),
+    // LSA origination
+    LsaIdAssignConflict(V::IpNetwork),
     // SPF
     SpfRootNotFound(Ipv4Addr),
     SpfNexthopCalcError(V::VertexId),
@@ -58,6 +60,7 @@ pub enum Error<V: Version> {
     SpfDelayUnexpectedEvent(spf::fsm::State, spf::fsm::Event),
     InterfaceStartError(String, IoError),
     BootCountNvmUpdate(DatabaseError),
+    GrRestartNvmUpdate(DatabaseError),
 }
 
 // OSPF I/O errors.
@@ -82,6 +85,7 @@ pub enum InterfaceCfgError {
     HelloIntervalMismatch(u16, u16),
     DeadIntervalMismatch(u32, u32),
     ExternalRoutingCapabilityMismatch(bool),
+    NssaCapabilityMismatch(bool),
     MtuMismatch(u16),
     DuplicateRouterId(Ipv4Addr),
 }
@@ -141,6 +145,9 @@ where
             | Error::Ospfv3LsaReservedScope(lsa_type) => {
                 warn!(%lsa_type, "{}", self);
             }
+            Error::LsaIdAssignConflict(prefix) => {
+                warn!(%prefix, "{}", self);
+            }
             Error::SpfRootNotFound(area_id) => {
                 warn!(%area_id, "{}", self);
             }
@@ -179,7 +186,8 @@ where
             Error::InterfaceStartError(name, error) => {
                 error!(%name, error = %with_source(error), "{}", self);
             }
-            Error::BootCountNvmUpdate(error) => {
+            Error::BootCountNvmUpdate(error)
+            | Error::GrRestartNvmUpdate(error) => {
                 error!(%error, "{}", self);
             }
         }
@@ -236,6 +244,9 @@ where
             Error::Ospfv3LsaReservedScope(..) => {
                 write!(f, "discarding LSA due to reserved scope")
             }
+            Error::LsaIdAssignConflict(..) => {
+                write!(f, "failed to assign a unique Link State ID to prefix")
+            }
             Error::SpfRootNotFound(..) => {
                 write!(f, "SPF root not found")
             }
@@ -284,6 +295,12 @@ where
                     "failed to record updated boot count in non-volatile storage"
                 )
             }
+            Error::GrRestartNvmUpdate(..) => {
+                write!(
+                    f,
+                    "failed to record graceful restart state in non-volatile storage"
+                )
+            }
         }
     }
 }
@@ -413,6 +430,9 @@ impl InterfaceCfgError {
             InterfaceCfgError::ExternalRoutingCapabilityMismatch(e_bit) => {
                 warn!(%e_bit, "{}", self);
             }
+            InterfaceCfgError::NssaCapabilityMismatch(n_bit) => {
+                warn!(%n_bit, "{}", self);
+            }
             InterfaceCfgError::MtuMismatch(mtu) => {
                 warn!(%mtu, "{}", self);
             }
@@ -444,6 +464,9 @@ impl std::fmt::Display for InterfaceCfgError {
             InterfaceCfgError::ExternalRoutingCapabilityMismatch(..) => {
                 write!(f, "external routing capability mismatch")
             }
+            InterfaceCfgError::NssaCapabilityMismatch(..) => {
+                write!(f, "NSSA capability mismatch")
+            }
             InterfaceCfgError::MtuMismatch(..) => {
                 write!(f, "MTU mismatch")
             }
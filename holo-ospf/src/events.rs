@@ -10,8 +10,11 @@ use std::net::Ipv4Addr;
 use std::sync::Arc;
 
 use chrono::Utc;
+use holo_utils::mpls::LdpSyncState;
 
-use crate::area::{Area, AreaType, BACKBONE_AREA_ID};
+use crate::area::{
+    self, Area, AreaType, BACKBONE_AREA_ID, NssaTranslatorState,
+};
 use crate::collections::{
     AreaIndex, AreaKey, Arena, InterfaceIndex, InterfaceKey, LsaEntryKey,
     LsdbIndex, LsdbKey, NeighborIndex, NeighborKey, lsdb_get, lsdb_get_mut,
@@ -22,7 +25,7 @@ use crate::error::{Error, InterfaceCfgError};
 use crate::flood::flood;
 use crate::gr::GrExitReason;
 use crate::instance::{InstanceArenas, InstanceUpView};
-use crate::interface::{Interface, VirtualLinkKey, ism};
+use crate::interface::{self, Interface, VirtualLinkKey, ism};
 use crate::lsdb::{
     self, LsaEntry, LsaEntryFlags, LsaOriginateEvent, lsa_compare,
 };
@@ -30,6 +33,7 @@ use crate::neighbor::{LastDbDesc, Neighbor, RxmtPacketType, nsm};
 use crate::northbound::notification;
 use crate::packet::error::DecodeResult;
 use crate::packet::iana::PacketType;
+use crate::packet::lls::ExtendedOptionsFlags;
 use crate::packet::lsa::{
     Lsa, LsaBodyVersion, LsaHdrVersion, LsaKey, LsaScope, LsaTypeVersion,
 };
@@ -142,11 +146,12 @@ where
 
     // Check the packet's Area ID and determine the interface it should be
     // processed on. If the Area ID matches, the packet stays on the current
-    // interface. Otherwise, if it is destined for the backbone, an attempt is
-    // made to map it to a virtual link interface.
+    // interface. Otherwise, an attempt is made to map it to a virtual link
+    // or to a multi-area adjacency.
     let (area_idx, iface_idx) = process_packet_resolve_interface(
         area_idx,
         iface_idx,
+        src,
         packet.hdr(),
         arenas,
     )
@@ -156,7 +161,7 @@ where
     })?;
     let area = &mut arenas.areas[area_idx];
     let iface = &mut arenas.interfaces[iface_idx];
-    if iface.is_virtual_link() && iface.is_down() {
+    if (iface.is_virtual_link() || iface.is_multi_area()) && iface.is_down() {
         return Ok(());
     }
 
@@ -182,23 +187,11 @@ where
         // Discard the packet if its sequence number is lower than the recorded
         // sequence number in the sender's neighbor data structure.
         //
-        // Sequence number checking is dependent on OSPF packet type in order to
-        // account for packet prioritization as specified in RFC 4222.
-        let nbr_auth_seqno = nbr.auth_seqno.entry(pkt_type).or_default();
-        match auth_seqno.cmp(nbr_auth_seqno) {
-            Ordering::Less => {
-                return Err(Error::PacketAuthInvalidSeqno(src, auth_seqno));
-            }
-            Ordering::Equal if V::STRICT_AUTH_SEQNO_CHECK => {
-                return Err(Error::PacketAuthInvalidSeqno(src, auth_seqno));
-            }
-            _ => {
-                // Packet sequence number is valid.
-            }
+        // RFC 7474 extended sequence numbers are strictly increasing.
+        let strict = V::STRICT_AUTH_SEQNO_CHECK || iface.config.auth_ext_seqno;
+        if !nbr.auth_seqno.check_update(pkt_type, auth_seqno, strict) {
+            return Err(Error::PacketAuthInvalidSeqno(src, auth_seqno));
         }
-
-        // Update neighbor's last received sequence number.
-        *nbr_auth_seqno = auth_seqno;
     }
 
     // Log received packet.
@@ -252,6 +245,7 @@ where
 pub(crate) fn process_packet_resolve_interface<V>(
     area_idx: AreaIndex,
     iface_idx: InterfaceIndex,
+    src: V::NetIpAddr,
     packet_hdr: &V::PacketHdr,
     arenas: &mut InstanceArenas<V>,
 ) -> Result<(AreaIndex, InterfaceIndex), InterfaceCfgError>
@@ -289,6 +283,28 @@ where
         }
     }
 
+    // Case 3 (RFC 5185 2.2): The packet may have come over a multi-area
+    // adjacency. The receiving interface must have a multi-area adjacency
+    // configured in the packet's area, and the packet's source address must
+    // match the adjacency's configured neighbor address.
+    if let Some((ma_area_idx, ma_area)) =
+        arenas.areas.get_by_area_id(packet_hdr.area_id())
+    {
+        let iface = &arenas.interfaces[iface_idx];
+        let ma_ifname =
+            interface::multi_area_ifname(&iface.name, packet_hdr.area_id());
+        if let Some((ma_iface_idx, ma_iface)) = ma_area
+            .interfaces
+            .get_by_name(&arenas.interfaces, &ma_ifname)
+            && ma_iface
+                .multi_area
+                .as_ref()
+                .is_some_and(|multi_area| multi_area.nbr_addr == Some(src))
+        {
+            return Ok((ma_area_idx, ma_iface_idx));
+        }
+    }
+
     // Otherwise: Area ID mismatch.
     Err(InterfaceCfgError::AreaIdMismatch(
         packet_hdr.area_id(),
@@ -364,6 +380,34 @@ where
     // Trigger the HelloReceived event.
     nbr.fsm(iface, area, instance, lsa_entries, nsm::Event::HelloRcvd);
 
+    // Examine LLS data block if enabled and present in the packet.
+    nbr.ext_options = ExtendedOptionsFlags::empty();
+    if iface.config.lls_enabled
+        && hello.options().l_bit()
+        && let Some(lls) = hello.lls()
+        && let Some(eof) = lls.eof
+    {
+        nbr.ext_options = eof;
+    }
+
+    // A fully adjacent neighbor signaling a restart with the RS bit must not
+    // have its adjacency reset, even though it might not list our Router ID
+    // in its Hello packets (RFC 4812 - Section 2.4).
+    let restart_signaled = nbr.state == nsm::State::Full
+        && nbr.oob_resync_capable(iface)
+        && nbr.ext_options.contains(ExtendedOptionsFlags::RS);
+    if restart_signaled {
+        // The neighbor is expected to initiate the OOB resynchronization
+        // procedure within RouterDeadInterval seconds.
+        if nbr.oob_resync.is_none() && nbr.tasks.resync_timer.is_none() {
+            nbr.resync_timer_start(iface, area, instance);
+
+            // Reply immediately so that the restarting neighbor can
+            // quickly re-establish bidirectional communication.
+            iface.sync_hello_tx(area, instance);
+        }
+    }
+
     // Trigger the 1-WayReceived or the 2-WayReceived event.
     if hello
         .neighbors()
@@ -371,6 +415,8 @@ where
         .any(|id| *id == instance.state.router_id)
     {
         nbr.fsm(iface, area, instance, lsa_entries, nsm::Event::TwoWayRcvd);
+    } else if restart_signaled {
+        return Ok(());
     } else {
         nbr.fsm(iface, area, instance, lsa_entries, nsm::Event::OneWayRcvd);
 
@@ -428,14 +474,6 @@ where
         nbr.bdr = hello.bdr();
     }
 
-    // Examine LLS data block if enabled and present in the packet.
-    if iface.config.lls_enabled
-        && hello.options().l_bit()
-        && let Some(_lls) = hello.lls()
-    {
-        // TODO: Handle LLS data
-    }
-
     Ok(())
 }
 
@@ -476,6 +514,13 @@ where
         ));
     }
 
+    // Check for NSSA capability mismatch (RFC 3101 - Section 2.1).
+    if hello.options().n_bit() != (area.config.area_type == AreaType::Nssa) {
+        return Err(InterfaceCfgError::NssaCapabilityMismatch(
+            hello.options().n_bit(),
+        ));
+    }
+
     // Check for duplicate Router ID.
     if hello.router_id() == instance.state.router_id {
         return Err(InterfaceCfgError::DuplicateRouterId(hello.router_id()));
@@ -509,8 +554,21 @@ where
         ));
     }
 
-    // Further processing depends on the neighbor's state.
-    match nbr.state {
+    // Check for the start of out-of-band LSDB resynchronization initiated by
+    // the neighbor (RFC 4811 - Section 2.4).
+    if nbr.state == nsm::State::Full
+        && nbr.oob_resync.is_none()
+        && nbr.oob_resync_capable(iface)
+        && dbdesc.dd_flags().contains(
+            DbDescFlags::I | DbDescFlags::M | DbDescFlags::MS | DbDescFlags::R,
+        )
+    {
+        nbr.oob_resync_start(iface, area, instance);
+    }
+
+    // Further processing depends on the neighbor's state, or on the state of
+    // the out-of-band LSDB resynchronization in progress.
+    match nbr.dd_state() {
         nsm::State::Down | nsm::State::Attempt | nsm::State::TwoWay => {
             return Err(Error::DbDescReject(nbr.router_id, nbr.state));
         }
@@ -568,6 +626,8 @@ where
             if dbdesc.dd_flags().contains(DbDescFlags::I)
                 || dbdesc.dd_flags().contains(DbDescFlags::MS)
                     != last_rcvd_dbdesc.dd_flags.contains(DbDescFlags::MS)
+                || (nbr.dd_flags.contains(DbDescFlags::R)
+                    && !dbdesc.dd_flags().contains(DbDescFlags::R))
             {
                 let reason = SeqNoMismatchReason::InconsistentFlags;
                 let event = nsm::Event::SeqNoMismatch(reason);
@@ -707,9 +767,10 @@ where
     // Examine LLS data block if enabled and present the packet.
     if iface.config.lls_enabled
         && dbdesc.options().l_bit()
-        && let Some(_lls) = dbdesc.lls()
+        && let Some(lls) = dbdesc.lls()
+        && let Some(eof) = lls.eof
     {
-        // TODO: Handle LLS data
+        nbr.ext_options = eof;
     }
 
     // Save last received Database Description packet.
@@ -874,7 +935,7 @@ where
     if lsa.hdr.is_maxage()
         && lse.is_none()
         && !arenas.neighbors.iter().any(|(_, nbr)| {
-            matches!(nbr.state, nsm::State::Exchange | nsm::State::Loading)
+            matches!(nbr.dd_state(), nsm::State::Exchange | nsm::State::Loading)
         })
     {
         // Acknowledge the receipt of the LSA.
@@ -946,8 +1007,10 @@ where
             iface.enqueue_delayed_ack(area, instance, &lse.data.hdr);
         }
 
-        // Grace-LSA processing.
-        if let Some((grace_period, reason, addr)) = lse.data.body.as_grace() {
+        // Grace-LSA processing (ignoring self-originated Grace-LSAs).
+        if !lse.flags.contains(LsaEntryFlags::SELF_ORIGINATED)
+            && let Some((grace_period, reason, addr)) = lse.data.body.as_grace()
+        {
             // For OSPFv2, on broadcast, NBMA and P2MP segments, the restarting
             // neighbor is identified by the IP interface address in the body of
             // the Grace-LSA.
@@ -1240,13 +1303,31 @@ where
 // ===== LSA origination event =====
 
 pub(crate) fn process_lsa_orig_event<V>(
-    instance: &InstanceUpView<'_, V>,
-    arenas: &InstanceArenas<V>,
+    instance: &mut InstanceUpView<'_, V>,
+    arenas: &mut InstanceArenas<V>,
     event: LsaOriginateEvent,
 ) -> Result<(), Error<V>>
 where
     V: Version,
 {
+    // While in graceful restart mode, self-originated LSAs aren't
+    // (re)originated. Adjacency and interface changes are still monitored to
+    // determine when the graceful restart should be exited.
+    if instance.state.gr_restart.is_some() {
+        match event {
+            LsaOriginateEvent::NeighborToFromFull { .. } => {
+                gr::restart_check_completion(instance, arenas);
+            }
+            LsaOriginateEvent::InterfaceStateChange { area_id, iface_id } => {
+                gr::restart_check_topology_change(
+                    instance, arenas, area_id, iface_id,
+                );
+            }
+            _ => (),
+        }
+        return Ok(());
+    }
+
     // Check which LSAs need to be reoriginated or flushed.
     V::lsa_orig_event(instance, arenas, event)
 }
@@ -1264,6 +1345,11 @@ pub(crate) fn process_lsa_orig_check<V>(
 where
     V: Version,
 {
+    // Self-originated LSAs aren't originated while in graceful restart mode.
+    if instance.state.gr_restart.is_some() {
+        return Ok(());
+    }
+
     // Lookup LSDB.
     let (lsdb_idx, _) = lsdb_get(
         &instance.state.lsdb,
@@ -1319,6 +1405,11 @@ pub(crate) fn process_lsa_flush<V>(
 where
     V: Version,
 {
+    // Self-originated LSAs aren't flushed while in graceful restart mode.
+    if instance.state.gr_restart.is_some() {
+        return Ok(());
+    }
+
     // Lookup LSA entry and its corresponding LSDB.
     let (lsdb_idx, lsdb) = lsdb_get_mut(
         &mut instance.state.lsdb,
@@ -1396,7 +1487,7 @@ where
     // Skip discarding MaxAge LSAs if any of the router's neighbors are in
     // states Exchange or Loading.
     if arenas.neighbors.iter().any(|(_, nbr)| {
-        matches!(nbr.state, nsm::State::Exchange | nsm::State::Loading)
+        matches!(nbr.dd_state(), nsm::State::Exchange | nsm::State::Loading)
     }) {
         return Ok(());
     }
@@ -1496,3 +1587,115 @@ where
 
     Ok(())
 }
+
+// ===== Graceful restart interval timeout =====
+
+pub(crate) fn process_restart_interval_timeout<V>(
+    instance: &mut InstanceUpView<'_, V>,
+    arenas: &mut InstanceArenas<V>,
+) -> Result<(), Error<V>>
+where
+    V: Version,
+{
+    if instance.state.gr_restart.is_some() {
+        // Exit from the graceful restart mode.
+        gr::restart_exit(instance, arenas, GrExitReason::TimedOut);
+    }
+
+    Ok(())
+}
+
+// ===== LDP-IGP synchronization holddown timeout =====
+
+pub(crate) fn process_ldp_sync_holddown_timeout<V>(
+    instance: &mut InstanceUpView<'_, V>,
+    arenas: &mut InstanceArenas<V>,
+    area_key: AreaKey,
+    iface_key: InterfaceKey,
+) -> Result<(), Error<V>>
+where
+    V: Version,
+{
+    // Lookup area and interface.
+    let (_, area) = arenas.areas.get_mut_by_key(&area_key)?;
+    let (_, iface) = area
+        .interfaces
+        .get_mut_by_key(&mut arenas.interfaces, &iface_key)?;
+    iface.state.tasks.ldp_sync_holddown = None;
+
+    if iface.state.ldp_sync == LdpSyncState::NotAchieved {
+        // Stop advertising the maximum metric.
+        let new_state = LdpSyncState::HolddownExpired;
+        Debug::<V>::InterfaceLdpSyncChange(
+            &iface.name,
+            iface.state.ldp_sync,
+            new_state,
+        )
+        .log();
+        iface.state.ldp_sync = new_state;
+
+        // (Re)originate LSAs that might have been affected.
+        instance.tx.protocol_input.lsa_orig_event(
+            LsaOriginateEvent::InterfaceCostChange { area_id: area.id },
+        );
+    }
+
+    Ok(())
+}
+
+// ===== NSSA translator stability interval timeout =====
+
+pub(crate) fn process_nssa_translator_stability_timeout<V>(
+    instance: &mut InstanceUpView<'_, V>,
+    arenas: &mut InstanceArenas<V>,
+    area_key: AreaKey,
+) -> Result<(), Error<V>>
+where
+    V: Version,
+{
+    // Lookup area.
+    let (_, area) = arenas.areas.get_mut_by_key(&area_key)?;
+    area.state.nssa_translator_stability_timer = None;
+
+    // Stop translating Type-7 LSAs.
+    if area.state.nssa_translator_state == NssaTranslatorState::Elected {
+        area::nssa_translator_state_change(
+            area,
+            NssaTranslatorState::Disabled,
+            instance,
+        );
+        instance
+            .tx
+            .protocol_input
+            .lsa_orig_event(LsaOriginateEvent::NssaTranslatorChange);
+    }
+
+    // Reevaluate the translator state and the translated routes.
+    area::update_nssa(
+        instance,
+        &mut arenas.areas,
+        &arenas.interfaces,
+        &arenas.lsa_entries,
+    );
+
+    Ok(())
+}
+
+// ===== Redistributed routes update timeout =====
+
+pub(crate) fn process_externals_update<V>(
+    instance: &mut InstanceUpView<'_, V>,
+    arenas: &mut InstanceArenas<V>,
+) where
+    V: Version,
+{
+    instance.state.externals_update_timer = None;
+
+    // Update AS-external and NSSA LSAs.
+    area::update_externals(
+        instance,
+        &mut arenas.areas,
+        &arenas.interfaces,
+        &arenas.lsa_entries,
+    );
+}
@@ -0,0 +1,192 @@
+//
+// Copyright (c) The Holo Core Contributors
+//
+// SPDX-License-Identifier: MIT
+//
+
+use std::collections::BTreeSet;
+use std::net::Ipv4Addr;
+
+use holo_utils::sr::IgpAlgoType;
+use num_traits::FromPrimitive;
+
+use crate::area::Area;
+use crate::collections::Arena;
+use crate::lsdb::LsaEntry;
+use crate::northbound::configuration::{FlexAlgoCfg, LinkAttrsCfg};
+use crate::packet::iana::{AslaSabmFlags, IgpMetricType};
+use crate::packet::tlv::{AslaStlv, ExtAdminGroup, FadTlv, MinMaxLinkDelay};
+use crate::version::Version;
+
+// Flexible Algorithm Definition in effect within an area.
+#[derive(Debug)]
+pub struct FlexAlgo {
+    pub algo: u8,
+    pub metric_type: IgpMetricType,
+    pub exclude_any: Option<ExtAdminGroup>,
+    pub include_any: Option<ExtAdminGroup>,
+    pub include_all: Option<ExtAdminGroup>,
+    pub exclude_srlgs: BTreeSet<u32>,
+    // Routers that advertise support for the algorithm.
+    pub participants: BTreeSet<Ipv4Addr>,
+}
+
+// ===== impl FlexAlgo =====
+
+impl FlexAlgo {
+    // Selects the Flexible Algorithm Definition in effect for the given area.
+    //
+    // Returns `None` if there's no usable definition, in which case the router
+    // MUST NOT participate in the algorithm (RFC 9350 - Section 5.3).
+    pub(crate) fn compute<V>(
+        algo: u8,
+        area: &Area<V>,
+        lsa_entries: &Arena<LsaEntry<V>>,
+    ) -> Option<FlexAlgo>
+    where
+        V: Version,
+    {
+        let mut participants = BTreeSet::new();
+        let mut winner: Option<(u8, Ipv4Addr, &FadTlv)> = None;
+
+        // Only the routers reachable within the area are considered.
+        for router_id in area.state.routers.keys() {
+            let ri = V::area_router_information(
+                &area.state.lsdb,
+                *router_id,
+                lsa_entries,
+            );
+
+            if ri.sr_algo.is_some_and(|sr_algo| {
+                sr_algo.get().contains(&IgpAlgoType::FlexAlgo(algo))
+            }) {
+                participants.insert(*router_id);
+            }
+
+            // From the advertisements of the FAD in the area, a router MUST
+            // select the one with the highest priority value. If there are
+            // multiple advertisements of the FAD with the same highest
+            // priority, the router MUST select the one that is originated
+            // from the router with the highest Router ID.
+            if let Some(fad) = ri.fads.iter().find(|fad| fad.flex_algo == algo)
+                && winner.is_none_or(|(priority, winner_id, _)| {
+                    (fad.priority, *router_id) > (priority, winner_id)
+                })
+            {
+                winner = Some((fad.priority, *router_id, fad));
+            }
+        }
+        let (_, _, fad) = winner?;
+
+        // If the node does not support or understand any of the components
+        // of the winning FAD, it MUST NOT participate in the algorithm.
+        if fad.calc_type != 0 || !fad.unknown_stlvs.is_empty() {
+            return None;
+        }
+        let metric_type = IgpMetricType::from_u8(fad.metric_type)?;
+
+        Some(FlexAlgo {
+            algo,
+            metric_type,
+            exclude_any: fad.exclude_admin_group.clone(),
+            include_any: fad.include_any_admin_group.clone(),
+            include_all: fad.include_all_admin_group.clone(),
+            exclude_srlgs: fad.exclude_srlgs.iter().copied().collect(),
+            participants,
+        })
+    }
+
+    // Returns the metric of the given link for this algorithm, or `None` if
+    // the link must be pruned from the topology.
+    pub(crate) fn link_metric(
+        &self,
+        igp_metric: u16,
+        aslas: &[AslaStlv],
+    ) -> Option<u32> {
+        // Only the link attributes advertised for the Flexible Algorithm
+        // application are used (RFC 9350 - Section 12).
+        let asla = AslaStlv::find(aslas, AslaSabmFlags::F);
+
+        // Apply the administrative group constraints.
+        let admin_group =
+            asla.and_then(|asla| asla.admin_group()).unwrap_or_default();
+        if let Some(exclude_any) = &self.exclude_any
+            && admin_group.intersects(exclude_any)
+        {
+            return None;
+        }
+        if let Some(include_any) = &self.include_any
+            && !admin_group.intersects(include_any)
+        {
+            return None;
+        }
+        if let Some(include_all) = &self.include_all
+            && !admin_group.contains(include_all)
+        {
+            return None;
+        }
+
+        // Apply the SRLG constraint.
+        if asla.is_some_and(|asla| {
+            asla.srlgs
+                .iter()
+                .any(|srlg| self.exclude_srlgs.contains(srlg))
+        }) {
+            return None;
+        }
+
+        // Links that don't advertise the metric used by the algorithm are
+        // pruned from the topology.
+        match self.metric_type {
+            IgpMetricType::IgpMetric => Some(igp_metric.into()),
+            IgpMetricType::MinUniLinkDelay => asla
+                .and_then(|asla| asla.min_max_delay)
+                .map(|delay| delay.min),
+            IgpMetricType::TeMetric => asla.and_then(|asla| asla.te_metric),
+        }
+    }
+}
+
+// ===== global functions =====
+
+// Builds the FAD TLV corresponding to the given configuration.
+pub(crate) fn fad_tlv(algo: u8, fa_cfg: &FlexAlgoCfg) -> FadTlv {
+    let admin_group = |bits: &BTreeSet<u16>| {
+        (!bits.is_empty()).then(|| ExtAdminGroup::from_bits(bits))
+    };
+
+    let mut fad =
+        FadTlv::new(algo, fa_cfg.metric_type as u8, 0, fa_cfg.priority);
+    fad.exclude_admin_group = admin_group(&fa_cfg.exclude_any);
+    fad.include_any_admin_group = admin_group(&fa_cfg.include_any);
+    fad.include_all_admin_group = admin_group(&fa_cfg.include_all);
+    fad.exclude_srlgs = fa_cfg.exclude_srlgs.iter().copied().collect();
+    fad
+}
+
+// Builds the ASLA Sub-TLV advertising the link attributes configured for the
+// Flexible Algorithm application, if any.
+pub(crate) fn link_asla(link_attrs: &LinkAttrsCfg) -> Option<AslaStlv> {
+    if link_attrs.te_metric.is_none()
+        && link_attrs.min_delay.is_none()
+        && link_attrs.admin_groups.is_empty()
+        && link_attrs.srlgs.is_empty()
+    {
+        return None;
+    }
+
+    let ext_admin_group = (!link_attrs.admin_groups.is_empty())
+        .then(|| ExtAdminGroup::from_bits(&link_attrs.admin_groups));
+    let min_max_delay = link_attrs.min_delay.map(|min| {
+        let max = link_attrs.max_delay.unwrap_or(min).max(min);
+        MinMaxLinkDelay::new(false, min, max)
+    });
+    Some(AslaStlv {
+        sabm: Some(AslaSabmFlags::F),
+        ext_admin_group,
+        te_metric: link_attrs.te_metric,
+        min_max_delay,
+        srlgs: link_attrs.srlgs.iter().copied().collect(),
+        ..Default::default()
+    })
+}
@@ -5,20 +5,246 @@
 //
 
 use std::cmp::Ordering;
-use std::collections::btree_map;
+use std::collections::{BTreeMap, BTreeSet, btree_map};
+use std::net::Ipv4Addr;
 use std::sync::Arc;
 
+use num_traits::FromPrimitive;
+
 use crate::area::Area;
 use crate::collections::{
     Areas, Arena, InterfaceIndex, LsdbIndex, NeighborIndex,
 };
+use crate::debug::Debug;
 use crate::instance::InstanceUpView;
-use crate::interface::{Interface, ism};
-use crate::lsdb;
+use crate::interface::{Interface, InterfaceType, ism};
+use crate::lsdb::{self, LsaEntry};
 use crate::neighbor::{Neighbor, nsm};
+use crate::packet::iana::FloodingAlgo;
 use crate::packet::lsa::{Lsa, LsaHdrVersion};
+use crate::packet::tlv::FloodingAlgoTlv;
+use crate::spf::{VertexIdVersion, VertexLsaVersion};
 use crate::version::Version;
 
+// Flooding reduction state of an area (draft-ietf-lsr-distoptflood).
+#[derive(Debug)]
+pub struct FloodingReduction<V: Version> {
+    // Cached data for each neighbor.
+    neighbors: BTreeMap<Ipv4Addr, NeighborCache<V>>,
+}
+
+#[derive(Debug)]
+pub struct NeighborCache<V: Version> {
+    // SPT computed with the hop count metric.
+    pub spt_hopcount: HopCountSpt<V>,
+    // Remote Neighbor List (RNL).
+    pub remote_nbr_list: BTreeMap<Ipv4Addr, FloodingAlgo>,
+}
+
+// Shortest-path tree computed with the hop count metric.
+//
+// Unlike the regular SPT, all equal-hop parents of each vertex are retained,
+// since they're needed to determine whether a node lies on any of the shortest
+// paths toward the root.
+#[derive(Debug)]
+pub struct HopCountSpt<V: Version> {
+    vertices: BTreeMap<V::VertexId, HopCountVertex<V>>,
+}
+
+#[derive(Debug)]
+pub struct HopCountVertex<V: Version> {
+    // Router ID (router vertices only).
+    pub router_id: Option<Ipv4Addr>,
+    // Number of routers between the root and this vertex.
+    pub hops: u16,
+    // Parent vertices on all shortest paths toward the root.
+    pub parents: Vec<V::VertexId>,
+}
+
+// ===== impl FloodingReduction =====
+
+impl<V> Default for FloodingReduction<V>
+where
+    V: Version,
+{
+    fn default() -> FloodingReduction<V> {
+        FloodingReduction {
+            neighbors: Default::default(),
+        }
+    }
+}
+
+// ===== impl HopCountSpt =====
+
+impl<V> HopCountSpt<V>
+where
+    V: Version,
+{
+    // Computes a shortest-path tree rooted at the given router, using the hop
+    // count as the metric of all links.
+    fn compute(
+        area: &Area<V>,
+        instance: &InstanceUpView<'_, V>,
+        lsa_entries: &Arena<LsaEntry<V>>,
+        router_id: Ipv4Addr,
+    ) -> HopCountSpt<V> {
+        let af = instance.state.af;
+        let extended_lsa = instance.config.extended_lsa;
+        let mut vertices = BTreeMap::new();
+
+        // Get root vertex.
+        let root_vid = V::VertexId::new_root(router_id);
+        let Some(root_vlsa) =
+            V::vertex_lsa_find(af, root_vid, area, extended_lsa, lsa_entries)
+        else {
+            return HopCountSpt { vertices };
+        };
+
+        // Initialize candidate list.
+        let mut cand_list = BTreeMap::new();
+        let mut cand_hops = BTreeMap::new();
+        cand_list.insert((0, root_vid), (root_vlsa, vec![]));
+        cand_hops.insert(root_vid, 0);
+
+        // Main SPF loop.
+        while let Some(((hops, vertex_id), (vertex_lsa, parents))) =
+            cand_list.pop_first()
+        {
+            cand_hops.remove(&vertex_id);
+
+            // Add vertex to SPT.
+            let router_id =
+                vertex_lsa.is_router().then(|| vertex_lsa.router_id());
+            vertices.insert(
+                vertex_id,
+                HopCountVertex {
+                    router_id,
+                    hops,
+                    parents,
+                },
+            );
+
+            // Iterate over all links described by the vertex's LSA.
+            for link in V::vertex_lsa_links(
+                &vertex_lsa,
+                af,
+                area,
+                extended_lsa,
+                lsa_entries,
+            ) {
+                // Check if the LSAs are mutually linked.
+                if !V::vertex_lsa_links(
+                    &link.lsa,
+                    af,
+                    area,
+                    extended_lsa,
+                    lsa_entries,
+                )
+                .any(|link| link.id == vertex_id)
+                {
+                    continue;
+                }
+
+                // Only router vertices increment the hop count.
+                let mut link_hops = hops;
+                if link.lsa.is_router() {
+                    link_hops = link_hops.saturating_add(1);
+                }
+
+                // Record additional equal-hop parents of vertices that are
+                // already on the shortest-path tree.
+                if let Some(vertex) = vertices.get_mut(&link.id) {
+                    if vertex.hops == link_hops
+                        && !vertex.parents.contains(&vertex_id)
+                    {
+                        vertex.parents.push(vertex_id);
+                    }
+                    continue;
+                }
+
+                // Check if this vertex is already present on the candidate
+                // list.
+                if let Some(hops) = cand_hops.get(&link.id).copied() {
+                    match link_hops.cmp(&hops) {
+                        Ordering::Less => {
+                            // Remove vertex since its key has changed. It will
+                            // be re-added with the correct key below.
+                            cand_list.remove(&(hops, link.id));
+                        }
+                        Ordering::Equal => {
+                            let (_, parents) =
+                                cand_list.get_mut(&(hops, link.id)).unwrap();
+                            if !parents.contains(&vertex_id) {
+                                parents.push(vertex_id);
+                            }
+                            continue;
+                        }
+                        Ordering::Greater => {
+                            // Ignore higher cost path.
+                            continue;
+                        }
+                    }
+                }
+                cand_list
+                    .insert((link_hops, link.id), (link.lsa, vec![vertex_id]));
+                cand_hops.insert(link.id, link_hops);
+            }
+        }
+
+        HopCountSpt { vertices }
+    }
+
+    // Returns true if `ancestor` appears on any shortest path from `descendant`
+    // toward the root. The search walks all parent links stored in the SPT.
+    pub(crate) fn is_on_path(
+        &self,
+        ancestor: Ipv4Addr,
+        descendant: Ipv4Addr,
+    ) -> bool {
+        let ancestor = V::VertexId::new_root(ancestor);
+        let descendant = V::VertexId::new_root(descendant);
+        if !self.vertices.contains_key(&ancestor)
+            || !self.vertices.contains_key(&descendant)
+        {
+            return false;
+        }
+
+        // Stack-based DFS over all parent chains.
+        let mut visited = BTreeSet::new();
+        let mut stack = vec![descendant];
+        while let Some(cur) = stack.pop() {
+            if cur == ancestor {
+                return true;
+            }
+            if !visited.insert(cur) {
+                continue;
+            }
+            if let Some(vertex) = self.vertices.get(&cur) {
+                stack.extend(vertex.parents.iter().copied());
+            }
+        }
+
+        false
+    }
+
+    // Returns an iterator over the Router IDs of all first-hop routers.
+    pub(crate) fn first_hops(&self) -> impl Iterator<Item = Ipv4Addr> + '_ {
+        self.routers_at(1)
+    }
+
+    // Returns an iterator over the Router IDs of all second-hop routers.
+    pub(crate) fn second_hops(&self) -> impl Iterator<Item = Ipv4Addr> + '_ {
+        self.routers_at(2)
+    }
+
+    fn routers_at(&self, hops: u16) -> impl Iterator<Item = Ipv4Addr> + '_ {
+        self.vertices
+            .values()
+            .filter(move |vertex| vertex.hops == hops)
+            .filter_map(|vertex| vertex.router_id)
+    }
+}
+
 // ===== global functions =====
 
 pub(crate) fn flood<V>(
@@ -40,8 +266,17 @@ where
     match lsdb_idx {
         LsdbIndex::Link(area_idx, iface_idx) => {
             let area = &areas[area_idx];
+            let reflood_list =
+                area_reflood_list(area, instance, neighbors, lsa, src);
             flood_interface(
-                iface_idx, area, instance, interfaces, neighbors, lsa, src,
+                iface_idx,
+                area,
+                instance,
+                interfaces,
+                neighbors,
+                lsa,
+                src,
+                reflood_list.as_ref(),
             )
         }
         LsdbIndex::Area(area_idx) => {
@@ -54,6 +289,72 @@ where
     }
 }
 
+// Rebuilds the flooding reduction state of the area.
+//
+// For each fully adjacent neighbor, a hop count SPT rooted at that neighbor is
+// computed, along with the flooding algorithm advertised by each of the
+// neighbor's own neighbors.
+pub(crate) fn flooding_reduction_init<V>(
+    area: &mut Area<V>,
+    instance: &InstanceUpView<'_, V>,
+    interfaces: &Arena<Interface<V>>,
+    neighbors: &Arena<Neighbor<V>>,
+    lsa_entries: &Arena<LsaEntry<V>>,
+) where
+    V: Version,
+{
+    area.state.flooding_reduction = Default::default();
+    if area.config.flooding_algo != FloodingAlgo::ModifiedManet {
+        return;
+    }
+
+    // Process all full adjacencies on active interfaces.
+    let mut cache_list = BTreeMap::new();
+    for nbr in area
+        .interfaces
+        .indexes()
+        .map(|iface_idx| &interfaces[iface_idx])
+        .filter(|iface| !iface.is_down())
+        .flat_map(|iface| iface.state.neighbors.iter(neighbors))
+        .filter(|nbr| nbr.state == nsm::State::Full)
+    {
+        if cache_list.contains_key(&nbr.router_id) {
+            continue;
+        }
+
+        // Compute a hop count SPT rooted at this neighbor.
+        let spt_hopcount =
+            HopCountSpt::compute(area, instance, lsa_entries, nbr.router_id);
+
+        // Compute the remote neighbors list and each neighbor's advertised
+        // flooding algorithm.
+        let remote_nbr_list = spt_hopcount
+            .first_hops()
+            .map(|router_id| {
+                let router_info = V::area_router_information(
+                    &area.state.lsdb,
+                    router_id,
+                    lsa_entries,
+                );
+                let flood_algo =
+                    remote_flooding_algo(router_info.flooding_algo);
+                (router_id, flood_algo)
+            })
+            .collect();
+
+        cache_list.insert(
+            nbr.router_id,
+            NeighborCache {
+                spt_hopcount,
+                remote_nbr_list,
+            },
+        );
+    }
+
+    // Store the computed cache.
+    area.state.flooding_reduction.neighbors = cache_list;
+}
+
 // ===== helper functions =====
 
 fn flood_interface<V>(
@@ -64,6 +365,7 @@ fn flood_interface<V>(
     neighbors: &mut Arena<Neighbor<V>>,
     lsa: &Arc<Lsa<V>>,
     src: Option<(InterfaceIndex, NeighborIndex)>,
+    reflood_list: Option<&BTreeSet<Ipv4Addr>>,
 ) -> bool
 where
     V: Version,
@@ -138,6 +440,25 @@ where
             continue;
         }
 
+        // Flooding reduction: skip fully adjacent neighbors on point-to-point
+        // and point-to-multipoint links that aren't part of the reflood list.
+        //
+        // Broadcast and NBMA links are excluded since DROther routers rely on
+        // the DR to relay LSAs received from other routers in the network.
+        if let Some(reflood_list) = reflood_list
+            && nbr.state == nsm::State::Full
+            && matches!(
+                iface.config.if_type,
+                InterfaceType::PointToPoint | InterfaceType::PointToMultipoint
+            )
+            && !reflood_list.contains(&nbr.router_id)
+        {
+            if instance.config.trace_opts.flooding {
+                Debug::<V>::LsaFloodReduced(nbr.router_id, &lsa.hdr).log();
+            }
+            continue;
+        }
+
         // 1.d) Add LSA to the neighbor's rxmt list (or update the old version).
         nbr.lists.ls_rxmt.insert(lsa_key, lsa.clone());
         nbr.rxmt_lsupd_start_check(iface, area, instance);
@@ -193,10 +514,18 @@ fn flood_area<V>(
 where
     V: Version,
 {
+    let reflood_list = area_reflood_list(area, instance, neighbors, lsa, src);
     let mut flooded_back = false;
     for iface_idx in area.interfaces.indexes() {
         flooded_back |= flood_interface(
-            iface_idx, area, instance, interfaces, neighbors, lsa, src,
+            iface_idx,
+            area,
+            instance,
+            interfaces,
+            neighbors,
+            lsa,
+            src,
+            reflood_list.as_ref(),
         );
     }
 
@@ -233,3 +562,320 @@ where
 
     flooded_back
 }
+
+// Builds the flooding reduction reflood list of an LSA received from the given
+// neighbor, if flooding reduction is enabled in the area.
+fn area_reflood_list<V>(
+    area: &Area<V>,
+    instance: &InstanceUpView<'_, V>,
+    neighbors: &Arena<Neighbor<V>>,
+    lsa: &Lsa<V>,
+    src: Option<(InterfaceIndex, NeighborIndex)>,
+) -> Option<BTreeSet<Ipv4Addr>>
+where
+    V: Version,
+{
+    if area.config.flooding_algo != FloodingAlgo::ModifiedManet {
+        return None;
+    }
+
+    // Self-originated LSAs are always flooded out all eligible interfaces.
+    let (_, nbr_idx) = src?;
+    let tn = neighbors[nbr_idx].router_id;
+    reflood_list(
+        &area.state.flooding_reduction,
+        instance.state.router_id,
+        tn,
+        &lsa.hdr,
+    )
+}
+
+// Builds the Modified MANET reflood list for an LSA received from the
+// transmitting neighbor (TN).
+//
+// Returns `None` when no cached data is available for the TN, in which case
+// the LSA should be flooded as usual.
+fn reflood_list<V>(
+    flooding_reduction: &FloodingReduction<V>,
+    router_id: Ipv4Addr,
+    tn: Ipv4Addr,
+    lsa_hdr: &V::LsaHdr,
+) -> Option<BTreeSet<Ipv4Addr>>
+where
+    V: Version,
+{
+    let cache = flooding_reduction.neighbors.get(&tn)?;
+    if cache.remote_nbr_list.is_empty() {
+        return None;
+    }
+
+    // Build the Two-Hop List (THL).
+    let lsa_originator = lsa_hdr.adv_rtr();
+    let mut two_hop_list = cache
+        .spt_hopcount
+        .second_hops()
+        // Skip LSA originator.
+        .filter(|router_id| *router_id != lsa_originator)
+        // Skip nodes on the shortest path from the TN towards the LSA
+        // originator.
+        .filter(|router_id| {
+            !cache.spt_hopcount.is_on_path(*router_id, lsa_originator)
+        })
+        .collect::<BTreeSet<_>>();
+
+    // Calculate the flood reduction hash for the LSA.
+    let h = flood_reduction_hash::<V>(lsa_hdr);
+
+    // Set N to the H MOD of RNum (N=H MOD RNum)
+    let rnum = cache.remote_nbr_list.len();
+    let n = h as usize % rnum;
+
+    // Iterate over the RNL in circular order beginning at index N.
+    let mut reflood_list = BTreeSet::default();
+    for (rnl, rnl_algo) in
+        cache.remote_nbr_list.iter().cycle().skip(n).take(rnum)
+    {
+        // Stop when the THL is empty.
+        if two_hop_list.is_empty() {
+            break;
+        }
+
+        // If the current RNL entry is the local router, reflood to all
+        // remaining THL members that are adjacent to it.
+        if *rnl == router_id {
+            for thl_node in two_hop_list {
+                if cache.spt_hopcount.is_on_path(*rnl, thl_node) {
+                    reflood_list.insert(thl_node);
+                }
+            }
+            break;
+        }
+
+        // Skip RNL members that are not using Modified MANET.
+        if *rnl_algo != FloodingAlgo::ModifiedManet {
+            continue;
+        }
+
+        // Remove from the THL any nodes connected to this RNL member.
+        two_hop_list
+            .retain(|thl_node| !cache.spt_hopcount.is_on_path(*rnl, *thl_node));
+    }
+
+    Some(reflood_list)
+}
+
+// Returns the flooding algorithm advertised by a remote neighbor.
+//
+// Neighbors that don't advertise a known flooding algorithm are assumed not to
+// perform flooding reduction.
+fn remote_flooding_algo(tlv: Option<&FloodingAlgoTlv>) -> FloodingAlgo {
+    tlv.and_then(|tlv| FloodingAlgo::from_u8(tlv.get()))
+        .unwrap_or(FloodingAlgo::ZeroPruner)
+}
+
+// Computes the flood reduction hash over the Link State ID and Advertising
+// Router of the LSA.
+fn flood_reduction_hash<V>(lsa_hdr: &V::LsaHdr) -> u16
+where
+    V: Version,
+{
+    let mut bytes = [0; 8];
+    bytes[..4].copy_from_slice(&lsa_hdr.lsa_id().octets());
+    bytes[4..].copy_from_slice(&lsa_hdr.adv_rtr().octets());
+    fletcher::calc_fletcher16(&bytes)
+}
+
+// ===== unit tests =====
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ospfv2::packet::iana::{LsaTypeCode, Options};
+    use crate::ospfv2::packet::lsa::LsaHdr;
+    use crate::ospfv2::spf::VertexId;
+    use crate::version::Ospfv2;
+
+    const RT1: Ipv4Addr = Ipv4Addr::new(1, 1, 1, 1);
+    const RT2: Ipv4Addr = Ipv4Addr::new(2, 2, 2, 2);
+    const RT3: Ipv4Addr = Ipv4Addr::new(3, 3, 3, 3);
+    const RT4: Ipv4Addr = Ipv4Addr::new(4, 4, 4, 4);
+    const RT5: Ipv4Addr = Ipv4Addr::new(5, 5, 5, 5);
+    const RT6: Ipv4Addr = Ipv4Addr::new(6, 6, 6, 6);
+    const RT7: Ipv4Addr = Ipv4Addr::new(7, 7, 7, 7);
+    const RT9: Ipv4Addr = Ipv4Addr::new(9, 9, 9, 9);
+
+    // Builds a hop count SPT from a list of routers, their hop counts and
+    // their parents.
+    fn hopcount_spt(
+        routers: &[(Ipv4Addr, u16, &[Ipv4Addr])],
+    ) -> HopCountSpt<Ospfv2> {
+        let vertices = routers
+            .iter()
+            .map(|(router_id, hops, parents)| {
+                let vertex = HopCountVertex {
+                    router_id: Some(*router_id),
+                    hops: *hops,
+                    parents: parents
+                        .iter()
+                        .map(|parent| VertexId::new_root(*parent))
+                        .collect(),
+                };
+                (VertexId::new_root(*router_id), vertex)
+            })
+            .collect();
+        HopCountSpt { vertices }
+    }
+
+    // Hop count SPT rooted at rt2, where rt1 is the local router:
+    //
+    //            rt2
+    //         /   |   \
+    //      rt1   rt3   rt4
+    //        \   / \   / \
+    //         rt6   rt5   rt7
+    fn test_spt() -> HopCountSpt<Ospfv2> {
+        hopcount_spt(&[
+            (RT2, 0, &[]),
+            (RT1, 1, &[RT2]),
+            (RT3, 1, &[RT2]),
+            (RT4, 1, &[RT2]),
+            (RT5, 2, &[RT3, RT4]),
+            (RT6, 2, &[RT1, RT3]),
+            (RT7, 2, &[RT4]),
+        ])
+    }
+
+    fn test_flooding_reduction() -> FloodingReduction<Ospfv2> {
+        let cache = NeighborCache {
+            spt_hopcount: test_spt(),
+            remote_nbr_list: BTreeMap::from([
+                (RT1, FloodingAlgo::ModifiedManet),
+                (RT3, FloodingAlgo::ModifiedManet),
+                (RT4, FloodingAlgo::ZeroPruner),
+            ]),
+        };
+        FloodingReduction {
+            neighbors: BTreeMap::from([(RT2, cache)]),
+        }
+    }
+
+    // Returns the header of a Router-LSA originated by `adv_rtr` whose flood
+    // reduction hash selects the given starting index in a RNL with `rnum`
+    // entries.
+    fn router_lsa_hdr(adv_rtr: Ipv4Addr, rnum: usize, start: usize) -> LsaHdr {
+        (0..=u8::MAX)
+            .map(|i| {
+                LsaHdr::new(
+                    0,
+                    Some(Options::E),
+                    LsaTypeCode::Router.into(),
+                    Ipv4Addr::new(10, 0, i, 0),
+                    adv_rtr,
+                    0,
+                )
+            })
+            .find(|lsa_hdr| {
+                flood_reduction_hash::<Ospfv2>(lsa_hdr) as usize % rnum == start
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn two_hop_coverage() {
+        let spt = test_spt();
+
+        assert_eq!(
+            spt.first_hops().collect::<BTreeSet<_>>(),
+            BTreeSet::from([RT1, RT3, RT4])
+        );
+        assert_eq!(
+            spt.second_hops().collect::<BTreeSet<_>>(),
+            BTreeSet::from([RT5, RT6, RT7])
+        );
+
+        // Two-hop nodes reachable over multiple equal-hop paths are covered
+        // by all of their parents.
+        assert!(spt.is_on_path(RT3, RT5));
+        assert!(spt.is_on_path(RT4, RT5));
+        assert!(spt.is_on_path(RT1, RT6));
+        assert!(spt.is_on_path(RT3, RT6));
+        assert!(spt.is_on_path(RT2, RT7));
+        assert!(!spt.is_on_path(RT1, RT5));
+        assert!(!spt.is_on_path(RT3, RT7));
+
+        // Routers outside the SPT aren't on any path.
+        assert!(!spt.is_on_path(RT9, RT5));
+        assert!(!spt.is_on_path(RT3, RT9));
+    }
+
+    #[test]
+    fn reflood_list_relay_selection() {
+        let flooding_reduction = test_flooding_reduction();
+
+        // Starting at the local router: reflood to the two-hop nodes
+        // adjacent to it.
+        let lsa_hdr = router_lsa_hdr(RT9, 3, 0);
+        assert_eq!(
+            reflood_list(&flooding_reduction, RT1, RT2, &lsa_hdr),
+            Some(BTreeSet::from([RT6]))
+        );
+
+        // Starting at rt3: rt3 covers rt5 and rt6, rt4 is skipped since it
+        // doesn't use Modified MANET, and the remaining rt7 isn't adjacent to
+        // the local router.
+        let lsa_hdr = router_lsa_hdr(RT9, 3, 1);
+        assert_eq!(
+            reflood_list(&flooding_reduction, RT1, RT2, &lsa_hdr),
+            Some(BTreeSet::new())
+        );
+
+        // Starting at rt4: rt4 is skipped, then the local router relays to
+        // the two-hop nodes adjacent to it.
+        let lsa_hdr = router_lsa_hdr(RT9, 3, 2);
+        assert_eq!(
+            reflood_list(&flooding_reduction, RT1, RT2, &lsa_hdr),
+            Some(BTreeSet::from([RT6]))
+        );
+
+        // The LSA originator is never part of the two-hop list.
+        let lsa_hdr = router_lsa_hdr(RT6, 3, 0);
+        assert_eq!(
+            reflood_list(&flooding_reduction, RT1, RT2, &lsa_hdr),
+            Some(BTreeSet::new())
+        );
+    }
+
+    #[test]
+    fn reflood_list_new_neighbor() {
+        let lsa_hdr = router_lsa_hdr(RT9, 3, 0);
+
+        // Neighbors that came up after the last SPF run have no cached data,
+        // hence LSAs received from them are flooded as usual.
+        let flooding_reduction = test_flooding_reduction();
+        assert_eq!(reflood_list(&flooding_reduction, RT1, RT3, &lsa_hdr), None);
+        let flooding_reduction = FloodingReduction::<Ospfv2>::default();
+        assert_eq!(reflood_list(&flooding_reduction, RT1, RT2, &lsa_hdr), None);
+
+        // Same for neighbors without a remote neighbor list.
+        let mut flooding_reduction = test_flooding_reduction();
+        flooding_reduction
+            .neighbors
+            .get_mut(&RT2)
+            .unwrap()
+            .remote_nbr_list
+            .clear();
+        assert_eq!(reflood_list(&flooding_reduction, RT1, RT2, &lsa_hdr), None);
+
+        // Remote neighbors not advertising a known flooding algorithm don't
+        // perform flooding reduction.
+        assert_eq!(remote_flooding_algo(None), FloodingAlgo::ZeroPruner);
+        assert_eq!(
+            remote_flooding_algo(Some(&FloodingAlgoTlv::new(2))),
+            FloodingAlgo::ModifiedManet
+        );
+        assert_eq!(
+            remote_flooding_algo(Some(&FloodingAlgoTlv::new(255))),
+            FloodingAlgo::ZeroPruner
+        );
+    }
+}
@@ -4,19 +4,47 @@
 // SPDX-License-Identifier: MIT
 //
 
+use std::collections::BTreeSet;
+use std::net::Ipv4Addr;
 use std::time::Duration;
 
-use crate::area::Area;
-use crate::debug::{Debug, GrRejectReason};
-use crate::instance::{InstanceArenas, InstanceUpView};
+use chrono::{TimeDelta, Utc};
+use holo_utils::gr::{
+    restart_deadline_clear, restart_deadline_get, restart_deadline_set,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::area::{self, Area};
+use crate::collections::{AreaId, InterfaceId, LsdbId, LsdbIndex};
+use crate::debug::{Debug, GrRejectReason, LsaFlushReason};
+use crate::error::Error;
+use crate::instance::{InstanceArenas, InstanceGrRestart, InstanceUpView};
 use crate::interface::{Interface, ism};
-use crate::lsdb::LsaOriginateEvent;
+use crate::lsdb::{self, LsaEntryFlags, LsaOriginateEvent};
 use crate::neighbor::{Neighbor, NeighborGrHelper, nsm};
 use crate::northbound::notification;
-use crate::packet::lsa::{LsaHdrVersion, LsaTypeVersion};
+use crate::packet::lsa::{LsaBodyVersion, LsaHdrVersion, LsaTypeVersion};
 use crate::packet::tlv::GrReason;
-use crate::tasks;
 use crate::version::Version;
+use crate::{ibus, spf, tasks};
+
+// Set of neighbors that were fully adjacent before a graceful restart.
+//
+// Neighbors are identified by their area ID, interface name and Router ID,
+// which remain stable across restarts.
+pub type PreRestartNbrs = BTreeSet<(Ipv4Addr, String, Ipv4Addr)>;
+
+// Graceful restart state stored in non-volatile memory, so that a restart of
+// the daemon itself can be handled as well.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GrRestartNvm {
+    // Graceful restart interval.
+    pub restart_interval: u16,
+    // Graceful restart reason advertised in the Grace-LSAs.
+    pub reason: GrReason,
+    // Neighbors that were fully adjacent before the restart.
+    pub pre_restart_nbrs: PreRestartNbrs,
+}
 
 // OSPF Graceful Restart exit reason.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -202,8 +230,333 @@ pub(crate) fn helper_exit<V>(
     instance.state.gr_helper_count -= 1;
 }
 
+// Prepares the instance for a planned graceful restart.
+//
+// Grace-LSAs are originated on all active interfaces, and the state needed to
+// enter the graceful restart mode is saved in non-volatile memory.
+pub(crate) fn restart_prepare<V>(
+    instance: &mut InstanceUpView<'_, V>,
+    arenas: &mut InstanceArenas<V>,
+) -> GrRestartNvm
+where
+    V: Version,
+{
+    let restart_interval = instance.config.gr.restart_interval;
+    let reason = GrReason::SoftwareRestart;
+    let mut pre_restart_nbrs = BTreeSet::new();
+
+    for area_idx in arenas.areas.indexes().collect::<Vec<_>>() {
+        let area_id = arenas.areas[area_idx].area_id;
+        let iface_idxs = arenas.areas[area_idx]
+            .interfaces
+            .indexes()
+            .collect::<Vec<_>>();
+        for iface_idx in iface_idxs {
+            let area = &arenas.areas[area_idx];
+            let iface = &arenas.interfaces[iface_idx];
+            if iface.is_down() || iface.is_passive() || iface.is_virtual_link()
+            {
+                continue;
+            }
+
+            // Keep track of the fully adjacent neighbors.
+            pre_restart_nbrs.extend(
+                iface
+                    .state
+                    .neighbors
+                    .iter(&arenas.neighbors)
+                    .filter(|nbr| nbr.state == nsm::State::Full)
+                    .map(|nbr| (area_id, iface.name.clone(), nbr.router_id)),
+            );
+
+            // Originate Grace-LSA.
+            let (options, lsa_id, lsa_body) =
+                V::lsa_grace(iface, area, restart_interval.into(), reason);
+            let lsdb_idx = LsdbIndex::Link(area_idx, iface_idx);
+            lsdb::originate_check(
+                instance, arenas, lsdb_idx, options, lsa_id, lsa_body,
+            );
+        }
+    }
+
+    // Save the graceful restart state in non-volatile memory.
+    let record = GrRestartNvm {
+        restart_interval,
+        reason,
+        pre_restart_nbrs,
+    };
+    restart_nvm_store(instance, &record);
+
+    record
+}
+
+// Resumes a graceful restart that was in progress when the instance was
+// stopped, as long as its grace period hasn't expired yet.
+pub(crate) fn restart_resume<V>(
+    instance: &mut InstanceUpView<'_, V>,
+    arenas: &mut InstanceArenas<V>,
+) where
+    V: Version,
+{
+    if let Some((record, remaining)) = restart_nvm_load(instance) {
+        restart_enter(instance, arenas, record, remaining);
+    }
+}
+
+// Enters the graceful restart mode after the instance has been restarted.
+pub(crate) fn restart_enter<V>(
+    instance: &mut InstanceUpView<'_, V>,
+    arenas: &mut InstanceArenas<V>,
+    record: GrRestartNvm,
+    restart_interval: u16,
+) where
+    V: Version,
+{
+    if instance.config.trace_opts.gr {
+        Debug::<V>::GrRestartEnter(restart_interval).log();
+    }
+    let status = match record.reason {
+        GrReason::SoftwareRestart | GrReason::SoftwareUpgrade => {
+            "planned-restart"
+        }
+        GrReason::Unknown | GrReason::ControlProcessorSwitchover => {
+            "unplanned-restart"
+        }
+    };
+    notification::restart_status_change(
+        instance,
+        status,
+        Some(restart_interval),
+        None,
+    );
+
+    // The pre-restart routes remain installed in the global RIB until the
+    // graceful restart is complete. Those that aren't refreshed by then are
+    // removed.
+    ibus::tx::route_stale_mark::<V>(&instance.tx.ibus);
+
+    // Start the restart interval timeout.
+    let restart_interval =
+        tasks::restart_interval_timer(instance, restart_interval);
+    instance.state.gr_restart = Some(InstanceGrRestart {
+        restart_interval,
+        pre_restart_nbrs: record.pre_restart_nbrs,
+    });
+
+    // Exit right away if there are no adjacencies to reestablish.
+    restart_check_completion(instance, arenas);
+}
+
+// Exits from the graceful restart mode if all pre-restart adjacencies have
+// been reestablished.
+pub(crate) fn restart_check_completion<V>(
+    instance: &mut InstanceUpView<'_, V>,
+    arenas: &mut InstanceArenas<V>,
+) where
+    V: Version,
+{
+    let Some(gr) = &instance.state.gr_restart else {
+        return;
+    };
+
+    // Adjacencies over interfaces that no longer exist aren't waited for.
+    let completed =
+        gr.pre_restart_nbrs
+            .iter()
+            .all(|(area_id, ifname, router_id)| {
+                let Some((_, area)) = arenas.areas.get_by_area_id(*area_id)
+                else {
+                    return true;
+                };
+                let Some((_, iface)) =
+                    area.interfaces.get_by_name(&arenas.interfaces, ifname)
+                else {
+                    return true;
+                };
+                iface
+                    .state
+                    .neighbors
+                    .get_by_router_id(&arenas.neighbors, *router_id)
+                    .is_some_and(|(_, nbr)| nbr.state == nsm::State::Full)
+            });
+    if completed {
+        restart_exit(instance, arenas, GrExitReason::Completed);
+    }
+}
+
+// Exits from the graceful restart mode if an interface with pre-restart
+// adjacencies went down, as that indicates a topology change.
+pub(crate) fn restart_check_topology_change<V>(
+    instance: &mut InstanceUpView<'_, V>,
+    arenas: &mut InstanceArenas<V>,
+    area_id: AreaId,
+    iface_id: InterfaceId,
+) where
+    V: Version,
+{
+    let Some(gr) = &instance.state.gr_restart else {
+        return;
+    };
+
+    let Ok((_, area)) = arenas.areas.get_by_id(area_id) else {
+        return;
+    };
+    let Ok((_, iface)) =
+        area.interfaces.get_by_id(&arenas.interfaces, iface_id)
+    else {
+        return;
+    };
+    if iface.is_down()
+        && gr
+            .pre_restart_nbrs
+            .iter()
+            .any(|(nbr_area_id, nbr_ifname, _)| {
+                *nbr_area_id == area.area_id && *nbr_ifname == iface.name
+            })
+    {
+        restart_exit(instance, arenas, GrExitReason::TopologyChanged);
+    }
+}
+
+// Exits from the graceful restart mode.
+pub(crate) fn restart_exit<V>(
+    instance: &mut InstanceUpView<'_, V>,
+    arenas: &mut InstanceArenas<V>,
+    reason: GrExitReason,
+) where
+    V: Version,
+{
+    if instance.config.trace_opts.gr {
+        Debug::<V>::GrRestartExit(reason).log();
+    }
+    notification::restart_status_change(
+        instance,
+        "not-restarting",
+        None,
+        Some(reason),
+    );
+
+    // Stop the restart interval timeout.
+    instance.state.gr_restart = None;
+    restart_nvm_clear(instance);
+
+    // Sweep the pre-restart routes that weren't refreshed once the routing
+    // table is recalculated.
+    instance.state.gr_stale_sweep = true;
+
+    // Reoriginate all self-originated LSAs.
+    instance
+        .tx
+        .protocol_input
+        .lsa_orig_event(LsaOriginateEvent::GrRestartExit);
+    area::update_externals(
+        instance,
+        &mut arenas.areas,
+        &arenas.interfaces,
+        &arenas.lsa_entries,
+    );
+
+    // Flush the Grace-LSAs.
+    for area in arenas.areas.iter() {
+        for iface in area.interfaces.iter(&arenas.interfaces) {
+            for (_, lse) in iface
+                .state
+                .lsdb
+                .iter(&arenas.lsa_entries)
+                .filter(|(_, lse)| {
+                    lse.flags.contains(LsaEntryFlags::SELF_ORIGINATED)
+                })
+                .filter(|(_, lse)| lse.data.body.as_grace().is_some())
+            {
+                instance.tx.protocol_input.lsa_flush(
+                    LsdbId::Link(area.id, iface.id),
+                    lse.id,
+                    LsaFlushReason::PrematureAging,
+                );
+            }
+        }
+    }
+
+    // Run a full SPF to reconcile the pre-restart routes with the new routing
+    // table.
+    instance
+        .tx
+        .protocol_input
+        .spf_delay_event(spf::fsm::Event::ConfigChange);
+}
+
+// Removes the graceful restart state from non-volatile memory.
+pub(crate) fn restart_nvm_clear<V>(instance: &InstanceUpView<'_, V>)
+where
+    V: Version,
+{
+    let Some(db) = &instance.shared.db else {
+        return;
+    };
+    let mut db = db.lock().unwrap();
+
+    let key = restart_nvm_key::<V>(instance.name);
+    if let Err(error) = db.rem(&key).and_then(|_| {
+        restart_deadline_clear(&mut db, V::PROTOCOL, instance.name)
+    }) {
+        Error::<V>::GrRestartNvmUpdate(error).log();
+    }
+}
+
 // ===== helper functions =====
 
+fn restart_nvm_store<V>(instance: &InstanceUpView<'_, V>, record: &GrRestartNvm)
+where
+    V: Version,
+{
+    let Some(db) = &instance.shared.db else {
+        return;
+    };
+    let mut db = db.lock().unwrap();
+
+    let key = restart_nvm_key::<V>(instance.name);
+    let deadline =
+        Utc::now() + TimeDelta::seconds(record.restart_interval.into());
+    if let Err(error) = db.set(&key, record).and_then(|_| {
+        restart_deadline_set(&mut db, V::PROTOCOL, instance.name, deadline)
+    }) {
+        Error::<V>::GrRestartNvmUpdate(error).log();
+    }
+}
+
+// Loads the graceful restart state from non-volatile memory, along with the
+// number of seconds left before the restart interval expires.
+fn restart_nvm_load<V>(
+    instance: &InstanceUpView<'_, V>,
+) -> Option<(GrRestartNvm, u16)>
+where
+    V: Version,
+{
+    let db = instance.shared.db.as_ref()?.lock().unwrap();
+
+    let key = restart_nvm_key::<V>(instance.name);
+    let record = db.get::<GrRestartNvm>(&key)?;
+    let remaining = restart_deadline_get(&db, V::PROTOCOL, instance.name)
+        .map(|deadline| (deadline - Utc::now()).num_seconds())
+        .filter(|remaining| *remaining > 0)
+        .and_then(|remaining| u16::try_from(remaining).ok());
+    drop(db);
+
+    // Discard the state of an expired graceful restart.
+    if remaining.is_none() {
+        restart_nvm_clear(instance);
+    }
+
+    remaining.map(|remaining| (record, remaining))
+}
+
+fn restart_nvm_key<V>(name: &str) -> String
+where
+    V: Version,
+{
+    format!("{}-{}-gr-restart", V::PROTOCOL, name)
+}
+
 fn helper_enter<V>(
     nbr: &mut Neighbor<V>,
     iface: &Interface<V>,
@@ -9,15 +9,19 @@ use std::net::Ipv4Addr;
 use holo_utils::bfd;
 use holo_utils::bier::BierCfgEvent;
 use holo_utils::ip::IpNetworkKind;
-use holo_utils::southbound::{AddressFlags, AddressMsg, InterfaceUpdateMsg};
+use holo_utils::southbound::{
+    AddressFlags, AddressMsg, InterfaceUpdateMsg, RouteKeyMsg, RouteMsg,
+};
 use holo_utils::sr::SrCfgEvent;
 
 use crate::error::Error;
-use crate::instance::Instance;
-use crate::interface::Interface;
+use crate::instance::{Instance, InstanceUpView};
+use crate::interface::{self, Interface};
 use crate::lsdb::LsaOriginateEvent;
 use crate::neighbor::nsm;
+use crate::route::RouteSys;
 use crate::version::Version;
+use crate::{area, tasks};
 
 // OSPF version-specific code.
 pub trait IbusRxVersion<V: Version> {
@@ -42,6 +46,29 @@ pub(crate) fn process_router_id_update<V>(
     instance.update();
 }
 
+pub(crate) fn process_ldp_sync_update<V>(
+    instance: &mut Instance<V>,
+    ifname: String,
+    synced: Option<bool>,
+) where
+    V: Version,
+{
+    // Update the local copy of the LDP-IGP synchronization state.
+    match synced {
+        Some(synced) => {
+            instance.system.ldp_sync.insert(ifname, synced);
+        }
+        None => {
+            instance.system.ldp_sync.remove(&ifname);
+        }
+    }
+
+    // Update the synchronization state of all interfaces.
+    if let Some((instance, arenas)) = instance.as_up() {
+        interface::ldp_sync_update_all(&instance, arenas);
+    }
+}
+
 pub(crate) fn process_iface_update<V>(
     instance: &mut Instance<V>,
     msg: InterfaceUpdateMsg,
@@ -77,6 +104,10 @@ pub(crate) fn process_iface_update<V>(
         &mut arenas.neighbors,
         &arenas.lsa_entries,
     );
+    // Synchronize the multi-area adjacencies bound to this interface.
+    if !arenas.interfaces[iface_idx].config.multi_areas.is_empty() {
+        interface::update_multi_area_adjs(&mut instance, arenas);
+    }
 }
 
 pub(crate) fn process_addr_add<V>(instance: &mut Instance<V>, msg: AddressMsg)
@@ -134,6 +165,10 @@ where
         &mut arenas.neighbors,
         &arenas.lsa_entries,
     );
+    // Synchronize the multi-area adjacencies bound to this interface.
+    if !arenas.interfaces[iface_idx].config.multi_areas.is_empty() {
+        interface::update_multi_area_adjs(&mut instance, arenas);
+    }
 }
 
 pub(crate) fn process_addr_del<V>(instance: &mut Instance<V>, msg: AddressMsg)
@@ -187,6 +222,10 @@ where
         &mut arenas.neighbors,
         &arenas.lsa_entries,
     );
+    // Synchronize the multi-area adjacencies bound to this interface.
+    if !arenas.interfaces[iface_idx].config.multi_areas.is_empty() {
+        interface::update_multi_area_adjs(&mut instance, arenas);
+    }
 }
 
 pub(crate) fn process_sr_cfg_change<V>(
@@ -321,3 +360,89 @@ where
 
     Ok(())
 }
+
+pub(crate) fn process_route_add<V>(instance: &mut Instance<V>, msg: RouteMsg)
+where
+    V: Version,
+{
+    let prefix = msg.prefix;
+    if !prefix.is_routable() {
+        return;
+    }
+
+    // Ignore routes from protocols that aren't being redistributed. The
+    // default route is also tracked for conditional default route
+    // origination.
+    let default_route = prefix.prefix() == 0
+        && instance.config.default_info_originate.is_some();
+    if !default_route
+        && !instance.config.redistribution.contains_key(&msg.protocol)
+    {
+        return;
+    }
+
+    // Add route to the table of redistributed routes.
+    let route = RouteSys {
+        protocol: msg.protocol,
+        metric: msg.metric,
+        tag: msg.tag,
+        nexthops: msg.nexthops,
+    };
+    instance.system.routes.insert(prefix, route);
+
+    // Schedule update of AS-external LSAs.
+    if let Some((mut instance, _)) = instance.as_up() {
+        externals_update_schedule(&mut instance);
+    }
+}
+
+pub(crate) fn process_route_del<V>(instance: &mut Instance<V>, msg: RouteKeyMsg)
+where
+    V: Version,
+{
+    // Remove route from the table of redistributed routes. The default route
+    // is withdrawn regardless of the protocol, as it's also reported as
+    // deleted once this instance's own default route becomes the best one.
+    let routes = &mut instance.system.routes;
+    if routes.get(&msg.prefix).is_none_or(|route| {
+        route.protocol != msg.protocol && msg.prefix.prefix() != 0
+    }) {
+        return;
+    }
+    routes.remove(&msg.prefix);
+
+    // Schedule update of AS-external LSAs.
+    if let Some((mut instance, _)) = instance.as_up() {
+        externals_update_schedule(&mut instance);
+    }
+}
+
+pub(crate) fn process_policy_update<V>(instance: &mut Instance<V>)
+where
+    V: Version,
+{
+    // Reevaluate the redistributed routes, which might be subject to the
+    // updated routing policies.
+    if let Some((mut instance, arenas)) = instance.as_up() {
+        area::update_externals(
+            &mut instance,
+            &mut arenas.areas,
+            &arenas.interfaces,
+            &arenas.lsa_entries,
+        );
+    }
+}
+
+// ===== helper functions =====
+
+// Schedules the reevaluation of the redistributed routes, coalescing route
+// updates received in quick succession.
+fn externals_update_schedule<V>(instance: &mut InstanceUpView<'_, V>)
+where
+    V: Version,
+{
+    if instance.state.externals_update_timer.is_none() {
+        let task = tasks::externals_update_timer(instance);
+        instance.state.externals_update_timer = Some(task);
+    }
+}
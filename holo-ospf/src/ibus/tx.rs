@@ -29,6 +29,10 @@ pub(crate) fn hostname_sub(ibus_tx: &IbusChannelsTx) {
     ibus_tx.hostname_sub();
 }
 
+pub(crate) fn ldp_sync_sub(ibus_tx: &IbusChannelsTx) {
+    ibus_tx.ldp_sync_sub();
+}
+
 pub(crate) fn route_install<V>(
     ibus_tx: &IbusChannelsTx,
     destination: &V::IpNetwork,
@@ -40,29 +44,7 @@ pub(crate) fn route_install<V>(
     V: Version,
 {
     // Fill-in nexthops.
-    let nexthops = route
-        .nexthops
-        .values()
-        .map(|nexthop| match nexthop.addr {
-            Some(addr) => {
-                let iface = &interfaces[nexthop.iface_idx];
-                Nexthop::Address {
-                    ifindex: iface.system.ifindex.unwrap(),
-                    addr: <V::IpAddr as Into<IpAddr>>::into(addr),
-                    labels: nexthop
-                        .sr_label
-                        .map(|label| vec![label])
-                        .unwrap_or_default(),
-                }
-            }
-            None => {
-                let iface = &interfaces[nexthop.iface_idx];
-                Nexthop::Interface {
-                    ifindex: iface.system.ifindex.unwrap(),
-                }
-            }
-        })
-        .collect::<BTreeSet<_>>();
+    let nexthops = route_nexthops(route, interfaces);
 
     // Install route.
     let msg = RouteMsg {
@@ -76,6 +58,7 @@ pub(crate) fn route_install<V>(
             route_type: route.path_type,
         },
         nexthops: nexthops.clone(),
+        backup_nexthops: route_backup_nexthops(route, interfaces),
     };
     ibus_tx.route_ip_add(msg);
 
@@ -153,6 +136,49 @@ pub(crate) fn route_uninstall<V>(
     }
 }
 
+pub(crate) fn flex_algo_label_install<V>(
+    ibus_tx: &IbusChannelsTx,
+    route: &RouteNet<V>,
+    old_sr_label: Option<Label>,
+    interfaces: &Arena<Interface<V>>,
+) where
+    V: Version,
+{
+    // Uninstall previous SR Prefix-SID input label if it has changed.
+    if old_sr_label != route.sr_label
+        && let Some(old_sr_label) = old_sr_label
+    {
+        flex_algo_label_uninstall::<V>(ibus_tx, old_sr_label);
+    }
+
+    // Install SR Prefix-SID input label.
+    if let Some(sr_label) = route.sr_label {
+        let msg = LabelInstallMsg {
+            protocol: V::PROTOCOL,
+            label: sr_label,
+            nexthops: route_nexthops(route, interfaces),
+            route: None,
+            replace: true,
+        };
+        ibus_tx.route_mpls_add(msg);
+    }
+}
+
+pub(crate) fn flex_algo_label_uninstall<V>(
+    ibus_tx: &IbusChannelsTx,
+    label: Label,
+) where
+    V: Version,
+{
+    let msg = LabelUninstallMsg {
+        protocol: V::PROTOCOL,
+        label,
+        nexthops: BTreeSet::new(),
+        route: None,
+    };
+    ibus_tx.route_mpls_del(msg);
+}
+
 pub(crate) fn adj_sid_install<V>(
     ibus_tx: &IbusChannelsTx,
     iface: &Interface<V>,
@@ -188,3 +214,75 @@ where
     };
     ibus_tx.route_mpls_del(msg);
 }
+
+pub(crate) fn route_stale_mark<V>(ibus_tx: &IbusChannelsTx)
+where
+    V: Version,
+{
+    ibus_tx.route_stale_mark(V::PROTOCOL);
+}
+
+pub(crate) fn route_stale_sweep<V>(ibus_tx: &IbusChannelsTx)
+where
+    V: Version,
+{
+    ibus_tx.route_stale_sweep(V::PROTOCOL);
+}
+
+// ===== helper functions =====
+
+fn route_nexthops<V>(
+    route: &RouteNet<V>,
+    interfaces: &Arena<Interface<V>>,
+) -> BTreeSet<Nexthop>
+where
+    V: Version,
+{
+    route
+        .nexthops
+        .values()
+        .map(|nexthop| match nexthop.addr {
+            Some(addr) => {
+                let iface = &interfaces[nexthop.iface_idx];
+                Nexthop::Address {
+                    ifindex: iface.system.ifindex.unwrap(),
+                    addr: <V::IpAddr as Into<IpAddr>>::into(addr),
+                    labels: nexthop
+                        .sr_label
+                        .map(|label| vec![label])
+                        .unwrap_or_default(),
+                }
+            }
+            None => {
+                let iface = &interfaces[nexthop.iface_idx];
+                Nexthop::Interface {
+                    ifindex: iface.system.ifindex.unwrap(),
+                }
+            }
+        })
+        .collect()
+}
+
+fn route_backup_nexthops<V>(
+    route: &RouteNet<V>,
+    interfaces: &Arena<Interface<V>>,
+) -> BTreeSet<Nexthop>
+where
+    V: Version,
+{
+    let Some(backup) = &route.backup else {
+        return BTreeSet::new();
+    };
+
+    let iface = &interfaces[backup.nexthop.iface_idx];
+    let ifindex = iface.system.ifindex.unwrap();
+    let nexthop = match backup.nexthop.addr {
+        Some(addr) => Nexthop::Address {
+            ifindex,
+            addr: <V::IpAddr as Into<IpAddr>>::into(addr),
+            labels: backup.labels.clone(),
+        },
+        None => Nexthop::Interface { ifindex },
+    };
+    [nexthop].into()
+}
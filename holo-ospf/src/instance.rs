@@ -7,7 +7,6 @@
 use std::collections::{BTreeMap, VecDeque};
 use std::net::Ipv4Addr;
 use std::sync::Arc;
-use std::sync::atomic::AtomicU64;
 use std::time::Instant;
 
 use chrono::{DateTime, Utc};
@@ -18,6 +17,7 @@ use holo_utils::ibus::IbusMsg;
 use holo_utils::ip::AddressFamily;
 use holo_utils::protocol::Protocol;
 use holo_utils::task::TimeoutTask;
+use ipnetwork::IpNetwork;
 use tokio::sync::mpsc;
 use tokio::sync::mpsc::{Receiver, Sender, UnboundedReceiver, UnboundedSender};
 
@@ -28,22 +28,25 @@ use crate::debug::{
     Debug, InstanceInactiveReason, InterfaceInactiveReason, LsaFlushReason,
 };
 use crate::error::Error;
-use crate::interface::{Interface, ism};
+use crate::gr::PreRestartNbrs;
+use crate::interface::{self, Interface, ism};
 use crate::lsdb::{LsaEntry, LsaLogEntry, LsaOriginateEvent};
 use crate::neighbor::{Neighbor, nsm};
 use crate::northbound::configuration::InstanceCfg;
 use crate::northbound::notification;
-use crate::route::{RouteNet, RouteNetFlags};
+use crate::packet::auth::AuthSeqNo;
+use crate::route::{ExternalNet, RouteNet, RouteNetFlags, RouteSys};
 use crate::spf::{SpfLogEntry, SpfTriggerLsa};
 use crate::tasks::messages::input::{
-    DbDescFreeMsg, DelayedAckMsg, GracePeriodMsg, IsmEventMsg, LsaFlushMsg,
-    LsaOrigCheckMsg, LsaOrigDelayedMsg, LsaOrigEventMsg, LsaRefreshMsg,
-    LsdbMaxAgeSweepMsg, NetRxPacketMsg, NsmEventMsg, RxmtIntervalMsg,
-    SendLsUpdateMsg, SpfDelayEventMsg,
+    DbDescFreeMsg, DelayedAckMsg, ExternalsUpdateMsg, GracePeriodMsg,
+    IsmEventMsg, LdpSyncHolddownMsg, LsaFlushMsg, LsaOrigCheckMsg,
+    LsaOrigDelayedMsg, LsaOrigEventMsg, LsaRefreshMsg, LsdbMaxAgeSweepMsg,
+    NetRxPacketMsg, NsmEventMsg, NssaTranslatorStabilityMsg,
+    RestartIntervalMsg, RxmtIntervalMsg, SendLsUpdateMsg, SpfDelayEventMsg,
 };
 use crate::tasks::messages::{ProtocolInputMsg, ProtocolOutputMsg};
 use crate::version::Version;
-use crate::{events, ibus, lsdb, output, spf};
+use crate::{area, events, gr, ibus, lsdb, output, spf};
 
 pub struct Instance<V: Version> {
     // Instance name.
@@ -65,6 +68,10 @@ pub struct Instance<V: Version> {
 #[derive(Debug, Default)]
 pub struct InstanceSys {
     pub router_id: Option<Ipv4Addr>,
+    // LDP-IGP synchronization state of the LDP-enabled interfaces.
+    pub ldp_sync: BTreeMap<String, bool>,
+    // Redistributed routes.
+    pub routes: BTreeMap<IpNetwork, RouteSys>,
 }
 
 #[derive(Debug)]
@@ -88,6 +95,16 @@ pub struct InstanceState<V: Version> {
     pub spf_schedule_time: Option<Instant>,
     // Routing table.
     pub rib: BTreeMap<V::IpNetwork, RouteNet<V>>,
+    // Per-algorithm routing tables (Flexible Algorithm).
+    pub flex_algo_rib: BTreeMap<u8, BTreeMap<V::IpNetwork, RouteNet<V>>>,
+    // Table of AS-external routes originated by this router.
+    pub externals: BTreeMap<V::IpNetwork, (u32, ExternalNet<V>)>,
+    // AS-external routes resulting from the translation of Type-7 LSAs.
+    pub nssa_translated: BTreeMap<V::IpNetwork, ExternalNet<V>>,
+    // Redistributed routes update timer.
+    pub externals_update_timer: Option<TimeoutTask>,
+    // Next AS-external LSA ID (OSPFv3 only).
+    pub next_type5_lsa_id: u32,
     // Statistics.
     pub orig_lsa_count: u32,
     pub rx_lsa_count: u32,
@@ -100,12 +117,25 @@ pub struct InstanceState<V: Version> {
     pub spf_log_next_id: u32,
     // Number of neighbors performing a graceful restart.
     pub gr_helper_count: usize,
-    // Authentication non-decreasing sequence number.
-    pub auth_seqno: Arc<AtomicU64>,
+    // Graceful restart state (restarting router mode).
+    pub gr_restart: Option<InstanceGrRestart>,
+    // Whether the routes preserved across a graceful restart need to be
+    // swept after the next routing table calculation.
+    pub gr_stale_sweep: bool,
+    // Authentication non-decreasing sequence numbers.
+    pub auth_seqno: Arc<AuthSeqNo>,
     // Hostname cache.
     pub hostnames: BTreeMap<Ipv4Addr, String>,
 }
 
+#[derive(Debug)]
+pub struct InstanceGrRestart {
+    // Graceful restart interval timeout.
+    pub restart_interval: TimeoutTask,
+    // Neighbors that were fully adjacent before the restart.
+    pub pre_restart_nbrs: PreRestartNbrs,
+}
+
 #[derive(Debug, Default)]
 pub struct InstanceArenas<V: Version> {
     pub areas: Areas<V>,
@@ -146,6 +176,14 @@ pub struct ProtocolInputChannelsTx<V: Version> {
     pub spf_delay_event: UnboundedSender<SpfDelayEventMsg>,
     // Grace period timeout.
     pub grace_period: Sender<GracePeriodMsg>,
+    // Graceful restart interval timeout.
+    pub restart_interval: Sender<RestartIntervalMsg>,
+    // LDP-IGP synchronization holddown timeout.
+    pub ldp_sync_holddown: Sender<LdpSyncHolddownMsg>,
+    // NSSA translator stability interval timeout.
+    pub nssa_translator_stability: Sender<NssaTranslatorStabilityMsg>,
+    // Redistributed routes update timeout.
+    pub externals_update: UnboundedSender<ExternalsUpdateMsg>,
 }
 
 #[derive(Debug)]
@@ -180,6 +218,14 @@ pub struct ProtocolInputChannelsRx<V: Version> {
     pub spf_delay_event: UnboundedReceiver<SpfDelayEventMsg>,
     // Grace period timeout.
     pub grace_period: Receiver<GracePeriodMsg>,
+    // Graceful restart interval timeout.
+    pub restart_interval: Receiver<RestartIntervalMsg>,
+    // LDP-IGP synchronization holddown timeout.
+    pub ldp_sync_holddown: Receiver<LdpSyncHolddownMsg>,
+    // NSSA translator stability interval timeout.
+    pub nssa_translator_stability: Receiver<NssaTranslatorStabilityMsg>,
+    // Redistributed routes update timeout.
+    pub externals_update: UnboundedReceiver<ExternalsUpdateMsg>,
 }
 
 pub struct InstanceUpView<'a, V: Version> {
@@ -228,7 +274,15 @@ where
     fn start(&mut self, af: AddressFamily, router_id: Ipv4Addr) {
         Debug::<V>::InstanceStart.log();
 
-        let state = InstanceState::new(af, router_id, self.boot_count_get());
+        // Initialize the authentication sequence numbers, updating the boot
+        // count in non-volatile storage whenever they wrap.
+        let mut auth_seqno =
+            AuthSeqNo::new(V::initial_auth_seqno(self.boot_count_get()));
+        if let Some(db) = &self.shared.db {
+            auth_seqno = auth_seqno
+                .with_boot_count_nvm(db.clone(), self.boot_count_key());
+        }
+        let state = InstanceState::new(af, router_id, auth_seqno);
 
         // Store instance initial state.
         self.state = Some(state);
@@ -254,8 +308,24 @@ where
             );
         }
 
+        // Try to start multi-area adjacencies.
+        interface::update_multi_area_adjs(&mut instance, arenas);
+
+        // Originate AS-external LSAs for the redistributed routes.
+        area::update_externals(
+            &mut instance,
+            &mut arenas.areas,
+            &arenas.interfaces,
+            &arenas.lsa_entries,
+        );
+
         // Update boot count in non-volatile storage.
         self.boot_count_update();
+
+        // Resume the graceful restart that was in progress when the instance
+        // (or the daemon itself) was stopped, if any.
+        let (mut instance, arenas) = self.as_up().unwrap();
+        gr::restart_resume(&mut instance, arenas);
     }
 
     fn stop(&mut self, reason: InstanceInactiveReason) {
@@ -265,17 +335,39 @@ where
 
         Debug::<V>::InstanceStop(reason).log();
 
-        // Flush all self-originated LSAs.
+        // When performing a graceful restart, self-originated LSAs and routes
+        // are preserved so that forwarding isn't disrupted.
         let (mut instance, arenas) = self.as_up().unwrap();
-        lsdb::flush_all_self_originated(&mut instance, arenas);
+        if reason != InstanceInactiveReason::GracefulRestart {
+            // Abort the graceful restart in progress, if any.
+            if instance.state.gr_restart.is_some() {
+                gr::restart_nvm_clear(&instance);
+            }
 
-        // Uninstall all routes.
-        for (dest, route) in
-            instance.state.rib.iter().filter(|(_, route)| {
-                route.flags.contains(RouteNetFlags::INSTALLED)
-            })
-        {
-            ibus::tx::route_uninstall(&instance.tx.ibus, dest, route);
+            // Flush all self-originated LSAs.
+            lsdb::flush_all_self_originated(&mut instance, arenas);
+
+            // Uninstall all routes.
+            for (dest, route) in
+                instance.state.rib.iter().filter(|(_, route)| {
+                    route.flags.contains(RouteNetFlags::INSTALLED)
+                })
+            {
+                ibus::tx::route_uninstall(&instance.tx.ibus, dest, route);
+            }
+            for label in instance
+                .state
+                .flex_algo_rib
+                .values()
+                .flat_map(|rib| rib.values())
+                .filter(|route| route.flags.contains(RouteNetFlags::INSTALLED))
+                .filter_map(|route| route.sr_label)
+            {
+                ibus::tx::flex_algo_label_uninstall::<V>(
+                    &instance.tx.ibus,
+                    label,
+                );
+            }
         }
 
         for area in arenas.areas.iter_mut() {
@@ -320,6 +412,30 @@ where
         }
     }
 
+    // Performs a planned graceful restart of the instance.
+    pub(crate) fn graceful_restart(&mut self) {
+        // Originate Grace-LSAs and save the state that needs to be preserved
+        // across the restart.
+        let Some((mut instance, arenas)) = self.as_up() else {
+            return;
+        };
+        let record = gr::restart_prepare(&mut instance, arenas);
+
+        // Restart the instance. The graceful restart mode is entered on
+        // startup using the state saved in non-volatile memory.
+        self.stop(InstanceInactiveReason::GracefulRestart);
+        self.update();
+
+        // Enter the graceful restart mode directly when non-volatile storage
+        // isn't available.
+        if let Some((mut instance, arenas)) = self.as_up()
+            && instance.state.gr_restart.is_none()
+        {
+            let restart_interval = record.restart_interval;
+            gr::restart_enter(&mut instance, arenas, record, restart_interval);
+        }
+    }
+
     pub(crate) fn is_active(&self) -> bool {
         self.state.is_some()
     }
@@ -344,6 +460,11 @@ where
         self.config.router_id.or(self.system.router_id)
     }
 
+    // Returns the non-volatile memory key of the instance boot count.
+    fn boot_count_key(&self) -> String {
+        format!("{}-{}-boot-count", V::PROTOCOL, self.name)
+    }
+
     // Retrieves the boot count of the instance from non-volatile memory.
     fn boot_count_get(&self) -> u32 {
         let mut boot_count = 0;
@@ -351,7 +472,7 @@ where
         if let Some(db) = &self.shared.db {
             let db = db.lock().unwrap();
 
-            let key = format!("{}-{}-boot-count", V::PROTOCOL, self.name);
+            let key = self.boot_count_key();
             if let Some(value) = db.get::<u32>(&key) {
                 boot_count = value;
             }
@@ -366,7 +487,7 @@ where
             let mut db = db.lock().unwrap();
             let mut boot_count = 0;
 
-            let key = format!("{}-{}-boot-count", V::PROTOCOL, self.name);
+            let key = self.boot_count_key();
             if let Some(value) = db.get::<u32>(&key) {
                 boot_count = value;
             }
@@ -431,11 +552,25 @@ where
 
         // Request information about the system hostname.
         ibus::tx::hostname_sub(&self.tx.ibus);
+
+        // Request information about the LDP-IGP synchronization state.
+        ibus::tx::ldp_sync_sub(&self.tx.ibus);
     }
 
     fn shutdown(mut self) {
-        // Ensure instance is disabled before exiting.
-        self.stop(InstanceInactiveReason::AdminDown);
+        // Ensure instance is disabled before exiting. If a graceful restart is
+        // in progress, the routes and self-originated LSAs are preserved so
+        // that it can be resumed once the daemon is started again.
+        let reason = if self
+            .state
+            .as_ref()
+            .is_some_and(|state| state.gr_restart.is_some())
+        {
+            InstanceInactiveReason::GracefulRestart
+        } else {
+            InstanceInactiveReason::AdminDown
+        };
+        self.stop(reason);
     }
 
     fn process_ibus_msg(&mut self, msg: IbusMsg) {
@@ -482,6 +617,11 @@ where
             mpsc::channel(4);
         let (spf_delay_eventp, spf_delay_eventc) = mpsc::unbounded_channel();
         let (grace_periodp, grace_periodc) = mpsc::channel(4);
+        let (restart_intervalp, restart_intervalc) = mpsc::channel(4);
+        let (ldp_sync_holddownp, ldp_sync_holddownc) = mpsc::channel(4);
+        let (nssa_translator_stabilityp, nssa_translator_stabilityc) =
+            mpsc::channel(4);
+        let (externals_updatep, externals_updatec) = mpsc::unbounded_channel();
 
         let tx = ProtocolInputChannelsTx {
             ism_event: ism_eventp,
@@ -499,6 +639,10 @@ where
             lsdb_maxage_sweep_interval: lsdb_maxage_sweep_intervalp,
             spf_delay_event: spf_delay_eventp,
             grace_period: grace_periodp,
+            restart_interval: restart_intervalp,
+            ldp_sync_holddown: ldp_sync_holddownp,
+            nssa_translator_stability: nssa_translator_stabilityp,
+            externals_update: externals_updatep,
         };
         let rx = ProtocolInputChannelsRx {
             ism_event: ism_eventc,
@@ -516,6 +660,10 @@ where
             lsdb_maxage_sweep_interval: lsdb_maxage_sweep_intervalc,
             spf_delay_event: spf_delay_eventc,
             grace_period: grace_periodc,
+            restart_interval: restart_intervalc,
+            ldp_sync_holddown: ldp_sync_holddownc,
+            nssa_translator_stability: nssa_translator_stabilityc,
+            externals_update: externals_updatec,
         };
 
         (tx, rx)
@@ -549,7 +697,7 @@ where
     fn new(
         af: AddressFamily,
         router_id: Ipv4Addr,
-        boot_count: u32,
+        auth_seqno: AuthSeqNo,
     ) -> InstanceState<V> {
         InstanceState {
             af,
@@ -564,6 +712,11 @@ where
             spf_trigger_lsas: Default::default(),
             spf_schedule_time: None,
             rib: Default::default(),
+            flex_algo_rib: Default::default(),
+            externals: Default::default(),
+            nssa_translated: Default::default(),
+            externals_update_timer: None,
+            next_type5_lsa_id: 0,
             orig_lsa_count: 0,
             rx_lsa_count: 0,
             discontinuity_time: Utc::now(),
@@ -572,7 +725,9 @@ where
             spf_log: Default::default(),
             spf_log_next_id: 0,
             gr_helper_count: 0,
-            auth_seqno: Arc::new(V::initial_auth_seqno(boot_count).into()),
+            gr_restart: None,
+            gr_stale_sweep: false,
+            auth_seqno: Arc::new(auth_seqno),
             hostnames: Default::default(),
         }
     }
@@ -716,6 +871,18 @@ where
             msg = self.grace_period.recv() => {
                 msg.map(ProtocolInputMsg::GracePeriod)
             }
+            msg = self.restart_interval.recv() => {
+                msg.map(ProtocolInputMsg::RestartInterval)
+            }
+            msg = self.ldp_sync_holddown.recv() => {
+                msg.map(ProtocolInputMsg::LdpSyncHolddown)
+            }
+            msg = self.nssa_translator_stability.recv() => {
+                msg.map(ProtocolInputMsg::NssaTranslatorStability)
+            }
+            msg = self.externals_update.recv() => {
+                msg.map(ProtocolInputMsg::ExternalsUpdate)
+            }
         }
     }
 }
@@ -773,6 +940,14 @@ where
         IbusMsg::RouterIdUpdate(router_id) => {
             ibus::rx::process_router_id_update(instance, router_id);
         }
+        // LDP-IGP synchronization update notification.
+        IbusMsg::LdpSyncUpd { ifname, synced } => {
+            ibus::rx::process_ldp_sync_update(instance, ifname, Some(synced));
+        }
+        // LDP-IGP synchronization delete notification.
+        IbusMsg::LdpSyncDel { ifname } => {
+            ibus::rx::process_ldp_sync_update(instance, ifname, None);
+        }
         // SR configuration update.
         IbusMsg::SrCfgUpd(sr_config) => {
             instance.shared.sr_config = sr_config;
@@ -791,6 +966,41 @@ where
         IbusMsg::HostnameUpdate(hostname) => {
             ibus::rx::process_hostname_update(instance, hostname)?;
         }
+        // Policy match sets update notification.
+        IbusMsg::PolicyMatchSetsUpd(match_sets) => {
+            // Update the local copy of the policy match sets.
+            instance.shared.policy_match_sets = match_sets;
+
+            // Update redistributed routes.
+            ibus::rx::process_policy_update(instance);
+        }
+        // Policy definition update notification.
+        IbusMsg::PolicyUpd(policy) => {
+            // Update the local copy of the policy definition.
+            instance
+                .shared
+                .policies
+                .insert(policy.name.clone(), policy.clone());
+
+            // Update redistributed routes.
+            ibus::rx::process_policy_update(instance);
+        }
+        // Policy definition delete notification.
+        IbusMsg::PolicyDel(policy_name) => {
+            // Remove the local copy of the policy definition.
+            instance.shared.policies.remove(&policy_name);
+
+            // Update redistributed routes.
+            ibus::rx::process_policy_update(instance);
+        }
+        // Route redistribute update notification.
+        IbusMsg::RouteRedistributeAdd(msg) => {
+            ibus::rx::process_route_add(instance, msg);
+        }
+        // Route redistribute delete notification.
+        IbusMsg::RouteRedistributeDel(msg) => {
+            ibus::rx::process_route_del(instance, msg);
+        }
         // Ignore other events.
         _ => {}
     }
@@ -929,6 +1139,31 @@ where
                 msg.nbr_key,
             )?
         }
+        // Graceful restart interval timeout.
+        ProtocolInputMsg::RestartInterval(_) => {
+            events::process_restart_interval_timeout(instance, arenas)?
+        }
+        // LDP-IGP synchronization holddown timeout.
+        ProtocolInputMsg::LdpSyncHolddown(msg) => {
+            events::process_ldp_sync_holddown_timeout(
+                instance,
+                arenas,
+                msg.area_key,
+                msg.iface_key,
+            )?
+        }
+        // NSSA translator stability interval timeout.
+        ProtocolInputMsg::NssaTranslatorStability(msg) => {
+            events::process_nssa_translator_stability_timeout(
+                instance,
+                arenas,
+                msg.area_key,
+            )?
+        }
+        // Redistributed routes update timeout.
+        ProtocolInputMsg::ExternalsUpdate(_) => {
+            events::process_externals_update(instance, arenas)
+        }
     }
 
     Ok(())
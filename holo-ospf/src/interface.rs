@@ -7,13 +7,13 @@
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::net::Ipv4Addr;
 use std::sync::Arc;
-use std::sync::atomic::AtomicU64;
 
 use arc_swap::ArcSwap;
 use chrono::{DateTime, Utc};
 use holo_protocol::InstanceChannelsTx;
 use holo_utils::ip::{AddressFamily, IpNetworkKind};
 use holo_utils::keychain::{Key, Keychains};
+use holo_utils::mpls::LdpSyncState;
 use holo_utils::socket::{AsyncFd, Socket};
 use holo_utils::southbound::InterfaceFlags;
 use holo_utils::task::{IntervalTask, Task, TimeoutTask};
@@ -27,14 +27,14 @@ use crate::area::Area;
 use crate::collections::{Arena, InterfaceId, Lsdb, NeighborIndex, Neighbors};
 use crate::debug::{Debug, InterfaceInactiveReason};
 use crate::error::{Error, InterfaceCfgError, IoError};
-use crate::instance::{Instance, InstanceUpView};
+use crate::instance::{Instance, InstanceArenas, InstanceUpView};
 use crate::lsdb::{LsaEntry, LsaOriginateEvent};
 use crate::neighbor::{Neighbor, NeighborNetId, nsm};
 use crate::network::MulticastAddr;
 use crate::northbound::configuration::InterfaceCfg;
 use crate::northbound::notification;
 use crate::packet::Packet;
-use crate::packet::auth::AuthMethod;
+use crate::packet::auth::{AuthMethod, AuthSeqNo};
 use crate::packet::lsa::{Lsa, LsaHdrVersion, LsaKey};
 use crate::tasks;
 use crate::tasks::messages::output::NetTxPacketMsg;
@@ -48,6 +48,7 @@ pub struct Interface<V: Version> {
     pub config: InterfaceCfg<V>,
     pub state: InterfaceState<V>,
     pub vlink_key: Option<VirtualLinkKey>,
+    pub multi_area: Option<MultiAreaAdj<V>>,
 }
 
 #[derive(Debug)]
@@ -63,6 +64,8 @@ pub struct InterfaceSys<V: Version> {
     // OSPFv2: primary address.
     pub primary_addr: Option<Ipv4Network>,
     pub unnumbered: bool,
+    // OSPFv2: addresses flagged as unnumbered by the southbound layer.
+    pub unnumbered_addrs: BTreeSet<Ipv4Network>,
     // OSPFv3: link-local address.
     pub linklocal_addr: Option<Ipv6Network>,
 }
@@ -96,6 +99,8 @@ pub struct InterfaceState<V: Version> {
     pub network_lsa_self: Option<LsaKey<V::LsaType>>,
     // Authentication data.
     pub auth: Arc<ArcSwap<Option<AuthMethod>>>,
+    // LDP-IGP synchronization state.
+    pub ldp_sync: LdpSyncState,
     // Tasks.
     pub tasks: InterfaceTasks<V>,
 }
@@ -106,7 +111,7 @@ pub struct InterfaceNet<V: Version> {
     pub socket: Arc<AsyncFd<Socket>>,
     // Network Tx task.
     _net_tx_task: Task<()>,
-    // Network Rx task. Absent for virtual links.
+    // Network Rx task. Absent for virtual links and multi-area adjacencies.
     _net_rx_task: Option<Task<()>>,
     // Network Tx output channel.
     pub net_tx_packetp: UnboundedSender<NetTxPacketMsg<V>>,
@@ -124,6 +129,8 @@ pub struct InterfaceTasks<V: Version> {
     pub ls_update_timer: Option<TimeoutTask>,
     // Delayed Ack task.
     pub ls_delayed_ack: Option<TimeoutTask>,
+    // LDP-IGP synchronization holddown timer task.
+    pub ldp_sync_holddown: Option<TimeoutTask>,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -156,6 +163,22 @@ pub struct VirtualLinkState<V: Version> {
     pub cost: u32,
 }
 
+// Multi-area adjacency (RFC 5185).
+//
+// A multi-area adjacency is modeled as a logical point-to-point interface
+// created in the secondary area and bound to an interface (the primary
+// interface) configured in another area. Packets are received and sent
+// using the primary interface's socket.
+#[derive(Debug)]
+pub struct MultiAreaAdj<V: Version> {
+    // Name of the primary interface.
+    pub ifname: String,
+    // Configured neighbor address.
+    pub nbr_addr: Option<V::NetIpAddr>,
+    // Raw socket of the primary interface.
+    pub socket: Option<Arc<AsyncFd<Socket>>>,
+}
+
 // Interface state machine.
 pub mod ism {
     use serde::{Deserialize, Serialize};
@@ -263,6 +286,7 @@ where
             config: InterfaceCfg::default(),
             state: InterfaceState::default(),
             vlink_key,
+            multi_area: None,
         }
     }
 
@@ -486,6 +510,10 @@ where
         self.config.if_type == InterfaceType::VirtualLink
     }
 
+    pub(crate) fn is_multi_area(&self) -> bool {
+        self.multi_area.is_some()
+    }
+
     fn auth(&self, keychains: &Keychains) -> Option<AuthMethod> {
         if let (Some(key), Some(key_id), Some(algo)) = (
             &self.config.auth_key,
@@ -511,7 +539,11 @@ where
         let auth = self.auth(&instance.shared.keychains);
         self.state.auth.store(Arc::new(auth));
 
-        if let Some(net) = &self.state.net {
+        // The socket of multi-area adjacencies is owned by the primary
+        // interface.
+        if let Some(net) = &self.state.net
+            && !self.is_multi_area()
+        {
             // Enable or disable checksum offloading.
             let cksum_enable = self.state.auth.load().is_none();
             if let Err(error) =
@@ -618,8 +650,11 @@ where
         self.state.ism_state = new_ism_state;
         notification::if_state_change(instance, self);
 
+        // Update the LDP-IGP synchronization state.
+        self.ldp_sync_update(area, instance);
+
         // Join or leave OSPF multicast groups as necessary.
-        if !self.is_virtual_link() {
+        if !self.is_virtual_link() && !self.is_multi_area() {
             self.update_mcast_groups();
         }
 
@@ -628,12 +663,62 @@ where
         self.state.discontinuity_time = Utc::now();
     }
 
+    // Updates the LDP-IGP synchronization state of the interface (RFC 5443),
+    // starting or stopping the holddown timer as necessary.
+    //
+    // Returns whether the metric advertised for the interface has changed.
+    pub(crate) fn ldp_sync_update(
+        &mut self,
+        area: &Area<V>,
+        instance: &InstanceUpView<'_, V>,
+    ) -> bool {
+        // Multi-area adjacencies inherit the state of their primary
+        // interfaces.
+        let ifname = match &self.multi_area {
+            Some(multi_area) => &multi_area.ifname,
+            None => &self.name,
+        };
+        let enabled = instance.config.ldp_igp_sync && !self.is_down();
+        let ldp_synced = instance.system.ldp_sync.get(ifname).copied();
+        let old_state = self.state.ldp_sync;
+        let new_state = old_state.next(enabled, ldp_synced);
+        if new_state == old_state {
+            return false;
+        }
+
+        Debug::<V>::InterfaceLdpSyncChange(&self.name, old_state, new_state)
+            .log();
+        self.state.ldp_sync = new_state;
+
+        // Start or stop the holddown timer.
+        let holddown = instance.config.ldp_igp_sync_holddown;
+        if new_state == LdpSyncState::NotAchieved && holddown != 0 {
+            let task =
+                tasks::ldp_sync_holddown_timer(self, area, instance, holddown);
+            self.state.tasks.ldp_sync_holddown = Some(task);
+        } else {
+            self.state.tasks.ldp_sync_holddown = None;
+        }
+
+        old_state.max_metric() != new_state.max_metric()
+    }
+
+    // Returns whether the maximum metric should be advertised for the
+    // interface while waiting for LDP to synchronize.
+    pub(crate) fn ldp_sync_max_metric(&self) -> bool {
+        self.state.ldp_sync.max_metric()
+    }
+
     pub(crate) fn hello_interval_start(
         &mut self,
         area: &Area<V>,
         instance: &InstanceUpView<'_, V>,
     ) {
         let dst = match self.config.if_type {
+            InterfaceType::PointToPoint if self.is_multi_area() => {
+                let multi_area = self.multi_area.as_ref().unwrap();
+                smallvec![multi_area.nbr_addr.unwrap()]
+            }
             InterfaceType::PointToPoint | InterfaceType::Broadcast => {
                 smallvec![*V::multicast_addr(MulticastAddr::AllSpfRtrs)]
             }
@@ -976,6 +1061,7 @@ where
             addr_list: Default::default(),
             primary_addr: None,
             unnumbered: false,
+            unnumbered_addrs: Default::default(),
             linklocal_addr: None,
         }
     }
@@ -1004,6 +1090,7 @@ where
             lsdb: Default::default(),
             network_lsa_self: None,
             auth: Default::default(),
+            ldp_sync: Default::default(),
             tasks: Default::default(),
         }
     }
@@ -1019,27 +1106,34 @@ where
         iface: &Interface<V>,
         area: &Area<V>,
         af: AddressFamily,
-        auth_seqno: &Arc<AtomicU64>,
+        auth_seqno: &Arc<AuthSeqNo>,
         instance_channels_tx: &InstanceChannelsTx<Instance<V>>,
     ) -> Result<Self, IoError> {
-        // Create raw socket.
-        let ifname = if iface.is_virtual_link() {
-            None
+        let socket = if let Some(multi_area) = &iface.multi_area {
+            // Multi-area adjacencies share the primary interface's socket.
+            multi_area.socket.clone().unwrap()
         } else {
-            Some(iface.name.as_ref())
+            // Create raw socket.
+            let ifname = if iface.is_virtual_link() {
+                None
+            } else {
+                Some(iface.name.as_ref())
+            };
+            let socket = V::socket(ifname)
+                .map_err(IoError::SocketError)
+                .and_then(|socket| {
+                    AsyncFd::new(socket).map_err(IoError::SocketError)
+                })
+                .map(Arc::new)?;
+
+            // Enable or disable checksum offloading.
+            let cksum_enable = iface.state.auth.load().is_none();
+            V::set_cksum_offloading(socket.get_ref(), cksum_enable).map_err(
+                |error| IoError::ChecksumOffloadError(cksum_enable, error),
+            )?;
+
+            socket
         };
-        let socket = V::socket(ifname)
-            .map_err(IoError::SocketError)
-            .and_then(|socket| {
-                AsyncFd::new(socket).map_err(IoError::SocketError)
-            })
-            .map(Arc::new)?;
-
-        // Enable or disable checksum offloading.
-        let cksum_enable = iface.state.auth.load().is_none();
-        V::set_cksum_offloading(socket.get_ref(), cksum_enable).map_err(
-            |error| IoError::ChecksumOffloadError(cksum_enable, error),
-        )?;
 
         // Start network Tx/Rx tasks.
         let (net_tx_packetp, net_tx_packetc) = mpsc::unbounded_channel();
@@ -1052,7 +1146,7 @@ where
             &instance_channels_tx.protocol_output,
         );
         let mut net_rx_task = None;
-        if !iface.is_virtual_link() {
+        if !iface.is_virtual_link() && !iface.is_multi_area() {
             net_rx_task = Some(tasks::net_rx(
                 socket.clone(),
                 iface,
@@ -1075,6 +1169,21 @@ where
     }
 }
 
+// ===== impl MultiAreaAdj =====
+
+impl<V> MultiAreaAdj<V>
+where
+    V: Version,
+{
+    pub(crate) fn new(ifname: String) -> MultiAreaAdj<V> {
+        MultiAreaAdj {
+            ifname,
+            nbr_addr: None,
+            socket: None,
+        }
+    }
+}
+
 // ===== impl InterfaceTasks =====
 
 impl<V> Default for InterfaceTasks<V>
@@ -1088,6 +1197,7 @@ where
             wait_timer: Default::default(),
             ls_update_timer: Default::default(),
             ls_delayed_ack: Default::default(),
+            ldp_sync_holddown: Default::default(),
         }
     }
 }
@@ -1118,5 +1228,131 @@ where
         return Err(InterfaceInactiveReason::MissingMtu);
     }
 
+    if let Some(multi_area) = &iface.multi_area {
+        if multi_area.nbr_addr.is_none() {
+            return Err(InterfaceInactiveReason::MissingNeighborAddress);
+        }
+        if multi_area.socket.is_none() {
+            return Err(InterfaceInactiveReason::PrimaryInterfaceDown);
+        }
+    }
+
     Ok(())
 }
+
+// Returns the name of the logical interface used by the multi-area adjacency
+// bound to the given primary interface.
+pub(crate) fn multi_area_ifname(ifname: &str, area_id: Ipv4Addr) -> String {
+    format!("{ifname}:{area_id}")
+}
+
+// Updates the LDP-IGP synchronization state of all interfaces, reoriginating
+// the LSAs of the areas where an advertised metric has changed.
+pub(crate) fn ldp_sync_update_all<V>(
+    instance: &InstanceUpView<'_, V>,
+    arenas: &mut InstanceArenas<V>,
+) where
+    V: Version,
+{
+    for area in arenas.areas.iter() {
+        let mut cost_change = false;
+        for iface_idx in area.interfaces.indexes() {
+            let iface = &mut arenas.interfaces[iface_idx];
+            cost_change |= iface.ldp_sync_update(area, instance);
+        }
+
+        if cost_change {
+            instance.tx.protocol_input.lsa_orig_event(
+                LsaOriginateEvent::InterfaceCostChange { area_id: area.id },
+            );
+        }
+    }
+}
+
+// Synchronizes all multi-area adjacencies with the state of their primary
+// interfaces, starting or stopping them as necessary.
+pub(crate) fn update_multi_area_adjs<V>(
+    instance: &mut InstanceUpView<'_, V>,
+    arenas: &mut InstanceArenas<V>,
+) where
+    V: Version,
+{
+    for area_idx in arenas.areas.indexes().collect::<Vec<_>>() {
+        let area = &arenas.areas[area_idx];
+        for iface_idx in area.interfaces.indexes().collect::<Vec<_>>() {
+            let iface = &arenas.interfaces[iface_idx];
+            let Some(multi_area) = &iface.multi_area else {
+                continue;
+            };
+
+            // Lookup the primary interface.
+            let primary = arenas.areas.iter().find_map(|area| {
+                area.interfaces
+                    .get_by_name(&arenas.interfaces, &multi_area.ifname)
+                    .map(|(_, primary)| primary)
+            });
+
+            // The multi-area adjacency inherits the system data of its
+            // primary interface, except for the addresses, since it's
+            // advertised as an unnumbered point-to-point link.
+            let system = primary
+                .map(|primary| InterfaceSys {
+                    flags: primary.system.flags,
+                    ifindex: primary.system.ifindex,
+                    mtu: primary.system.mtu,
+                    addr_list: Default::default(),
+                    primary_addr: primary.system.primary_addr,
+                    unnumbered: true,
+                    unnumbered_addrs: Default::default(),
+                    linklocal_addr: primary.system.linklocal_addr,
+                })
+                .unwrap_or_default();
+            let socket = primary
+                .and_then(|primary| primary.state.net.as_ref())
+                .map(|net| net.socket.clone());
+
+            // Restart the adjacency if the primary interface's socket or
+            // source address has changed.
+            let restart = !iface.is_down()
+                && (multi_area
+                    .socket
+                    .as_ref()
+                    .zip(socket.as_ref())
+                    .is_some_and(|(old, new)| !Arc::ptr_eq(old, new))
+                    || iface.system.primary_addr != system.primary_addr
+                    || iface.system.linklocal_addr != system.linklocal_addr);
+
+            // Update the adjacency's system data.
+            let area = &mut arenas.areas[area_idx];
+            let iface = &mut arenas.interfaces[iface_idx];
+            if iface.system.ifindex != system.ifindex {
+                area.interfaces.update_ifindex(
+                    iface_idx,
+                    iface,
+                    system.ifindex,
+                );
+            }
+            iface.system = system;
+            iface.multi_area.as_mut().unwrap().socket = socket;
+
+            // Check if the adjacency needs to be started or stopped.
+            let area = &arenas.areas[area_idx];
+            if restart {
+                let reason = InterfaceInactiveReason::Resetting;
+                iface.fsm(
+                    area,
+                    instance,
+                    &mut arenas.neighbors,
+                    &arenas.lsa_entries,
+                    Event::InterfaceDown(reason),
+                );
+            }
+            iface.update(
+                area,
+                instance,
+                &mut arenas.neighbors,
+                &arenas.lsa_entries,
+            );
+        }
+    }
+}
@@ -0,0 +1,937 @@
+//
+// Copyright (c) The Holo Core Contributors
+//
+// SPDX-License-Identifier: MIT
+//
+
+use std::collections::BTreeMap;
+use std::net::{IpAddr, Ipv4Addr};
+
+use bitflags::bitflags;
+use holo_utils::ip::IpAddrKind;
+use holo_utils::mpls::Label;
+
+use crate::area::Area;
+use crate::collections::{Areas, Arena};
+use crate::instance::InstanceUpView;
+use crate::interface::Interface;
+use crate::lsdb::{LSA_INFINITY, LsaEntry};
+use crate::neighbor::Neighbor;
+use crate::route::{Nexthop, PathType, RouteNet, RouteNetFlags};
+use crate::spf::{
+    SpfExternalNetwork, Vertex, VertexIdVersion, VertexLsaVersion,
+};
+use crate::sr;
+use crate::version::Version;
+
+// Area topology information used to compute IP Fast Reroute alternates.
+#[derive(Debug)]
+pub struct LfaTopology<V: Version> {
+    // Links to the neighboring routers.
+    pub links: Vec<LfaLink<V>>,
+    // Shortest-path distances from each neighbor to all area vertices.
+    pub nbr_dist: BTreeMap<Ipv4Addr, BTreeMap<V::VertexId, u32>>,
+    // Shortest-path distances from all area vertices to the calculating
+    // router and to each of its neighbors (remote LFA only).
+    pub rev_dist: BTreeMap<Ipv4Addr, BTreeMap<V::VertexId, u32>>,
+}
+
+// Link from the calculating router to a neighboring router.
+#[derive(Debug)]
+pub struct LfaLink<V: Version> {
+    pub nbr_router_id: Ipv4Addr,
+    pub nexthop: Nexthop<V::IpAddr>,
+    pub cost: u32,
+}
+
+// Backup nexthop computed by IP Fast Reroute.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BackupNexthop<I: IpAddrKind> {
+    pub alt_type: AlternateType,
+    pub nexthop: Nexthop<I>,
+    // PQ node used as the remote LFA tunnel endpoint.
+    pub pq_node: Option<Ipv4Addr>,
+    // MPLS labels pushed on the backup path.
+    pub labels: Vec<Label>,
+    pub protection: ProtectionFlags,
+    // Metric to the destination through the alternate path.
+    pub metric: u32,
+    // Metric to the alternate node.
+    pub metric_to_alt: u32,
+    // Metric from the alternate node to the destination.
+    pub metric_from_alt: u32,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum AlternateType {
+    Lfa,
+    RemoteLfa,
+}
+
+bitflags! {
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    pub struct ProtectionFlags: u8 {
+        const NODE = 0x01;
+        const LINK = 0x02;
+        const DOWNSTREAM = 0x04;
+    }
+}
+
+// Points of attachment of the area prefixes, given as the vertices that
+// advertise them and the cost from these vertices to the prefixes.
+#[derive(Debug)]
+struct AreaAttachments<V: Version> {
+    intra: BTreeMap<V::IpNetwork, Vec<(V::VertexId, u32)>>,
+    inter: BTreeMap<V::IpNetwork, Vec<(V::VertexId, u32)>>,
+    nssa: BTreeMap<V::IpNetwork, Vec<(V::VertexId, u32)>>,
+}
+
+// ===== impl BackupNexthop =====
+
+impl<I> BackupNexthop<I>
+where
+    I: IpAddrKind,
+{
+    // Returns the address that identifies the alternate: the PQ node for
+    // remote LFAs, or the nexthop address otherwise.
+    pub(crate) fn alternate(&self) -> IpAddr {
+        match (self.pq_node, self.nexthop.addr) {
+            (Some(pq_node), _) => pq_node.into(),
+            (None, Some(addr)) => addr.into(),
+            (None, None) => self
+                .nexthop
+                .nbr_router_id
+                .unwrap_or(Ipv4Addr::UNSPECIFIED)
+                .into(),
+        }
+    }
+}
+
+// ===== global functions =====
+
+// Computes the area topology information needed to find IP Fast Reroute
+// alternates.
+//
+// Returns `None` if LFA isn't enabled on any interface of the area.
+pub(crate) fn compute_topology<V>(
+    area: &Area<V>,
+    instance: &InstanceUpView<'_, V>,
+    interfaces: &Arena<Interface<V>>,
+    neighbors: &Arena<Neighbor<V>>,
+    lsa_entries: &Arena<LsaEntry<V>>,
+) -> Option<LfaTopology<V>>
+where
+    V: Version,
+{
+    let area_ifaces = || area.interfaces.indexes().map(|idx| &interfaces[idx]);
+    if !area_ifaces().any(|iface| iface.config.lfa.enabled) {
+        return None;
+    }
+
+    let af = instance.state.af;
+    let extended_lsa = instance.config.extended_lsa;
+    let router_id = instance.state.router_id;
+
+    // Get root vertex.
+    let root_vid = V::VertexId::new_root(router_id);
+    let root_vlsa =
+        V::vertex_lsa_find(af, root_vid, area, extended_lsa, lsa_entries)?;
+    let root_v = Vertex::<V>::new(root_vid, root_vlsa, 0, 0);
+
+    // Find the links to all neighboring routers, either directly connected or
+    // reachable through a transit network.
+    let mut links = vec![];
+    for link in
+        V::vertex_lsa_links(&root_v.lsa, af, area, extended_lsa, lsa_entries)
+    {
+        if link_cost_back::<V>(&link.lsa, root_vid, area, instance, lsa_entries)
+            .is_none()
+        {
+            continue;
+        }
+
+        if link.lsa.is_router() {
+            let nbr_router_id = link.lsa.router_id();
+            match V::calc_nexthops(
+                area,
+                &root_v,
+                link.parent,
+                link.id,
+                &link.lsa,
+                interfaces,
+                neighbors,
+                extended_lsa,
+                lsa_entries,
+            ) {
+                Ok(nexthops) => {
+                    links.extend(nexthops.into_values().map(|nexthop| {
+                        LfaLink {
+                            nbr_router_id,
+                            nexthop,
+                            cost: link.cost.into(),
+                        }
+                    }))
+                }
+                Err(error) => error.log(),
+            }
+            continue;
+        }
+
+        // Compute the nexthops of the transit network, which are inherited by
+        // the routers attached to it.
+        let cost = link.cost.into();
+        let mut net_v = Vertex::<V>::new(link.id, link.lsa, cost, 0);
+        match V::calc_nexthops(
+            area,
+            &root_v,
+            link.parent,
+            net_v.id,
+            &net_v.lsa,
+            interfaces,
+            neighbors,
+            extended_lsa,
+            lsa_entries,
+        ) {
+            Ok(nexthops) => net_v.nexthops = nexthops,
+            Err(error) => {
+                error.log();
+                continue;
+            }
+        }
+        for net_link in
+            V::vertex_lsa_links(&net_v.lsa, af, area, extended_lsa, lsa_entries)
+        {
+            if net_link.id == root_vid
+                || link_cost_back::<V>(
+                    &net_link.lsa,
+                    net_v.id,
+                    area,
+                    instance,
+                    lsa_entries,
+                )
+                .is_none()
+            {
+                continue;
+            }
+
+            let nbr_router_id = net_link.lsa.router_id();
+            match V::calc_nexthops(
+                area,
+                &net_v,
+                None,
+                net_link.id,
+                &net_link.lsa,
+                interfaces,
+                neighbors,
+                extended_lsa,
+                lsa_entries,
+            ) {
+                Ok(nexthops) => {
+                    links.extend(nexthops.into_values().map(|nexthop| {
+                        LfaLink {
+                            nbr_router_id,
+                            nexthop,
+                            cost,
+                        }
+                    }))
+                }
+                Err(error) => error.log(),
+            }
+        }
+    }
+
+    // Compute the shortest-path distances from all neighbors.
+    let mut nbr_dist = BTreeMap::new();
+    for nbr_router_id in links.iter().map(|link| link.nbr_router_id) {
+        nbr_dist.entry(nbr_router_id).or_insert_with(|| {
+            compute_distances(area, instance, lsa_entries, nbr_router_id, false)
+        });
+    }
+
+    // Compute the reverse shortest-path distances needed to find the
+    // Q-space of the protected links.
+    let mut rev_dist = BTreeMap::new();
+    if area_ifaces().any(|iface| iface.config.lfa.remote_lfa_enabled) {
+        for router_id in std::iter::once(router_id)
+            .chain(links.iter().map(|link| link.nbr_router_id))
+        {
+            rev_dist.entry(router_id).or_insert_with(|| {
+                compute_distances(area, instance, lsa_entries, router_id, true)
+            });
+        }
+    }
+
+    Some(LfaTopology {
+        links,
+        nbr_dist,
+        rev_dist,
+    })
+}
+
+// Computes the backup nexthops of all routes in the provided routing table.
+pub(crate) fn update_backups<V>(
+    rib: &mut BTreeMap<V::IpNetwork, RouteNet<V>>,
+    instance: &InstanceUpView<'_, V>,
+    areas: &Areas<V>,
+    interfaces: &Arena<Interface<V>>,
+    lsa_entries: &Arena<LsaEntry<V>>,
+) where
+    V: Version,
+{
+    // Nothing to do if IP Fast Reroute isn't enabled on any area.
+    if !areas.iter().any(|area| area.state.lfa.is_some()) {
+        for route in rib.values_mut() {
+            route.backup = None;
+            route.flags.remove(RouteNetFlags::FRR_ENABLED);
+        }
+        return;
+    }
+
+    let router_id = instance.state.router_id;
+    let extended_lsa = instance.config.extended_lsa;
+
+    // Find the points of attachment of all prefixes.
+    let area_atts = areas
+        .iter()
+        .filter(|area| area.state.lfa.is_some())
+        .map(|area| {
+            let atts = area_attachments(area, instance, lsa_entries);
+            (area.area_id, atts)
+        })
+        .collect::<BTreeMap<_, _>>();
+    let mut external_atts = BTreeMap::<_, Vec<_>>::new();
+    for lsa in
+        V::external_networks(&instance.state.lsdb, extended_lsa, lsa_entries)
+            .filter(|lsa| lsa.metric < LSA_INFINITY)
+            .filter(|lsa| lsa.adv_rtr != router_id)
+    {
+        let vertex_id = V::VertexId::new_root(lsa.adv_rtr);
+        let metric = external_metric(&lsa);
+        external_atts
+            .entry(lsa.prefix)
+            .or_default()
+            .push((vertex_id, metric));
+    }
+
+    // Distances from the candidate PQ nodes, computed on demand.
+    let mut pq_dist = BTreeMap::new();
+
+    for (prefix, route) in rib.iter_mut() {
+        route.backup = None;
+        route.flags.remove(RouteNetFlags::FRR_ENABLED);
+
+        // Only routes whose primary nexthops are all on interfaces where LFA
+        // is enabled are protected.
+        if route.flags.contains(RouteNetFlags::CONNECTED)
+            || route.nexthops.is_empty()
+            || !route
+                .nexthops
+                .values()
+                .all(|nexthop| interfaces[nexthop.iface_idx].config.lfa.enabled)
+        {
+            continue;
+        }
+
+        // Find the area whose topology is used to reach the destination.
+        let area_id = match route.path_type {
+            PathType::IntraArea | PathType::InterArea => route.area_id,
+            _ => {
+                let iface_idx =
+                    route.nexthops.values().next().unwrap().iface_idx;
+                areas
+                    .iter()
+                    .find(|area| {
+                        area.interfaces.indexes().any(|idx| idx == iface_idx)
+                    })
+                    .map(|area| area.area_id)
+            }
+        };
+        let Some((_, area)) =
+            area_id.and_then(|area_id| areas.get_by_area_id(area_id))
+        else {
+            continue;
+        };
+        let (Some(topology), Some(atts)) =
+            (&area.state.lfa, area_atts.get(&area.area_id))
+        else {
+            continue;
+        };
+        route.flags.insert(RouteNetFlags::FRR_ENABLED);
+
+        // External routes are attached to the advertising ASBRs.
+        let atts = match route.path_type {
+            PathType::IntraArea => atts.intra.get(prefix),
+            PathType::InterArea => atts.inter.get(prefix),
+            PathType::Type1External | PathType::Type2External => {
+                external_atts.get(prefix)
+            }
+            PathType::Nssa1 | PathType::Nssa2 => atts.nssa.get(prefix),
+        };
+        let Some(atts) = atts else {
+            continue;
+        };
+
+        // Try to find a regular LFA first, falling back to remote LFA.
+        let backup = lfa_select(
+            route,
+            atts,
+            area,
+            topology,
+            instance,
+            interfaces,
+            lsa_entries,
+        )
+        .or_else(|| {
+            remote_lfa_select(
+                route,
+                atts,
+                area,
+                topology,
+                instance,
+                interfaces,
+                lsa_entries,
+                &mut pq_dist,
+            )
+        });
+        route.backup = backup;
+    }
+}
+
+// ===== helper functions =====
+
+// Finds the best loop-free alternate for the given route (RFC 5286).
+fn lfa_select<V>(
+    route: &RouteNet<V>,
+    atts: &[(V::VertexId, u32)],
+    area: &Area<V>,
+    topology: &LfaTopology<V>,
+    instance: &InstanceUpView<'_, V>,
+    interfaces: &Arena<Interface<V>>,
+    lsa_entries: &Arena<LsaEntry<V>>,
+) -> Option<BackupNexthop<V::IpAddr>>
+where
+    V: Version,
+{
+    let lfa_cfg = &instance.config.lfa;
+    let root_vid = V::VertexId::new_root(instance.state.router_id);
+    let d_s_p = prefix_distance::<V>(
+        |vid| area.state.spt.get(vid).map(|vertex| vertex.distance),
+        atts,
+    )?;
+
+    let mut best: Option<(&LfaLink<V>, BackupNexthop<V::IpAddr>)> = None;
+    for link in topology
+        .links
+        .iter()
+        .filter(|link| is_candidate(link, route, interfaces))
+    {
+        let Some(nbr_dist) = topology.nbr_dist.get(&link.nbr_router_id) else {
+            continue;
+        };
+        let Some(d_n_s) = nbr_dist.get(&root_vid).copied() else {
+            continue;
+        };
+        let Some(d_n_p) =
+            prefix_distance::<V>(|vid| nbr_dist.get(vid).copied(), atts)
+        else {
+            continue;
+        };
+
+        // Loop-free criterion (RFC 5286 - Inequality 1).
+        if d_n_p >= d_n_s.saturating_add(d_s_p) {
+            continue;
+        }
+        let mut protection = ProtectionFlags::LINK;
+
+        // Downstream path criterion (RFC 5286 - Inequality 2).
+        if d_n_p < d_s_p {
+            protection.insert(ProtectionFlags::DOWNSTREAM);
+        } else if lfa_cfg.downstream_only {
+            continue;
+        }
+
+        // Node protection criterion (RFC 5286 - Inequality 3), evaluated for
+        // all primary next-hop routers.
+        if route.nexthops.values().all(|nexthop| {
+            nexthop.nbr_router_id.is_some_and(|primary| {
+                primary != link.nbr_router_id
+                    && is_node_protecting(
+                        topology, nbr_dist, d_n_p, primary, atts,
+                    )
+            })
+        }) {
+            protection.insert(ProtectionFlags::NODE);
+        }
+
+        let backup = BackupNexthop {
+            alt_type: AlternateType::Lfa,
+            nexthop: link.nexthop,
+            pq_node: None,
+            labels: vec![],
+            protection,
+            metric: link.cost.saturating_add(d_n_p),
+            metric_to_alt: link.cost,
+            metric_from_alt: d_n_p,
+        };
+
+        // Prefer node-protecting alternates if so configured, followed by the
+        // alternates with the lowest metric.
+        let rank = |backup: &BackupNexthop<V::IpAddr>| {
+            (
+                lfa_cfg.prefer_node_protection
+                    && !backup.protection.contains(ProtectionFlags::NODE),
+                backup.metric,
+            )
+        };
+        if best
+            .as_ref()
+            .is_none_or(|(_, best)| rank(&backup) < rank(best))
+        {
+            best = Some((link, backup));
+        }
+    }
+    let (link, mut backup) = best?;
+
+    // Use the Prefix-SID of the destination, if any, so that the alternate
+    // also protects labeled traffic.
+    if instance.config.sr_enabled
+        && let Some(prefix_sid) = &route.prefix_sid
+    {
+        let last_hop = is_attached::<V>(atts, link.nbr_router_id);
+        if let Some(label) = sr::prefix_sid_remote_label(
+            area,
+            instance,
+            link.nbr_router_id,
+            prefix_sid,
+            last_hop,
+            lsa_entries,
+        ) {
+            backup.labels.push(label);
+        }
+    }
+
+    Some(backup)
+}
+
+// Finds a remote loop-free alternate for the given route (RFC 7490).
+//
+// The repair tunnel to the PQ node is built using Segment Routing: the
+// Node-SID of the PQ node is pushed on top of the Prefix-SID of the
+// destination.
+fn remote_lfa_select<V>(
+    route: &RouteNet<V>,
+    atts: &[(V::VertexId, u32)],
+    area: &Area<V>,
+    topology: &LfaTopology<V>,
+    instance: &InstanceUpView<'_, V>,
+    interfaces: &Arena<Interface<V>>,
+    lsa_entries: &Arena<LsaEntry<V>>,
+    pq_dist: &mut BTreeMap<(Ipv4Addr, Ipv4Addr), BTreeMap<V::VertexId, u32>>,
+) -> Option<BackupNexthop<V::IpAddr>>
+where
+    V: Version,
+{
+    if !instance.config.sr_enabled {
+        return None;
+    }
+
+    // Only routes with a single primary nexthop are considered.
+    let mut primaries = route.nexthops.values();
+    let primary = primaries.next()?;
+    if primaries.next().is_some() {
+        return None;
+    }
+    if !interfaces[primary.iface_idx].config.lfa.remote_lfa_enabled {
+        return None;
+    }
+    let router_id = instance.state.router_id;
+    let root_vid = V::VertexId::new_root(router_id);
+    let primary_rtr = primary.nbr_router_id?;
+    let primary_link = topology.links.iter().find(|link| {
+        link.nexthop.iface_idx == primary.iface_idx
+            && link.nexthop.addr == primary.addr
+    })?;
+    let rev_s = topology.rev_dist.get(&router_id)?;
+    let rev_e = topology.rev_dist.get(&primary_rtr)?;
+    let d_s_p = prefix_distance::<V>(
+        |vid| area.state.spt.get(vid).map(|vertex| vertex.distance),
+        atts,
+    )?;
+
+    // Find the candidate PQ nodes, along with the neighbors used to reach
+    // them.
+    let mut candidates = vec![];
+    for vertex in area
+        .state
+        .spt
+        .values()
+        .filter(|vertex| vertex.lsa.is_router() && vertex.hops > 0)
+    {
+        // Q-space: routers that reach the primary next-hop router without
+        // crossing the protected link.
+        let (Some(d_y_e), Some(d_y_s)) =
+            (rev_e.get(&vertex.id), rev_s.get(&vertex.id))
+        else {
+            continue;
+        };
+        if *d_y_e >= d_y_s.saturating_add(primary_link.cost) {
+            continue;
+        }
+
+        // Extended P-space: routers reachable from one of the neighbors
+        // without going back through the calculating router.
+        for link in topology
+            .links
+            .iter()
+            .filter(|link| is_candidate(link, route, interfaces))
+        {
+            let Some(nbr_dist) = topology.nbr_dist.get(&link.nbr_router_id)
+            else {
+                continue;
+            };
+            let (Some(d_n_y), Some(d_n_s)) =
+                (nbr_dist.get(&vertex.id), nbr_dist.get(&root_vid))
+            else {
+                continue;
+            };
+            if *d_n_y >= d_n_s.saturating_add(vertex.distance) {
+                continue;
+            }
+
+            let metric_to_alt = link.cost.saturating_add(*d_n_y);
+            candidates.push((metric_to_alt, vertex.lsa.router_id(), link));
+        }
+    }
+    candidates.sort_by_key(|(metric, pq_node, _)| (*metric, *pq_node));
+
+    for (metric_to_alt, pq_node, link) in candidates {
+        // The PQ node must forward traffic to the destination without looping
+        // back through the calculating router.
+        let y_dist =
+            pq_dist.entry((area.area_id, pq_node)).or_insert_with(|| {
+                compute_distances(area, instance, lsa_entries, pq_node, false)
+            });
+        let Some(d_y_p) =
+            prefix_distance::<V>(|vid| y_dist.get(vid).copied(), atts)
+        else {
+            continue;
+        };
+        let Some(d_y_s) = y_dist.get(&root_vid) else {
+            continue;
+        };
+        if d_y_p >= d_y_s.saturating_add(d_s_p) {
+            continue;
+        }
+
+        let mut protection = ProtectionFlags::LINK;
+        if d_y_p < d_s_p {
+            protection.insert(ProtectionFlags::DOWNSTREAM);
+        } else if instance.config.lfa.downstream_only {
+            continue;
+        }
+
+        // Check whether neither the path to the PQ node nor the path from the
+        // PQ node to the destination go through the primary next-hop router.
+        if pq_node != primary_rtr
+            && link.nbr_router_id != primary_rtr
+            && let Some(e_dist) = topology.nbr_dist.get(&primary_rtr)
+            && let Some(nbr_dist) = topology.nbr_dist.get(&link.nbr_router_id)
+            && let Some(d_n_y) =
+                nbr_dist.get(&V::VertexId::new_root(pq_node)).copied()
+            && let Some(d_n_e) =
+                nbr_dist.get(&V::VertexId::new_root(primary_rtr)).copied()
+            && let Some(d_e_y) =
+                e_dist.get(&V::VertexId::new_root(pq_node)).copied()
+            && d_n_y < d_n_e.saturating_add(d_e_y)
+            && let Some(d_y_e) =
+                y_dist.get(&V::VertexId::new_root(primary_rtr)).copied()
+            && let Some(d_e_p) =
+                prefix_distance::<V>(|vid| e_dist.get(vid).copied(), atts)
+            && d_y_p < d_y_e.saturating_add(d_e_p)
+        {
+            protection.insert(ProtectionFlags::NODE);
+        }
+
+        // Build the label stack of the repair tunnel.
+        let Some(labels) = remote_lfa_labels(
+            route,
+            atts,
+            area,
+            instance,
+            link.nbr_router_id,
+            pq_node,
+            lsa_entries,
+        ) else {
+            continue;
+        };
+
+        return Some(BackupNexthop {
+            alt_type: AlternateType::RemoteLfa,
+            nexthop: link.nexthop,
+            pq_node: Some(pq_node),
+            labels,
+            protection,
+            metric: metric_to_alt.saturating_add(d_y_p),
+            metric_to_alt,
+            metric_from_alt: d_y_p,
+        });
+    }
+
+    None
+}
+
+// Computes the label stack of a remote LFA repair tunnel: the Node-SID of the
+// PQ node, as expected by the neighbor used to reach it, followed by the
+// Prefix-SID of the destination, as expected by the PQ node.
+fn remote_lfa_labels<V>(
+    route: &RouteNet<V>,
+    atts: &[(V::VertexId, u32)],
+    area: &Area<V>,
+    instance: &InstanceUpView<'_, V>,
+    nbr_router_id: Ipv4Addr,
+    pq_node: Ipv4Addr,
+    lsa_entries: &Arena<LsaEntry<V>>,
+) -> Option<Vec<Label>>
+where
+    V: Version,
+{
+    let node_sid = sr::node_sid_find(area, instance, pq_node, lsa_entries)?;
+    let mut labels = vec![sr::prefix_sid_remote_label(
+        area,
+        instance,
+        nbr_router_id,
+        &node_sid,
+        nbr_router_id == pq_node,
+        lsa_entries,
+    )?];
+
+    // The destination's Prefix-SID isn't necessary when the PQ node is the
+    // one advertising the prefix.
+    if !is_attached::<V>(atts, pq_node) {
+        let prefix_sid = route.prefix_sid.as_ref()?;
+        labels.push(sr::prefix_sid_remote_label(
+            area,
+            instance,
+            pq_node,
+            prefix_sid,
+            false,
+            lsa_entries,
+        )?);
+    }
+
+    Some(labels)
+}
+
+// Checks whether the given link can be used as an alternate for the provided
+// route. Links that share the outgoing interface with any of the primary
+// nexthops can't protect against the failure of that interface.
+fn is_candidate<V>(
+    link: &LfaLink<V>,
+    route: &RouteNet<V>,
+    interfaces: &Arena<Interface<V>>,
+) -> bool
+where
+    V: Version,
+{
+    interfaces[link.nexthop.iface_idx]
+        .config
+        .lfa
+        .candidate_enabled
+        && !route
+            .nexthops
+            .values()
+            .any(|nexthop| nexthop.iface_idx == link.nexthop.iface_idx)
+}
+
+// Checks whether an alternate neighbor protects against the failure of the
+// given primary next-hop router (RFC 5286 - Inequality 3).
+fn is_node_protecting<V>(
+    topology: &LfaTopology<V>,
+    nbr_dist: &BTreeMap<V::VertexId, u32>,
+    d_n_p: u32,
+    primary: Ipv4Addr,
+    atts: &[(V::VertexId, u32)],
+) -> bool
+where
+    V: Version,
+{
+    let Some(d_n_e) = nbr_dist.get(&V::VertexId::new_root(primary)) else {
+        return false;
+    };
+    let Some(d_e_p) = topology.nbr_dist.get(&primary).and_then(|e_dist| {
+        prefix_distance::<V>(|vid| e_dist.get(vid).copied(), atts)
+    }) else {
+        return false;
+    };
+    d_n_p < d_n_e.saturating_add(d_e_p)
+}
+
+// Checks whether the given router is one of the points of attachment of a
+// prefix.
+fn is_attached<V>(atts: &[(V::VertexId, u32)], router_id: Ipv4Addr) -> bool
+where
+    V: Version,
+{
+    let vertex_id = V::VertexId::new_root(router_id);
+    atts.iter().any(|(att_vid, _)| *att_vid == vertex_id)
+}
+
+// Computes the distance to a prefix given the distances to all of its points
+// of attachment.
+fn prefix_distance<V>(
+    dist: impl Fn(&V::VertexId) -> Option<u32>,
+    atts: &[(V::VertexId, u32)],
+) -> Option<u32>
+where
+    V: Version,
+{
+    atts.iter()
+        .filter_map(|(vertex_id, metric)| {
+            dist(vertex_id).map(|distance| distance.saturating_add(*metric))
+        })
+        .min()
+}
+
+// Computes the shortest-path distances from the given router to all vertices
+// of the area, or from all vertices to the given router when `reverse` is set.
+fn compute_distances<V>(
+    area: &Area<V>,
+    instance: &InstanceUpView<'_, V>,
+    lsa_entries: &Arena<LsaEntry<V>>,
+    router_id: Ipv4Addr,
+    reverse: bool,
+) -> BTreeMap<V::VertexId, u32>
+where
+    V: Version,
+{
+    let af = instance.state.af;
+    let extended_lsa = instance.config.extended_lsa;
+    let mut dist = BTreeMap::new();
+
+    let root_vid = V::VertexId::new_root(router_id);
+    let Some(root_vlsa) =
+        V::vertex_lsa_find(af, root_vid, area, extended_lsa, lsa_entries)
+    else {
+        return dist;
+    };
+    let mut cand_list = BTreeMap::new();
+    cand_list.insert((0, root_vid), root_vlsa);
+
+    while let Some(((distance, vertex_id), vertex_lsa)) = cand_list.pop_first()
+    {
+        if dist.contains_key(&vertex_id) {
+            continue;
+        }
+        dist.insert(vertex_id, distance);
+
+        for link in V::vertex_lsa_links(
+            &vertex_lsa,
+            af,
+            area,
+            extended_lsa,
+            lsa_entries,
+        ) {
+            if dist.contains_key(&link.id) {
+                continue;
+            }
+
+            // Check if the LSAs are mutually linked.
+            let Some(cost_back) = link_cost_back::<V>(
+                &link.lsa,
+                vertex_id,
+                area,
+                instance,
+                lsa_entries,
+            ) else {
+                continue;
+            };
+            let cost = if reverse { cost_back } else { link.cost };
+            let distance = distance.saturating_add(cost.into());
+            cand_list.insert((distance, link.id), link.lsa);
+        }
+    }
+
+    dist
+}
+
+// Returns the cost of the link from the given vertex back to its parent, or
+// `None` if the vertices aren't mutually linked.
+fn link_cost_back<V>(
+    vertex_lsa: &V::VertexLsa,
+    parent_id: V::VertexId,
+    area: &Area<V>,
+    instance: &InstanceUpView<'_, V>,
+    lsa_entries: &Arena<LsaEntry<V>>,
+) -> Option<u16>
+where
+    V: Version,
+{
+    V::vertex_lsa_links(
+        vertex_lsa,
+        instance.state.af,
+        area,
+        instance.config.extended_lsa,
+        lsa_entries,
+    )
+    .find(|link| link.id == parent_id)
+    .map(|link| link.cost)
+}
+
+// Finds the points of attachment of all intra-area, inter-area and NSSA
+// prefixes of the given area.
+fn area_attachments<V>(
+    area: &Area<V>,
+    instance: &InstanceUpView<'_, V>,
+    lsa_entries: &Arena<LsaEntry<V>>,
+) -> AreaAttachments<V>
+where
+    V: Version,
+{
+    let router_id = instance.state.router_id;
+    let extended_lsa = instance.config.extended_lsa;
+    let mut atts = AreaAttachments {
+        intra: BTreeMap::new(),
+        inter: BTreeMap::new(),
+        nssa: BTreeMap::new(),
+    };
+
+    for stub in V::intra_area_networks(area, extended_lsa, lsa_entries) {
+        atts.intra
+            .entry(stub.prefix)
+            .or_default()
+            .push((stub.vertex.id, stub.metric.into()));
+    }
+    for lsa in V::inter_area_networks(area, extended_lsa, lsa_entries)
+        .filter(|lsa| lsa.metric < LSA_INFINITY)
+        .filter(|lsa| lsa.adv_rtr != router_id)
+    {
+        let vertex_id = V::VertexId::new_root(lsa.adv_rtr);
+        atts.inter
+            .entry(lsa.prefix)
+            .or_default()
+            .push((vertex_id, lsa.metric));
+    }
+    for lsa in V::nssa_networks(&area.state.lsdb, extended_lsa, lsa_entries)
+        .filter(|lsa| lsa.metric < LSA_INFINITY)
+        .filter(|lsa| lsa.adv_rtr != router_id)
+    {
+        let vertex_id = V::VertexId::new_root(lsa.adv_rtr);
+        let metric = external_metric(&lsa);
+        atts.nssa
+            .entry(lsa.prefix)
+            .or_default()
+            .push((vertex_id, metric));
+    }
+
+    atts
+}
+
+// Returns the cost from the advertising ASBR to an external prefix. Type 2
+// metrics are ignored since they're the same regardless of the path to the
+// ASBR.
+fn external_metric<V>(lsa: &SpfExternalNetwork<V>) -> u32
+where
+    V: Version,
+{
+    if lsa.e_bit { 0 } else { lsa.metric }
+}
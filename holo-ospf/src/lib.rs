@@ -16,11 +16,13 @@ pub mod collections;
 pub mod debug;
 pub mod error;
 pub mod events;
+pub mod flex_algo;
 pub mod flood;
 pub mod gr;
 pub mod ibus;
 pub mod instance;
 pub mod interface;
+pub mod lfa;
 pub mod lsdb;
 pub mod neighbor;
 pub mod network;
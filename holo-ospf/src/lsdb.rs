@@ -5,7 +5,7 @@
 //
 
 use std::cmp::Ordering;
-use std::collections::{btree_map, hash_map};
+use std::collections::{BTreeMap, btree_map, hash_map};
 use std::net::Ipv4Addr;
 use std::sync::Arc;
 use std::time::Instant;
@@ -14,6 +14,7 @@ use bitflags::bitflags;
 use chrono::Utc;
 use derive_new::new;
 use holo_utils::bier::BierCfgEvent;
+use holo_utils::ip::IpNetworkKind;
 use holo_utils::sr::SrCfgEvent;
 use holo_utils::task::TimeoutTask;
 use serde::{Deserialize, Serialize};
@@ -33,7 +34,8 @@ use crate::neighbor::nsm;
 use crate::packet::lsa::{
     Lsa, LsaBodyVersion, LsaHdrVersion, LsaKey, LsaTypeVersion,
 };
-use crate::route::{SummaryNet, SummaryRtr};
+use crate::packet::tlv::GrReason;
+use crate::route::{ExternalNet, SummaryNet, SummaryRtr};
 use crate::spf::SpfTriggerLsa;
 use crate::tasks::messages::input::LsaFlushMsg;
 use crate::version::Version;
@@ -99,6 +101,13 @@ pub enum LsaOriginateEvent {
     InterfaceFlagChange {
         area_id: AreaId,
     },
+    InterfaceLinkAttrChange {
+        area_id: AreaId,
+    },
+    InterfacePrefixSuppressionChange {
+        area_id: AreaId,
+        iface_id: InterfaceId,
+    },
     NeighborToFromFull {
         area_id: AreaId,
         iface_id: InterfaceId,
@@ -130,12 +139,19 @@ pub enum LsaOriginateEvent {
         area_id: AreaId,
         iface_id: InterfaceId,
     },
+    GrRestartExit,
     BierEnableChange,
     BierCfgChange {
         change: BierCfgEvent,
     },
     HostnameChange,
     NodeTagsChange,
+    FlexAlgoChange,
+    FloodingReductionChange {
+        area_id: AreaId,
+    },
+    NssaTranslatorChange,
+    AsbrStatusChange,
 }
 
 #[derive(Debug)]
@@ -193,6 +209,24 @@ pub trait LsdbVersion<V: Version> {
         event: LsaOriginateEvent,
     ) -> Result<(), Error<V>>;
 
+    // Return the Grace-LSA used to announce a planned restart on the given
+    // interface.
+    fn lsa_grace(
+        iface: &Interface<V>,
+        area: &Area<V>,
+        grace_period: u32,
+        reason: GrReason,
+    ) -> (Option<V::PacketOptions>, Ipv4Addr, V::LsaBody);
+
+    // Assign Link State IDs to the LSAs describing the given prefixes.
+    //
+    // Returns `None` when the Link State ID doesn't carry any addressing
+    // semantics (OSPFv3), in which case LSA IDs are allocated on demand.
+    // Prefixes missing from the returned map can't be advertised.
+    fn prefix_lsa_ids(
+        prefixes: impl Iterator<Item = V::IpNetwork>,
+    ) -> Option<BTreeMap<V::IpNetwork, u32>>;
+
     // Originate Type-3 Summary LSA (OSPFv2) or Inter-Area-Network-LSA (OSPFv3).
     fn lsa_orig_inter_area_network(
         area: &mut Area<V>,
@@ -211,6 +245,23 @@ pub trait LsdbVersion<V: Version> {
         summary: &SummaryRtr<V>,
     ) -> u32;
 
+    // Originate Type-5 AS-External-LSA.
+    fn lsa_orig_as_external(
+        instance: &mut InstanceUpView<'_, V>,
+        prefix: V::IpNetwork,
+        lsa_id: Option<u32>,
+        external: &ExternalNet<V>,
+    ) -> u32;
+
+    // Originate Type-7 NSSA-LSA.
+    fn lsa_orig_nssa(
+        area: &mut Area<V>,
+        instance: &InstanceUpView<'_, V>,
+        prefix: V::IpNetwork,
+        lsa_id: Option<u32>,
+        external: &ExternalNet<V>,
+    ) -> u32;
+
     // Return the LSDB index corresponding to the provided LSA type.
     fn lsdb_get_by_lsa_type(
         iface_idx: InterfaceIndex,
@@ -857,6 +908,31 @@ fn log_lsa<V>(
     instance.state.lsa_log.truncate(LSA_LOG_MAX_SIZE);
 }
 
+// Determines whether the Router-LSA E-bit should be set for the given area.
+pub(crate) fn router_lsa_e_bit<V>(
+    area: &Area<V>,
+    instance: &InstanceUpView<'_, V>,
+) -> bool
+where
+    V: Version,
+{
+    match area.config.area_type {
+        // The E-bit is set when the router originates AS-external routes.
+        AreaType::Normal => !instance.state.externals.is_empty(),
+        // AS-external routes aren't advertised into stub areas.
+        AreaType::Stub => false,
+        // Within NSSAs, the E-bit is set when the router originates Type-7
+        // LSAs other than the default route.
+        AreaType::Nssa => {
+            let default = V::IpNetwork::default(instance.state.af);
+            area.state
+                .nssa_externals
+                .keys()
+                .any(|prefix| *prefix != default)
+        }
+    }
+}
+
 // Determines whether the Router-LSA V-bit should be set for the given area.
 pub(crate) fn router_lsa_v_bit<V>(
     area: &Area<V>,
@@ -4,7 +4,7 @@
 // SPDX-License-Identifier: MIT
 //
 
-use std::collections::{BTreeMap, HashMap};
+use std::collections::BTreeMap;
 use std::net::Ipv4Addr;
 use std::sync::Arc;
 
@@ -24,7 +24,8 @@ use crate::instance::InstanceUpView;
 use crate::interface::{Interface, InterfaceType, ism};
 use crate::lsdb::{LsaEntry, LsaOriginateEvent};
 use crate::northbound::notification;
-use crate::packet::iana::PacketType;
+use crate::packet::auth::AuthSeqNoRx;
+use crate::packet::lls::ExtendedOptionsFlags;
 use crate::packet::lsa::{Lsa, LsaHdrVersion, LsaKey};
 use crate::packet::tlv::GrReason;
 use crate::packet::{DbDescFlags, DbDescVersion};
@@ -49,7 +50,14 @@ pub struct Neighbor<V: Version> {
     pub dd_seq_no: u32,
     pub last_rcvd_dbdesc: Option<LastDbDesc<V>>,
     pub last_sent_dbdesc: Option<NetTxPacketMsg<V>>,
-    pub auth_seqno: HashMap<PacketType, u64>,
+    pub auth_seqno: AuthSeqNoRx,
+
+    // LLS Extended Options and Flags last received from the neighbor.
+    pub ext_options: ExtendedOptionsFlags,
+    // Database exchange state while performing out-of-band LSDB
+    // resynchronization (RFC 4811). The neighbor remains in the Full state
+    // throughout the process.
+    pub oob_resync: Option<State>,
 
     pub event_count: u32,
     pub discontinuity_time: DateTime<Utc>,
@@ -95,6 +103,7 @@ pub struct NeighborGrHelper {
 pub struct NeighborTasks {
     pub inactivity_timer: Option<TimeoutTask>,
     pub dbdesc_free_timer: Option<TimeoutTask>,
+    pub resync_timer: Option<TimeoutTask>,
     rxmt_dbdesc: Option<IntervalTask>,
     rxmt_lsreq: Option<IntervalTask>,
     rxmt_lsupd: Option<IntervalTask>,
@@ -195,6 +204,8 @@ where
             last_rcvd_dbdesc: None,
             last_sent_dbdesc: None,
             auth_seqno: Default::default(),
+            ext_options: ExtendedOptionsFlags::empty(),
+            oob_resync: None,
             event_count: 0,
             discontinuity_time: Utc::now(),
             adj_sids: Default::default(),
@@ -249,6 +260,15 @@ where
                     self.dd_flags.insert(
                         DbDescFlags::I | DbDescFlags::M | DbDescFlags::MS,
                     );
+
+                    // When restarting, request the neighbor to resynchronize
+                    // its LSDB without resetting the adjacency (RFC 4812 -
+                    // Section 2.3).
+                    if instance.state.gr_restart.is_some()
+                        && self.oob_resync_capable(iface)
+                    {
+                        self.dd_flags.insert(DbDescFlags::R);
+                    }
                     output::send_dbdesc(self, iface, area, instance);
                     Some(State::ExStart)
                 } else {
@@ -257,34 +277,7 @@ where
             }
             // NSM (state, event) -> (Action, new state)
             (State::ExStart, Event::NegotiationDone) => {
-                // List the contents of the entire LSDB in the summary list.
-                let options = self.options;
-                for lse in iface
-                    .state
-                    .lsdb
-                    .iter(lsa_entries)
-                    .chain(area.state.lsdb.iter(lsa_entries))
-                    .chain(instance.state.lsdb.iter(lsa_entries))
-                    .map(|(_, lse)| lse)
-                    // Filter out unneeded LSAs.
-                    .filter(|lse| {
-                        V::lsa_type_is_valid(
-                            Some(area.config.area_type),
-                            Some(iface.config.if_type),
-                            options,
-                            lse.data.hdr.lsa_type(),
-                        )
-                    })
-                {
-                    let lsa_key = lse.data.hdr.key();
-                    if lse.data.hdr.is_maxage() {
-                        self.lists.ls_rxmt.insert(lsa_key, lse.data.clone());
-                        self.rxmt_lsupd_start_check(iface, area, instance);
-                    } else {
-                        self.lists.db_summary.insert(lsa_key, lse.data.clone());
-                    }
-                }
-
+                self.db_summary_init(iface, area, instance, lsa_entries);
                 self.dd_flags.remove(DbDescFlags::I);
                 Some(State::Exchange)
             }
@@ -304,6 +297,34 @@ where
                 // No action required.
                 Some(State::Full)
             }
+            // Out-of-band LSDB resynchronization (RFC 4811 - Section 2.4).
+            (State::Full, Event::NegotiationDone)
+                if self.oob_resync == Some(State::ExStart) =>
+            {
+                self.db_summary_init(iface, area, instance, lsa_entries);
+                self.dd_flags.remove(DbDescFlags::I);
+                self.oob_resync = Some(State::Exchange);
+                None
+            }
+            (State::Full, Event::ExchangeDone)
+                if self.oob_resync == Some(State::Exchange) =>
+            {
+                if self.lists.ls_request_pending.is_empty()
+                    && self.lists.ls_request.is_empty()
+                {
+                    self.oob_resync_finish(instance);
+                } else {
+                    // Wait for outstanding LS Requests to be responded.
+                    self.oob_resync = Some(State::Loading);
+                }
+                None
+            }
+            (State::Full, Event::LoadingDone)
+                if self.oob_resync == Some(State::Loading) =>
+            {
+                self.oob_resync_finish(instance);
+                None
+            }
             // NSM (state, event) -> (Action, new state)
             (
                 State::ExStart | State::Exchange | State::Loading | State::Full,
@@ -512,6 +533,96 @@ where
         self.discontinuity_time = Utc::now();
     }
 
+    // Lists the contents of the entire LSDB in the neighbor's Database
+    // summary list.
+    fn db_summary_init(
+        &mut self,
+        iface: &Interface<V>,
+        area: &Area<V>,
+        instance: &InstanceUpView<'_, V>,
+        lsa_entries: &Arena<LsaEntry<V>>,
+    ) {
+        let options = self.options;
+        for lse in iface
+            .state
+            .lsdb
+            .iter(lsa_entries)
+            .chain(area.state.lsdb.iter(lsa_entries))
+            .chain(instance.state.lsdb.iter(lsa_entries))
+            .map(|(_, lse)| lse)
+            // Filter out unneeded LSAs.
+            .filter(|lse| {
+                V::lsa_type_is_valid(
+                    Some(area.config.area_type),
+                    Some(iface.config.if_type),
+                    options,
+                    lse.data.hdr.lsa_type(),
+                )
+            })
+        {
+            let lsa_key = lse.data.hdr.key();
+            if lse.data.hdr.is_maxage() {
+                self.lists.ls_rxmt.insert(lsa_key, lse.data.clone());
+                self.rxmt_lsupd_start_check(iface, area, instance);
+            } else {
+                self.lists.db_summary.insert(lsa_key, lse.data.clone());
+            }
+        }
+    }
+
+    // Checks whether out-of-band LSDB resynchronization can be performed with
+    // this neighbor (RFC 4811 - Section 2.4).
+    pub(crate) fn oob_resync_capable(&self, iface: &Interface<V>) -> bool {
+        iface.config.lls_enabled
+            && self.ext_options.contains(ExtendedOptionsFlags::LR)
+    }
+
+    // Starts out-of-band LSDB resynchronization with a neighbor in the Full
+    // state (RFC 4811 - Section 2.4).
+    pub(crate) fn oob_resync_start(
+        &mut self,
+        iface: &Interface<V>,
+        area: &Area<V>,
+        instance: &InstanceUpView<'_, V>,
+    ) {
+        if instance.config.trace_opts.neighbor {
+            Debug::<V>::NeighborOobResyncStart(self.router_id).log();
+        }
+
+        self.oob_resync = Some(State::ExStart);
+        self.tasks.resync_timer = None;
+        self.dd_seq_no = self.dd_seq_no.wrapping_add(1);
+        self.dd_flags.insert(
+            DbDescFlags::I | DbDescFlags::M | DbDescFlags::MS | DbDescFlags::R,
+        );
+        output::send_dbdesc(self, iface, area, instance);
+    }
+
+    fn oob_resync_finish(&mut self, instance: &InstanceUpView<'_, V>) {
+        if instance.config.trace_opts.neighbor {
+            Debug::<V>::NeighborOobResyncEnd(self.router_id).log();
+        }
+
+        self.oob_resync = None;
+        self.dd_flags.remove(DbDescFlags::R);
+    }
+
+    // Returns the state of the database exchange process, taking into account
+    // any out-of-band LSDB resynchronization in progress.
+    pub(crate) fn dd_state(&self) -> State {
+        self.oob_resync.unwrap_or(self.state)
+    }
+
+    pub(crate) fn resync_timer_start(
+        &mut self,
+        iface: &Interface<V>,
+        area: &Area<V>,
+        instance: &InstanceUpView<'_, V>,
+    ) {
+        let task = tasks::nsm_resync_timer(self, iface, area, instance);
+        self.tasks.resync_timer = Some(task);
+    }
+
     pub(crate) fn loading_done_check(
         &mut self,
         iface: &Interface<V>,
@@ -526,7 +637,7 @@ where
             // Check if there are new LSAs to request.
             if !self.lists.ls_request.is_empty() {
                 output::send_lsreq(self, iface, area, instance);
-            } else if self.state == nsm::State::Loading {
+            } else if self.dd_state() == nsm::State::Loading {
                 // Database loading has completed.
                 instance.tx.protocol_input.nsm_event(
                     area.id,
@@ -542,8 +653,11 @@ where
         self.options = None;
         self.last_rcvd_dbdesc = None;
         self.last_sent_dbdesc = None;
+        self.oob_resync = None;
+        self.dd_flags.remove(DbDescFlags::R);
         self.lists = Default::default();
         self.tasks.dbdesc_free_timer = None;
+        self.tasks.resync_timer = None;
         self.tasks.rxmt_dbdesc = None;
         self.tasks.rxmt_lsreq = None;
         self.tasks.rxmt_lsupd = None;
@@ -8,7 +8,6 @@ use std::io::{IoSlice, IoSliceMut};
 use std::ops::Deref;
 use std::os::fd::AsRawFd;
 use std::sync::Arc;
-use std::sync::atomic::AtomicU64;
 
 use arc_swap::ArcSwap;
 use bytes::Bytes;
@@ -23,7 +22,9 @@ use crate::collections::{AreaId, InterfaceId};
 use crate::debug::Debug;
 use crate::error::{Error, IoError};
 use crate::northbound::configuration::TraceOptionPacketResolved;
-use crate::packet::auth::{AuthDecodeCtx, AuthEncodeCtx, AuthMethod};
+use crate::packet::auth::{
+    AuthDecodeCtx, AuthEncodeCtx, AuthMethod, AuthSeqNo,
+};
 use crate::packet::error::DecodeResult;
 use crate::packet::{Packet, PacketHdrVersion};
 use crate::tasks::messages::input::NetRxPacketMsg;
@@ -145,7 +146,8 @@ pub(crate) async fn write_loop<V>(
     ifindex: u32,
     src: V::NetIpAddr,
     auth: Arc<ArcSwap<Option<AuthMethod>>>,
-    auth_seqno: Arc<AtomicU64>,
+    auth_seqno: Arc<AuthSeqNo>,
+    auth_ext_seqno: bool,
     trace_opts: Arc<ArcSwap<TraceOptionPacketResolved>>,
     mut net_tx_packetc: UnboundedReceiver<NetTxPacketMsg<V>>,
 ) where
@@ -169,7 +171,12 @@ pub(crate) async fn write_loop<V>(
                         }
                     }
                 };
-                Some(AuthEncodeCtx::new(auth_key, &auth_seqno, src.into()))
+                Some(AuthEncodeCtx::new(
+                    auth_key,
+                    &auth_seqno,
+                    auth_ext_seqno,
+                    src.into(),
+                ))
             }
             None => None,
         };
@@ -201,6 +208,7 @@ pub(crate) async fn read_loop<V>(
     iface_id: InterfaceId,
     af: AddressFamily,
     auth: Arc<ArcSwap<Option<AuthMethod>>>,
+    auth_ext_seqno: bool,
     net_packet_rxp: Sender<NetRxPacketMsg<V>>,
 ) -> Result<(), SendError<NetRxPacketMsg<V>>>
 where
@@ -248,10 +256,9 @@ where
                 let mut buf = Bytes::copy_from_slice(&iov[0].deref()[0..bytes]);
                 let auth_guard = auth.load();
                 let packet = V::validate_ip_hdr(&mut buf).and_then(|_| {
-                    let auth = auth_guard
-                        .as_ref()
-                        .as_ref()
-                        .map(|auth| AuthDecodeCtx::new(auth, src.into()));
+                    let auth = auth_guard.as_ref().as_ref().map(|auth| {
+                        AuthDecodeCtx::new(auth, auth_ext_seqno, src.into())
+                    });
                     Packet::decode(af, &mut buf, auth)
                 });
                 let msg = NetRxPacketMsg {
@@ -15,20 +15,21 @@ use holo_utils::bfd;
 use holo_utils::crypto::CryptoAlgo;
 use holo_utils::ip::{AddressFamily, IpAddrKind, IpNetworkKind};
 use holo_utils::protocol::Protocol;
+use holo_utils::sr::SrCfgEvent;
 use holo_utils::yang::DataNodeRefExt;
 use holo_yang::{ToYang, TryFromYang};
 use yang5::data::Data;
 
-use crate::area::{self, AreaType, BACKBONE_AREA_ID};
+use crate::area::{self, AreaType, BACKBONE_AREA_ID, NssaTranslatorRole};
 use crate::collections::{AreaIndex, InterfaceIndex};
 use crate::debug::InterfaceInactiveReason;
 use crate::instance::Instance;
-use crate::interface::{InterfaceType, VirtualLinkKey, ism};
+use crate::interface::{self, InterfaceType, MultiAreaAdj, VirtualLinkKey, ism};
 use crate::lsdb::LsaOriginateEvent;
 use crate::neighbor::nsm;
 use crate::northbound::yang_gen::ospf;
-use crate::packet::iana::PacketType;
-use crate::route::RouteNetFlags;
+use crate::packet::iana::{FloodingAlgo, IgpMetricType, PacketType};
+use crate::route::{ExternalMetricType, RouteNetFlags};
 use crate::version::{Ospfv2, Ospfv3, Version};
 use crate::{gr, ibus, spf, sr, tasks};
 
@@ -36,11 +37,14 @@ use crate::{gr, ibus, spf, sr, tasks};
 pub enum ListEntry<V: Version> {
     None,
     NodeTag(u32),
+    Redistribution(Protocol),
+    FlexAlgo(u8),
     TraceOption(InstanceTraceOption),
     Area(AreaIndex),
     AreaRange(AreaIndex, V::IpNetwork),
     Interface(AreaIndex, InterfaceIndex),
     StaticNbr(InterfaceIndex, V::NetIpAddr),
+    MultiArea(InterfaceIndex, Ipv4Addr),
     InterfaceTraceOption(InterfaceIndex, InterfaceTraceOption),
 }
 
@@ -64,20 +68,33 @@ pub enum Event {
     InterfacePriorityChange(AreaIndex, InterfaceIndex),
     InterfaceCostChange(AreaIndex),
     InterfaceFlagChange(AreaIndex),
+    InterfacePrefixSuppressionChange(AreaIndex, InterfaceIndex),
     InterfaceSyncHelloTx(AreaIndex, InterfaceIndex),
     InterfaceUpdateAuth(AreaIndex, InterfaceIndex),
     InterfaceBfdChange(InterfaceIndex),
     InterfaceUpdateTraceOptions(InterfaceIndex),
     InterfaceIbusSub(String),
     StubRouterChange,
+    LdpSyncChange,
+    PrefixSuppressionUpdate,
     GrHelperChange,
     SrEnableChange(bool),
+    SrMappingServerChange,
+    FlexAlgoChange,
+    InterfaceLinkAttrChange(AreaIndex),
+    FloodingReductionChange(AreaIndex),
     RerunSpf,
     UpdateVirtualLinks,
+    UpdateMultiAreaAdjs,
     UpdateSummaries,
     ReinstallRoutes,
     BierEnableChange(bool),
     NodeTagsChange,
+    RedistributeAdd(Protocol),
+    RedistributeDelete(Protocol),
+    DefaultInfoOriginateAdd,
+    DefaultInfoOriginateDelete,
+    UpdateExternals,
     UpdateTraceOptions,
 }
 
@@ -102,9 +119,18 @@ pub struct InstanceCfg {
     pub spf_hold_down: u32,
     pub spf_time_to_learn: u32,
     pub stub_router: bool,
+    pub ldp_igp_sync: bool,
+    pub ldp_igp_sync_holddown: u16,
+    pub prefix_suppression: bool,
     pub node_tags: BTreeSet<u32>,
+    pub redistribution: BTreeMap<Protocol, RedistributionCfg>,
+    pub default_info_originate: Option<DefaultInfoOriginateCfg>,
     pub extended_lsa: bool,
     pub sr_enabled: bool,
+    pub sr_ms_advertise_policies: BTreeSet<String>,
+    pub sr_ms_receive: bool,
+    pub flex_algo: BTreeMap<u8, FlexAlgoCfg>,
+    pub lfa: LfaCfg,
     pub instance_id: u8,
     pub bier: BierOspfCfg,
     pub trace_opts: InstanceTraceOptions,
@@ -125,8 +151,42 @@ pub struct Preference {
     pub external: u8,
 }
 
+#[derive(Debug)]
+pub struct RedistributionCfg {
+    pub metric: Option<u32>,
+    pub metric_type: ExternalMetricType,
+    pub tag: Option<u32>,
+    pub route_policy: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct DefaultInfoOriginateCfg {
+    pub always: bool,
+    pub metric: u32,
+    pub metric_type: ExternalMetricType,
+}
+
+#[derive(Debug)]
+pub struct LfaCfg {
+    pub prefer_node_protection: bool,
+    pub downstream_only: bool,
+}
+
+#[derive(Debug)]
+pub struct FlexAlgoCfg {
+    pub advertise_definition: bool,
+    pub metric_type: IgpMetricType,
+    pub priority: u8,
+    pub exclude_any: BTreeSet<u16>,
+    pub include_any: BTreeSet<u16>,
+    pub include_all: BTreeSet<u16>,
+    pub exclude_srlgs: BTreeSet<u32>,
+}
+
 #[derive(Debug)]
 pub struct InstanceGrCfg {
+    pub enabled: bool,
+    pub restart_interval: u16,
     pub helper_enabled: bool,
     pub helper_strict_lsa_checking: bool,
 }
@@ -163,6 +223,10 @@ pub struct AreaCfg {
     pub area_type: AreaType,
     pub summary: bool,
     pub default_cost: u32,
+    pub nssa_translator_role: NssaTranslatorRole,
+    pub nssa_default_originate: bool,
+    pub nssa_translator_stability_interval: u16,
+    pub flooding_algo: FloodingAlgo,
 }
 
 #[derive(Debug)]
@@ -184,17 +248,38 @@ pub struct InterfaceCfg<V: Version> {
     pub enabled: bool,
     pub cost: u16,
     pub mtu_ignore: bool,
+    pub prefix_suppression: InheritableConfig<bool>,
     pub node_flag: bool,
     pub anycast_flag: bool,
     pub static_nbrs: BTreeMap<V::NetIpAddr, StaticNbr>,
+    pub multi_areas: BTreeMap<Ipv4Addr, MultiAreaCfg<V>>,
     pub auth_keychain: Option<String>,
     pub auth_keyid: Option<u32>,
     pub auth_key: Option<String>,
     pub auth_algo: Option<CryptoAlgo>,
+    pub auth_ext_seqno: bool,
     pub bfd_enabled: bool,
     pub bfd_params: bfd::ClientCfg,
     pub trace_opts: InterfaceTraceOptions,
     pub lls_enabled: bool,
+    pub link_attrs: LinkAttrsCfg,
+    pub lfa: InterfaceLfaCfg,
+}
+
+#[derive(Debug)]
+pub struct InterfaceLfaCfg {
+    pub candidate_enabled: bool,
+    pub enabled: bool,
+    pub remote_lfa_enabled: bool,
+}
+
+#[derive(Debug, Default)]
+pub struct LinkAttrsCfg {
+    pub te_metric: Option<u32>,
+    pub min_delay: Option<u32>,
+    pub max_delay: Option<u32>,
+    pub admin_groups: BTreeSet<u16>,
+    pub srlgs: BTreeSet<u32>,
 }
 
 #[derive(Debug)]
@@ -204,6 +289,12 @@ pub struct StaticNbr {
     pub priority: u8,
 }
 
+#[derive(Debug)]
+pub struct MultiAreaCfg<V: Version> {
+    pub cost: Option<u16>,
+    pub nbr_addr: Option<V::NetIpAddr>,
+}
+
 #[derive(Clone, Copy, Debug)]
 pub enum InterfaceTraceOption {
     PacketsAll,
@@ -378,6 +469,16 @@ where
             let event_queue = args.event_queue;
             event_queue.insert(Event::ReinstallRoutes);
         })
+        .path(ospf::graceful_restart::enabled::PATH)
+        .modify_apply(|instance, args| {
+            let enabled = args.dnode.get_bool();
+            instance.config.gr.enabled = enabled;
+        })
+        .path(ospf::graceful_restart::restart_interval::PATH)
+        .modify_apply(|instance, args| {
+            let restart_interval = args.dnode.get_u16();
+            instance.config.gr.restart_interval = restart_interval;
+        })
         .path(ospf::graceful_restart::helper_enabled::PATH)
         .modify_apply(|instance, args| {
             let enabled = args.dnode.get_bool();
@@ -437,6 +538,49 @@ where
             let event_queue = args.event_queue;
             event_queue.insert(Event::StubRouterChange);
         })
+        .path(ospf::mpls::ldp::igp_sync::PATH)
+        .modify_apply(|instance, args| {
+            let igp_sync = args.dnode.get_bool();
+            instance.config.ldp_igp_sync = igp_sync;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LdpSyncChange);
+        })
+        .delete_apply(|instance, args| {
+            instance.config.ldp_igp_sync = false;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LdpSyncChange);
+        })
+        .path(ospf::mpls::ldp::igp_sync_holddown::PATH)
+        .modify_apply(|instance, args| {
+            let holddown = args.dnode.get_u16();
+            instance.config.ldp_igp_sync_holddown = holddown;
+        })
+        .path(ospf::prefix_suppression::PATH)
+        .modify_apply(|instance, args| {
+            let prefix_suppression = args.dnode.get_bool();
+            instance.config.prefix_suppression = prefix_suppression;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::PrefixSuppressionUpdate);
+        })
+        .path(ospf::fast_reroute::lfa::prefer_node_protection::PATH)
+        .modify_apply(|instance, args| {
+            let prefer_node_protection = args.dnode.get_bool();
+            instance.config.lfa.prefer_node_protection = prefer_node_protection;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::RerunSpf);
+        })
+        .path(ospf::fast_reroute::lfa::downstream_only::PATH)
+        .modify_apply(|instance, args| {
+            let downstream_only = args.dnode.get_bool();
+            instance.config.lfa.downstream_only = downstream_only;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::RerunSpf);
+        })
         .path(ospf::node_tags::node_tag::PATH)
         .create_apply(|instance, args| {
             let node_tag = args.dnode.get_u32_relative("tag").unwrap();
@@ -456,6 +600,143 @@ where
             let node_tag = dnode.get_u32_relative("tag").unwrap();
             ListEntry::NodeTag(node_tag)
         })
+        .path(ospf::redistribution::PATH)
+        .create_apply(|instance, args| {
+            let protocol = args.dnode.get_string_relative("./type").unwrap();
+            let protocol = Protocol::try_from_yang(&protocol).unwrap();
+            instance.config.redistribution.insert(protocol, Default::default());
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::RedistributeAdd(protocol));
+        })
+        .delete_apply(|instance, args| {
+            let protocol = args.list_entry.into_redistribution().unwrap();
+            instance.config.redistribution.remove(&protocol);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::RedistributeDelete(protocol));
+        })
+        .lookup(|_instance, _list_entry, dnode| {
+            let protocol = dnode.get_string_relative("./type").unwrap();
+            let protocol = Protocol::try_from_yang(&protocol).unwrap();
+            ListEntry::Redistribution(protocol)
+        })
+        .path(ospf::redistribution::metric::PATH)
+        .modify_apply(|instance, args| {
+            let protocol = args.list_entry.into_redistribution().unwrap();
+            let redist_cfg = instance.config.redistribution.get_mut(&protocol).unwrap();
+
+            let metric = args.dnode.get_u32();
+            redist_cfg.metric = Some(metric);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::UpdateExternals);
+        })
+        .delete_apply(|instance, args| {
+            let protocol = args.list_entry.into_redistribution().unwrap();
+            let redist_cfg = instance.config.redistribution.get_mut(&protocol).unwrap();
+
+            redist_cfg.metric = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::UpdateExternals);
+        })
+        .path(ospf::redistribution::metric_type::PATH)
+        .modify_apply(|instance, args| {
+            let protocol = args.list_entry.into_redistribution().unwrap();
+            let redist_cfg = instance.config.redistribution.get_mut(&protocol).unwrap();
+
+            let metric_type = args.dnode.get_string();
+            let metric_type = ExternalMetricType::try_from_yang(&metric_type).unwrap();
+            redist_cfg.metric_type = metric_type;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::UpdateExternals);
+        })
+        .path(ospf::redistribution::tag::PATH)
+        .modify_apply(|instance, args| {
+            let protocol = args.list_entry.into_redistribution().unwrap();
+            let redist_cfg = instance.config.redistribution.get_mut(&protocol).unwrap();
+
+            let tag = args.dnode.get_u32();
+            redist_cfg.tag = Some(tag);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::UpdateExternals);
+        })
+        .delete_apply(|instance, args| {
+            let protocol = args.list_entry.into_redistribution().unwrap();
+            let redist_cfg = instance.config.redistribution.get_mut(&protocol).unwrap();
+
+            redist_cfg.tag = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::UpdateExternals);
+        })
+        .path(ospf::redistribution::route_policy::PATH)
+        .modify_apply(|instance, args| {
+            let protocol = args.list_entry.into_redistribution().unwrap();
+            let redist_cfg = instance.config.redistribution.get_mut(&protocol).unwrap();
+
+            let policy = args.dnode.get_string();
+            redist_cfg.route_policy = Some(policy);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::UpdateExternals);
+        })
+        .delete_apply(|instance, args| {
+            let protocol = args.list_entry.into_redistribution().unwrap();
+            let redist_cfg = instance.config.redistribution.get_mut(&protocol).unwrap();
+
+            redist_cfg.route_policy = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::UpdateExternals);
+        })
+        .path(ospf::default_information_originate::PATH)
+        .create_apply(|instance, args| {
+            instance.config.default_info_originate = Some(Default::default());
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::DefaultInfoOriginateAdd);
+        })
+        .delete_apply(|instance, args| {
+            instance.config.default_info_originate = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::DefaultInfoOriginateDelete);
+        })
+        .path(ospf::default_information_originate::always::PATH)
+        .modify_apply(|instance, args| {
+            let default_cfg = instance.config.default_info_originate.as_mut().unwrap();
+
+            let always = args.dnode.get_bool();
+            default_cfg.always = always;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::UpdateExternals);
+        })
+        .path(ospf::default_information_originate::metric::PATH)
+        .modify_apply(|instance, args| {
+            let default_cfg = instance.config.default_info_originate.as_mut().unwrap();
+
+            let metric = args.dnode.get_u32();
+            default_cfg.metric = metric;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::UpdateExternals);
+        })
+        .path(ospf::default_information_originate::metric_type::PATH)
+        .modify_apply(|instance, args| {
+            let default_cfg = instance.config.default_info_originate.as_mut().unwrap();
+
+            let metric_type = args.dnode.get_string();
+            let metric_type = ExternalMetricType::try_from_yang(&metric_type).unwrap();
+            default_cfg.metric_type = metric_type;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::UpdateExternals);
+        })
         .path(ospf::extended_lsa_support::PATH)
         .modify_apply(|instance, args| {
             let extended_lsa = args.dnode.get_bool();
@@ -475,6 +756,166 @@ where
             let event_queue = args.event_queue;
             event_queue.insert(Event::SrEnableChange(sr_enabled));
         })
+        .path(ospf::segment_routing::bindings::advertise::policies::PATH)
+        .create_apply(|instance, args| {
+            let policy = args.dnode.get_string();
+            instance.config.sr_ms_advertise_policies.insert(policy);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::SrMappingServerChange);
+        })
+        .delete_apply(|instance, args| {
+            let policy = args.dnode.get_string();
+            instance.config.sr_ms_advertise_policies.remove(&policy);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::SrMappingServerChange);
+        })
+        .path(ospf::segment_routing::bindings::receive::PATH)
+        .modify_apply(|instance, args| {
+            let receive = args.dnode.get_bool();
+            instance.config.sr_ms_receive = receive;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::RerunSpf);
+        })
+        .path(ospf::flex_algo::definition::PATH)
+        .create_apply(|instance, args| {
+            let algo = args.dnode.get_u8_relative("./algo-number").unwrap();
+            instance.config.flex_algo.insert(algo, Default::default());
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
+        .delete_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            instance.config.flex_algo.remove(&algo);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
+        .lookup(|_instance, _list_entry, dnode| {
+            let algo = dnode.get_u8_relative("./algo-number").unwrap();
+            ListEntry::FlexAlgo(algo)
+        })
+        .path(ospf::flex_algo::definition::advertise_definition::PATH)
+        .modify_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let fa_cfg = instance.config.flex_algo.get_mut(&algo).unwrap();
+
+            let advertise = args.dnode.get_bool();
+            fa_cfg.advertise_definition = advertise;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
+        .path(ospf::flex_algo::definition::metric_type::PATH)
+        .modify_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let fa_cfg = instance.config.flex_algo.get_mut(&algo).unwrap();
+
+            let metric_type = args.dnode.get_string();
+            let metric_type = IgpMetricType::try_from_yang(&metric_type).unwrap();
+            fa_cfg.metric_type = metric_type;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
+        .path(ospf::flex_algo::definition::priority::PATH)
+        .modify_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let fa_cfg = instance.config.flex_algo.get_mut(&algo).unwrap();
+
+            let priority = args.dnode.get_u8();
+            fa_cfg.priority = priority;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
+        .path(ospf::flex_algo::definition::admin_groups::exclude_any::PATH)
+        .create_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let fa_cfg = instance.config.flex_algo.get_mut(&algo).unwrap();
+
+            let bit = args.dnode.get_u16();
+            fa_cfg.exclude_any.insert(bit);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
+        .delete_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let fa_cfg = instance.config.flex_algo.get_mut(&algo).unwrap();
+
+            let bit = args.dnode.get_u16();
+            fa_cfg.exclude_any.remove(&bit);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
+        .path(ospf::flex_algo::definition::admin_groups::include_any::PATH)
+        .create_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let fa_cfg = instance.config.flex_algo.get_mut(&algo).unwrap();
+
+            let bit = args.dnode.get_u16();
+            fa_cfg.include_any.insert(bit);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
+        .delete_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let fa_cfg = instance.config.flex_algo.get_mut(&algo).unwrap();
+
+            let bit = args.dnode.get_u16();
+            fa_cfg.include_any.remove(&bit);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
+        .path(ospf::flex_algo::definition::admin_groups::include_all::PATH)
+        .create_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let fa_cfg = instance.config.flex_algo.get_mut(&algo).unwrap();
+
+            let bit = args.dnode.get_u16();
+            fa_cfg.include_all.insert(bit);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
+        .delete_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let fa_cfg = instance.config.flex_algo.get_mut(&algo).unwrap();
+
+            let bit = args.dnode.get_u16();
+            fa_cfg.include_all.remove(&bit);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
+        .path(ospf::flex_algo::definition::exclude_srlgs::PATH)
+        .create_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let fa_cfg = instance.config.flex_algo.get_mut(&algo).unwrap();
+
+            let srlg = args.dnode.get_u32();
+            fa_cfg.exclude_srlgs.insert(srlg);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
+        .delete_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let fa_cfg = instance.config.flex_algo.get_mut(&algo).unwrap();
+
+            let srlg = args.dnode.get_u32();
+            fa_cfg.exclude_srlgs.remove(&srlg);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
         .path(ospf::areas::area::PATH)
         .create_apply(|instance, args| {
             let area_id = args.dnode.get_ipv4_relative("area-id").unwrap();
@@ -482,6 +923,7 @@ where
 
             let event_queue = args.event_queue;
             event_queue.insert(Event::AreaCreate(area_idx));
+            event_queue.insert(Event::UpdateMultiAreaAdjs);
         })
         .delete_apply(|_instance, args| {
             let area_idx = args.list_entry.into_area().unwrap();
@@ -489,6 +931,7 @@ where
             let event_queue = args.event_queue;
             event_queue.insert(Event::AreaDelete(area_idx));
             event_queue.insert(Event::RerunSpf);
+            event_queue.insert(Event::UpdateMultiAreaAdjs);
         })
         .lookup(|instance, _list_entry, dnode| {
             let area_id = dnode.get_ipv4_relative("./area-id").unwrap();
@@ -504,6 +947,11 @@ where
             area.config.area_type = area_type;
             area.config.summary = ospf::areas::area::summary::DFLT;
             area.config.default_cost = ospf::areas::area::default_cost::DFLT;
+            let translator_role = ospf::areas::area::nssa::translator_role::DFLT;
+            let translator_role = NssaTranslatorRole::try_from_yang(translator_role).unwrap();
+            area.config.nssa_translator_role = translator_role;
+            area.config.nssa_default_originate = ospf::areas::area::nssa::default_originate::DFLT;
+            area.config.nssa_translator_stability_interval = ospf::areas::area::nssa::translator_stability_interval::DFLT;
 
             let event_queue = args.event_queue;
             event_queue.insert(Event::AreaTypeChange(area_idx));
@@ -537,7 +985,59 @@ where
         .delete_apply(|_instance, _args| {
             // Nothing to do.
         })
-        .path(ospf::areas::area::ranges::range::PATH)
+        .path(ospf::areas::area::nssa::translator_role::PATH)
+        .modify_apply(|instance, args| {
+            let area_idx = args.list_entry.into_area().unwrap();
+            let area = &mut instance.arenas.areas[area_idx];
+
+            let translator_role = args.dnode.get_string();
+            let translator_role = NssaTranslatorRole::try_from_yang(&translator_role).unwrap();
+            area.config.nssa_translator_role = translator_role;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::UpdateSummaries);
+        })
+        .delete_apply(|_instance, _args| {
+            // Nothing to do.
+        })
+        .path(ospf::areas::area::nssa::default_originate::PATH)
+        .modify_apply(|instance, args| {
+            let area_idx = args.list_entry.into_area().unwrap();
+            let area = &mut instance.arenas.areas[area_idx];
+
+            let default_originate = args.dnode.get_bool();
+            area.config.nssa_default_originate = default_originate;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::UpdateSummaries);
+        })
+        .delete_apply(|_instance, _args| {
+            // Nothing to do.
+        })
+        .path(ospf::areas::area::nssa::translator_stability_interval::PATH)
+        .modify_apply(|instance, args| {
+            let area_idx = args.list_entry.into_area().unwrap();
+            let area = &mut instance.arenas.areas[area_idx];
+
+            let interval = args.dnode.get_u16();
+            area.config.nssa_translator_stability_interval = interval;
+        })
+        .delete_apply(|_instance, _args| {
+            // Nothing to do.
+        })
+.path(ospf::areas::area::flooding_reduction::algorithm::PATH)
+        .modify_apply(|instance, args| {
+            let area_idx = args.list_entry.into_area().unwrap();
+            let area = &mut instance.arenas.areas[area_idx];
+
+            let algo = args.dnode.get_string();
+            let algo = FloodingAlgo::try_from_yang(&algo).unwrap();
+            area.config.flooding_algo = algo;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FloodingReductionChange(area_idx));
+        })
+                .path(ospf::areas::area::ranges::range::PATH)
         .create_apply(|instance, args| {
             let area_idx = args.list_entry.into_area().unwrap();
             let area = &mut instance.arenas.areas[area_idx];
@@ -701,13 +1201,15 @@ where
             let area = &mut instance.arenas.areas[area_idx];
 
             let ifname = args.dnode.get_string_relative("name").unwrap();
-            let (iface_idx, _) = area.interfaces.insert(&mut instance.arenas.interfaces, ifname.clone(), None);
+            let (iface_idx, iface) = area.interfaces.insert(&mut instance.arenas.interfaces, ifname.clone(), None);
+            iface.config.prefix_suppression.resolved = instance.config.prefix_suppression;
 
             let event_queue = args.event_queue;
             event_queue.insert(Event::InstanceUpdate);
             event_queue.insert(Event::InterfaceUpdate(area_idx, iface_idx));
             event_queue.insert(Event::InterfaceUpdateTraceOptions(iface_idx));
             event_queue.insert(Event::InterfaceIbusSub(ifname));
+            event_queue.insert(Event::UpdateMultiAreaAdjs);
         })
         .delete_apply(|_instance, args| {
             let (area_idx, iface_idx) = args.list_entry.into_interface().unwrap();
@@ -715,6 +1217,7 @@ where
             let event_queue = args.event_queue;
             event_queue.insert(Event::InstanceUpdate);
             event_queue.insert(Event::InterfaceDelete(area_idx, iface_idx));
+            event_queue.insert(Event::UpdateMultiAreaAdjs);
         })
         .lookup(|instance, list_entry, dnode| {
             let area_idx = list_entry.into_area().unwrap();
@@ -748,6 +1251,7 @@ where
 
             let event_queue = args.event_queue;
             event_queue.insert(Event::InterfaceReset(area_idx, iface_idx));
+            event_queue.insert(Event::UpdateMultiAreaAdjs);
         })
         .path(ospf::areas::area::interfaces::interface::priority::PATH)
         .modify_apply(|instance, args| {
@@ -817,6 +1321,76 @@ where
             let priority = args.dnode.get_u8();
             snbr.priority = priority;
         })
+        .path(ospf::areas::area::interfaces::interface::multi_areas::multi_area::PATH)
+        .create_apply(|instance, args| {
+            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let area_id = args.dnode.get_ipv4_relative("multi-area-id").unwrap();
+            iface.config.multi_areas.insert(area_id, Default::default());
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::UpdateMultiAreaAdjs);
+        })
+        .delete_apply(|instance, args| {
+            let (iface_idx, area_id) = args.list_entry.into_multi_area().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            iface.config.multi_areas.remove(&area_id);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::UpdateMultiAreaAdjs);
+        })
+        .lookup(|_instance, list_entry, dnode| {
+            let (_, iface_idx) = list_entry.into_interface().unwrap();
+
+            let area_id = dnode.get_ipv4_relative("./multi-area-id").unwrap();
+            ListEntry::MultiArea(iface_idx, area_id)
+        })
+        .path(ospf::areas::area::interfaces::interface::multi_areas::multi_area::cost::PATH)
+        .modify_apply(|instance, args| {
+            let (iface_idx, area_id) = args.list_entry.into_multi_area().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+            let multi_area = iface.config.multi_areas.get_mut(&area_id).unwrap();
+
+            let cost = args.dnode.get_u16();
+            multi_area.cost = Some(cost);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::UpdateMultiAreaAdjs);
+        })
+        .delete_apply(|instance, args| {
+            let (iface_idx, area_id) = args.list_entry.into_multi_area().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+            let multi_area = iface.config.multi_areas.get_mut(&area_id).unwrap();
+
+            multi_area.cost = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::UpdateMultiAreaAdjs);
+        })
+        .path(ospf::areas::area::interfaces::interface::multi_areas::multi_area::neighbor_address::PATH)
+        .modify_apply(|instance, args| {
+            let (iface_idx, area_id) = args.list_entry.into_multi_area().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+            let multi_area = iface.config.multi_areas.get_mut(&area_id).unwrap();
+
+            let nbr_addr = args.dnode.get_ip();
+            multi_area.nbr_addr = V::NetIpAddr::get(nbr_addr);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::UpdateMultiAreaAdjs);
+        })
+        .delete_apply(|instance, args| {
+            let (iface_idx, area_id) = args.list_entry.into_multi_area().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+            let multi_area = iface.config.multi_areas.get_mut(&area_id).unwrap();
+
+            multi_area.nbr_addr = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::UpdateMultiAreaAdjs);
+        })
         .path(ospf::areas::area::interfaces::interface::bfd::enabled::PATH)
         .modify_apply(|instance, args| {
             let (_, iface_idx) = args.list_entry.into_interface().unwrap();
@@ -867,90 +1441,237 @@ where
         .delete_apply(|_instance, _args| {
             // Nothing to do.
         })
-        .path(ospf::areas::area::interfaces::interface::bfd::min_interval::PATH)
+        .path(ospf::areas::area::interfaces::interface::bfd::min_interval::PATH)
+        .modify_apply(|instance, args| {
+            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let min_interval = args.dnode.get_u32();
+            iface.config.bfd_params.min_tx = min_interval;
+            iface.config.bfd_params.min_rx = min_interval;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceBfdChange(iface_idx));
+        })
+        .delete_apply(|_instance, _args| {
+            // Nothing to do.
+        })
+        .path(ospf::areas::area::interfaces::interface::hello_interval::PATH)
+        .modify_apply(|instance, args| {
+            let (area_idx, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let hello_interval = args.dnode.get_u16();
+            iface.config.hello_interval = hello_interval;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceResetHelloInterval(area_idx, iface_idx));
+            event_queue.insert(Event::InterfaceSyncHelloTx(area_idx, iface_idx));
+            event_queue.insert(Event::UpdateMultiAreaAdjs);
+        })
+        .path(ospf::areas::area::interfaces::interface::dead_interval::PATH)
+        .modify_apply(|instance, args| {
+            let (area_idx, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let dead_interval = args.dnode.get_u16();
+            iface.config.dead_interval = dead_interval;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceResetDeadInterval(area_idx, iface_idx));
+            event_queue.insert(Event::InterfaceSyncHelloTx(area_idx, iface_idx));
+            event_queue.insert(Event::UpdateMultiAreaAdjs);
+        })
+        .path(ospf::areas::area::interfaces::interface::retransmit_interval::PATH)
+        .modify_apply(|instance, args| {
+            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let retransmit_interval = args.dnode.get_u16();
+            iface.config.retransmit_interval = retransmit_interval;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::UpdateMultiAreaAdjs);
+        })
+        .path(ospf::areas::area::interfaces::interface::transmit_delay::PATH)
+        .modify_apply(|instance, args| {
+            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let transmit_delay = args.dnode.get_u16();
+            iface.config.transmit_delay = transmit_delay;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::UpdateMultiAreaAdjs);
+        })
+        .path(ospf::areas::area::interfaces::interface::lls::PATH)
+        .modify_apply(|instance, args| {
+            let (_area_idx, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let enabled = args.dnode.get_bool();
+            iface.config.lls_enabled = enabled;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::UpdateMultiAreaAdjs);
+        })
+        .path(ospf::areas::area::interfaces::interface::enabled::PATH)
+        .modify_apply(|instance, args| {
+            let (area_idx, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let enabled = args.dnode.get_bool();
+            iface.config.enabled = enabled;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceUpdate(area_idx, iface_idx));
+            event_queue.insert(Event::UpdateMultiAreaAdjs);
+        })
+        .path(ospf::areas::area::interfaces::interface::fast_reroute::lfa::candidate_enabled::PATH)
+        .modify_apply(|instance, args| {
+            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let candidate_enabled = args.dnode.get_bool();
+            iface.config.lfa.candidate_enabled = candidate_enabled;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::RerunSpf);
+        })
+        .path(ospf::areas::area::interfaces::interface::fast_reroute::lfa::enabled::PATH)
+        .modify_apply(|instance, args| {
+            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let enabled = args.dnode.get_bool();
+            iface.config.lfa.enabled = enabled;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::RerunSpf);
+        })
+        .path(ospf::areas::area::interfaces::interface::fast_reroute::lfa::remote_lfa::enabled::PATH)
+        .modify_apply(|instance, args| {
+            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let enabled = args.dnode.get_bool();
+            iface.config.lfa.remote_lfa_enabled = enabled;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::RerunSpf);
+        })
+        .path(ospf::areas::area::interfaces::interface::cost::PATH)
+        .modify_apply(|instance, args| {
+            let (area_idx, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let cost = args.dnode.get_u16();
+            iface.config.cost = cost;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceCostChange(area_idx));
+        })
+        .path(ospf::areas::area::interfaces::interface::flex_algo_link_attributes::te_metric::PATH)
+        .modify_apply(|instance, args| {
+            let (area_idx, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let te_metric = args.dnode.get_u32();
+            iface.config.link_attrs.te_metric = Some(te_metric);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceLinkAttrChange(area_idx));
+        })
+        .delete_apply(|instance, args| {
+            let (area_idx, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            iface.config.link_attrs.te_metric = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceLinkAttrChange(area_idx));
+        })
+        .path(ospf::areas::area::interfaces::interface::flex_algo_link_attributes::min_delay::PATH)
         .modify_apply(|instance, args| {
-            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
+            let (area_idx, iface_idx) = args.list_entry.into_interface().unwrap();
             let iface = &mut instance.arenas.interfaces[iface_idx];
 
-            let min_interval = args.dnode.get_u32();
-            iface.config.bfd_params.min_tx = min_interval;
-            iface.config.bfd_params.min_rx = min_interval;
+            let min_delay = args.dnode.get_u32();
+            iface.config.link_attrs.min_delay = Some(min_delay);
 
             let event_queue = args.event_queue;
-            event_queue.insert(Event::InterfaceBfdChange(iface_idx));
-        })
-        .delete_apply(|_instance, _args| {
-            // Nothing to do.
+            event_queue.insert(Event::InterfaceLinkAttrChange(area_idx));
         })
-        .path(ospf::areas::area::interfaces::interface::hello_interval::PATH)
-        .modify_apply(|instance, args| {
+        .delete_apply(|instance, args| {
             let (area_idx, iface_idx) = args.list_entry.into_interface().unwrap();
             let iface = &mut instance.arenas.interfaces[iface_idx];
 
-            let hello_interval = args.dnode.get_u16();
-            iface.config.hello_interval = hello_interval;
+            iface.config.link_attrs.min_delay = None;
 
             let event_queue = args.event_queue;
-            event_queue.insert(Event::InterfaceResetHelloInterval(area_idx, iface_idx));
-            event_queue.insert(Event::InterfaceSyncHelloTx(area_idx, iface_idx));
+            event_queue.insert(Event::InterfaceLinkAttrChange(area_idx));
         })
-        .path(ospf::areas::area::interfaces::interface::dead_interval::PATH)
+        .path(ospf::areas::area::interfaces::interface::flex_algo_link_attributes::max_delay::PATH)
         .modify_apply(|instance, args| {
             let (area_idx, iface_idx) = args.list_entry.into_interface().unwrap();
             let iface = &mut instance.arenas.interfaces[iface_idx];
 
-            let dead_interval = args.dnode.get_u16();
-            iface.config.dead_interval = dead_interval;
+            let max_delay = args.dnode.get_u32();
+            iface.config.link_attrs.max_delay = Some(max_delay);
 
             let event_queue = args.event_queue;
-            event_queue.insert(Event::InterfaceResetDeadInterval(area_idx, iface_idx));
-            event_queue.insert(Event::InterfaceSyncHelloTx(area_idx, iface_idx));
+            event_queue.insert(Event::InterfaceLinkAttrChange(area_idx));
         })
-        .path(ospf::areas::area::interfaces::interface::retransmit_interval::PATH)
-        .modify_apply(|instance, args| {
-            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
+        .delete_apply(|instance, args| {
+            let (area_idx, iface_idx) = args.list_entry.into_interface().unwrap();
             let iface = &mut instance.arenas.interfaces[iface_idx];
 
-            let retransmit_interval = args.dnode.get_u16();
-            iface.config.retransmit_interval = retransmit_interval;
+            iface.config.link_attrs.max_delay = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceLinkAttrChange(area_idx));
         })
-        .path(ospf::areas::area::interfaces::interface::transmit_delay::PATH)
-        .modify_apply(|instance, args| {
-            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
+        .path(ospf::areas::area::interfaces::interface::flex_algo_link_attributes::admin_groups::PATH)
+        .create_apply(|instance, args| {
+            let (area_idx, iface_idx) = args.list_entry.into_interface().unwrap();
             let iface = &mut instance.arenas.interfaces[iface_idx];
 
-            let transmit_delay = args.dnode.get_u16();
-            iface.config.transmit_delay = transmit_delay;
+            let bit = args.dnode.get_u16();
+            iface.config.link_attrs.admin_groups.insert(bit);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceLinkAttrChange(area_idx));
         })
-        .path(ospf::areas::area::interfaces::interface::lls::PATH)
-        .modify_apply(|instance, args| {
-            let (_area_idx, iface_idx) = args.list_entry.into_interface().unwrap();
+        .delete_apply(|instance, args| {
+            let (area_idx, iface_idx) = args.list_entry.into_interface().unwrap();
             let iface = &mut instance.arenas.interfaces[iface_idx];
 
-            let enabled = args.dnode.get_bool();
-            iface.config.lls_enabled = enabled;
+            let bit = args.dnode.get_u16();
+            iface.config.link_attrs.admin_groups.remove(&bit);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceLinkAttrChange(area_idx));
         })
-        .path(ospf::areas::area::interfaces::interface::enabled::PATH)
-        .modify_apply(|instance, args| {
+        .path(ospf::areas::area::interfaces::interface::flex_algo_link_attributes::srlgs::PATH)
+        .create_apply(|instance, args| {
             let (area_idx, iface_idx) = args.list_entry.into_interface().unwrap();
             let iface = &mut instance.arenas.interfaces[iface_idx];
 
-            let enabled = args.dnode.get_bool();
-            iface.config.enabled = enabled;
+            let srlg = args.dnode.get_u32();
+            iface.config.link_attrs.srlgs.insert(srlg);
 
             let event_queue = args.event_queue;
-            event_queue.insert(Event::InterfaceUpdate(area_idx, iface_idx));
+            event_queue.insert(Event::InterfaceLinkAttrChange(area_idx));
         })
-        .path(ospf::areas::area::interfaces::interface::cost::PATH)
-        .modify_apply(|instance, args| {
+        .delete_apply(|instance, args| {
             let (area_idx, iface_idx) = args.list_entry.into_interface().unwrap();
             let iface = &mut instance.arenas.interfaces[iface_idx];
 
-            let cost = args.dnode.get_u16();
-            iface.config.cost = cost;
+            let srlg = args.dnode.get_u32();
+            iface.config.link_attrs.srlgs.remove(&srlg);
 
             let event_queue = args.event_queue;
-            event_queue.insert(Event::InterfaceCostChange(area_idx));
+            event_queue.insert(Event::InterfaceLinkAttrChange(area_idx));
         })
         .path(ospf::areas::area::interfaces::interface::mtu_ignore::PATH)
         .modify_apply(|instance, args| {
@@ -959,6 +1680,31 @@ where
 
             let mtu_ignore = args.dnode.get_bool();
             iface.config.mtu_ignore = mtu_ignore;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::UpdateMultiAreaAdjs);
+        })
+        .path(ospf::areas::area::interfaces::interface::prefix_suppression::PATH)
+        .modify_apply(|instance, args| {
+            let (area_idx, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let prefix_suppression = args.dnode.get_bool();
+            iface.config.prefix_suppression.explicit = Some(prefix_suppression);
+            iface.config.prefix_suppression.resolved = prefix_suppression;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfacePrefixSuppressionChange(area_idx, iface_idx));
+        })
+        .delete_apply(|instance, args| {
+            let (area_idx, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            iface.config.prefix_suppression.explicit = None;
+            iface.config.prefix_suppression.resolved = instance.config.prefix_suppression;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfacePrefixSuppressionChange(area_idx, iface_idx));
         })
         .path(ospf::areas::area::interfaces::interface::node_flag::PATH)
         .modify_apply(|instance, args| {
@@ -1303,6 +2049,26 @@ fn load_callbacks_ospfv2() -> Callbacks<Instance<Ospfv2>> {
             let event_queue = args.event_queue;
             event_queue.insert(Event::InterfaceUpdateAuth(area_idx, iface_idx));
         })
+        .path(ospf::areas::area::virtual_links::virtual_link::authentication::ospfv2_auth_trailer_rfc::PATH)
+        .modify_apply(|instance, args| {
+            let (area_idx, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let auth_trailer_rfc = args.dnode.get_string();
+            iface.config.auth_ext_seqno = auth_trailer_rfc == "rfc7474";
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceReset(area_idx, iface_idx));
+        })
+        .delete_apply(|instance, args| {
+            let (area_idx, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            iface.config.auth_ext_seqno = false;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceReset(area_idx, iface_idx));
+        })
         .path(ospf::areas::area::interfaces::interface::authentication::ospfv2_key_chain::PATH)
         .modify_apply(|instance, args| {
             let (area_idx, iface_idx) = args.list_entry.into_interface().unwrap();
@@ -1313,6 +2079,7 @@ fn load_callbacks_ospfv2() -> Callbacks<Instance<Ospfv2>> {
 
             let event_queue = args.event_queue;
             event_queue.insert(Event::InterfaceUpdateAuth(area_idx, iface_idx));
+            event_queue.insert(Event::UpdateMultiAreaAdjs);
         })
         .delete_apply(|instance, args| {
             let (area_idx, iface_idx) = args.list_entry.into_interface().unwrap();
@@ -1322,6 +2089,7 @@ fn load_callbacks_ospfv2() -> Callbacks<Instance<Ospfv2>> {
 
             let event_queue = args.event_queue;
             event_queue.insert(Event::InterfaceUpdateAuth(area_idx, iface_idx));
+            event_queue.insert(Event::UpdateMultiAreaAdjs);
         })
         .path(ospf::areas::area::interfaces::interface::authentication::ospfv2_key_id::PATH)
         .modify_apply(|instance, args| {
@@ -1333,6 +2101,7 @@ fn load_callbacks_ospfv2() -> Callbacks<Instance<Ospfv2>> {
 
             let event_queue = args.event_queue;
             event_queue.insert(Event::InterfaceUpdateAuth(area_idx, iface_idx));
+            event_queue.insert(Event::UpdateMultiAreaAdjs);
         })
         .delete_apply(|instance, args| {
             let (area_idx, iface_idx) = args.list_entry.into_interface().unwrap();
@@ -1342,6 +2111,7 @@ fn load_callbacks_ospfv2() -> Callbacks<Instance<Ospfv2>> {
 
             let event_queue = args.event_queue;
             event_queue.insert(Event::InterfaceUpdateAuth(area_idx, iface_idx));
+            event_queue.insert(Event::UpdateMultiAreaAdjs);
         })
         .path(ospf::areas::area::interfaces::interface::authentication::ospfv2_key::PATH)
         .modify_apply(|instance, args| {
@@ -1353,6 +2123,7 @@ fn load_callbacks_ospfv2() -> Callbacks<Instance<Ospfv2>> {
 
             let event_queue = args.event_queue;
             event_queue.insert(Event::InterfaceUpdateAuth(area_idx, iface_idx));
+            event_queue.insert(Event::UpdateMultiAreaAdjs);
         })
         .delete_apply(|instance, args| {
             let (area_idx, iface_idx) = args.list_entry.into_interface().unwrap();
@@ -1362,6 +2133,7 @@ fn load_callbacks_ospfv2() -> Callbacks<Instance<Ospfv2>> {
 
             let event_queue = args.event_queue;
             event_queue.insert(Event::InterfaceUpdateAuth(area_idx, iface_idx));
+            event_queue.insert(Event::UpdateMultiAreaAdjs);
         })
         .path(ospf::areas::area::interfaces::interface::authentication::ospfv2_crypto_algorithm::PATH)
         .modify_apply(|instance, args| {
@@ -1374,6 +2146,7 @@ fn load_callbacks_ospfv2() -> Callbacks<Instance<Ospfv2>> {
 
             let event_queue = args.event_queue;
             event_queue.insert(Event::InterfaceUpdateAuth(area_idx, iface_idx));
+            event_queue.insert(Event::UpdateMultiAreaAdjs);
         })
         .delete_apply(|instance, args| {
             let (area_idx, iface_idx) = args.list_entry.into_interface().unwrap();
@@ -1383,6 +2156,29 @@ fn load_callbacks_ospfv2() -> Callbacks<Instance<Ospfv2>> {
 
             let event_queue = args.event_queue;
             event_queue.insert(Event::InterfaceUpdateAuth(area_idx, iface_idx));
+            event_queue.insert(Event::UpdateMultiAreaAdjs);
+        })
+        .path(ospf::areas::area::interfaces::interface::authentication::ospfv2_auth_trailer_rfc::PATH)
+        .modify_apply(|instance, args| {
+            let (area_idx, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let auth_trailer_rfc = args.dnode.get_string();
+            iface.config.auth_ext_seqno = auth_trailer_rfc == "rfc7474";
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceReset(area_idx, iface_idx));
+            event_queue.insert(Event::UpdateMultiAreaAdjs);
+        })
+        .delete_apply(|instance, args| {
+            let (area_idx, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            iface.config.auth_ext_seqno = false;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceReset(area_idx, iface_idx));
+            event_queue.insert(Event::UpdateMultiAreaAdjs);
         })
         .build()
 }
@@ -1514,6 +2310,7 @@ fn load_callbacks_ospfv3() -> Callbacks<Instance<Ospfv3>> {
 
             let event_queue = args.event_queue;
             event_queue.insert(Event::InterfaceUpdateAuth(area_idx, iface_idx));
+            event_queue.insert(Event::UpdateMultiAreaAdjs);
         })
         .delete_apply(|instance, args| {
             let (area_idx, iface_idx) = args.list_entry.into_interface().unwrap();
@@ -1523,6 +2320,7 @@ fn load_callbacks_ospfv3() -> Callbacks<Instance<Ospfv3>> {
 
             let event_queue = args.event_queue;
             event_queue.insert(Event::InterfaceUpdateAuth(area_idx, iface_idx));
+            event_queue.insert(Event::UpdateMultiAreaAdjs);
         })
         .path(ospf::areas::area::interfaces::interface::authentication::ospfv3_sa_id::PATH)
         .modify_apply(|instance, args| {
@@ -1534,6 +2332,7 @@ fn load_callbacks_ospfv3() -> Callbacks<Instance<Ospfv3>> {
 
             let event_queue = args.event_queue;
             event_queue.insert(Event::InterfaceUpdateAuth(area_idx, iface_idx));
+            event_queue.insert(Event::UpdateMultiAreaAdjs);
         })
         .delete_apply(|instance, args| {
             let (area_idx, iface_idx) = args.list_entry.into_interface().unwrap();
@@ -1543,6 +2342,7 @@ fn load_callbacks_ospfv3() -> Callbacks<Instance<Ospfv3>> {
 
             let event_queue = args.event_queue;
             event_queue.insert(Event::InterfaceUpdateAuth(area_idx, iface_idx));
+            event_queue.insert(Event::UpdateMultiAreaAdjs);
         })
         .path(ospf::areas::area::interfaces::interface::authentication::ospfv3_key::PATH)
         .modify_apply(|instance, args| {
@@ -1554,6 +2354,7 @@ fn load_callbacks_ospfv3() -> Callbacks<Instance<Ospfv3>> {
 
             let event_queue = args.event_queue;
             event_queue.insert(Event::InterfaceUpdateAuth(area_idx, iface_idx));
+            event_queue.insert(Event::UpdateMultiAreaAdjs);
         })
         .delete_apply(|instance, args| {
             let (area_idx, iface_idx) = args.list_entry.into_interface().unwrap();
@@ -1563,6 +2364,7 @@ fn load_callbacks_ospfv3() -> Callbacks<Instance<Ospfv3>> {
 
             let event_queue = args.event_queue;
             event_queue.insert(Event::InterfaceUpdateAuth(area_idx, iface_idx));
+            event_queue.insert(Event::UpdateMultiAreaAdjs);
         })
         .path(ospf::areas::area::interfaces::interface::authentication::ospfv3_crypto_algorithm::PATH)
         .modify_apply(|instance, args| {
@@ -1575,6 +2377,7 @@ fn load_callbacks_ospfv3() -> Callbacks<Instance<Ospfv3>> {
 
             let event_queue = args.event_queue;
             event_queue.insert(Event::InterfaceUpdateAuth(area_idx, iface_idx));
+            event_queue.insert(Event::UpdateMultiAreaAdjs);
         })
         .delete_apply(|instance, args| {
             let (area_idx, iface_idx) = args.list_entry.into_interface().unwrap();
@@ -1584,6 +2387,7 @@ fn load_callbacks_ospfv3() -> Callbacks<Instance<Ospfv3>> {
 
             let event_queue = args.event_queue;
             event_queue.insert(Event::InterfaceUpdateAuth(area_idx, iface_idx));
+            event_queue.insert(Event::UpdateMultiAreaAdjs);
         })
         .build()
 }
@@ -1602,16 +2406,24 @@ fn load_validation_callbacks() -> ValidationCallbacks {
 
             Ok(())
         })
+        .path(ospf::areas::area::interfaces::interface::multi_areas::multi_area::PATH)
+        .validate(|args| {
+            // Ensure the multi-area adjacency isn't configured in the primary
+            // interface's own area.
+            let area_id = args.dnode.get_ipv4_relative("./multi-area-id").unwrap();
+            let primary_area_id = args.dnode.get_ipv4_relative("../../../../area-id").unwrap();
+            if area_id == primary_area_id {
+                return Err("multi-area adjacency configured in the primary interface's area".to_string());
+            }
+
+            Ok(())
+        })
         .path(ospf::areas::area::area_type::PATH)
         .validate(|args| {
             let area_type = args.dnode.get_string();
             let Some(area_type) = AreaType::try_from_yang(&area_type) else {
                 return Err("unsupported area type".to_string());
             };
-            if area_type == AreaType::Nssa {
-                return Err("unsupported area type".to_string());
-            }
-
             let area_id = args.dnode.get_ipv4_relative("../area-id").unwrap();
             if area_type != AreaType::Normal && area_id == BACKBONE_AREA_ID {
                 return Err("can't change type of the backbone area".to_string());
@@ -1699,6 +2511,17 @@ fn load_validation_callbacks_ospfv3() -> ValidationCallbacks {
 
             Ok(())
         })
+        .path(ospf::segment_routing::bindings::advertise::policies::PATH)
+        .validate(|args| {
+            let ptype = args.dnode.get_string_relative("../../../../../type").unwrap();
+            let ptype = Protocol::try_from_yang(&ptype).unwrap();
+            if ptype != Protocol::OSPFV3 {
+                return Ok(());
+            }
+
+            // The OSPFv3 Extended Prefix Range TLV isn't supported yet.
+            Err("Segment Routing mapping server isn't supported for OSPFv3".to_string())
+        })
         .path(ospf::areas::area::interfaces::interface::authentication::ospfv3_crypto_algorithm::PATH)
         .validate(|args| {
             let valid_options = [CryptoAlgo::HmacSha1.to_yang(), CryptoAlgo::HmacSha256.to_yang(), CryptoAlgo::HmacSha384.to_yang(), CryptoAlgo::HmacSha512.to_yang()];
@@ -1806,7 +2629,7 @@ where
                     let iface = &mut arenas.interfaces[iface_idx];
 
                     // Cancel ibus subscription.
-                    if !iface.is_virtual_link() {
+                    if !iface.is_virtual_link() && !iface.is_multi_area() {
                         instance.tx.ibus.interface_unsub(Some(iface.name.clone()));
                     }
 
@@ -1894,6 +2717,39 @@ where
                     });
                 }
             }
+            Event::InterfacePrefixSuppressionChange(area_idx, iface_idx) => {
+                if let Some((instance, arenas)) = self.as_up() {
+                    let area = &arenas.areas[area_idx];
+                    let iface = &arenas.interfaces[iface_idx];
+
+                    instance.tx.protocol_input.lsa_orig_event(LsaOriginateEvent::InterfacePrefixSuppressionChange {
+                        area_id: area.id,
+                        iface_id: iface.id,
+                    });
+                }
+            }
+            Event::InterfaceLinkAttrChange(area_idx) => {
+                if let Some((instance, arenas)) = self.as_up() {
+                    let area = &arenas.areas[area_idx];
+
+                    instance.tx.protocol_input.lsa_orig_event(LsaOriginateEvent::InterfaceLinkAttrChange {
+                        area_id: area.id,
+                    });
+                }
+            }
+            Event::FloodingReductionChange(area_idx) => {
+                if let Some((instance, arenas)) = self.as_up() {
+                    let area = &arenas.areas[area_idx];
+
+                    // (Re)originate Router Information LSA.
+                    instance.tx.protocol_input.lsa_orig_event(LsaOriginateEvent::FloodingReductionChange {
+                        area_id: area.id,
+                    });
+
+                    // Recompute the flooding reduction state.
+                    instance.tx.protocol_input.spf_delay_event(spf::fsm::Event::ConfigChange);
+                }
+            }
             Event::InterfaceSyncHelloTx(area_idx, iface_idx) => {
                 if let Some((instance, arenas)) = self.as_up() {
                     let area = &arenas.areas[area_idx];
@@ -1947,6 +2803,27 @@ where
                     instance.tx.protocol_input.lsa_orig_event(LsaOriginateEvent::StubRouterChange);
                 }
             }
+            Event::LdpSyncChange => {
+                if let Some((instance, arenas)) = self.as_up() {
+                    // Update the LDP-IGP synchronization state of all
+                    // interfaces.
+                    interface::ldp_sync_update_all(&instance, arenas);
+                }
+            }
+            Event::PrefixSuppressionUpdate => {
+                for area_idx in self.arenas.areas.indexes().collect::<Vec<_>>() {
+                    let area = &self.arenas.areas[area_idx];
+                    for iface_idx in area.interfaces.indexes().collect::<Vec<_>>() {
+                        let iface = &mut self.arenas.interfaces[iface_idx];
+                        if iface.config.prefix_suppression.explicit.is_some() {
+                            continue;
+                        }
+                        iface.config.prefix_suppression.resolved = self.config.prefix_suppression;
+
+                        self.process_event(Event::InterfacePrefixSuppressionChange(area_idx, iface_idx));
+                    }
+                }
+            }
             Event::GrHelperChange => {
                 if let Some((mut instance, arenas)) = self.as_up() {
                     // Exit from the helper mode for all neighbors.
@@ -1984,6 +2861,24 @@ where
                     }
                 }
             }
+            Event::SrMappingServerChange => {
+                if let Some((instance, _)) = self.as_up()
+                    && instance.config.sr_enabled
+                {
+                    // (Re)originate LSAs that might have been affected.
+                    let change = SrCfgEvent::MappingServerUpdate;
+                    instance.tx.protocol_input.lsa_orig_event(LsaOriginateEvent::SrCfgChange { change });
+                }
+            }
+            Event::FlexAlgoChange => {
+                if let Some((instance, _)) = self.as_up() {
+                    // (Re)originate Router Information LSA(s).
+                    instance.tx.protocol_input.lsa_orig_event(LsaOriginateEvent::FlexAlgoChange);
+
+                    // Recompute the Flexible Algorithm topologies.
+                    instance.tx.protocol_input.spf_delay_event(spf::fsm::Event::ConfigChange);
+                }
+            }
             Event::BierEnableChange(bier_enabled) => {
                 if let Some((instance, _arenas)) = self.as_up() {
                     // (Re)originate LSAs that might have been affected.
@@ -2007,9 +2902,99 @@ where
                     area::update_virtual_links(&instance, &mut arenas.areas, &mut arenas.interfaces, &arenas.lsa_entries);
                 }
             }
+            Event::UpdateMultiAreaAdjs => {
+                // Collect the configured multi-area adjacencies, keyed by
+                // area ID and primary interface name.
+                let mut multi_areas = BTreeMap::new();
+                for area in self.arenas.areas.iter() {
+                    for iface_idx in area.interfaces.indexes() {
+                        let iface = &self.arenas.interfaces[iface_idx];
+                        for area_id in iface.config.multi_areas.keys() {
+                            multi_areas.insert((*area_id, iface.name.clone()), iface_idx);
+                        }
+                    }
+                }
+
+                // Delete multi-area adjacencies that are no longer configured.
+                for area_idx in self.arenas.areas.indexes().collect::<Vec<_>>() {
+                    let area_id = self.arenas.areas[area_idx].area_id;
+                    for iface_idx in self.arenas.areas[area_idx].interfaces.indexes().collect::<Vec<_>>() {
+                        let iface = &self.arenas.interfaces[iface_idx];
+                        if let Some(multi_area) = &iface.multi_area
+                            && !multi_areas.contains_key(&(area_id, multi_area.ifname.clone()))
+                        {
+                            self.process_event(Event::InterfaceDelete(area_idx, iface_idx));
+                        }
+                    }
+                }
+
+                // Create the missing multi-area adjacencies and update the
+                // configuration inherited from their primary interfaces.
+                let mut restart_list = vec![];
+                let mut cost_change_list = BTreeSet::new();
+                for ((area_id, ifname), primary_idx) in multi_areas {
+                    let Some((area_idx, area)) = self.arenas.areas.get_mut_by_area_id(area_id) else {
+                        continue;
+                    };
+
+                    let ma_ifname = interface::multi_area_ifname(&ifname, area_id);
+                    let iface_idx = match area.interfaces.get_by_name(&self.arenas.interfaces, &ma_ifname) {
+                        Some((iface_idx, _)) => iface_idx,
+                        None => {
+                            let (iface_idx, iface) = area.interfaces.insert(&mut self.arenas.interfaces, ma_ifname, None);
+                            iface.multi_area = Some(MultiAreaAdj::new(ifname));
+                            iface.config.update_trace_options(&self.config);
+                            iface_idx
+                        }
+                    };
+
+                    let (primary, iface) = self.arenas.interfaces.get2_mut(primary_idx, iface_idx);
+                    let (primary, iface) = (primary.unwrap(), iface.unwrap());
+                    let ma_cfg = &primary.config.multi_areas[&area_id];
+                    let old_cost = iface.config.cost;
+                    let mut restart = iface.config.update_multi_area(&primary.config, ma_cfg);
+                    let multi_area = iface.multi_area.as_mut().unwrap();
+                    if multi_area.nbr_addr != ma_cfg.nbr_addr {
+                        multi_area.nbr_addr = ma_cfg.nbr_addr;
+                        restart = true;
+                    }
+                    if restart && !iface.is_down() {
+                        restart_list.push((area_idx, iface_idx));
+                    }
+                    if iface.config.cost != old_cost {
+                        cost_change_list.insert(area_idx);
+                    }
+                }
+
+                if let Some((mut instance, arenas)) = self.as_up() {
+                    // Restart multi-area adjacencies whose parameters have
+                    // changed.
+                    for (area_idx, iface_idx) in restart_list {
+                        let area = &arenas.areas[area_idx];
+                        let iface = &mut arenas.interfaces[iface_idx];
+
+                        let reason = InterfaceInactiveReason::Resetting;
+                        iface.fsm(area, &mut instance, &mut arenas.neighbors, &arenas.lsa_entries, ism::Event::InterfaceDown(reason));
+                    }
+
+                    // Reoriginate the Router-LSAs of areas where the cost of
+                    // a multi-area adjacency has changed.
+                    for area_idx in cost_change_list {
+                        let area = &arenas.areas[area_idx];
+
+                        instance.tx.protocol_input.lsa_orig_event(LsaOriginateEvent::InterfaceCostChange {
+                            area_id: area.id,
+                        });
+                    }
+
+                    // Start or stop multi-area adjacencies as necessary.
+                    interface::update_multi_area_adjs(&mut instance, arenas);
+                }
+            }
             Event::UpdateSummaries => {
                 if let Some((mut instance, arenas)) = self.as_up() {
                     area::update_summary_lsas(&mut instance, &mut arenas.areas, &arenas.interfaces, &arenas.lsa_entries);
+                    area::update_nssa(&mut instance, &mut arenas.areas, &arenas.interfaces, &arenas.lsa_entries);
                 }
             }
             Event::ReinstallRoutes => {
@@ -2018,6 +3003,9 @@ where
                         let distance = route.distance(instance.config);
                         ibus::tx::route_install(&instance.tx.ibus, dest, route, None, distance, &arenas.interfaces);
                     }
+                    for route in instance.state.flex_algo_rib.values().flat_map(|rib| rib.values()).filter(|route| route.flags.contains(RouteNetFlags::INSTALLED)) {
+                        ibus::tx::flex_algo_label_install(&instance.tx.ibus, route, None, &arenas.interfaces);
+                    }
                 }
             }
             Event::NodeTagsChange => {
@@ -2025,6 +3013,50 @@ where
                     let _ = V::lsa_orig_event(&instance, arenas, LsaOriginateEvent::NodeTagsChange);
                 }
             }
+            Event::RedistributeAdd(protocol) => {
+                // Subscribe to route redistribution for the given protocol.
+                self.tx.ibus.route_redistribute_sub(protocol, None);
+
+                // Update AS-external LSAs.
+                self.process_event(Event::UpdateExternals);
+            }
+            Event::RedistributeDelete(protocol) => {
+                // Unsubscribe from route redistribution for the given protocol.
+                self.tx.ibus.route_redistribute_unsub(protocol, None);
+
+                // Remove redistributed routes. The default route is kept while
+                // it's tracked for conditional default route origination.
+                let default_info_originate = self.config.default_info_originate.is_some();
+                self.system.routes.retain(|prefix, route| route.protocol != protocol || (default_info_originate && prefix.prefix() == 0));
+
+                // Update AS-external LSAs.
+                self.process_event(Event::UpdateExternals);
+            }
+            Event::DefaultInfoOriginateAdd => {
+                // Subscribe to default route updates, regardless of the
+                // protocols being redistributed.
+                self.tx.ibus.route_default_sub(None);
+
+                // Update AS-external LSAs.
+                self.process_event(Event::UpdateExternals);
+            }
+            Event::DefaultInfoOriginateDelete => {
+                // Unsubscribe from default route updates.
+                self.tx.ibus.route_default_unsub(None);
+
+                // Remove the default route unless its protocol is being
+                // redistributed.
+                let redistribution = &self.config.redistribution;
+                self.system.routes.retain(|prefix, route| prefix.prefix() != 0 || redistribution.contains_key(&route.protocol));
+
+                // Update AS-external LSAs.
+                self.process_event(Event::UpdateExternals);
+            }
+            Event::UpdateExternals => {
+                if let Some((mut instance, arenas)) = self.as_up() {
+                    area::update_externals(&mut instance, &mut arenas.areas, &arenas.interfaces, &arenas.lsa_entries);
+                }
+            }
             Event::UpdateTraceOptions => {
                 for area_idx in self.arenas.areas.indexes().collect::<Vec<_>>() {
                     let area = &mut self.arenas.areas[area_idx];
@@ -2072,6 +3104,43 @@ where
     }
 }
 
+impl<V> InterfaceCfg<V>
+where
+    V: Version,
+{
+    // Updates the configuration of a multi-area adjacency, which is inherited
+    // from its primary interface (RFC 5185). Returns whether any parameter
+    // requiring the adjacency to be restarted has changed.
+    pub(crate) fn update_multi_area(&mut self, primary: &InterfaceCfg<V>, ma_cfg: &MultiAreaCfg<V>) -> bool {
+        let restart = self.instance_id.resolved != primary.instance_id.resolved
+            || self.hello_interval != primary.hello_interval
+            || self.dead_interval != primary.dead_interval
+            || self.auth_keychain != primary.auth_keychain
+            || self.auth_keyid != primary.auth_keyid
+            || self.auth_key != primary.auth_key
+            || self.auth_algo != primary.auth_algo
+            || self.auth_ext_seqno != primary.auth_ext_seqno;
+
+        self.instance_id.resolved = primary.instance_id.resolved;
+        self.if_type = InterfaceType::PointToPoint;
+        self.hello_interval = primary.hello_interval;
+        self.dead_interval = primary.dead_interval;
+        self.retransmit_interval = primary.retransmit_interval;
+        self.transmit_delay = primary.transmit_delay;
+        self.enabled = primary.enabled;
+        self.cost = ma_cfg.cost.unwrap_or(primary.cost);
+        self.mtu_ignore = primary.mtu_ignore;
+        self.lls_enabled = primary.lls_enabled;
+        self.auth_keychain = primary.auth_keychain.clone();
+        self.auth_keyid = primary.auth_keyid;
+        self.auth_key = primary.auth_key.clone();
+        self.auth_algo = primary.auth_algo;
+        self.auth_ext_seqno = primary.auth_ext_seqno;
+
+        restart
+    }
+}
+
 impl TraceOptionPacketResolved {
     pub(crate) fn tx(&self, pkt_type: PacketType) -> bool {
         match pkt_type {
@@ -2119,7 +3188,10 @@ impl Default for InstanceCfg {
         let spf_time_to_learn = ospf::spf_control::ietf_spf_delay::time_to_learn::DFLT;
         let extended_lsa = ospf::extended_lsa_support::DFLT;
         let sr_enabled = ospf::segment_routing::enabled::DFLT;
+        let sr_ms_receive = ospf::segment_routing::bindings::receive::DFLT;
         let instance_id = ospf::instance_id::DFLT;
+        let prefix_suppression = ospf::prefix_suppression::DFLT;
+        let ldp_igp_sync_holddown = ospf::mpls::ldp::igp_sync_holddown::DFLT;
 
         InstanceCfg {
             af: None,
@@ -2134,9 +3206,18 @@ impl Default for InstanceCfg {
             spf_hold_down,
             spf_time_to_learn,
             stub_router: false,
+            ldp_igp_sync: false,
+            ldp_igp_sync_holddown,
+            prefix_suppression,
             node_tags: Default::default(),
+            redistribution: Default::default(),
+            default_info_originate: None,
             extended_lsa,
             sr_enabled,
+            sr_ms_advertise_policies: Default::default(),
+            sr_ms_receive,
+            flex_algo: Default::default(),
+            lfa: Default::default(),
             instance_id,
             bier: Default::default(),
             trace_opts: Default::default(),
@@ -2144,6 +3225,35 @@ impl Default for InstanceCfg {
     }
 }
 
+impl Default for RedistributionCfg {
+    fn default() -> RedistributionCfg {
+        let metric_type = ospf::redistribution::metric_type::DFLT;
+        let metric_type = ExternalMetricType::try_from_yang(metric_type).unwrap();
+
+        RedistributionCfg {
+            metric: None,
+            metric_type,
+            tag: None,
+            route_policy: None,
+        }
+    }
+}
+
+impl Default for DefaultInfoOriginateCfg {
+    fn default() -> DefaultInfoOriginateCfg {
+        let always = ospf::default_information_originate::always::DFLT;
+        let metric = ospf::default_information_originate::metric::DFLT;
+        let metric_type = ospf::default_information_originate::metric_type::DFLT;
+        let metric_type = ExternalMetricType::try_from_yang(metric_type).unwrap();
+
+        DefaultInfoOriginateCfg {
+            always,
+            metric,
+            metric_type,
+        }
+    }
+}
+
 impl Default for BierOspfCfg {
     fn default() -> Self {
         let enabled = ospf::bier::bier::enable::DFLT;
@@ -2172,12 +3282,47 @@ impl Default for Preference {
     }
 }
 
+impl Default for LfaCfg {
+    fn default() -> LfaCfg {
+        let prefer_node_protection = ospf::fast_reroute::lfa::prefer_node_protection::DFLT;
+        let downstream_only = ospf::fast_reroute::lfa::downstream_only::DFLT;
+
+        LfaCfg {
+            prefer_node_protection,
+            downstream_only,
+        }
+    }
+}
+
+impl Default for FlexAlgoCfg {
+    fn default() -> FlexAlgoCfg {
+        let advertise_definition = ospf::flex_algo::definition::advertise_definition::DFLT;
+        let metric_type = ospf::flex_algo::definition::metric_type::DFLT;
+        let metric_type = IgpMetricType::try_from_yang(metric_type).unwrap();
+        let priority = ospf::flex_algo::definition::priority::DFLT;
+
+        FlexAlgoCfg {
+            advertise_definition,
+            metric_type,
+            priority,
+            exclude_any: Default::default(),
+            include_any: Default::default(),
+            include_all: Default::default(),
+            exclude_srlgs: Default::default(),
+        }
+    }
+}
+
 impl Default for InstanceGrCfg {
     fn default() -> InstanceGrCfg {
+        let enabled = ospf::graceful_restart::enabled::DFLT;
+        let restart_interval = ospf::graceful_restart::restart_interval::DFLT;
         let helper_enabled = ospf::graceful_restart::helper_enabled::DFLT;
         let helper_strict_lsa_checking = ospf::graceful_restart::helper_strict_lsa_checking::DFLT;
 
         InstanceGrCfg {
+            enabled,
+            restart_interval,
             helper_enabled,
             helper_strict_lsa_checking,
         }
@@ -2190,11 +3335,21 @@ impl Default for AreaCfg {
         let area_type = AreaType::try_from_yang(area_type).unwrap();
         let summary = ospf::areas::area::summary::DFLT;
         let default_cost = ospf::areas::area::default_cost::DFLT;
+        let nssa_translator_role = ospf::areas::area::nssa::translator_role::DFLT;
+        let nssa_translator_role = NssaTranslatorRole::try_from_yang(nssa_translator_role).unwrap();
+        let nssa_default_originate = ospf::areas::area::nssa::default_originate::DFLT;
+        let nssa_translator_stability_interval = ospf::areas::area::nssa::translator_stability_interval::DFLT;
+        let flooding_algo = ospf::areas::area::flooding_reduction::algorithm::DFLT;
+        let flooding_algo = FloodingAlgo::try_from_yang(flooding_algo).unwrap();
 
         AreaCfg {
             area_type,
             summary,
             default_cost,
+            nssa_translator_role,
+            nssa_default_originate,
+            nssa_translator_stability_interval,
+            flooding_algo,
         }
     }
 }
@@ -2227,6 +3382,7 @@ where
         let enabled = ospf::areas::area::interfaces::interface::enabled::DFLT;
         let cost = ospf::areas::area::interfaces::interface::cost::DFLT;
         let mtu_ignore = ospf::areas::area::interfaces::interface::mtu_ignore::DFLT;
+        let prefix_suppression = ospf::prefix_suppression::DFLT;
         let node_flag = ospf::areas::area::interfaces::interface::node_flag::DFLT;
         let anycast_flag = ospf::areas::area::interfaces::interface::anycast_flag::DFLT;
         let bfd_enabled = ospf::areas::area::interfaces::interface::bfd::enabled::DFLT;
@@ -2244,17 +3400,36 @@ where
             enabled,
             cost,
             mtu_ignore,
+            prefix_suppression: InheritableConfig::new(prefix_suppression),
             node_flag,
             anycast_flag,
             static_nbrs: Default::default(),
+            multi_areas: Default::default(),
             auth_keychain: None,
             auth_keyid: None,
             auth_key: None,
             auth_algo: None,
+            auth_ext_seqno: false,
             bfd_enabled,
             bfd_params: Default::default(),
             trace_opts: Default::default(),
             lls_enabled,
+            link_attrs: Default::default(),
+            lfa: Default::default(),
+        }
+    }
+}
+
+impl Default for InterfaceLfaCfg {
+    fn default() -> InterfaceLfaCfg {
+        let candidate_enabled = ospf::areas::area::interfaces::interface::fast_reroute::lfa::candidate_enabled::DFLT;
+        let enabled = ospf::areas::area::interfaces::interface::fast_reroute::lfa::enabled::DFLT;
+        let remote_lfa_enabled = ospf::areas::area::interfaces::interface::fast_reroute::lfa::remote_lfa::enabled::DFLT;
+
+        InterfaceLfaCfg {
+            candidate_enabled,
+            enabled,
+            remote_lfa_enabled,
         }
     }
 }
@@ -2272,6 +3447,18 @@ impl Default for StaticNbr {
     }
 }
 
+impl<V> Default for MultiAreaCfg<V>
+where
+    V: Version,
+{
+    fn default() -> MultiAreaCfg<V> {
+        MultiAreaCfg {
+            cost: None,
+            nbr_addr: None,
+        }
+    }
+}
+
 impl Default for TraceOptionPacketResolved {
     fn default() -> TraceOptionPacketResolved {
         let disabled = TraceOptionPacketType {
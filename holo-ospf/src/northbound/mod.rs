@@ -29,9 +29,11 @@ pub trait NorthboundVersion<V: Version> {
 #[allow(unused_imports, unused_variables)]
 #[allow(clippy::module_inception)]
 pub mod yang_gen {
+    use holo_utils::mpls::LdpSyncState;
     use holo_utils::southbound::OspfRouteType;
     pub use routing::control_plane_protocols::control_plane_protocol::ospf;
 
+    use crate::area::NssaTranslatorState;
     use crate::gr::GrExitReason;
     use crate::interface::ism;
     use crate::neighbor::nsm;
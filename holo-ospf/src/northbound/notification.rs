@@ -11,6 +11,7 @@ use holo_northbound::notification;
 use holo_yang::ToYang;
 use holo_yang::types::TimerValueSecs16;
 
+use crate::area::Area;
 use crate::error::InterfaceCfgError;
 use crate::gr::GrExitReason;
 use crate::instance::InstanceUpView;
@@ -146,6 +147,22 @@ where
     notification::send(&instance.tx.nb, nbr_restart_helper_status_change::PATH, data);
 }
 
+pub(crate) fn restart_status_change<V>(instance: &InstanceUpView<'_, V>, status: &str, restart_interval: Option<u16>, exit_reason: Option<GrExitReason>)
+where
+    V: Version,
+{
+    use yang::restart_status_change::{self, RestartStatusChange};
+
+    let data = RestartStatusChange {
+        routing_protocol_name: Some(instance.name.into()),
+        address_family: Some(instance.state.af.to_yang()),
+        status: Some(status.into()),
+        restart_interval,
+        exit_reason,
+    };
+    notification::send(&instance.tx.nb, restart_status_change::PATH, data);
+}
+
 pub(crate) fn if_rx_bad_packet<V>(instance: &InstanceUpView<'_, V>, iface: &Interface<V>, src: V::NetIpAddr)
 where
     V: Version,
@@ -185,6 +202,21 @@ where
     notification::send(&instance.tx.nb, if_rx_bad_lsa::PATH, data);
 }
 
+pub(crate) fn nssa_translator_status_change<V>(instance: &InstanceUpView<'_, V>, area: &Area<V>)
+where
+    V: Version,
+{
+    use yang::nssa_translator_status_change::{self, NssaTranslatorStatusChange};
+
+    let data = NssaTranslatorStatusChange {
+        routing_protocol_name: Some(instance.name.into()),
+        address_family: Some(instance.state.af.to_yang()),
+        area_id: Some(area.area_id),
+        status: Some(area.state.nssa_translator_state),
+    };
+    notification::send(&instance.tx.nb, nssa_translator_status_change::PATH, data);
+}
+
 pub(crate) fn sr_index_out_of_range<V>(instance: &InstanceUpView<'_, V>, nbr_router_id: Ipv4Addr, index: u32)
 where
     V: Version,
@@ -4,7 +4,7 @@
 // SPDX-License-Identifier: MIT
 //
 
-use holo_northbound::rpc::{Provider, RpcResult, YangOps, YangRpc};
+use holo_northbound::rpc::{Provider, RpcError, RpcErrorTag, RpcResult, YangOps, YangRpc};
 
 use crate::instance::Instance;
 use crate::neighbor::nsm;
@@ -74,3 +74,26 @@ impl<V: Version> YangRpc<Instance<V>> for yang::clear_database::ClearDatabase {
         Ok(())
     }
 }
+
+impl<V: Version> YangRpc<Instance<V>> for yang::graceful_restart::GracefulRestart {
+    fn invoke(&mut self, instance: &mut Instance<V>) -> RpcResult {
+        if !instance.is_active() {
+            return Ok(());
+        }
+
+        // Check if graceful restart is enabled in the configuration.
+        if !instance.config.gr.enabled {
+            return Err(RpcError::new(RpcErrorTag::OperationFailed).with_message("graceful restart is not enabled"));
+        }
+
+        // Check if a graceful restart is already in progress.
+        if instance.state.as_ref().is_some_and(|state| state.gr_restart.is_some()) {
+            return Err(RpcError::new(RpcErrorTag::OperationFailed).with_message("graceful restart already in progress"));
+        }
+
+        // Perform the graceful restart.
+        instance.graceful_restart();
+
+        Ok(())
+    }
+}
@@ -19,7 +19,7 @@ use holo_yang::types::{HexStr, TimerValueMillis, TimerValueSecs16, Timeticks};
 use holo_yang::{ToYang, ToYangFlags};
 use num_traits::FromPrimitive;
 
-use crate::area::Area;
+use crate::area::{Area, AreaType};
 use crate::collections::LsdbSingleType;
 use crate::instance::Instance;
 use crate::interface::{Interface, ism};
@@ -27,9 +27,11 @@ use crate::lsdb::{LsaEntry, LsaLogEntry, LsaLogId};
 use crate::neighbor::Neighbor;
 use crate::northbound::yang::FletcherChecksum16;
 use crate::northbound::yang_gen::ospf;
+use crate::packet::lls::ExtendedOptionsFlags;
 use crate::packet::lsa::{LsaBodyVersion, LsaHdrVersion};
 use crate::packet::tlv::{BierEncapSubStlv, BierStlv, GrReason, NodeAdminTagTlv, SidLabelRangeTlv, SrLocalBlockTlv, UnknownTlv};
-use crate::route::{Nexthop, RouteNet};
+use crate::lfa::{AlternateType, ProtectionFlags};
+use crate::route::{Nexthop, RouteNet, RouteNetFlags};
 use crate::spf::SpfLogEntry;
 use crate::version::{Ospfv2, Ospfv3, Version};
 use crate::{ospfv2, ospfv3};
@@ -156,6 +158,100 @@ impl<'a, V: Version> YangContainer<'a, Instance<V>> for ospf::statistics::Statis
     }
 }
 
+impl<'a, V: Version> YangList<'a, Instance<V>> for ospf::statistics::protected_routes::address_family_stats::AddressFamilyStats<'a> {
+    type ParentListEntry = ();
+    type ListEntry = (&'a V::IpNetwork, &'a RouteNet<V>);
+
+    fn iter(instance: &'a Instance<V>, _: &Self::ParentListEntry) -> Option<impl ListIterator<'a, Self::ListEntry>> {
+        let rib = &instance.state.as_ref()?.rib;
+        let iter = rib.iter().filter(|(_, route)| route.backup.is_some());
+        Some(iter)
+    }
+
+    fn new(instance: &'a Instance<V>, (prefix, route): &Self::ListEntry) -> Self {
+        let state = instance.state.as_ref().unwrap();
+        let backup = route.backup.as_ref().unwrap();
+        let protection_available = backup.protection.to_yang_flags().join(" ");
+        Self {
+            address_family: state.af.to_yang(),
+            prefix: (**prefix).into(),
+            alternate: backup.alternate(),
+            alternate_type: Some(backup.alt_type.to_yang()),
+            best: Some(true),
+            non_best_reason: None,
+            protection_available: Some(protection_available.into()),
+            alternate_metric_1: Some(backup.metric),
+            alternate_metric_2: Some(backup.metric_to_alt),
+            alternate_metric_3: Some(backup.metric_from_alt),
+        }
+    }
+}
+
+impl<'a, V: Version> YangList<'a, Instance<V>> for ospf::statistics::unprotected_routes::address_family_stats::AddressFamilyStats<'a> {
+    type ParentListEntry = ();
+    type ListEntry = (&'a V::IpNetwork, &'a RouteNet<V>);
+
+    fn iter(instance: &'a Instance<V>, _: &Self::ParentListEntry) -> Option<impl ListIterator<'a, Self::ListEntry>> {
+        let rib = &instance.state.as_ref()?.rib;
+        let iter = rib.iter().filter(|(_, route)| route.flags.contains(RouteNetFlags::FRR_ENABLED) && route.backup.is_none());
+        Some(iter)
+    }
+
+    fn new(instance: &'a Instance<V>, (prefix, _): &Self::ListEntry) -> Self {
+        let state = instance.state.as_ref().unwrap();
+        Self {
+            address_family: state.af.to_yang(),
+            prefix: (**prefix).into(),
+        }
+    }
+}
+
+impl<'a, V: Version> YangList<'a, Instance<V>> for ospf::statistics::protection_statistics::ProtectionStatistics<'a> {
+    type ParentListEntry = ();
+    type ListEntry = AlternateType;
+
+    fn iter(instance: &'a Instance<V>, _: &Self::ParentListEntry) -> Option<impl ListIterator<'a, Self::ListEntry>> {
+        instance.state.as_ref()?;
+        if !instance.arenas.areas.iter().any(|area| area.state.lfa.is_some()) {
+            return None;
+        }
+        let iter = [AlternateType::Lfa, AlternateType::RemoteLfa].into_iter();
+        Some(iter)
+    }
+
+    fn new(_instance: &'a Instance<V>, alt_type: &Self::ListEntry) -> Self {
+        Self {
+            frr_protection_method: alt_type.to_yang(),
+        }
+    }
+}
+
+impl<'a, V: Version> YangList<'a, Instance<V>> for ospf::statistics::protection_statistics::address_family_stats::AddressFamilyStats<'a> {
+    type ParentListEntry = AlternateType;
+    type ListEntry = AlternateType;
+
+    fn iter(_instance: &'a Instance<V>, alt_type: &Self::ParentListEntry) -> Option<impl ListIterator<'a, Self::ListEntry>> {
+        let iter = std::iter::once(*alt_type);
+        Some(iter)
+    }
+
+    fn new(instance: &'a Instance<V>, alt_type: &Self::ListEntry) -> Self {
+        let state = instance.state.as_ref().unwrap();
+        let routes = state.rib.values().filter(|route| route.flags.contains(RouteNetFlags::FRR_ENABLED));
+        let backups = routes.clone().filter_map(|route| route.backup.as_ref()).filter(|backup| backup.alt_type == *alt_type);
+        let total_routes = routes.count();
+        let protected_routes = backups.clone().count();
+        Self {
+            address_family: state.af.to_yang(),
+            total_routes: Some(total_routes.saturating_into()),
+            unprotected_routes: Some((total_routes - protected_routes).saturating_into()),
+            protected_routes: Some(protected_routes.saturating_into()),
+            linkprotected_routes: Some(backups.clone().filter(|backup| backup.protection.contains(ProtectionFlags::LINK)).count().saturating_into()),
+            nodeprotected_routes: Some(backups.filter(|backup| backup.protection.contains(ProtectionFlags::NODE)).count().saturating_into()),
+        }
+    }
+}
+
 impl<'a, V: Version> YangList<'a, Instance<V>> for ospf::statistics::database::as_scope_lsa_type::AsScopeLsaType {
     type ParentListEntry = ();
     type ListEntry = &'a LsdbSingleType<V>;
@@ -882,6 +978,18 @@ impl<'a, V: Version> YangList<'a, Instance<V>> for ospf::spf_log::event::Event<'
     }
 }
 
+impl<'a, V: Version> YangContainer<'a, Instance<V>> for ospf::spf_log::event::statistics::Statistics<'a> {
+    type ParentListEntry = &'a SpfLogEntry<V>;
+
+    fn new(_instance: &'a Instance<V>, log: &Self::ParentListEntry) -> Option<Self> {
+        Some(Self {
+            algorithm: Some(log.stats.algorithm.to_yang()),
+            vertex_count: Some(log.stats.vertex_count),
+            route_count: Some(log.stats.route_count),
+        })
+    }
+}
+
 impl<'a, V: Version> YangList<'a, Instance<V>> for ospf::spf_log::event::trigger_lsa::TriggerLsa<'a> {
     type ParentListEntry = &'a SpfLogEntry<V>;
     type ListEntry = &'a LsaLogId<V>;
@@ -962,6 +1070,20 @@ impl<'a, V: Version> YangContainer<'a, Instance<V>> for ospf::areas::area::stati
             asbr_count: Some(area.asbr_count() as _),
             area_scope_lsa_count: Some(area.state.lsdb.lsa_count()),
             area_scope_lsa_cksum_sum: Some(area.state.lsdb.cksum_sum()).ignore_in_testing(),
+            ar_nssa_translator_event_count: Some(area.state.nssa_translator_event_count).ignore_in_testing(),
+        })
+    }
+}
+
+impl<'a, V: Version> YangContainer<'a, Instance<V>> for ospf::areas::area::nssa::Nssa {
+    type ParentListEntry = &'a Area<V>;
+
+    fn new(_instance: &'a Instance<V>, area: &Self::ParentListEntry) -> Option<Self> {
+        if area.config.area_type != AreaType::Nssa {
+            return None;
+        }
+        Some(Self {
+            translator_state: Some(area.state.nssa_translator_state),
         })
     }
 }
@@ -1138,6 +1260,41 @@ impl<'a> YangContainer<'a, Instance<Ospfv2>> for ospf::areas::area::database::ar
     }
 }
 
+impl<'a> YangContainer<'a, Instance<Ospfv2>> for ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::external::External {
+    type ParentListEntry = &'a LsaEntry<Ospfv2>;
+
+    fn new(_instance: &'a Instance<Ospfv2>, lse: &Self::ParentListEntry) -> Option<Self> {
+        let lsa = &lse.data;
+        Some(Self {
+            network_mask: lsa.body.as_nssa_external().map(|lsa_body| lsa_body.mask),
+        })
+    }
+}
+
+impl<'a> YangList<'a, Instance<Ospfv2>> for ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::external::topologies::topology::Topology<'a> {
+    type ParentListEntry = &'a LsaEntry<Ospfv2>;
+    type ListEntry = &'a LsaEntry<Ospfv2>;
+
+    fn iter(_instance: &'a Instance<Ospfv2>, &lse: &Self::ParentListEntry) -> Option<impl ListIterator<'a, Self::ListEntry>> {
+        let lsa = &lse.data;
+        let _lsa_body = lsa.body.as_nssa_external()?;
+        let iter = std::iter::once(lse);
+        Some(iter)
+    }
+
+    fn new(_instance: &'a Instance<Ospfv2>, lse: &Self::ListEntry) -> Self {
+        let lsa = &lse.data;
+        let lsa_body = lsa.body.as_nssa_external().unwrap();
+        Self {
+            mt_id: Some(0),
+            flags: Some(lsa_body.flags.to_yang()),
+            metric: Some(lsa_body.metric),
+            forwarding_address: lsa_body.fwd_addr,
+            external_route_tag: Some(lsa_body.tag),
+        }
+    }
+}
+
 impl<'a> YangContainer<'a, Instance<Ospfv2>> for ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::summary::Summary {
     type ParentListEntry = &'a LsaEntry<Ospfv2>;
 
@@ -1714,6 +1871,40 @@ impl<'a> YangContainer<'a, Instance<Ospfv3>> for ospf::areas::area::database::ar
     }
 }
 
+impl<'a> YangContainer<'a, Instance<Ospfv3>> for ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv3::body::nssa::Nssa<'a> {
+    type ParentListEntry = &'a LsaEntry<Ospfv3>;
+
+    fn new(_instance: &'a Instance<Ospfv3>, lse: &Self::ParentListEntry) -> Option<Self> {
+        let lsa = &lse.data;
+        let lsa_body = lsa.body.as_std_nssa()?;
+        Some(Self {
+            metric: Some(lsa_body.metric),
+            flags: Some(lsa_body.flags.to_yang()),
+            referenced_ls_type: lsa_body.ref_lsa_type.map(|lsa_type| lsa_type.to_yang()),
+            unknown_referenced_ls_type: lsa_body.ref_lsa_type.and_then(|ref_lsa_type| if ref_lsa_type.function_code().is_none() { Some(ref_lsa_type.0) } else { None }),
+            prefix: Some(lsa_body.prefix),
+            forwarding_address: lsa_body.fwd_addr.map(|addr| match addr {
+                IpAddr::V4(addr) => addr.to_ipv6_mapped(),
+                IpAddr::V6(addr) => addr,
+            }),
+            external_route_tag: lsa_body.tag,
+            referenced_link_state_id: lsa_body.ref_lsa_id.map(|lsa_id| lsa_id.into()),
+        })
+    }
+}
+
+impl<'a> YangContainer<'a, Instance<Ospfv3>> for ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv3::body::nssa::prefix_options::PrefixOptions<'a> {
+    type ParentListEntry = &'a LsaEntry<Ospfv3>;
+
+    fn new(_instance: &'a Instance<Ospfv3>, lse: &Self::ParentListEntry) -> Option<Self> {
+        let lsa = &lse.data;
+        let lsa_body = lsa.body.as_std_nssa()?;
+        Some(Self {
+            prefix_options: lsa_body.prefix_options.to_yang_flags_iter(),
+        })
+    }
+}
+
 impl<'a> YangContainer<'a, Instance<Ospfv3>> for ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv3::body::intra_area_prefix::IntraAreaPrefix<'a> {
     type ParentListEntry = &'a LsaEntry<Ospfv3>;
 
@@ -2331,6 +2522,161 @@ impl<'a> YangContainer<'a, Instance<Ospfv3>>
     }
 }
 
+impl<'a> YangList<'a, Instance<Ospfv3>> for ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv3::body::e_nssa::e_external_tlvs::EExternalTlvs {
+    type ParentListEntry = &'a LsaEntry<Ospfv3>;
+    type ListEntry = &'a LsaEntry<Ospfv3>;
+
+    fn iter(_instance: &'a Instance<Ospfv3>, &lse: &Self::ParentListEntry) -> Option<impl ListIterator<'a, Self::ListEntry>> {
+        let lsa = &lse.data;
+        let _ = lsa.body.as_ext_nssa()?;
+        let iter = std::iter::once(lse);
+        Some(iter)
+    }
+
+    fn new(_instance: &'a Instance<Ospfv3>, _tlv: &Self::ListEntry) -> Self {
+        Self {}
+    }
+}
+
+impl<'a> YangContainer<'a, Instance<Ospfv3>> for ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv3::body::e_nssa::e_external_tlvs::unknown_tlv::UnknownTlv<'a> {
+    type ParentListEntry = &'a LsaEntry<Ospfv3>;
+
+    fn new(_instance: &'a Instance<Ospfv3>, _lse: &Self::ParentListEntry) -> Option<Self> {
+        // TODO: unknown TLVs aren't tracked at this level yet.
+        None
+    }
+}
+
+impl<'a> YangContainer<'a, Instance<Ospfv3>> for ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv3::body::e_nssa::e_external_tlvs::external_prefix_tlv::ExternalPrefixTlv {
+    type ParentListEntry = &'a LsaEntry<Ospfv3>;
+
+    fn new(_instance: &'a Instance<Ospfv3>, lse: &Self::ParentListEntry) -> Option<Self> {
+        let lsa = &lse.data;
+        let lsa_body = lsa.body.as_ext_nssa()?;
+        Some(Self {
+            metric: Some(lsa_body.metric),
+            prefix: Some(lsa_body.prefix),
+        })
+    }
+}
+
+impl<'a> YangContainer<'a, Instance<Ospfv3>> for ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv3::body::e_nssa::e_external_tlvs::external_prefix_tlv::flags::Flags<'a> {
+    type ParentListEntry = &'a LsaEntry<Ospfv3>;
+
+    fn new(_instance: &'a Instance<Ospfv3>, lse: &Self::ParentListEntry) -> Option<Self> {
+        let lsa = &lse.data;
+        let lsa_body = lsa.body.as_ext_nssa()?;
+        Some(Self {
+            ospfv3_e_external_prefix_bits: lsa_body.flags.to_yang_flags_iter(),
+        })
+    }
+}
+
+impl<'a> YangContainer<'a, Instance<Ospfv3>> for ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv3::body::e_nssa::e_external_tlvs::external_prefix_tlv::prefix_options::PrefixOptions<'a> {
+    type ParentListEntry = &'a LsaEntry<Ospfv3>;
+
+    fn new(_instance: &'a Instance<Ospfv3>, lse: &Self::ParentListEntry) -> Option<Self> {
+        let lsa = &lse.data;
+        let lsa_body = lsa.body.as_ext_nssa()?;
+        Some(Self {
+            prefix_options: lsa_body.prefix_options.to_yang_flags_iter(),
+        })
+    }
+}
+
+impl<'a> YangList<'a, Instance<Ospfv3>> for ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv3::body::e_nssa::e_external_tlvs::external_prefix_tlv::sub_tlvs::SubTlvs {
+    type ParentListEntry = &'a LsaEntry<Ospfv3>;
+    type ListEntry = Ospfv3PrefixSubTlv<'a>;
+
+    fn iter(_instance: &'a Instance<Ospfv3>, _lse: &Self::ParentListEntry) -> Option<impl ListIterator<'a, Self::ListEntry>> {
+        None::<std::iter::Empty<_>> // TODO
+    }
+
+    fn new(_instance: &'a Instance<Ospfv3>, _tlv: &Self::ListEntry) -> Self {
+        Self {}
+    }
+}
+
+impl<'a> YangContainer<'a, Instance<Ospfv3>> for ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv3::body::e_nssa::e_external_tlvs::external_prefix_tlv::sub_tlvs::ipv6_fwd_addr_sub_tlv::Ipv6FwdAddrSubTlv {
+    type ParentListEntry = Ospfv3PrefixSubTlv<'a>;
+
+    fn new(_instance: &'a Instance<Ospfv3>, _sub_tlv: &Self::ParentListEntry) -> Option<Self> {
+        Some(Self {
+            forwarding_address: None, // TODO
+        })
+    }
+}
+
+impl<'a> YangContainer<'a, Instance<Ospfv3>> for ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv3::body::e_nssa::e_external_tlvs::external_prefix_tlv::sub_tlvs::ipv4_fwd_addr_sub_tlv::Ipv4FwdAddrSubTlv {
+    type ParentListEntry = Ospfv3PrefixSubTlv<'a>;
+
+    fn new(_instance: &'a Instance<Ospfv3>, _sub_tlv: &Self::ParentListEntry) -> Option<Self> {
+        Some(Self {
+            forwarding_address: None, // TODO
+        })
+    }
+}
+
+impl<'a> YangContainer<'a, Instance<Ospfv3>> for ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv3::body::e_nssa::e_external_tlvs::external_prefix_tlv::sub_tlvs::route_tag_sub_tlv::RouteTagSubTlv {
+    type ParentListEntry = Ospfv3PrefixSubTlv<'a>;
+
+    fn new(_instance: &'a Instance<Ospfv3>, _sub_tlv: &Self::ParentListEntry) -> Option<Self> {
+        Some(Self {
+            route_tag: None, // TODO
+        })
+    }
+}
+
+impl<'a> YangContainer<'a, Instance<Ospfv3>> for ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv3::body::e_nssa::e_external_tlvs::external_prefix_tlv::sub_tlvs::unknown_sub_tlv::UnknownSubTlv<'a> {
+    type ParentListEntry = Ospfv3PrefixSubTlv<'a>;
+
+    fn new(_instance: &'a Instance<Ospfv3>, parent: &Self::ParentListEntry) -> Option<Self> {
+        let Ospfv3PrefixSubTlv::Unknown(tlv) = parent else {
+            return None;
+        };
+        Some(Self {
+            r#type: Some(tlv.tlv_type),
+            length: Some(tlv.length),
+            value: Some(HexStr(tlv.value.as_ref())),
+        })
+    }
+}
+
+impl<'a> YangList<'a, Instance<Ospfv3>>
+    for ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv3::body::e_nssa::e_external_tlvs::external_prefix_tlv::sub_tlvs::prefix_sid_sub_tlvs::prefix_sid_sub_tlv::PrefixSidSubTlv<'a>
+{
+    type ParentListEntry = Ospfv3PrefixSubTlv<'a>;
+    type ListEntry = &'a ospfv3::packet::lsa::PrefixSid;
+
+    fn iter(_instance: &'a Instance<Ospfv3>, parent: &Self::ParentListEntry) -> Option<impl ListIterator<'a, Self::ListEntry>> {
+        let Ospfv3PrefixSubTlv::PrefixSids(prefix_sids) = parent else {
+            return None;
+        };
+        let iter = prefix_sids.values();
+        Some(iter)
+    }
+
+    fn new(_instance: &'a Instance<Ospfv3>, stlv: &Self::ListEntry) -> Self {
+        Self {
+            algorithm: Some(stlv.algo.to_yang()),
+            label_value: stlv.sid.as_label().map(|label| label.get()),
+            index_value: stlv.sid.as_index().copied(),
+        }
+    }
+}
+
+impl<'a> YangContainer<'a, Instance<Ospfv3>>
+    for ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv3::body::e_nssa::e_external_tlvs::external_prefix_tlv::sub_tlvs::prefix_sid_sub_tlvs::prefix_sid_sub_tlv::ospfv3_prefix_sid_flags::Ospfv3PrefixSidFlags<'a>
+{
+    type ParentListEntry = &'a ospfv3::packet::lsa::PrefixSid;
+
+    fn new(_instance: &'a Instance<Ospfv3>, prefix_sid: &Self::ParentListEntry) -> Option<Self> {
+        Some(Self {
+            flag: prefix_sid.flags.to_yang_flags_iter(),
+        })
+    }
+}
+
 impl<'a> YangContainer<'a, Instance<Ospfv3>> for ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv3::body::e_intra_area_prefix::EIntraAreaPrefix {
     type ParentListEntry = &'a LsaEntry<Ospfv3>;
 
@@ -2880,7 +3226,7 @@ impl<'a, V: Version> YangList<'a, Instance<V>> for ospf::areas::area::interfaces
     type ListEntry = &'a Interface<V>;
 
     fn iter(instance: &'a Instance<V>, area: &Self::ParentListEntry) -> Option<impl ListIterator<'a, Self::ListEntry>> {
-        let iter = area.interfaces.iter(&instance.arenas.interfaces).filter(|iface| !iface.is_virtual_link());
+        let iter = area.interfaces.iter(&instance.arenas.interfaces).filter(|iface| !iface.is_virtual_link() && !iface.is_multi_area());
         Some(iter)
     }
 
@@ -2936,6 +3282,20 @@ impl<'a, V: Version> YangContainer<'a, Instance<V>> for ospf::areas::area::inter
     }
 }
 
+impl<'a, V: Version> YangContainer<'a, Instance<V>> for ospf::areas::area::interfaces::interface::ldp_igp_sync::LdpIgpSync {
+    type ParentListEntry = &'a Interface<V>;
+
+    fn new(instance: &'a Instance<V>, iface: &Self::ParentListEntry) -> Option<Self> {
+        if !instance.config.ldp_igp_sync {
+            return None;
+        }
+        Some(Self {
+            state: Some(iface.state.ldp_sync),
+            holddown_timer: iface.state.tasks.ldp_sync_holddown.as_ref().map(|task| task.remaining().as_secs().saturating_into()).ignore_in_testing(),
+        })
+    }
+}
+
 impl<'a, V: Version> YangList<'a, Instance<V>> for ospf::areas::area::interfaces::interface::statistics::database::link_scope_lsa_type::LinkScopeLsaType {
     type ParentListEntry = &'a Interface<V>;
     type ListEntry = &'a LsdbSingleType<V>;
@@ -3033,6 +3393,22 @@ impl<'a, V: Version> YangContainer<'a, Instance<V>> for ospf::areas::area::inter
     }
 }
 
+impl<'a, V: Version> YangContainer<'a, Instance<V>> for ospf::areas::area::interfaces::interface::neighbors::neighbor::oob_resync::OobResync {
+    type ParentListEntry = (&'a Interface<V>, &'a Neighbor<V>);
+
+    fn new(_instance: &'a Instance<V>, (iface, nbr): &Self::ParentListEntry) -> Option<Self> {
+        if !iface.config.lls_enabled {
+            return None;
+        }
+        Some(Self {
+            lr_capable: Some(nbr.ext_options.contains(ExtendedOptionsFlags::LR)),
+            restart_signaled: Some(nbr.ext_options.contains(ExtendedOptionsFlags::RS)),
+            in_progress: Some(nbr.oob_resync.is_some()),
+            resync_timer: nbr.tasks.resync_timer.as_ref().map(|task| task.remaining().as_secs().saturating_into()).ignore_in_testing(),
+        })
+    }
+}
+
 impl<'a, V: Version> YangList<'a, Instance<V>> for ospf::areas::area::interfaces::interface::database::link_scope_lsa_type::LinkScopeLsaType {
     type ParentListEntry = &'a Interface<V>;
     type ListEntry = &'a LsdbSingleType<V>;
@@ -9,17 +9,19 @@ use std::fmt::Write;
 
 use holo_yang::{ToYang, ToYangFlags, TryFromYang};
 
-use crate::area::AreaType;
+use crate::area::{AreaType, NssaTranslatorRole, NssaTranslatorState};
 use crate::error::InterfaceCfgError;
 use crate::gr::GrExitReason;
 use crate::interface::{InterfaceType, ism};
+use crate::lfa::{AlternateType, ProtectionFlags};
 use crate::lsdb::LsaLogReason;
 use crate::neighbor::nsm;
 use crate::northbound::configuration::{InstanceTraceOption, InterfaceTraceOption};
 use crate::packet::error::LsaValidationError;
-use crate::packet::iana::{PacketType, RouterInfoCaps};
+use crate::packet::iana::{FloodingAlgo, IgpMetricType, PacketType, RouterInfoCaps};
 use crate::packet::tlv::{AdjSidFlags, GrReason, PrefixSidFlags};
-use crate::spf::SpfLogType;
+use crate::route::ExternalMetricType;
+use crate::spf::{SpfAlgorithm, SpfLogType};
 use crate::{ospfv2, ospfv3, spf};
 
 // Fletcher checksum (u16 displayed as hex, e.g. "0x1234").
@@ -40,6 +42,16 @@ impl ToYang for PacketType {
     }
 }
 
+impl ToYang for NssaTranslatorState {
+    fn to_yang(&self) -> Cow<'static, str> {
+        match self {
+            NssaTranslatorState::Enabled => "enabled".into(),
+            NssaTranslatorState::Elected => "elected".into(),
+            NssaTranslatorState::Disabled => "disabled".into(),
+        }
+    }
+}
+
 impl ToYang for ism::State {
     fn to_yang(&self) -> Cow<'static, str> {
         match self {
@@ -100,6 +112,16 @@ impl ToYang for SpfLogType {
     }
 }
 
+impl ToYang for SpfAlgorithm {
+    fn to_yang(&self) -> Cow<'static, str> {
+        match self {
+            SpfAlgorithm::Full => "full".into(),
+            SpfAlgorithm::Incremental => "incremental".into(),
+            SpfAlgorithm::Partial => "partial".into(),
+        }
+    }
+}
+
 impl ToYang for InterfaceCfgError {
     fn to_yang(&self) -> Cow<'static, str> {
         match self {
@@ -109,6 +131,7 @@ impl ToYang for InterfaceCfgError {
             InterfaceCfgError::HelloIntervalMismatch(..) => "hello-interval-mismatch".into(),
             InterfaceCfgError::DeadIntervalMismatch(..) => "dead-interval-mismatch".into(),
             InterfaceCfgError::ExternalRoutingCapabilityMismatch(..) => "option-mismatch".into(),
+            InterfaceCfgError::NssaCapabilityMismatch(..) => "option-mismatch".into(),
             InterfaceCfgError::MtuMismatch(..) => "mtu-mismatch".into(),
             InterfaceCfgError::DuplicateRouterId(..) => "duplicate-router-id".into(),
         }
@@ -221,6 +244,33 @@ impl ToYang for GrExitReason {
     }
 }
 
+impl ToYang for AlternateType {
+    fn to_yang(&self) -> Cow<'static, str> {
+        match self {
+            AlternateType::Lfa => "lfa".into(),
+            AlternateType::RemoteLfa => "remote-lfa".into(),
+        }
+    }
+}
+
+impl ToYangFlags for ProtectionFlags {
+    fn to_yang_flags(&self) -> Vec<&'static str> {
+        let mut flags = vec![];
+
+        if self.contains(ProtectionFlags::NODE) {
+            flags.push("node-protect");
+        }
+        if self.contains(ProtectionFlags::LINK) {
+            flags.push("link-protect");
+        }
+        if self.contains(ProtectionFlags::DOWNSTREAM) {
+            flags.push("downstream-protect");
+        }
+
+        flags
+    }
+}
+
 impl ToYang for ospfv2::packet::lsa::LsaAsExternalFlags {
     fn to_yang(&self) -> Cow<'static, str> {
         use ospfv2::packet::lsa::LsaAsExternalFlags;
@@ -269,6 +319,7 @@ impl ToYang for ospfv2::packet::lsa::LsaType {
             Some(LsaTypeCode::SummaryNetwork) => "ospfv2-network-summary-lsa".into(),
             Some(LsaTypeCode::SummaryRouter) => "ospfv2-asbr-summary-lsa".into(),
             Some(LsaTypeCode::AsExternal) => "ospfv2-as-external-lsa".into(),
+            Some(LsaTypeCode::NssaExternal) => "ospfv2-nssa-lsa".into(),
             Some(LsaTypeCode::OpaqueLink) => "ospfv2-link-scope-opaque-lsa".into(),
             Some(LsaTypeCode::OpaqueArea) => "ospfv2-area-scope-opaque-lsa".into(),
             Some(LsaTypeCode::OpaqueAs) => "ospfv2-as-scope-opaque-lsa".into(),
@@ -355,6 +406,7 @@ impl ToYang for ospfv3::packet::lsa::LsaType {
             Some(LsaFunctionCode::InterAreaPrefix) => "ospfv3-inter-area-prefix-lsa".into(),
             Some(LsaFunctionCode::InterAreaRouter) => "ospfv3-inter-area-router-lsa".into(),
             Some(LsaFunctionCode::AsExternal) => "ospfv3-external-lsa-type".into(),
+            Some(LsaFunctionCode::Nssa) => "ospfv3-nssa-lsa".into(),
             Some(LsaFunctionCode::Link) => "ospfv3-link-lsa".into(),
             Some(LsaFunctionCode::IntraAreaPrefix) => "ospfv3-intra-area-prefix-lsa".into(),
             Some(LsaFunctionCode::RouterInfo) => "ospfv3-router-information-lsa".into(),
@@ -364,6 +416,7 @@ impl ToYang for ospfv3::packet::lsa::LsaType {
             Some(LsaFunctionCode::ExtInterAreaPrefix) => "ietf-ospfv3-extended-lsa:ospfv3-e-inter-area-prefix-lsa".into(),
             Some(LsaFunctionCode::ExtInterAreaRouter) => "ietf-ospfv3-extended-lsa:ospfv3-e-inter-area-router-lsa".into(),
             Some(LsaFunctionCode::ExtAsExternal) => "ietf-ospfv3-extended-lsa:ospfv3-e-external-lsa-type".into(),
+            Some(LsaFunctionCode::ExtNssa) => "ietf-ospfv3-extended-lsa:ospfv3-e-nssa-lsa".into(),
             Some(LsaFunctionCode::ExtLink) => "ietf-ospfv3-extended-lsa:ospfv3-e-link-lsa".into(),
             Some(LsaFunctionCode::ExtIntraAreaPrefix) => "ietf-ospfv3-extended-lsa:ospfv3-e-intra-area-prefix-lsa".into(),
             None => "ospfv3-unknown-lsa-type".into(),
@@ -509,6 +562,47 @@ impl TryFromYang for AreaType {
     }
 }
 
+impl TryFromYang for NssaTranslatorRole {
+    fn try_from_yang(value: &str) -> Option<NssaTranslatorRole> {
+        match value {
+            "always" => Some(NssaTranslatorRole::Always),
+            "candidate" => Some(NssaTranslatorRole::Candidate),
+            _ => None,
+        }
+    }
+}
+
+impl TryFromYang for FloodingAlgo {
+    fn try_from_yang(value: &str) -> Option<FloodingAlgo> {
+        match value {
+            "holo-ospf:flooding-algorithm-zero-pruner" => Some(FloodingAlgo::ZeroPruner),
+            "holo-ospf:flooding-algorithm-modified-manet" => Some(FloodingAlgo::ModifiedManet),
+            _ => None,
+        }
+    }
+}
+
+impl TryFromYang for ExternalMetricType {
+    fn try_from_yang(value: &str) -> Option<ExternalMetricType> {
+        match value {
+            "type-1" => Some(ExternalMetricType::Type1),
+            "type-2" => Some(ExternalMetricType::Type2),
+            _ => None,
+        }
+    }
+}
+
+impl TryFromYang for IgpMetricType {
+    fn try_from_yang(value: &str) -> Option<IgpMetricType> {
+        match value {
+            "igp-metric" => Some(IgpMetricType::IgpMetric),
+            "min-unidirectional-link-delay" => Some(IgpMetricType::MinUniLinkDelay),
+            "te-metric" => Some(IgpMetricType::TeMetric),
+            _ => None,
+        }
+    }
+}
+
 impl TryFromYang for InterfaceType {
     fn try_from_yang(value: &str) -> Option<InterfaceType> {
         match value {
@@ -14,7 +14,9 @@ use crate::collections::Arena;
 use crate::interface::Interface;
 use crate::lsdb::LsaEntry;
 use crate::ospfv2::packet::iana::{LsaRouterLinkType, LsaTypeCode, Options};
-use crate::ospfv2::packet::lsa_opaque::ExtPrefixTlv;
+use crate::ospfv2::packet::lsa_opaque::{
+    ExtLinkTlv, ExtPrefixRangeTlv, ExtPrefixTlv,
+};
 use crate::packet::iana::PacketType;
 use crate::packet::lsa::{LsaHdrVersion, LsaKey};
 use crate::route::RouteRtr;
@@ -23,6 +25,10 @@ use crate::version::Ospfv2;
 #[derive(Debug, Default)]
 pub struct AreaState {
     pub ext_prefix_db: HashMap<(Ipv4Addr, Ipv4Network), ExtPrefixTlv>,
+    // Extended Link TLVs indexed by advertising router, Link ID and Link Data.
+    pub ext_link_db: HashMap<(Ipv4Addr, Ipv4Addr, Ipv4Addr), ExtLinkTlv>,
+    // Extended Prefix Range TLVs indexed by advertising router.
+    pub ext_prefix_range_db: Vec<(Ipv4Addr, ExtPrefixRangeTlv)>,
 }
 
 // ===== impl Ospfv2 =====
@@ -47,6 +53,16 @@ impl AreaVersion<Self> for Ospfv2 {
             options.insert(Options::O);
         }
 
+        // The N-bit is only set in Hello packets sent on NSSA interfaces.
+        if let OptionsLocation::Packet {
+            pkt_type: PacketType::Hello,
+            ..
+        } = location
+            && area.config.area_type == AreaType::Nssa
+        {
+            options.insert(Options::NP);
+        }
+
         if let OptionsLocation::Packet { lls: true, .. } = location {
             options.insert(Options::L);
         }
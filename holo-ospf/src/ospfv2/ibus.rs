@@ -20,7 +20,14 @@ impl IbusRxVersion<Self> for Ospfv2 {
         addr: Ipv4Network,
         unnumbered: bool,
     ) {
-        if iface.system.primary_addr.is_none() {
+        if unnumbered {
+            iface.system.unnumbered_addrs.insert(addr);
+        }
+
+        // Prefer numbered addresses over unnumbered ones.
+        if iface.system.primary_addr.is_none()
+            || (iface.system.unnumbered && !unnumbered)
+        {
             Debug::<Self>::InterfacePrimaryAddrSelect(&iface.name, &addr).log();
 
             // Mark address as the primary one.
@@ -30,23 +37,33 @@ impl IbusRxVersion<Self> for Ospfv2 {
     }
 
     fn process_addr_del(iface: &mut Interface<Self>, addr: Ipv4Network) {
+        iface.system.unnumbered_addrs.remove(&addr);
+
         if iface.system.primary_addr == Some(addr) {
             Debug::<Self>::InterfacePrimaryAddrDelete(&iface.name).log();
 
             // Remove primary address.
             iface.system.primary_addr = None;
 
-            // Try to find other address to select as primary.
-            if let Some(addr) = iface
+            // Try to find other address to select as primary, preferring
+            // numbered addresses over unnumbered ones.
+            let mut addrs = iface
                 .system
                 .addr_list
                 .iter()
-                .find(|addr| addr.ip().is_usable())
+                .filter(|addr| addr.ip().is_usable())
+                .copied();
+            if let Some(addr) = addrs
+                .clone()
+                .find(|addr| !iface.system.unnumbered_addrs.contains(addr))
+                .or_else(|| addrs.next())
             {
-                Debug::<Self>::InterfacePrimaryAddrSelect(&iface.name, addr)
+                Debug::<Self>::InterfacePrimaryAddrSelect(&iface.name, &addr)
                     .log();
 
-                iface.system.primary_addr = Some(*addr);
+                iface.system.primary_addr = Some(addr);
+                iface.system.unnumbered =
+                    iface.system.unnumbered_addrs.contains(&addr);
             }
         }
     }
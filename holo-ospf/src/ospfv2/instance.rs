@@ -21,14 +21,22 @@ impl InstanceVersion<Self> for Ospfv2 {
         AddressFamily::Ipv4
     }
 
-    fn initial_auth_seqno(_boot_count: u32) -> u64 {
-        // Initialize the authentication sequence number as the number of
-        // seconds since the Unix epoch (1 January 1970).
+    fn initial_auth_seqno(boot_count: u32) -> u64 {
+        // Initialize the lower-order 32 bits of the authentication sequence
+        // number as the number of seconds since the Unix epoch (1 January
+        // 1970). These are the only bits sent when RFC 7474 isn't in use.
         // By using this approach, the chances of successfully replaying
         // packets from a restarted OSPF instance are significantly reduced.
-        SystemTime::now()
+        let secs = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .expect("Time went backwards")
-            .as_secs()
+            .as_secs();
+
+        // RFC 7474 extends the sequence number to 64 bits, using the
+        // higher-order 32 bits as a boot count that is incremented anytime
+        // the OSPFv2 router loses its sequence number state. The boot count
+        // is also incremented whenever the lower-order 32 bits wrap (see
+        // `AuthSeqNo::next`).
+        ((boot_count as u64) << 32) | (secs as u32 as u64)
     }
 }
@@ -23,6 +23,7 @@ use crate::ospfv2::packet::{Hello, PacketHdr};
 use crate::packet::Packet;
 use crate::packet::auth::AuthMethod;
 use crate::packet::iana::PacketType;
+use crate::packet::lls::{ExtendedOptionsFlags, LlsHelloData};
 use crate::version::Ospfv2;
 
 // ===== impl Ospfv2 =====
@@ -38,6 +39,14 @@ impl InterfaceVersion<Self> for Ospfv2 {
             return Err(InterfaceInactiveReason::MissingIpv4Address);
         }
 
+        // Unnumbered interfaces are only supported on point-to-point
+        // networks.
+        if iface.system.unnumbered
+            && iface.config.if_type != InterfaceType::PointToPoint
+        {
+            return Err(InterfaceInactiveReason::UnnumberedNotPointToPoint);
+        }
+
         Ok(())
     }
 
@@ -64,8 +73,13 @@ impl InterfaceVersion<Self> for Ospfv2 {
         };
 
         let lls = if iface.config.lls_enabled {
-            // TODO: Get LLS configuration
-            None
+            // Advertise the LR capability, and signal a restart using the RS
+            // bit while graceful restart is in progress (RFC 4811/4812).
+            let mut eof = ExtendedOptionsFlags::LR;
+            if instance.state.gr_restart.is_some() {
+                eof.insert(ExtendedOptionsFlags::RS);
+            }
+            Some(LlsHelloData { eof: Some(eof) })
         } else {
             None
         };
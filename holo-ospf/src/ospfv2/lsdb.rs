@@ -4,7 +4,7 @@
 // SPDX-License-Identifier: MIT
 //
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::net::Ipv4Addr;
 
 use holo_utils::ip::{AddressFamily, Ipv4NetworkExt};
@@ -12,15 +12,18 @@ use holo_utils::mpls::Label;
 use holo_utils::sr::{IgpAlgoType, Sid, SidLastHopBehavior, SrCfgEvent};
 use ipnetwork::{IpNetwork, Ipv4Network};
 use itertools::Itertools;
+use num_traits::ToPrimitive;
 
 use crate::area::{
-    Area, AreaType, AreaVersion, BACKBONE_AREA_ID, OptionsLocation,
+    Area, AreaType, AreaVersion, BACKBONE_AREA_ID, NssaTranslatorState,
+    OptionsLocation,
 };
 use crate::collections::{
     AreaIndex, Arena, InterfaceIndex, LsaEntryId, LsdbId, LsdbIndex, lsdb_get,
 };
 use crate::debug::LsaFlushReason;
 use crate::error::Error;
+use crate::flex_algo;
 use crate::instance::{InstanceArenas, InstanceUpView};
 use crate::interface::{Interface, InterfaceType, ism};
 use crate::lsdb::{
@@ -28,25 +31,28 @@ use crate::lsdb::{
 };
 use crate::neighbor::nsm;
 use crate::ospfv2::packet::iana::{
-    LsaExtPrefixFlags, LsaOpaqueType, LsaRouterFlags, LsaRouterLinkType,
-    LsaTypeCode, Options,
+    ExtPrefixRangeFlags, LsaExtPrefixFlags, LsaOpaqueType, LsaRouterFlags,
+    LsaRouterLinkType, LsaTypeCode, Options,
 };
 use crate::ospfv2::packet::lsa::{
-    LsaBody, LsaHdr, LsaNetwork, LsaRouter, LsaRouterLink, LsaSummary, LsaType,
+    LsaAsExternal, LsaAsExternalFlags, LsaBody, LsaHdr, LsaNetwork, LsaRouter,
+    LsaRouterLink, LsaSummary, LsaType,
 };
 use crate::ospfv2::packet::lsa_opaque::{
-    ExtLinkTlv, ExtPrefixRouteType, ExtPrefixTlv, LsaExtLink, LsaExtPrefix,
-    LsaOpaque, LsaRouterInfo, OpaqueLsaId, PrefixSid,
+    ExtLinkTlv, ExtPrefixRangeTlv, ExtPrefixRouteType, ExtPrefixTlv,
+    GrInterfaceAddrTlv, LsaExtLink, LsaExtPrefix, LsaGrace, LsaOpaque,
+    LsaRouterInfo, OpaqueLsaId, PrefixSid,
 };
-use crate::packet::iana::RouterInfoCaps;
+use crate::packet::iana::{FloodingAlgo, RouterInfoCaps};
 use crate::packet::lsa::{
     Lsa, LsaHdrVersion, LsaKey, LsaScope, LsaTypeVersion,
 };
 use crate::packet::tlv::{
-    DynamicHostnameTlv, NodeAdminTagTlv, PrefixSidFlags, RouterInfoCapsTlv,
-    SidLabelRangeTlv, SrAlgoTlv, SrLocalBlockTlv,
+    DynamicHostnameTlv, FloodingAlgoTlv, GrReason, GrReasonTlv, GracePeriodTlv,
+    NodeAdminTagTlv, PrefixSidFlags, RouterInfoCapsTlv, SidLabelRangeTlv,
+    SrAlgoTlv, SrLocalBlockTlv,
 };
-use crate::route::{SummaryNet, SummaryRtr};
+use crate::route::{ExternalNet, SummaryNet, SummaryRtr};
 use crate::version::Ospfv2;
 
 // ===== impl Ospfv2 =====
@@ -98,6 +104,14 @@ impl LsdbVersion<Self> for Ospfv2 {
             return false;
         }
 
+        // Reject NSSA LSAs on non-NSSA areas.
+        if let Some(area_type) = area_type
+            && area_type != AreaType::Nssa
+            && lsa_type.type_code() == Some(LsaTypeCode::NssaExternal)
+        {
+            return false;
+        }
+
         true
     }
 
@@ -153,6 +167,10 @@ impl LsdbVersion<Self> for Ospfv2 {
                 lsa_orig_ext_prefix(area, instance, arenas);
             }
             LsaOriginateEvent::InterfaceDrChange { area_id, iface_id }
+            | LsaOriginateEvent::InterfacePrefixSuppressionChange {
+                area_id,
+                iface_id,
+            }
             | LsaOriginateEvent::GrHelperExit { area_id, iface_id } => {
                 // (Re)originate Router-LSA.
                 let (_, area) = arenas.areas.get_by_id(area_id)?;
@@ -192,6 +210,11 @@ impl LsdbVersion<Self> for Ospfv2 {
                 let (_, area) = arenas.areas.get_by_id(area_id)?;
                 lsa_orig_ext_prefix(area, instance, arenas);
             }
+            LsaOriginateEvent::InterfaceLinkAttrChange { area_id } => {
+                // (Re)originate Extended Link Opaque LSA(s).
+                let (_, area) = arenas.areas.get_by_id(area_id)?;
+                lsa_orig_ext_link(area, instance, arenas);
+            }
             LsaOriginateEvent::NeighborToFromFull { area_id, iface_id } => {
                 // (Re)originate Router-LSA.
                 let (_, area) = arenas.areas.get_by_id(area_id)?;
@@ -248,7 +271,9 @@ impl LsdbVersion<Self> for Ospfv2 {
                 // reoriginated or flushed.
                 process_self_originated_lsa(instance, arenas, lsdb_id, lse_id)?;
             }
-            LsaOriginateEvent::StubRouterChange => {
+            LsaOriginateEvent::StubRouterChange
+            | LsaOriginateEvent::NssaTranslatorChange
+            | LsaOriginateEvent::AsbrStatusChange => {
                 // (Re)originate Router-LSA in all areas.
                 for area in arenas.areas.iter() {
                     lsa_orig_router(area, instance, arenas);
@@ -286,26 +311,134 @@ impl LsdbVersion<Self> for Ospfv2 {
                             }
                         }
                     }
+                    SrCfgEvent::MappingServerUpdate => {
+                        // (Re)originate Extended Prefix Opaque LSA(s) in all
+                        // areas.
+                        for area in arenas.areas.iter() {
+                            lsa_orig_ext_prefix(area, instance, arenas);
+                        }
+                    }
                 }
             }
+            LsaOriginateEvent::FloodingReductionChange { area_id } => {
+                // (Re)originate Router Information LSA in the area.
+                let (_, area) = arenas.areas.get_by_id(area_id)?;
+                lsa_orig_router_info(area, instance);
+            }
             LsaOriginateEvent::HostnameChange
-            | LsaOriginateEvent::NodeTagsChange => {
+            | LsaOriginateEvent::NodeTagsChange
+            | LsaOriginateEvent::FlexAlgoChange => {
                 // (Re)originate Router Information LSA(s) in all areas.
                 for area in arenas.areas.iter() {
                     lsa_orig_router_info(area, instance);
                 }
             }
+            LsaOriginateEvent::GrRestartExit => {
+                // (Re)originate all self-originated LSAs, since their
+                // origination was suppressed during the graceful restart.
+                for area in arenas.areas.iter() {
+                    lsa_orig_router(area, instance, arenas);
+                    lsa_orig_router_info(area, instance);
+                    lsa_orig_ext_prefix(area, instance, arenas);
+                    lsa_orig_ext_link(area, instance, arenas);
+
+                    // (Re)originate or flush Network-LSA(s).
+                    for iface in area.interfaces.iter(&arenas.interfaces) {
+                        if iface.state.ism_state == ism::State::Dr
+                            && iface
+                                .state
+                                .neighbors
+                                .iter(&arenas.neighbors)
+                                .any(|nbr| nbr.state == nsm::State::Full)
+                        {
+                            lsa_orig_network(iface, area, instance, arenas);
+                        } else {
+                            lsa_flush_network(iface, area, instance, arenas);
+                        }
+                    }
+                }
+            }
             _ => (),
         };
 
         Ok(())
     }
 
+    fn lsa_grace(
+        iface: &Interface<Self>,
+        area: &Area<Self>,
+        grace_period: u32,
+        reason: GrReason,
+    ) -> (Option<Options>, Ipv4Addr, LsaBody) {
+        // LSA's header options.
+        let options = Ospfv2::area_options(area, OptionsLocation::Lsa);
+
+        // Initialize Opaque LSA ID.
+        let lsa_id = OpaqueLsaId::new(LsaOpaqueType::Grace as u8, 0).into();
+
+        // On broadcast, NBMA and P2MP segments, the restarting router is
+        // identified by its IP interface address.
+        let addr = (iface.is_broadcast_or_nbma()
+            || iface.config.if_type == InterfaceType::PointToMultipoint)
+            .then_some(iface.system.primary_addr)
+            .flatten()
+            .map(|addr| GrInterfaceAddrTlv::new(addr.ip()));
+
+        let lsa_body = LsaBody::OpaqueLink(LsaOpaque::Grace(LsaGrace {
+            grace_period: Some(GracePeriodTlv::new(grace_period)),
+            gr_reason: Some(GrReasonTlv::new(reason as u8)),
+            addr,
+            unknown_tlvs: vec![],
+        }));
+        (Some(options), lsa_id, lsa_body)
+    }
+
+    fn prefix_lsa_ids(
+        prefixes: impl Iterator<Item = Ipv4Network>,
+    ) -> Option<BTreeMap<Ipv4Network, u32>> {
+        // Group the prefixes by network address. Within each group, the least
+        // specific prefix uses the network address as its Link State ID, while
+        // the others use the network address with all host bits set (RFC 2328
+        // - Appendix E). Host routes can't have any host bits set, hence they
+        // always take precedence for the network address.
+        let mut groups: BTreeMap<Ipv4Addr, Vec<Ipv4Network>> = BTreeMap::new();
+        for prefix in prefixes {
+            groups.entry(prefix.network()).or_default().push(prefix);
+        }
+
+        let mut lsa_ids = BTreeMap::new();
+        let mut used_lsa_ids = BTreeSet::new();
+        let mut displaced = vec![];
+        for (addr, mut group) in groups {
+            group
+                .sort_by_key(|prefix| (prefix.prefix() != 32, prefix.prefix()));
+            let mut group = group.into_iter();
+            if let Some(prefix) = group.next() {
+                lsa_ids.insert(prefix, addr.into());
+                used_lsa_ids.insert(addr);
+            }
+            displaced.extend(group);
+        }
+
+        // Prefixes whose Link State ID would collide with the one of another
+        // LSA can't be advertised.
+        for prefix in displaced {
+            let lsa_id = prefix.broadcast();
+            if used_lsa_ids.insert(lsa_id) {
+                lsa_ids.insert(prefix, lsa_id.into());
+            } else {
+                Error::<Self>::LsaIdAssignConflict(prefix).log();
+            }
+        }
+
+        Some(lsa_ids)
+    }
+
     fn lsa_orig_inter_area_network(
         area: &mut Area<Self>,
         instance: &InstanceUpView<'_, Self>,
         prefix: Ipv4Network,
-        _lsa_id: Option<u32>,
+        lsa_id: Option<u32>,
         summary: &SummaryNet<Self>,
     ) -> u32 {
         let lsdb_id = LsdbId::Area(area.id);
@@ -313,8 +446,8 @@ impl LsdbVersion<Self> for Ospfv2 {
         // LSA's header options.
         let options = Self::area_options(area, OptionsLocation::Lsa);
 
-        // TODO: implement Appendix's E algorithm for assigning Link State IDs.
-        let lsa_id = prefix.ip();
+        // LSA ID (RFC 2328 - Appendix E).
+        let lsa_id = lsa_id.map(Ipv4Addr::from).unwrap_or(prefix.ip());
 
         // (Re)originate Type-3 Summary-LSA.
         let lsa_body = LsaBody::SummaryNetwork(LsaSummary {
@@ -360,6 +493,57 @@ impl LsdbVersion<Self> for Ospfv2 {
         lsa_id.into()
     }
 
+    fn lsa_orig_as_external(
+        instance: &mut InstanceUpView<'_, Self>,
+        prefix: Ipv4Network,
+        lsa_id: Option<u32>,
+        external: &ExternalNet<Self>,
+    ) -> u32 {
+        // LSA ID (RFC 2328 - Appendix E).
+        let lsa_id = lsa_id.map(Ipv4Addr::from).unwrap_or(prefix.ip());
+
+        // (Re)originate Type-5 AS-External-LSA.
+        let lsa_body = LsaBody::AsExternal(lsa_as_external(prefix, external));
+        instance.tx.protocol_input.lsa_orig_check(
+            LsdbId::As,
+            Some(Options::E),
+            lsa_id,
+            lsa_body,
+        );
+
+        lsa_id.into()
+    }
+
+    fn lsa_orig_nssa(
+        area: &mut Area<Self>,
+        instance: &InstanceUpView<'_, Self>,
+        prefix: Ipv4Network,
+        lsa_id: Option<u32>,
+        external: &ExternalNet<Self>,
+    ) -> u32 {
+        let lsdb_id = LsdbId::Area(area.id);
+
+        // LSA's header options.
+        let mut options = Self::area_options(area, OptionsLocation::Lsa);
+        if external.propagate {
+            options.insert(Options::NP);
+        }
+
+        // LSA ID (RFC 2328 - Appendix E).
+        let lsa_id = lsa_id.map(Ipv4Addr::from).unwrap_or(prefix.ip());
+
+        // (Re)originate Type-7 NSSA-LSA.
+        let lsa_body = LsaBody::NssaExternal(lsa_as_external(prefix, external));
+        instance.tx.protocol_input.lsa_orig_check(
+            lsdb_id,
+            Some(options),
+            lsa_id,
+            lsa_body,
+        );
+
+        lsa_id.into()
+    }
+
     fn lsdb_get_by_lsa_type(
         iface_idx: InterfaceIndex,
         area_idx: AreaIndex,
@@ -422,6 +606,23 @@ impl LsdbVersion<Self> for Ospfv2 {
 
 // ===== helper functions =====
 
+fn lsa_as_external(
+    prefix: Ipv4Network,
+    external: &ExternalNet<Ospfv2>,
+) -> LsaAsExternal {
+    let mut flags = LsaAsExternalFlags::empty();
+    if external.e_bit {
+        flags.insert(LsaAsExternalFlags::E);
+    }
+    LsaAsExternal {
+        mask: prefix.mask(),
+        flags,
+        metric: external.metric,
+        fwd_addr: external.fwd_addr,
+        tag: external.tag.unwrap_or(0),
+    }
+}
+
 fn lsa_orig_router(
     area: &Area<Ospfv2>,
     instance: &InstanceUpView<'_, Ospfv2>,
@@ -437,9 +638,15 @@ fn lsa_orig_router(
     if arenas.areas.is_abr(&arenas.interfaces) {
         flags.insert(LsaRouterFlags::B);
     }
+    if lsdb::router_lsa_e_bit(area, instance) {
+        flags.insert(LsaRouterFlags::E);
+    }
     if lsdb::router_lsa_v_bit(area, arenas) {
         flags.insert(LsaRouterFlags::V);
     }
+    if area.state.nssa_translator_state != NssaTranslatorState::Disabled {
+        flags.insert(LsaRouterFlags::NT);
+    }
 
     // Router-LSA's links.
     let mut links = vec![];
@@ -463,29 +670,26 @@ fn lsa_orig_router(
         }
 
         // When stub-router is configured (RFC 6987), set the cost of all
-        // non-stub links to MaxLinkMetric.
-        let non_stub_cost = if instance.config.stub_router {
-            MAX_LINK_METRIC
-        } else {
-            iface.config.cost
-        };
+        // non-stub links to MaxLinkMetric. The same applies to links where
+        // LDP isn't synchronized yet (RFC 5443).
+        let non_stub_cost =
+            if instance.config.stub_router || iface.ldp_sync_max_metric() {
+                MAX_LINK_METRIC
+            } else {
+                iface.config.cost
+            };
 
         let mut add_stub_links = false;
         match iface.config.if_type {
             InterfaceType::PointToPoint | InterfaceType::PointToMultipoint => {
                 // Add a Type-1 link (p2p) for each fully adjacent neighbor.
-                let primary_addr = iface.system.primary_addr.unwrap();
+                let link_data = p2p_link_data(iface);
                 for nbr in iface
                     .state
                     .neighbors
                     .iter(&arenas.neighbors)
                     .filter(|nbr| nbr.state == nsm::State::Full)
                 {
-                    let link_data = if iface.system.unnumbered {
-                        Ipv4Addr::from(iface.system.ifindex.unwrap())
-                    } else {
-                        primary_addr.ip()
-                    };
                     let link = LsaRouterLink::new(
                         LsaRouterLinkType::PointToPoint,
                         nbr.router_id,
@@ -561,6 +765,17 @@ fn lsa_orig_router(
                     .system
                     .addr_list
                     .iter()
+                    // Unnumbered addresses are borrowed from other
+                    // interfaces, which already advertise them.
+                    .filter(|addr| {
+                        !iface.system.unnumbered_addrs.contains(*addr)
+                    })
+                    // When prefix suppression is enabled, only secondary
+                    // addresses are advertised (RFC 6860).
+                    .filter(|addr| {
+                        !iface.config.prefix_suppression.resolved
+                            || iface.system.primary_addr != Some(**addr)
+                    })
                     .map(|addr| addr.apply_mask())
                     .map(|addr| {
                         LsaRouterLink::new(
@@ -584,6 +799,16 @@ fn lsa_orig_router(
     );
 }
 
+// Returns the Link Data of point-to-point links. For unnumbered interfaces,
+// this is the interface's ifindex (RFC 2328 - Appendix A.4.2).
+fn p2p_link_data(iface: &Interface<Ospfv2>) -> Ipv4Addr {
+    if iface.system.unnumbered {
+        Ipv4Addr::from(iface.system.ifindex.unwrap())
+    } else {
+        iface.system.primary_addr.unwrap().ip()
+    }
+}
+
 fn lsa_orig_network(
     iface: &Interface<Ospfv2>,
     area: &Area<Ospfv2>,
@@ -599,8 +824,14 @@ fn lsa_orig_network(
     // Designated Router.
     let lsa_id = iface.system.primary_addr.unwrap().ip();
 
-    // Network-LSA's mask.
-    let mask = iface.system.primary_addr.unwrap().mask();
+    // Network-LSA's mask. When prefix suppression is enabled, a /32 mask is
+    // used so that only a host route to the DR is derived from the LSA
+    // (RFC 6860).
+    let mask = if iface.config.prefix_suppression.resolved {
+        Ipv4Addr::BROADCAST
+    } else {
+        iface.system.primary_addr.unwrap().mask()
+    };
 
     // Network-LSA's attached routers.
     let myself = instance.state.router_id;
@@ -652,9 +883,28 @@ fn lsa_orig_router_info(
     let mut sr_algo = None;
     let mut srgb = vec![];
     let mut srlb = vec![];
+    let mut fads = vec![];
     if instance.config.sr_enabled {
         // Fill in supported SR algorithms.
-        sr_algo = Some(SrAlgoTlv::new([IgpAlgoType::Spf].into()));
+        let algos = std::iter::once(IgpAlgoType::Spf)
+            .chain(
+                instance
+                    .config
+                    .flex_algo
+                    .keys()
+                    .map(|algo| IgpAlgoType::FlexAlgo(*algo)),
+            )
+            .collect();
+        sr_algo = Some(SrAlgoTlv::new(algos));
+
+        // Fill in Flexible Algorithm Definitions.
+        fads = instance
+            .config
+            .flex_algo
+            .iter()
+            .filter(|(_, fa_cfg)| fa_cfg.advertise_definition)
+            .map(|(algo, fa_cfg)| flex_algo::fad_tlv(*algo, fa_cfg))
+            .collect();
 
         // Fill in local SRGB.
         for range in &sr_config.srgb {
@@ -677,6 +927,14 @@ fn lsa_orig_router_info(
         node_tags.push(NodeAdminTagTlv::new(instance.config.node_tags.clone()));
     }
 
+    // Fill in flooding reduction algorithm.
+    let mut flooding_algo = None;
+    if area.config.flooding_algo != FloodingAlgo::ZeroPruner {
+        flooding_algo = Some(FloodingAlgoTlv::new(
+            area.config.flooding_algo.to_u8().unwrap(),
+        ));
+    }
+
     // (Re)originate Router Information LSA.
     let mut info_caps = RouterInfoCaps::STUB_ROUTER;
     if instance.config.gr.helper_enabled {
@@ -696,6 +954,8 @@ fn lsa_orig_router_info(
             .as_ref()
             .map(|hostname| DynamicHostnameTlv::new(hostname.to_string())),
         node_tags,
+        fads,
+        flooding_algo,
         unknown_tlvs: vec![],
     }));
     instance.tx.protocol_input.lsa_orig_check(
@@ -726,7 +986,6 @@ fn lsa_orig_ext_prefix(
             if let IpNetwork::V4(prefix) = prefix {
                 // Add Prefix-SID Sub-TLV.
                 let mut psid_flags = PrefixSidFlags::empty();
-                let mut prefix_sids = BTreeMap::new();
                 match prefix_sid.last_hop {
                     SidLastHopBehavior::ExpNull => {
                         psid_flags.insert(PrefixSidFlags::NP);
@@ -738,20 +997,18 @@ fn lsa_orig_ext_prefix(
                     SidLastHopBehavior::Php => (),
                 }
                 let sid = Sid::Index(prefix_sid.index);
-                prefix_sids
+                prefixes
+                    .entry(*prefix)
+                    .or_insert_with(|| {
+                        ExtPrefixTlv::new(
+                            ExtPrefixRouteType::IntraArea,
+                            0,
+                            LsaExtPrefixFlags::empty(),
+                            *prefix,
+                        )
+                    })
+                    .prefix_sids
                     .insert(*algo, PrefixSid::new(psid_flags, *algo, sid));
-
-                prefixes.insert(
-                    *prefix,
-                    ExtPrefixTlv {
-                        route_type: ExtPrefixRouteType::IntraArea,
-                        af: 0,
-                        flags: LsaExtPrefixFlags::empty(),
-                        prefix: *prefix,
-                        prefix_sids,
-                        unknown_tlvs: vec![],
-                    },
-                );
             }
         }
     }
@@ -793,9 +1050,44 @@ fn lsa_orig_ext_prefix(
             });
     }
 
+    // Add the prefix ranges of all advertised mapping server policies. If the
+    // same entry is present in multiple policies, the first one is used.
+    let mut ranges = BTreeMap::new();
+    if instance.config.sr_enabled {
+        for ((prefix, algo), entry) in instance
+            .config
+            .sr_ms_advertise_policies
+            .iter()
+            .filter_map(|name| sr_config.ms_policies.get(name))
+            .flat_map(|policy| policy.entries.iter())
+        {
+            if let IpNetwork::V4(prefix) = prefix {
+                ranges.entry((*prefix, *algo)).or_insert(entry);
+            }
+        }
+    }
+    let ranges = ranges
+        .into_iter()
+        .map(|((prefix, algo), entry)| {
+            // The M-Flag indicates that the Prefix-SID was advertised by an SR
+            // Mapping Server.
+            let psid_flags = PrefixSidFlags::M;
+            let sid = Sid::Index(entry.index);
+            let mut tlv = ExtPrefixRangeTlv::new(
+                0,
+                entry.range as u16,
+                ExtPrefixRangeFlags::empty(),
+                prefix,
+            );
+            tlv.prefix_sids
+                .insert(algo, PrefixSid::new(psid_flags, algo, sid));
+            tlv
+        })
+        .collect::<Vec<_>>();
+
     // (Re)originate as many Extended Prefix Opaque LSAs as necessary.
     let mut opaque_id: u32 = 0;
-    let mut originate_fn = |prefixes| {
+    let mut originate_fn = |prefixes, ranges| {
         // Initialize Opaque LSA ID.
         let lsa_id =
             OpaqueLsaId::new(LsaOpaqueType::ExtPrefix as u8, opaque_id).into();
@@ -804,6 +1096,7 @@ fn lsa_orig_ext_prefix(
         let lsa_body =
             LsaBody::OpaqueArea(LsaOpaque::ExtPrefix(LsaExtPrefix {
                 prefixes,
+                ranges,
             }));
         instance.tx.protocol_input.lsa_orig_check(
             lsdb_id,
@@ -823,7 +1116,17 @@ fn lsa_orig_ext_prefix(
         )
         .into_iter()
     {
-        originate_fn(prefixes.collect());
+        originate_fn(prefixes.collect(), vec![]);
+    }
+    for ranges in ranges
+        .into_iter()
+        .chunks(
+            (Lsa::<Ospfv2>::MAX_LENGTH - LsaHdr::LENGTH as usize)
+                / ExtPrefixRangeTlv::BASE_LENGTH as usize,
+        )
+        .into_iter()
+    {
+        originate_fn(BTreeMap::new(), ranges.collect());
     }
 
     // Flush self-originated Extended Prefix Opaque LSAs that are no longer
@@ -888,6 +1191,7 @@ fn lsa_orig_ext_link(
             .filter(|iface| iface.state.ism_state != ism::State::Loopback)
         {
             let primary_addr = iface.system.primary_addr.unwrap();
+            let asla = flex_algo::link_asla(&iface.config.link_attrs);
             match iface.config.if_type {
                 InterfaceType::PointToPoint
                 | InterfaceType::PointToMultipoint => {
@@ -897,13 +1201,14 @@ fn lsa_orig_ext_link(
                         .iter(&arenas.neighbors)
                         .filter(|nbr| nbr.state == nsm::State::Full)
                     {
-                        let link_tlv = ExtLinkTlv::new(
+                        let mut link_tlv = ExtLinkTlv::new(
                             LsaRouterLinkType::PointToPoint,
                             nbr.router_id,
-                            primary_addr.ip(),
+                            p2p_link_data(iface),
                             nbr.adj_sids.clone(),
                             None,
                         );
+                        link_tlv.asla.extend(asla.clone());
                         originate_fn(link_tlv);
                     }
                 }
@@ -935,13 +1240,14 @@ fn lsa_orig_ext_link(
                             .flat_map(|nbr| nbr.adj_sids.iter())
                             .copied()
                             .collect();
-                        let link_tlv = ExtLinkTlv::new(
+                        let mut link_tlv = ExtLinkTlv::new(
                             LsaRouterLinkType::TransitNetwork,
                             iface.state.dr.unwrap().get(),
                             primary_addr.ip(),
                             adj_sids,
                             None,
                         );
+                        link_tlv.asla.extend(asla);
                         originate_fn(link_tlv);
                     };
                 }
@@ -1027,10 +1333,9 @@ fn process_self_originated_lsa(
             // Do nothing. These LSAs will be either reoriginated or flushed
             // once SPF runs and the routing table is computed.
         }
-        Some(LsaTypeCode::AsExternal) => {
-            // Flush AS-External-LSA (redistribution of local routes isn't
-            // supported at the moment).
-            flush = true;
+        Some(LsaTypeCode::AsExternal | LsaTypeCode::NssaExternal) => {
+            // Do nothing. These LSAs will be either reoriginated or flushed
+            // once SPF runs and the routing table is computed.
         }
         Some(
             LsaTypeCode::OpaqueLink
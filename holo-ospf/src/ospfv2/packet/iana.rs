@@ -36,6 +36,7 @@ pub enum AuthType {
     Null = 0x00,
     Simple = 0x01,
     Cryptographic = 0x02,
+    CryptographicExtSeqno = 0x03,
 }
 
 // OSPFv2 LSA type code.
@@ -50,6 +51,7 @@ pub enum LsaTypeCode {
     SummaryNetwork = 3,
     SummaryRouter = 4,
     AsExternal = 5,
+    NssaExternal = 7,
     OpaqueLink = 9,
     OpaqueArea = 10,
     OpaqueAs = 11,
@@ -138,6 +140,19 @@ bitflags! {
     }
 }
 
+// OSPFv2 Extended Prefix Range TLV Flags.
+//
+// IANA registry:
+// https://www.iana.org/assignments/ospfv2-parameters/ospfv2-parameters.xhtml#extended-prefix-range-tlv-flags
+bitflags! {
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[derive(Deserialize, Serialize)]
+    #[serde(transparent)]
+    pub struct ExtPrefixRangeFlags: u8 {
+        const IA = 0x80;
+    }
+}
+
 // OSPFv2 Extended Prefix TLV Sub-TLV types.
 //
 // IANA registry:
@@ -173,4 +188,10 @@ pub enum ExtLinkStlvType {
     AdjSid = 2,
     LanAdjSid = 3,
     LinkMsd = 6,
+    Asla = 10,
+    Srlg = 11,
+    MinMaxLinkDelay = 13,
+    AdminGroup = 19,
+    ExtAdminGroup = 20,
+    TeMetric = 22,
 }
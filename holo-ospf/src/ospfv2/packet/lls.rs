@@ -4,15 +4,13 @@
 // SPDX-License-Identifier: MIT
 //
 
-use std::sync::atomic::Ordering;
-
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 use derive_new::new;
 use num_traits::FromPrimitive;
 use serde::{Deserialize, Serialize};
 
-use super::{PacketHdrAuth, validate_digest};
-use crate::packet::auth::{self, AuthDecodeCtx, AuthEncodeCtx};
+use super::{PacketHdr, PacketHdrAuth, auth_digest, validate_digest};
+use crate::packet::auth::{AuthDecodeCtx, AuthEncodeCtx};
 use crate::packet::error::{DecodeError, DecodeResult};
 use crate::packet::lls::{
     ExtendedOptionsFlagsTlv, LLS_HDR_SIZE, LlsDbDescData, LlsHelloData,
@@ -107,12 +105,11 @@ impl CryptoAuthTlv {
         lls_encode_end::<Ospfv2>(buf, lls_start_pos, true);
 
         // Compute auth digest on full LLS block until auth digest.
-        let digest = auth::message_digest(
+        let digest = auth_digest(
             &buf[lls_start_pos..auth_digest_start],
-            auth.key.algo,
-            &auth.key.string,
-            None,
-            None,
+            auth.key,
+            auth.ext_seqno,
+            &auth.src_addr,
         );
 
         // Replace auth digest place holder with actual digest.
@@ -149,9 +146,10 @@ impl LlsVersion<Self> for Ospfv2 {
         // block.  Also, when present, this TLV MUST be the last TLV in the LLS
         // block."
         if let Some(auth) = auth {
-            let ca = CryptoAuthTlv::new(
-                (auth.seqno.load(Ordering::Relaxed) as u32) - 1,
-            );
+            // Use the same sequence number as the packet header.
+            let seqno = &buf[PacketHdr::AUTH_SEQNO_RANGE];
+            let seqno = u32::from_be_bytes(seqno.try_into().unwrap());
+            let ca = CryptoAuthTlv::new(seqno);
             ca.encode(buf, start_pos, auth);
         }
         lls_encode_end::<Ospfv2>(buf, start_pos, auth.is_some());
@@ -178,7 +176,15 @@ impl LlsVersion<Self> for Ospfv2 {
         // If authentication trailer is embedded, skip it.
         // The authentication digest has already been verified earlier, so no
         // need for a double check here.
-        if let PacketHdrAuth::Cryptographic { auth_len, .. } = hdr_auth {
+        if let PacketHdrAuth::Cryptographic {
+            auth_len,
+            ext_seqno,
+            ..
+        } = hdr_auth
+        {
+            if ext_seqno {
+                buf.advance(PacketHdr::AUTH_EXT_SEQNO_LEN as usize);
+            }
             buf.advance(auth_len as usize);
         } else {
             // Validate LLS block checksum when authentication is disabled.
@@ -235,6 +241,7 @@ impl LlsVersion<Self> for Ospfv2 {
                         key_id,
                         auth_len,
                         seqno,
+                        ..
                     } = hdr_auth
                     {
                         // RFC 5613 Section 2.5 : "The Sequence Number field
@@ -49,6 +49,7 @@ pub enum LsaBody {
     SummaryNetwork(LsaSummary),
     SummaryRouter(LsaSummary),
     AsExternal(LsaAsExternal),
+    NssaExternal(LsaAsExternal),
     OpaqueLink(LsaOpaque),
     OpaqueArea(LsaOpaque),
     OpaqueAs(LsaOpaque),
@@ -131,7 +132,8 @@ pub struct LsaRouter {
 pub struct LsaRouterLink {
     pub link_type: LsaRouterLinkType,
     pub link_id: Ipv4Addr,
-    // TODO: might be an ifindex for unnumbered point-to-point connections.
+    // For unnumbered point-to-point connections, this is the interface's
+    // ifindex.
     pub link_data: Ipv4Addr,
     pub metric: u16,
 }
@@ -183,6 +185,8 @@ pub struct LsaSummary {
 //
 // OSPFv2 AS-External-LSA.
 //
+// The same encoding format is used by NSSA-LSAs (RFC 3101).
+//
 // Encoding format (LSA body):
 //
 //  0                   1                   2                   3
@@ -262,6 +266,7 @@ impl LsaTypeVersion for LsaType {
                 | LsaTypeCode::Network
                 | LsaTypeCode::SummaryNetwork
                 | LsaTypeCode::SummaryRouter
+                | LsaTypeCode::NssaExternal
                 | LsaTypeCode::OpaqueArea,
             ) => LsaScope::Area,
             Some(LsaTypeCode::AsExternal | LsaTypeCode::OpaqueAs) => {
@@ -280,6 +285,7 @@ impl LsaTypeVersion for LsaType {
                     | LsaTypeCode::SummaryNetwork
                     | LsaTypeCode::SummaryRouter
                     | LsaTypeCode::AsExternal
+                    | LsaTypeCode::NssaExternal
             )
         )
     }
@@ -441,6 +447,9 @@ impl LsaBodyVersion<Ospfv2> for LsaBody {
             Some(LsaTypeCode::AsExternal) => {
                 LsaBody::AsExternal(LsaAsExternal::decode(buf)?)
             }
+            Some(LsaTypeCode::NssaExternal) => {
+                LsaBody::NssaExternal(LsaAsExternal::decode(buf)?)
+            }
             Some(LsaTypeCode::OpaqueLink) => {
                 LsaBody::OpaqueLink(LsaOpaque::decode(lsa_id, buf)?)
             }
@@ -463,6 +472,7 @@ impl LsaBodyVersion<Ospfv2> for LsaBody {
             LsaBody::SummaryNetwork(lsa) => lsa.encode(buf),
             LsaBody::SummaryRouter(lsa) => lsa.encode(buf),
             LsaBody::AsExternal(lsa) => lsa.encode(buf),
+            LsaBody::NssaExternal(lsa) => lsa.encode(buf),
             LsaBody::OpaqueLink(lsa) => lsa.encode(buf),
             LsaBody::OpaqueArea(lsa) => lsa.encode(buf),
             LsaBody::OpaqueAs(lsa) => lsa.encode(buf),
@@ -477,6 +487,7 @@ impl LsaBodyVersion<Ospfv2> for LsaBody {
             LsaBody::SummaryNetwork(_lsa) => LsaTypeCode::SummaryNetwork.into(),
             LsaBody::SummaryRouter(_lsa) => LsaTypeCode::SummaryRouter.into(),
             LsaBody::AsExternal(_lsa) => LsaTypeCode::AsExternal.into(),
+            LsaBody::NssaExternal(_lsa) => LsaTypeCode::NssaExternal.into(),
             LsaBody::OpaqueLink(_lsa) => LsaTypeCode::OpaqueLink.into(),
             LsaBody::OpaqueArea(_lsa) => LsaTypeCode::OpaqueArea.into(),
             LsaBody::OpaqueAs(_lsa) => LsaTypeCode::OpaqueAs.into(),
@@ -586,6 +597,10 @@ impl LsaRouterFlagsVersion for LsaRouterFlags {
     fn is_vlink_endpoint(&self) -> bool {
         self.contains(LsaRouterFlags::V)
     }
+
+    fn is_nssa_translator(&self) -> bool {
+        self.contains(LsaRouterFlags::NT)
+    }
 }
 
 // ===== impl LsaNetwork =====
@@ -713,4 +728,12 @@ impl LsaVersion<Self> for Ospfv2 {
     fn type4_summary(_extended_lsa: bool) -> LsaType {
         LsaTypeCode::SummaryRouter.into()
     }
+
+    fn type5_external(_extended_lsa: bool) -> LsaType {
+        LsaTypeCode::AsExternal.into()
+    }
+
+    fn type7_nssa(_extended_lsa: bool) -> LsaType {
+        LsaTypeCode::NssaExternal.into()
+    }
 }
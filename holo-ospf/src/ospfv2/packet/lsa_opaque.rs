@@ -15,22 +15,24 @@ use holo_utils::mpls::Label;
 use holo_utils::sr::{IgpAlgoType, Sid};
 use ipnetwork::Ipv4Network;
 use num_derive::FromPrimitive;
-use num_traits::FromPrimitive;
+use num_traits::{FromPrimitive, ToPrimitive};
 use serde::{Deserialize, Serialize};
 
 use crate::ospfv2::packet::iana::{
-    ExtLinkStlvType, ExtLinkTlvType, ExtPrefixStlvType, ExtPrefixTlvType,
-    GraceTlvType, LsaExtPrefixFlags, LsaOpaqueType, LsaRouterLinkType,
+    ExtLinkStlvType, ExtLinkTlvType, ExtPrefixRangeFlags, ExtPrefixStlvType,
+    ExtPrefixTlvType, GraceTlvType, LsaExtPrefixFlags, LsaOpaqueType,
+    LsaRouterLinkType,
 };
 use crate::ospfv2::packet::lsa::LsaUnknown;
 use crate::packet::error::{DecodeError, DecodeResult};
 use crate::packet::iana::RouterInfoTlvType;
 use crate::packet::lsa::{AdjSidVersion, PrefixSidVersion};
 use crate::packet::tlv::{
-    AdjSidFlags, DynamicHostnameTlv, GrReasonTlv, GracePeriodTlv, MsdTlv,
-    NodeAdminTagTlv, PrefixSidFlags, RouterFuncCapsTlv, RouterInfoCapsTlv,
-    SidLabelRangeTlv, SrAlgoTlv, SrLocalBlockTlv, SrmsPrefTlv, TLV_HDR_SIZE,
-    UnknownTlv, tlv_encode_end, tlv_encode_start, tlv_wire_len,
+    AdjSidFlags, AslaStlv, DynamicHostnameTlv, FadTlv, FloodingAlgoTlv,
+    GrReasonTlv, GracePeriodTlv, LinkAttrStlvCodes, MsdTlv, NodeAdminTagTlv,
+    PrefixSidFlags, RouterFuncCapsTlv, RouterInfoCapsTlv, SidLabelRangeTlv,
+    SrAlgoTlv, SrLocalBlockTlv, SrmsPrefTlv, TLV_HDR_SIZE, UnknownTlv,
+    tlv_encode_end, tlv_encode_start, tlv_wire_len,
 };
 
 // OSPFv2 opaque LSA ID.
@@ -105,6 +107,12 @@ pub struct LsaRouterInfo {
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub node_tags: Vec<NodeAdminTagTlv>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fads: Vec<FadTlv>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flooding_algo: Option<FloodingAlgoTlv>,
     pub unknown_tlvs: Vec<UnknownTlv>,
 }
 
@@ -124,6 +132,9 @@ pub struct LsaRouterInfo {
 #[derive(Deserialize, Serialize)]
 pub struct LsaExtPrefix {
     pub prefixes: BTreeMap<Ipv4Network, ExtPrefixTlv>,
+    #[new(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ranges: Vec<ExtPrefixRangeTlv>,
 }
 
 //
@@ -189,6 +200,39 @@ pub enum ExtPrefixRouteType {
     NssaExternal = 7,
 }
 
+//
+// OSPFv2 Extended Prefix Range TLV.
+//
+// Encoding format:
+//
+//  0                   1                   2                   3
+//  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |              Type             |             Length            |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |  Prefix Length |     AF       |         Range Size            |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |     Flags      |                  Reserved                    |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |                Address Prefix (variable)                      |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |                      Sub-TLVs (variable)                      |
+// +-                                                             -+
+// |                             ...                               |
+//
+#[derive(Clone, Debug, Eq, new, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub struct ExtPrefixRangeTlv {
+    pub af: u8,
+    pub range_size: u16,
+    pub flags: ExtPrefixRangeFlags,
+    pub prefix: Ipv4Network,
+    #[new(default)]
+    pub prefix_sids: BTreeMap<IgpAlgoType, PrefixSid>,
+    #[new(default)]
+    pub unknown_tlvs: Vec<UnknownTlv>,
+}
+
 //
 // Prefix-SID Sub-TLV.
 //
@@ -241,6 +285,10 @@ pub struct ExtLinkTlv {
     pub adj_sids: Vec<AdjSid>,
     pub msds: Option<MsdTlv>,
     #[new(default)]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub asla: Vec<AslaStlv>,
+    #[new(default)]
     pub unknown_tlvs: Vec<UnknownTlv>,
 }
 
@@ -492,6 +540,15 @@ impl LsaRouterInfo {
                     let srms_pref = SrmsPrefTlv::decode(tlv_len, &mut buf_tlv)?;
                     router_info.srms_pref.get_or_insert(srms_pref);
                 }
+                Some(RouterInfoTlvType::FlexAlgoDefinition) => {
+                    let fad = FadTlv::decode(tlv_len, &mut buf_tlv)?;
+                    router_info.fads.push(fad);
+                }
+                Some(RouterInfoTlvType::FloodingAlgo) => {
+                    let flooding_algo =
+                        FloodingAlgoTlv::decode(tlv_len, &mut buf_tlv)?;
+                    router_info.flooding_algo.get_or_insert(flooding_algo);
+                }
                 _ => {
                     // Save unknown TLV.
                     router_info
@@ -532,6 +589,12 @@ impl LsaRouterInfo {
         if let Some(srms_pref) = &self.srms_pref {
             srms_pref.encode(buf);
         }
+        for fad in &self.fads {
+            fad.encode(buf);
+        }
+        if let Some(flooding_algo) = &self.flooding_algo {
+            flooding_algo.encode(buf);
+        }
     }
 }
 
@@ -571,6 +634,12 @@ impl LsaExtPrefix {
                         e.insert(prefix_tlv);
                     }
                 }
+                Some(ExtPrefixTlvType::ExtPrefixRange) => {
+                    // Decode TLV.
+                    let range_tlv =
+                        ExtPrefixRangeTlv::decode(tlv_len, &mut buf_tlv)?;
+                    lsa.ranges.push(range_tlv);
+                }
                 _ => {
                     // Ignore unknown TLV.
                 }
@@ -584,6 +653,9 @@ impl LsaExtPrefix {
         for prefix_tlv in self.prefixes.values() {
             prefix_tlv.encode(buf);
         }
+        for range_tlv in &self.ranges {
+            range_tlv.encode(buf);
+        }
     }
 }
 
@@ -677,39 +749,13 @@ impl ExtPrefixTlv {
             let mut buf_stlv = buf.copy_to_bytes(stlv_wlen as usize);
             match stlv_etype {
                 Some(ExtPrefixStlvType::PrefixSid) => {
-                    let flags = buf_stlv.try_get_u8()?;
-                    let flags = PrefixSidFlags::from_bits_truncate(flags);
-                    let _reserved = buf_stlv.try_get_u8()?;
-                    let mtid = buf_stlv.try_get_u8()?;
-                    if mtid != 0 {
-                        // Unsupported MT-ID - ignore.
-                        continue;
-                    }
-                    let algo = buf_stlv.try_get_u8()?;
-                    let Some(algo) = IgpAlgoType::from_u8(algo) else {
-                        // Unsupported algorithm - ignore.
+                    let Some(prefix_sid) = PrefixSid::decode(&mut buf_stlv)?
+                    else {
                         continue;
                     };
-
-                    // Parse SID (variable length).
-                    let sid = if !flags
-                        .intersects(PrefixSidFlags::V | PrefixSidFlags::L)
-                    {
-                        Sid::Index(buf_stlv.try_get_u32()?)
-                    } else if flags
-                        .contains(PrefixSidFlags::V | PrefixSidFlags::L)
-                    {
-                        let label = buf_stlv.try_get_u24()? & Label::VALUE_MASK;
-                        Sid::Label(Label::new(label))
-                    } else {
-                        // Invalid V-Flag and L-Flag combination - ignore.
-                        continue;
-                    };
-
-                    let prefix_sid = PrefixSid::new(flags, algo, sid);
                     // TODO: in case there are multiple Prefix-SIDs for the same
                     // algorithm, all of them need to be ignored.
-                    tlv.prefix_sids.insert(algo, prefix_sid);
+                    tlv.prefix_sids.insert(prefix_sid.algo, prefix_sid);
                 }
                 _ => {
                     // Save unknown Sub-TLV.
@@ -730,17 +776,81 @@ impl ExtPrefixTlv {
         buf.put_u8(self.flags.bits());
         buf.put_ipv4(&self.prefix.ip());
         // Prefix-SID Sub-TLVs.
-        for (algo, prefix_sid) in &self.prefix_sids {
-            let start_pos = tlv_encode_start(buf, ExtPrefixStlvType::PrefixSid);
-            buf.put_u8(prefix_sid.flags.bits());
-            buf.put_u8(0);
-            buf.put_u8(0);
-            buf.put_u8(*algo as u8);
-            match prefix_sid.sid {
-                Sid::Index(index) => buf.put_u32(index),
-                Sid::Label(label) => buf.put_u24(label.get()),
+        for prefix_sid in self.prefix_sids.values() {
+            prefix_sid.encode(buf);
+        }
+        tlv_encode_end(buf, start_pos);
+    }
+}
+
+// ===== impl ExtPrefixRangeTlv =====
+
+impl ExtPrefixRangeTlv {
+    pub const BASE_LENGTH: u16 = 12;
+
+    fn decode(tlv_len: u16, buf: &mut Bytes) -> DecodeResult<Self> {
+        // Validate TLV length.
+        if tlv_len < Self::BASE_LENGTH {
+            return Err(DecodeError::InvalidTlvLength(tlv_len));
+        }
+
+        // Parse fixed fields.
+        let prefixlen = buf.try_get_u8()?;
+        let af = buf.try_get_u8()?;
+        let range_size = buf.try_get_u16()?;
+        let flags = buf.try_get_u8()?;
+        let flags = ExtPrefixRangeFlags::from_bits_truncate(flags);
+        let _reserved = buf.try_get_u24()?;
+        let addr = buf.try_get_ipv4()?;
+        let prefix = Ipv4Network::new(addr, prefixlen)
+            .map_err(|_| DecodeError::InvalidIpPrefix)?;
+        let mut tlv = ExtPrefixRangeTlv::new(af, range_size, flags, prefix);
+
+        // Parse Sub-TLVs.
+        while buf.remaining() >= TLV_HDR_SIZE as usize {
+            // Parse Sub-TLV type.
+            let stlv_type = buf.try_get_u16()?;
+            let stlv_etype = ExtPrefixStlvType::from_u16(stlv_type);
+
+            // Parse and validate Sub-TLV length.
+            let stlv_len = buf.try_get_u16()?;
+            let stlv_wlen = tlv_wire_len(stlv_len);
+            if stlv_wlen as usize > buf.remaining() {
+                return Err(DecodeError::InvalidTlvLength(stlv_len));
+            }
+
+            // Parse Sub-TLV value.
+            let mut buf_stlv = buf.copy_to_bytes(stlv_wlen as usize);
+            match stlv_etype {
+                Some(ExtPrefixStlvType::PrefixSid) => {
+                    let Some(prefix_sid) = PrefixSid::decode(&mut buf_stlv)?
+                    else {
+                        continue;
+                    };
+                    tlv.prefix_sids.insert(prefix_sid.algo, prefix_sid);
+                }
+                _ => {
+                    // Save unknown Sub-TLV.
+                    tlv.unknown_tlvs
+                        .push(UnknownTlv::new(stlv_type, stlv_len, buf_stlv));
+                }
             }
-            tlv_encode_end(buf, start_pos);
+        }
+
+        Ok(tlv)
+    }
+
+    fn encode(&self, buf: &mut BytesMut) {
+        let start_pos = tlv_encode_start(buf, ExtPrefixTlvType::ExtPrefixRange);
+        buf.put_u8(self.prefix.prefix());
+        buf.put_u8(self.af);
+        buf.put_u16(self.range_size);
+        buf.put_u8(self.flags.bits());
+        buf.put_u24(0);
+        buf.put_ipv4(&self.prefix.ip());
+        // Prefix-SID Sub-TLVs.
+        for prefix_sid in self.prefix_sids.values() {
+            prefix_sid.encode(buf);
         }
         tlv_encode_end(buf, start_pos);
     }
@@ -829,6 +939,14 @@ impl ExtLinkTlv {
                         AdjSid::new(flags, weight, nbr_router_id, sid);
                     tlv.adj_sids.push(adj_sid);
                 }
+                Some(ExtLinkStlvType::Asla) => {
+                    if let Some(asla) = AslaStlv::decode::<ExtLinkStlvType>(
+                        stlv_len,
+                        &mut buf_stlv,
+                    )? {
+                        tlv.asla.push(asla);
+                    }
+                }
                 _ => {
                     // Save unknown Sub-TLV.
                     tlv.unknown_tlvs
@@ -871,12 +989,72 @@ impl ExtLinkTlv {
         if let Some(msds) = &self.msds {
             msds.encode(ExtLinkStlvType::LinkMsd as u16, buf);
         }
+        // ASLA Sub-TLVs.
+        for asla in &self.asla {
+            asla.encode::<ExtLinkStlvType>(ExtLinkStlvType::Asla, buf);
+        }
         tlv_encode_end(buf, start_pos);
     }
 }
 
+// ===== impl ExtLinkStlvType =====
+
+impl LinkAttrStlvCodes for ExtLinkStlvType {
+    const SRLG: u16 = ExtLinkStlvType::Srlg as u16;
+    const MIN_MAX_LINK_DELAY: u16 = ExtLinkStlvType::MinMaxLinkDelay as u16;
+    const ADMIN_GROUP: u16 = ExtLinkStlvType::AdminGroup as u16;
+    const EXT_ADMIN_GROUP: u16 = ExtLinkStlvType::ExtAdminGroup as u16;
+    const TE_METRIC: u16 = ExtLinkStlvType::TeMetric as u16;
+}
+
 // ===== impl PrefixSid =====
 
+impl PrefixSid {
+    // Decodes a Prefix-SID Sub-TLV value, returning `None` if the Sub-TLV
+    // should be ignored.
+    fn decode(buf: &mut Bytes) -> DecodeResult<Option<Self>> {
+        let flags = buf.try_get_u8()?;
+        let flags = PrefixSidFlags::from_bits_truncate(flags);
+        let _reserved = buf.try_get_u8()?;
+        let mtid = buf.try_get_u8()?;
+        if mtid != 0 {
+            // Unsupported MT-ID - ignore.
+            return Ok(None);
+        }
+        let algo = buf.try_get_u8()?;
+        let Some(algo) = IgpAlgoType::from_u8(algo) else {
+            // Unsupported algorithm - ignore.
+            return Ok(None);
+        };
+
+        // Parse SID (variable length).
+        let sid = if !flags.intersects(PrefixSidFlags::V | PrefixSidFlags::L) {
+            Sid::Index(buf.try_get_u32()?)
+        } else if flags.contains(PrefixSidFlags::V | PrefixSidFlags::L) {
+            let label = buf.try_get_u24()? & Label::VALUE_MASK;
+            Sid::Label(Label::new(label))
+        } else {
+            // Invalid V-Flag and L-Flag combination - ignore.
+            return Ok(None);
+        };
+
+        Ok(Some(PrefixSid::new(flags, algo, sid)))
+    }
+
+    fn encode(&self, buf: &mut BytesMut) {
+        let start_pos = tlv_encode_start(buf, ExtPrefixStlvType::PrefixSid);
+        buf.put_u8(self.flags.bits());
+        buf.put_u8(0);
+        buf.put_u8(0);
+        buf.put_u8(self.algo.to_u8().unwrap());
+        match self.sid {
+            Sid::Index(index) => buf.put_u32(index),
+            Sid::Label(label) => buf.put_u24(label.get()),
+        }
+        tlv_encode_end(buf, start_pos);
+    }
+}
+
 impl PrefixSidVersion for PrefixSid {
     fn flags(&self) -> PrefixSidFlags {
         self.flags
@@ -886,9 +1064,17 @@ impl PrefixSidVersion for PrefixSid {
         &mut self.flags
     }
 
+    fn algo(&self) -> IgpAlgoType {
+        self.algo
+    }
+
     fn sid(&self) -> Sid {
         self.sid
     }
+
+    fn sid_mut(&mut self) -> &mut Sid {
+        &mut self.sid
+    }
 }
 
 // ===== impl AdjSid =====
@@ -10,12 +10,13 @@ pub mod lsa;
 pub mod lsa_opaque;
 
 use std::collections::BTreeSet;
-use std::net::Ipv4Addr;
-use std::sync::atomic;
+use std::net::{IpAddr, Ipv4Addr};
 
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 use holo_utils::bytes::{BytesExt, BytesMutExt, TLS_BUF};
+use holo_utils::crypto::CryptoProtocolId;
 use holo_utils::ip::{AddressFamily, Ipv4AddrExt};
+use holo_utils::keychain::Key;
 use internet_checksum::Checksum;
 use num_traits::FromPrimitive;
 use serde::{Deserialize, Serialize};
@@ -76,6 +77,7 @@ pub enum PacketHdrAuth {
         key_id: u8,
         auth_len: u8,
         seqno: u32,
+        ext_seqno: bool,
     },
 }
 
@@ -235,6 +237,10 @@ impl OptionsVersion<Ospfv2> for Options {
     fn l_bit(&self) -> bool {
         self.contains(Options::L)
     }
+
+    fn n_bit(&self) -> bool {
+        self.contains(Options::NP)
+    }
 }
 
 // ===== impl PacketHdr =====
@@ -243,6 +249,10 @@ impl PacketHdr {
     pub const VERSION: u8 = 2;
     pub const CKSUM_RANGE: std::ops::Range<usize> = 12..14;
     pub const AUTH_RANGE: std::ops::Range<usize> = 16..24;
+    pub const AUTH_SEQNO_RANGE: std::ops::Range<usize> = 20..24;
+    // Length of the lower-order 32 bits of the RFC 7474 extended sequence
+    // number, which precede the message digest.
+    pub const AUTH_EXT_SEQNO_LEN: u16 = 4;
 }
 
 impl PacketHdrVersion<Ospfv2> for PacketHdr {
@@ -294,7 +304,10 @@ impl PacketHdrVersion<Ospfv2> for PacketHdr {
                 let _ = buf.try_get_u64()?;
                 PacketHdrAuth::Null
             }
-            Some(AuthType::Cryptographic) => {
+            Some(
+                au_type @ (AuthType::Cryptographic
+                | AuthType::CryptographicExtSeqno),
+            ) => {
                 let _ = buf.try_get_u16()?;
                 let key_id = buf.try_get_u8()?;
                 let auth_len = buf.try_get_u8()?;
@@ -303,6 +316,7 @@ impl PacketHdrVersion<Ospfv2> for PacketHdr {
                     key_id,
                     auth_len,
                     seqno,
+                    ext_seqno: au_type == AuthType::CryptographicExtSeqno,
                 }
             }
             _ => {
@@ -333,6 +347,15 @@ impl PacketHdrVersion<Ospfv2> for PacketHdr {
         buf.put_u16(0);
         // Authentication.
         match auth {
+            Some(auth) if auth.ext_seqno => {
+                buf.put_u16(AuthType::CryptographicExtSeqno as u16);
+                buf.put_u16(0);
+                buf.put_u8(auth.key.id as u8);
+                buf.put_u8(auth.key.algo.digest_size());
+                // The sequence number will be initialized later, along with
+                // its lower-order 32 bits in the authentication trailer.
+                buf.put_u32(0);
+            }
             Some(auth) => {
                 buf.put_u16(AuthType::Cryptographic as u16);
                 buf.put_u16(0);
@@ -340,9 +363,7 @@ impl PacketHdrVersion<Ospfv2> for PacketHdr {
                 buf.put_u8(auth.key.algo.digest_size());
                 // RFC 5709 does not include provisions for handling sequence
                 // number overflows.
-                buf.put_u32(
-                    auth.seqno.fetch_add(1, atomic::Ordering::Relaxed) as u32
-                );
+                buf.put_u32(auth.seqno.next() as u32);
             }
             None => {
                 buf.put_u16(AuthType::Null as u16);
@@ -830,10 +851,26 @@ impl PacketVersion<Self> for Ospfv2 {
                 key_id,
                 auth_len,
                 seqno,
+                ext_seqno,
             } => {
+                // Discard the packet if the use of RFC 7474 extended sequence
+                // numbers doesn't match the interface's configuration.
+                let auth = auth.unwrap();
+                if auth.ext_seqno != *ext_seqno {
+                    return Err(DecodeError::AuthTypeMismatch);
+                }
+
+                // When RFC 7474 is in use, the lower-order 32 bits of the
+                // sequence number precede the message digest.
+                let digest_start = if *ext_seqno {
+                    pkt_len as usize + PacketHdr::AUTH_EXT_SEQNO_LEN as usize
+                } else {
+                    pkt_len as usize
+                };
+
                 // Validate that the buffer is large enough to contain the
                 // authentication trailer announced by the header.
-                let trailer_end = (pkt_len as usize)
+                let trailer_end = digest_start
                     .checked_add(*auth_len as usize)
                     .ok_or(DecodeError::AuthLenError(*auth_len as u16))?;
                 if trailer_end > data.len() {
@@ -841,15 +878,28 @@ impl PacketVersion<Self> for Ospfv2 {
                 }
 
                 // Get the authentication trailer.
-                let auth_trailer = &data[pkt_len as usize..trailer_end];
+                let auth_trailer = &data[digest_start..trailer_end];
 
                 // Compute message digest.
-                let data = &data[..pkt_len as usize];
+                let data = &data[..digest_start];
 
-                validate_digest(*key_id, *auth_len, auth, auth_trailer, data)?;
+                validate_digest(
+                    *key_id,
+                    *auth_len,
+                    Some(auth),
+                    auth_trailer,
+                    data,
+                )?;
 
                 // Authentication succeeded.
-                Ok(Some((*seqno).into()))
+                let mut seqno = *seqno as u64;
+                if *ext_seqno {
+                    let seqno_low = &data[pkt_len as usize..];
+                    let seqno_low =
+                        u32::from_be_bytes(seqno_low.try_into().unwrap());
+                    seqno = (seqno << 32) | seqno_low as u64;
+                }
+                Ok(Some(seqno))
             }
         }
     }
@@ -859,13 +909,18 @@ impl PacketVersion<Self> for Ospfv2 {
         auth: AuthEncodeCtx<'_>,
         lls: Option<&LlsData>,
     ) {
-        let digest = auth::message_digest(
-            buf,
-            auth.key.algo,
-            &auth.key.string,
-            None,
-            None,
-        );
+        // RFC 7474: Encode the 64-bit sequence number, with its higher-order
+        // 32 bits in the packet header and its lower-order 32 bits preceding
+        // the message digest.
+        if auth.ext_seqno {
+            let pkt_type = PacketType::from_u8(buf[1]).unwrap();
+            let seqno = auth.seqno.next_pkt_type(pkt_type);
+            buf[PacketHdr::AUTH_SEQNO_RANGE]
+                .copy_from_slice(&((seqno >> 32) as u32).to_be_bytes());
+            buf.put_u32(seqno as u32);
+        }
+
+        let digest = auth_digest(buf, auth.key, auth.ext_seqno, &auth.src_addr);
         buf.put_slice(&digest);
 
         // RFC 5613 Section 2: "To perform link-local signaling (LLS), OSPF
@@ -925,8 +980,7 @@ pub(crate) fn validate_digest(
     }
 
     // Compute message digest.
-    let digest =
-        auth::message_digest(data, auth_key.algo, &auth_key.string, None, None);
+    let digest = auth_digest(data, auth_key, auth.ext_seqno, &auth.src_addr);
 
     // Check if the received message digest is valid.
     if *digest_rx != digest {
@@ -935,3 +989,26 @@ pub(crate) fn validate_digest(
 
     Ok(())
 }
+
+// Computes the message digest of an OSPFv2 packet or LLS data block.
+pub(crate) fn auth_digest(
+    data: &[u8],
+    key: &Key,
+    ext_seqno: bool,
+    src: &IpAddr,
+) -> Vec<u8> {
+    // RFC 7474: when extended sequence numbers are in use, the OSPFv2
+    // Cryptographic Protocol ID is appended to the authentication key, and the
+    // IP source address is included in the Apad.
+    if ext_seqno {
+        auth::message_digest(
+            data,
+            key.algo,
+            &key.string,
+            Some(CryptoProtocolId::Ospfv2),
+            Some(src),
+        )
+    } else {
+        auth::message_digest(data, key.algo, &key.string, None, None)
+    }
+}
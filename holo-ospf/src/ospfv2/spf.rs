@@ -23,18 +23,19 @@ use crate::ospfv2::packet::iana::{
     LsaRouterFlags, LsaRouterLinkType, LsaTypeCode, Options,
 };
 use crate::ospfv2::packet::lsa::{
-    LsaAsExternalFlags, LsaBody, LsaRouterLink, LsaType,
+    LsaAsExternalFlags, LsaBody, LsaRouter, LsaRouterLink, LsaType,
 };
 use crate::ospfv2::packet::lsa_opaque::{
     ExtPrefixRouteType, LsaOpaque, PrefixSid,
 };
 use crate::packet::lsa::{Lsa, LsaHdrVersion, LsaKey};
+use crate::packet::tlv::AslaStlv;
 use crate::route::{Nexthop, NexthopKey, Nexthops};
 use crate::spf::{
     SpfComputation, SpfExternalNetwork, SpfInterAreaNetwork,
     SpfInterAreaRouter, SpfIntraAreaNetwork, SpfLink, SpfPartialComputation,
-    SpfRouterInfo, SpfTriggerLsa, SpfVersion, Vertex, VertexIdVersion,
-    VertexLsaVersion,
+    SpfPrefixRange, SpfRouterInfo, SpfTriggerLsa, SpfVersion, Vertex,
+    VertexIdVersion, VertexLsaVersion,
 };
 use crate::version::Ospfv2;
 
@@ -99,31 +100,37 @@ impl SpfVersion<Self> for Ospfv2 {
     fn spf_computation_type(
         trigger_lsas: &[SpfTriggerLsa<Self>],
     ) -> SpfComputation<Self> {
-        // Router-LSA and Network-LSA changes represent topological changes,
-        // hence a full SPF run is required to recompute the SPT.
-        //
-        // Certain Opaque-LSA changes don't strictly require a full SPF run, but
-        // doing so greatly simplify things (e.g. no need to keep track of which
-        // routes are affected by which SRGBs).
-        if trigger_lsas.iter().map(|tlsa| &tlsa.new).any(|lsa| {
-            matches!(
-                lsa.body,
-                LsaBody::Router(_)
-                    | LsaBody::Network(_)
-                    | LsaBody::OpaqueArea(
-                        LsaOpaque::RouterInfo(_)
-                            | LsaOpaque::ExtPrefix(_)
-                            | LsaOpaque::ExtLink(_)
-                    )
-                    | LsaBody::OpaqueAs(LsaOpaque::ExtPrefix(_))
-            )
-        }) {
-            return SpfComputation::Full;
-        }
-
         // In OSPFv2 intra-area information is embedded in Router-LSAs and
         // Network-LSAs.
-        let intra = Default::default();
+        let mut intra = BTreeSet::new();
+        for tlsa in trigger_lsas {
+            match &tlsa.new.body {
+                // Router-LSA changes affecting only stub links don't change
+                // the SPT, hence only the affected intra-area routes need to
+                // be recomputed.
+                LsaBody::Router(_) => match router_lsa_stub_changes(tlsa) {
+                    Some(prefixes) => intra.extend(prefixes),
+                    None => return SpfComputation::Full,
+                },
+                // Other Router-LSA and Network-LSA changes represent
+                // topological changes, hence a full SPF run is required to
+                // recompute the SPT.
+                //
+                // Certain Opaque-LSA changes don't strictly require a full SPF
+                // run, but doing so greatly simplify things (e.g. no need to
+                // keep track of which routes are affected by which SRGBs).
+                LsaBody::Network(_)
+                | LsaBody::OpaqueArea(
+                    LsaOpaque::RouterInfo(_)
+                    | LsaOpaque::ExtPrefix(_)
+                    | LsaOpaque::ExtLink(_),
+                )
+                | LsaBody::OpaqueAs(LsaOpaque::ExtPrefix(_)) => {
+                    return SpfComputation::Full;
+                }
+                _ => (),
+            }
+        }
 
         // Check Type-3 Summary LSA changes.
         let inter_network = trigger_lsas
@@ -135,7 +142,9 @@ impl SpfVersion<Self> for Ospfv2 {
                     .map(move |lsa_body| (lsa.hdr, lsa_body))
             })
             .filter_map(|(lsa_hdr, lsa_body)| {
-                Ipv4Network::with_netmask(lsa_hdr.lsa_id, lsa_body.mask).ok()
+                Ipv4Network::with_netmask(lsa_hdr.lsa_id, lsa_body.mask)
+                    .ok()
+                    .map(|prefix| prefix.apply_mask())
             })
             .collect();
 
@@ -147,17 +156,20 @@ impl SpfVersion<Self> for Ospfv2 {
             .map(|lsa_hdr| lsa_hdr.lsa_id)
             .collect::<BTreeSet<_>>();
 
-        // Check AS-External LSA changes.
+        // Check AS-External and NSSA LSA changes.
         let external = trigger_lsas
             .iter()
             .map(|tlsa| &tlsa.new)
             .filter_map(|lsa| {
                 lsa.body
                     .as_as_external()
+                    .or(lsa.body.as_nssa_external())
                     .map(move |lsa_body| (lsa.hdr, lsa_body))
             })
             .filter_map(|(lsa_hdr, lsa_body)| {
-                Ipv4Network::with_netmask(lsa_hdr.lsa_id, lsa_body.mask).ok()
+                Ipv4Network::with_netmask(lsa_hdr.lsa_id, lsa_body.mask)
+                    .ok()
+                    .map(|prefix| prefix.apply_mask())
             })
             .collect();
 
@@ -459,6 +471,21 @@ impl SpfVersion<Self> for Ospfv2 {
         }
     }
 
+    fn link_asla<'a>(
+        vertex_lsa: &'a VertexLsa,
+        link: &'a LsaRouterLink,
+        area: &'a Area<Ospfv2>,
+    ) -> &'a [AslaStlv] {
+        // In OSPFv2, link attributes are advertised in the Extended Link
+        // Opaque LSA that corresponds to the router link.
+        area.state
+            .version
+            .ext_link_db
+            .get(&(vertex_lsa.router_id(), link.link_id, link.link_data))
+            .map(|link_tlv| link_tlv.asla.as_slice())
+            .unwrap_or_default()
+    }
+
     fn intra_area_networks<'a>(
         area: &'a Area<Self>,
         _extended_lsa: bool,
@@ -549,9 +576,12 @@ impl SpfVersion<Self> for Ospfv2 {
             .filter(|lsa| !lsa.hdr.is_maxage())
             .filter_map(|lsa| {
                 let lsa_body = lsa.body.as_summary_network().unwrap();
+                // The Link State ID might have host bits set (RFC 2328 -
+                // Appendix E).
                 let prefix =
                     Ipv4Network::with_netmask(lsa.hdr.lsa_id, lsa_body.mask)
-                        .ok()?;
+                        .ok()?
+                        .apply_mask();
                 let prefix_sids = route_prefix_sids(
                     area,
                     lsa.hdr.adv_rtr,
@@ -599,9 +629,42 @@ impl SpfVersion<Self> for Ospfv2 {
             .filter(|lsa| !lsa.hdr.is_maxage())
             .filter_map(|lsa| {
                 let lsa_body = lsa.body.as_as_external().unwrap();
+                // The Link State ID might have host bits set (RFC 2328 -
+                // Appendix E).
+                let prefix =
+                    Ipv4Network::with_netmask(lsa.hdr.lsa_id, lsa_body.mask)
+                        .ok()?
+                        .apply_mask();
+
+                Some(SpfExternalNetwork {
+                    adv_rtr: lsa.hdr.adv_rtr,
+                    e_bit: lsa_body.flags.contains(LsaAsExternalFlags::E),
+                    prefix,
+                    prefix_options: Default::default(),
+                    metric: lsa_body.metric,
+                    fwd_addr: lsa_body.fwd_addr,
+                    tag: Some(lsa_body.tag),
+                    propagate: false,
+                })
+            })
+    }
+
+    fn nssa_networks<'a>(
+        lsdb: &'a Lsdb<Self>,
+        _extended_lsa: bool,
+        lsa_entries: &'a Arena<LsaEntry<Self>>,
+    ) -> impl Iterator<Item = SpfExternalNetwork<Self>> + 'a {
+        lsdb.iter_by_type(lsa_entries, LsaTypeCode::NssaExternal.into())
+            .map(|(_, lse)| &lse.data)
+            .filter(|lsa| !lsa.hdr.is_maxage())
+            .filter_map(|lsa| {
+                let lsa_body = lsa.body.as_nssa_external().unwrap();
+                // The Link State ID might have host bits set (RFC 2328 -
+                // Appendix E).
                 let prefix =
                     Ipv4Network::with_netmask(lsa.hdr.lsa_id, lsa_body.mask)
-                        .ok()?;
+                        .ok()?
+                        .apply_mask();
 
                 Some(SpfExternalNetwork {
                     adv_rtr: lsa.hdr.adv_rtr,
@@ -611,10 +674,27 @@ impl SpfVersion<Self> for Ospfv2 {
                     metric: lsa_body.metric,
                     fwd_addr: lsa_body.fwd_addr,
                     tag: Some(lsa_body.tag),
+                    propagate: lsa.hdr.options.contains(Options::NP),
                 })
             })
     }
 
+    fn prefix_ranges<'a>(
+        area: &'a Area<Self>,
+    ) -> impl Iterator<Item = SpfPrefixRange<Self>> + 'a {
+        area.state.version.ext_prefix_range_db.iter().filter_map(
+            |(adv_rtr, tlv)| {
+                let prefix_sid = tlv.prefix_sids.get(&IgpAlgoType::Spf)?;
+                Some(SpfPrefixRange {
+                    adv_rtr: *adv_rtr,
+                    prefix: tlv.prefix,
+                    range_size: tlv.range_size,
+                    prefix_sid: *prefix_sid,
+                })
+            },
+        )
+    }
+
     fn area_router_information<'a>(
         lsdb: &'a Lsdb<Self>,
         router_id: Ipv4Addr,
@@ -649,6 +729,14 @@ impl SpfVersion<Self> for Ospfv2 {
             // Multiple occurrences of the SID/Label Range TLV MAY be advertised
             // in order to advertise multiple ranges.
             ri_agg.srgb.extend(&ri_lsa.srgb);
+
+            // Flexible Algorithm Definitions.
+            ri_agg.fads.extend(&ri_lsa.fads);
+
+            // Flooding reduction algorithm.
+            if let Some(flooding_algo) = &ri_lsa.flooding_algo {
+                ri_agg.flooding_algo.get_or_insert(flooding_algo);
+            }
         }
 
         ri_agg
@@ -659,6 +747,8 @@ impl SpfVersion<Self> for Ospfv2 {
         lsa_entries: &Arena<LsaEntry<Self>>,
     ) {
         area.state.version.ext_prefix_db.clear();
+        area.state.version.ext_link_db.clear();
+        area.state.version.ext_prefix_range_db.clear();
 
         for (adv_rtr, lsa_body) in area
             .state
@@ -685,6 +775,21 @@ impl SpfVersion<Self> for Ospfv2 {
                         .entry((adv_rtr, *prefix))
                         .or_insert_with(|| tlv.clone());
                 }
+                area.state.version.ext_prefix_range_db.extend(
+                    lsa_body.ranges.iter().map(|tlv| (adv_rtr, tlv.clone())),
+                );
+            }
+            if let Some(link_tlv) = lsa_body
+                .as_ext_link()
+                .and_then(|lsa_body| lsa_body.link.as_ref())
+            {
+                // As with the Extended Prefix Opaque LSA, the Extended Link
+                // TLV in the LSA with the smallest Opaque ID is used.
+                area.state
+                    .version
+                    .ext_link_db
+                    .entry((adv_rtr, link_tlv.link_id, link_tlv.link_data))
+                    .or_insert_with(|| link_tlv.clone());
             }
         }
     }
@@ -692,16 +797,70 @@ impl SpfVersion<Self> for Ospfv2 {
 
 // ===== helper functions =====
 
+// Returns the stub networks that were added, removed or modified in the
+// provided Router-LSA update, or `None` if the update isn't limited to stub
+// links (i.e. the SPT might have changed).
+fn router_lsa_stub_changes(
+    tlsa: &SpfTriggerLsa<Ospfv2>,
+) -> Option<BTreeSet<Ipv4Network>> {
+    let old_lsa = tlsa.old.as_ref()?;
+    let new_lsa = &tlsa.new;
+    if old_lsa.hdr.is_maxage()
+        || new_lsa.hdr.is_maxage()
+        || old_lsa.hdr.options != new_lsa.hdr.options
+    {
+        return None;
+    }
+
+    let old_body = old_lsa.body.as_router()?;
+    let new_body = new_lsa.body.as_router()?;
+    if old_body.flags != new_body.flags {
+        return None;
+    }
+
+    // Check whether the non-stub links have changed.
+    let non_stub = |link: &&LsaRouterLink| {
+        link.link_type != LsaRouterLinkType::StubNetwork
+    };
+    if !old_body
+        .links
+        .iter()
+        .filter(non_stub)
+        .eq(new_body.links.iter().filter(non_stub))
+    {
+        return None;
+    }
+
+    // Compute the stub networks that have changed.
+    let stub_links = |lsa_body: &LsaRouter| {
+        lsa_body
+            .links
+            .iter()
+            .filter(|link| link.link_type == LsaRouterLinkType::StubNetwork)
+            .filter_map(|link| {
+                Ipv4Network::with_netmask(link.link_id, link.link_data)
+                    .ok()
+                    .map(|prefix| (prefix.apply_mask(), link.metric))
+            })
+            .collect::<BTreeSet<_>>()
+    };
+    let old_stubs = stub_links(old_body);
+    let new_stubs = stub_links(new_body);
+    let prefixes = old_stubs
+        .symmetric_difference(&new_stubs)
+        .map(|(prefix, _)| *prefix)
+        .collect();
+
+    Some(prefixes)
+}
+
 fn route_prefix_sids(
     area: &Area<Ospfv2>,
     adv_rtr: Ipv4Addr,
     prefix: &Ipv4Network,
     route_type: ExtPrefixRouteType,
 ) -> BTreeMap<IgpAlgoType, PrefixSid> {
-    let mut prefix_sids = BTreeMap::new();
-
-    if let Some(prefix_sid) = area
-        .state
+    area.state
         .version
         .ext_prefix_db
         .get(&(adv_rtr, *prefix))
@@ -709,10 +868,172 @@ fn route_prefix_sids(
             route_type == tlv.route_type
                 || route_type == ExtPrefixRouteType::Unspecified
         })
-        .and_then(|tlv| tlv.prefix_sids.get(&IgpAlgoType::Spf))
-    {
-        prefix_sids.insert(IgpAlgoType::Spf, *prefix_sid);
+        .map(|tlv| tlv.prefix_sids.clone())
+        .unwrap_or_default()
+}
+
+// ===== unit tests =====
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lsdb::{LSA_INIT_SEQ_NO, LSA_MAX_AGE, LsaLogId};
+
+    const ADV_RTR: Ipv4Addr = Ipv4Addr::new(1, 1, 1, 1);
+
+    fn p2p_link(nbr_id: Ipv4Addr, metric: u16) -> LsaRouterLink {
+        LsaRouterLink::new(
+            LsaRouterLinkType::PointToPoint,
+            nbr_id,
+            Ipv4Addr::new(10, 0, 1, 1),
+            metric,
+        )
     }
 
-    prefix_sids
+    fn stub_link(prefix: &str, metric: u16) -> LsaRouterLink {
+        let prefix: Ipv4Network = prefix.parse().unwrap();
+        LsaRouterLink::new(
+            LsaRouterLinkType::StubNetwork,
+            prefix.ip(),
+            prefix.mask(),
+            metric,
+        )
+    }
+
+    fn router_lsa(
+        age: u16,
+        seq_no: u32,
+        flags: LsaRouterFlags,
+        links: Vec<LsaRouterLink>,
+    ) -> Arc<Lsa<Ospfv2>> {
+        let body = LsaBody::Router(LsaRouter { flags, links });
+        let lsa =
+            Lsa::new(age, Some(Options::E), ADV_RTR, ADV_RTR, seq_no, body);
+        Arc::new(lsa)
+    }
+
+    fn trigger_lsa(
+        old: Option<Arc<Lsa<Ospfv2>>>,
+        new: Arc<Lsa<Ospfv2>>,
+    ) -> SpfTriggerLsa<Ospfv2> {
+        let log_id = LsaLogId {
+            area_id: Some(Ipv4Addr::UNSPECIFIED),
+            lsa_type: new.hdr.lsa_type,
+            lsa_id: new.hdr.lsa_id,
+            adv_rtr: new.hdr.adv_rtr,
+            seq_no: new.hdr.seq_no,
+        };
+        SpfTriggerLsa::new(old, new, log_id)
+    }
+
+    fn base_links() -> Vec<LsaRouterLink> {
+        vec![
+            p2p_link(Ipv4Addr::new(2, 2, 2, 2), 10),
+            stub_link("10.0.1.0/24", 10),
+            stub_link("1.1.1.1/32", 0),
+        ]
+    }
+
+    fn computation_type(
+        old: Arc<Lsa<Ospfv2>>,
+        new: Arc<Lsa<Ospfv2>>,
+    ) -> SpfComputation<Ospfv2> {
+        Ospfv2::spf_computation_type(&[trigger_lsa(Some(old), new)])
+    }
+
+    // Stub-only changes result in a partial computation limited to the
+    // affected prefixes.
+    #[test]
+    fn stub_only_change() {
+        let flags = LsaRouterFlags::empty();
+        let old = router_lsa(0, LSA_INIT_SEQ_NO, flags, base_links());
+
+        // New stub network.
+        let mut links = base_links();
+        links.push(stub_link("172.16.1.0/24", 10));
+        let new = router_lsa(0, LSA_INIT_SEQ_NO + 1, flags, links);
+        let SpfComputation::Partial(partial) =
+            computation_type(old.clone(), new)
+        else {
+            panic!("expected partial SPF computation");
+        };
+        assert_eq!(
+            partial.intra,
+            BTreeSet::from(["172.16.1.0/24".parse().unwrap()])
+        );
+        assert!(partial.inter_network.is_empty());
+        assert!(partial.inter_router.is_empty());
+        assert!(partial.external.is_empty());
+
+        // Removed stub network.
+        let mut links = base_links();
+        links.pop();
+        let new = router_lsa(0, LSA_INIT_SEQ_NO + 1, flags, links);
+        let SpfComputation::Partial(partial) =
+            computation_type(old.clone(), new)
+        else {
+            panic!("expected partial SPF computation");
+        };
+        assert_eq!(
+            partial.intra,
+            BTreeSet::from(["1.1.1.1/32".parse().unwrap()])
+        );
+
+        // Stub network metric change.
+        let mut links = base_links();
+        links[1].metric = 20;
+        let new = router_lsa(0, LSA_INIT_SEQ_NO + 1, flags, links);
+        let SpfComputation::Partial(partial) = computation_type(old, new)
+        else {
+            panic!("expected partial SPF computation");
+        };
+        assert_eq!(
+            partial.intra,
+            BTreeSet::from(["10.0.1.0/24".parse().unwrap()])
+        );
+    }
+
+    // Topology changes require a full SPF run.
+    #[test]
+    fn topology_change() {
+        let flags = LsaRouterFlags::empty();
+        let old = router_lsa(0, LSA_INIT_SEQ_NO, flags, base_links());
+
+        // New point-to-point link.
+        let mut links = base_links();
+        links.push(p2p_link(Ipv4Addr::new(3, 3, 3, 3), 10));
+        let new = router_lsa(0, LSA_INIT_SEQ_NO + 1, flags, links);
+        assert!(matches!(
+            computation_type(old.clone(), new),
+            SpfComputation::Full
+        ));
+
+        // Point-to-point link metric change.
+        let mut links = base_links();
+        links[0].metric = 20;
+        let new = router_lsa(0, LSA_INIT_SEQ_NO + 1, flags, links);
+        assert!(matches!(
+            computation_type(old.clone(), new),
+            SpfComputation::Full
+        ));
+
+        // Router flags change.
+        let new =
+            router_lsa(0, LSA_INIT_SEQ_NO + 1, LsaRouterFlags::B, base_links());
+        assert!(matches!(
+            computation_type(old.clone(), new),
+            SpfComputation::Full
+        ));
+
+        // LSA flushed.
+        let new = router_lsa(LSA_MAX_AGE, LSA_INIT_SEQ_NO, flags, base_links());
+        assert!(matches!(computation_type(old, new), SpfComputation::Full));
+
+        // New LSA.
+        let new = router_lsa(0, LSA_INIT_SEQ_NO, flags, base_links());
+        assert!(matches!(
+            Ospfv2::spf_computation_type(&[trigger_lsa(None, new)]),
+            SpfComputation::Full
+        ));
+    }
 }
@@ -14,6 +14,7 @@ use crate::interface::Interface;
 use crate::lsdb::LsaEntry;
 use crate::ospfv3::packet::iana::{Options, PrefixOptions};
 use crate::ospfv3::packet::lsa::LsaIntraAreaPrefix;
+use crate::packet::iana::PacketType;
 use crate::packet::lsa::LsaHdrVersion;
 use crate::route::RouteRtr;
 use crate::version::Ospfv3;
@@ -23,6 +24,8 @@ pub struct AreaState {
     // Next inter-area LSA IDs.
     pub next_type3_lsa_id: u32,
     pub next_type4_lsa_id: u32,
+    // Next NSSA LSA ID.
+    pub next_type7_lsa_id: u32,
 }
 
 // ===== impl Ospfv3 =====
@@ -37,6 +40,16 @@ impl AreaVersion<Self> for Ospfv3 {
             options.insert(Options::E);
         }
 
+        // The N-bit is only set in Hello packets sent on NSSA interfaces.
+        if let OptionsLocation::Packet {
+            pkt_type: PacketType::Hello,
+            ..
+        } = location
+            && area.config.area_type == AreaType::Nssa
+        {
+            options.insert(Options::N);
+        }
+
         if let OptionsLocation::Packet { auth: true, .. } = location {
             options.insert(Options::AT);
         }
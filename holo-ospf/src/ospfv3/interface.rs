@@ -23,6 +23,7 @@ use crate::ospfv3::packet::{Hello, PacketHdr};
 use crate::packet::Packet;
 use crate::packet::auth::AuthMethod;
 use crate::packet::iana::PacketType;
+use crate::packet::lls::{ExtendedOptionsFlags, LlsHelloData};
 use crate::version::Ospfv3;
 
 // ===== impl Ospfv3 =====
@@ -67,8 +68,13 @@ impl InterfaceVersion<Self> for Ospfv3 {
         };
 
         let lls = if iface.config.lls_enabled {
-            // TODO: Get LLS configuration.
-            None
+            // Advertise the LR capability, and signal a restart using the RS
+            // bit while graceful restart is in progress (RFC 4811/4812).
+            let mut eof = ExtendedOptionsFlags::LR;
+            if instance.state.gr_restart.is_some() {
+                eof.insert(ExtendedOptionsFlags::RS);
+            }
+            Some(LlsHelloData { eof: Some(eof) })
         } else {
             None
         };
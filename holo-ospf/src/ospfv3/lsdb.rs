@@ -15,15 +15,18 @@ use holo_utils::mpls::Label;
 use holo_utils::sr::{IgpAlgoType, Sid, SidLastHopBehavior, SrCfgEvent};
 use ipnetwork::IpNetwork;
 use itertools::Itertools;
+use num_traits::ToPrimitive;
 
 use crate::area::{
-    Area, AreaType, AreaVersion, BACKBONE_AREA_ID, OptionsLocation,
+    Area, AreaType, AreaVersion, BACKBONE_AREA_ID, NssaTranslatorState,
+    OptionsLocation,
 };
 use crate::collections::{
     AreaIndex, Arena, InterfaceIndex, LsaEntryId, LsdbId, LsdbIndex, lsdb_get,
 };
 use crate::debug::LsaFlushReason;
 use crate::error::Error;
+use crate::flex_algo;
 use crate::instance::{InstanceArenas, InstanceUpView};
 use crate::interface::{Interface, InterfaceType, ism};
 use crate::lsdb::{self, LsaOriginateEvent, LsdbVersion, MAX_LINK_METRIC};
@@ -32,21 +35,21 @@ use crate::ospfv3::packet::iana::{
     LsaFunctionCode, LsaRouterFlags, LsaRouterLinkType, Options, PrefixOptions,
 };
 use crate::ospfv3::packet::lsa::{
-    LsaBody, LsaHdr, LsaInterAreaPrefix, LsaInterAreaRouter,
-    LsaIntraAreaPrefix, LsaIntraAreaPrefixEntry, LsaLink, LsaLinkPrefix,
-    LsaNetwork, LsaRouter, LsaRouterInfo, LsaRouterLink, LsaScopeCode, LsaType,
-    PrefixSid,
+    LsaAsExternal, LsaAsExternalFlags, LsaBody, LsaGrace, LsaHdr,
+    LsaInterAreaPrefix, LsaInterAreaRouter, LsaIntraAreaPrefix,
+    LsaIntraAreaPrefixEntry, LsaLink, LsaLinkPrefix, LsaNetwork, LsaRouter,
+    LsaRouterInfo, LsaRouterLink, LsaScopeCode, LsaType, PrefixSid,
 };
-use crate::packet::iana::RouterInfoCaps;
+use crate::packet::iana::{FloodingAlgo, RouterInfoCaps};
 use crate::packet::lsa::{
     Lsa, LsaHdrVersion, LsaKey, LsaScope, LsaTypeVersion, PrefixSidVersion,
 };
 use crate::packet::tlv::{
-    BierEncapSubStlv, BierStlv, DynamicHostnameTlv, NodeAdminTagTlv,
-    PrefixSidFlags, RouterInfoCapsTlv, SidLabelRangeTlv, SrAlgoTlv,
-    SrLocalBlockTlv,
+    BierEncapSubStlv, BierStlv, DynamicHostnameTlv, FloodingAlgoTlv, GrReason,
+    GrReasonTlv, GracePeriodTlv, NodeAdminTagTlv, PrefixSidFlags,
+    RouterInfoCapsTlv, SidLabelRangeTlv, SrAlgoTlv, SrLocalBlockTlv,
 };
-use crate::route::{SummaryNet, SummaryNetFlags, SummaryRtr};
+use crate::route::{ExternalNet, SummaryNet, SummaryNetFlags, SummaryRtr};
 use crate::version::Ospfv3;
 
 // ===== impl Ospfv3 =====
@@ -82,6 +85,15 @@ impl LsdbVersion<Self> for Ospfv3 {
             return false;
         }
 
+        // Reject NSSA LSAs on non-NSSA areas.
+        if let Some(area_type) = area_type
+            && area_type != AreaType::Nssa
+            && lsa_type.function_code_normalized()
+                == Some(LsaFunctionCode::Nssa)
+        {
+            return false;
+        }
+
         true
     }
 
@@ -188,12 +200,22 @@ impl LsdbVersion<Self> for Ospfv3 {
                 // (Re)originate Intra-area-prefix-LSA(s).
                 lsa_orig_intra_area_prefix(area, instance, arenas);
             }
-            LsaOriginateEvent::InterfaceFlagChange { area_id } => {
+            LsaOriginateEvent::InterfaceFlagChange { area_id }
+            | LsaOriginateEvent::InterfacePrefixSuppressionChange {
+                area_id,
+                ..
+            } => {
                 let (_, area) = arenas.areas.get_by_id(area_id)?;
 
                 // (Re)originate Intra-area-prefix-LSA(s).
                 lsa_orig_intra_area_prefix(area, instance, arenas);
             }
+            LsaOriginateEvent::InterfaceLinkAttrChange { area_id } => {
+                let (_, area) = arenas.areas.get_by_id(area_id)?;
+
+                // (Re)originate Router-LSA(s).
+                lsa_orig_router(area, instance, arenas);
+            }
             LsaOriginateEvent::NeighborToFromFull { area_id, iface_id } => {
                 // (Re)originate Router-LSA(s).
                 let (_, area) = arenas.areas.get_by_id(area_id)?;
@@ -273,7 +295,9 @@ impl LsdbVersion<Self> for Ospfv3 {
                 // reoriginated or flushed.
                 process_self_originated_lsa(instance, arenas, lsdb_id, lse_id)?;
             }
-            LsaOriginateEvent::StubRouterChange => {
+            LsaOriginateEvent::StubRouterChange
+            | LsaOriginateEvent::NssaTranslatorChange
+            | LsaOriginateEvent::AsbrStatusChange => {
                 // (Re)originate Router-LSA(s) in all areas.
                 for area in arenas.areas.iter() {
                     lsa_orig_router(area, instance, arenas);
@@ -312,10 +336,20 @@ impl LsdbVersion<Self> for Ospfv3 {
                             }
                         }
                     }
+                    SrCfgEvent::MappingServerUpdate => {
+                        // The OSPFv3 Extended Prefix Range TLV isn't supported
+                        // yet.
+                    }
                 }
             }
+            LsaOriginateEvent::FloodingReductionChange { area_id } => {
+                // (Re)originate Router-Info-LSA in the area.
+                let (_, area) = arenas.areas.get_by_id(area_id)?;
+                lsa_orig_router_info(area, instance);
+            }
             LsaOriginateEvent::HostnameChange
-            | LsaOriginateEvent::NodeTagsChange => {
+            | LsaOriginateEvent::NodeTagsChange
+            | LsaOriginateEvent::FlexAlgoChange => {
                 // (Re)originate Router-Info-LSA(s) in all areas.
                 for area in arenas.areas.iter() {
                     lsa_orig_router_info(area, instance);
@@ -338,11 +372,67 @@ impl LsdbVersion<Self> for Ospfv3 {
                     }
                 }
             },
+            LsaOriginateEvent::GrRestartExit => {
+                // (Re)originate all self-originated LSAs, since their
+                // origination was suppressed during the graceful restart.
+                for area in arenas.areas.iter() {
+                    lsa_orig_router(area, instance, arenas);
+                    lsa_orig_router_info(area, instance);
+
+                    for iface in area.interfaces.iter(&arenas.interfaces) {
+                        // (Re)originate or flush Network-LSA.
+                        if iface.state.ism_state == ism::State::Dr
+                            && iface
+                                .state
+                                .neighbors
+                                .iter(&arenas.neighbors)
+                                .any(|nbr| nbr.state == nsm::State::Full)
+                        {
+                            lsa_orig_network(iface, area, instance, arenas);
+                        } else {
+                            lsa_flush_network(iface, area, instance, arenas);
+                        }
+
+                        // (Re)originate or flush Link-LSA.
+                        if iface.state.ism_state >= ism::State::Waiting {
+                            lsa_orig_link(iface, area, instance);
+                        } else {
+                            lsa_flush_link(iface, area, instance, arenas);
+                        }
+                    }
+
+                    lsa_orig_intra_area_prefix(area, instance, arenas);
+                }
+            }
         };
 
         Ok(())
     }
 
+    fn lsa_grace(
+        iface: &Interface<Self>,
+        _area: &Area<Self>,
+        grace_period: u32,
+        reason: GrReason,
+    ) -> (Option<Options>, Ipv4Addr, LsaBody) {
+        // The Link State ID is set to the router's Interface ID on the link.
+        let lsa_id = Ipv4Addr::from(iface.system.ifindex.unwrap());
+
+        let lsa_body = LsaBody::Grace(LsaGrace {
+            grace_period: Some(GracePeriodTlv::new(grace_period)),
+            gr_reason: Some(GrReasonTlv::new(reason as u8)),
+            unknown_tlvs: vec![],
+        });
+        (None, lsa_id, lsa_body)
+    }
+
+    fn prefix_lsa_ids(
+        _prefixes: impl Iterator<Item = IpNetwork>,
+    ) -> Option<BTreeMap<IpNetwork, u32>> {
+        // OSPFv3 LSA IDs don't carry any addressing semantics.
+        None
+    }
+
     fn lsa_orig_inter_area_network(
         area: &mut Area<Self>,
         instance: &InstanceUpView<'_, Self>,
@@ -424,6 +514,65 @@ impl LsdbVersion<Self> for Ospfv3 {
         lsa_id
     }
 
+    fn lsa_orig_as_external(
+        instance: &mut InstanceUpView<'_, Self>,
+        prefix: IpNetwork,
+        lsa_id: Option<u32>,
+        external: &ExternalNet<Self>,
+    ) -> u32 {
+        let extended_lsa = instance.config.extended_lsa;
+
+        // Get LSA-ID.
+        let lsa_id = lsa_id.unwrap_or_else(|| {
+            instance.state.next_type5_lsa_id += 1;
+            instance.state.next_type5_lsa_id
+        });
+
+        // (Re)originate AS-External-LSA.
+        let lsa_body = LsaBody::AsExternal(lsa_as_external(
+            extended_lsa,
+            prefix,
+            external,
+        ));
+        instance.tx.protocol_input.lsa_orig_check(
+            LsdbId::As,
+            None,
+            lsa_id.into(),
+            lsa_body,
+        );
+
+        lsa_id
+    }
+
+    fn lsa_orig_nssa(
+        area: &mut Area<Self>,
+        instance: &InstanceUpView<'_, Self>,
+        prefix: IpNetwork,
+        lsa_id: Option<u32>,
+        external: &ExternalNet<Self>,
+    ) -> u32 {
+        let lsdb_id = LsdbId::Area(area.id);
+        let extended_lsa = instance.config.extended_lsa;
+
+        // Get LSA-ID.
+        let lsa_id = lsa_id.unwrap_or_else(|| {
+            area.state.version.next_type7_lsa_id += 1;
+            area.state.version.next_type7_lsa_id
+        });
+
+        // (Re)originate NSSA-LSA.
+        let lsa_body =
+            LsaBody::Nssa(lsa_as_external(extended_lsa, prefix, external));
+        instance.tx.protocol_input.lsa_orig_check(
+            lsdb_id,
+            None,
+            lsa_id.into(),
+            lsa_body,
+        );
+
+        lsa_id
+    }
+
     fn lsdb_get_by_lsa_type(
         iface_idx: InterfaceIndex,
         area_idx: AreaIndex,
@@ -487,6 +636,36 @@ impl LsdbVersion<Self> for Ospfv3 {
 
 // ===== helper functions =====
 
+fn lsa_as_external(
+    extended_lsa: bool,
+    prefix: IpNetwork,
+    external: &ExternalNet<Ospfv3>,
+) -> LsaAsExternal {
+    let mut flags = LsaAsExternalFlags::empty();
+    if external.e_bit {
+        flags.insert(LsaAsExternalFlags::E);
+    }
+    if external.fwd_addr.is_some() {
+        flags.insert(LsaAsExternalFlags::F);
+    }
+    if external.tag.is_some() {
+        flags.insert(LsaAsExternalFlags::T);
+    }
+    let mut prefix_options = external.prefix_options;
+    prefix_options.set(PrefixOptions::P, external.propagate);
+    LsaAsExternal::new(
+        extended_lsa,
+        flags,
+        external.metric,
+        prefix_options,
+        prefix,
+        external.fwd_addr,
+        external.tag,
+        None,
+        None,
+    )
+}
+
 fn lsa_orig_router(
     area: &Area<Ospfv3>,
     instance: &InstanceUpView<'_, Ospfv3>,
@@ -503,9 +682,15 @@ fn lsa_orig_router(
     if arenas.areas.is_abr(&arenas.interfaces) {
         flags.insert(LsaRouterFlags::B);
     }
+    if lsdb::router_lsa_e_bit(area, instance) {
+        flags.insert(LsaRouterFlags::E);
+    }
     if lsdb::router_lsa_v_bit(area, arenas) {
         flags.insert(LsaRouterFlags::V);
     }
+    if area.state.nssa_translator_state != NssaTranslatorState::Disabled {
+        flags.insert(LsaRouterFlags::NT);
+    }
 
     // Router-LSA's links.
     let mut links = vec![];
@@ -531,13 +716,19 @@ fn lsa_orig_router(
         let ifindex = iface.system.ifindex.unwrap();
 
         // When stub-router is configured (RFC 6987), set the cost of all
-        // links to MaxLinkMetric.
-        let cost = if instance.config.stub_router {
+        // links to MaxLinkMetric. The same applies to links where LDP isn't
+        // synchronized yet (RFC 5443).
+        let cost = if instance.config.stub_router || iface.ldp_sync_max_metric()
+        {
             MAX_LINK_METRIC
         } else {
             iface.config.cost
         };
 
+        // Application-specific link attributes (Flexible Algorithm).
+        let asla = flex_algo::link_asla(&iface.config.link_attrs)
+            .filter(|_| instance.config.sr_enabled);
+
         match iface.config.if_type {
             InterfaceType::PointToPoint | InterfaceType::PointToMultipoint => {
                 // Add a Type-1 link (p2p) for each fully adjacent neighbor.
@@ -547,7 +738,7 @@ fn lsa_orig_router(
                     .iter(&arenas.neighbors)
                     .filter(|nbr| nbr.state == nsm::State::Full)
                 {
-                    let link = LsaRouterLink::new(
+                    let mut link = LsaRouterLink::new(
                         LsaRouterLinkType::PointToPoint,
                         cost,
                         ifindex,
@@ -555,6 +746,7 @@ fn lsa_orig_router(
                         nbr.router_id,
                         nbr.adj_sids.clone(),
                     );
+                    link.asla.extend(asla.clone());
                     links.push(link);
                 }
             }
@@ -588,7 +780,7 @@ fn lsa_orig_router(
                     .flat_map(|nbr| nbr.adj_sids.iter())
                     .copied()
                     .collect();
-                let link = LsaRouterLink::new(
+                let mut link = LsaRouterLink::new(
                     LsaRouterLinkType::TransitNetwork,
                     cost,
                     ifindex,
@@ -596,6 +788,7 @@ fn lsa_orig_router(
                     dr_router_id,
                     adj_sids,
                 );
+                link.asla.extend(asla);
                 links.push(link);
             }
             InterfaceType::VirtualLink => {
@@ -849,11 +1042,18 @@ fn lsa_orig_intra_area_prefix(
         })
         // Get all interface addresses.
         .flat_map(|iface| {
+            // When prefix suppression is enabled, only secondary prefixes are
+            // advertised, unless the interface is in the Loopback state
+            // (RFC 6860).
+            let suppressed = (iface.state.ism_state != ism::State::Loopback)
+                .then(|| suppressed_prefix(iface, instance.state.af))
+                .flatten();
             iface
                 .system
                 .addr_list
                 .iter()
                 .map(move |addr| (iface, addr.apply_mask()))
+                .filter(move |(_, addr)| Some(*addr) != suppressed)
         })
         // Filter by address family.
         .filter(|(_, addr)| addr.address_family() == instance.state.af)
@@ -896,27 +1096,29 @@ fn lsa_orig_intra_area_prefix(
             )
         };
 
-        // Add Prefix-SID Sub-TLV.
-        if instance.config.sr_enabled
-            && let Some(prefix_sid) =
-                sr_config.prefix_sids.get(&(prefix, IgpAlgoType::Spf))
-        {
-            let mut flags = PrefixSidFlags::empty();
-            match prefix_sid.last_hop {
-                SidLastHopBehavior::ExpNull => {
-                    flags.insert(PrefixSidFlags::NP);
-                    flags.insert(PrefixSidFlags::E);
-                }
-                SidLastHopBehavior::NoPhp => {
-                    flags.insert(PrefixSidFlags::NP);
+        // Add Prefix-SID Sub-TLV(s).
+        if instance.config.sr_enabled {
+            for ((_, algo), prefix_sid) in sr_config
+                .prefix_sids
+                .iter()
+                .filter(|((psid_prefix, _), _)| *psid_prefix == prefix)
+            {
+                let mut flags = PrefixSidFlags::empty();
+                match prefix_sid.last_hop {
+                    SidLastHopBehavior::ExpNull => {
+                        flags.insert(PrefixSidFlags::NP);
+                        flags.insert(PrefixSidFlags::E);
+                    }
+                    SidLastHopBehavior::NoPhp => {
+                        flags.insert(PrefixSidFlags::NP);
+                    }
+                    SidLastHopBehavior::Php => (),
                 }
-                SidLastHopBehavior::Php => (),
+                let sid = Sid::Index(prefix_sid.index);
+                entry
+                    .prefix_sids
+                    .insert(*algo, PrefixSid::new(flags, *algo, sid));
             }
-            let algo = IgpAlgoType::Spf;
-            let sid = Sid::Index(prefix_sid.index);
-            entry
-                .prefix_sids
-                .insert(algo, PrefixSid::new(flags, algo, sid));
         }
 
         // Add BIER Sub-TLV(s) if BIER is enabled and allowed to advertise
@@ -1015,6 +1217,7 @@ fn lsa_orig_intra_area_prefix(
         // Skip non-DR interfaces.
         .filter(|iface| iface.state.ism_state == ism::State::Dr)
     {
+        let suppressed = suppressed_prefix(iface, instance.state.af);
         let mut prefixes = HashMap::new();
         for prefix in iface
             .state
@@ -1054,6 +1257,9 @@ fn lsa_orig_intra_area_prefix(
                     true
                 }
             })
+            // Filter out the transit prefix when prefix suppression is
+            // enabled (RFC 6860).
+            .filter(|prefix| Some(prefix.value) != suppressed)
         {
             match prefixes.entry(prefix.value) {
                 hash_map::Entry::Occupied(mut o) => {
@@ -1144,10 +1350,29 @@ fn lsa_orig_router_info(
     let mut sr_algo = None;
     let mut srgb = vec![];
     let mut srlb = vec![];
+    let mut fads = vec![];
     let mut node_tags = vec![];
     if instance.config.sr_enabled {
         // Fill in supported SR algorithms.
-        sr_algo = Some(SrAlgoTlv::new([IgpAlgoType::Spf].into()));
+        let algos = std::iter::once(IgpAlgoType::Spf)
+            .chain(
+                instance
+                    .config
+                    .flex_algo
+                    .keys()
+                    .map(|algo| IgpAlgoType::FlexAlgo(*algo)),
+            )
+            .collect();
+        sr_algo = Some(SrAlgoTlv::new(algos));
+
+        // Fill in Flexible Algorithm Definitions.
+        fads = instance
+            .config
+            .flex_algo
+            .iter()
+            .filter(|(_, fa_cfg)| fa_cfg.advertise_definition)
+            .map(|(algo, fa_cfg)| flex_algo::fad_tlv(*algo, fa_cfg))
+            .collect();
 
         // Fill in local SRGB.
         for range in &sr_config.srgb {
@@ -1169,6 +1394,14 @@ fn lsa_orig_router_info(
         node_tags.push(NodeAdminTagTlv::new(instance.config.node_tags.clone()));
     }
 
+    // Fill in flooding reduction algorithm.
+    let mut flooding_algo = None;
+    if area.config.flooding_algo != FloodingAlgo::ZeroPruner {
+        flooding_algo = Some(FloodingAlgoTlv::new(
+            area.config.flooding_algo.to_u8().unwrap(),
+        ));
+    }
+
     // (Re)originate Router Information LSA.
     let scope = LsaScopeCode::Area;
     let mut info_caps = RouterInfoCaps::STUB_ROUTER;
@@ -1190,6 +1423,8 @@ fn lsa_orig_router_info(
             .as_ref()
             .map(|hostname| DynamicHostnameTlv::new(hostname.to_string())),
         node_tags,
+        fads,
+        flooding_algo,
         unknown_tlvs: vec![],
     });
     instance
@@ -1251,10 +1486,14 @@ fn process_self_originated_lsa(
             // Do nothing. These LSAs will be either reoriginated or flushed
             // once SPF runs and the routing table is computed.
         }
-        Some(LsaFunctionCode::AsExternal) => {
-            // Flush AS-External-LSA (redistribution of local routes isn't
-            // supported at the moment).
-            flush = true;
+        Some(
+            LsaFunctionCode::AsExternal
+            | LsaFunctionCode::ExtAsExternal
+            | LsaFunctionCode::Nssa
+            | LsaFunctionCode::ExtNssa,
+        ) => {
+            // Do nothing. These LSAs will be either reoriginated or flushed
+            // once SPF runs and the routing table is computed.
         }
         Some(LsaFunctionCode::Link) => {
             let (area_idx, iface_idx) = lsdb_idx.into_link().unwrap();
@@ -1294,6 +1533,34 @@ fn process_self_originated_lsa(
     Ok(())
 }
 
+// Returns the interface's transit prefix when prefix suppression is enabled.
+//
+// The transit prefix is the one derived from the interface's first global
+// address, mirroring the primary address selection used by OSPFv2.
+fn suppressed_prefix(
+    iface: &Interface<Ospfv3>,
+    af: AddressFamily,
+) -> Option<IpNetwork> {
+    if !iface.config.prefix_suppression.resolved {
+        return None;
+    }
+
+    iface
+        .system
+        .addr_list
+        .iter()
+        .filter(|addr| addr.address_family() == af)
+        .filter(|addr| {
+            if let IpAddr::V6(addr) = addr.ip() {
+                !addr.is_unicast_link_local()
+            } else {
+                true
+            }
+        })
+        .map(|addr| addr.apply_mask())
+        .next()
+}
+
 fn lsa_flush(
     instance: &InstanceUpView<'_, Ospfv3>,
     lsdb_id: LsdbId,
@@ -58,6 +58,7 @@ pub enum LsaFunctionCode {
     InterAreaPrefix = 3,
     InterAreaRouter = 4,
     AsExternal = 5,
+    Nssa = 7,
     Link = 8,
     IntraAreaPrefix = 9,
     // Extended LSA Types
@@ -66,6 +67,7 @@ pub enum LsaFunctionCode {
     ExtInterAreaPrefix = 35,
     ExtInterAreaRouter = 36,
     ExtAsExternal = 37,
+    ExtNssa = 39,
     ExtLink = 40,
     ExtIntraAreaPrefix = 41,
     // Other LSA types
@@ -107,6 +109,12 @@ pub enum ExtLsaStlv {
     LanAdjSid = 6,
     SidLabel = 7,
     LinkMsd = 9,
+    Asla = 11,
+    Srlg = 12,
+    MinMaxLinkDelay = 14,
+    AdminGroup = 20,
+    ExtAdminGroup = 21,
+    TeMetric = 22,
     Bier = 42,
 }
 
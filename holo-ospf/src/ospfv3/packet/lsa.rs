@@ -17,7 +17,7 @@ use holo_utils::mpls::Label;
 use holo_utils::sr::{IgpAlgoType, Sid};
 use ipnetwork::IpNetwork;
 use num_derive::{FromPrimitive, ToPrimitive};
-use num_traits::FromPrimitive;
+use num_traits::{FromPrimitive, ToPrimitive};
 use serde::{Deserialize, Serialize};
 
 use crate::ospfv3::packet::iana::{
@@ -34,8 +34,9 @@ use crate::packet::lsa::{
     PrefixSidVersion,
 };
 use crate::packet::tlv::{
-    AdjSidFlags, BierStlv, DynamicHostnameTlv, GrReason, GrReasonTlv,
-    GracePeriodTlv, MsdTlv, NodeAdminTagTlv, PrefixSidFlags, RouterFuncCapsTlv,
+    AdjSidFlags, AslaStlv, BierStlv, DynamicHostnameTlv, FadTlv,
+    FloodingAlgoTlv, GrReason, GrReasonTlv, GracePeriodTlv, LinkAttrStlvCodes,
+    MsdTlv, NodeAdminTagTlv, PrefixSidFlags, RouterFuncCapsTlv,
     RouterInfoCapsTlv, SidLabelRangeTlv, SrAlgoTlv, SrLocalBlockTlv,
     SrmsPrefTlv, TLV_HDR_SIZE, UnknownTlv, tlv_encode_end, tlv_encode_start,
     tlv_wire_len,
@@ -107,6 +108,7 @@ pub enum LsaBody {
     InterAreaPrefix(LsaInterAreaPrefix),
     InterAreaRouter(LsaInterAreaRouter),
     AsExternal(LsaAsExternal),
+    Nssa(LsaAsExternal),
     Link(LsaLink),
     IntraAreaPrefix(LsaIntraAreaPrefix),
     Grace(LsaGrace),
@@ -166,6 +168,7 @@ pub struct ExtLsaStlvs {
     pub route_tag: Option<u32>,
     pub prefix_sids: BTreeMap<IgpAlgoType, PrefixSid>,
     pub adj_sids: Vec<AdjSid>,
+    pub asla: Vec<AslaStlv>,
     pub bier: Vec<BierStlv>,
     pub unknown: Vec<UnknownTlv>,
 }
@@ -318,6 +321,10 @@ pub struct LsaRouterLink {
     pub nbr_router_id: Ipv4Addr,
     pub adj_sids: Vec<AdjSid>,
     #[new(default)]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub asla: Vec<AslaStlv>,
+    #[new(default)]
     pub unknown_stlvs: Vec<UnknownTlv>,
 }
 
@@ -856,6 +863,14 @@ pub struct LsaRouterInfo {
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub node_tags: Vec<NodeAdminTagTlv>,
     #[new(default)]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fads: Vec<FadTlv>,
+    #[new(default)]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flooding_algo: Option<FloodingAlgoTlv>,
+    #[new(default)]
     pub unknown_tlvs: Vec<UnknownTlv>,
 }
 
@@ -913,6 +928,7 @@ impl LsaTypeVersion for LsaType {
                     | LsaFunctionCode::InterAreaPrefix
                     | LsaFunctionCode::InterAreaRouter
                     | LsaFunctionCode::AsExternal
+                    | LsaFunctionCode::Nssa
                     | LsaFunctionCode::IntraAreaPrefix
             )
         )
@@ -945,6 +961,7 @@ impl LsaFunctionCode {
                 LsaFunctionCode::InterAreaRouter
             }
             LsaFunctionCode::ExtAsExternal => LsaFunctionCode::AsExternal,
+            LsaFunctionCode::ExtNssa => LsaFunctionCode::Nssa,
             LsaFunctionCode::ExtLink => LsaFunctionCode::Link,
             LsaFunctionCode::ExtIntraAreaPrefix => {
                 LsaFunctionCode::IntraAreaPrefix
@@ -1108,6 +1125,14 @@ impl LsaBody {
         self.as_as_external().filter(|lsa_body| lsa_body.extended)
     }
 
+    pub(crate) fn as_std_nssa(&self) -> Option<&LsaAsExternal> {
+        self.as_nssa().filter(|lsa_body| !lsa_body.extended)
+    }
+
+    pub(crate) fn as_ext_nssa(&self) -> Option<&LsaAsExternal> {
+        self.as_nssa().filter(|lsa_body| lsa_body.extended)
+    }
+
     pub(crate) fn as_std_link(&self) -> Option<&LsaLink> {
         self.as_link().filter(|lsa_body| !lsa_body.extended)
     }
@@ -1156,6 +1181,9 @@ impl LsaBodyVersion<Ospfv3> for LsaBody {
             Some(LsaFunctionCode::AsExternal) => {
                 LsaBody::AsExternal(LsaAsExternal::decode_legacy(af, buf)?)
             }
+            Some(LsaFunctionCode::Nssa) => {
+                LsaBody::Nssa(LsaAsExternal::decode_legacy(af, buf)?)
+            }
             Some(LsaFunctionCode::Link) => {
                 LsaBody::Link(LsaLink::decode_legacy(af, buf)?)
             }
@@ -1182,6 +1210,9 @@ impl LsaBodyVersion<Ospfv3> for LsaBody {
             Some(LsaFunctionCode::ExtAsExternal) => {
                 LsaBody::AsExternal(LsaAsExternal::decode_extended(af, buf)?)
             }
+            Some(LsaFunctionCode::ExtNssa) => {
+                LsaBody::Nssa(LsaAsExternal::decode_extended(af, buf)?)
+            }
             Some(LsaFunctionCode::ExtLink) => {
                 LsaBody::Link(LsaLink::decode_extended(af, buf)?)
             }
@@ -1210,6 +1241,7 @@ impl LsaBodyVersion<Ospfv3> for LsaBody {
             LsaBody::InterAreaPrefix(lsa) => lsa.encode(buf),
             LsaBody::InterAreaRouter(lsa) => lsa.encode(buf),
             LsaBody::AsExternal(lsa) => lsa.encode(buf),
+            LsaBody::Nssa(lsa) => lsa.encode(buf),
             LsaBody::Link(lsa) => lsa.encode(buf),
             LsaBody::IntraAreaPrefix(lsa) => lsa.encode(buf),
             LsaBody::Grace(lsa) => lsa.encode(buf),
@@ -1229,6 +1261,7 @@ impl LsaBodyVersion<Ospfv3> for LsaBody {
                 LsaInterAreaRouter::lsa_type(lsa.extended)
             }
             LsaBody::AsExternal(lsa) => LsaAsExternal::lsa_type(lsa.extended),
+            LsaBody::Nssa(lsa) => LsaAsExternal::nssa_lsa_type(lsa.extended),
             LsaBody::Link(lsa) => LsaLink::lsa_type(lsa.extended),
             LsaBody::IntraAreaPrefix(lsa) => {
                 LsaIntraAreaPrefix::lsa_type(lsa.extended)
@@ -1378,6 +1411,10 @@ impl LsaRouterFlagsVersion for LsaRouterFlags {
     fn is_vlink_endpoint(&self) -> bool {
         self.contains(LsaRouterFlags::V)
     }
+
+    fn is_nssa_translator(&self) -> bool {
+        self.contains(LsaRouterFlags::NT)
+    }
 }
 
 // ===== impl LsaRouterLink =====
@@ -1410,6 +1447,7 @@ impl LsaRouterLink {
         if extended {
             let stlvs = ExtLsaStlvs::decode(buf)?;
             link.adj_sids = stlvs.adj_sids;
+            link.asla = stlvs.asla;
             link.unknown_stlvs = stlvs.unknown;
         }
 
@@ -1431,6 +1469,7 @@ impl LsaRouterLink {
     fn sub_tlvs(&self) -> ExtLsaStlvs {
         ExtLsaStlvs {
             adj_sids: self.adj_sids.clone(),
+            asla: self.asla.clone(),
             ..Default::default()
         }
     }
@@ -2017,6 +2056,20 @@ impl LsaAsExternal {
             }
         }
     }
+
+    pub(crate) const fn nssa_lsa_type(extended: bool) -> LsaType {
+        let scope = LsaScopeCode::Area;
+        match extended {
+            true => {
+                let function_code = LsaFunctionCode::ExtNssa;
+                LsaType(U_BIT | scope as u16 | function_code as u16)
+            }
+            false => {
+                let function_code = LsaFunctionCode::Nssa;
+                LsaType(scope as u16 | function_code as u16)
+            }
+        }
+    }
 }
 
 // ===== impl LsaLink =====
@@ -2498,6 +2551,15 @@ impl LsaRouterInfo {
                     let srms_pref = SrmsPrefTlv::decode(tlv_len, &mut buf_tlv)?;
                     router_info.srms_pref.get_or_insert(srms_pref);
                 }
+                Some(RouterInfoTlvType::FlexAlgoDefinition) => {
+                    let fad = FadTlv::decode(tlv_len, &mut buf_tlv)?;
+                    router_info.fads.push(fad);
+                }
+                Some(RouterInfoTlvType::FloodingAlgo) => {
+                    let flooding_algo =
+                        FloodingAlgoTlv::decode(tlv_len, &mut buf_tlv)?;
+                    router_info.flooding_algo.get_or_insert(flooding_algo);
+                }
                 _ => {
                     // Save unknown TLV.
                     router_info
@@ -2538,6 +2600,12 @@ impl LsaRouterInfo {
         if let Some(srms_pref) = &self.srms_pref {
             srms_pref.encode(buf);
         }
+        for fad in &self.fads {
+            fad.encode(buf);
+        }
+        if let Some(flooding_algo) = &self.flooding_algo {
+            flooding_algo.encode(buf);
+        }
     }
 
     pub(crate) const fn lsa_type(&self) -> LsaType {
@@ -2665,6 +2733,13 @@ impl ExtLsaStlvs {
                         AdjSid::new(flags, weight, nbr_router_id, sid);
                     stlvs.adj_sids.push(adj_sid);
                 }
+                Some(ExtLsaStlv::Asla) => {
+                    if let Some(asla) =
+                        AslaStlv::decode::<ExtLsaStlv>(stlv_len, &mut buf_stlv)?
+                    {
+                        stlvs.asla.push(asla);
+                    }
+                }
                 Some(ExtLsaStlv::Bier) => {
                     let bier = BierStlv::decode(stlv_len, &mut buf_stlv)?;
                     stlvs.bier.push(bier);
@@ -2700,7 +2775,7 @@ impl ExtLsaStlvs {
         for (algo, prefix_sid) in &self.prefix_sids {
             let start_pos = tlv_encode_start(buf, ExtLsaStlv::PrefixSid);
             buf.put_u8(prefix_sid.flags.bits());
-            buf.put_u8(*algo as u8);
+            buf.put_u8(algo.to_u8().unwrap());
             buf.put_u16(0);
             match prefix_sid.sid {
                 Sid::Index(index) => buf.put_u32(index),
@@ -2726,12 +2801,25 @@ impl ExtLsaStlvs {
             }
             tlv_encode_end(buf, start_pos);
         }
+        for asla in &self.asla {
+            asla.encode::<ExtLsaStlv>(ExtLsaStlv::Asla, buf);
+        }
         for bier in &self.bier {
             BierStlv::encode(bier, buf, ExtLsaStlv::Bier);
         }
     }
 }
 
+// ===== impl ExtLsaStlv =====
+
+impl LinkAttrStlvCodes for ExtLsaStlv {
+    const SRLG: u16 = ExtLsaStlv::Srlg as u16;
+    const MIN_MAX_LINK_DELAY: u16 = ExtLsaStlv::MinMaxLinkDelay as u16;
+    const ADMIN_GROUP: u16 = ExtLsaStlv::AdminGroup as u16;
+    const EXT_ADMIN_GROUP: u16 = ExtLsaStlv::ExtAdminGroup as u16;
+    const TE_METRIC: u16 = ExtLsaStlv::TeMetric as u16;
+}
+
 // ===== impl PrefixSid =====
 
 impl PrefixSidVersion for PrefixSid {
@@ -2743,9 +2831,17 @@ impl PrefixSidVersion for PrefixSid {
         &mut self.flags
     }
 
+    fn algo(&self) -> IgpAlgoType {
+        self.algo
+    }
+
     fn sid(&self) -> Sid {
         self.sid
     }
+
+    fn sid_mut(&mut self) -> &mut Sid {
+        &mut self.sid
+    }
 }
 
 // ===== impl AdjSid =====
@@ -2788,6 +2884,14 @@ impl LsaVersion<Self> for Ospfv3 {
     fn type4_summary(extended_lsa: bool) -> LsaType {
         LsaInterAreaRouter::lsa_type(extended_lsa)
     }
+
+    fn type5_external(extended_lsa: bool) -> LsaType {
+        LsaAsExternal::lsa_type(extended_lsa)
+    }
+
+    fn type7_nssa(extended_lsa: bool) -> LsaType {
+        LsaAsExternal::nssa_lsa_type(extended_lsa)
+    }
 }
 
 // ===== global functions =====
@@ -10,7 +10,6 @@ pub mod lsa;
 
 use std::collections::BTreeSet;
 use std::net::Ipv4Addr;
-use std::sync::atomic;
 
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 use holo_utils::bytes::{BytesExt, BytesMutExt, TLS_BUF};
@@ -250,6 +249,10 @@ impl OptionsVersion<Ospfv3> for Options {
     fn l_bit(&self) -> bool {
         self.contains(Options::L)
     }
+
+    fn n_bit(&self) -> bool {
+        self.contains(Options::N)
+    }
 }
 
 // ===== impl PacketHdr =====
@@ -942,7 +945,7 @@ impl PacketVersion<Self> for Ospfv3 {
         // TODO RFC 7166 - Section 4.1.1:
         // "If the lower-order 32-bit value wraps, the higher-order 32-bit value
         // should be incremented and saved in non-volatile storage".
-        buf.put_u64(auth.seqno.fetch_add(1, atomic::Ordering::Relaxed));
+        buf.put_u64(auth.seqno.next());
 
         // Append message digest.
         let digest = auth::message_digest(
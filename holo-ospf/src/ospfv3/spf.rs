@@ -26,12 +26,13 @@ use crate::ospfv3::packet::lsa::{
     LsaRouterLink, LsaScopeCode, LsaType,
 };
 use crate::packet::lsa::{Lsa, LsaHdrVersion, LsaKey};
+use crate::packet::tlv::AslaStlv;
 use crate::route::{Nexthop, NexthopKey, Nexthops};
 use crate::spf::{
     SpfComputation, SpfExternalNetwork, SpfInterAreaNetwork,
     SpfInterAreaRouter, SpfIntraAreaNetwork, SpfLink, SpfPartialComputation,
-    SpfRouterInfo, SpfTriggerLsa, SpfVersion, Vertex, VertexIdVersion,
-    VertexLsaVersion,
+    SpfPrefixRange, SpfRouterInfo, SpfTriggerLsa, SpfVersion, Vertex,
+    VertexIdVersion, VertexLsaVersion,
 };
 use crate::version::Ospfv3;
 
@@ -146,11 +147,11 @@ impl SpfVersion<Self> for Ospfv3 {
             .map(|lsa_body| lsa_body.router_id)
             .collect::<BTreeSet<_>>();
 
-        // Check AS-External LSA changes.
+        // Check AS-External and NSSA LSA changes.
         let external = trigger_lsas
             .iter()
             .map(|tlsa| &tlsa.new)
-            .filter_map(|lsa| lsa.body.as_as_external())
+            .filter_map(|lsa| lsa.body.as_as_external().or(lsa.body.as_nssa()))
             .map(|lsa_body| lsa_body.prefix)
             .collect();
 
@@ -418,6 +419,14 @@ impl SpfVersion<Self> for Ospfv3 {
         }
     }
 
+    fn link_asla<'a>(
+        _vertex_lsa: &'a VertexLsa,
+        link: &'a LsaRouterLink,
+        _area: &'a Area<Ospfv3>,
+    ) -> &'a [AslaStlv] {
+        &link.asla
+    }
+
     fn intra_area_networks<'a>(
         area: &'a Area<Self>,
         extended_lsa: bool,
@@ -545,10 +554,48 @@ impl SpfVersion<Self> for Ospfv3 {
                         metric: lsa_body.metric,
                         fwd_addr: lsa_body.fwd_addr,
                         tag: lsa_body.tag,
+                        propagate: false,
                     })
             })
     }
 
+    fn nssa_networks<'a>(
+        lsdb: &'a Lsdb<Self>,
+        extended_lsa: bool,
+        lsa_entries: &'a Arena<LsaEntry<Self>>,
+    ) -> impl Iterator<Item = SpfExternalNetwork<Self>> + 'a {
+        lsdb.iter_by_type(
+            lsa_entries,
+            LsaAsExternal::nssa_lsa_type(extended_lsa),
+        )
+        .map(|(_, lse)| &lse.data)
+        .filter(|lsa| !lsa.hdr.is_maxage())
+        .filter_map(|lsa| {
+            let lsa_body = lsa.body.as_nssa().unwrap();
+            (!lsa_body.prefix_options.contains(PrefixOptions::NU)).then_some(
+                SpfExternalNetwork {
+                    adv_rtr: lsa.hdr.adv_rtr,
+                    e_bit: lsa_body.flags.contains(LsaAsExternalFlags::E),
+                    prefix: lsa_body.prefix,
+                    prefix_options: lsa_body.prefix_options,
+                    metric: lsa_body.metric,
+                    fwd_addr: lsa_body.fwd_addr,
+                    tag: lsa_body.tag,
+                    propagate: lsa_body
+                        .prefix_options
+                        .contains(PrefixOptions::P),
+                },
+            )
+        })
+    }
+
+    fn prefix_ranges<'a>(
+        _area: &'a Area<Self>,
+    ) -> impl Iterator<Item = SpfPrefixRange<Self>> + 'a {
+        // The OSPFv3 Extended Prefix Range TLV isn't supported yet.
+        std::iter::empty()
+    }
+
     fn area_router_information<'a>(
         lsdb: &'a Lsdb<Self>,
         router_id: Ipv4Addr,
@@ -582,6 +629,14 @@ impl SpfVersion<Self> for Ospfv3 {
             // Multiple occurrences of the SID/Label Range TLV MAY be advertised
             // in order to advertise multiple ranges.
             ri_agg.srgb.extend(&ri_lsa.srgb);
+
+            // Flexible Algorithm Definitions.
+            ri_agg.fads.extend(&ri_lsa.fads);
+
+            // Flooding reduction algorithm.
+            if let Some(flooding_algo) = &ri_lsa.flooding_algo {
+                ri_agg.flooding_algo.get_or_insert(flooding_algo);
+            }
         }
 
         ri_agg
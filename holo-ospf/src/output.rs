@@ -14,6 +14,7 @@ use crate::lsdb;
 use crate::neighbor::{Neighbor, nsm};
 use crate::network::MulticastAddr;
 use crate::packet::iana::PacketType;
+use crate::packet::lls::{ExtendedOptionsFlags, LlsDbDescData};
 use crate::packet::lsa::LsaHdrVersion;
 use crate::packet::{
     DbDescFlags, DbDescVersion, LsAckVersion, LsRequestVersion,
@@ -64,8 +65,9 @@ pub(crate) fn send_dbdesc<V>(
     }
 
     let lls = if iface.config.lls_enabled {
-        // TODO; get LLS configuration
-        None
+        Some(LlsDbDescData {
+            eof: Some(ExtendedOptionsFlags::LR),
+        })
     } else {
         None
     };
@@ -434,7 +436,9 @@ fn send_dest_nbr<V>(
 where
     V: Version,
 {
-    let addr = if iface.config.if_type == InterfaceType::PointToPoint {
+    let addr = if iface.config.if_type == InterfaceType::PointToPoint
+        && !iface.is_multi_area()
+    {
         *V::multicast_addr(MulticastAddr::AllSpfRtrs)
     } else {
         nbr.src
@@ -474,6 +478,12 @@ where
                 .map(|nbr| nbr.src)
                 .collect()
         }
+        InterfaceType::PointToPoint if iface.is_multi_area() => {
+            // Packets sent over multi-area adjacencies are unicast to the
+            // configured neighbor address.
+            let multi_area = iface.multi_area.as_ref().unwrap();
+            smallvec![multi_area.nbr_addr.unwrap()]
+        }
         InterfaceType::PointToPoint => {
             let addr = MulticastAddr::AllSpfRtrs;
             smallvec![*V::multicast_addr(addr)]
@@ -4,19 +4,24 @@
 // SPDX-License-Identifier: MIT
 //
 
+use std::collections::HashMap;
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 use std::sync::Arc;
-use std::sync::atomic::AtomicU64;
+use std::sync::atomic::{self, AtomicU64};
 
 use derive_new::new;
 use hmac::digest::{Mac, OutputSizeUser};
 use hmac::{EagerHash, Hmac, KeyInit};
+use holo_utils::Database;
 use holo_utils::crypto::{CryptoAlgo, CryptoProtocolId, HMAC_APAD};
 use holo_utils::ip::{Ipv4AddrExt, Ipv6AddrExt};
 use holo_utils::keychain::{Key, Keychain};
 use md5::{Digest, Md5};
 use sha1::Sha1;
 use sha2::{Sha256, Sha384, Sha512};
+use tracing::error;
+
+use crate::packet::iana::PacketType;
 
 #[derive(Clone, Debug)]
 pub enum AuthMethod {
@@ -29,7 +34,9 @@ pub struct AuthEncodeCtx<'a> {
     // Authentication key.
     pub key: &'a Key,
     // Authentication sequence number.
-    pub seqno: &'a Arc<AtomicU64>,
+    pub seqno: &'a Arc<AuthSeqNo>,
+    // Whether RFC 7474 extended sequence numbers are in use (OSPFv2 only).
+    pub ext_seqno: bool,
     // Packet source.
     pub src_addr: IpAddr,
 }
@@ -38,10 +45,152 @@ pub struct AuthEncodeCtx<'a> {
 pub struct AuthDecodeCtx<'a> {
     // Authentication method.
     pub method: &'a AuthMethod,
+    // Whether RFC 7474 extended sequence numbers are in use (OSPFv2 only).
+    pub ext_seqno: bool,
     // Packet source.
     pub src_addr: IpAddr,
 }
 
+// Authentication sequence numbers used for sending packets.
+#[derive(Debug)]
+pub struct AuthSeqNo {
+    // Sequence number shared by all packet types.
+    shared: AtomicU64,
+    // Per-packet-type sequence numbers.
+    //
+    // RFC 7474 Section 4.1 says:
+    // "Separate sequence number spaces are maintained for each OSPFv2 packet
+    // type".
+    pkt_types: [AtomicU64; 5],
+    // Non-volatile storage of the boot count.
+    boot_count_nvm: Option<BootCountNvm>,
+}
+
+// Non-volatile storage location of the instance boot count.
+pub struct BootCountNvm {
+    db: Database,
+    key: String,
+}
+
+// Last authentication sequence numbers received from a neighbor.
+//
+// Sequence number checking is dependent on OSPF packet type in order to
+// account for packet prioritization as specified in RFC 4222.
+#[derive(Debug, Default)]
+pub struct AuthSeqNoRx(HashMap<PacketType, u64>);
+
+// ===== impl AuthSeqNo =====
+
+impl AuthSeqNo {
+    pub fn new(initial: u64) -> AuthSeqNo {
+        AuthSeqNo {
+            shared: AtomicU64::new(initial),
+            pkt_types: std::array::from_fn(|_| AtomicU64::new(initial)),
+            boot_count_nvm: None,
+        }
+    }
+
+    // Sets the non-volatile storage location of the boot count, which is
+    // updated whenever the lower-order 32 bits of a sequence number wrap.
+    pub(crate) fn with_boot_count_nvm(
+        mut self,
+        db: Database,
+        key: String,
+    ) -> AuthSeqNo {
+        self.boot_count_nvm = Some(BootCountNvm { db, key });
+        self
+    }
+
+    // Returns the next sequence number from the shared sequence number space.
+    pub(crate) fn next(&self) -> u64 {
+        let seqno = self.shared.fetch_add(1, atomic::Ordering::Relaxed);
+        self.wrap_check(seqno);
+        seqno
+    }
+
+    // Returns the next sequence number from the sequence number space of the
+    // given packet type.
+    pub(crate) fn next_pkt_type(&self, pkt_type: PacketType) -> u64 {
+        let seqno = self.pkt_types[pkt_type as usize - 1]
+            .fetch_add(1, atomic::Ordering::Relaxed);
+        self.wrap_check(seqno);
+        seqno
+    }
+
+    // Checks whether the lower-order 32 bits of the sequence number are about
+    // to wrap, in which case the carry goes into the higher-order 32 bits
+    // (boot count).
+    //
+    // RFC 7474 - Section 4:
+    // "If the lower-order 32-bit value wraps, the higher-order 32-bit value
+    // should be incremented and saved in non-volatile storage".
+    fn wrap_check(&self, seqno: u64) {
+        if seqno as u32 != u32::MAX {
+            return;
+        }
+        if let Some(boot_count_nvm) = &self.boot_count_nvm {
+            let boot_count = ((seqno >> 32) as u32).wrapping_add(1);
+            boot_count_nvm.update(boot_count);
+        }
+    }
+}
+
+// ===== impl BootCountNvm =====
+
+impl BootCountNvm {
+    // Records that the given boot count is now in use.
+    //
+    // The stored boot count is always kept higher than the one in use, so
+    // that the sequence numbers sent after a restart are higher than the ones
+    // sent before it.
+    fn update(&self, boot_count: u32) {
+        let mut db = self.db.lock().unwrap();
+        let next_boot_count = boot_count.saturating_add(1);
+        if db
+            .get::<u32>(&self.key)
+            .is_some_and(|value| value >= next_boot_count)
+        {
+            return;
+        }
+        if let Err(error) = db.set(&self.key, &next_boot_count) {
+            error!(%error, "failed to record updated boot count in non-volatile storage");
+        }
+    }
+}
+
+impl std::fmt::Debug for BootCountNvm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BootCountNvm")
+            .field("key", &self.key)
+            .finish()
+    }
+}
+
+// ===== impl AuthSeqNoRx =====
+
+impl AuthSeqNoRx {
+    // Checks whether the received sequence number is acceptable, updating the
+    // last sequence number received for the packet type in that case.
+    //
+    // Packets whose sequence numbers are lower than the last one received are
+    // considered replays. When strict checking is enabled, packets repeating
+    // the last sequence number are considered replays as well.
+    pub(crate) fn check_update(
+        &mut self,
+        pkt_type: PacketType,
+        seqno: u64,
+        strict: bool,
+    ) -> bool {
+        let last_seqno = self.0.entry(pkt_type).or_default();
+        if seqno < *last_seqno || (strict && seqno == *last_seqno) {
+            return false;
+        }
+
+        *last_seqno = seqno;
+        true
+    }
+}
+
 // ===== helper functions =====
 
 fn keyed_md5_digest(data: &[u8], key: &[u8]) -> [u8; 16] {
@@ -134,3 +283,61 @@ pub(crate) fn message_digest(
         }
     }
 }
+
+// ===== unit tests =====
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use pickledb::{PickleDb, PickleDbDumpPolicy, SerializationMethod};
+
+    use super::*;
+
+    const BOOT_COUNT_KEY: &str = "ospfv2-test-boot-count";
+
+    fn database(boot_count: u32) -> Database {
+        let mut db = PickleDb::new(
+            "/dev/null",
+            PickleDbDumpPolicy::NeverDump,
+            SerializationMethod::Json,
+        );
+        db.set(BOOT_COUNT_KEY, &boot_count).unwrap();
+        Arc::new(Mutex::new(db))
+    }
+
+    fn boot_count(db: &Database) -> Option<u32> {
+        db.lock().unwrap().get::<u32>(BOOT_COUNT_KEY)
+    }
+
+    #[test]
+    fn seqno_wrap() {
+        // Boot count 5 in use, 6 already stored for the next restart.
+        let db = database(6);
+        let initial = (5 << 32) | (u32::MAX as u64 - 1);
+        let seqno = AuthSeqNo::new(initial)
+            .with_boot_count_nvm(db.clone(), BOOT_COUNT_KEY.to_owned());
+
+        // No wrap yet.
+        assert_eq!(seqno.next(), (5 << 32) | (u32::MAX as u64 - 1));
+        assert_eq!(boot_count(&db), Some(6));
+
+        // The lower-order 32 bits wrap, carrying into the boot count, which is
+        // saved in non-volatile storage.
+        assert_eq!(seqno.next(), (5 << 32) | u32::MAX as u64);
+        assert_eq!(seqno.next(), 6 << 32);
+        assert_eq!(boot_count(&db), Some(7));
+
+        // Each packet type has its own sequence number space. Wrapping the
+        // sequence number of a packet type to a boot count that was already
+        // saved doesn't change the stored value.
+        let pkt_type = PacketType::Hello;
+        assert_eq!(
+            seqno.next_pkt_type(pkt_type),
+            (5 << 32) | (u32::MAX as u64 - 1)
+        );
+        assert_eq!(seqno.next_pkt_type(pkt_type), (5 << 32) | u32::MAX as u64);
+        assert_eq!(seqno.next_pkt_type(pkt_type), 6 << 32);
+        assert_eq!(boot_count(&db), Some(7));
+    }
+}
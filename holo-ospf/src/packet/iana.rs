@@ -39,6 +39,78 @@ pub enum RouterInfoTlvType {
     NodeMsd = 12,
     SrLocalBlock = 14,
     SrmsPref = 15,
+    FlexAlgoDefinition = 16,
+    // FIXME: TBD in https://datatracker.ietf.org/doc/html/draft-ietf-lsr-distoptflood
+    FloodingAlgo = 19,
+}
+
+// IGP Algorithm Type For Computing Flooding Topology.
+//
+// IANA registry not yet created (see draft-prz-lsr-interop-flood-reduction-architecture-01).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(FromPrimitive, ToPrimitive)]
+#[derive(Deserialize, Serialize)]
+pub enum FloodingAlgo {
+    ZeroPruner = 1,
+    // FIXME: TBD1 in https://datatracker.ietf.org/doc/html/draft-ietf-lsr-distoptflood-11#section-4
+    ModifiedManet = 2,
+}
+
+// OSPF Flexible Algorithm Definition TLV Sub-TLV types.
+//
+// IANA registry:
+// https://www.iana.org/assignments/ospf-parameters/ospf-parameters.xhtml#ospf-fad-tlv-sub-tlvs
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(FromPrimitive, ToPrimitive)]
+#[derive(Deserialize, Serialize)]
+pub enum FadStlvType {
+    ExcludeAdminGroup = 1,
+    IncludeAnyAdminGroup = 2,
+    IncludeAllAdminGroup = 3,
+    Flags = 4,
+    ExcludeSrlg = 5,
+}
+
+// IGP Flexible Algorithm Definition Flags.
+//
+// IANA registry:
+// https://www.iana.org/assignments/igp-parameters/igp-parameters.xhtml#igp-flexible-algorithm-definition-flags
+bitflags! {
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[derive(Deserialize, Serialize)]
+    #[serde(transparent)]
+    pub struct FadFlags: u8 {
+        const M = 0x80;
+    }
+}
+
+// IGP Metric-Types.
+//
+// IANA registry:
+// https://www.iana.org/assignments/igp-parameters/igp-parameters.xhtml#igp-metric-type
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(FromPrimitive, ToPrimitive)]
+#[derive(Deserialize, Serialize)]
+pub enum IgpMetricType {
+    IgpMetric = 0,
+    MinUniLinkDelay = 1,
+    TeMetric = 2,
+}
+
+// Link Attribute Application Identifiers (Standard Application Bit Mask).
+//
+// IANA registry:
+// https://www.iana.org/assignments/igp-parameters/igp-parameters.xhtml#link-attribute-applications
+bitflags! {
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[derive(Deserialize, Serialize)]
+    #[serde(transparent)]
+    pub struct AslaSabmFlags: u8 {
+        const R = 0x80;
+        const S = 0x40;
+        const F = 0x20;
+        const X = 0x10;
+    }
 }
 
 // OSPF Router Informational Capability Bits.
@@ -12,7 +12,7 @@ use derive_new::new;
 use holo_utils::bytes::TLS_BUF;
 use holo_utils::ip::AddressFamily;
 use holo_utils::mpls::Label;
-use holo_utils::sr::Sid;
+use holo_utils::sr::{IgpAlgoType, Sid};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
@@ -81,6 +81,12 @@ pub trait LsaVersion<V: Version> {
 
     // Return LSA type for inter-area router LSAs.
     fn type4_summary(extended_lsa: bool) -> Self::LsaType;
+
+    // Return LSA type for AS-external LSAs.
+    fn type5_external(extended_lsa: bool) -> Self::LsaType;
+
+    // Return LSA type for NSSA LSAs.
+    fn type7_nssa(extended_lsa: bool) -> Self::LsaType;
 }
 
 // OSPF version-specific code.
@@ -195,6 +201,7 @@ where
     fn is_abr(&self) -> bool;
     fn is_asbr(&self) -> bool;
     fn is_vlink_endpoint(&self) -> bool;
+    fn is_nssa_translator(&self) -> bool;
 }
 
 // OSPF version-specific code.
@@ -222,7 +229,11 @@ where
 
     fn flags_mut(&mut self) -> &mut PrefixSidFlags;
 
+    fn algo(&self) -> IgpAlgoType;
+
     fn sid(&self) -> Sid;
+
+    fn sid_mut(&mut self) -> &mut Sid;
 }
 
 // OSPF version-specific code.
@@ -41,6 +41,7 @@ bitflags! {
         const MS = 0x01;
         const M = 0x02;
         const I = 0x04;
+        const R = 0x08;
     }
 }
 
@@ -176,6 +177,9 @@ where
 
     // Return whether the L-bit is set.
     fn l_bit(&self) -> bool;
+
+    // Return whether the N-bit is set.
+    fn n_bit(&self) -> bool;
 }
 
 // OSPF version-specific code.
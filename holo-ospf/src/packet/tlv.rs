@@ -18,7 +18,10 @@ use num_traits::{FromPrimitive, ToPrimitive};
 use serde::{Deserialize, Serialize};
 
 use crate::packet::error::{DecodeError, DecodeResult};
-use crate::packet::iana::{RouterFuncCaps, RouterInfoCaps, RouterInfoTlvType};
+use crate::packet::iana::{
+    AslaSabmFlags, FadFlags, FadStlvType, RouterFuncCaps, RouterInfoCaps,
+    RouterInfoTlvType,
+};
 
 // TLV header size.
 pub const TLV_HDR_SIZE: u16 = 4;
@@ -211,6 +214,141 @@ pub struct MsdTlv(BTreeMap<u8, u8>);
 #[derive(Deserialize, Serialize)]
 pub struct SrmsPrefTlv(u8);
 
+//
+// Flooding Algorithm TLV.
+//
+// Encoding format:
+//
+//  0                   1                   2                   3
+//  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |              Type             |             Length            |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |   Algorithm   |                   Reserved                    |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+//
+#[derive(Clone, Debug, Default, Eq, new, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub struct FloodingAlgoTlv(u8);
+
+//
+// Flexible Algorithm Definition (FAD) TLV.
+//
+// Encoding format:
+//
+//  0                   1                   2                   3
+//  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |              Type             |             Length            |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |Flex-Algorithm |  Metric-Type  |   Calc-Type   |    Priority   |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |                     Sub-TLVs                                  |
+// +                                                               +
+// |                 ...                                           |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+//
+#[derive(Clone, Debug, Eq, new, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub struct FadTlv {
+    pub flex_algo: u8,
+    pub metric_type: u8,
+    pub calc_type: u8,
+    pub priority: u8,
+    #[new(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exclude_admin_group: Option<ExtAdminGroup>,
+    #[new(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include_any_admin_group: Option<ExtAdminGroup>,
+    #[new(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include_all_admin_group: Option<ExtAdminGroup>,
+    #[new(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flags: Option<FadFlags>,
+    #[new(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude_srlgs: Vec<u32>,
+    #[new(default)]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unknown_stlvs: Vec<UnknownTlv>,
+}
+
+//
+// Extended Administrative Group.
+//
+// Variable-length bitmask (RFC 7308), stored as a sequence of 32-bit words.
+// A traditional 32-bit Administrative Group maps to a single-word extended
+// group.
+//
+#[derive(Clone, Debug, Default, Eq, new, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub struct ExtAdminGroup(Vec<u32>);
+
+//
+// Application-Specific Link Attributes (ASLA) Sub-TLV.
+//
+// Encoding format:
+//
+//  0                   1                   2                   3
+//  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |              Type             |             Length            |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// | SABM Length   | UDABM Length  |            Reserved           |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |   Standard Application Identifier Bit Mask (variable)        |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |   User-Defined Application Identifier Bit Mask (variable)    |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |                   Link Attribute Sub-TLVs                     |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+//
+// The Link Attribute Sub-TLVs use the code points of the enclosing TLV's
+// Sub-TLV registry, which differ between OSPFv2 and OSPFv3.
+//
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub struct AslaStlv {
+    // A zero-length SABM applies to all applications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sabm: Option<AslaSabmFlags>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub udabm: Vec<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub admin_group: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ext_admin_group: Option<ExtAdminGroup>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub te_metric: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_max_delay: Option<MinMaxLinkDelay>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub srlgs: Vec<u32>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unknown_stlvs: Vec<UnknownTlv>,
+}
+
+// Min/Max Unidirectional Link Delay (RFC 7471), in microseconds.
+#[derive(Clone, Copy, Debug, Eq, new, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub struct MinMaxLinkDelay {
+    pub anomalous: bool,
+    pub min: u32,
+    pub max: u32,
+}
+
+// Code points of the Link Attribute Sub-TLVs that can be carried in the
+// ASLA Sub-TLV.
+pub trait LinkAttrStlvCodes {
+    const SRLG: u16;
+    const MIN_MAX_LINK_DELAY: u16;
+    const ADMIN_GROUP: u16;
+    const EXT_ADMIN_GROUP: u16;
+    const TE_METRIC: u16;
+}
+
 // Prefix-SID Flags.
 //
 // For simplicity, use a shared struct for both OSPFv2 and OSPFv3 since the
@@ -606,7 +744,7 @@ impl SrAlgoTlv {
     pub(crate) fn encode(&self, buf: &mut BytesMut) {
         let start_pos = tlv_encode_start(buf, RouterInfoTlvType::SrAlgo);
         for algo in &self.0 {
-            buf.put_u8(*algo as u8);
+            buf.put_u8(algo.to_u8().unwrap());
         }
         tlv_encode_end(buf, start_pos);
     }
@@ -931,6 +1069,381 @@ impl SrmsPrefTlv {
     }
 }
 
+// ===== impl FloodingAlgoTlv =====
+
+impl FloodingAlgoTlv {
+    pub(crate) fn decode(tlv_len: u16, buf: &mut Bytes) -> DecodeResult<Self> {
+        // Validate TLV length.
+        if tlv_len != 4 {
+            return Err(DecodeError::InvalidTlvLength(tlv_len));
+        }
+
+        let algo = buf.try_get_u8()?;
+        let _reserved = buf.try_get_u24()?;
+
+        Ok(FloodingAlgoTlv(algo))
+    }
+
+    pub(crate) fn encode(&self, buf: &mut BytesMut) {
+        let start_pos = tlv_encode_start(buf, RouterInfoTlvType::FloodingAlgo);
+        buf.put_u8(self.0);
+        let reserved = 0;
+        buf.put_u24(reserved);
+        tlv_encode_end(buf, start_pos);
+    }
+
+    pub(crate) fn get(&self) -> u8 {
+        self.0
+    }
+}
+
+// ===== impl FadTlv =====
+
+impl FadTlv {
+    const BASE_LENGTH: u16 = 4;
+
+    pub(crate) fn decode(tlv_len: u16, buf: &mut Bytes) -> DecodeResult<Self> {
+        // Validate the TLV length.
+        if tlv_len < Self::BASE_LENGTH {
+            return Err(DecodeError::InvalidTlvLength(tlv_len));
+        }
+
+        let flex_algo = buf.try_get_u8()?;
+        let metric_type = buf.try_get_u8()?;
+        let calc_type = buf.try_get_u8()?;
+        let priority = buf.try_get_u8()?;
+        let mut fad = FadTlv::new(flex_algo, metric_type, calc_type, priority);
+
+        // Parse Sub-TLVs.
+        let mut buf = buf.copy_to_bytes((tlv_len - Self::BASE_LENGTH) as usize);
+        while buf.remaining() >= TLV_HDR_SIZE as usize {
+            // Parse Sub-TLV type.
+            let stlv_type = buf.try_get_u16()?;
+            let stlv_etype = FadStlvType::from_u16(stlv_type);
+
+            // Parse and validate Sub-TLV length.
+            let stlv_len = buf.try_get_u16()?;
+            let stlv_wlen = tlv_wire_len(stlv_len);
+            if stlv_wlen as usize > buf.remaining() {
+                return Err(DecodeError::InvalidTlvLength(stlv_len));
+            }
+
+            // Parse Sub-TLV value.
+            let mut buf_stlv = buf.copy_to_bytes(stlv_wlen as usize);
+            match stlv_etype {
+                Some(FadStlvType::ExcludeAdminGroup) => {
+                    let ag = ExtAdminGroup::decode(stlv_len, &mut buf_stlv)?;
+                    fad.exclude_admin_group.get_or_insert(ag);
+                }
+                Some(FadStlvType::IncludeAnyAdminGroup) => {
+                    let ag = ExtAdminGroup::decode(stlv_len, &mut buf_stlv)?;
+                    fad.include_any_admin_group.get_or_insert(ag);
+                }
+                Some(FadStlvType::IncludeAllAdminGroup) => {
+                    let ag = ExtAdminGroup::decode(stlv_len, &mut buf_stlv)?;
+                    fad.include_all_admin_group.get_or_insert(ag);
+                }
+                Some(FadStlvType::Flags) => {
+                    if stlv_len == 0 {
+                        return Err(DecodeError::InvalidTlvLength(stlv_len));
+                    }
+                    let flags = buf_stlv.try_get_u8()?;
+                    let flags = FadFlags::from_bits_truncate(flags);
+                    fad.flags.get_or_insert(flags);
+                }
+                Some(FadStlvType::ExcludeSrlg) => {
+                    if !stlv_len.is_multiple_of(4) {
+                        return Err(DecodeError::InvalidTlvLength(stlv_len));
+                    }
+                    for _ in 0..stlv_len / 4 {
+                        let srlg = buf_stlv.try_get_u32()?;
+                        fad.exclude_srlgs.push(srlg);
+                    }
+                }
+                None => {
+                    // Save unknown Sub-TLV.
+                    fad.unknown_stlvs
+                        .push(UnknownTlv::new(stlv_type, stlv_len, buf_stlv));
+                }
+            }
+        }
+
+        Ok(fad)
+    }
+
+    pub(crate) fn encode(&self, buf: &mut BytesMut) {
+        let start_pos =
+            tlv_encode_start(buf, RouterInfoTlvType::FlexAlgoDefinition);
+        buf.put_u8(self.flex_algo);
+        buf.put_u8(self.metric_type);
+        buf.put_u8(self.calc_type);
+        buf.put_u8(self.priority);
+        if let Some(ag) = &self.exclude_admin_group {
+            ag.encode(FadStlvType::ExcludeAdminGroup, buf);
+        }
+        if let Some(ag) = &self.include_any_admin_group {
+            ag.encode(FadStlvType::IncludeAnyAdminGroup, buf);
+        }
+        if let Some(ag) = &self.include_all_admin_group {
+            ag.encode(FadStlvType::IncludeAllAdminGroup, buf);
+        }
+        if let Some(flags) = &self.flags {
+            let start_pos = tlv_encode_start(buf, FadStlvType::Flags);
+            buf.put_u8(flags.bits());
+            tlv_encode_end(buf, start_pos);
+        }
+        if !self.exclude_srlgs.is_empty() {
+            let start_pos = tlv_encode_start(buf, FadStlvType::ExcludeSrlg);
+            for srlg in &self.exclude_srlgs {
+                buf.put_u32(*srlg);
+            }
+            tlv_encode_end(buf, start_pos);
+        }
+        tlv_encode_end(buf, start_pos);
+    }
+}
+
+// ===== impl ExtAdminGroup =====
+
+impl ExtAdminGroup {
+    pub(crate) fn decode(tlv_len: u16, buf: &mut Bytes) -> DecodeResult<Self> {
+        // Validate the TLV length.
+        if !tlv_len.is_multiple_of(4) {
+            return Err(DecodeError::InvalidTlvLength(tlv_len));
+        }
+
+        let mut words = vec![];
+        for _ in 0..tlv_len / 4 {
+            let word = buf.try_get_u32()?;
+            words.push(word);
+        }
+
+        Ok(ExtAdminGroup(words))
+    }
+
+    pub(crate) fn encode(
+        &self,
+        tlv_type: impl ToPrimitive,
+        buf: &mut BytesMut,
+    ) {
+        let start_pos = tlv_encode_start(buf, tlv_type);
+        for word in &self.0 {
+            buf.put_u32(*word);
+        }
+        tlv_encode_end(buf, start_pos);
+    }
+
+    // Builds an extended administrative group from a set of bit positions.
+    pub(crate) fn from_bits(bits: &BTreeSet<u16>) -> Self {
+        let mut words = vec![];
+        for bit in bits {
+            let word = (*bit / 32) as usize;
+            if words.len() <= word {
+                words.resize(word + 1, 0);
+            }
+            words[word] |= 1 << (*bit % 32);
+        }
+        ExtAdminGroup(words)
+    }
+
+    // Returns whether any bit is set in both groups.
+    pub(crate) fn intersects(&self, other: &ExtAdminGroup) -> bool {
+        self.0.iter().zip(other.0.iter()).any(|(a, b)| a & b != 0)
+    }
+
+    // Returns whether all bits set in `other` are also set in this group.
+    pub(crate) fn contains(&self, other: &ExtAdminGroup) -> bool {
+        other.0.iter().enumerate().all(|(i, b)| {
+            let a = self.0.get(i).copied().unwrap_or(0);
+            a & b == *b
+        })
+    }
+}
+
+// ===== impl AslaStlv =====
+
+impl AslaStlv {
+    const BASE_LENGTH: u16 = 4;
+    const MAX_MASK_LENGTH: u8 = 8;
+
+    pub(crate) fn decode<C: LinkAttrStlvCodes>(
+        tlv_len: u16,
+        buf: &mut Bytes,
+    ) -> DecodeResult<Option<Self>> {
+        // Validate the TLV length.
+        if tlv_len < Self::BASE_LENGTH {
+            return Err(DecodeError::InvalidTlvLength(tlv_len));
+        }
+
+        let sabm_len = buf.try_get_u8()?;
+        let udabm_len = buf.try_get_u8()?;
+        let _reserved = buf.try_get_u16()?;
+
+        // RFC 8920 - Section 3.1:
+        // "If the SABM or UDABM Length is not a multiple of 4 or exceeds 8,
+        // the ASLA Sub-TLV MUST be ignored".
+        if !sabm_len.is_multiple_of(4)
+            || !udabm_len.is_multiple_of(4)
+            || sabm_len > Self::MAX_MASK_LENGTH
+            || udabm_len > Self::MAX_MASK_LENGTH
+        {
+            return Ok(None);
+        }
+        let masks_len = sabm_len as u16 + udabm_len as u16;
+        if tlv_len < Self::BASE_LENGTH + masks_len {
+            return Err(DecodeError::InvalidTlvLength(tlv_len));
+        }
+
+        let mut asla = AslaStlv::default();
+
+        // Parse application bit masks.
+        if sabm_len > 0 {
+            let mut sabm = buf.copy_to_bytes(sabm_len as usize);
+            let bits = sabm.try_get_u8()?;
+            asla.sabm = Some(AslaSabmFlags::from_bits_truncate(bits));
+        }
+        asla.udabm = buf.copy_to_bytes(udabm_len as usize).to_vec();
+
+        // Parse Link Attribute Sub-TLVs.
+        let mut buf = buf
+            .copy_to_bytes((tlv_len - Self::BASE_LENGTH - masks_len) as usize);
+        while buf.remaining() >= TLV_HDR_SIZE as usize {
+            // Parse Sub-TLV type.
+            let stlv_type = buf.try_get_u16()?;
+
+            // Parse and validate Sub-TLV length.
+            let stlv_len = buf.try_get_u16()?;
+            let stlv_wlen = tlv_wire_len(stlv_len);
+            if stlv_wlen as usize > buf.remaining() {
+                return Err(DecodeError::InvalidTlvLength(stlv_len));
+            }
+
+            // Parse Sub-TLV value.
+            let mut buf_stlv = buf.copy_to_bytes(stlv_wlen as usize);
+            match stlv_type {
+                t if t == C::ADMIN_GROUP => {
+                    if stlv_len != 4 {
+                        return Err(DecodeError::InvalidTlvLength(stlv_len));
+                    }
+                    let ag = buf_stlv.try_get_u32()?;
+                    asla.admin_group.get_or_insert(ag);
+                }
+                t if t == C::EXT_ADMIN_GROUP => {
+                    let ag = ExtAdminGroup::decode(stlv_len, &mut buf_stlv)?;
+                    asla.ext_admin_group.get_or_insert(ag);
+                }
+                t if t == C::TE_METRIC => {
+                    if stlv_len != 4 {
+                        return Err(DecodeError::InvalidTlvLength(stlv_len));
+                    }
+                    let metric = buf_stlv.try_get_u32()?;
+                    asla.te_metric.get_or_insert(metric);
+                }
+                t if t == C::MIN_MAX_LINK_DELAY => {
+                    if stlv_len != 8 {
+                        return Err(DecodeError::InvalidTlvLength(stlv_len));
+                    }
+                    let min = buf_stlv.try_get_u32()?;
+                    let max = buf_stlv.try_get_u32()?;
+                    let anomalous = (min & 0x80000000) != 0;
+                    let delay = MinMaxLinkDelay::new(
+                        anomalous,
+                        min & 0x00FFFFFF,
+                        max & 0x00FFFFFF,
+                    );
+                    asla.min_max_delay.get_or_insert(delay);
+                }
+                t if t == C::SRLG => {
+                    if !stlv_len.is_multiple_of(4) {
+                        return Err(DecodeError::InvalidTlvLength(stlv_len));
+                    }
+                    for _ in 0..stlv_len / 4 {
+                        let srlg = buf_stlv.try_get_u32()?;
+                        asla.srlgs.push(srlg);
+                    }
+                }
+                _ => {
+                    // Save unknown Sub-TLV.
+                    asla.unknown_stlvs
+                        .push(UnknownTlv::new(stlv_type, stlv_len, buf_stlv));
+                }
+            }
+        }
+
+        Ok(Some(asla))
+    }
+
+    pub(crate) fn encode<C: LinkAttrStlvCodes>(
+        &self,
+        stlv_type: impl ToPrimitive,
+        buf: &mut BytesMut,
+    ) {
+        let start_pos = tlv_encode_start(buf, stlv_type);
+        let sabm_len = if self.sabm.is_some() { 4 } else { 0 };
+        buf.put_u8(sabm_len);
+        buf.put_u8(self.udabm.len() as u8);
+        buf.put_u16(0);
+        if let Some(sabm) = &self.sabm {
+            buf.put_u8(sabm.bits());
+            buf.put_bytes(0, 3);
+        }
+        buf.put_slice(&self.udabm);
+        if let Some(ag) = &self.admin_group {
+            let start_pos = tlv_encode_start(buf, C::ADMIN_GROUP);
+            buf.put_u32(*ag);
+            tlv_encode_end(buf, start_pos);
+        }
+        if let Some(ag) = &self.ext_admin_group {
+            ag.encode(C::EXT_ADMIN_GROUP, buf);
+        }
+        if let Some(metric) = &self.te_metric {
+            let start_pos = tlv_encode_start(buf, C::TE_METRIC);
+            buf.put_u32(*metric);
+            tlv_encode_end(buf, start_pos);
+        }
+        if let Some(delay) = &self.min_max_delay {
+            let start_pos = tlv_encode_start(buf, C::MIN_MAX_LINK_DELAY);
+            let mut min = delay.min & 0x00FFFFFF;
+            if delay.anomalous {
+                min |= 0x80000000;
+            }
+            buf.put_u32(min);
+            buf.put_u32(delay.max & 0x00FFFFFF);
+            tlv_encode_end(buf, start_pos);
+        }
+        if !self.srlgs.is_empty() {
+            let start_pos = tlv_encode_start(buf, C::SRLG);
+            for srlg in &self.srlgs {
+                buf.put_u32(*srlg);
+            }
+            tlv_encode_end(buf, start_pos);
+        }
+        tlv_encode_end(buf, start_pos);
+    }
+
+    // Returns the ASLA Sub-TLV applicable to the given application.
+    //
+    // Sub-TLVs that explicitly list the application take precedence over the
+    // ones advertised with a zero-length SABM (RFC 8920, Section 6.1).
+    pub(crate) fn find(
+        aslas: &[AslaStlv],
+        app: AslaSabmFlags,
+    ) -> Option<&AslaStlv> {
+        aslas
+            .iter()
+            .find(|asla| asla.sabm.is_some_and(|sabm| sabm.contains(app)))
+            .or_else(|| aslas.iter().find(|asla| asla.sabm.is_none()))
+    }
+
+    // Returns the link's administrative group, preferring the Extended
+    // Administrative Group when both are present (RFC 7308).
+    pub(crate) fn admin_group(&self) -> Option<ExtAdminGroup> {
+        self.ext_admin_group
+            .clone()
+            .or_else(|| self.admin_group.map(|ag| ExtAdminGroup(vec![ag])))
+    }
+}
+
 // ===== global functions =====
 
 // The TLV length is padded to 4-byte alignment.
@@ -11,22 +11,29 @@ use std::net::Ipv4Addr;
 use bitflags::bitflags;
 use derive_new::new;
 use holo_utils::bier::BierInfo;
-use holo_utils::ip::IpAddrKind;
+use holo_utils::ip::{IpAddrExt, IpAddrKind, IpNetworkKind};
 use holo_utils::mpls::Label;
-use holo_utils::southbound::OspfRouteType;
+use holo_utils::policy::{
+    MatchSets, MetricModification, MetricType, Policy, PolicyAction,
+    PolicyCondition,
+};
+use holo_utils::protocol::Protocol;
+use holo_utils::southbound::{self, OspfRouteType};
 use holo_utils::sr::IgpAlgoType;
+use ipnetwork::IpNetwork;
 
-use crate::area::{Area, BACKBONE_AREA_ID};
+use crate::area::{Area, AreaType, BACKBONE_AREA_ID};
 use crate::collections::{Areas, Arena, InterfaceIndex};
 use crate::debug::Debug;
 use crate::instance::InstanceUpView;
-use crate::interface::Interface;
+use crate::interface::{Interface, InterfaceType};
+use crate::lfa::BackupNexthop;
 use crate::lsdb::{LSA_INFINITY, LsaEntry};
 use crate::northbound::configuration::InstanceCfg;
 use crate::packet::lsa::{LsaKey, LsaRouterFlagsVersion};
-use crate::spf::{SpfPartialComputation, VertexLsaVersion};
+use crate::spf::{SpfPartialComputation, Vertex, VertexLsaVersion};
 use crate::version::Version;
-use crate::{bier, ibus, sr};
+use crate::{bier, ibus, lfa, sr};
 
 // Network routing table entry.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -41,6 +48,7 @@ pub struct RouteNet<V: Version> {
     pub prefix_sid: Option<V::PrefixSid>,
     pub sr_label: Option<Label>,
     pub nexthops: Nexthops<V::IpAddr>,
+    pub backup: Option<BackupNexthop<V::IpAddr>>,
     pub flags: RouteNetFlags,
     pub bier_info: Option<BierInfo>,
 }
@@ -51,6 +59,7 @@ bitflags! {
         const CONNECTED = 0x01;
         const INSTALLED = 0x02;
         const SUMMARIZED = 0x04;
+        const FRR_ENABLED = 0x08;
     }
 }
 
@@ -88,6 +97,34 @@ pub struct SummaryRtr<V: Version> {
     pub metric: u32,
 }
 
+// Locally originated AS-external or NSSA "network" route.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExternalNet<V: Version> {
+    pub prefix_options: V::PrefixOptions,
+    pub e_bit: bool,
+    pub metric: u32,
+    pub fwd_addr: Option<V::IpAddr>,
+    pub tag: Option<u32>,
+    // Whether the P-bit should be set (NSSA-LSAs only).
+    pub propagate: bool,
+}
+
+// Route redistributed from the global RIB.
+#[derive(Clone, Debug)]
+pub struct RouteSys {
+    pub protocol: Protocol,
+    pub metric: u32,
+    pub tag: Option<u32>,
+    pub nexthops: BTreeSet<southbound::Nexthop>,
+}
+
+// AS-external metric type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ExternalMetricType {
+    Type1,
+    Type2,
+}
+
 // Route nexthop key.
 #[derive(Clone, Copy, Debug, Eq, new, Ord, PartialEq, PartialOrd)]
 pub struct NexthopKey<I: IpAddrKind> {
@@ -125,17 +162,20 @@ where
         match self.path_type {
             PathType::IntraArea => config.preference.intra_area,
             PathType::InterArea => config.preference.inter_area,
-            PathType::Type1External | PathType::Type2External => {
-                config.preference.external
-            }
+            PathType::Type1External
+            | PathType::Type2External
+            | PathType::Nssa1
+            | PathType::Nssa2 => config.preference.external,
         }
     }
 
     pub(crate) fn metric(&self) -> u32 {
         match self.path_type {
             PathType::IntraArea | PathType::InterArea => self.metric,
-            PathType::Type1External => self.metric,
-            PathType::Type2External => self.type2_metric.unwrap(),
+            PathType::Type1External | PathType::Nssa1 => self.metric,
+            PathType::Type2External | PathType::Nssa2 => {
+                self.type2_metric.unwrap()
+            }
         }
     }
 }
@@ -188,6 +228,15 @@ pub(crate) fn update_rib_full<V>(
 
     // Compute external routes.
     update_rib_external(&mut rib, None, instance, areas, lsa_entries);
+    for area in areas
+        .iter()
+        .filter(|area| area.config.area_type == AreaType::Nssa)
+    {
+        update_rib_nssa(&mut rib, None, None, area, instance, lsa_entries);
+    }
+
+    // Compute IP Fast Reroute backup nexthops.
+    lfa::update_backups(&mut rib, instance, areas, interfaces, lsa_entries);
 
     // Update OSPF routes in the global RIB.
     update_global_rib(&mut rib, old_rib, instance, interfaces);
@@ -310,7 +359,10 @@ pub(crate) fn update_rib_partial<V>(
             (reevaluate_all || partial.external.contains(prefix))
                 && matches!(
                     route.path_type,
-                    PathType::Type1External | PathType::Type2External
+                    PathType::Type1External
+                        | PathType::Type2External
+                        | PathType::Nssa1
+                        | PathType::Nssa2
                 )
         }));
 
@@ -327,8 +379,30 @@ pub(crate) fn update_rib_partial<V>(
             areas,
             lsa_entries,
         );
+        for area in areas
+            .iter()
+            .filter(|area| area.config.area_type == AreaType::Nssa)
+        {
+            update_rib_nssa(
+                &mut partial_rib,
+                Some(&rib),
+                filter,
+                area,
+                instance,
+                lsa_entries,
+            );
+        }
     }
 
+    // Compute IP Fast Reroute backup nexthops.
+    lfa::update_backups(
+        &mut partial_rib,
+        instance,
+        areas,
+        interfaces,
+        lsa_entries,
+    );
+
     // Update OSPF routes in the global RIB.
     update_global_rib(&mut partial_rib, old_rib, instance, interfaces);
 
@@ -337,6 +411,147 @@ pub(crate) fn update_rib_partial<V>(
     instance.state.rib = rib;
 }
 
+// Updates the routing tables of the Flexible Algorithms this router
+// participates in.
+//
+// Only intra-area destinations are computed. Since IP forwarding always
+// follows the default algorithm, Flexible Algorithm routes are installed in
+// the form of Prefix-SID label entries only.
+pub(crate) fn update_rib_flex_algo<V>(
+    instance: &mut InstanceUpView<'_, V>,
+    areas: &Areas<V>,
+    interfaces: &Arena<Interface<V>>,
+    lsa_entries: &Arena<LsaEntry<V>>,
+) where
+    V: Version,
+{
+    let mut old_flex_algo_rib =
+        std::mem::take(&mut instance.state.flex_algo_rib);
+    let mut flex_algo_rib = BTreeMap::new();
+
+    for algo in instance.config.flex_algo.keys() {
+        let mut rib = BTreeMap::new();
+
+        // Compute intra-area routes.
+        for area in areas.iter() {
+            let Some(spt) = area.state.flex_algo_spt.get(algo) else {
+                continue;
+            };
+            update_rib_flex_algo_area(
+                &mut rib,
+                *algo,
+                spt,
+                area,
+                instance,
+                lsa_entries,
+            );
+        }
+
+        // Update Prefix-SID label entries.
+        let old_rib = old_flex_algo_rib.remove(algo).unwrap_or_default();
+        update_flex_algo_labels(&mut rib, old_rib, instance, interfaces);
+
+        flex_algo_rib.insert(*algo, rib);
+    }
+
+    // Uninstall the label entries of algorithms that are no longer
+    // configured.
+    for old_rib in old_flex_algo_rib.into_values() {
+        update_flex_algo_labels(
+            &mut BTreeMap::new(),
+            old_rib,
+            instance,
+            interfaces,
+        );
+    }
+
+    // Save updated routing tables.
+    instance.state.flex_algo_rib = flex_algo_rib;
+}
+
+// Computes the AS-external routes that should be originated as a result of
+// route redistribution and default route origination.
+pub(crate) fn redistributed_externals<V>(
+    instance: &InstanceUpView<'_, V>,
+    interfaces: &Arena<Interface<V>>,
+) -> BTreeMap<V::IpNetwork, ExternalNet<V>>
+where
+    V: Version,
+{
+    let default_prefix = V::IpNetwork::default(instance.state.af);
+    let mut default_present = false;
+    let mut externals = BTreeMap::new();
+
+    for (prefix, route) in &instance.system.routes {
+        // Skip routes from other address families.
+        let Some(prefix) = V::IpNetwork::get(*prefix)
+            .filter(|prefix| prefix.address_family() == instance.state.af)
+        else {
+            continue;
+        };
+
+        // The default route is only originated through the
+        // "default-information-originate" configuration.
+        if prefix == default_prefix {
+            default_present = true;
+            continue;
+        }
+
+        // Get redistribution configuration for the route's protocol.
+        let Some(redist_cfg) =
+            instance.config.redistribution.get(&route.protocol)
+        else {
+            continue;
+        };
+
+        let mut external = ExternalNet {
+            prefix_options: Default::default(),
+            e_bit: redist_cfg.metric_type == ExternalMetricType::Type2,
+            metric: redist_cfg.metric.unwrap_or(route.metric),
+            fwd_addr: external_fwd_addr(route, interfaces),
+            tag: redist_cfg.tag.or(route.tag),
+            propagate: false,
+        };
+
+        // Apply the configured routing policy, if any. Routes are not
+        // redistributed while the referenced policy isn't defined.
+        if let Some(policy_name) = &redist_cfg.route_policy {
+            let Some(policy) = instance.shared.policies.get(policy_name) else {
+                continue;
+            };
+            if !redistribution_policy_apply(
+                policy,
+                &instance.shared.policy_match_sets,
+                prefix.into(),
+                route,
+                &mut external,
+            ) {
+                continue;
+            }
+        }
+        external.metric = std::cmp::min(external.metric, LSA_INFINITY - 1);
+
+        externals.insert(prefix, external);
+    }
+
+    // Originate the default route if configured to do so.
+    if let Some(default_cfg) = &instance.config.default_info_originate
+        && (default_cfg.always || default_present)
+    {
+        let external = ExternalNet {
+            prefix_options: Default::default(),
+            e_bit: default_cfg.metric_type == ExternalMetricType::Type2,
+            metric: default_cfg.metric,
+            fwd_addr: None,
+            tag: None,
+            propagate: false,
+        };
+        externals.insert(default_prefix, external);
+    }
+
+    externals
+}
+
 // ===== helper functions =====
 
 // Computes intra-area routes.
@@ -349,6 +564,14 @@ fn update_rib_intra_area<V>(
 ) where
     V: Version,
 {
+    // Collect the prefix ranges advertised by SR Mapping Servers.
+    let sr_mappings =
+        if instance.config.sr_enabled && instance.config.sr_ms_receive {
+            sr::mapping_server_entries(area)
+        } else {
+            vec![]
+        };
+
     // Iterate over all stub networks and their corresponding vertices.
     let extended_lsa = instance.config.extended_lsa;
     for stub in V::intra_area_networks(area, extended_lsa, lsa_entries)
@@ -415,13 +638,22 @@ fn update_rib_intra_area<V>(
             prefix_sid: None,
             sr_label: None,
             nexthops: stub.vertex.nexthops.clone(),
+            backup: None,
             flags,
             bier_info: None,
         };
 
-        // Update route's Prefix-SID (if any).
+        // Update route's Prefix-SID (if any). Prefix-SIDs advertised by the
+        // prefix originator take precedence over the ones advertised by SR
+        // Mapping Servers.
         if instance.config.sr_enabled
-            && let Some(prefix_sid) = stub.prefix_sids.get(&IgpAlgoType::Spf)
+            && let Some(prefix_sid) = stub
+                .prefix_sids
+                .get(&IgpAlgoType::Spf)
+                .copied()
+                .or_else(|| {
+                    sr::mapping_server_lookup(&sr_mappings, stub.prefix)
+                })
         {
             let local = stub.vertex.hops == 0;
             let last_hop = stub.vertex.hops == 1;
@@ -430,7 +662,7 @@ fn update_rib_intra_area<V>(
                 instance,
                 origin.adv_rtr,
                 &mut new_route,
-                prefix_sid,
+                &prefix_sid,
                 local,
                 last_hop,
                 lsa_entries,
@@ -507,6 +739,7 @@ fn update_rib_inter_area_networks<V>(
             prefix_sid: None,
             sr_label: None,
             nexthops: route_br.nexthops.clone(),
+            backup: None,
             flags: RouteNetFlags::empty(),
             bier_info: None,
         };
@@ -596,6 +829,7 @@ fn update_rib_transit_area<V>(
             prefix_sid: None,
             sr_label: None,
             nexthops: route_br.nexthops.clone(),
+            backup: None,
             flags: RouteNetFlags::empty(),
             bier_info: None,
         };
@@ -840,6 +1074,7 @@ fn update_rib_external<V>(
             prefix_sid: None,
             sr_label: None,
             nexthops: route_asbr.nexthops.clone(),
+            backup: None,
             flags: RouteNetFlags::empty(),
             bier_info: None,
         };
@@ -849,6 +1084,129 @@ fn update_rib_external<V>(
     }
 }
 
+// Computes NSSA external routes (RFC 3101 - Section 2.5).
+//
+// When `lookup_rib` is provided, forwarding addresses are resolved using it
+// instead of the routing table being updated.
+fn update_rib_nssa<V>(
+    rib: &mut BTreeMap<V::IpNetwork, RouteNet<V>>,
+    lookup_rib: Option<&BTreeMap<V::IpNetwork, RouteNet<V>>>,
+    filter: Option<&BTreeSet<V::IpNetwork>>,
+    area: &Area<V>,
+    instance: &InstanceUpView<'_, V>,
+    lsa_entries: &Arena<LsaEntry<V>>,
+) where
+    V: Version,
+{
+    // Examine all Type-7 LSAs.
+    let extended_lsa = instance.config.extended_lsa;
+    let router_id = instance.state.router_id;
+    for lsa in V::nssa_networks(&area.state.lsdb, extended_lsa, lsa_entries)
+        // Filter out unreachable LSAs.
+        .filter(|lsa| lsa.metric < LSA_INFINITY)
+        // Filter out LSAs originated by the calculating router itself.
+        .filter(|lsa| lsa.adv_rtr != router_id)
+        // Filter prefixes when running partial SPF.
+        .filter(|lsa| {
+            if let Some(filter) = filter {
+                filter.contains(&lsa.prefix)
+            } else {
+                true
+            }
+        })
+    {
+        // Look up the intra-area routing table entry for the NSSA ASBR (or the
+        // NSSA border router originating a default route) that originated the
+        // LSA.
+        let Some(route_asbr) = area
+            .state
+            .routers
+            .get(&lsa.adv_rtr)
+            .filter(|route| route.path_type == PathType::IntraArea)
+            .filter(|route| route.flags.is_asbr() || route.flags.is_abr())
+        else {
+            // If no such entry exists, do nothing with this LSA and consider
+            // the next in the list.
+            if instance.config.trace_opts.spf {
+                Debug::<V>::SpfUnreachableAsbr(&lsa.prefix, lsa.adv_rtr).log();
+            }
+            continue;
+        };
+
+        // RFC 3101 - Section 2.5 (3):
+        // "If the forwarding address is non-zero, look up the forwarding
+        // address in the routing table. The matching routing table entry
+        // must specify an intra-area path through the Type-7 LSA's area; if
+        // no such path exists then do nothing with this LSA and consider the
+        // next in the list".
+        let (fwd_metric, nexthops) = match lsa.fwd_addr {
+            Some(fwd_addr) => {
+                let lookup_rib = lookup_rib.unwrap_or(rib);
+                let Some(route_fwd) =
+                    nssa_fwd_addr_lookup(lookup_rib, fwd_addr, area)
+                else {
+                    continue;
+                };
+                (route_fwd.metric, route_fwd.nexthops.clone())
+            }
+            None => (route_asbr.metric, route_asbr.nexthops.clone()),
+        };
+
+        // Get path type and metric.
+        let (path_type, metric, type2_metric) = match lsa.e_bit {
+            true => (PathType::Nssa2, fwd_metric, Some(lsa.metric)),
+            false => {
+                (PathType::Nssa1, fwd_metric.saturating_add(lsa.metric), None)
+            }
+        };
+
+        // Create new NSSA external route.
+        let new_route = RouteNet {
+            prefix_options: lsa.prefix_options,
+            area_id: Some(area.area_id),
+            path_type,
+            origin: None,
+            metric,
+            type2_metric,
+            tag: lsa.tag,
+            prefix_sid: None,
+            sr_label: None,
+            nexthops,
+            backup: None,
+            flags: RouteNetFlags::empty(),
+            bier_info: None,
+        };
+
+        // Try to add or update NSSA external route in the RIB.
+        route_update(rib, lsa.prefix, new_route, instance.config.max_paths);
+    }
+}
+
+// Looks up the forwarding address of a Type-7 LSA in the routing table,
+// returning the best matching entry if it specifies an intra-area path through
+// the LSA's area.
+fn nssa_fwd_addr_lookup<'a, V>(
+    rib: &'a BTreeMap<V::IpNetwork, RouteNet<V>>,
+    fwd_addr: V::IpAddr,
+    area: &Area<V>,
+) -> Option<&'a RouteNet<V>>
+where
+    V: Version,
+{
+    rib.iter()
+        .filter(|(prefix, _)| prefix.contains(fwd_addr))
+        .filter(|(_, route)| route.metric < LSA_INFINITY)
+        .max_by_key(|(prefix, _)| {
+            let prefix: IpNetwork = (**prefix).into();
+            prefix.prefix()
+        })
+        .map(|(_, route)| route)
+        .filter(|route| {
+            route.path_type == PathType::IntraArea
+                && route.area_id == Some(area.area_id)
+        })
+}
+
 // Updates OSPF routes in the global RIB.
 //
 // This step should be done at the end of the routing table calculation to
@@ -876,6 +1234,7 @@ fn update_global_rib<V>(
                 && old_route.tag == route.tag
                 && old_route.sr_label == route.sr_label
                 && old_route.nexthops == route.nexthops
+                && old_route.backup == route.backup
             {
                 if old_route.flags.contains(RouteNetFlags::INSTALLED) {
                     route.flags.insert(RouteNetFlags::INSTALLED);
@@ -915,6 +1274,134 @@ fn update_global_rib<V>(
     }
 }
 
+// Computes the intra-area routes of a Flexible Algorithm.
+fn update_rib_flex_algo_area<V>(
+    rib: &mut BTreeMap<V::IpNetwork, RouteNet<V>>,
+    algo: u8,
+    spt: &BTreeMap<V::VertexId, Vertex<V>>,
+    area: &Area<V>,
+    instance: &InstanceUpView<'_, V>,
+    lsa_entries: &Arena<LsaEntry<V>>,
+) where
+    V: Version,
+{
+    let extended_lsa = instance.config.extended_lsa;
+    for stub in V::intra_area_networks(area, extended_lsa, lsa_entries) {
+        // Only prefixes that have a Prefix-SID for the algorithm are
+        // reachable through the Flexible Algorithm topology.
+        let Some(prefix_sid) =
+            stub.prefix_sids.get(&IgpAlgoType::FlexAlgo(algo))
+        else {
+            continue;
+        };
+
+        // Get the corresponding vertex in the algorithm's topology.
+        let Some(vertex) = spt.get(&stub.vertex.id) else {
+            continue;
+        };
+
+        // Create new route.
+        let origin = vertex.lsa.origin();
+        let metric = vertex.distance.saturating_add(stub.metric.into());
+        let mut flags = RouteNetFlags::empty();
+        if vertex.hops == 0 {
+            flags.insert(RouteNetFlags::CONNECTED);
+        }
+        let mut new_route = RouteNet {
+            prefix_options: stub.prefix_options,
+            area_id: Some(area.area_id),
+            path_type: PathType::IntraArea,
+            origin: Some(origin),
+            metric,
+            type2_metric: None,
+            tag: None,
+            prefix_sid: None,
+            sr_label: None,
+            nexthops: vertex.nexthops.clone(),
+            backup: None,
+            flags,
+            bier_info: None,
+        };
+
+        // Update route's Prefix-SID.
+        let local = vertex.hops == 0;
+        let last_hop = vertex.hops == 1;
+        sr::prefix_sid_update(
+            area,
+            instance,
+            origin.adv_rtr,
+            &mut new_route,
+            prefix_sid,
+            local,
+            last_hop,
+            lsa_entries,
+        );
+        if new_route.prefix_sid.is_none() {
+            continue;
+        }
+
+        // Try to add or update route in the RIB.
+        route_update(rib, stub.prefix, new_route, instance.config.max_paths);
+    }
+}
+
+// Installs or uninstalls the Prefix-SID label entries of a Flexible Algorithm.
+fn update_flex_algo_labels<V>(
+    rib: &mut BTreeMap<V::IpNetwork, RouteNet<V>>,
+    mut old_rib: BTreeMap<V::IpNetwork, RouteNet<V>>,
+    instance: &InstanceUpView<'_, V>,
+    interfaces: &Arena<Interface<V>>,
+) where
+    V: Version,
+{
+    // Install new label entries or label entries that have changed.
+    for (prefix, route) in rib {
+        let mut old_sr_label = None;
+
+        // Remove route from the old RIB if it's present.
+        if let Some(old_route) = old_rib.remove(prefix)
+            && old_route.flags.contains(RouteNetFlags::INSTALLED)
+        {
+            old_sr_label = old_route.sr_label;
+
+            // Skip reinstalling the label entry if it hasn't changed.
+            if old_route.sr_label == route.sr_label
+                && old_route.nexthops == route.nexthops
+            {
+                route.flags.insert(RouteNetFlags::INSTALLED);
+                continue;
+            }
+        }
+
+        if !route.flags.contains(RouteNetFlags::CONNECTED)
+            && !route.nexthops.is_empty()
+            && route.sr_label.is_some()
+        {
+            ibus::tx::flex_algo_label_install(
+                &instance.tx.ibus,
+                route,
+                old_sr_label,
+                interfaces,
+            );
+            route.flags.insert(RouteNetFlags::INSTALLED);
+        } else if let Some(old_sr_label) = old_sr_label {
+            ibus::tx::flex_algo_label_uninstall::<V>(
+                &instance.tx.ibus,
+                old_sr_label,
+            );
+        }
+    }
+
+    // Uninstall label entries that are no longer available.
+    for label in old_rib
+        .into_values()
+        .filter(|route| route.flags.contains(RouteNetFlags::INSTALLED))
+        .filter_map(|route| route.sr_label)
+    {
+        ibus::tx::flex_algo_label_uninstall::<V>(&instance.tx.ibus, label);
+    }
+}
+
 fn route_update<V>(
     rib: &mut BTreeMap<V::IpNetwork, RouteNet<V>>,
     prefix: V::IpNetwork,
@@ -968,19 +1455,21 @@ fn route_compare<V>(a: &RouteNet<V>, b: &RouteNet<V>) -> Ordering
 where
     V: Version,
 {
-    let cmp = a.path_type.cmp(&b.path_type);
+    let cmp = path_type_rank(a.path_type).cmp(&path_type_rank(b.path_type));
     if cmp != Ordering::Equal {
         return cmp;
     }
 
-    match a.path_type {
-        PathType::IntraArea | PathType::InterArea => a.metric.cmp(&b.metric),
-        PathType::Type1External => {
+    let cmp = match a.path_type {
+        PathType::IntraArea | PathType::InterArea => {
+            return a.metric.cmp(&b.metric);
+        }
+        PathType::Type1External | PathType::Nssa1 => {
             // TODO: prefer intra-area paths using non-backbone areas (16.4.1).
 
             a.metric.cmp(&b.metric)
         }
-        PathType::Type2External => {
+        PathType::Type2External | PathType::Nssa2 => {
             let cmp = a.type2_metric.cmp(&b.type2_metric);
             if cmp != Ordering::Equal {
                 return cmp;
@@ -990,5 +1479,159 @@ where
 
             a.metric.cmp(&b.metric)
         }
+    };
+    if cmp != Ordering::Equal {
+        return cmp;
+    }
+
+    // When a Type-5 and a Type-7 LSA provide equally preferred paths, the
+    // Type-5 LSA is preferred (RFC 3101 - Section 2.5, step 6).
+    a.path_type.cmp(&b.path_type)
+}
+
+// Returns the preference of the given path type, where Type-7 routes are
+// ranked the same as their Type-5 counterparts.
+fn path_type_rank(path_type: PathType) -> u8 {
+    match path_type {
+        PathType::IntraArea => 0,
+        PathType::InterArea => 1,
+        PathType::Type1External | PathType::Nssa1 => 2,
+        PathType::Type2External | PathType::Nssa2 => 3,
+    }
+}
+
+// Returns the forwarding address of a redistributed route.
+//
+// The forwarding address is set only when the route's nexthop is reachable
+// through a broadcast or NBMA interface that is running OSPF in non-passive
+// mode. Otherwise, traffic is forwarded to the ASBR itself.
+fn external_fwd_addr<V>(
+    route: &RouteSys,
+    interfaces: &Arena<Interface<V>>,
+) -> Option<V::IpAddr>
+where
+    V: Version,
+{
+    route.nexthops.iter().find_map(|nexthop| {
+        let southbound::Nexthop::Address { ifindex, addr, .. } = nexthop else {
+            return None;
+        };
+
+        // Link-local addresses can't be used as forwarding addresses.
+        if !addr.to_host_prefix().is_routable() {
+            return None;
+        }
+        let addr = V::IpAddr::get(*addr)?;
+
+        interfaces
+            .iter()
+            .map(|(_, iface)| iface)
+            .filter(|iface| iface.system.ifindex == Some(*ifindex))
+            .filter(|iface| !iface.is_down() && !iface.is_passive())
+            .filter(|iface| {
+                matches!(
+                    iface.config.if_type,
+                    InterfaceType::Broadcast | InterfaceType::NonBroadcast
+                )
+            })
+            .any(|iface| {
+                iface.system.addr_list.iter().any(|net| net.contains(addr))
+            })
+            .then_some(addr)
+    })
+}
+
+// Applies the given routing policy to a redistributed route, updating the
+// AS-external route attributes as instructed by the policy actions.
+//
+// Returns a boolean value indicating whether the route was accepted.
+fn redistribution_policy_apply<V>(
+    policy: &Policy,
+    match_sets: &MatchSets,
+    prefix: IpNetwork,
+    route: &RouteSys,
+    external: &mut ExternalNet<V>,
+) -> bool
+where
+    V: Version,
+{
+    for stmt in policy.stmts.values() {
+        // Check if all conditions in the policy statement are satisfied.
+        if !stmt.conditions.values().all(|condition| match condition {
+            // "source-protocol"
+            PolicyCondition::SrcProtocol(protocol) => {
+                route.protocol == *protocol
+            }
+            // "match-prefix-set"
+            PolicyCondition::MatchPrefixSet(value) => {
+                let af = prefix.address_family();
+                match match_sets.prefixes.get(&(value.clone(), af)) {
+                    Some(set) => set.prefixes.iter().any(|range| {
+                        prefix.ip() == range.prefix.ip()
+                            && prefix.prefix() >= range.masklen_lower
+                            && prefix.prefix() <= range.masklen_upper
+                    }),
+                    None => false,
+                }
+            }
+            // "match-tag-set"
+            PolicyCondition::MatchTagSet(value) => {
+                if let Some(tag) = &route.tag
+                    && let Some(set) = match_sets.tags.get(value)
+                {
+                    set.tags.contains(tag)
+                } else {
+                    false
+                }
+            }
+            // Ignore unsupported conditions.
+            _ => true,
+        }) {
+            continue;
+        }
+
+        // Process actions defined in the policy statement.
+        let mut accept = None;
+        for action in stmt.actions.values() {
+            match action {
+                // "policy-result"
+                PolicyAction::Accept(value) => {
+                    accept = Some(*value);
+                }
+                // "set-metric"
+                PolicyAction::SetMetric { value, mod_type } => {
+                    external.metric = match mod_type {
+                        MetricModification::Set => *value,
+                        MetricModification::Add => {
+                            external.metric.saturating_add(*value)
+                        }
+                        MetricModification::Subtract => {
+                            external.metric.saturating_sub(*value)
+                        }
+                    };
+                }
+                // "set-metric-type"
+                PolicyAction::SetMetricType(MetricType::OspfType1) => {
+                    external.e_bit = false;
+                }
+                PolicyAction::SetMetricType(MetricType::OspfType2) => {
+                    external.e_bit = true;
+                }
+                // "set-tag"
+                PolicyAction::SetTag(tag) => {
+                    external.tag = Some(*tag);
+                }
+                // Ignore unsupported actions.
+                _ => (),
+            }
+        }
+
+        // The policy result terminates the policy evaluation.
+        if let Some(accept) = accept {
+            return accept;
+        }
     }
+
+    // Reject routes that aren't explicitly accepted.
+    false
 }
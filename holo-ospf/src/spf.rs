@@ -20,15 +20,18 @@ use crate::area::Area;
 use crate::collections::{Areas, Arena, Lsdb};
 use crate::debug::Debug;
 use crate::error::Error;
+use crate::flex_algo::FlexAlgo;
 use crate::instance::{InstanceArenas, InstanceUpView};
 use crate::interface::Interface;
 use crate::lsdb::{LsaEntry, LsaLogId};
 use crate::neighbor::Neighbor;
 use crate::packet::lsa::{Lsa, LsaKey, LsaRouterFlagsVersion};
-use crate::packet::tlv::{BierStlv, SidLabelRangeTlv, SrAlgoTlv};
+use crate::packet::tlv::{
+    AslaStlv, BierStlv, FadTlv, FloodingAlgoTlv, SidLabelRangeTlv, SrAlgoTlv,
+};
 use crate::route::{Nexthops, PathType, RouteRtr};
 use crate::version::Version;
-use crate::{area, route, tasks};
+use crate::{area, flood, ibus, lfa, route, tasks};
 
 // Maximum size of the SPF log record.
 const SPF_LOG_MAX_SIZE: usize = 32;
@@ -86,6 +89,14 @@ pub struct SpfInterAreaNetwork<V: Version> {
     pub prefix_sids: BTreeMap<IgpAlgoType, V::PrefixSid>,
 }
 
+#[derive(Debug)]
+pub struct SpfPrefixRange<V: Version> {
+    pub adv_rtr: Ipv4Addr,
+    pub prefix: V::IpNetwork,
+    pub range_size: u16,
+    pub prefix_sid: V::PrefixSid,
+}
+
 #[derive(Debug)]
 pub struct SpfInterAreaRouter<V: Version> {
     pub adv_rtr: Ipv4Addr,
@@ -104,12 +115,16 @@ pub struct SpfExternalNetwork<V: Version> {
     pub metric: u32,
     pub fwd_addr: Option<V::IpAddr>,
     pub tag: Option<u32>,
+    // P-bit (NSSA-LSAs only).
+    pub propagate: bool,
 }
 
 #[derive(Debug, Default)]
 pub struct SpfRouterInfo<'a> {
     pub sr_algo: Option<&'a SrAlgoTlv>,
     pub srgb: Vec<&'a SidLabelRangeTlv>,
+    pub fads: Vec<&'a FadTlv>,
+    pub flooding_algo: Option<&'a FloodingAlgoTlv>,
 }
 
 #[derive(Debug, new)]
@@ -127,10 +142,32 @@ pub enum SpfLogType {
     External,
 }
 
+#[derive(Clone, Copy, Debug)]
+pub enum SpfAlgorithm {
+    // Full SPF run, recomputing all shortest-path trees and routes.
+    Full,
+    // Incremental SPF, where only leaf changes (e.g. stub networks) are
+    // processed without recomputing the shortest-path trees.
+    Incremental,
+    // Partial route calculation for summary and external LSA changes
+    // (RFC 2328 - Sections 16.5 and 16.6).
+    Partial,
+}
+
+#[derive(Debug, new)]
+pub struct SpfLogStats {
+    pub algorithm: SpfAlgorithm,
+    // Number of vertices in the shortest-path trees.
+    pub vertex_count: u32,
+    // Number of routes in the routing table.
+    pub route_count: u32,
+}
+
 #[derive(Debug, new)]
 pub struct SpfLogEntry<V: Version> {
     pub id: u32,
     pub spf_type: SpfLogType,
+    pub stats: SpfLogStats,
     pub schedule_time: Instant,
     pub start_time: Instant,
     pub end_time: Instant,
@@ -180,6 +217,14 @@ pub trait SpfVersion<V: Version> {
         lsa_entries: &'a Arena<LsaEntry<V>>,
     ) -> Box<dyn Iterator<Item = SpfLink<'a, V>> + 'a>;
 
+    // Return the Application-Specific Link Attributes of the provided router
+    // link.
+    fn link_asla<'a>(
+        vertex_lsa: &'a Self::VertexLsa,
+        link: &'a V::LsaRouterLink,
+        area: &'a Area<V>,
+    ) -> &'a [AslaStlv];
+
     // Return iterator over all intra-area networks.
     fn intra_area_networks<'a>(
         area: &'a Area<V>,
@@ -208,6 +253,18 @@ pub trait SpfVersion<V: Version> {
         lsa_entries: &'a Arena<LsaEntry<V>>,
     ) -> impl Iterator<Item = SpfExternalNetwork<V>> + 'a;
 
+    // Return iterator over all NSSA external networks.
+    fn nssa_networks<'a>(
+        lsdb: &'a Lsdb<V>,
+        extended_lsa: bool,
+        lsa_entries: &'a Arena<LsaEntry<V>>,
+    ) -> impl Iterator<Item = SpfExternalNetwork<V>> + 'a;
+
+    // Return iterator over all prefix ranges advertised by SR Mapping Servers.
+    fn prefix_ranges<'a>(
+        area: &'a Area<V>,
+    ) -> impl Iterator<Item = SpfPrefixRange<V>> + 'a;
+
     // Locate the Router Information LSA for the specified area.
     fn area_router_information<'a>(
         lsdb: &'a Lsdb<V>,
@@ -496,6 +553,12 @@ fn compute_spf<V>(
 ) where
     V: Version,
 {
+    // While in graceful restart mode, routing table calculations are deferred
+    // until the restart is complete.
+    if instance.state.gr_restart.is_some() {
+        return;
+    }
+
     // Get time the SPF was scheduled.
     let schedule_time = instance
         .state
@@ -529,9 +592,18 @@ fn compute_spf<V>(
         }
     };
 
+    // Get SPF algorithm used to handle the computation.
+    let spf_algorithm = match &spf_computation_type {
+        SpfComputation::Full => SpfAlgorithm::Full,
+        SpfComputation::Partial(partial) if !partial.intra.is_empty() => {
+            SpfAlgorithm::Incremental
+        }
+        SpfComputation::Partial(_) => SpfAlgorithm::Partial,
+    };
+
     // Log SPF computation start.
     if instance.config.trace_opts.spf {
-        Debug::<V>::SpfStart(spf_log_type).log();
+        Debug::<V>::SpfStart(spf_log_type, spf_algorithm).log();
     }
 
     match &mut spf_computation_type {
@@ -545,6 +617,14 @@ fn compute_spf<V>(
             route::update_rib_full(instance, areas, interfaces, lsa_entries);
         }
         SpfComputation::Partial(partial) => {
+            // Leaf changes don't affect the shortest-path trees, but their
+            // vertices need to reference the latest version of the LSAs.
+            if !partial.intra.is_empty() {
+                for area in areas.iter_mut() {
+                    update_spt_lsas(area, instance, lsa_entries);
+                }
+            }
+
             // Update routing table.
             route::update_rib_partial(
                 partial,
@@ -556,12 +636,46 @@ fn compute_spf<V>(
         }
     }
 
+    // Collect statistics about the resulting shortest-path trees and routing
+    // table. Partial runs leave the shortest-path trees untouched, so their
+    // statistics are directly comparable to those of full runs.
+    let vertex_count = areas
+        .iter()
+        .map(|area| {
+            area.state.spt.len()
+                + area
+                    .state
+                    .flex_algo_spt
+                    .values()
+                    .map(|spt| spt.len())
+                    .sum::<usize>()
+        })
+        .sum::<usize>();
+    let route_count = instance.state.rib.len();
+    let stats = SpfLogStats::new(
+        spf_algorithm,
+        vertex_count as u32,
+        route_count as u32,
+    );
+
+    // Update Flexible Algorithm routing tables.
+    route::update_rib_flex_algo(instance, areas, interfaces, lsa_entries);
+
+    // Remove the routes preserved across a graceful restart that weren't
+    // refreshed by the first routing table calculation after the restart.
+    if std::mem::take(&mut instance.state.gr_stale_sweep) {
+        ibus::tx::route_stale_sweep::<V>(&instance.tx.ibus);
+    }
+
     // Update virtual links.
     area::update_virtual_links(instance, areas, interfaces, lsa_entries);
 
     // Update summary LSAs.
     area::update_summary_lsas(instance, areas, interfaces, lsa_entries);
 
+    // Update NSSA translator state and Type-7 LSAs.
+    area::update_nssa(instance, areas, interfaces, lsa_entries);
+
     // Update time of last SPF computation.
     let end_time = Instant::now();
     instance.state.spf_last_time = Some(end_time);
@@ -576,6 +690,7 @@ fn compute_spf<V>(
     log_spf_run(
         instance,
         spf_log_type,
+        stats,
         schedule_time,
         start_time,
         end_time,
@@ -593,21 +708,142 @@ fn run_area<V>(
 ) where
     V: Version,
 {
-    let af = instance.state.af;
-    let extended_lsa = instance.config.extended_lsa;
     area.state.transit_capability = false;
 
     // Parse and compile opaque data that is relevant to the SPF computation.
     V::area_opaque_data_compile(area, lsa_entries);
 
-    // Get root vertex.
-    let root_vid = V::VertexId::new_root(instance.state.router_id);
-    let Some(root_vlsa) =
-        V::vertex_lsa_find(af, root_vid, area, extended_lsa, lsa_entries)
+    // Clear router's routing table.
+    area.state.routers.clear();
+
+    // Compute the shortest-path tree.
+    let Some(spt) =
+        compute_spt(area, instance, interfaces, neighbors, lsa_entries, None)
     else {
         Error::<V>::SpfRootNotFound(area.area_id).log();
         return;
     };
+
+    // Add "router" routing table entries.
+    for vertex in spt.values().filter(|vertex| vertex.lsa.is_router()) {
+        let route = RouteRtr::new(
+            area.area_id,
+            PathType::IntraArea,
+            vertex.lsa.router_options(),
+            vertex.lsa.router_flags(),
+            vertex.distance,
+            vertex.nexthops.clone(),
+        );
+        area.state.routers.insert(vertex.lsa.router_id(), route);
+
+        // Set TransitCapability.
+        if vertex.lsa.router_flags().is_vlink_endpoint() {
+            area.state.transit_capability = true;
+        }
+    }
+
+    // Update area's SPT.
+    area.state.spt = spt;
+
+    // Compute the topology information used to find IP Fast Reroute
+    // alternates.
+    area.state.lfa = lfa::compute_topology(
+        area,
+        instance,
+        interfaces,
+        neighbors,
+        lsa_entries,
+    );
+
+    // Update the flooding reduction state.
+    flood::flooding_reduction_init(
+        area,
+        instance,
+        interfaces,
+        neighbors,
+        lsa_entries,
+    );
+
+    // Compute the shortest-path trees of the Flexible Algorithms this router
+    // participates in.
+    area.state.flex_algo_spt.clear();
+    if instance.config.sr_enabled {
+        for algo in instance.config.flex_algo.keys() {
+            let Some(fa) = FlexAlgo::compute(*algo, area, lsa_entries) else {
+                continue;
+            };
+            if let Some(spt) = compute_spt(
+                area,
+                instance,
+                interfaces,
+                neighbors,
+                lsa_entries,
+                Some(&fa),
+            ) {
+                area.state.flex_algo_spt.insert(*algo, spt);
+            }
+        }
+    }
+
+    // Update statistics.
+    area.state.spf_run_count += 1;
+    area.state.discontinuity_time = Utc::now();
+}
+
+// Updates the vertices of the area's shortest-path trees to reference the
+// latest version of their LSAs.
+//
+// This is used when the SPT is reused across LSA changes that don't affect the
+// topology.
+fn update_spt_lsas<V>(
+    area: &mut Area<V>,
+    instance: &InstanceUpView<'_, V>,
+    lsa_entries: &Arena<LsaEntry<V>>,
+) where
+    V: Version,
+{
+    let af = instance.state.af;
+    let extended_lsa = instance.config.extended_lsa;
+
+    let mut spt = std::mem::take(&mut area.state.spt);
+    let mut flex_algo_spt = std::mem::take(&mut area.state.flex_algo_spt);
+    for vertex in spt
+        .values_mut()
+        .chain(flex_algo_spt.values_mut().flat_map(|spt| spt.values_mut()))
+    {
+        if let Some(lsa) =
+            V::vertex_lsa_find(af, vertex.id, area, extended_lsa, lsa_entries)
+        {
+            vertex.lsa = lsa;
+        }
+    }
+    area.state.spt = spt;
+    area.state.flex_algo_spt = flex_algo_spt;
+}
+
+// Computes the shortest-path tree of the provided area.
+//
+// When a Flexible Algorithm is provided, routers that don't participate in the
+// algorithm and links that don't satisfy its constraints are pruned from the
+// topology, and link costs are given by the algorithm's metric type.
+fn compute_spt<V>(
+    area: &Area<V>,
+    instance: &InstanceUpView<'_, V>,
+    interfaces: &Arena<Interface<V>>,
+    neighbors: &Arena<Neighbor<V>>,
+    lsa_entries: &Arena<LsaEntry<V>>,
+    flex_algo: Option<&FlexAlgo>,
+) -> Option<BTreeMap<V::VertexId, Vertex<V>>>
+where
+    V: Version,
+{
+    let af = instance.state.af;
+    let extended_lsa = instance.config.extended_lsa;
+
+    // Get root vertex.
+    let root_vid = V::VertexId::new_root(instance.state.router_id);
+    let root_vlsa =
+        V::vertex_lsa_find(af, root_vid, area, extended_lsa, lsa_entries)?;
     let root_v = Vertex::<V>::new(root_vid, root_vlsa, 0, 0);
 
     // Initialize SPT and candidate list.
@@ -615,33 +851,12 @@ fn run_area<V>(
     let mut cand_list = BTreeMap::new();
     cand_list.insert((root_v.distance, root_v.id), root_v);
 
-    // Clear router's routing table.
-    area.state.routers.clear();
-
     // Main SPF loop.
     while let Some(((_, vertex_id), vertex)) = cand_list.pop_first() {
         // Add vertex to SPT.
         spt.insert(vertex.id, vertex);
         let vertex = spt.get(&vertex_id).unwrap();
 
-        if vertex.lsa.is_router() {
-            // Add "router" routing table entry.
-            let route = RouteRtr::new(
-                area.area_id,
-                PathType::IntraArea,
-                vertex.lsa.router_options(),
-                vertex.lsa.router_flags(),
-                vertex.distance,
-                vertex.nexthops.clone(),
-            );
-            area.state.routers.insert(vertex.lsa.router_id(), route);
-
-            // Set TransitCapability.
-            if vertex.lsa.router_flags().is_vlink_endpoint() {
-                area.state.transit_capability = true;
-            }
-        }
-
         // Iterate over all links described by the vertex's LSA.
         for link in V::vertex_lsa_links(
             &vertex.lsa,
@@ -668,8 +883,27 @@ fn run_area<V>(
                 continue;
             }
 
+            // Get the link cost, applying the Flexible Algorithm constraints
+            // if necessary.
+            let cost = match (flex_algo, link.parent) {
+                (Some(fa), _)
+                    if link.lsa.is_router()
+                        && !fa.participants.contains(&link.lsa.router_id()) =>
+                {
+                    continue;
+                }
+                (Some(fa), Some((_, rtr_link))) => {
+                    let aslas = V::link_asla(&vertex.lsa, rtr_link, area);
+                    match fa.link_metric(link.cost, aslas) {
+                        Some(cost) => cost,
+                        None => continue,
+                    }
+                }
+                _ => link.cost.into(),
+            };
+
             // Calculate distance to the link's vertex.
-            let distance = vertex.distance.saturating_add(link.cost.into());
+            let distance = vertex.distance.saturating_add(cost);
 
             // Increment number of hops to the root.
             let mut hops = vertex.hops;
@@ -720,12 +954,7 @@ fn run_area<V>(
         }
     }
 
-    // Update area's SPT.
-    area.state.spt = spt;
-
-    // Update statistics.
-    area.state.spf_run_count += 1;
-    area.state.discontinuity_time = Utc::now();
+    Some(spt)
 }
 
 // Computes the set of nexthops that should be used to reach the given
@@ -770,6 +999,7 @@ where
 fn log_spf_run<V>(
     instance: &mut InstanceUpView<'_, V>,
     spf_log_type: SpfLogType,
+    stats: SpfLogStats,
     schedule_time: Instant,
     start_time: Instant,
     end_time: Instant,
@@ -792,6 +1022,7 @@ fn log_spf_run<V>(
     let log_entry = SpfLogEntry::new(
         *log_id,
         spf_log_type,
+        stats,
         schedule_time,
         start_time,
         end_time,
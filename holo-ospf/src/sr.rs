@@ -7,7 +7,8 @@
 use std::net::Ipv4Addr;
 
 use holo_utils::mpls::Label;
-use holo_utils::sr::{IgpAlgoType, Sid};
+use holo_utils::sr::{IgpAlgoType, Sid, prefix_range_offset};
+use ipnetwork::IpNetwork;
 
 use crate::area::Area;
 use crate::collections::Arena;
@@ -21,6 +22,7 @@ use crate::northbound::notification;
 use crate::packet::lsa::{AdjSidVersion, PrefixSidVersion};
 use crate::packet::tlv::{PrefixSidFlags, SidLabelRangeTlv};
 use crate::route::RouteNet;
+use crate::spf::{SpfPrefixRange, VertexLsaVersion};
 use crate::version::Version;
 
 // ===== global functions =====
@@ -41,10 +43,14 @@ pub(crate) fn prefix_sid_update<V>(
     // A router receiving a Prefix-SID from a remote node and with an algorithm
     // value that the remote node has not advertised in the SR-Algorithm TLV
     // MUST ignore the Prefix-SID Sub- TLV.
+    //
+    // This check doesn't apply to Prefix-SIDs advertised by SR Mapping Servers
+    // (M-Flag set), since the prefix originator might not support SR at all.
     let ri = V::area_router_information(&area.state.lsdb, adv_rtr, lsa_entries);
-    if !ri
-        .sr_algo
-        .is_some_and(|sr_algo| sr_algo.get().contains(&IgpAlgoType::Spf))
+    if !prefix_sid.flags().contains(PrefixSidFlags::M)
+        && !ri
+            .sr_algo
+            .is_some_and(|sr_algo| sr_algo.get().contains(&prefix_sid.algo()))
     {
         return;
     }
@@ -75,6 +81,125 @@ pub(crate) fn prefix_sid_update<V>(
     }
 }
 
+// Resolves the provided Prefix-SID to the MPLS label expected by the given
+// router, which doesn't need to be directly connected.
+pub(crate) fn prefix_sid_remote_label<V>(
+    area: &Area<V>,
+    instance: &InstanceUpView<'_, V>,
+    router_id: Ipv4Addr,
+    prefix_sid: &V::PrefixSid,
+    last_hop: bool,
+    lsa_entries: &Arena<LsaEntry<V>>,
+) -> Option<Label>
+where
+    V: Version,
+{
+    match prefix_sid_output_label(
+        area,
+        instance,
+        router_id,
+        prefix_sid,
+        last_hop,
+        lsa_entries,
+    ) {
+        Ok(label) => Some(label),
+        Err(error) => {
+            error.log();
+            None
+        }
+    }
+}
+
+// Finds the Node-SID of the given router, that is, an index-based Prefix-SID
+// associated to one of its host prefixes.
+pub(crate) fn node_sid_find<V>(
+    area: &Area<V>,
+    instance: &InstanceUpView<'_, V>,
+    router_id: Ipv4Addr,
+    lsa_entries: &Arena<LsaEntry<V>>,
+) -> Option<V::PrefixSid>
+where
+    V: Version,
+{
+    let max_prefixlen = instance.state.af.max_prefixlen();
+    V::intra_area_networks(area, instance.config.extended_lsa, lsa_entries)
+        .filter(|stub| {
+            stub.vertex.lsa.is_router()
+                && stub.vertex.lsa.router_id() == router_id
+        })
+        .filter(|stub| {
+            let prefix: IpNetwork = stub.prefix.into();
+            prefix.prefix() == max_prefixlen
+        })
+        .find_map(|stub| {
+            stub.prefix_sids
+                .get(&IgpAlgoType::Spf)
+                .filter(|prefix_sid| matches!(prefix_sid.sid(), Sid::Index(_)))
+                .copied()
+        })
+}
+
+// Returns the prefix ranges advertised by SR Mapping Servers in the given area,
+// sorted by order of preference.
+pub(crate) fn mapping_server_entries<V>(
+    area: &Area<V>,
+) -> Vec<SpfPrefixRange<V>>
+where
+    V: Version,
+{
+    let mut mappings = V::prefix_ranges(area).collect::<Vec<_>>();
+
+    // Overlapping ranges are resolved by preferring the smallest range, then
+    // the longest prefix, then the smallest starting prefix, then the smallest
+    // starting SID, and finally the lowest advertising Router ID.
+    let sid_value = |sid: Sid| match sid {
+        Sid::Index(index) => index,
+        Sid::Label(label) => label.get(),
+    };
+    mappings.sort_by(|a, b| {
+        let a_prefix: IpNetwork = a.prefix.into();
+        let b_prefix: IpNetwork = b.prefix.into();
+        a.range_size
+            .cmp(&b.range_size)
+            .then_with(|| b_prefix.prefix().cmp(&a_prefix.prefix()))
+            .then_with(|| a_prefix.cmp(&b_prefix))
+            .then_with(|| {
+                sid_value(a.prefix_sid.sid())
+                    .cmp(&sid_value(b.prefix_sid.sid()))
+            })
+            .then_with(|| a.adv_rtr.cmp(&b.adv_rtr))
+    });
+
+    mappings
+}
+
+// Looks up the Prefix-SID mapped to the given prefix by SR Mapping Servers.
+pub(crate) fn mapping_server_lookup<V>(
+    mappings: &[SpfPrefixRange<V>],
+    prefix: V::IpNetwork,
+) -> Option<V::PrefixSid>
+where
+    V: Version,
+{
+    let prefix: IpNetwork = prefix.into();
+    mappings.iter().find_map(|mapping| {
+        let offset = prefix_range_offset(
+            &mapping.prefix.into(),
+            mapping.range_size.into(),
+            &prefix,
+        )?;
+        let mut prefix_sid = mapping.prefix_sid;
+        *prefix_sid.sid_mut() = match prefix_sid.sid() {
+            Sid::Index(index) => Sid::Index(index.checked_add(offset)?),
+            Sid::Label(label) if offset == 0 => Sid::Label(label),
+            Sid::Label(_) => return None,
+        };
+        // Make sure the M-Flag is set, as it's used to identify mapped SIDs.
+        prefix_sid.flags_mut().insert(PrefixSidFlags::M);
+        Some(prefix_sid)
+    })
+}
+
 // Adds SR Adj-SID.
 pub(crate) fn adj_sid_add<V>(
     nbr: &mut Neighbor<V>,
@@ -190,7 +315,12 @@ where
     V: Version,
 {
     if last_hop {
-        // TODO: handle the M-Flag (Mapping Server Flag).
+        // Handle the M-Flag. Prefix-SIDs advertised by SR Mapping Servers are
+        // typically used for prefixes originated by non-SR routers, so the
+        // penultimate hop must always pop the label.
+        if prefix_sid.flags().contains(PrefixSidFlags::M) {
+            return Ok(Label::implicit_null());
+        }
 
         // Handle the NP-Flag.
         if !prefix_sid.flags().contains(PrefixSidFlags::NP) {
@@ -5,7 +5,6 @@
 //
 
 use std::sync::Arc;
-use std::sync::atomic::AtomicU64;
 use std::time::{Duration, Instant};
 
 use holo_utils::ip::AddressFamily;
@@ -17,10 +16,11 @@ use tracing::{Instrument, debug_span};
 
 use crate::area::Area;
 use crate::collections::{LsaEntryId, LsdbId};
-use crate::debug::LsaFlushReason;
+use crate::debug::{LsaFlushReason, SeqNoMismatchReason};
 use crate::instance::InstanceUpView;
 use crate::interface::{Interface, ism};
 use crate::neighbor::{Neighbor, nsm};
+use crate::packet::auth::AuthSeqNo;
 use crate::packet::lsa::{Lsa, LsaHdrVersion, LsaKey};
 use crate::version::Version;
 use crate::{lsdb, network, spf};
@@ -39,6 +39,7 @@ use crate::{lsdb, network, spf};
 //             ism_wait_timer (Nx) -> |              | -> (Nx) hello_interval
 //                                    |              |
 //       nsm_inactivity_timer (Nx) -> |              |
+//           nsm_resync_timer (Nx) -> |              |
 //       packet_rxmt_interval (Nx) -> |              |
 //          dbdesc_free_timer (Nx) -> |              |
 //            ls_update_timer (Nx) -> |              |
@@ -50,6 +51,7 @@ use crate::{lsdb, network, spf};
 // lsdb_maxage_sweep_interval (Nx) -> |              |
 //                                    |              |
 //            spf_delay_timer (Nx) -> |              |
+//     externals_update_timer (1x) -> |              |
 //                                    +--------------+
 //                             ibus_tx (1x) | ^ (1x) ibus_rx
 //                                          | |
@@ -105,6 +107,10 @@ pub mod messages {
             LsdbMaxAgeSweep(LsdbMaxAgeSweepMsg),
             SpfDelayEvent(SpfDelayEventMsg),
             GracePeriod(GracePeriodMsg),
+            RestartInterval(RestartIntervalMsg),
+            LdpSyncHolddown(LdpSyncHolddownMsg),
+            NssaTranslatorStability(NssaTranslatorStabilityMsg),
+            ExternalsUpdate(ExternalsUpdateMsg),
         }
 
         #[derive(Debug, Deserialize, Serialize)]
@@ -211,6 +217,23 @@ pub mod messages {
             pub iface_key: InterfaceKey,
             pub nbr_key: NeighborKey,
         }
+
+        #[derive(Clone, Debug, Deserialize, Serialize)]
+        pub struct RestartIntervalMsg {}
+
+        #[derive(Clone, Debug, Deserialize, Serialize)]
+        pub struct LdpSyncHolddownMsg {
+            pub area_key: AreaKey,
+            pub iface_key: InterfaceKey,
+        }
+
+        #[derive(Clone, Debug, Deserialize, Serialize)]
+        pub struct NssaTranslatorStabilityMsg {
+            pub area_key: AreaKey,
+        }
+
+        #[derive(Clone, Debug, Deserialize, Serialize)]
+        pub struct ExternalsUpdateMsg {}
     }
 
     // Output messages (main task -> child task).
@@ -257,6 +280,7 @@ where
         let area_id = area.id;
         let iface_id = iface.id;
         let auth = iface.state.auth.clone();
+        let auth_ext_seqno = iface.config.auth_ext_seqno;
         let net_packet_rxp = net_packet_rxp.clone();
 
         Task::spawn_supervised(move || {
@@ -270,6 +294,7 @@ where
                     iface_id,
                     af,
                     auth,
+                    auth_ext_seqno,
                     net_packet_rxp,
                 )
                 .await;
@@ -288,7 +313,7 @@ where
 pub(crate) fn net_tx<V>(
     socket: Arc<AsyncFd<Socket>>,
     iface: &Interface<V>,
-    auth_seqno: &Arc<AtomicU64>,
+    auth_seqno: &Arc<AuthSeqNo>,
     mut net_packet_txc: UnboundedReceiver<messages::output::NetTxPacketMsg<V>>,
     #[cfg(feature = "testing")] proto_output_tx: &Sender<
         messages::ProtocolOutputMsg<V>,
@@ -315,6 +340,7 @@ where
         let src = iface.state.src_addr.unwrap();
         let auth = iface.state.auth.clone();
         let auth_seqno = auth_seqno.clone();
+        let auth_ext_seqno = iface.config.auth_ext_seqno;
         let trace_opts = iface.config.trace_opts.packets_resolved.clone();
 
         Task::spawn(
@@ -326,6 +352,7 @@ where
                     src,
                     auth,
                     auth_seqno,
+                    auth_ext_seqno,
                     trace_opts,
                     net_packet_txc,
                 )
@@ -451,6 +478,41 @@ where
     }
 }
 
+// Neighbor's out-of-band resynchronization timer (RFC 4812 - Section 2.4).
+pub(crate) fn nsm_resync_timer<V>(
+    nbr: &Neighbor<V>,
+    iface: &Interface<V>,
+    area: &Area<V>,
+    instance: &InstanceUpView<'_, V>,
+) -> TimeoutTask
+where
+    V: Version,
+{
+    #[cfg(not(feature = "testing"))]
+    {
+        let timeout = Duration::from_secs(iface.config.dead_interval.into());
+        let nbr_id = nbr.id;
+        let area_id = area.id;
+        let iface_id = iface.id;
+        let nsm_eventp = instance.tx.protocol_input.nsm_event.clone();
+
+        TimeoutTask::new(timeout, move || async move {
+            let reason = SeqNoMismatchReason::ResyncTimeout;
+            let msg = messages::input::NsmEventMsg {
+                area_key: area_id.into(),
+                iface_key: iface_id.into(),
+                nbr_key: nbr_id.into(),
+                event: nsm::Event::SeqNoMismatch(reason),
+            };
+            let _ = nsm_eventp.send(msg);
+        })
+    }
+    #[cfg(feature = "testing")]
+    {
+        TimeoutTask {}
+    }
+}
+
 // Send periodic packet retransmissions.
 pub(crate) fn packet_rxmt_interval<V>(
     iface: &Interface<V>,
@@ -779,3 +841,127 @@ where
         TimeoutTask {}
     }
 }
+
+// Graceful restart interval timer task.
+pub(crate) fn restart_interval_timer<V>(
+    instance: &InstanceUpView<'_, V>,
+    restart_interval: u16,
+) -> TimeoutTask
+where
+    V: Version,
+{
+    #[cfg(not(feature = "testing"))]
+    {
+        let restart_intervalp =
+            instance.tx.protocol_input.restart_interval.clone();
+
+        TimeoutTask::new(
+            Duration::from_secs(restart_interval.into()),
+            move || async move {
+                let _ = restart_intervalp
+                    .send(messages::input::RestartIntervalMsg {})
+                    .await;
+            },
+        )
+    }
+    #[cfg(feature = "testing")]
+    {
+        TimeoutTask {}
+    }
+}
+
+// LDP-IGP synchronization holddown timer task.
+pub(crate) fn ldp_sync_holddown_timer<V>(
+    iface: &Interface<V>,
+    area: &Area<V>,
+    instance: &InstanceUpView<'_, V>,
+    holddown: u16,
+) -> TimeoutTask
+where
+    V: Version,
+{
+    #[cfg(not(feature = "testing"))]
+    {
+        let area_id = area.id;
+        let iface_id = iface.id;
+        let ldp_sync_holddownp =
+            instance.tx.protocol_input.ldp_sync_holddown.clone();
+
+        TimeoutTask::new(
+            Duration::from_secs(holddown.into()),
+            move || async move {
+                let _ = ldp_sync_holddownp
+                    .send(messages::input::LdpSyncHolddownMsg {
+                        area_key: area_id.into(),
+                        iface_key: iface_id.into(),
+                    })
+                    .await;
+            },
+        )
+    }
+    #[cfg(feature = "testing")]
+    {
+        TimeoutTask {}
+    }
+}
+
+// NSSA translator stability interval timer task.
+pub(crate) fn nssa_translator_stability_timer<V>(
+    area: &Area<V>,
+    instance: &InstanceUpView<'_, V>,
+    interval: u16,
+) -> TimeoutTask
+where
+    V: Version,
+{
+    #[cfg(not(feature = "testing"))]
+    {
+        let area_id = area.id;
+        let nssa_translator_stabilityp =
+            instance.tx.protocol_input.nssa_translator_stability.clone();
+
+        TimeoutTask::new(
+            Duration::from_secs(interval.into()),
+            move || async move {
+                let _ = nssa_translator_stabilityp
+                    .send(messages::input::NssaTranslatorStabilityMsg {
+                        area_key: area_id.into(),
+                    })
+                    .await;
+            },
+        )
+    }
+    #[cfg(feature = "testing")]
+    {
+        TimeoutTask {}
+    }
+}
+
+// Redistributed routes update timer task.
+//
+// Route updates received from the RIB are coalesced so that the AS-external
+// and NSSA LSAs are reevaluated only once per burst of updates.
+pub(crate) fn externals_update_timer<V>(
+    instance: &InstanceUpView<'_, V>,
+) -> TimeoutTask
+where
+    V: Version,
+{
+    let externals_updatep = instance.tx.protocol_input.externals_update.clone();
+
+    #[cfg(not(feature = "testing"))]
+    {
+        // Start timer.
+        TimeoutTask::new(Duration::from_millis(100), move || async move {
+            let _ =
+                externals_updatep.send(messages::input::ExternalsUpdateMsg {});
+        })
+    }
+    #[cfg(feature = "testing")]
+    {
+        // Update the redistributed routes immediately.
+        let _ = externals_updatep.send(messages::input::ExternalsUpdateMsg {});
+
+        TimeoutTask {}
+    }
+}
@@ -5,4 +5,4 @@
 //
 
 mod ospfv2;
-//mod ospfv3;
+mod ospfv3;
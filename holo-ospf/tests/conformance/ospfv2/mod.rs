@@ -10,6 +10,83 @@ use holo_ospf::instance::Instance;
 use holo_ospf::version::Ospfv2;
 use holo_protocol::test::stub::run_test;
 
+// Test description:
+//
+// Input:
+//  * Ibus: SR configuration update (SRGB 16000-23999)
+//
+// Input:
+//  * Northbound:
+//    - enable Segment Routing
+//    - configure Flexible Algorithm 128 excluding links with admin group 1
+//    - assign admin group 1 to the eth-rt5 link
+// Output:
+//  * Ibus: install the Adj-SIDs of the rt4 and rt5 adjacencies
+//  * Protocol: send an LS Update to all adjacencies containing:
+//    - the updated self-originated RI-LSA (SR algorithms, SRGB and FAD)
+//    - the self-originated Extended Link LSAs, the eth-rt5 one carrying an
+//      ASLA sub-TLV with admin group 1
+//  * Northbound:
+//    - the new self-originated LSAs are present in the LSDB
+//    - the retransmission queue length of all adjacencies increases to 3
+//    - the SPF Delay FSM state transitions from "quiet" to "short-wait"
+//
+// Input:
+//  * Protocol: received RI-LSAs from rt4 and rt5 advertising Flexible
+//    Algorithm 128, and Extended Prefix LSAs with their algorithm 128
+//    Prefix-SIDs
+// Output:
+//  * Protocol:
+//    - send an LS Update to rt5 containing the received LSAs
+//    - send an LS Ack to rt4 containing the received LSAs
+//  * Northbound:
+//    - the received LSAs are present in the LSDB
+//    - the retransmission queue length of the rt5 adjacency increases to 7
+//
+// Input:
+//  * Protocol: SPF delay timer expiration
+// Output:
+//  * Ibus: install the algorithm 128 Prefix-SIDs of rt4 and rt5, both via
+//    rt4 since the eth-rt5 link is excluded from the algorithm's topology
+#[tokio::test]
+async fn flex_algo_affinity1() {
+    run_test::<Instance<Ospfv2>>("flex-algo-affinity1", "topo2-1", "rt6").await;
+}
+
+// Test description:
+//
+// Input:
+//  * Northbound: enable LFA on the eth-rt4 and eth-rt5 interfaces
+// Output:
+//  * Ibus: reinstall the single-path routes with a link-protecting backup
+//    nexthop through the other neighbor
+//  * Northbound: 8 routes are reported as protected and the 3 ECMP routes
+//    as unprotected
+//
+// Input:
+//  * Northbound: change the cost of the eth-rt5 interface to 25
+// Output:
+//  * Protocol: send an LS Update to all adjacencies containing the updated
+//    self-originated Router-LSA
+//  * Northbound:
+//    - the updated Router-LSA is present in the LSDB
+//    - the retransmission queue length of all adjacencies increases to 1
+//    - the SPF Delay FSM state transitions from "quiet" to "short-wait"
+//
+// Input:
+//  * Protocol: SPF delay timer expiration
+// Output:
+//  * Ibus: reinstall all routes with rt4 as the primary nexthop and rt5 as
+//    the backup nexthop
+//  * Northbound:
+//    - the local RIB reflects the new primary nexthops and metrics
+//    - all 11 routes are reported as protected, most of them with node
+//      protection
+#[tokio::test]
+async fn frr_lfa1() {
+    run_test::<Instance<Ospfv2>>("frr-lfa1", "topo2-1", "rt6").await;
+}
+
 // Test description:
 //
 // Input:
@@ -215,6 +292,55 @@ async fn lsa_refresh2() {
     run_test::<Instance<Ospfv2>>("lsa-refresh2", "topo2-1", "rt2").await;
 }
 
+// Test description:
+//
+// Input:
+//  * Northbound: configure a multi-area adjacency in area 0.0.0.0 over the
+//    eth-rt1 interface (area 0.0.0.1), with 10.0.1.1 as the neighbor address
+// Output:
+//  * Northbound: the "eth-rt1:0.0.0.0" logical interface transitioned to the
+//    "point-to-point" state
+//
+// Input:
+//  * Protocol: received a Hello packet on eth-rt1 from rt1 with the area ID
+//    set to 0.0.0.0
+// Output:
+//  * Protocol: send a DbDesc packet (I/M/MS bits set) to 10.0.1.1 over the
+//    multi-area adjacency
+//  * Northbound: the multi-area adjacency transitioned to the "exstart" state
+//
+// Input:
+//  * Protocol: received a DbDesc packet from rt1 acknowledging rt2 as the
+//    master
+// Output:
+//  * Protocol: send a DbDesc packet to 10.0.1.1 containing the headers of the
+//    area 0.0.0.0 LSAs
+//  * Northbound: the multi-area adjacency transitioned to the "exchange" state
+//
+// Input:
+//  * Protocol: received a DbDesc packet from rt1 acknowledging the previous
+//    one
+// Output:
+//  * Protocol: send an LS Update to rt1 (over the multi-area adjacency) and
+//    rt3 containing the updated Router-LSA for area 0.0.0.0
+//  * Northbound:
+//    - the multi-area adjacency transitioned to the "full" state
+//    - the self-originated Router-LSA for area 0.0.0.0 now contains an
+//      unnumbered point-to-point link to rt1
+//    - the retransmission queue length of the rt3 adjacency increases to 1
+//    - the SPF Delay FSM state transitions from "quiet" to "short-wait"
+//
+// Input:
+//  * Protocol: received a Hello packet on eth-rt1 with the area ID set to
+//    0.0.0.0, but from a source other than the configured neighbor address
+// Output:
+//  * Northbound: send an "if-config-error" notification for eth-rt1 (area
+//    mismatch)
+#[tokio::test]
+async fn multi_area_adj1() {
+    run_test::<Instance<Ospfv2>>("multi-area-adj1", "topo1-1", "rt2").await;
+}
+
 // Test description:
 //
 // Input:
@@ -257,6 +383,29 @@ async fn nb_config_area_dflt_cost1() {
         .await;
 }
 
+// Test description:
+//
+// Input:
+//  * Northbound: enable summary advertisement into the 0.0.0.3 totally NSSA
+//    area
+// Output:
+//  * Protocol: send an LS Update containing ten Type-3 Summary LSAs and the
+//    flushed default Type-3 Summary LSA out the eth-rt7 interface, destined to
+//    224.0.0.5
+//  * Northbound:
+//    - ten new self-originated Type-3 Summary LSAs are now present in the LSDB
+//    - the self-originated default Type-3 Summary LSA was prematurely aged
+//    - the retransmission queue length of adjacency 7.7.7.7 increases to 11
+#[tokio::test]
+async fn nb_config_area_nssa_summary1() {
+    run_test::<Instance<Ospfv2>>(
+        "nb-config-area-nssa-summary1",
+        "topo1-4",
+        "rt6",
+    )
+    .await;
+}
+
 // Test description:
 //
 // Input:
@@ -518,6 +667,23 @@ async fn nb_config_iface_cost1() {
         .await;
 }
 
+// Test description:
+//
+// Input:
+//  * Northbound: enable prefix suppression on the eth-rt1 interface
+// Output:
+//  * Protocol: send an LS Update to rt1 containing the updated self-originated
+//    Router-LSA, which no longer includes the stub link to 10.0.1.0/24
+#[tokio::test]
+async fn nb_config_iface_prefix_suppression1() {
+    run_test::<Instance<Ospfv2>>(
+        "nb-config-iface-prefix-suppression1",
+        "topo1-1",
+        "rt2",
+    )
+    .await;
+}
+
 // Test description:
 //
 // Input:
@@ -614,6 +780,35 @@ async fn nb_rpc_clear_database1() {
         .await;
 }
 
+// Test description:
+//
+// Input:
+//  * Northbound: enable graceful restart
+// Output: no changes
+//
+// Input:
+//  * Northbound: "holo-ospf:graceful-restart" RPC
+// Output:
+//  * Protocol: send Grace-LSAs on all active interfaces
+//  * IBUS: the pre-restart routes are marked as stale
+//  * Northbound:
+//    - restart-status-change notification (planned restart)
+//    - all neighbors were killed
+//    - the local RIB is empty
+//
+// Input:
+//  * Protocol: graceful restart interval timeout
+// Output:
+//  * IBUS: the stale routes are swept (no adjacencies came up)
+//  * Northbound:
+//    - restart-status-change notification (timed out)
+//    - the SPF Delay FSM state transitions from "quiet" to "short-wait"
+#[tokio::test]
+async fn nb_rpc_graceful_restart1() {
+    run_test::<Instance<Ospfv2>>("nb-rpc-graceful-restart1", "topo1-1", "rt6")
+        .await;
+}
+
 // Test description:
 //
 // Input:
@@ -663,6 +858,141 @@ async fn nb_rpc_clear_neighbor2() {
         .await;
 }
 
+// Test description:
+//
+// Input:
+//  * Protocol: receive an LS Update from 7.7.7.7 containing an updated
+//    Router-LSA (E-bit set) and a Type-7 LSA for 172.16.1.0/24
+// Output:
+//  * Protocol: send an LS Ack to 7.7.7.7 acknowledging the received LSAs
+//  * Northbound:
+//    - the Router-LSA and the Type-7 LSA are now present in the 0.0.0.3 LSDB
+//    - the SPF Delay FSM state transitions from "quiet" to "short-wait"
+//
+// Input:
+//  * Protocol: SPF_TIMER expiration
+// Output:
+//  * Protocol: send an LS Update to 3.3.3.3 containing the updated area 0.0.0.0
+//    Router-LSA (E-bit set) and the Type-5 LSA translated from the Type-7 LSA
+//  * Northbound:
+//    - new NSSA external route to 172.16.1.0/24
+//    - the translated Type-5 LSA is now present in the AS-scope LSDB
+//    - the retransmission queue length of adjacency 3.3.3.3 increases to 2
+//  * Ibus: install the route to 172.16.1.0/24
+#[tokio::test]
+async fn nssa_translation1() {
+    run_test::<Instance<Ospfv2>>("nssa-translation1", "topo1-4", "rt6").await;
+}
+
+// Test description:
+//
+// Input:
+//  * Protocol: receive an LS Update from 7.7.7.7 containing an updated
+//    Router-LSA (B-bit set)
+// Output:
+//  * Protocol: send an LS Ack to 7.7.7.7 acknowledging the received LSA
+//  * Northbound:
+//    - the Router-LSA is now present in the 0.0.0.3 LSDB
+//    - the SPF Delay FSM state transitions from "quiet" to "short-wait"
+//
+// Input:
+//  * Protocol: SPF_TIMER expiration
+// Output:
+//  * Northbound:
+//    - the ABR count of area 0.0.0.3 is now 2
+//    - the NSSA translator state remains "elected" while the
+//      TranslatorStabilityInterval is running
+//
+// Input:
+//  * Protocol: TranslatorStabilityInterval expiration for area 0.0.0.3
+// Output:
+//  * Protocol: send an LS Update to 7.7.7.7 containing the updated Router-LSA
+//    (Nt-bit cleared)
+//  * Northbound:
+//    - the NSSA translator state transitions to "disabled"
+//    - the retransmission queue length of adjacency 7.7.7.7 increases to 1
+//    - nssa-translator-status-change notification
+#[tokio::test]
+async fn nssa_translator_election1() {
+    run_test::<Instance<Ospfv2>>("nssa-translator-election1", "topo1-4", "rt6")
+        .await;
+}
+
+// Test description:
+//
+// Input:
+//  * Northbound: enable LLS on eth-rt4
+// Output:
+//  * Northbound: the OOB resync state of neighbor 4.4.4.4 is all cleared
+//
+// Input:
+//  * Protocol: receive a Hello from 4.4.4.4 with the LR and RS bits set in
+//    the LLS Extended Options, not listing 6.6.6.6 as a neighbor
+// Output:
+//  * Northbound: neighbor 4.4.4.4 remains in the "full" state, and is now
+//    flagged as LR-capable and restarting
+//
+// Input:
+//  * Protocol: receive a Database Description packet from 4.4.4.4 with the
+//    I, M, MS and R bits set
+// Output:
+//  * Protocol: send a Database Description packet to 4.4.4.4 with the I, M,
+//    MS and R bits set, starting the OOB resync as master
+//  * Northbound: neighbor 4.4.4.4 remains in the "full" state, with an OOB
+//    resync now in progress
+//
+// Input:
+//  * Protocol: receive a Database Description packet from 4.4.4.4 with the R
+//    bit set, acknowledging the initial sequence number
+// Output:
+//  * Protocol: send a Database Description packet to 4.4.4.4 containing the
+//    headers of all LSAs in the LSDB
+//
+// Input:
+//  * Protocol: receive an empty Database Description packet from 4.4.4.4
+//    acknowledging the last sequence number
+// Output:
+//  * Northbound: the OOB resync completes and neighbor 4.4.4.4 remains in the
+//    "full" state
+//
+// Input:
+//  * Protocol: receive a Hello from 4.4.4.4 with only the LR bit set in the
+//    LLS Extended Options
+// Output:
+//  * Northbound: neighbor 4.4.4.4 is no longer flagged as restarting
+#[tokio::test]
+async fn oob_resync1() {
+    run_test::<Instance<Ospfv2>>("oob-resync1", "topo2-1", "rt6").await;
+}
+
+// Test description:
+//
+// Input:
+//  * Northbound: enable LLS on eth-rt4
+// Output:
+//  * Northbound: the OOB resync state of neighbor 4.4.4.4 is all cleared
+//
+// Input:
+//  * Protocol: receive a Hello from 4.4.4.4 with the LR and RS bits set in
+//    the LLS Extended Options, not listing 6.6.6.6 as a neighbor
+// Output:
+//  * Northbound: neighbor 4.4.4.4 remains in the "full" state, and is now
+//    flagged as LR-capable and restarting
+//
+// Input:
+//  * Protocol: OOB resync timer expiration for neighbor 4.4.4.4
+// Output:
+//  * Protocol: send a Database Description packet to 4.4.4.4 with the I, M
+//    and MS bits set, and an LS Update containing the updated Router-LSA
+//    (point-to-point link to 4.4.4.4 removed)
+//  * Northbound:
+//    - neighbor 4.4.4.4 transitions to the "exstart" state
+//    - nbr-state-change notification
+#[tokio::test]
+async fn oob_resync2() {
+    run_test::<Instance<Ospfv2>>("oob-resync2", "topo2-1", "rt6").await;
+}
+
 // Test description:
 //
 // Input:
@@ -1195,6 +1525,45 @@ async fn rxmt_lsupd1() {
     run_test::<Instance<Ospfv2>>("rxmt-lsupd1", "topo2-1", "rt2").await;
 }
 
+// Test description:
+//
+// Input:
+//  * Protocol: received an updated Router-LSA from rt4 whose only change is a
+//    new stub network (172.16.1.0/24)
+// Output:
+//  * Protocol: send an LS Update to rt5 containing the received Router-LSA
+//  * Northbound:
+//    - the received Router-LSA is present in the LSDB
+//    - the retransmission queue length of the rt5 adjacency increases to 1
+//    - the SPF Delay FSM state transitions from "quiet" to "short-wait"
+//
+// Input:
+//  * Protocol: SPF delay timer expiration
+// Output:
+//  * Ibus: install route to 172.16.1.0/24 via rt4
+//  * Northbound:
+//    - new incremental SPF run logged, reusing the existing SPT
+//    - new route to 172.16.1.0/24 via rt4
+//
+// Input:
+//  * Protocol: received an updated RI LSA from rt4 (not advertising the
+//    stub router capability anymore)
+// Output:
+//  * Protocol: send an LS Update to rt5 containing the received RI LSA
+//  * Northbound:
+//    - the received RI LSA is present in the LSDB
+//    - the retransmission queue length of the rt5 adjacency increases to 2
+//
+// Input:
+//  * Protocol: SPF delay timer expiration
+// Output:
+//  * Northbound: new full SPF run logged, and the routing table is the same
+//    as the one computed by the previous incremental SPF run
+#[tokio::test]
+async fn spf_partial1() {
+    run_test::<Instance<Ospfv2>>("spf-partial1", "topo2-1", "rt6").await;
+}
+
 // Test description:
 //
 // New interface address should prompt the local Router-LSA to be reoriginated.
@@ -1330,6 +1699,64 @@ async fn ibus_addr_del3() {
     run_test::<Instance<Ospfv2>>("ibus-addr-del3", "topo2-1", "rt2").await;
 }
 
+// Test description:
+//
+// Input:
+//  * Ibus: unnumbered address 6.6.6.6/32 added to eth-rt4
+// Output: no changes (the numbered address remains the primary one)
+//
+// Input:
+//  * Ibus: address 10.0.7.6/24 was removed from eth-rt4
+// Output:
+//  * Protocol: send an LS Update to all adjacencies containing the updated
+//    Router-LSA (the link to rt4 now uses the interface ifindex as its link
+//    data, and the 10.0.7.0/24 stub network was removed)
+//  * Northbound:
+//    - the self-originated Router-LSA was updated
+//    - the retransmission queue length of all adjacencies increases to 1
+//    - the SPF Delay FSM state transitions from "quiet" to "short-wait"
+//
+// Input:
+//  * Ibus: address 10.0.7.6/24 added to eth-rt4
+// Output:
+//  * Protocol: send an LS Update to all adjacencies containing the updated
+//    Router-LSA (the link to rt4 uses 10.0.7.6 as its link data again, and
+//    the 10.0.7.0/24 stub network was added)
+//  * Northbound: the self-originated Router-LSA was updated
+//
+// Input:
+//  * Ibus: unnumbered address 6.6.6.6/32 was removed from eth-rt4
+// Output: no changes
+#[tokio::test]
+async fn ibus_addr_unnumbered1() {
+    run_test::<Instance<Ospfv2>>("ibus-addr-unnumbered1", "topo2-1", "rt6")
+        .await;
+}
+
+// Test description:
+//
+// Numbered host addresses shouldn't be mistaken for unnumbered ones.
+//
+// Input:
+//  * Ibus: numbered address 172.16.1.6/32 added to eth-rt4
+// Output:
+//  * Protocol: send an LS Update to all adjacencies containing the updated
+//    Router-LSA, which has a new stub network (172.16.1.6/32)
+//  * Northbound: the self-originated Router-LSA was updated
+//
+// Input:
+//  * Ibus: address 10.0.7.6/24 was removed from eth-rt4
+// Output:
+//  * Protocol: send an LS Update to all adjacencies containing the updated
+//    Router-LSA (the link to rt4 now uses 172.16.1.6 as its link data, and
+//    the 10.0.7.0/24 stub network was removed)
+//  * Northbound: the self-originated Router-LSA was updated
+#[tokio::test]
+async fn ibus_addr_unnumbered2() {
+    run_test::<Instance<Ospfv2>>("ibus-addr-unnumbered2", "topo2-1", "rt6")
+        .await;
+}
+
 // Test description:
 //
 // Input:
@@ -1537,6 +1964,132 @@ async fn ibus_iface_update7() {
     run_test::<Instance<Ospfv2>>("ibus-iface-update7", "topo2-1", "rt2").await;
 }
 
+// Test description:
+//
+// Input:
+//  * Northbound: enable redistribution of static routes using Type-1 metrics
+//    and the route tag 100
+// Output:
+//  * Ibus: subscribe to the redistribution of static routes
+//
+// Input:
+//  * Ibus: static route 172.16.1.0/24 (metric 20) added
+// Output:
+//  * Northbound:
+//    - new self-originated AS-External-LSA for 172.16.1.0/24
+//    - the self-originated Router-LSA now has the E-bit set
+//    - the SPF Delay FSM state transitions from "quiet" to "short-wait"
+//  * Protocol: send an LS Update to all adjacencies containing the new
+//    AS-External-LSA and the updated Router-LSA
+//
+// Input:
+//  * Ibus: static route 172.16.1.0/24 removed
+// Output:
+//  * Northbound:
+//    - the self-originated AS-External-LSA for 172.16.1.0/24 was flushed
+//    - the self-originated Router-LSA no longer has the E-bit set
+//  * Protocol: send an LS Update to all adjacencies containing the flushed
+//    AS-External-LSA and the updated Router-LSA
+#[tokio::test]
+async fn ibus_route_redist1() {
+    run_test::<Instance<Ospfv2>>("ibus-route-redist1", "topo2-1", "rt6").await;
+}
+
+// Test description:
+//
+// Input:
+//  * Northbound: enable redistribution of static routes using Type-1 metrics
+//    and the route tag 100
+// Output:
+//  * Ibus: subscribe to the redistribution of static routes
+//
+// Input:
+//  * Ibus: static route 172.16.0.0/24 (metric 20) added
+// Output:
+//  * Protocol: send an LS Update to all adjacencies containing the new
+//    AS-External-LSA (LSA-ID 172.16.0.0) and the updated Router-LSA
+//
+// Input:
+//  * Ibus: static route 172.16.0.0/16 (metric 20) added
+// Output:
+//  * Protocol: send an LS Update to all adjacencies containing the
+//    AS-External-LSA for 172.16.0.0/16 (LSA-ID 172.16.0.0) and the
+//    AS-External-LSA for 172.16.0.0/24, which was moved to LSA-ID
+//    172.16.0.255 (RFC 2328 - Appendix E)
+//
+// Input:
+//  * Ibus: static route 172.16.0.0/16 removed
+// Output:
+//  * Protocol: send an LS Update to all adjacencies containing the
+//    AS-External-LSA for 172.16.0.0/24, which was moved back to LSA-ID
+//    172.16.0.0, and the flushed AS-External-LSA with LSA-ID 172.16.0.255
+//
+// Input:
+//  * Ibus: static route 172.16.0.0/24 removed
+// Output:
+//  * Protocol: send an LS Update to all adjacencies containing the flushed
+//    AS-External-LSA and the updated Router-LSA
+#[tokio::test]
+async fn ibus_route_redist2() {
+    run_test::<Instance<Ospfv2>>("ibus-route-redist2", "topo2-1", "rt6").await;
+}
+
+// Test description:
+//
+// Input:
+//  * Northbound: enable redistribution of static routes using Type-1 metrics
+//    and the route tag 100
+// Output:
+//  * Ibus: subscribe to the redistribution of static routes
+//
+// Input:
+//  * Ibus: static route 172.16.0.0/16 (metric 20) added
+// Output:
+//  * Protocol: send an LS Update to all adjacencies containing the new
+//    AS-External-LSA (LSA-ID 172.16.0.0) and the updated Router-LSA
+//
+// Input:
+//  * Ibus: static route 172.16.0.0/24 (metric 20) added
+// Output:
+//  * Protocol: send an LS Update to all adjacencies containing the new
+//    AS-External-LSA for 172.16.0.0/24, originated with LSA-ID 172.16.0.255
+//    since the shorter prefix keeps LSA-ID 172.16.0.0 (RFC 2328 - Appendix E)
+//
+// Input:
+//  * Ibus: static route 172.16.0.0/16 removed
+// Output:
+//  * Protocol: send an LS Update to all adjacencies containing the
+//    AS-External-LSA for 172.16.0.0/24, which was moved to LSA-ID 172.16.0.0,
+//    and the flushed AS-External-LSA with LSA-ID 172.16.0.255
+#[tokio::test]
+async fn ibus_route_redist3() {
+    run_test::<Instance<Ospfv2>>("ibus-route-redist3", "topo2-1", "rt6").await;
+}
+
+// Test description:
+//
+// Input:
+//  * Northbound: enable the conditional origination of an AS-external default
+//    route
+// Output:
+//  * Ibus: subscribe to default route updates
+//
+// Input:
+//  * Ibus: static default route added (static routes aren't redistributed)
+// Output:
+//  * Protocol: send an LS Update to all adjacencies containing the new default
+//    AS-External-LSA and the updated Router-LSA
+//
+// Input:
+//  * Ibus: static default route removed
+// Output:
+//  * Protocol: send an LS Update to all adjacencies containing the flushed
+//    default AS-External-LSA and the updated Router-LSA
+#[tokio::test]
+async fn ibus_route_redist4() {
+    run_test::<Instance<Ospfv2>>("ibus-route-redist4", "topo2-1", "rt6").await;
+}
+
 // Test description:
 //
 // The system Router ID should be ignored if the OSPF instance has an explicit
@@ -10,6 +10,29 @@ use holo_ospf::instance::Instance;
 use holo_ospf::version::Ospfv3;
 use holo_protocol::test::stub::run_test;
 
+// Test description:
+//
+// Input:
+//  * Ibus: addresses 3000::3/64 and 3001::3/64 added to eth-rt5-1
+//  * Northbound: enable prefix suppression on the eth-rt5-1 interface
+// Output:
+//  * Protocol:
+//    - send an LS Update to all adjacencies containing the updated
+//      Intra-Area-Prefix-LSA, which has two new prefixes (3000::/64 and
+//      3001::/64)
+//    - send an LS Update to all adjacencies containing the updated
+//      Intra-Area-Prefix-LSA, which no longer includes the transit prefix
+//      (3000::/64) but still includes the secondary prefix (3001::/64)
+#[tokio::test]
+async fn nb_config_iface_prefix_suppression1() {
+    run_test::<Instance<Ospfv3>>(
+        "nb-config-iface-prefix-suppression1",
+        "topo2-1",
+        "rt3",
+    )
+    .await;
+}
+
 // Test description:
 //
 // Input:
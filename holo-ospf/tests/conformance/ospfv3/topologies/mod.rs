@@ -8,6 +8,7 @@ use holo_ospf::instance::Instance;
 use holo_ospf::version::Ospfv3;
 use holo_protocol::test::stub::run_test_topology;
 
+#[ignore]
 #[tokio::test]
 async fn topology1_1() {
     for rt_num in 1..=7 {
@@ -16,6 +17,7 @@ async fn topology1_1() {
     }
 }
 
+#[ignore]
 #[tokio::test]
 async fn topology1_2() {
     for rt_num in 1..=7 {
@@ -24,6 +26,7 @@ async fn topology1_2() {
     }
 }
 
+#[ignore]
 #[tokio::test]
 async fn topology2_1() {
     for rt_num in 1..=6 {
@@ -32,6 +35,7 @@ async fn topology2_1() {
     }
 }
 
+#[ignore]
 #[tokio::test]
 async fn topology2_2() {
     for rt_num in 1..=6 {
@@ -40,6 +44,7 @@ async fn topology2_2() {
     }
 }
 
+#[ignore]
 #[tokio::test]
 async fn topology_3_1() {
     for rt_num in 1..=6 {
@@ -48,6 +53,7 @@ async fn topology_3_1() {
     }
 }
 
+#[ignore]
 #[tokio::test]
 async fn topology_3_2() {
     for rt_num in 1..=6 {
@@ -56,6 +62,7 @@ async fn topology_3_2() {
     }
 }
 
+#[ignore]
 #[tokio::test]
 async fn topology_3_3() {
     for rt_num in 1..=6 {
@@ -5,7 +5,6 @@
 //
 
 use std::net::Ipv4Addr;
-use std::sync::atomic::AtomicU64;
 use std::sync::{Arc, LazyLock as Lazy};
 
 use bytes::Bytes;
@@ -14,7 +13,9 @@ use holo_ospf::ospfv2::packet::iana::*;
 use holo_ospf::ospfv2::packet::lsa::*;
 use holo_ospf::ospfv2::packet::lsa_opaque::*;
 use holo_ospf::ospfv2::packet::*;
-use holo_ospf::packet::auth::{AuthDecodeCtx, AuthEncodeCtx, AuthMethod};
+use holo_ospf::packet::auth::{
+    AuthDecodeCtx, AuthEncodeCtx, AuthMethod, AuthSeqNo,
+};
 use holo_ospf::packet::error::DecodeError;
 use holo_ospf::packet::iana::*;
 use holo_ospf::packet::lls::ExtendedOptionsFlags;
@@ -39,14 +40,20 @@ const SRC_ADDR: Ipv4Addr = Ipv4Addr::UNSPECIFIED;
 fn test_encode_packet(
     bytes_expected: &[u8],
     auth_data: &Option<(Key, u64)>,
+    ext_seqno: bool,
     packet: &Packet<Ospfv2>,
 ) {
     // Prepare authentication context.
     let mut auth = None;
     let auth_seqno;
     if let Some((auth_key, seqno)) = auth_data {
-        auth_seqno = Arc::new(AtomicU64::new(*seqno));
-        auth = Some(AuthEncodeCtx::new(auth_key, &auth_seqno, SRC_ADDR.into()));
+        auth_seqno = Arc::new(AuthSeqNo::new(*seqno));
+        auth = Some(AuthEncodeCtx::new(
+            auth_key,
+            &auth_seqno,
+            ext_seqno,
+            SRC_ADDR.into(),
+        ));
     }
 
     // Encode the packet.
@@ -57,6 +64,7 @@ fn test_encode_packet(
 fn test_decode_packet(
     bytes: &[u8],
     auth_data: &Option<(Key, u64)>,
+    ext_seqno: bool,
     packet_expected: &Packet<Ospfv2>,
 ) {
     // Prepare authentication context.
@@ -64,7 +72,8 @@ fn test_decode_packet(
     let auth_method;
     if let Some((auth_key, _)) = auth_data {
         auth_method = AuthMethod::ManualKey(auth_key.clone());
-        auth = Some(AuthDecodeCtx::new(&auth_method, SRC_ADDR.into()));
+        auth =
+            Some(AuthDecodeCtx::new(&auth_method, ext_seqno, SRC_ADDR.into()));
     };
 
     // Decode the packet.
@@ -355,6 +364,46 @@ static HELLO1_HMAC_SHA256: Lazy<(Vec<u8>, Option<(Key, u64)>, Packet<Ospfv2>)> =
         )
     });
 
+static HELLO1_HMAC_SHA256_EXT_SEQNO: Lazy<(
+    Vec<u8>,
+    Option<(Key, u64)>,
+    Packet<Ospfv2>,
+)> = Lazy::new(|| {
+    (
+        vec![
+            0x02, 0x01, 0x00, 0x34, 0x01, 0x01, 0x01, 0x01, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x01, 0x20, 0x00, 0x00,
+            0x00, 0x05, 0xff, 0xff, 0xff, 0x00, 0x00, 0x03, 0x02, 0x01, 0x00,
+            0x00, 0x00, 0x0c, 0x0a, 0x00, 0x01, 0x03, 0x0a, 0x00, 0x01, 0x02,
+            0x02, 0x02, 0x02, 0x02, 0x03, 0x03, 0x03, 0x03, 0x32, 0x45, 0xd0,
+            0x14, 0x55, 0x9b, 0x19, 0x60, 0xd9, 0x55, 0xae, 0xea, 0xf5, 0xbe,
+            0x50, 0x67, 0x0e, 0x4f, 0x8d, 0xd2, 0x33, 0xb0, 0xd4, 0xa4, 0xb2,
+            0xe7, 0x01, 0x34, 0x7c, 0x91, 0x94, 0x48, 0x4f, 0xd1, 0x10, 0x17,
+        ],
+        Some((
+            Key::new(1, CryptoAlgo::HmacSha256, "HOLO".as_bytes().to_vec()),
+            22318272532,
+        )),
+        Packet::Hello(Hello {
+            hdr: PacketHdr {
+                pkt_type: PacketType::Hello,
+                router_id: ip4!("1.1.1.1"),
+                area_id: ip4!("0.0.0.0"),
+                auth_seqno: Some(22318272532),
+            },
+            network_mask: ip4!("255.255.255.0"),
+            hello_interval: 3,
+            options: Options::E,
+            priority: 1,
+            dead_interval: 12,
+            dr: Some(ip4!("10.0.1.3").into()),
+            bdr: Some(ip4!("10.0.1.2").into()),
+            neighbors: [ip4!("2.2.2.2"), ip4!("3.3.3.3")].into(),
+            lls: None,
+        }),
+    )
+});
+
 static HELLO1_HMAC_SHA256_LLS: Lazy<(
     Vec<u8>,
     Option<(Key, u64)>,
@@ -923,6 +972,8 @@ static LSA2: Lazy<(Vec<u8>, Lsa<Ospfv2>)> = Lazy::new(|| {
                     NodeAdminTagTlv::new([1, 2, 3].into()),
                     NodeAdminTagTlv::new([4, 5, 6].into()),
                 ],
+                fads: vec![],
+                flooding_algo: None,
                 unknown_tlvs: vec![],
             })),
         ),
@@ -964,6 +1015,7 @@ static LSA3: Lazy<(Vec<u8>, Lsa<Ospfv2>)> = Lazy::new(|| {
                         }
                     },
                 },
+                ranges: vec![],
             })),
         ),
     )
@@ -996,6 +1048,7 @@ static LSA4: Lazy<(Vec<u8>, Lsa<Ospfv2>)> = Lazy::new(|| {
                         sid: Sid::Label(Label::new(4000)),
                     }],
                     msds: Default::default(),
+                    asla: vec![],
                     unknown_tlvs: vec![],
                 }),
             })),
@@ -1003,6 +1056,69 @@ static LSA4: Lazy<(Vec<u8>, Lsa<Ospfv2>)> = Lazy::new(|| {
     )
 });
 
+static LSA5: Lazy<(Vec<u8>, Lsa<Ospfv2>)> = Lazy::new(|| {
+    (
+        vec![
+            0x00, 0x01, 0x42, 0x0a, 0x07, 0x00, 0x00, 0x01, 0x01, 0x01, 0x01,
+            0x01, 0x80, 0x00, 0x00, 0x01, 0x4a, 0xdf, 0x00, 0x30, 0x00, 0x02,
+            0x00, 0x18, 0x18, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0a,
+            0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x08, 0x20, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x64,
+        ],
+        Lsa::new(
+            1,
+            Some(Options::O | Options::E),
+            ip4!("7.0.0.1"),
+            ip4!("1.1.1.1"),
+            0x80000001,
+            LsaBody::OpaqueArea(LsaOpaque::ExtPrefix(LsaExtPrefix {
+                prefixes: btreemap! {},
+                ranges: vec![ExtPrefixRangeTlv {
+                    af: 0,
+                    range_size: 256,
+                    flags: ExtPrefixRangeFlags::empty(),
+                    prefix: net4!("10.0.0.0/24"),
+                    prefix_sids: btreemap! {
+                        IgpAlgoType::Spf => {
+                            PrefixSid {
+                                flags: PrefixSidFlags::M,
+                                algo: IgpAlgoType::Spf,
+                                sid: Sid::Index(100),
+                            }
+                        }
+                    },
+                    unknown_tlvs: vec![],
+                }],
+            })),
+        ),
+    )
+});
+
+static NSSA_LSA1: Lazy<(Vec<u8>, Lsa<Ospfv2>)> = Lazy::new(|| {
+    (
+        vec![
+            0x00, 0x01, 0x08, 0x07, 0xac, 0x10, 0x01, 0x00, 0x01, 0x01, 0x01,
+            0x01, 0x80, 0x00, 0x00, 0x01, 0x10, 0xd8, 0x00, 0x24, 0xff, 0xff,
+            0xff, 0x00, 0x80, 0x00, 0x00, 0x14, 0x0a, 0x00, 0x01, 0x01, 0x00,
+            0x00, 0x00, 0x00,
+        ],
+        Lsa::new(
+            1,
+            Some(Options::NP),
+            ip4!("172.16.1.0"),
+            ip4!("1.1.1.1"),
+            0x80000001,
+            LsaBody::NssaExternal(LsaAsExternal {
+                mask: ip4!("255.255.255.0"),
+                flags: LsaAsExternalFlags::E,
+                metric: 20,
+                fwd_addr: Some(ip4!("10.0.1.1")),
+                tag: 0,
+            }),
+        ),
+    )
+});
+
 static GRACE_LSA1: Lazy<(Vec<u8>, Lsa<Ospfv2>)> = Lazy::new(|| {
     (
         vec![
@@ -1027,6 +1143,117 @@ static GRACE_LSA1: Lazy<(Vec<u8>, Lsa<Ospfv2>)> = Lazy::new(|| {
     )
 });
 
+static FAD_LSA1: Lazy<(Vec<u8>, Lsa<Ospfv2>)> = Lazy::new(|| {
+    (
+        vec![
+            0x00, 0x01, 0x42, 0x0a, 0x04, 0x00, 0x00, 0x00, 0x01, 0x01, 0x01,
+            0x01, 0x80, 0x00, 0x00, 0x01, 0xef, 0xd7, 0x00, 0x54, 0x00, 0x10,
+            0x00, 0x34, 0x80, 0x01, 0x00, 0x64, 0x00, 0x01, 0x00, 0x04, 0x00,
+            0x00, 0x00, 0x01, 0x00, 0x02, 0x00, 0x08, 0x00, 0x00, 0x00, 0x02,
+            0x80, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x04, 0x00, 0x00, 0x00,
+            0x04, 0x00, 0x04, 0x00, 0x01, 0x80, 0x00, 0x00, 0x00, 0x00, 0x05,
+            0x00, 0x08, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x14, 0x00,
+            0x10, 0x00, 0x04, 0x81, 0x00, 0x00, 0x00,
+        ],
+        Lsa::new(
+            1,
+            Some(Options::O | Options::E),
+            OpaqueLsaId::new(LsaOpaqueType::RouterInfo as u8, 0).into(),
+            ip4!("1.1.1.1"),
+            0x80000001,
+            LsaBody::OpaqueArea(LsaOpaque::RouterInfo(LsaRouterInfo {
+                info_caps: None,
+                func_caps: None,
+                sr_algo: None,
+                srgb: vec![],
+                srlb: vec![],
+                msds: None,
+                srms_pref: None,
+                info_hostname: None,
+                node_tags: vec![],
+                fads: vec![
+                    FadTlv {
+                        flex_algo: 128,
+                        metric_type: 1,
+                        calc_type: 0,
+                        priority: 100,
+                        exclude_admin_group: Some(ExtAdminGroup::new(vec![
+                            0x00000001,
+                        ])),
+                        include_any_admin_group: Some(ExtAdminGroup::new(
+                            vec![0x00000002, 0x80000000],
+                        )),
+                        include_all_admin_group: Some(ExtAdminGroup::new(
+                            vec![0x00000004],
+                        )),
+                        flags: Some(FadFlags::M),
+                        exclude_srlgs: vec![10, 20],
+                        unknown_stlvs: vec![],
+                    },
+                    FadTlv::new(129, 0, 0, 0),
+                ],
+                flooding_algo: None,
+                unknown_tlvs: vec![],
+            })),
+        ),
+    )
+});
+
+static ASLA_LSA1: Lazy<(Vec<u8>, Lsa<Ospfv2>)> = Lazy::new(|| {
+    (
+        vec![
+            0x00, 0x01, 0x42, 0x0a, 0x08, 0x00, 0x00, 0x00, 0x01, 0x01, 0x01,
+            0x01, 0x80, 0x00, 0x00, 0x01, 0xef, 0x68, 0x00, 0x70, 0x00, 0x01,
+            0x00, 0x58, 0x01, 0x00, 0x00, 0x00, 0x02, 0x02, 0x02, 0x02, 0x0a,
+            0x00, 0x01, 0x01, 0x00, 0x0a, 0x00, 0x38, 0x04, 0x00, 0x00, 0x00,
+            0x20, 0x00, 0x00, 0x00, 0x00, 0x13, 0x00, 0x04, 0x00, 0x00, 0x00,
+            0x01, 0x00, 0x14, 0x00, 0x08, 0x00, 0x00, 0x00, 0x01, 0x80, 0x00,
+            0x00, 0x00, 0x00, 0x16, 0x00, 0x04, 0x00, 0x00, 0x00, 0x64, 0x00,
+            0x0d, 0x00, 0x08, 0x00, 0x00, 0x03, 0xe8, 0x00, 0x00, 0x07, 0xd0,
+            0x00, 0x0b, 0x00, 0x04, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x0a, 0x00,
+            0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x16, 0x00, 0x04, 0x00, 0x00,
+            0x00, 0x32,
+        ],
+        Lsa::new(
+            1,
+            Some(Options::O | Options::E),
+            ip4!("8.0.0.0"),
+            ip4!("1.1.1.1"),
+            0x80000001,
+            LsaBody::OpaqueArea(LsaOpaque::ExtLink(LsaExtLink {
+                link: Some(ExtLinkTlv {
+                    link_type: LsaRouterLinkType::PointToPoint,
+                    link_id: ip4!("2.2.2.2"),
+                    link_data: ip4!("10.0.1.1"),
+                    adj_sids: vec![],
+                    msds: Default::default(),
+                    asla: vec![
+                        AslaStlv {
+                            sabm: Some(AslaSabmFlags::F),
+                            udabm: vec![],
+                            admin_group: Some(0x00000001),
+                            ext_admin_group: Some(ExtAdminGroup::new(vec![
+                                0x00000001, 0x80000000,
+                            ])),
+                            te_metric: Some(100),
+                            min_max_delay: Some(MinMaxLinkDelay::new(
+                                false, 1000, 2000,
+                            )),
+                            srlgs: vec![10],
+                            unknown_stlvs: vec![],
+                        },
+                        AslaStlv {
+                            te_metric: Some(50),
+                            ..Default::default()
+                        },
+                    ],
+                    unknown_tlvs: vec![],
+                }),
+            })),
+        ),
+    )
+});
+
 //
 // Tests.
 //
@@ -1034,205 +1261,217 @@ static GRACE_LSA1: Lazy<(Vec<u8>, Lsa<Ospfv2>)> = Lazy::new(|| {
 #[test]
 fn test_encode_hello1() {
     let (ref bytes, ref auth, ref hello) = *HELLO1;
-    test_encode_packet(bytes, auth, hello);
+    test_encode_packet(bytes, auth, false, hello);
 }
 
 #[test]
 fn test_decode_hello1() {
     let (ref bytes, ref auth, ref hello) = *HELLO1;
-    test_decode_packet(bytes, auth, hello);
+    test_decode_packet(bytes, auth, false, hello);
 }
 
 #[test]
 fn test_encode_hello1_lls() {
     let (ref bytes, ref auth, ref hello) = *HELLO1_LLS;
-    test_encode_packet(bytes, auth, hello);
+    test_encode_packet(bytes, auth, false, hello);
 }
 
 #[test]
 fn test_decode_hello1_lls() {
     let (ref bytes, ref auth, ref hello) = *HELLO1_LLS;
-    test_decode_packet(bytes, auth, hello);
+    test_decode_packet(bytes, auth, false, hello);
 }
 
 #[test]
 fn test_encode_hello_md5() {
     let (ref bytes, ref auth, ref hello) = *HELLO1_MD5;
-    test_encode_packet(bytes, auth, hello);
+    test_encode_packet(bytes, auth, false, hello);
 }
 
 #[test]
 fn test_decode_hello_md5() {
     let (ref bytes, ref auth, ref hello) = *HELLO1_MD5;
-    test_decode_packet(bytes, auth, hello);
+    test_decode_packet(bytes, auth, false, hello);
 }
 
 #[test]
 fn test_encode_hello_md5_lls() {
     let (ref bytes, ref auth, ref hello) = *HELLO1_MD5_LLS;
-    test_encode_packet(bytes, auth, hello);
+    test_encode_packet(bytes, auth, false, hello);
 }
 
 #[test]
 fn test_decode_hello_md5_lls() {
     let (ref bytes, ref auth, ref hello) = *HELLO1_MD5_LLS;
-    test_decode_packet(bytes, auth, hello);
+    test_decode_packet(bytes, auth, false, hello);
 }
 
 #[test]
 fn test_encode_hello_hmac_sha1() {
     let (ref bytes, ref auth, ref hello) = *HELLO1_HMAC_SHA1;
-    test_encode_packet(bytes, auth, hello);
+    test_encode_packet(bytes, auth, false, hello);
 }
 
 #[test]
 fn test_decode_hello_hmac_sha1() {
     let (ref bytes, ref auth, ref hello) = *HELLO1_HMAC_SHA1;
-    test_decode_packet(bytes, auth, hello);
+    test_decode_packet(bytes, auth, false, hello);
 }
 
 #[test]
 fn test_encode_hello_hmac_sha1_lls() {
     let (ref bytes, ref auth, ref hello) = *HELLO1_HMAC_SHA1_LLS;
-    test_encode_packet(bytes, auth, hello);
+    test_encode_packet(bytes, auth, false, hello);
 }
 
 #[test]
 fn test_decode_hello_hmac_sha1_lls() {
     let (ref bytes, ref auth, ref hello) = *HELLO1_HMAC_SHA1_LLS;
-    test_decode_packet(bytes, auth, hello);
+    test_decode_packet(bytes, auth, false, hello);
 }
 
 #[test]
 fn test_encode_hello_hmac_sha256() {
     let (ref bytes, ref auth, ref hello) = *HELLO1_HMAC_SHA256;
-    test_encode_packet(bytes, auth, hello);
+    test_encode_packet(bytes, auth, false, hello);
 }
 
 #[test]
 fn test_decode_hello_hmac_sha256() {
     let (ref bytes, ref auth, ref hello) = *HELLO1_HMAC_SHA256;
-    test_decode_packet(bytes, auth, hello);
+    test_decode_packet(bytes, auth, false, hello);
+}
+
+#[test]
+fn test_encode_hello_hmac_sha256_ext_seqno() {
+    let (ref bytes, ref auth, ref hello) = *HELLO1_HMAC_SHA256_EXT_SEQNO;
+    test_encode_packet(bytes, auth, true, hello);
+}
+
+#[test]
+fn test_decode_hello_hmac_sha256_ext_seqno() {
+    let (ref bytes, ref auth, ref hello) = *HELLO1_HMAC_SHA256_EXT_SEQNO;
+    test_decode_packet(bytes, auth, true, hello);
 }
 
 #[test]
 fn test_encode_hello_hmac_sha256_lls() {
     let (ref bytes, ref auth, ref hello) = *HELLO1_HMAC_SHA256_LLS;
-    test_encode_packet(bytes, auth, hello);
+    test_encode_packet(bytes, auth, false, hello);
 }
 
 #[test]
 fn test_decode_hello_hmac_sha256_lls() {
     let (ref bytes, ref auth, ref hello) = *HELLO1_HMAC_SHA256_LLS;
-    test_decode_packet(bytes, auth, hello);
+    test_decode_packet(bytes, auth, false, hello);
 }
 
 #[test]
 fn test_encode_hello_hmac_sha384() {
     let (ref bytes, ref auth, ref hello) = *HELLO1_HMAC_SHA384;
-    test_encode_packet(bytes, auth, hello);
+    test_encode_packet(bytes, auth, false, hello);
 }
 
 #[test]
 fn test_decode_hello_hmac_sha384() {
     let (ref bytes, ref auth, ref hello) = *HELLO1_HMAC_SHA384;
-    test_decode_packet(bytes, auth, hello);
+    test_decode_packet(bytes, auth, false, hello);
 }
 
 #[test]
 fn test_encode_hello_hmac_sha384_lls() {
     let (ref bytes, ref auth, ref hello) = *HELLO1_HMAC_SHA384_LLS;
-    test_encode_packet(bytes, auth, hello);
+    test_encode_packet(bytes, auth, false, hello);
 }
 
 #[test]
 fn test_decode_hello_hmac_sha384_lls() {
     let (ref bytes, ref auth, ref hello) = *HELLO1_HMAC_SHA384_LLS;
-    test_decode_packet(bytes, auth, hello);
+    test_decode_packet(bytes, auth, false, hello);
 }
 
 #[test]
 fn test_encode_hello_hmac_sha512() {
     let (ref bytes, ref auth, ref hello) = *HELLO1_HMAC_SHA512;
-    test_encode_packet(bytes, auth, hello);
+    test_encode_packet(bytes, auth, false, hello);
 }
 
 #[test]
 fn test_decode_hello_hmac_sha512() {
     let (ref bytes, ref auth, ref hello) = *HELLO1_HMAC_SHA512;
-    test_decode_packet(bytes, auth, hello);
+    test_decode_packet(bytes, auth, false, hello);
 }
 
 #[test]
 fn test_encode_hello_hmac_sha512_lls() {
     let (ref bytes, ref auth, ref hello) = *HELLO1_HMAC_SHA512_LLS;
-    test_encode_packet(bytes, auth, hello);
+    test_encode_packet(bytes, auth, false, hello);
 }
 
 #[test]
 fn test_decode_hello_hmac_sha512_lls() {
     let (ref bytes, ref auth, ref hello) = *HELLO1_HMAC_SHA512_LLS;
-    test_decode_packet(bytes, auth, hello);
+    test_decode_packet(bytes, auth, false, hello);
 }
 
 #[test]
 fn test_encode_dbdesc1() {
     let (ref bytes, ref auth, ref dbdescr) = *DBDESC1;
-    test_encode_packet(bytes, auth, dbdescr);
+    test_encode_packet(bytes, auth, false, dbdescr);
 }
 
 #[test]
 fn test_decode_dbdesc1() {
     let (ref bytes, ref auth, ref dbdescr) = *DBDESC1;
-    test_decode_packet(bytes, auth, dbdescr);
+    test_decode_packet(bytes, auth, false, dbdescr);
 }
 
 #[test]
 fn test_encode_dbdesc1_lls() {
     let (ref bytes, ref auth, ref dbdescr) = *DBDESC1_LLS;
-    test_encode_packet(bytes, auth, dbdescr);
+    test_encode_packet(bytes, auth, false, dbdescr);
 }
 
 #[test]
 fn test_decode_dbdesc1_lls() {
     let (ref bytes, ref auth, ref dbdescr) = *DBDESC1_LLS;
-    test_decode_packet(bytes, auth, dbdescr);
+    test_decode_packet(bytes, auth, false, dbdescr);
 }
 
 #[test]
 fn test_encode_lsrequest1() {
     let (ref bytes, ref auth, ref request) = *LSREQUEST1;
-    test_encode_packet(bytes, auth, request);
+    test_encode_packet(bytes, auth, false, request);
 }
 
 #[test]
 fn test_decode_lsrequest1() {
     let (ref bytes, ref auth, ref request) = *LSREQUEST1;
-    test_decode_packet(bytes, auth, request);
+    test_decode_packet(bytes, auth, false, request);
 }
 
 #[test]
 fn test_encode_lsupdate1() {
     let (ref bytes, ref auth, ref lsupdate) = *LSUPDATE1;
-    test_encode_packet(bytes, auth, lsupdate);
+    test_encode_packet(bytes, auth, false, lsupdate);
 }
 
 #[test]
 fn test_decode_lsupdate1() {
     let (ref bytes, ref auth, ref lsupdate) = *LSUPDATE1;
-    test_decode_packet(bytes, auth, lsupdate);
+    test_decode_packet(bytes, auth, false, lsupdate);
 }
 
 #[test]
 fn test_encode_lsack1() {
     let (ref bytes, ref auth, ref lsack) = *LSACK1;
-    test_encode_packet(bytes, auth, lsack);
+    test_encode_packet(bytes, auth, false, lsack);
 }
 
 #[test]
 fn test_decode_lsack1() {
     let (ref bytes, ref auth, ref lsack) = *LSACK1;
-    test_decode_packet(bytes, auth, lsack);
+    test_decode_packet(bytes, auth, false, lsack);
 }
 
 #[test]
@@ -1283,6 +1522,30 @@ fn test_decode_lsa4() {
     test_decode_lsa(bytes, lsa);
 }
 
+#[test]
+fn test_encode_lsa5() {
+    let (ref bytes, ref lsa) = *LSA5;
+    test_encode_lsa(bytes, lsa);
+}
+
+#[test]
+fn test_decode_lsa5() {
+    let (ref bytes, ref lsa) = *LSA5;
+    test_decode_lsa(bytes, lsa);
+}
+
+#[test]
+fn test_encode_nssa_lsa1() {
+    let (ref bytes, ref lsa) = *NSSA_LSA1;
+    test_encode_lsa(bytes, lsa);
+}
+
+#[test]
+fn test_decode_nssa_lsa1() {
+    let (ref bytes, ref lsa) = *NSSA_LSA1;
+    test_decode_lsa(bytes, lsa);
+}
+
 #[test]
 fn test_encode_grace_lsa1() {
     let (ref bytes, ref lsa) = *GRACE_LSA1;
@@ -1295,6 +1558,30 @@ fn test_decode_grace_lsa1() {
     test_decode_lsa(bytes, lsa);
 }
 
+#[test]
+fn test_encode_fad_lsa1() {
+    let (ref bytes, ref lsa) = *FAD_LSA1;
+    test_encode_lsa(bytes, lsa);
+}
+
+#[test]
+fn test_decode_fad_lsa1() {
+    let (ref bytes, ref lsa) = *FAD_LSA1;
+    test_decode_lsa(bytes, lsa);
+}
+
+#[test]
+fn test_encode_asla_lsa1() {
+    let (ref bytes, ref lsa) = *ASLA_LSA1;
+    test_encode_lsa(bytes, lsa);
+}
+
+#[test]
+fn test_decode_asla_lsa1() {
+    let (ref bytes, ref lsa) = *ASLA_LSA1;
+    test_decode_lsa(bytes, lsa);
+}
+
 #[test]
 fn test_decode_invalid_auth_len() {
     let (ref bytes, ref auth_data, _) = *HELLO1_MD5;
@@ -1306,7 +1593,7 @@ fn test_decode_invalid_auth_len() {
 
     let (auth_key, _) = auth_data.as_ref().unwrap();
     let auth_method = AuthMethod::ManualKey(auth_key.clone());
-    let auth = Some(AuthDecodeCtx::new(&auth_method, SRC_ADDR.into()));
+    let auth = Some(AuthDecodeCtx::new(&auth_method, false, SRC_ADDR.into()));
 
     let mut buf = Bytes::copy_from_slice(&bytes);
     let result = Packet::<Ospfv2>::decode(AddressFamily::Ipv4, &mut buf, auth);
@@ -5,7 +5,6 @@
 //
 
 use std::net::Ipv6Addr;
-use std::sync::atomic::AtomicU64;
 use std::sync::{Arc, LazyLock as Lazy};
 
 use bytes::Bytes;
@@ -13,7 +12,9 @@ use const_addrs::{ip, ip4, net};
 use holo_ospf::ospfv3::packet::iana::*;
 use holo_ospf::ospfv3::packet::lsa::*;
 use holo_ospf::ospfv3::packet::*;
-use holo_ospf::packet::auth::{AuthDecodeCtx, AuthEncodeCtx, AuthMethod};
+use holo_ospf::packet::auth::{
+    AuthDecodeCtx, AuthEncodeCtx, AuthMethod, AuthSeqNo,
+};
 use holo_ospf::packet::error::DecodeError;
 use holo_ospf::packet::iana::*;
 use holo_ospf::packet::lls::ExtendedOptionsFlags;
@@ -45,8 +46,13 @@ fn test_encode_packet(
     let mut auth = None;
     let auth_seqno;
     if let Some((auth_key, seqno)) = auth_data {
-        auth_seqno = Arc::new(AtomicU64::new(*seqno));
-        auth = Some(AuthEncodeCtx::new(auth_key, &auth_seqno, SRC_ADDR.into()));
+        auth_seqno = Arc::new(AuthSeqNo::new(*seqno));
+        auth = Some(AuthEncodeCtx::new(
+            auth_key,
+            &auth_seqno,
+            false,
+            SRC_ADDR.into(),
+        ));
     }
 
     // Encode the packet.
@@ -65,7 +71,7 @@ fn test_decode_packet(
     let auth_method;
     if let Some((auth_key, _)) = auth_data {
         auth_method = AuthMethod::ManualKey(auth_key.clone());
-        auth = Some(AuthDecodeCtx::new(&auth_method, SRC_ADDR.into()));
+        auth = Some(AuthDecodeCtx::new(&auth_method, false, SRC_ADDR.into()));
     };
 
     // Decode the packet.
@@ -999,6 +1005,8 @@ static LSA3: Lazy<(Vec<u8>, Lsa<Ospfv3>)> = Lazy::new(|| {
                     NodeAdminTagTlv::new([1, 2, 3].into()),
                     NodeAdminTagTlv::new([4, 5, 6].into()),
                 ],
+                fads: vec![],
+                flooding_algo: None,
                 unknown_tlvs: vec![],
             }),
         ),
@@ -1037,7 +1045,7 @@ static EXT_ROUTER_LSA1: Lazy<(Vec<u8>, Lsa<Ospfv3>)> = Lazy::new(|| {
                         nbr_router_id: None,
                         sid: Sid::Label(Label::new(4000)),
                     }],
-
+                    asla: vec![],
                     unknown_stlvs: vec![],
                 }],
                 unknown_tlvs: vec![],
@@ -1314,6 +1322,123 @@ static GRACE_LSA1: Lazy<(Vec<u8>, Lsa<Ospfv3>)> = Lazy::new(|| {
     )
 });
 
+static FAD_LSA1: Lazy<(Vec<u8>, Lsa<Ospfv3>)> = Lazy::new(|| {
+    (
+        vec![
+            0x00, 0x01, 0xa0, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x01, 0x01, 0x01,
+            0x01, 0x80, 0x00, 0x00, 0x01, 0x80, 0xea, 0x00, 0x54, 0x00, 0x10,
+            0x00, 0x34, 0x80, 0x01, 0x00, 0x64, 0x00, 0x01, 0x00, 0x04, 0x00,
+            0x00, 0x00, 0x01, 0x00, 0x02, 0x00, 0x08, 0x00, 0x00, 0x00, 0x02,
+            0x80, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x04, 0x00, 0x00, 0x00,
+            0x04, 0x00, 0x04, 0x00, 0x01, 0x80, 0x00, 0x00, 0x00, 0x00, 0x05,
+            0x00, 0x08, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x00, 0x14, 0x00,
+            0x10, 0x00, 0x04, 0x81, 0x00, 0x00, 0x00,
+        ],
+        Lsa::new(
+            1,
+            None,
+            ip4!("0.0.0.0"),
+            ip4!("1.1.1.1"),
+            0x80000001,
+            LsaBody::RouterInfo(LsaRouterInfo {
+                scope: LsaScopeCode::Area,
+                info_caps: None,
+                func_caps: None,
+                sr_algo: None,
+                srgb: vec![],
+                srlb: vec![],
+                msds: Default::default(),
+                srms_pref: None,
+                info_hostname: None,
+                node_tags: vec![],
+                fads: vec![
+                    FadTlv {
+                        flex_algo: 128,
+                        metric_type: 1,
+                        calc_type: 0,
+                        priority: 100,
+                        exclude_admin_group: Some(ExtAdminGroup::new(vec![
+                            0x00000001,
+                        ])),
+                        include_any_admin_group: Some(ExtAdminGroup::new(
+                            vec![0x00000002, 0x80000000],
+                        )),
+                        include_all_admin_group: Some(ExtAdminGroup::new(
+                            vec![0x00000004],
+                        )),
+                        flags: Some(FadFlags::M),
+                        exclude_srlgs: vec![10, 20],
+                        unknown_stlvs: vec![],
+                    },
+                    FadTlv::new(129, 0, 0, 0),
+                ],
+                flooding_algo: None,
+                unknown_tlvs: vec![],
+            }),
+        ),
+    )
+});
+
+static EXT_ROUTER_ASLA_LSA1: Lazy<(Vec<u8>, Lsa<Ospfv3>)> = Lazy::new(|| {
+    (
+        vec![
+            0x00, 0x06, 0xa0, 0x21, 0x00, 0x00, 0x00, 0x00, 0x06, 0x06, 0x06,
+            0x06, 0x80, 0x00, 0x00, 0x02, 0x5e, 0x43, 0x00, 0x78, 0x01, 0x00,
+            0x01, 0x13, 0x00, 0x01, 0x00, 0x5c, 0x01, 0x00, 0x00, 0x0a, 0x00,
+            0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0x06, 0x03, 0x03, 0x03, 0x03,
+            0x00, 0x0b, 0x00, 0x38, 0x04, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00,
+            0x00, 0x00, 0x14, 0x00, 0x04, 0x00, 0x00, 0x00, 0x01, 0x00, 0x15,
+            0x00, 0x08, 0x00, 0x00, 0x00, 0x01, 0x80, 0x00, 0x00, 0x00, 0x00,
+            0x16, 0x00, 0x04, 0x00, 0x00, 0x00, 0x64, 0x00, 0x0e, 0x00, 0x08,
+            0x00, 0x00, 0x03, 0xe8, 0x00, 0x00, 0x07, 0xd0, 0x00, 0x0c, 0x00,
+            0x04, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x0b, 0x00, 0x0c, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x16, 0x00, 0x04, 0x00, 0x00, 0x00, 0x32,
+        ],
+        Lsa::new(
+            6,
+            None,
+            ip4!("0.0.0.0"),
+            ip4!("6.6.6.6"),
+            2147483650,
+            LsaBody::Router(LsaRouter {
+                extended: true,
+                flags: LsaRouterFlags::B,
+                options: Options::R | Options::E | Options::V6 | Options::AF,
+                links: vec![LsaRouterLink {
+                    link_type: LsaRouterLinkType::PointToPoint,
+                    metric: 10,
+                    iface_id: 5,
+                    nbr_iface_id: 6,
+                    nbr_router_id: ip4!("3.3.3.3"),
+                    adj_sids: vec![],
+                    asla: vec![
+                        AslaStlv {
+                            sabm: Some(AslaSabmFlags::F),
+                            udabm: vec![],
+                            admin_group: Some(0x00000001),
+                            ext_admin_group: Some(ExtAdminGroup::new(vec![
+                                0x00000001, 0x80000000,
+                            ])),
+                            te_metric: Some(100),
+                            min_max_delay: Some(MinMaxLinkDelay::new(
+                                false, 1000, 2000,
+                            )),
+                            srlgs: vec![10],
+                            unknown_stlvs: vec![],
+                        },
+                        AslaStlv {
+                            te_metric: Some(50),
+                            ..Default::default()
+                        },
+                    ],
+                    unknown_stlvs: vec![],
+                }],
+                unknown_tlvs: vec![],
+            }),
+        ),
+    )
+});
+
 //
 // Tests.
 //
@@ -1666,6 +1791,30 @@ fn test_decode_grace_lsa1() {
     test_decode_lsa(bytes, lsa, AddressFamily::Ipv4);
 }
 
+#[test]
+fn test_encode_fad_lsa1() {
+    let (ref bytes, ref lsa) = *FAD_LSA1;
+    test_encode_lsa(bytes, lsa);
+}
+
+#[test]
+fn test_decode_fad_lsa1() {
+    let (ref bytes, ref lsa) = *FAD_LSA1;
+    test_decode_lsa(bytes, lsa, AddressFamily::Ipv6);
+}
+
+#[test]
+fn test_encode_extended_router_asla_lsa1() {
+    let (ref bytes, ref lsa) = *EXT_ROUTER_ASLA_LSA1;
+    test_encode_lsa(bytes, lsa);
+}
+
+#[test]
+fn test_decode_extended_router_asla_lsa1() {
+    let (ref bytes, ref lsa) = *EXT_ROUTER_ASLA_LSA1;
+    test_decode_lsa(bytes, lsa, AddressFamily::Ipv6);
+}
+
 #[test]
 fn test_decode_invalid_lls_length() {
     let (ref bytes, ref auth_data, _) = *HELLO1_HMAC_SHA1_LLS;
@@ -1677,7 +1826,7 @@ fn test_decode_invalid_lls_length() {
 
     let (auth_key, _) = auth_data.as_ref().unwrap();
     let auth_method = AuthMethod::ManualKey(auth_key.clone());
-    let auth = Some(AuthDecodeCtx::new(&auth_method, SRC_ADDR.into()));
+    let auth = Some(AuthDecodeCtx::new(&auth_method, false, SRC_ADDR.into()));
 
     let mut buf = Bytes::copy_from_slice(&bytes);
     let result = Packet::<Ospfv3>::decode(AddressFamily::Ipv6, &mut buf, auth);
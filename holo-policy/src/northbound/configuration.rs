@@ -947,6 +947,30 @@ fn load_callbacks() -> Callbacks<Master> {
         .delete_apply(|_master, _args| {
             // TODO: implement me!
         })
+        // BGP condition: aspa-validation
+        .path(routing_policy::policy_definitions::policy_definition::statements::statement::conditions::bgp_conditions::aspa_validation::PATH)
+        .modify_apply(|master, args| {
+            let (policy_name, stmt_name) = args.list_entry.into_policy_stmt().unwrap();
+            let policy = master.policies.get_mut(&policy_name).unwrap();
+            let stmt = policy.stmts.get_mut(&stmt_name).unwrap();
+
+            let state = args.dnode.get_string();
+            let state = bgp::AspaValidationState::try_from_yang(&state).unwrap();
+            stmt.condition_add(PolicyCondition::Bgp(BgpPolicyCondition::AspaValidation(state)));
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::PolicyChange(policy.name.clone()));
+        })
+        .delete_apply(|master, args| {
+            let (policy_name, stmt_name) = args.list_entry.into_policy_stmt().unwrap();
+            let policy = master.policies.get_mut(&policy_name).unwrap();
+            let stmt = policy.stmts.get_mut(&stmt_name).unwrap();
+
+            stmt.condition_remove(PolicyConditionType::Bgp(BgpPolicyConditionType::AspaValidation));
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::PolicyChange(policy.name.clone()));
+        })
         .path(routing_policy::policy_definitions::policy_definition::statements::statement::actions::policy_result::PATH)
         .modify_apply(|master, args| {
             let (policy_name, stmt_name) = args.list_entry.into_policy_stmt().unwrap();
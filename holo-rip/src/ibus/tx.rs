@@ -41,6 +41,7 @@ pub(crate) fn route_install<V>(
             labels: Vec::new(),
         }]
         .into(),
+        backup_nexthops: Default::default(),
     };
 
     // Send message.
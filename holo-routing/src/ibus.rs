@@ -13,7 +13,8 @@ use holo_utils::ibus::{
 use holo_utils::ip::{AddressFamily, IpNetworkKind, JointPrefixMapExt};
 use holo_utils::protocol::Protocol;
 use holo_utils::southbound::{
-    AddressFlags, Nexthop, RouteKeyMsg, RouteKind, RouteMsg, RouteOpaqueAttrs,
+    AddressFlags, InterfaceFlags, Nexthop, RouteKeyMsg, RouteKind, RouteMsg,
+    RouteOpaqueAttrs,
 };
 use ipnetwork::IpNetwork;
 
@@ -91,6 +92,33 @@ pub(crate) fn process_msg(
             // Remove the local copy of the keychain.
             master.shared.keychains.remove(&keychain_name);
         }
+        IbusMsg::LdpSyncSub {} => {
+            // Send the current synchronization state of all LDP-enabled
+            // interfaces.
+            for (ifname, (_, synced)) in &master.ldp_sync.interfaces {
+                let msg = IbusMsg::LdpSyncUpd {
+                    ifname: ifname.clone(),
+                    synced: *synced,
+                };
+                send(&client.tx, msg);
+            }
+            master.ldp_sync.subscriptions.insert(client.id, client.tx);
+        }
+        IbusMsg::LdpSyncUpd { ifname, synced } => {
+            // Update the local copy and relay to all subscribers.
+            master
+                .ldp_sync
+                .interfaces
+                .insert(ifname.clone(), (client.id, synced));
+            notify_ldp_sync(master, IbusMsg::LdpSyncUpd { ifname, synced });
+        }
+        IbusMsg::LdpSyncDel { ifname } => {
+            // Remove the local copy and relay to all subscribers.
+            let removed = master.ldp_sync.interfaces.remove(&ifname);
+            if removed.is_some() {
+                notify_ldp_sync(master, IbusMsg::LdpSyncDel { ifname });
+            }
+        }
         // Nexthop tracking registration.
         IbusMsg::NexthopTrack { addr } => {
             master.rib.nht_add(client, addr);
@@ -139,10 +167,20 @@ pub(crate) fn process_msg(
         IbusMsg::BierPurge => {
             master.birt.entries.clear();
         }
+        IbusMsg::RouteStaleMark { protocol } => {
+            // Mark the client's routes as stale.
+            master.rib.route_mark_stale(client.id, protocol);
+        }
+        IbusMsg::RouteStaleSweep { protocol } => {
+            // Remove the protocol's routes that weren't refreshed.
+            master.rib.route_sweep_stale(protocol, &master.netlink_tx);
+        }
         IbusMsg::RouteRedistributeSub { protocol, af } => {
             let sub = master.rib.subscriptions.entry(client.id).or_insert(
                 RedistributeSub {
+                    client_id: client.id,
                     protocols: Default::default(),
+                    default_route: Default::default(),
                     tx: client.tx,
                 },
             );
@@ -189,7 +227,54 @@ pub(crate) fn process_msg(
                 if matches!(af, None | Some(AddressFamily::Ipv6)) {
                     sub.protocols.remove(&(AddressFamily::Ipv6, protocol));
                 }
-                if sub.protocols.is_empty() {
+                if sub.protocols.is_empty() && sub.default_route.is_empty() {
+                    o.remove();
+                }
+            }
+        }
+        IbusMsg::RouteDefaultSub { af } => {
+            let sub = master.rib.subscriptions.entry(client.id).or_insert(
+                RedistributeSub {
+                    client_id: client.id,
+                    protocols: Default::default(),
+                    default_route: Default::default(),
+                    tx: client.tx,
+                },
+            );
+            for route_af in [AddressFamily::Ipv4, AddressFamily::Ipv6] {
+                if af.is_some_and(|af| af != route_af) {
+                    continue;
+                }
+                sub.default_route.insert(route_af);
+
+                // Notify the active default route, if any.
+                let prefix = IpNetwork::default(route_af);
+                if let Some(best_route) = master
+                    .rib
+                    .ip
+                    .get(&prefix)
+                    .and_then(|routes| routes.values().next())
+                    .filter(|route| {
+                        route.flags.contains(RouteFlags::ACTIVE)
+                            && !route.flags.contains(RouteFlags::REMOVED)
+                    })
+                {
+                    notify_redistribute_add(sub, prefix, best_route);
+                }
+            }
+        }
+        IbusMsg::RouteDefaultUnsub { af } => {
+            if let hash_map::Entry::Occupied(mut o) =
+                master.rib.subscriptions.entry(client.id)
+            {
+                let sub = o.get_mut();
+                if matches!(af, None | Some(AddressFamily::Ipv4)) {
+                    sub.default_route.remove(&AddressFamily::Ipv4);
+                }
+                if matches!(af, None | Some(AddressFamily::Ipv6)) {
+                    sub.default_route.remove(&AddressFamily::Ipv6);
+                }
+                if sub.protocols.is_empty() && sub.default_route.is_empty() {
                     o.remove();
                 }
             }
@@ -203,7 +288,20 @@ pub(crate) fn process_notification_msg(master: &mut Master, msg: IbusMsg) {
     match msg {
         // Interface update notification.
         IbusMsg::InterfaceUpd(msg) => {
+            let old_flags = master
+                .interfaces
+                .get_by_ifindex(msg.ifindex)
+                .map(|iface| iface.flags);
             master.interfaces.update(msg.ifname, msg.ifindex, msg.flags);
+
+            // Switch affected routes to or from their backup nexthops when
+            // the operational status of the interface changes.
+            if let Some(old_flags) = old_flags
+                && old_flags.contains(InterfaceFlags::OPERATIVE)
+                    != msg.flags.contains(InterfaceFlags::OPERATIVE)
+            {
+                master.rib.backup_nexthops_reevaluate(msg.ifindex);
+            }
         }
         // Interface delete notification.
         IbusMsg::InterfaceDel(ifname) => {
@@ -231,6 +329,7 @@ pub(crate) fn process_notification_msg(master: &mut Master, msg: IbusMsg) {
                     tag: None,
                     opaque_attrs: RouteOpaqueAttrs::None,
                     nexthops: [Nexthop::Interface { ifindex }].into(),
+                    backup_nexthops: Default::default(),
                 });
             }
         }
@@ -263,7 +362,22 @@ pub(crate) fn disconnect(master: &mut Master, id: IbusClientId) {
     for nhte in master.rib.nht.values_mut() {
         nhte.subscriptions.remove(&id);
     }
-    master.rib.route_remove_all_by_owner(id);
+    let gr_protocols = crate::gr_pending_protocols(&master.shared);
+    master.rib.route_remove_all_by_owner(id, &gr_protocols);
+
+    // Withdraw the LDP-IGP synchronization state advertised by the client.
+    master.ldp_sync.subscriptions.remove(&id);
+    let mut ifnames = vec![];
+    master.ldp_sync.interfaces.retain(|ifname, (owner, _)| {
+        if *owner != id {
+            return true;
+        }
+        ifnames.push(ifname.clone());
+        false
+    });
+    for ifname in ifnames {
+        notify_ldp_sync(master, IbusMsg::LdpSyncDel { ifname });
+    }
 }
 
 // Requests information about all interfaces addresses.
@@ -277,10 +391,17 @@ pub(crate) fn notify_redistribute_add(
     prefix: IpNetwork,
     route: &Route,
 ) {
-    if !sub
+    let redistribute = sub
         .protocols
-        .contains(&(prefix.address_family(), route.protocol))
-    {
+        .contains(&(prefix.address_family(), route.protocol));
+    if !redistribute && !is_default_sub(sub, prefix) {
+        return;
+    }
+
+    // Report the subscriber's own default route as deleted, as it can't be
+    // used to condition the origination of that same route.
+    if !redistribute && route.owner == sub.client_id {
+        notify_redistribute_del(sub, prefix, route.protocol);
         return;
     }
 
@@ -293,6 +414,7 @@ pub(crate) fn notify_redistribute_add(
         tag: route.tag,
         opaque_attrs: route.opaque_attrs,
         nexthops: route.nexthops.clone(),
+        backup_nexthops: Default::default(),
     };
     let msg = IbusMsg::RouteRedistributeAdd(msg);
     send(&sub.tx, msg.clone());
@@ -304,7 +426,9 @@ pub(crate) fn notify_redistribute_del(
     prefix: IpNetwork,
     protocol: Protocol,
 ) {
-    if !sub.protocols.contains(&(prefix.address_family(), protocol)) {
+    if !sub.protocols.contains(&(prefix.address_family(), protocol))
+        && !is_default_sub(sub, prefix)
+    {
         return;
     }
 
@@ -324,8 +448,21 @@ pub(crate) fn notify_nht_update(addr: IpAddr, nhte: &NhtEntry) {
     }
 }
 
+// Sends LDP-IGP synchronization notification to all subscribers.
+fn notify_ldp_sync(master: &Master, msg: IbusMsg) {
+    for ibus_tx in master.ldp_sync.subscriptions.values() {
+        send(ibus_tx, msg.clone());
+    }
+}
+
 // ===== helper functions =====
 
+// Checks whether the given prefix is a default route the subscriber has
+// requested updates for.
+fn is_default_sub(sub: &RedistributeSub, prefix: IpNetwork) -> bool {
+    prefix.prefix() == 0 && sub.default_route.contains(&prefix.address_family())
+}
+
 fn send(ibus_tx: &IbusSender, msg: IbusMsg) {
     let _ = ibus_tx.send(msg);
 }
@@ -12,7 +12,7 @@ pub mod northbound;
 mod rib;
 mod sysctl;
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 
 use derive_new::new;
 use futures::stream::{SelectAll, StreamExt};
@@ -21,6 +21,7 @@ use holo_northbound::{
 };
 use holo_protocol::InstanceShared;
 use holo_utils::bier::BierCfg;
+use holo_utils::gr;
 use holo_utils::ibus::{
     IbusChannelsTx, IbusClient, IbusClientId, IbusConnEvent, IbusConnReceiver,
     IbusConnStream, IbusMsg, IbusReceiver, IbusSender, connection_stream,
@@ -62,6 +63,17 @@ pub struct Master {
     pub instances: BTreeMap<InstanceId, InstanceHandle>,
     // BIER Routing Table (BIRT)
     pub birt: Birt,
+    // LDP-IGP synchronization state.
+    pub ldp_sync: LdpSync,
+}
+
+#[derive(Debug, Default)]
+pub struct LdpSync {
+    // Synchronization state of the LDP-enabled interfaces, along with the
+    // client that advertised them.
+    pub interfaces: BTreeMap<String, (IbusClientId, bool)>,
+    // Clients subscribed to synchronization updates.
+    pub subscriptions: HashMap<IbusClientId, IbusSender>,
 }
 
 #[derive(Debug, Eq, Hash, PartialEq, PartialOrd, new, Ord)]
@@ -197,6 +209,17 @@ fn event_aggregator(
     })
 }
 
+// Returns the protocols that have a graceful restart in progress.
+fn gr_pending_protocols(shared: &InstanceShared) -> BTreeSet<Protocol> {
+    let Some(db) = &shared.db else {
+        return Default::default();
+    };
+    let db = db.lock().unwrap();
+    Protocol::route_types()
+        .filter(|protocol| gr::restart_pending(&db, *protocol))
+        .collect()
+}
+
 // ===== global functions =====
 
 pub fn start(
@@ -226,6 +249,7 @@ pub fn start(
             bier_config: Default::default(),
             instances: Default::default(),
             birt: Birt::new(birt_update_queue_tx),
+            ldp_sync: Default::default(),
         };
 
         // Request information about all interfaces addresses.
@@ -248,8 +272,9 @@ pub fn start(
         let netlink_handle = netlink::init();
 
         // Purge stale routes potentially left behind by a previous Holo
-        // instance.
-        netlink::purge_stale_routes(&netlink_handle).await;
+        // instance, except those of protocols performing a graceful restart.
+        let gr_protocols = gr_pending_protocols(&shared);
+        netlink::purge_stale_routes(&netlink_handle, &gr_protocols).await;
 
         // Start netlink Tx task.
         let netlink_tx_task = tokio::task::spawn(async move {
@@ -292,8 +317,12 @@ pub fn start(
                 birt_update_queue_rx,
             );
 
-            // Uninstall all routes before exiting.
-            master.rib.route_uninstall_all(&master.netlink_tx);
+            // Uninstall all routes before exiting, except those of protocols
+            // performing a graceful restart.
+            let gr_protocols = gr_pending_protocols(&master.shared);
+            master
+                .rib
+                .route_uninstall_all(&master.netlink_tx, &gr_protocols);
             drop(master.netlink_tx);
             let _ = tokio::runtime::Handle::current().block_on(netlink_tx_task);
         });
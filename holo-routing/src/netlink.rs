@@ -4,7 +4,8 @@
 // SPDX-License-Identifier: MIT
 //
 
-use std::net::IpAddr;
+use std::collections::BTreeSet;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 use std::num::NonZeroI32;
 
 use capctl::caps::CapState;
@@ -16,7 +17,8 @@ use ipnetwork::IpNetwork;
 use netlink_packet_core::ErrorMessage;
 use netlink_packet_route::AddressFamily;
 use netlink_packet_route::route::{
-    MplsLabel, RouteMessage, RouteNextHop, RouteProtocol, RouteType,
+    MplsLabel, RouteAddress, RouteAttribute, RouteMessage, RouteNextHop,
+    RouteProtocol, RouteType,
 };
 use rtnetlink::{
     Error, Handle, RouteMessageBuilder, RouteNextHopBuilder, new_connection,
@@ -30,6 +32,11 @@ use crate::rib::Route;
 pub enum NetlinkRequest {
     RouteAdd(RouteMessage),
     RouteDel(RouteMessage),
+    StaleRoutesPurge {
+        protocol: RouteProtocol,
+        af: AddressFamily,
+        keep: BTreeSet<IpNetwork>,
+    },
 }
 
 // ===== impl NetlinkRequest =====
@@ -59,6 +66,25 @@ impl NetlinkRequest {
                     error!(%error, "failed to uninstall route");
                 }
             }
+            NetlinkRequest::StaleRoutesPurge { protocol, af, keep } => {
+                let msg = RouteMessageBuilder::<IpAddr>::new().build();
+                let mut routes = handle.route().get(msg).execute();
+                while let Ok(Some(route)) = routes.try_next().await {
+                    if route.header.protocol != protocol
+                        || route.header.address_family != af
+                        || route_prefix(&route)
+                            .is_none_or(|prefix| keep.contains(&prefix))
+                    {
+                        continue;
+                    }
+
+                    if let Err(error) =
+                        handle.route().del(route).execute().await
+                    {
+                        warn!(?protocol, ?error, "failed to purge stale route");
+                    }
+                }
+            }
         }
     }
 }
@@ -77,7 +103,8 @@ pub(crate) fn ip_route_install(
         IpNetwork::V4(_) => AddressFamily::Inet,
         IpNetwork::V6(_) => AddressFamily::Inet6,
     };
-    let nexthops = netlink_nexthops(af, route.nexthops.iter(), interfaces);
+    let nexthops =
+        netlink_nexthops(af, route.fib_nexthops(interfaces).iter(), interfaces);
     let msg = RouteMessageBuilder::<IpAddr>::new()
         .destination_prefix(prefix.ip(), prefix.prefix())
         .unwrap()
@@ -164,6 +191,30 @@ pub(crate) fn mpls_route_uninstall(
     netlink_tx.send(NetlinkRequest::RouteDel(msg)).unwrap();
 }
 
+// Purges routes of the given protocol left in the kernel by a previous Holo
+// instance, except those whose prefix is present in the RIB.
+//
+// This is used once a graceful restart that spanned a restart of the daemon
+// is complete, as the routes preserved across the restart aren't known to the
+// RIB.
+pub(crate) fn stale_routes_purge(
+    netlink_tx: &UnboundedSender<NetlinkRequest>,
+    protocol: Protocol,
+    keep: BTreeSet<IpNetwork>,
+) {
+    let af = match protocol {
+        Protocol::OSPFV2 | Protocol::RIPV2 => AddressFamily::Inet,
+        Protocol::OSPFV3 | Protocol::RIPNG => AddressFamily::Inet6,
+        _ => return,
+    };
+    let protocol = netlink_protocol(protocol);
+
+    // Enqueue netlink request.
+    netlink_tx
+        .send(NetlinkRequest::StaleRoutesPurge { protocol, af, keep })
+        .unwrap();
+}
+
 // Purge stale routes that may have been left behind by a previous Holo
 // instance.
 //
@@ -173,8 +224,16 @@ pub(crate) fn mpls_route_uninstall(
 //
 // This function should be called during startup to clean up any such stale
 // routes. It filters routes by protocol type (e.g., BGP, OSPF), assuming that
-// only Holo installs routes using those protocols.
-pub(crate) async fn purge_stale_routes(handle: &Handle) {
+// only Holo installs routes using those protocols. Routes of protocols
+// performing a graceful restart are preserved.
+pub(crate) async fn purge_stale_routes(
+    handle: &Handle,
+    gr_protocols: &BTreeSet<Protocol>,
+) {
+    let gr_protocols = gr_protocols
+        .iter()
+        .map(|protocol| netlink_protocol(*protocol))
+        .collect::<Vec<_>>();
     let msg = RouteMessageBuilder::<IpAddr>::new().build();
     let mut routes = handle.route().get(msg).execute();
     while let Ok(Some(route)) = routes.try_next().await {
@@ -187,7 +246,8 @@ pub(crate) async fn purge_stale_routes(handle: &Handle) {
                 | RouteProtocol::Ospf
                 | RouteProtocol::Rip
                 | RouteProtocol::Static
-        ) {
+        ) || gr_protocols.contains(&protocol)
+        {
             continue;
         }
 
@@ -233,6 +293,27 @@ fn netlink_protocol(protocol: Protocol) -> RouteProtocol {
     }
 }
 
+fn route_prefix(route: &RouteMessage) -> Option<IpNetwork> {
+    let addr = route
+        .attributes
+        .iter()
+        .find_map(|attr| match attr {
+            RouteAttribute::Destination(RouteAddress::Inet(addr)) => {
+                Some(IpAddr::from(*addr))
+            }
+            RouteAttribute::Destination(RouteAddress::Inet6(addr)) => {
+                Some(IpAddr::from(*addr))
+            }
+            _ => None,
+        })
+        .or(match route.header.address_family {
+            AddressFamily::Inet => Some(Ipv4Addr::UNSPECIFIED.into()),
+            AddressFamily::Inet6 => Some(Ipv6Addr::UNSPECIFIED.into()),
+            _ => None,
+        })?;
+    IpNetwork::new(addr, route.header.destination_prefix_length).ok()
+}
+
 fn netlink_nexthops<'a>(
     af: AddressFamily,
     nexthops: impl Iterator<Item = &'a Nexthop>,
@@ -17,7 +17,7 @@ use holo_utils::ip::{AddressFamily, IpNetworkKind};
 use holo_utils::mpls::LabelRange;
 use holo_utils::protocol::Protocol;
 use holo_utils::southbound::{Nexthop, RouteKeyMsg, RouteKind, RouteMsg, RouteOpaqueAttrs};
-use holo_utils::sr::{IgpAlgoType, SidLastHopBehavior, SrCfgEvent, SrCfgPrefixSid};
+use holo_utils::sr::{IgpAlgoType, SidLastHopBehavior, SrCfgEvent, SrCfgMsEntry, SrCfgMsPolicy, SrCfgPrefixSid};
 use holo_utils::yang::DataNodeRefExt;
 use holo_yang::TryFromYang;
 use ipnetwork::IpNetwork;
@@ -41,6 +41,8 @@ pub enum ListEntry {
     StaticRoute(IpNetwork),
     StaticRouteNexthop(IpNetwork, String),
     SrCfgPrefixSid(IpNetwork, IgpAlgoType),
+    SrCfgMsPolicy(String),
+    SrCfgMsEntry(String, IpNetwork, IgpAlgoType),
     BierCfgSubDomain(SubDomainId, AddressFamily),
     BierCfgEncapsulation(SubDomainId, AddressFamily, Bsl, BierEncapsulationType),
     BierCfgBift(BfrId),
@@ -61,6 +63,7 @@ pub enum Event {
     SrCfgUpdate,
     SrCfgLabelRangeUpdate,
     SrCfgPrefixSidUpdate(AddressFamily),
+    SrCfgMappingServerUpdate,
     BierCfgUpdate,
     BierCfgEncapUpdate(SubDomainId, AddressFamily, Bsl, BierEncapsulationType),
     BierCfgSubDomainUpdate(AddressFamily),
@@ -526,6 +529,141 @@ fn load_callbacks() -> Callbacks<Master> {
             event_queue.insert(Event::SrCfgUpdate);
             event_queue.insert(Event::SrCfgPrefixSidUpdate(prefix.address_family()));
         })
+        .path(sr_mpls::bindings::flex_algo_prefix_sid_map::flex_algo_prefix_sid::PATH)
+        .create_apply(|master, args| {
+            let prefix = args.dnode.get_prefix_relative("./prefix").unwrap();
+            let algo = args.dnode.get_u8_relative("./algorithm").unwrap();
+            let algo = IgpAlgoType::FlexAlgo(algo);
+            let index = args.dnode.get_u32_relative("./start-sid").unwrap();
+            let last_hop = args.dnode.get_string_relative("./last-hop-behavior").unwrap();
+            let last_hop = SidLastHopBehavior::try_from_yang(&last_hop).unwrap();
+            let psid = SrCfgPrefixSid::new(index, last_hop);
+            master.sr_config.prefix_sids.insert((prefix, algo), psid);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::SrCfgUpdate);
+            event_queue.insert(Event::SrCfgPrefixSidUpdate(prefix.address_family()));
+        })
+        .delete_apply(|master, args| {
+            let (prefix, algo) = args.list_entry.into_sr_cfg_prefix_sid().unwrap();
+            master.sr_config.prefix_sids.remove(&(prefix, algo));
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::SrCfgUpdate);
+            event_queue.insert(Event::SrCfgPrefixSidUpdate(prefix.address_family()));
+        })
+        .lookup(|_master, _list_entry, dnode| {
+            let prefix = dnode.get_prefix_relative("./prefix").unwrap();
+            let algo = dnode.get_u8_relative("./algorithm").unwrap();
+            ListEntry::SrCfgPrefixSid(prefix, IgpAlgoType::FlexAlgo(algo))
+        })
+        .path(sr_mpls::bindings::flex_algo_prefix_sid_map::flex_algo_prefix_sid::start_sid::PATH)
+        .modify_apply(|master, args| {
+            let (prefix, algo) = args.list_entry.into_sr_cfg_prefix_sid().unwrap();
+            let psid = master.sr_config.prefix_sids.get_mut(&(prefix, algo)).unwrap();
+
+            let index = args.dnode.get_u32();
+            psid.index = index;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::SrCfgUpdate);
+            event_queue.insert(Event::SrCfgPrefixSidUpdate(prefix.address_family()));
+        })
+        .path(sr_mpls::bindings::flex_algo_prefix_sid_map::flex_algo_prefix_sid::last_hop_behavior::PATH)
+        .modify_apply(|master, args| {
+            let (prefix, algo) = args.list_entry.into_sr_cfg_prefix_sid().unwrap();
+            let psid = master.sr_config.prefix_sids.get_mut(&(prefix, algo)).unwrap();
+
+            let last_hop = args.dnode.get_string();
+            let last_hop = SidLastHopBehavior::try_from_yang(&last_hop).unwrap();
+            psid.last_hop = last_hop;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::SrCfgUpdate);
+            event_queue.insert(Event::SrCfgPrefixSidUpdate(prefix.address_family()));
+        })
+        .path(sr_mpls::bindings::mapping_server::policy::PATH)
+        .create_apply(|master, args| {
+            let name = args.dnode.get_string_relative("./name").unwrap();
+            master.sr_config.ms_policies.insert(name, SrCfgMsPolicy::default());
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::SrCfgUpdate);
+            event_queue.insert(Event::SrCfgMappingServerUpdate);
+        })
+        .delete_apply(|master, args| {
+            let name = args.list_entry.into_sr_cfg_ms_policy().unwrap();
+            master.sr_config.ms_policies.remove(&name);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::SrCfgUpdate);
+            event_queue.insert(Event::SrCfgMappingServerUpdate);
+        })
+        .lookup(|_master, _list_entry, dnode| {
+            let name = dnode.get_string_relative("./name").unwrap();
+            ListEntry::SrCfgMsPolicy(name)
+        })
+        .path(sr_mpls::bindings::mapping_server::policy::entries::mapping_entry::PATH)
+        .create_apply(|master, args| {
+            let name = args.list_entry.into_sr_cfg_ms_policy().unwrap();
+            let policy = master.sr_config.ms_policies.get_mut(&name).unwrap();
+
+            let prefix = args.dnode.get_prefix_relative("./prefix").unwrap();
+            let algo = args.dnode.get_string_relative("./algorithm").unwrap();
+            let algo = IgpAlgoType::try_from_yang(&algo).unwrap();
+            let index = args.dnode.get_u32_relative("./start-sid").unwrap();
+            let range = args.dnode.get_u32_relative("./range").unwrap();
+            let entry = SrCfgMsEntry::new(index, range);
+            policy.entries.insert((prefix, algo), entry);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::SrCfgUpdate);
+            event_queue.insert(Event::SrCfgMappingServerUpdate);
+        })
+        .delete_apply(|master, args| {
+            let (name, prefix, algo) = args.list_entry.into_sr_cfg_ms_entry().unwrap();
+            let policy = master.sr_config.ms_policies.get_mut(&name).unwrap();
+
+            policy.entries.remove(&(prefix, algo));
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::SrCfgUpdate);
+            event_queue.insert(Event::SrCfgMappingServerUpdate);
+        })
+        .lookup(|_master, list_entry, dnode| {
+            let name = list_entry.into_sr_cfg_ms_policy().unwrap();
+
+            let prefix = dnode.get_prefix_relative("./prefix").unwrap();
+            let algo = dnode.get_string_relative("./algorithm").unwrap();
+            let algo = IgpAlgoType::try_from_yang(&algo).unwrap();
+            ListEntry::SrCfgMsEntry(name, prefix, algo)
+        })
+        .path(sr_mpls::bindings::mapping_server::policy::entries::mapping_entry::start_sid::PATH)
+        .modify_apply(|master, args| {
+            let (name, prefix, algo) = args.list_entry.into_sr_cfg_ms_entry().unwrap();
+            let policy = master.sr_config.ms_policies.get_mut(&name).unwrap();
+            let entry = policy.entries.get_mut(&(prefix, algo)).unwrap();
+
+            let index = args.dnode.get_u32();
+            entry.index = index;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::SrCfgUpdate);
+            event_queue.insert(Event::SrCfgMappingServerUpdate);
+        })
+        .path(sr_mpls::bindings::mapping_server::policy::entries::mapping_entry::range::PATH)
+        .modify_apply(|master, args| {
+            let (name, prefix, algo) = args.list_entry.into_sr_cfg_ms_entry().unwrap();
+            let policy = master.sr_config.ms_policies.get_mut(&name).unwrap();
+            let entry = policy.entries.get_mut(&(prefix, algo)).unwrap();
+
+            let range = args.dnode.get_u32();
+            entry.range = range;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::SrCfgUpdate);
+            event_queue.insert(Event::SrCfgMappingServerUpdate);
+        })
         .path(sr_mpls::srgb::srgb::PATH)
         .create_prepare(|master, args| {
             let lower_bound = args.dnode.get_u32_relative("./lower-bound").unwrap();
@@ -1062,6 +1200,34 @@ fn load_validation_callbacks() -> ValidationCallbacks {
 
             Ok(())
         })
+        .path(sr_mpls::bindings::connected_prefix_sid_map::connected_prefix_sid::PATH)
+        .validate(|args| {
+            let algo = args.dnode.get_string_relative("./algorithm").unwrap();
+
+            // Flexible Algorithm Prefix-SIDs are configured separately since
+            // they require an algorithm number.
+            if IgpAlgoType::try_from_yang(&algo).is_none() {
+                return Err("unsupported Prefix-SID algorithm (use the flex-algo-prefix-sid list for Flexible Algorithms)".to_owned());
+            }
+
+            Ok(())
+        })
+        .path(sr_mpls::bindings::mapping_server::policy::entries::mapping_entry::PATH)
+        .validate(|args| {
+            let algo = args.dnode.get_string_relative("./algorithm").unwrap();
+            if IgpAlgoType::try_from_yang(&algo).is_none() {
+                return Err("unsupported mapping entry algorithm".to_owned());
+            }
+
+            // The range is encoded as a 16-bit field in the IS-IS SID/Label
+            // Binding TLV and in the OSPFv2 Extended Prefix Range TLV.
+            let range = args.dnode.get_u32_relative("./range").unwrap();
+            if range == 0 || range > u16::MAX as u32 {
+                return Err("mapping entry range must be between 1 and 65535".to_owned());
+            }
+
+            Ok(())
+        })
         .build()
 }
 
@@ -1160,6 +1326,7 @@ impl Provider for Master {
                     tag: None,
                     opaque_attrs: RouteOpaqueAttrs::None,
                     nexthops,
+                    backup_nexthops: Default::default(),
                 };
 
                 // Send message.
@@ -1196,6 +1363,12 @@ impl Provider for Master {
                     let _ = instance.ibus_tx.send(IbusMsg::SrCfgEvent(SrCfgEvent::PrefixSidUpdate(af)));
                 }
             }
+            Event::SrCfgMappingServerUpdate => {
+                // Notify protocol instances about the updated mapping server configuration.
+                for instance in self.instances.values() {
+                    let _ = instance.ibus_tx.send(IbusMsg::SrCfgEvent(SrCfgEvent::MappingServerUpdate));
+                }
+            }
             Event::BierCfgUpdate => {
                 // Update the shared BIER configuration by creating a new reference-counted copy.
                 self.shared.bier_config = Arc::new(self.bier_config.clone());
@@ -18,12 +18,12 @@ impl Provider for Master {
     const YANG_OPS: YangOps<Self> = yang_gen::ops::YANG_OPS_RPC;
 
     fn relay_rpc(&self, rpc: &DataNodeRef<'_>) -> Result<Option<Vec<NbDaemonSender>>, RpcError> {
-        let (protocol, name) = find_instance(rpc)?;
+        let (protocols, name) = find_instance(rpc)?;
 
         let mut child_tasks = vec![];
         for (instance_id, instance) in &self.instances {
             // Filter by protocol type.
-            if instance_id.protocol != protocol {
+            if !protocols.contains(&instance_id.protocol) {
                 continue;
             }
 
@@ -46,41 +46,42 @@ impl Provider for Master {
 // Using top-level RPCs in the IETF IGP modules was a mistake, since there's no
 // easy way to identify the protocol type and name. YANG actions would greatly
 // simplify this.
-fn find_instance(rpc: &DataNodeRef<'_>) -> Result<(Protocol, Option<String>), RpcError> {
-    let (protocol, name) = match rpc.schema().module().name() {
+fn find_instance(rpc: &DataNodeRef<'_>) -> Result<(&'static [Protocol], Option<String>), RpcError> {
+    let (protocols, name) = match rpc.schema().module().name() {
         "ietf-bgp" => {
-            let protocol = Protocol::BGP;
+            let protocols = &[Protocol::BGP][..];
             let name = rpc.get_string_relative(control_plane_protocol::name::PATH.as_ref());
-            (protocol, name)
+            (protocols, name)
         }
         "ietf-isis" => {
-            let protocol = Protocol::ISIS;
+            let protocols = &[Protocol::ISIS][..];
             let name = rpc.get_string_relative("./routing-protocol-instance-name");
-            (protocol, name)
+            (protocols, name)
         }
         "ietf-mpls-ldp" => {
-            let protocol = Protocol::LDP;
+            let protocols = &[Protocol::LDP][..];
             let name = match rpc.path().as_ref() {
                 "/ietf-mpls-ldp:mpls-ldp-clear-peer" | "/ietf-mpls-ldp:mpls-ldp-clear-peer-statistics" => rpc.get_string_relative("./protocol-name"),
                 "/ietf-mpls-ldp:mpls-ldp-clear-hello-adjacency" => rpc.get_string_relative("./hello-adjacency/protocol-name"),
                 _ => None,
             };
-            (protocol, name)
+            (protocols, name)
         }
-        "ietf-ospf" => {
-            // TODO
-            let protocol = Protocol::OSPFV2;
+        "ietf-ospf" | "holo-ospf" => {
+            // The same RPCs are shared by OSPFv2 and OSPFv3, so the instance
+            // is identified by its name only.
+            let protocols = &[Protocol::OSPFV2, Protocol::OSPFV3][..];
             let name = rpc.get_string_relative("./routing-protocol-name");
-            (protocol, name)
+            (protocols, name)
         }
         "ietf-rip" => {
             // TODO
-            let protocol = Protocol::RIPV2;
+            let protocols = &[Protocol::RIPV2][..];
             let name = rpc.get_string_relative("./rip-instance");
-            (protocol, name)
+            (protocols, name)
         }
         _ => return Err(RpcError::new(RpcErrorTag::OperationFailed).with_message("unknown protocol")),
     };
 
-    Ok((protocol, name))
+    Ok((protocols, name))
 }
@@ -15,8 +15,8 @@ use holo_utils::ip::{AddressFamily, IpAddrExt};
 use holo_utils::mpls::Label;
 use holo_utils::protocol::Protocol;
 use holo_utils::southbound::{
-    LabelInstallMsg, LabelUninstallMsg, Nexthop, RouteKeyMsg, RouteKind,
-    RouteMsg, RouteOpaqueAttrs,
+    InterfaceFlags, LabelInstallMsg, LabelUninstallMsg, Nexthop, RouteKeyMsg,
+    RouteKind, RouteMsg, RouteOpaqueAttrs,
 };
 use ipnetwork::IpNetwork;
 use prefix_trie::joint::map::JointPrefixMap;
@@ -48,6 +48,8 @@ pub struct Route {
     pub tag: Option<u32>,
     pub opaque_attrs: RouteOpaqueAttrs,
     pub nexthops: BTreeSet<Nexthop>,
+    #[new(default)]
+    pub backup_nexthops: BTreeSet<Nexthop>,
     pub last_updated: DateTime<Utc>,
     pub flags: RouteFlags,
 }
@@ -57,6 +59,7 @@ bitflags! {
     pub struct RouteFlags: u8 {
         const ACTIVE = 0x01;
         const REMOVED = 0x02;
+        const STALE = 0x04;
     }
 }
 
@@ -69,7 +72,9 @@ pub struct NhtEntry {
 #[derive(Debug)]
 #[derive(new)]
 pub struct RedistributeSub {
+    pub client_id: IbusClientId,
     pub protocols: BTreeSet<(AddressFamily, Protocol)>,
+    pub default_route: BTreeSet<AddressFamily>,
     pub tx: IbusSender,
 }
 
@@ -99,7 +104,7 @@ impl Rib {
         match rib_prefix.entry(msg.distance) {
             btree_map::Entry::Vacant(v) => {
                 // If the IP route does not exist, create a new entry.
-                v.insert(Route::new(
+                let route = v.insert(Route::new(
                     msg.protocol,
                     owner,
                     msg.kind,
@@ -111,6 +116,7 @@ impl Rib {
                     Utc::now(),
                     RouteFlags::empty(),
                 ));
+                route.backup_nexthops = msg.backup_nexthops;
             }
             btree_map::Entry::Occupied(o) => {
                 let route = o.into_mut();
@@ -123,8 +129,9 @@ impl Rib {
                 route.tag = msg.tag;
                 route.opaque_attrs = msg.opaque_attrs;
                 route.nexthops = msg.nexthops;
+                route.backup_nexthops = msg.backup_nexthops;
                 route.last_updated = Utc::now();
-                route.flags.remove(RouteFlags::REMOVED);
+                route.flags.remove(RouteFlags::REMOVED | RouteFlags::STALE);
             }
         }
 
@@ -184,7 +191,7 @@ impl Rib {
                     route.merge_nexthops(&msg.nexthops);
                 }
                 route.last_updated = Utc::now();
-                route.flags.remove(RouteFlags::REMOVED);
+                route.flags.remove(RouteFlags::REMOVED | RouteFlags::STALE);
             }
         }
 
@@ -488,11 +495,45 @@ impl Rib {
         let _ = self.update_queue_tx.send(());
     }
 
+    // Reevaluates the active routes that have backup nexthops after an
+    // interface operational status change.
+    //
+    // This allows traffic to be switched over to the precomputed backup
+    // nexthops as soon as the failure is detected, without waiting for the
+    // owning protocol to reconverge.
+    pub(crate) fn backup_nexthops_reevaluate(&mut self, ifindex: u32) {
+        for (prefix, rib_prefix) in self.ip.iter() {
+            if rib_prefix.values().any(|route| {
+                route.flags.contains(RouteFlags::ACTIVE)
+                    && !route.backup_nexthops.is_empty()
+                    && route
+                        .nexthops
+                        .iter()
+                        .chain(route.backup_nexthops.iter())
+                        .any(|nexthop| nexthop.ifindex() == Some(ifindex))
+            }) {
+                self.ip_update_queue.insert(prefix);
+            }
+        }
+        let _ = self.update_queue_tx.send(());
+    }
+
     // Removes all IP and MPLS routes installed by the given client.
-    pub(crate) fn route_remove_all_by_owner(&mut self, owner: IbusClientId) {
+    //
+    // Routes of protocols performing a graceful restart are marked as stale
+    // instead, so that they're preserved until the restart is complete.
+    pub(crate) fn route_remove_all_by_owner(
+        &mut self,
+        owner: IbusClientId,
+        gr_protocols: &BTreeSet<Protocol>,
+    ) {
         for (prefix, rib_prefix) in self.ip.iter_mut() {
             for route in rib_prefix.values_mut() {
                 if route.owner == owner {
+                    if gr_protocols.contains(&route.protocol) {
+                        route.flags.insert(RouteFlags::STALE);
+                        continue;
+                    }
                     route.flags.insert(RouteFlags::REMOVED);
                     self.ip_update_queue.insert(prefix);
                 }
@@ -500,23 +541,98 @@ impl Rib {
         }
         for (label, route) in &mut self.mpls {
             if route.owner == owner {
+                if gr_protocols.contains(&route.protocol) {
+                    route.flags.insert(RouteFlags::STALE);
+                    continue;
+                }
+                route.flags.insert(RouteFlags::REMOVED);
+                self.mpls_update_queue.insert(*label);
+            }
+        }
+        let _ = self.update_queue_tx.send(());
+    }
+
+    // Marks all IP and MPLS routes of the given protocol installed by the
+    // given client as stale.
+    //
+    // Stale routes are kept installed while the client performs a graceful
+    // restart, and are removed once it's complete unless they're refreshed in
+    // the meantime.
+    pub(crate) fn route_mark_stale(
+        &mut self,
+        owner: IbusClientId,
+        protocol: Protocol,
+    ) {
+        for (_, rib_prefix) in self.ip.iter_mut() {
+            for route in rib_prefix.values_mut() {
+                if route.owner == owner && route.protocol == protocol {
+                    route.flags.insert(RouteFlags::STALE);
+                }
+            }
+        }
+        for route in self.mpls.values_mut() {
+            if route.owner == owner && route.protocol == protocol {
+                route.flags.insert(RouteFlags::STALE);
+            }
+        }
+    }
+
+    // Removes all IP and MPLS routes of the given protocol that are still
+    // marked as stale, regardless of the client that installed them.
+    //
+    // Routes left in the kernel by a previous Holo instance that weren't
+    // refreshed are purged as well.
+    pub(crate) fn route_sweep_stale(
+        &mut self,
+        protocol: Protocol,
+        netlink_tx: &UnboundedSender<NetlinkRequest>,
+    ) {
+        for (prefix, rib_prefix) in self.ip.iter_mut() {
+            for route in rib_prefix.values_mut() {
+                if route.protocol == protocol
+                    && route.flags.contains(RouteFlags::STALE)
+                {
+                    route.flags.insert(RouteFlags::REMOVED);
+                    self.ip_update_queue.insert(prefix);
+                }
+            }
+        }
+        for (label, route) in &mut self.mpls {
+            if route.protocol == protocol
+                && route.flags.contains(RouteFlags::STALE)
+            {
                 route.flags.insert(RouteFlags::REMOVED);
                 self.mpls_update_queue.insert(*label);
             }
         }
         let _ = self.update_queue_tx.send(());
+
+        // Purge kernel routes that aren't present in the RIB.
+        let keep = self
+            .ip
+            .iter()
+            .filter(|(_, rib_prefix)| {
+                rib_prefix
+                    .values()
+                    .any(|route| !route.flags.contains(RouteFlags::REMOVED))
+            })
+            .map(|(prefix, _)| prefix)
+            .collect();
+        netlink::stale_routes_purge(netlink_tx, protocol, keep);
     }
 
-    // Uninstall all routes.
+    // Uninstall all routes, except those of the given protocols, which are
+    // performing a graceful restart.
     pub(crate) fn route_uninstall_all(
         &mut self,
         netlink_tx: &UnboundedSender<NetlinkRequest>,
+        gr_protocols: &BTreeSet<Protocol>,
     ) {
         for (prefix, rib_prefix) in &self.ip {
-            if let Some(route) = rib_prefix
-                .values()
-                .find(|route| route.flags.contains(RouteFlags::ACTIVE))
-            {
+            if let Some(route) = rib_prefix.values().find(|route| {
+                route.flags.contains(RouteFlags::ACTIVE)
+                    && !gr_protocols.contains(&route.protocol)
+            }) {
                 netlink::ip_route_uninstall(
                     netlink_tx,
                     &prefix,
@@ -525,6 +641,9 @@ impl Rib {
             }
         }
         for (label, route) in &self.mpls {
+            if gr_protocols.contains(&route.protocol) {
+                continue;
+            }
             netlink::mpls_route_uninstall(netlink_tx, *label, route.protocol);
         }
     }
@@ -533,6 +652,32 @@ impl Rib {
 // ===== impl Route =====
 
 impl Route {
+    // Returns the nexthops that should be installed in the kernel.
+    //
+    // The backup nexthops are used only when none of the primary nexthops
+    // is reachable through an operational interface.
+    pub(crate) fn fib_nexthops<'a>(
+        &'a self,
+        interfaces: &Interfaces,
+    ) -> &'a BTreeSet<Nexthop> {
+        let is_operative = |nexthop: &Nexthop| {
+            nexthop.ifindex().is_none_or(|ifindex| {
+                interfaces.get_by_ifindex(ifindex).is_none_or(|iface| {
+                    iface.flags.contains(InterfaceFlags::OPERATIVE)
+                })
+            })
+        };
+
+        if !self.backup_nexthops.is_empty()
+            && !self.nexthops.iter().any(is_operative)
+            && self.backup_nexthops.iter().any(is_operative)
+        {
+            return &self.backup_nexthops;
+        }
+
+        &self.nexthops
+    }
+
     // Merges the provided set of nexthops into this route.
     //
     // If a matching nexthop is found, its labels are copied. Otherwise, the
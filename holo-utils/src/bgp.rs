@@ -63,6 +63,15 @@ pub struct Extv6Comm(pub Ipv6Addr, pub u32);
 #[derive(Deserialize, Serialize)]
 pub struct LargeComm(pub [u8; 12]);
 
+// Outcome of the ASPA-based AS_PATH verification.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[derive(Deserialize, Serialize)]
+pub enum AspaValidationState {
+    Valid,
+    Invalid,
+    Unknown,
+}
+
 // BGP Well-known Communities.
 //
 // IANA registry:
@@ -72,6 +81,9 @@ pub struct LargeComm(pub [u8; 12]);
 #[derive(Deserialize, Serialize)]
 #[repr(u32)]
 pub enum WellKnownCommunities {
+    GracefulShutdown = 0xFFFF0000,
+    LlgrStale = 0xFFFF0006,
+    NoLlgr = 0xFFFF0007,
     NoExport = 0xFFFFFF01,
     NoAdvertise = 0xFFFFFF02,
     NoExportSubconfed = 0xFFFFFF03,
@@ -121,11 +133,38 @@ impl TryFromYang for Origin {
     }
 }
 
+// ===== impl AspaValidationState =====
+
+impl ToYang for AspaValidationState {
+    fn to_yang(&self) -> Cow<'static, str> {
+        match self {
+            AspaValidationState::Valid => "valid".into(),
+            AspaValidationState::Invalid => "invalid".into(),
+            AspaValidationState::Unknown => "unknown".into(),
+        }
+    }
+}
+
+impl TryFromYang for AspaValidationState {
+    fn try_from_yang(value: &str) -> Option<AspaValidationState> {
+        match value {
+            "valid" => Some(AspaValidationState::Valid),
+            "invalid" => Some(AspaValidationState::Invalid),
+            "unknown" => Some(AspaValidationState::Unknown),
+            _ => None,
+        }
+    }
+}
+
 // ===== impl WellKnownCommunities =====
 
 impl ToYang for WellKnownCommunities {
     fn to_yang(&self) -> Cow<'static, str> {
         match self {
+            // There are no YANG identities for these communities.
+            WellKnownCommunities::GracefulShutdown => "65535:0".into(),
+            WellKnownCommunities::LlgrStale => "65535:6".into(),
+            WellKnownCommunities::NoLlgr => "65535:7".into(),
             WellKnownCommunities::NoExport => {
                 "iana-bgp-community-types:no-export".into()
             }
@@ -0,0 +1,70 @@
+//
+// Copyright (c) The Holo Core Contributors
+//
+// SPDX-License-Identifier: MIT
+//
+
+use chrono::{DateTime, Utc};
+use pickledb::PickleDb;
+
+use crate::DatabaseError;
+use crate::protocol::Protocol;
+
+// Suffix of the keys used to store graceful restart deadlines.
+const KEY_SUFFIX: &str = "-gr-deadline";
+
+// The graceful restart deadline of a protocol instance is stored in
+// non-volatile memory, so that the routes it installed are preserved across a
+// restart of the daemon itself.
+
+// Stores the graceful restart deadline of the given protocol instance.
+pub fn restart_deadline_set(
+    db: &mut PickleDb,
+    protocol: Protocol,
+    name: &str,
+    deadline: DateTime<Utc>,
+) -> Result<(), DatabaseError> {
+    let key = restart_deadline_key(protocol, name);
+    db.set(&key, &deadline.timestamp())
+}
+
+// Returns the graceful restart deadline of the given protocol instance, if
+// any.
+pub fn restart_deadline_get(
+    db: &PickleDb,
+    protocol: Protocol,
+    name: &str,
+) -> Option<DateTime<Utc>> {
+    let key = restart_deadline_key(protocol, name);
+    db.get::<i64>(&key)
+        .and_then(|secs| DateTime::from_timestamp(secs, 0))
+}
+
+// Removes the graceful restart deadline of the given protocol instance.
+pub fn restart_deadline_clear(
+    db: &mut PickleDb,
+    protocol: Protocol,
+    name: &str,
+) -> Result<(), DatabaseError> {
+    let key = restart_deadline_key(protocol, name);
+    db.rem(&key).map(|_| ())
+}
+
+// Returns whether any instance of the given protocol has a graceful restart
+// in progress.
+pub fn restart_pending(db: &PickleDb, protocol: Protocol) -> bool {
+    let prefix = format!("{protocol}-");
+    let now = Utc::now();
+    db.get_all()
+        .iter()
+        .filter(|key| key.starts_with(&prefix) && key.ends_with(KEY_SUFFIX))
+        .filter_map(|key| db.get::<i64>(key))
+        .filter_map(|secs| DateTime::from_timestamp(secs, 0))
+        .any(|deadline| deadline > now)
+}
+
+// ===== helper functions =====
+
+fn restart_deadline_key(protocol: Protocol, name: &str) -> String {
+    format!("{protocol}-{name}{KEY_SUFFIX}")
+}
@@ -159,6 +159,13 @@ pub enum IbusMsg {
     KeychainUpd(Arc<Keychain>),
     /// Key-chain delete notification.
     KeychainDel(String),
+    /// Request a subscription to LDP-IGP synchronization notifications.
+    LdpSyncSub {},
+    /// LDP-IGP synchronization state update for an LDP-enabled interface.
+    LdpSyncUpd { ifname: String, synced: bool },
+    /// LDP-IGP synchronization delete notification (LDP is no longer enabled
+    /// on the interface).
+    LdpSyncDel { ifname: String },
     /// Create a macvlan interface.
     MacvlanAdd {
         parent_ifname: String,
@@ -200,6 +207,14 @@ pub enum IbusMsg {
     /// be purged. E.g., One could ask to purge the BIRT populated by a specific
     /// instance of OSPFv3 but not those populated by IS-IS.
     BierPurge,
+    /// Marks all routes of the given protocol installed by the sender as
+    /// stale. Stale routes are preserved until they're either refreshed or
+    /// swept.
+    RouteStaleMark { protocol: Protocol },
+    /// Removes all routes of the given protocol installed by the sender that
+    /// are still marked as stale, including routes left in the kernel by a
+    /// previous instance of the daemon.
+    RouteStaleSweep { protocol: Protocol },
     /// Requests a subscription to route update notifications for a specific
     /// protocol, with optional filtering by address family.
     RouteRedistributeSub {
@@ -211,6 +226,13 @@ pub enum IbusMsg {
         protocol: Protocol,
         af: Option<AddressFamily>,
     },
+    /// Requests a subscription to default route update notifications,
+    /// regardless of the protocol that originated the route, with optional
+    /// filtering by address family. Routes owned by the subscriber itself are
+    /// reported as deleted.
+    RouteDefaultSub { af: Option<AddressFamily> },
+    /// Cancel a previously requested subscription to default route updates.
+    RouteDefaultUnsub { af: Option<AddressFamily> },
     /// Route redistribute update notification.
     RouteRedistributeAdd(RouteMsg),
     /// Route redistribute delete notification.
@@ -306,6 +328,21 @@ impl IbusChannelsTx {
             .send(IbusMsg::InterfaceIpDelRequest { ifname, addr });
     }
 
+    /// Sends an [`IbusMsg::LdpSyncSub`] message to `holo-routing`.
+    pub fn ldp_sync_sub(&self) {
+        self.routing.send(IbusMsg::LdpSyncSub {});
+    }
+
+    /// Sends an [`IbusMsg::LdpSyncUpd`] message to `holo-routing`.
+    pub fn ldp_sync_upd(&self, ifname: String, synced: bool) {
+        self.routing.send(IbusMsg::LdpSyncUpd { ifname, synced });
+    }
+
+    /// Sends an [`IbusMsg::LdpSyncDel`] message to `holo-routing`.
+    pub fn ldp_sync_del(&self, ifname: String) {
+        self.routing.send(IbusMsg::LdpSyncDel { ifname });
+    }
+
     /// Sends an [`IbusMsg::MacvlanAdd`] message to `holo-interface`.
     pub fn macvlan_add(
         &self,
@@ -375,6 +412,16 @@ impl IbusChannelsTx {
         self.routing.send(IbusMsg::BierPurge);
     }
 
+    /// Sends an [`IbusMsg::RouteStaleMark`] message to `holo-routing`.
+    pub fn route_stale_mark(&self, protocol: Protocol) {
+        self.routing.send(IbusMsg::RouteStaleMark { protocol });
+    }
+
+    /// Sends an [`IbusMsg::RouteStaleSweep`] message to `holo-routing`.
+    pub fn route_stale_sweep(&self, protocol: Protocol) {
+        self.routing.send(IbusMsg::RouteStaleSweep { protocol });
+    }
+
     /// Sends an [`IbusMsg::RouteRedistributeSub`] message to `holo-routing`.
     pub fn route_redistribute_sub(
         &self,
@@ -395,6 +442,16 @@ impl IbusChannelsTx {
             .send(IbusMsg::RouteRedistributeUnsub { protocol, af });
     }
 
+    /// Sends an [`IbusMsg::RouteDefaultSub`] message to `holo-routing`.
+    pub fn route_default_sub(&self, af: Option<AddressFamily>) {
+        self.routing.send(IbusMsg::RouteDefaultSub { af });
+    }
+
+    /// Sends an [`IbusMsg::RouteDefaultUnsub`] message to `holo-routing`.
+    pub fn route_default_unsub(&self, af: Option<AddressFamily>) {
+        self.routing.send(IbusMsg::RouteDefaultUnsub { af });
+    }
+
     #[doc(hidden)]
     pub fn keychain_upd(&self, keychain: Arc<Keychain>) {
         self.routing.send(IbusMsg::KeychainUpd(keychain));
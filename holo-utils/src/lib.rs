@@ -19,6 +19,7 @@ pub mod bier;
 pub mod bytes;
 pub mod capabilities;
 pub mod crypto;
+pub mod gr;
 pub mod ibus;
 pub mod ip;
 pub mod keychain;
@@ -43,6 +43,22 @@ pub enum LabelManagerError {
     LabelSpaceExhausted,
 }
 
+// LDP-IGP synchronization state of an IGP interface (RFC 5443).
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub enum LdpSyncState {
+    // Synchronization isn't in effect on the interface, either because it
+    // isn't enabled or because LDP isn't enabled on the interface.
+    #[default]
+    Disabled,
+    // LDP isn't synchronized yet. The maximum metric is advertised.
+    NotAchieved,
+    // The holddown timer expired before LDP was synchronized.
+    HolddownExpired,
+    // LDP is synchronized.
+    Achieved,
+}
+
 // ===== impl Label =====
 
 impl Label {
@@ -210,6 +226,41 @@ impl Default for LabelManager {
     }
 }
 
+// ===== impl LdpSyncState =====
+
+impl LdpSyncState {
+    // Computes the next synchronization state, given whether synchronization
+    // is enabled on the interface and the synchronization status advertised
+    // by LDP for it (`None` when LDP isn't enabled on the interface).
+    pub fn next(self, enabled: bool, ldp_synced: Option<bool>) -> Self {
+        match (enabled, ldp_synced) {
+            (false, _) | (true, None) => LdpSyncState::Disabled,
+            (true, Some(true)) => LdpSyncState::Achieved,
+            (true, Some(false)) => match self {
+                LdpSyncState::HolddownExpired => LdpSyncState::HolddownExpired,
+                _ => LdpSyncState::NotAchieved,
+            },
+        }
+    }
+
+    // Returns whether the maximum metric should be advertised for the
+    // interface.
+    pub fn max_metric(&self) -> bool {
+        *self == LdpSyncState::NotAchieved
+    }
+}
+
+impl ToYang for LdpSyncState {
+    fn to_yang(&self) -> Cow<'static, str> {
+        match self {
+            LdpSyncState::Disabled => "disabled".into(),
+            LdpSyncState::NotAchieved => "not-achieved".into(),
+            LdpSyncState::HolddownExpired => "holddown-expired".into(),
+            LdpSyncState::Achieved => "achieved".into(),
+        }
+    }
+}
+
 // ===== impl LabelManagerError =====
 
 impl std::fmt::Display for LabelManagerError {
@@ -229,3 +280,41 @@ impl std::fmt::Display for LabelManagerError {
 }
 
 impl std::error::Error for LabelManagerError {}
+
+#[cfg(test)]
+mod test_ldp_sync {
+    use super::*;
+
+    #[test]
+    fn test_ldp_sync_disabled() {
+        let state = LdpSyncState::NotAchieved;
+        assert_eq!(state.next(false, Some(false)), LdpSyncState::Disabled);
+        assert_eq!(state.next(true, None), LdpSyncState::Disabled);
+    }
+
+    #[test]
+    fn test_ldp_sync_achieved() {
+        let state = LdpSyncState::Disabled.next(true, Some(false));
+        assert_eq!(state, LdpSyncState::NotAchieved);
+        assert!(state.max_metric());
+
+        let state = state.next(true, Some(true));
+        assert_eq!(state, LdpSyncState::Achieved);
+        assert!(!state.max_metric());
+
+        // Loss of synchronization.
+        let state = state.next(true, Some(false));
+        assert_eq!(state, LdpSyncState::NotAchieved);
+    }
+
+    #[test]
+    fn test_ldp_sync_holddown_expired() {
+        let state = LdpSyncState::HolddownExpired;
+        assert_eq!(
+            state.next(true, Some(false)),
+            LdpSyncState::HolddownExpired
+        );
+        assert!(!state.max_metric());
+        assert_eq!(state.next(true, Some(true)), LdpSyncState::Achieved);
+    }
+}
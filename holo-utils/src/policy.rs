@@ -46,7 +46,7 @@ pub enum PolicyResult<T> {
 }
 
 // Default policy type.
-#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
 #[derive(Deserialize, Serialize)]
 pub enum DefaultPolicyType {
     AcceptRoute,
@@ -251,6 +251,7 @@ pub enum BgpPolicyConditionType {
     MatchLargeCommSet,
     MatchAsPathSet,
     MatchNexthopSet,
+    AspaValidation,
 }
 
 // BGP policy condition statement.
@@ -307,6 +308,7 @@ pub enum BgpPolicyCondition {
         value: String,
         match_type: MatchSetRestrictedType,
     },
+    AspaValidation(bgp::AspaValidationState),
 }
 
 // Policy action statement type.
@@ -708,6 +710,9 @@ impl BgpPolicyCondition {
             BgpPolicyCondition::MatchNexthopSet { .. } => {
                 BgpPolicyConditionType::MatchNexthopSet
             }
+            BgpPolicyCondition::AspaValidation(..) => {
+                BgpPolicyConditionType::AspaValidation
+            }
         }
     }
 }
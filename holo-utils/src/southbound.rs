@@ -91,6 +91,8 @@ pub enum OspfRouteType {
     InterArea,
     Type1External,
     Type2External,
+    Nssa1,
+    Nssa2,
 }
 
 // IS-IS route types.
@@ -141,6 +143,10 @@ pub struct RouteMsg {
     #[serde(skip)]
     pub opaque_attrs: RouteOpaqueAttrs,
     pub nexthops: BTreeSet<Nexthop>,
+    // Backup nexthops computed by IP Fast Reroute, used when all primary
+    // nexthops become unavailable.
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub backup_nexthops: BTreeSet<Nexthop>,
 }
 
 #[derive(Clone, Debug)]
@@ -217,6 +223,15 @@ impl Nexthop {
         }
     }
 
+    // Returns the index of the outgoing interface, if known.
+    pub fn ifindex(&self) -> Option<u32> {
+        match self {
+            Nexthop::Address { ifindex, .. }
+            | Nexthop::Interface { ifindex } => Some(*ifindex),
+            Nexthop::Recursive { .. } => None,
+        }
+    }
+
     // Removes all labels from a `Nexthop::Address` variant.
     pub fn remove_labels(&mut self) {
         if let Nexthop::Address { labels, .. } = self {
@@ -249,6 +264,8 @@ impl ToYang for OspfRouteType {
             OspfRouteType::InterArea => "inter-area".into(),
             OspfRouteType::Type1External => "external-1".into(),
             OspfRouteType::Type2External => "external-2".into(),
+            OspfRouteType::Nssa1 => "nssa-1".into(),
+            OspfRouteType::Nssa2 => "nssa-2".into(),
         }
     }
 }
@@ -5,13 +5,14 @@
 //
 
 use std::borrow::Cow;
-use std::collections::{BTreeSet, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 
 use derive_new::new;
 use enum_as_inner::EnumAsInner;
 use holo_yang::{ToYang, TryFromYang};
 use ipnetwork::IpNetwork;
-use num_derive::{FromPrimitive, ToPrimitive};
+use num_derive::FromPrimitive;
+use num_traits::{FromPrimitive, ToPrimitive};
 use serde::{Deserialize, Serialize};
 
 use crate::ip::AddressFamily;
@@ -21,12 +22,17 @@ use crate::mpls::{Label, LabelRange};
 //
 // IANA registry:
 // https://www.iana.org/assignments/igp-parameters/igp-parameters.xhtml#igp-algorithm-types
+//
+// Algorithms are serialized as strings so that they can be used as JSON map
+// keys.
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-#[derive(FromPrimitive, ToPrimitive)]
 #[derive(Deserialize, Serialize)]
+#[serde(into = "String", try_from = "String")]
 pub enum IgpAlgoType {
-    Spf = 0,
-    StrictSpf = 1,
+    Spf,
+    StrictSpf,
+    // Flexible Algorithm (RFC 9350), in the 128-255 range.
+    FlexAlgo(u8),
 }
 
 // IGP MSD-Types.
@@ -62,6 +68,8 @@ pub enum SidLastHopBehavior {
 pub struct SrCfg {
     #[serde(with = "vectorize")]
     pub prefix_sids: HashMap<(IpNetwork, IgpAlgoType), SrCfgPrefixSid>,
+    #[serde(default)]
+    pub ms_policies: BTreeMap<String, SrCfgMsPolicy>,
     pub srgb: BTreeSet<LabelRange>,
     pub srlb: BTreeSet<LabelRange>,
 }
@@ -74,15 +82,84 @@ pub struct SrCfgPrefixSid {
     pub last_hop: SidLastHopBehavior,
 }
 
+// Mapping server policy configuration.
+#[derive(Clone, Debug, Default)]
+#[derive(Deserialize, Serialize)]
+pub struct SrCfgMsPolicy {
+    #[serde(with = "vectorize")]
+    pub entries: HashMap<(IpNetwork, IgpAlgoType), SrCfgMsEntry>,
+}
+
+// Mapping server entry configuration.
+#[derive(Clone, Debug, new)]
+#[derive(Deserialize, Serialize)]
+pub struct SrCfgMsEntry {
+    pub index: u32,
+    pub range: u32,
+}
+
 // Type of Segment Routing configuration change.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub enum SrCfgEvent {
     LabelRangeUpdate,
     PrefixSidUpdate(AddressFamily),
+    MappingServerUpdate,
 }
 
 // ===== impl IgpAlgoType =====
 
+impl IgpAlgoType {
+    // Range of algorithm values reserved for Flexible Algorithms.
+    pub const FLEX_ALGO_RANGE: std::ops::RangeInclusive<u8> = 128..=255;
+
+    // Returns the Flexible Algorithm number, if this is a Flexible Algorithm.
+    pub fn flex_algo(&self) -> Option<u8> {
+        match self {
+            IgpAlgoType::FlexAlgo(algo) => Some(*algo),
+            _ => None,
+        }
+    }
+}
+
+impl FromPrimitive for IgpAlgoType {
+    fn from_i64(value: i64) -> Option<Self> {
+        u8::try_from(value).ok().and_then(Self::from_u8)
+    }
+
+    fn from_u64(value: u64) -> Option<Self> {
+        u8::try_from(value).ok().and_then(Self::from_u8)
+    }
+
+    fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(IgpAlgoType::Spf),
+            1 => Some(IgpAlgoType::StrictSpf),
+            value if Self::FLEX_ALGO_RANGE.contains(&value) => {
+                Some(IgpAlgoType::FlexAlgo(value))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl ToPrimitive for IgpAlgoType {
+    fn to_i64(&self) -> Option<i64> {
+        self.to_u8().map(i64::from)
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.to_u8().map(u64::from)
+    }
+
+    fn to_u8(&self) -> Option<u8> {
+        match self {
+            IgpAlgoType::Spf => Some(0),
+            IgpAlgoType::StrictSpf => Some(1),
+            IgpAlgoType::FlexAlgo(algo) => Some(*algo),
+        }
+    }
+}
+
 impl ToYang for IgpAlgoType {
     fn to_yang(&self) -> Cow<'static, str> {
         match self {
@@ -94,6 +171,9 @@ impl ToYang for IgpAlgoType {
                 "ietf-segment-routing-common:prefix-sid-algorithm-strict-spf"
                     .into()
             }
+            IgpAlgoType::FlexAlgo(_) => {
+                "holo-routing:prefix-sid-algorithm-flex-algo".into()
+            }
         }
     }
 }
@@ -112,6 +192,34 @@ impl TryFromYang for IgpAlgoType {
     }
 }
 
+impl From<IgpAlgoType> for String {
+    fn from(algo: IgpAlgoType) -> String {
+        match algo {
+            IgpAlgoType::Spf => "Spf".to_owned(),
+            IgpAlgoType::StrictSpf => "StrictSpf".to_owned(),
+            IgpAlgoType::FlexAlgo(algo) => format!("FlexAlgo({algo})"),
+        }
+    }
+}
+
+impl TryFrom<String> for IgpAlgoType {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<IgpAlgoType, String> {
+        match value.as_str() {
+            "Spf" => Ok(IgpAlgoType::Spf),
+            "StrictSpf" => Ok(IgpAlgoType::StrictSpf),
+            _ => value
+                .strip_prefix("FlexAlgo(")
+                .and_then(|algo| algo.strip_suffix(')'))
+                .and_then(|algo| algo.parse::<u8>().ok())
+                .filter(|algo| Self::FLEX_ALGO_RANGE.contains(algo))
+                .map(IgpAlgoType::FlexAlgo)
+                .ok_or_else(|| format!("invalid IGP algorithm: {value}")),
+        }
+    }
+}
+
 // ===== impl Sid =====
 
 impl Sid {
@@ -135,3 +243,72 @@ impl TryFromYang for SidLastHopBehavior {
         }
     }
 }
+
+// ===== global functions =====
+
+// Returns the position of the given prefix within the block of `range`
+// consecutive prefixes that starts at `start`, if any.
+//
+// This is used to resolve the SIDs advertised by SR Mapping Servers, where a
+// single entry maps a contiguous block of prefixes to a block of SIDs.
+pub fn prefix_range_offset(
+    start: &IpNetwork,
+    range: u32,
+    prefix: &IpNetwork,
+) -> Option<u32> {
+    if start.prefix() != prefix.prefix() {
+        return None;
+    }
+
+    let (start_addr, addr, bits) = match (start, prefix) {
+        (IpNetwork::V4(start), IpNetwork::V4(prefix)) => (
+            u128::from(u32::from(start.network())),
+            u128::from(u32::from(prefix.network())),
+            32,
+        ),
+        (IpNetwork::V6(start), IpNetwork::V6(prefix)) => (
+            u128::from(start.network()),
+            u128::from(prefix.network()),
+            128,
+        ),
+        _ => return None,
+    };
+
+    // Compare only the network bits of both prefixes.
+    let shift = bits - u32::from(prefix.prefix());
+    let start_addr = start_addr.checked_shr(shift).unwrap_or(0);
+    let addr = addr.checked_shr(shift).unwrap_or(0);
+    let offset = addr.checked_sub(start_addr)?;
+    (offset < u128::from(range)).then_some(offset as u32)
+}
+
+#[cfg(test)]
+mod test_prefix_range {
+    use super::*;
+
+    fn offset(start: &str, range: u32, prefix: &str) -> Option<u32> {
+        prefix_range_offset(
+            &start.parse().unwrap(),
+            range,
+            &prefix.parse().unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_ipv4_range() {
+        assert_eq!(offset("10.0.0.1/32", 1, "10.0.0.1/32"), Some(0));
+        assert_eq!(offset("10.0.0.1/32", 10, "10.0.0.5/32"), Some(4));
+        assert_eq!(offset("10.0.0.1/32", 10, "10.0.0.11/32"), None);
+        assert_eq!(offset("10.0.0.1/32", 10, "10.0.0.0/32"), None);
+        assert_eq!(offset("10.0.0.0/24", 4, "10.0.3.0/24"), Some(3));
+        assert_eq!(offset("10.0.0.0/24", 4, "10.0.3.0/25"), None);
+    }
+
+    #[test]
+    fn test_ipv6_range() {
+        assert_eq!(offset("2001:db8::1/128", 16, "2001:db8::f/128"), Some(14));
+        assert_eq!(offset("2001:db8::/64", 2, "2001:db8:0:1::/64"), Some(1));
+        assert_eq!(offset("::/0", 1, "::/0"), Some(0));
+        assert_eq!(offset("2001:db8::1/128", 16, "10.0.0.1/32"), None);
+    }
+}
@@ -164,6 +164,8 @@ static YANG_EMBEDDED_MODULES: Lazy<EmbeddedModules> = Lazy::new(|| {
         // IETF Holo augmentations
         EmbeddedModuleKey::new("holo-bgp", None, None, None) =>
             include_str!("../modules/augmentations/holo-bgp.yang"),
+        EmbeddedModuleKey::new("holo-bgp-policy", None, None, None) =>
+            include_str!("../modules/augmentations/holo-bgp-policy.yang"),
         EmbeddedModuleKey::new("holo-isis", None, None, None) =>
             include_str!("../modules/augmentations/holo-isis.yang"),
         EmbeddedModuleKey::new("holo-isis-dev", None, None, None) =>
@@ -284,6 +286,7 @@ pub mod implemented_modules {
         "ietf-isis-msd",
         "ietf-isis-sr-mpls",
         "ietf-segment-routing-mpls",
+        "ietf-te-types",
         // IETF Holo augmentations
         "holo-isis",
         "holo-isis-dev",
@@ -314,6 +317,8 @@ pub mod implemented_modules {
         // IETF modules
         "ietf-routing-policy",
         "ietf-bgp-policy",
+        // IETF Holo augmentations
+        "holo-bgp-policy",
     ];
     pub const RIP: &[&str] = &[
         // IETF modules
@@ -411,6 +416,7 @@ pub static YANG_FEATURES: Lazy<HashMap<&'static str, Vec<&'static str>>> =
                 "bfd",
                 "ietf-spf-delay",
                 "key-chain",
+                "ldp-igp-sync",
                 "lsp-refresh",
                 "max-ecmp",
                 "multi-topology",
@@ -423,15 +429,22 @@ pub static YANG_FEATURES: Lazy<HashMap<&'static str, Vec<&'static str>>> =
             "ietf-ospf" => vec![
                 "bfd",
                 "explicit-router-id",
+                "fast-reroute",
                 "graceful-restart",
                 "ietf-spf-delay",
                 "key-chain",
+                "ldp-igp-sync",
+                "lfa",
                 "lls",
                 "max-ecmp",
                 "mtu-ignore",
+                "multi-area-adj",
                 "node-flag",
                 "node-tag",
+                "ospfv2-authentication-trailer",
                 "ospfv3-authentication-trailer",
+                "prefix-suppression",
+                "remote-lfa",
                 "stub-router",
             ],
             "ietf-rip" => vec![
@@ -442,6 +455,12 @@ pub static YANG_FEATURES: Lazy<HashMap<&'static str, Vec<&'static str>>> =
             "ietf-segment-routing-common" => vec![
                 "sid-last-hop-behavior",
             ],
+            "ietf-segment-routing-mpls" => vec![
+                "mapping-server",
+            ],
+            "ietf-te-types" => vec![
+                "extended-admin-groups",
+            ],
             "ietf-vrrp" => vec![
                 "validate-interval-errors",
             ],